@@ -1,6 +1,7 @@
 use std::{fmt, path::PathBuf};
 
 use clap::{Parser, Subcommand, ValueEnum};
+use jito_tip_router_core::{config::ConfigAdminRole, pause::PausableSubsystem};
 use solana_sdk::clock::DEFAULT_SLOTS_PER_EPOCH;
 
 #[derive(Parser)]
@@ -169,6 +170,14 @@ pub enum ProgramCommand {
     CrankSnapshot {},
     CrankDistribute {},
     CrankCloseEpochAccounts {},
+    CrankDistributeTokenRewards {
+        #[arg(long, help = "Mint of the SPL token rewards")]
+        mint: String,
+    },
+    CrankCloseTokenRewardRouters {
+        #[arg(long, help = "Mint of the SPL token rewards")]
+        mint: String,
+    },
     MigrateTdaMerkleRootUploadAuthorities,
 
     /// Admin
@@ -205,10 +214,39 @@ pub enum ProgramCommand {
             help = "Reward multiplier in basis points"
         )]
         reward_multiplier_bps: u64,
-        #[arg(long, help = "Switchboard feed address")]
+        #[arg(
+            long,
+            help = "Switchboard feed, Pyth price feed or SPL stake pool address"
+        )]
         switchboard_feed: Option<String>,
         #[arg(long, help = "Weight when no feed is available")]
         no_feed_weight: Option<u128>,
+        #[arg(
+            long,
+            help = "Oracle of the feed, 0 = Switchboard, 1 = Pyth, 2 = SPL stake pool"
+        )]
+        oracle_type: Option<u8>,
+        #[arg(
+            long,
+            help = "Max age of the feed, in slots for Switchboard, seconds for Pyth and epochs for SPL stake pools"
+        )]
+        max_staleness: Option<u64>,
+        #[arg(long, help = "Max std dev or confidence relative to the price in bps")]
+        max_confidence_bps: Option<u16>,
+        #[arg(long, help = "Max weight change from the last epoch in bps")]
+        max_weight_change_bps: Option<u16>,
+    },
+    AdminDeactivateVault {
+        #[arg(long, help = "Vault address")]
+        vault: String,
+        #[arg(long, help = "First epoch the vault is left out of weight tables")]
+        deactivation_epoch: u64,
+    },
+    AdminDeactivateStMint {
+        #[arg(long, help = "ST mint address")]
+        st_mint: String,
+        #[arg(long, help = "First epoch the ST mint is left out of weight tables")]
+        deactivation_epoch: u64,
     },
     AdminSetWeight {
         #[arg(long, help = "Vault address")]
@@ -232,6 +270,68 @@ pub enum ProgramCommand {
         valid_slots_after_consensus: Option<u64>,
         #[arg(long, help = "Starting valid epoch")]
         starting_valid_epoch: Option<u64>,
+        #[arg(
+            long,
+            help = "Slots operators have to commit their votes, 0 disables commit-reveal voting"
+        )]
+        commit_window_slots: Option<u64>,
+        #[arg(
+            long,
+            help = "Slots operators have to reveal their votes after the commit window"
+        )]
+        reveal_window_slots: Option<u64>,
+        #[arg(
+            long,
+            help = "Share of stake weight in basis points needed for consensus, applies to epochs whose epoch state is created after the update"
+        )]
+        consensus_threshold_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Minimum number of operators that must vote for consensus, at most the NCN's operator count, 0 disables"
+        )]
+        quorum_operator_count: Option<u16>,
+        #[arg(
+            long,
+            help = "Minimum share of active operators in basis points that must vote for consensus, 0 disables"
+        )]
+        quorum_operator_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Stake weight in basis points the leading ballot needs to resolve a stalled vote, below the consensus threshold, 0 disables"
+        )]
+        stall_fallback_threshold_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Epochs a proposed admin has to wait before accepting, 0 allows setting admins directly"
+        )]
+        admin_handover_delay_epochs: Option<u16>,
+        #[arg(
+            long,
+            help = "Slots after the weight table is created during which oracle prices are averaged, 0 disables"
+        )]
+        weight_sampling_window_slots: Option<u64>,
+        #[arg(
+            long,
+            help = "Max share of the total stake weight in basis points an operator votes with, 0 disables"
+        )]
+        max_operator_stake_weight_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Max share of the total stake weight in basis points a single vault delegation adds to an operator's vote, 0 disables"
+        )]
+        max_vault_stake_weight_bps: Option<u16>,
+        #[arg(
+            long,
+            help = "Minimum epochs of notice before a fee increase can activate, 0 disables"
+        )]
+        fee_increase_notice_epochs: Option<u64>,
+        #[arg(
+            long,
+            help = "Lamports the account payer pays the signer of each successful crank step, 0 disables"
+        )]
+        crank_bounty_lamports: Option<u64>,
+        #[arg(long, help = "Max lamports paid out in crank bounties per epoch")]
+        max_crank_bounty_lamports_per_epoch: Option<u64>,
     },
     AdminSetConfigFees {
         #[arg(long, help = "New block engine fee in basis points")]
@@ -248,6 +348,40 @@ pub enum ProgramCommand {
         new_ncn_fee_bps: Option<u16>,
         #[arg(long, help = "New priority fee distribution fee in basis points")]
         new_priority_fee_distribution_fee_bps: Option<u16>,
+        #[arg(long, help = "Epoch the new fees activate, defaults to the next epoch")]
+        activation_epoch: Option<u64>,
+    },
+    AdminCancelScheduledFees {
+        #[arg(long, help = "Activation epoch of the scheduled fees to cancel")]
+        activation_epoch: u64,
+    },
+    AdminSetBaseFeeGroup {
+        #[arg(long, help = "Base fee group")]
+        base_fee_group: u8,
+        #[arg(long, help = "Name of the group, up to 32 bytes")]
+        name: Option<String>,
+        #[arg(long, help = "Enable or disable the group")]
+        enabled: Option<bool>,
+        #[arg(
+            long,
+            help = "Payout asset, 0 = JitoSOL, 1 = SOL (operators only), 2 = wSOL"
+        )]
+        payout_asset: Option<u8>,
+    },
+    AdminSetPausedSubsystems {
+        #[arg(
+            long,
+            value_enum,
+            value_delimiter = ',',
+            help = "Comma separated subsystems to pause, every other subsystem is unpaused"
+        )]
+        paused: Vec<Subsystem>,
+    },
+    AdminSetRewardMint {
+        #[arg(long, help = "Mint of the SPL token rewards")]
+        mint: String,
+        #[arg(long, help = "Disallow the mint instead of allowing it")]
+        disallow: bool,
     },
     AdminSetNewAdmin {
         #[arg(long, help = "New admin address")]
@@ -256,11 +390,110 @@ pub enum ProgramCommand {
         set_fee_admin: bool,
         #[arg(long, help = "Set tie breaker admin")]
         set_tie_breaker_admin: bool,
+        #[arg(long, help = "Set pause admin")]
+        set_pause_admin: bool,
+    },
+    AdminProposeNewAdmin {
+        #[arg(long, value_enum, help = "Admin role")]
+        role: AdminRole,
+        #[arg(long, help = "New admin address")]
+        new_admin: String,
+    },
+    AdminAcceptAdmin {
+        #[arg(long, value_enum, help = "Admin role")]
+        role: AdminRole,
+    },
+    AdminCancelAdminProposal {
+        #[arg(long, value_enum, help = "Admin role")]
+        role: AdminRole,
     },
     AdminFundAccountPayer {
         #[arg(long, help = "Amount of SOL to fund")]
         amount_in_sol: f64,
     },
+    AdminSetAdminMultisig {
+        #[arg(long, value_enum, help = "Admin role")]
+        role: AdminRole,
+        #[arg(long, help = "Number of approvals needed to execute a proposal")]
+        threshold: u8,
+        #[arg(long, value_delimiter = ',', help = "Comma separated signer addresses")]
+        signers: Vec<String>,
+    },
+    AdminMultisigProposeTieBreaker {
+        #[arg(long, help = "Meta merkle root")]
+        meta_merkle_root: String,
+    },
+    AdminMultisigProposeConfigFees {
+        #[arg(long, help = "New block engine fee in basis points")]
+        new_block_engine_fee_bps: Option<u16>,
+        #[arg(long, help = "Base fee group")]
+        base_fee_group: Option<u8>,
+        #[arg(long, help = "New base fee wallet")]
+        new_base_fee_wallet: Option<String>,
+        #[arg(long, help = "New base fee in basis points")]
+        new_base_fee_bps: Option<u16>,
+        #[arg(long, help = "NCN fee group")]
+        ncn_fee_group: Option<u8>,
+        #[arg(long, help = "New NCN fee in basis points")]
+        new_ncn_fee_bps: Option<u16>,
+        #[arg(long, help = "New priority fee distribution fee in basis points")]
+        new_priority_fee_distribution_fee_bps: Option<u16>,
+        #[arg(long, help = "Epoch the new fees activate, defaults to the next epoch")]
+        activation_epoch: Option<u64>,
+    },
+    AdminMultisigProposeBaseFeeGroup {
+        #[arg(long, help = "Base fee group")]
+        base_fee_group: u8,
+        #[arg(long, help = "Name of the group, up to 32 bytes")]
+        name: Option<String>,
+        #[arg(long, help = "Enable or disable the group")]
+        enabled: Option<bool>,
+        #[arg(long, help = "Payout asset, 0 = JitoSOL, 1 = SOL, 2 = wSOL")]
+        payout_asset: Option<u8>,
+    },
+    AdminMultisigProposeNewAdmin {
+        #[arg(long, value_enum, help = "Admin role")]
+        role: AdminRole,
+        #[arg(long, help = "New admin address")]
+        new_admin: String,
+    },
+    AdminMultisigProposeSigner {
+        #[arg(long, value_enum, help = "Admin role")]
+        role: AdminRole,
+        #[arg(long, help = "Signer address")]
+        signer: String,
+        #[arg(long, help = "Remove the signer instead of adding it")]
+        remove: bool,
+        #[arg(long, help = "Threshold of the multisig afterwards")]
+        threshold: u8,
+    },
+    AdminMultisigProposePausedSubsystems {
+        #[arg(
+            long,
+            value_enum,
+            value_delimiter = ',',
+            help = "Comma separated subsystems to pause, must include every paused subsystem"
+        )]
+        paused: Vec<Subsystem>,
+    },
+    AdminApproveAction {
+        #[arg(long, value_enum, help = "Admin role")]
+        role: AdminRole,
+        #[arg(long, help = "Proposal nonce")]
+        nonce: u64,
+    },
+    AdminExecuteAction {
+        #[arg(long, value_enum, help = "Admin role")]
+        role: AdminRole,
+        #[arg(long, help = "Proposal nonce")]
+        nonce: u64,
+    },
+    AdminCloseAction {
+        #[arg(long, value_enum, help = "Admin role")]
+        role: AdminRole,
+        #[arg(long, help = "Proposal nonce")]
+        nonce: u64,
+    },
 
     /// Instructions
     CreateVaultRegistry,
@@ -298,6 +531,11 @@ pub enum ProgramCommand {
         operator: String,
     },
 
+    ApplyStakeWeightCaps {
+        #[arg(long, help = "Operator address")]
+        operator: String,
+    },
+
     CreateBallotBox,
 
     OperatorCastVote {
@@ -307,6 +545,40 @@ pub enum ProgramCommand {
         meta_merkle_root: String,
     },
 
+    OperatorWithdrawVote {
+        #[arg(long, help = "Operator address")]
+        operator: String,
+    },
+
+    OperatorSetVoteKey {
+        #[arg(long, help = "Operator address")]
+        operator: String,
+        #[arg(
+            long,
+            help = "Vote key address, the default pubkey removes the vote key"
+        )]
+        vote_key: String,
+    },
+
+    OperatorCloseVoteKey {
+        #[arg(long, help = "Operator address")]
+        operator: String,
+    },
+
+    SetPayoutPreference {
+        #[arg(long, help = "Operator or vault address")]
+        recipient: String,
+        #[arg(long, help = "Payout asset, 0 = JitoSOL, 1 = SOL, 2 = wSOL")]
+        payout_asset: u8,
+        #[arg(
+            long,
+            help = "Where SOL rewards are sent, required when paying out in SOL"
+        )]
+        sol_destination: Option<String>,
+    },
+
+    ResolveStalledVote,
+
     CreateBaseRewardRouter,
 
     CreateNcnRewardRouter {
@@ -332,6 +604,14 @@ pub enum ProgramCommand {
         ncn_fee_group: u8,
     },
 
+    MigrateAccount {
+        #[arg(
+            long,
+            help = "Address of the account to upgrade to its next layout version"
+        )]
+        account: String,
+    },
+
     /// Getters
     GetNcn,
     GetNcnOperatorState {
@@ -365,6 +645,10 @@ pub enum ProgramCommand {
         operator: String,
     },
     GetBallotBox,
+    GetPayoutPreference {
+        #[arg(long, help = "Operator or vault address")]
+        recipient: String,
+    },
     GetBaseRewardRouter,
     GetBaseRewardReceiverAddress,
     GetNcnRewardRouter {
@@ -471,6 +755,44 @@ impl fmt::Display for Args {
     }
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum AdminRole {
+    Fee,
+    TieBreaker,
+    Pause,
+}
+
+impl From<AdminRole> for ConfigAdminRole {
+    fn from(role: AdminRole) -> Self {
+        match role {
+            AdminRole::Fee => Self::FeeAdmin,
+            AdminRole::TieBreaker => Self::TieBreakerAdmin,
+            AdminRole::Pause => Self::PauseAdmin,
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum Subsystem {
+    Voting,
+    MerkleRootUpload,
+    Routing,
+    Distribution,
+    Claiming,
+}
+
+impl From<Subsystem> for PausableSubsystem {
+    fn from(subsystem: Subsystem) -> Self {
+        match subsystem {
+            Subsystem::Voting => Self::Voting,
+            Subsystem::MerkleRootUpload => Self::MerkleRootUpload,
+            Subsystem::Routing => Self::Routing,
+            Subsystem::Distribution => Self::Distribution,
+            Subsystem::Claiming => Self::Claiming,
+        }
+    }
+}
+
 #[derive(ValueEnum, Debug, Clone)]
 pub enum Cluster {
     Mainnet,
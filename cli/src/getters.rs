@@ -18,18 +18,26 @@ use jito_restaking_core::{
 use jito_tip_distribution_sdk::TipDistributionAccount;
 use jito_tip_router_core::{
     account_payer::AccountPayer,
-    ballot_box::BallotBox,
+    admin_multisig::{AdminMultisig, AdminProposal},
+    ballot_box::{BallotBox, OperatorVote},
+    ballot_box_page::BallotBoxPage,
     base_fee_group::BaseFeeGroup,
-    base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
-    config::Config as TipRouterConfig,
+    base_reward_router::{BaseRewardReceiver, BaseRewardRouter, NcnRewardRoute},
+    base_reward_router_page::BaseRewardRouterPage,
+    config::{Config as TipRouterConfig, ConfigAdminRole},
     constants::JITOSOL_POOL_ADDRESS,
     epoch_marker::EpochMarker,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    epoch_state_page::EpochStatePage,
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    operator_snapshot_page::OperatorSnapshotPage,
+    payout_preference::PayoutPreference,
     vault_registry::VaultRegistry,
+    vault_registry_page::VaultRegistryPage,
     weight_table::WeightTable,
+    weight_table_page::WeightTablePage,
 };
 use jito_vault_core::{
     config::Config as VaultConfig, vault::Vault, vault_ncn_ticket::VaultNcnTicket,
@@ -45,7 +53,7 @@ use solana_client::{
 };
 use solana_commitment_config::CommitmentConfig;
 use solana_sdk::clock::DEFAULT_SLOTS_PER_EPOCH;
-use solana_sdk::{account::Account, pubkey::Pubkey};
+use solana_sdk::{account::Account, instruction::AccountMeta, program_pack::Pack, pubkey::Pubkey};
 use spl_associated_token_account_interface::address::get_associated_token_address;
 use tokio::time::sleep;
 
@@ -125,6 +133,47 @@ pub async fn get_tip_router_config(handler: &CliHandler) -> Result<TipRouterConf
     Ok(*account)
 }
 
+pub async fn get_admin_multisig(
+    handler: &CliHandler,
+    role: ConfigAdminRole,
+) -> Result<AdminMultisig> {
+    let (address, _, _) =
+        AdminMultisig::find_program_address(&handler.tip_router_program_id, handler.ncn()?, role);
+
+    let account = get_account(handler, &address).await?;
+
+    if account.is_none() {
+        return Err(anyhow::anyhow!("Admin multisig account not found"));
+    }
+    let account = account.unwrap();
+
+    let account = AdminMultisig::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(*account)
+}
+
+pub async fn get_admin_proposal(
+    handler: &CliHandler,
+    role: ConfigAdminRole,
+    nonce: u64,
+) -> Result<AdminProposal> {
+    let (address, _, _) = AdminProposal::find_program_address(
+        &handler.tip_router_program_id,
+        handler.ncn()?,
+        role,
+        nonce,
+    );
+
+    let account = get_account(handler, &address).await?;
+
+    if account.is_none() {
+        return Err(anyhow::anyhow!("Admin proposal account not found"));
+    }
+    let account = account.unwrap();
+
+    let account = AdminProposal::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(*account)
+}
+
 pub async fn get_vault_registry(handler: &CliHandler) -> Result<VaultRegistry> {
     let (address, _, _) =
         VaultRegistry::find_program_address(&handler.tip_router_program_id, handler.ncn()?);
@@ -140,6 +189,37 @@ pub async fn get_vault_registry(handler: &CliHandler) -> Result<VaultRegistry> {
     Ok(*account)
 }
 
+pub async fn get_vault_registry_page(handler: &CliHandler, page: u64) -> Result<VaultRegistryPage> {
+    let (address, _, _) = VaultRegistryPage::find_program_address(
+        &handler.tip_router_program_id,
+        handler.ncn()?,
+        page,
+    );
+
+    let account = get_account(handler, &address).await?;
+
+    if account.is_none() {
+        return Err(anyhow::anyhow!("Account not found"));
+    }
+    let account = account.unwrap();
+
+    let account = VaultRegistryPage::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(*account)
+}
+
+/// All pages created for the vault registry, in order
+pub async fn get_vault_registry_pages(
+    handler: &CliHandler,
+    vault_registry: &VaultRegistry,
+) -> Result<Vec<VaultRegistryPage>> {
+    let mut pages = Vec::with_capacity(vault_registry.page_count() as usize);
+    for page in 0..vault_registry.page_count() {
+        pages.push(get_vault_registry_page(handler, page).await?);
+    }
+
+    Ok(pages)
+}
+
 pub async fn get_epoch_state(handler: &CliHandler, epoch: u64) -> Result<EpochState> {
     let (address, _, _) =
         EpochState::find_program_address(&handler.tip_router_program_id, handler.ncn()?, epoch);
@@ -170,6 +250,42 @@ pub async fn get_weight_table(handler: &CliHandler, epoch: u64) -> Result<Weight
     Ok(*account)
 }
 
+pub async fn get_weight_table_page(
+    handler: &CliHandler,
+    epoch: u64,
+    page: u64,
+) -> Result<WeightTablePage> {
+    let (address, _, _) = WeightTablePage::find_program_address(
+        &handler.tip_router_program_id,
+        handler.ncn()?,
+        epoch,
+        page,
+    );
+
+    let account = get_account(handler, &address).await?;
+
+    if account.is_none() {
+        return Err(anyhow::anyhow!("Account not found"));
+    }
+    let account = account.unwrap();
+
+    let account = WeightTablePage::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(*account)
+}
+
+/// All pages initialized for the weight table, in order
+pub async fn get_weight_table_pages(
+    handler: &CliHandler,
+    weight_table: &WeightTable,
+) -> Result<Vec<WeightTablePage>> {
+    let mut pages = Vec::with_capacity(weight_table.pages_initialized() as usize);
+    for page in 0..weight_table.pages_initialized() {
+        pages.push(get_weight_table_page(handler, weight_table.epoch(), page).await?);
+    }
+
+    Ok(pages)
+}
+
 pub async fn get_epoch_snapshot(handler: &CliHandler, epoch: u64) -> Result<EpochSnapshot> {
     let (address, _, _) =
         EpochSnapshot::find_program_address(&handler.tip_router_program_id, handler.ncn()?, epoch);
@@ -208,6 +324,52 @@ pub async fn get_operator_snapshot(
     Ok(*account)
 }
 
+pub async fn get_operator_snapshot_page(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    epoch: u64,
+    page: u64,
+) -> Result<OperatorSnapshotPage> {
+    let (address, _, _) = OperatorSnapshotPage::find_program_address(
+        &handler.tip_router_program_id,
+        operator,
+        handler.ncn()?,
+        epoch,
+        page,
+    );
+
+    let account = get_account(handler, &address).await?;
+
+    if account.is_none() {
+        return Err(anyhow::anyhow!("Account not found"));
+    }
+    let account = account.unwrap();
+
+    let account = OperatorSnapshotPage::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(*account)
+}
+
+/// All pages created for the operator snapshot, in order
+pub async fn get_operator_snapshot_pages(
+    handler: &CliHandler,
+    operator_snapshot: &OperatorSnapshot,
+) -> Result<Vec<OperatorSnapshotPage>> {
+    let mut pages = Vec::with_capacity(operator_snapshot.page_count() as usize);
+    for page in 0..operator_snapshot.page_count() {
+        pages.push(
+            get_operator_snapshot_page(
+                handler,
+                operator_snapshot.operator(),
+                operator_snapshot.epoch(),
+                page,
+            )
+            .await?,
+        );
+    }
+
+    Ok(pages)
+}
+
 pub async fn get_ballot_box(handler: &CliHandler, epoch: u64) -> Result<BallotBox> {
     let (address, _, _) =
         BallotBox::find_program_address(&handler.tip_router_program_id, handler.ncn()?, epoch);
@@ -223,6 +385,84 @@ pub async fn get_ballot_box(handler: &CliHandler, epoch: u64) -> Result<BallotBo
     Ok(*account)
 }
 
+/// The payout preference of an operator or vault, `None` when it was never set
+pub async fn get_payout_preference(
+    handler: &CliHandler,
+    recipient: &Pubkey,
+) -> Result<Option<PayoutPreference>> {
+    let (address, _, _) = PayoutPreference::find_program_address(
+        &handler.tip_router_program_id,
+        recipient,
+        handler.ncn()?,
+    );
+
+    let account = get_account(handler, &address).await?;
+
+    Ok(account
+        .map(|account| PayoutPreference::try_from_slice_unchecked(account.data.as_slice()).copied())
+        .transpose()?)
+}
+
+pub async fn get_ballot_box_page(
+    handler: &CliHandler,
+    epoch: u64,
+    page: u64,
+) -> Result<BallotBoxPage> {
+    let (address, _, _) = BallotBoxPage::find_program_address(
+        &handler.tip_router_program_id,
+        handler.ncn()?,
+        epoch,
+        page,
+    );
+
+    let account = get_account(handler, &address).await?;
+
+    if account.is_none() {
+        return Err(anyhow::anyhow!("Account not found"));
+    }
+    let account = account.unwrap();
+
+    let account = BallotBoxPage::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(*account)
+}
+
+/// All pages created for the ballot box, in order
+pub async fn get_ballot_box_pages(
+    handler: &CliHandler,
+    ballot_box: &BallotBox,
+) -> Result<Vec<BallotBoxPage>> {
+    let mut pages = Vec::with_capacity(ballot_box.page_count() as usize);
+    for page in 0..ballot_box.page_count() {
+        pages.push(get_ballot_box_page(handler, ballot_box.epoch(), page).await?);
+    }
+
+    Ok(pages)
+}
+
+/// The non-empty operator votes of the ballot box and all of its pages
+pub async fn get_all_operator_votes(
+    handler: &CliHandler,
+    ballot_box: &BallotBox,
+) -> Result<Vec<OperatorVote>> {
+    let mut operator_votes: Vec<OperatorVote> = ballot_box
+        .operator_votes()
+        .iter()
+        .filter(|vote| !vote.is_empty())
+        .copied()
+        .collect();
+
+    for page in get_ballot_box_pages(handler, ballot_box).await? {
+        operator_votes.extend(
+            page.operator_votes()
+                .iter()
+                .filter(|vote| !vote.is_empty())
+                .copied(),
+        );
+    }
+
+    Ok(operator_votes)
+}
+
 pub async fn get_base_reward_router(handler: &CliHandler, epoch: u64) -> Result<BaseRewardRouter> {
     let (address, _, _) = BaseRewardRouter::find_program_address(
         &handler.tip_router_program_id,
@@ -286,6 +526,56 @@ pub async fn get_ncn_reward_router(
     Ok(*account)
 }
 
+pub async fn get_base_reward_router_for_mint(
+    handler: &CliHandler,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<BaseRewardRouter> {
+    let (address, _, _) = BaseRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        handler.ncn()?,
+        mint,
+        epoch,
+    );
+
+    let account = get_account(handler, &address).await?;
+
+    if account.is_none() {
+        return Err(anyhow::anyhow!("Account not found"));
+    }
+    let account = account.unwrap();
+
+    let account = BaseRewardRouter::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(*account)
+}
+
+pub async fn get_ncn_reward_router_for_mint(
+    handler: &CliHandler,
+    ncn_fee_group: NcnFeeGroup,
+    operator: &Pubkey,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<NcnRewardRouter> {
+    let (address, _, _) = NcnRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        operator,
+        handler.ncn()?,
+        mint,
+        epoch,
+    );
+
+    let account = get_account(handler, &address).await?;
+
+    if account.is_none() {
+        return Err(anyhow::anyhow!("Account not found"));
+    }
+    let account = account.unwrap();
+
+    let account = NcnRewardRouter::try_from_slice_unchecked(account.data.as_slice())?;
+    Ok(*account)
+}
+
 pub async fn get_ncn_reward_receiver(
     handler: &CliHandler,
     ncn_fee_group: NcnFeeGroup,
@@ -326,6 +616,24 @@ pub async fn get_receiver_rewards(handler: &CliHandler, address: &Pubkey) -> Res
     Ok(account.lamports - rent)
 }
 
+/// SPL token rewards held by a receiver, the balance of its ATA for `mint`
+pub async fn get_receiver_token_rewards(
+    handler: &CliHandler,
+    address: &Pubkey,
+    mint: &Pubkey,
+) -> Result<u64> {
+    let token_account = get_associated_token_address(address, mint);
+    let account = get_account(handler, &token_account).await?;
+
+    if account.is_none() {
+        return Err(anyhow::anyhow!("Account not found"));
+    }
+    let account = account.unwrap();
+
+    let token_account = spl_token_interface::state::Account::unpack(&account.data)?;
+    Ok(token_account.amount)
+}
+
 pub async fn get_base_reward_receiver_rewards(handler: &CliHandler, epoch: u64) -> Result<u64> {
     let (address, _) = get_base_reward_receiver(handler, epoch).await?;
     get_receiver_rewards(handler, &address).await
@@ -348,8 +656,8 @@ pub async fn get_total_rewards_to_be_distributed(handler: &CliHandler, epoch: u6
         let winning_ballot = ballot_box.get_winning_ballot_tally()?;
         let winning_ballot_index = winning_ballot.index();
 
-        ballot_box
-            .operator_votes()
+        get_all_operator_votes(handler, &ballot_box)
+            .await?
             .iter()
             .filter_map(|vote| {
                 if vote.ballot_index() == winning_ballot_index {
@@ -545,6 +853,106 @@ pub async fn get_ncn_operator_state(
     Ok(*account)
 }
 
+/// The epoch state page tracking the operator, if it is past `MAX_OPERATORS`
+pub async fn get_epoch_state_page_for_operator(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<Option<Pubkey>> {
+    let ncn = *handler.ncn()?;
+    let ncn_operator_index = get_ncn_operator_state(handler, operator).await?.index();
+
+    Ok(
+        EpochStatePage::page_for_operator(ncn_operator_index).map(|page| {
+            EpochStatePage::find_program_address(&handler.tip_router_program_id, &ncn, epoch, page)
+                .0
+        }),
+    )
+}
+
+pub async fn get_base_reward_router_page_for_operator(
+    handler: &CliHandler,
+    mint: &Pubkey,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<Option<Pubkey>> {
+    let ncn = *handler.ncn()?;
+    let ncn_operator_index = get_ncn_operator_state(handler, operator).await?.index();
+
+    Ok(
+        BaseRewardRouterPage::page_for_operator(ncn_operator_index).map(|page| {
+            BaseRewardRouterPage::find_program_address_for_mint(
+                &handler.tip_router_program_id,
+                &ncn,
+                mint,
+                epoch,
+                page,
+            )
+            .0
+        }),
+    )
+}
+
+/// The operator's reward route in the router for `mint`, read from the router page for
+/// operators past `MAX_OPERATORS`
+pub async fn get_base_reward_route(
+    handler: &CliHandler,
+    mint: &Pubkey,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<Option<NcnRewardRoute>> {
+    match get_base_reward_router_page_for_operator(handler, mint, operator, epoch).await? {
+        Some(address) => {
+            let Some(account) = get_account(handler, &address).await? else {
+                return Ok(None);
+            };
+            let account = BaseRewardRouterPage::try_from_slice_unchecked(account.data.as_slice())?;
+            Ok(account.ncn_fee_group_reward_route(operator).ok().copied())
+        }
+        None => {
+            let base_reward_router = get_base_reward_router_for_mint(handler, mint, epoch).await?;
+            Ok(base_reward_router
+                .ncn_fee_group_reward_route(operator)
+                .ok()
+                .copied())
+        }
+    }
+}
+
+/// The ballot box pages and then the base reward router pages of the epoch, in order, as routing
+/// expects them
+pub async fn get_routing_page_accounts(
+    handler: &CliHandler,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<Vec<AccountMeta>> {
+    let ncn = *handler.ncn()?;
+    let page_count = get_ballot_box(handler, epoch).await?.page_count();
+
+    let ballot_box_pages = (0..page_count).map(|page| {
+        AccountMeta::new_readonly(
+            BallotBoxPage::find_program_address(&handler.tip_router_program_id, &ncn, epoch, page)
+                .0,
+            false,
+        )
+    });
+    let base_reward_router_pages = (0..page_count).map(|page| {
+        AccountMeta::new(
+            BaseRewardRouterPage::find_program_address_for_mint(
+                &handler.tip_router_program_id,
+                &ncn,
+                mint,
+                epoch,
+                page,
+            )
+            .0,
+            false,
+        )
+    });
+
+    Ok(ballot_box_pages.chain(base_reward_router_pages).collect())
+}
+
 pub async fn get_vault_ncn_ticket(handler: &CliHandler, vault: &Pubkey) -> Result<VaultNcnTicket> {
     let (address, _, _) =
         VaultNcnTicket::find_program_address(&handler.vault_program_id, vault, handler.ncn()?);
@@ -575,6 +983,13 @@ pub async fn get_ncn_vault_ticket(handler: &CliHandler, vault: &Pubkey) -> Resul
     Ok(*account)
 }
 
+/// The page keeping the vault in the vault registry, weight table and operator snapshots,
+/// `None` for vaults within `MAX_VAULTS`
+pub async fn get_vault_page(handler: &CliHandler, vault: &Pubkey) -> Result<Option<u64>> {
+    let ncn_vault_ticket = get_ncn_vault_ticket(handler, vault).await?;
+    Ok(VaultRegistryPage::page_for_vault(ncn_vault_ticket.index()))
+}
+
 pub async fn get_vault_operator_delegation(
     handler: &CliHandler,
     vault: &Pubkey,
@@ -950,14 +1365,28 @@ pub async fn get_total_epoch_rent_cost(handler: &CliHandler) -> Result<u64> {
         fee_group_count as u64
     };
 
+    // Vaults past MAX_VAULTS are kept in weight table and operator snapshot pages
+    let page_count = {
+        let ncn = get_ncn(handler).await?;
+        VaultRegistryPage::pages_needed(ncn.vault_count())
+    };
+
     let mut rent_cost = 0;
 
     rent_cost += client
         .get_minimum_balance_for_rent_exemption(EpochState::SIZE)
         .await?;
+    rent_cost += client
+        .get_minimum_balance_for_rent_exemption(EpochStatePage::SIZE)
+        .await?
+        * EpochStatePage::pages_needed(operator_count);
     rent_cost += client
         .get_minimum_balance_for_rent_exemption(WeightTable::SIZE)
         .await?;
+    rent_cost += client
+        .get_minimum_balance_for_rent_exemption(WeightTablePage::SIZE)
+        .await?
+        * page_count;
     rent_cost += client
         .get_minimum_balance_for_rent_exemption(EpochSnapshot::SIZE)
         .await?;
@@ -965,12 +1394,25 @@ pub async fn get_total_epoch_rent_cost(handler: &CliHandler) -> Result<u64> {
         .get_minimum_balance_for_rent_exemption(OperatorSnapshot::SIZE)
         .await?
         * operator_count;
+    rent_cost += client
+        .get_minimum_balance_for_rent_exemption(OperatorSnapshotPage::SIZE)
+        .await?
+        * operator_count
+        * page_count;
     rent_cost += client
         .get_minimum_balance_for_rent_exemption(BallotBox::SIZE)
         .await?;
+    rent_cost += client
+        .get_minimum_balance_for_rent_exemption(BallotBoxPage::SIZE)
+        .await?
+        * BallotBoxPage::pages_needed(operator_count);
     rent_cost += client
         .get_minimum_balance_for_rent_exemption(BaseRewardRouter::SIZE)
         .await?;
+    rent_cost += client
+        .get_minimum_balance_for_rent_exemption(BaseRewardRouterPage::SIZE)
+        .await?
+        * BallotBoxPage::pages_needed(operator_count);
     // Base Reward Receiver
     rent_cost += client.get_minimum_balance_for_rent_exemption(0).await?;
     rent_cost += client
@@ -8,29 +8,41 @@ use crate::{
         get_all_vaults, get_all_vaults_in_ncn, get_ballot_box, get_base_reward_receiver,
         get_base_reward_router, get_current_slot, get_epoch_snapshot, get_epoch_state,
         get_is_epoch_completed, get_ncn, get_ncn_operator_state, get_ncn_reward_receiver,
-        get_ncn_reward_router, get_ncn_vault_ticket, get_operator_snapshot, get_stake_pool,
-        get_tip_router_config, get_total_epoch_rent_cost, get_total_rewards_to_be_distributed,
-        get_vault_ncn_ticket, get_vault_operator_delegation, get_vault_registry, get_weight_table,
-        OptedInValidatorInfo,
+        get_ncn_reward_router, get_ncn_vault_ticket, get_operator_snapshot,
+        get_operator_snapshot_pages, get_payout_preference, get_stake_pool, get_tip_router_config,
+        get_total_epoch_rent_cost, get_total_rewards_to_be_distributed, get_vault_ncn_ticket,
+        get_vault_operator_delegation, get_vault_registry, get_vault_registry_pages,
+        get_weight_table, get_weight_table_pages, OptedInValidatorInfo,
     },
     instructions::{
-        admin_create_config, admin_fund_account_payer, admin_register_st_mint,
-        admin_set_config_fees, admin_set_new_admin, admin_set_parameters, admin_set_tie_breaker,
-        admin_set_weight, crank_close_epoch_accounts, crank_distribute, crank_register_vaults,
-        crank_set_weight, crank_snapshot, crank_switchboard, create_and_add_test_operator,
-        create_and_add_test_vault, create_ballot_box, create_base_reward_router,
-        create_epoch_snapshot, create_epoch_state, create_ncn_reward_router,
-        create_operator_snapshot, create_test_ncn, create_vault_registry, create_weight_table,
-        distribute_base_ncn_rewards, full_vault_update, migrate_tda_merkle_root_upload_authorities,
-        register_vault, route_base_rewards, route_ncn_rewards, set_weight,
-        snapshot_vault_operator_delegation, update_all_vaults_in_network,
+        admin_accept_admin, admin_cancel_admin_proposal, admin_cancel_scheduled_fees,
+        admin_create_config, admin_deactivate_st_mint, admin_deactivate_vault,
+        admin_fund_account_payer, admin_propose_new_admin, admin_register_st_mint,
+        admin_set_admin_multisig, admin_set_base_fee_group, admin_set_config_fees,
+        admin_set_new_admin, admin_set_parameters, admin_set_paused_subsystems,
+        admin_set_reward_mint, admin_set_tie_breaker, admin_set_weight, apply_stake_weight_caps,
+        approve_admin_action, client_admin_role, close_admin_action, crank_close_epoch_accounts,
+        crank_close_token_reward_routers, crank_distribute, crank_distribute_token_rewards,
+        crank_register_vaults, crank_set_weight, crank_snapshot, crank_switchboard,
+        create_and_add_test_operator, create_and_add_test_vault, create_ballot_box,
+        create_base_reward_router, create_epoch_snapshot, create_epoch_state,
+        create_ncn_reward_router, create_operator_snapshot, create_test_ncn, create_vault_registry,
+        create_weight_table, distribute_base_ncn_rewards, execute_admin_action, full_vault_update,
+        migrate_account, migrate_tda_merkle_root_upload_authorities, operator_close_vote_key,
+        operator_set_vote_key, operator_withdraw_vote, propose_admin_action, register_vault,
+        resolve_stalled_vote, route_base_rewards, route_ncn_rewards, set_payout_preference,
+        set_weight, snapshot_vault_operator_delegation, update_all_vaults_in_network,
     },
     keeper::keeper_loop::startup_keeper,
 };
 use anyhow::{anyhow, Result};
 use base64::{engine::general_purpose, Engine};
+use jito_tip_router_client::types::AdminAction;
 use jito_tip_router_core::{
-    account_payer::AccountPayer, base_reward_router::BaseRewardReceiver, ncn_fee_group::NcnFeeGroup,
+    account_payer::AccountPayer, base_fee_group::BaseFeeGroupSettings,
+    base_reward_router::BaseRewardReceiver, config::ConfigAdminRole as CoreConfigAdminRole,
+    ncn_fee_group::NcnFeeGroup, oracle::OracleType, pause::PausableSubsystem,
+    payout_preference::PayoutAsset,
 };
 use log::info;
 use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
@@ -279,6 +291,14 @@ impl CliHandler {
             ProgramCommand::CrankCloseEpochAccounts {} => {
                 crank_close_epoch_accounts(self, self.epoch).await
             }
+            ProgramCommand::CrankDistributeTokenRewards { mint } => {
+                let mint = Pubkey::from_str(&mint).expect("error parsing mint");
+                crank_distribute_token_rewards(self, &mint, self.epoch).await
+            }
+            ProgramCommand::CrankCloseTokenRewardRouters { mint } => {
+                let mint = Pubkey::from_str(&mint).expect("error parsing mint");
+                crank_close_token_reward_routers(self, &mint, self.epoch).await
+            }
             ProgramCommand::MigrateTdaMerkleRootUploadAuthorities => {
                 migrate_tda_merkle_root_upload_authorities(self, self.epoch).await
             }
@@ -317,12 +337,18 @@ impl CliHandler {
                 reward_multiplier_bps,
                 switchboard_feed,
                 no_feed_weight,
+                oracle_type,
+                max_staleness,
+                max_confidence_bps,
+                max_weight_change_bps,
             } => {
                 let vault = Pubkey::from_str(&vault).expect("error parsing vault");
                 let switchboard = switchboard_feed
                     .map(|s| Pubkey::from_str(&s).expect("error parsing switchboard feed"));
                 let ncn_fee_group =
                     NcnFeeGroup::try_from(ncn_fee_group).expect("error parsing fee group");
+                let oracle_type = oracle_type
+                    .map(|o| OracleType::try_from(o).expect("error parsing oracle type"));
                 admin_register_st_mint(
                     self,
                     &vault,
@@ -330,9 +356,27 @@ impl CliHandler {
                     reward_multiplier_bps,
                     switchboard,
                     no_feed_weight,
+                    oracle_type,
+                    max_staleness,
+                    max_confidence_bps,
+                    max_weight_change_bps,
                 )
                 .await
             }
+            ProgramCommand::AdminDeactivateVault {
+                vault,
+                deactivation_epoch,
+            } => {
+                let vault = Pubkey::from_str(&vault).expect("error parsing vault");
+                admin_deactivate_vault(self, &vault, deactivation_epoch).await
+            }
+            ProgramCommand::AdminDeactivateStMint {
+                st_mint,
+                deactivation_epoch,
+            } => {
+                let st_mint = Pubkey::from_str(&st_mint).expect("error parsing st mint");
+                admin_deactivate_st_mint(self, &st_mint, deactivation_epoch).await
+            }
             ProgramCommand::AdminSetWeight { vault, weight } => {
                 let vault = Pubkey::from_str(&vault).expect("error parsing vault");
                 admin_set_weight(self, &vault, self.epoch, weight).await
@@ -355,6 +399,19 @@ impl CliHandler {
                 epochs_after_consensus_before_close,
                 valid_slots_after_consensus,
                 starting_valid_epoch,
+                commit_window_slots,
+                reveal_window_slots,
+                consensus_threshold_bps,
+                quorum_operator_count,
+                quorum_operator_bps,
+                stall_fallback_threshold_bps,
+                admin_handover_delay_epochs,
+                weight_sampling_window_slots,
+                max_operator_stake_weight_bps,
+                max_vault_stake_weight_bps,
+                fee_increase_notice_epochs,
+                crank_bounty_lamports,
+                max_crank_bounty_lamports_per_epoch,
             } => {
                 admin_set_parameters(
                     self,
@@ -362,14 +419,40 @@ impl CliHandler {
                     epochs_after_consensus_before_close,
                     valid_slots_after_consensus,
                     starting_valid_epoch,
+                    commit_window_slots,
+                    reveal_window_slots,
+                    consensus_threshold_bps,
+                    quorum_operator_count,
+                    quorum_operator_bps,
+                    stall_fallback_threshold_bps,
+                    admin_handover_delay_epochs,
+                    weight_sampling_window_slots,
+                    max_operator_stake_weight_bps,
+                    max_vault_stake_weight_bps,
+                    fee_increase_notice_epochs,
+                    crank_bounty_lamports,
+                    max_crank_bounty_lamports_per_epoch,
                 )
                 .await?;
                 let config = get_tip_router_config(self).await?;
-                info!("\n\n--- Parameters Set ---\nepochs_before_stall: {}\nepochs_after_consensus_before_close: {}\nvalid_slots_after_consensus: {}\nstarting_valid_epoch: {}\n",
+                info!("\n\n--- Parameters Set ---\nepochs_before_stall: {}\nepochs_after_consensus_before_close: {}\nvalid_slots_after_consensus: {}\nstarting_valid_epoch: {}\ncommit_window_slots: {}\nreveal_window_slots: {}\nconsensus_threshold_bps: {}\nquorum_operator_count: {}\nquorum_operator_bps: {}\nstall_fallback_threshold_bps: {}\nadmin_handover_delay_epochs: {}\nweight_sampling_window_slots: {}\nmax_operator_stake_weight_bps: {}\nmax_vault_stake_weight_bps: {}\nfee_increase_notice_epochs: {}\ncrank_bounty_lamports: {}\nmax_crank_bounty_lamports_per_epoch: {}\n",
                     config.epochs_before_stall(),
                     config.epochs_after_consensus_before_close(),
                     config.valid_slots_after_consensus(),
-                    config.starting_valid_epoch()
+                    config.starting_valid_epoch(),
+                    config.commit_window_slots(),
+                    config.reveal_window_slots(),
+                    config.consensus_threshold_bps(),
+                    config.quorum_operator_count(),
+                    config.quorum_operator_bps(),
+                    config.stall_fallback_threshold_bps(),
+                    config.admin_handover_delay_epochs(self.epoch),
+                    config.weight_sampling_window_slots(),
+                    config.max_operator_stake_weight_bps(),
+                    config.max_vault_stake_weight_bps(),
+                    config.fee_config.fee_increase_notice_epochs(),
+                    config.crank_bounty_lamports(),
+                    config.max_crank_bounty_lamports_per_epoch()
                 );
 
                 Ok(())
@@ -382,6 +465,7 @@ impl CliHandler {
                 ncn_fee_group,
                 new_ncn_fee_bps,
                 new_priority_fee_distribution_fee_bps,
+                activation_epoch,
             } => {
                 admin_set_config_fees(
                     self,
@@ -392,20 +476,195 @@ impl CliHandler {
                     ncn_fee_group,
                     new_ncn_fee_bps,
                     new_priority_fee_distribution_fee_bps,
+                    activation_epoch,
                 )
                 .await
             }
+            ProgramCommand::AdminCancelScheduledFees { activation_epoch } => {
+                admin_cancel_scheduled_fees(self, activation_epoch).await
+            }
+            ProgramCommand::AdminSetBaseFeeGroup {
+                base_fee_group,
+                name,
+                enabled,
+                payout_asset,
+            } => {
+                let payout_asset = payout_asset
+                    .map(|payout_asset| {
+                        PayoutAsset::try_from(payout_asset)
+                            .map_err(|e| anyhow!("invalid payout asset: {e}"))
+                    })
+                    .transpose()?;
+                admin_set_base_fee_group(self, base_fee_group, name, enabled, payout_asset).await
+            }
+            ProgramCommand::AdminSetPausedSubsystems { paused } => {
+                let paused_subsystems = paused
+                    .into_iter()
+                    .map(PausableSubsystem::from)
+                    .fold(0, |flags, subsystem| flags | subsystem.flag());
+                admin_set_paused_subsystems(self, paused_subsystems).await
+            }
+            ProgramCommand::AdminSetRewardMint { mint, disallow } => {
+                let mint = Pubkey::from_str(&mint).expect("error parsing mint");
+                admin_set_reward_mint(self, &mint, !disallow).await
+            }
             ProgramCommand::AdminSetNewAdmin {
                 new_admin,
                 set_fee_admin,
                 set_tie_breaker_admin,
+                set_pause_admin,
             } => {
                 let new_admin = Pubkey::from_str(&new_admin).expect("error parsing new admin");
-                admin_set_new_admin(self, &new_admin, set_fee_admin, set_tie_breaker_admin).await
+                admin_set_new_admin(
+                    self,
+                    &new_admin,
+                    set_fee_admin,
+                    set_tie_breaker_admin,
+                    set_pause_admin,
+                )
+                .await
+            }
+            ProgramCommand::AdminProposeNewAdmin { role, new_admin } => {
+                let new_admin = Pubkey::from_str(&new_admin).expect("error parsing new admin");
+                admin_propose_new_admin(self, role.into(), &new_admin).await
+            }
+            ProgramCommand::AdminAcceptAdmin { role } => {
+                admin_accept_admin(self, role.into()).await
+            }
+            ProgramCommand::AdminCancelAdminProposal { role } => {
+                admin_cancel_admin_proposal(self, role.into()).await
             }
             ProgramCommand::AdminFundAccountPayer { amount_in_sol } => {
                 admin_fund_account_payer(self, amount_in_sol).await
             }
+            ProgramCommand::AdminSetAdminMultisig {
+                role,
+                threshold,
+                signers,
+            } => {
+                let signers = signers
+                    .iter()
+                    .map(|s| {
+                        Pubkey::from_str(s).map_err(|e| anyhow!("invalid signer pubkey {s}: {e}"))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                admin_set_admin_multisig(self, role.into(), threshold, signers).await
+            }
+            ProgramCommand::AdminMultisigProposeTieBreaker { meta_merkle_root } => {
+                let meta_merkle_root = parse_meta_merkle_root(&meta_merkle_root)?;
+                propose_admin_action(
+                    self,
+                    CoreConfigAdminRole::TieBreakerAdmin,
+                    AdminAction::SetTieBreaker {
+                        meta_merkle_root,
+                        epoch: self.epoch,
+                    },
+                )
+                .await
+            }
+            ProgramCommand::AdminMultisigProposeConfigFees {
+                new_block_engine_fee_bps,
+                base_fee_group,
+                new_base_fee_wallet,
+                new_base_fee_bps,
+                ncn_fee_group,
+                new_ncn_fee_bps,
+                new_priority_fee_distribution_fee_bps,
+                activation_epoch,
+            } => {
+                let new_base_fee_wallet = new_base_fee_wallet
+                    .map(|s| {
+                        Pubkey::from_str(&s).map_err(|e| anyhow!("invalid base fee wallet: {e}"))
+                    })
+                    .transpose()?;
+                propose_admin_action(
+                    self,
+                    CoreConfigAdminRole::FeeAdmin,
+                    AdminAction::SetConfigFees {
+                        new_block_engine_fee_bps,
+                        base_fee_group,
+                        new_base_fee_wallet,
+                        new_base_fee_bps,
+                        ncn_fee_group,
+                        new_ncn_fee_bps,
+                        new_priority_fee_distribution_fee_bps,
+                        activation_epoch,
+                    },
+                )
+                .await
+            }
+            ProgramCommand::AdminMultisigProposeBaseFeeGroup {
+                base_fee_group,
+                name,
+                enabled,
+                payout_asset,
+            } => {
+                propose_admin_action(
+                    self,
+                    CoreConfigAdminRole::FeeAdmin,
+                    AdminAction::SetBaseFeeGroup {
+                        base_fee_group,
+                        name: name.map(|name| BaseFeeGroupSettings::name_bytes(&name)),
+                        enabled,
+                        payout_asset,
+                    },
+                )
+                .await
+            }
+            ProgramCommand::AdminMultisigProposeNewAdmin { role, new_admin } => {
+                let new_admin = Pubkey::from_str(&new_admin).expect("error parsing new admin");
+                let role: CoreConfigAdminRole = role.into();
+                propose_admin_action(
+                    self,
+                    role,
+                    AdminAction::SetNewAdmin {
+                        role: client_admin_role(role),
+                        new_admin,
+                    },
+                )
+                .await
+            }
+            ProgramCommand::AdminMultisigProposeSigner {
+                role,
+                signer,
+                remove,
+                threshold,
+            } => {
+                let signer = Pubkey::from_str(&signer).expect("error parsing signer");
+                let role: CoreConfigAdminRole = role.into();
+                propose_admin_action(
+                    self,
+                    role,
+                    AdminAction::SetMultisigSigner {
+                        role: client_admin_role(role),
+                        signer,
+                        is_signer: !remove,
+                        threshold,
+                    },
+                )
+                .await
+            }
+            ProgramCommand::AdminMultisigProposePausedSubsystems { paused } => {
+                let paused_subsystems = paused
+                    .into_iter()
+                    .map(PausableSubsystem::from)
+                    .fold(0, |flags, subsystem| flags | subsystem.flag());
+                propose_admin_action(
+                    self,
+                    CoreConfigAdminRole::PauseAdmin,
+                    AdminAction::SetPausedSubsystems { paused_subsystems },
+                )
+                .await
+            }
+            ProgramCommand::AdminApproveAction { role, nonce } => {
+                approve_admin_action(self, role.into(), nonce).await
+            }
+            ProgramCommand::AdminExecuteAction { role, nonce } => {
+                execute_admin_action(self, role.into(), nonce).await
+            }
+            ProgramCommand::AdminCloseAction { role, nonce } => {
+                close_admin_action(self, role.into(), nonce).await
+            }
 
             // Instructions
             ProgramCommand::CreateVaultRegistry => create_vault_registry(self).await,
@@ -440,6 +699,10 @@ impl CliHandler {
                 let operator = Pubkey::from_str(&operator).expect("error parsing operator");
                 snapshot_vault_operator_delegation(self, &vault, &operator, self.epoch).await
             }
+            ProgramCommand::ApplyStakeWeightCaps { operator } => {
+                let operator = Pubkey::from_str(&operator).expect("error parsing operator");
+                apply_stake_weight_caps(self, &operator, self.epoch).await
+            }
 
             ProgramCommand::CreateBallotBox => create_ballot_box(self, self.epoch).await,
             ProgramCommand::OperatorCastVote {
@@ -450,6 +713,33 @@ impl CliHandler {
                 operator,
                 meta_merkle_root
             )),
+            ProgramCommand::OperatorWithdrawVote { operator } => {
+                let operator = Pubkey::from_str(&operator).expect("error parsing operator");
+                operator_withdraw_vote(self, &operator, self.epoch).await
+            }
+            ProgramCommand::OperatorSetVoteKey { operator, vote_key } => {
+                let operator = Pubkey::from_str(&operator).expect("error parsing operator");
+                let vote_key = Pubkey::from_str(&vote_key).expect("error parsing vote key");
+                operator_set_vote_key(self, &operator, &vote_key).await
+            }
+            ProgramCommand::OperatorCloseVoteKey { operator } => {
+                let operator = Pubkey::from_str(&operator).expect("error parsing operator");
+                operator_close_vote_key(self, &operator).await
+            }
+            ProgramCommand::SetPayoutPreference {
+                recipient,
+                payout_asset,
+                sol_destination,
+            } => {
+                let recipient = Pubkey::from_str(&recipient).expect("error parsing recipient");
+                let payout_asset =
+                    PayoutAsset::try_from(payout_asset).expect("error parsing payout asset");
+                let sol_destination = sol_destination
+                    .map(|s| Pubkey::from_str(&s).expect("error parsing SOL destination"))
+                    .unwrap_or_default();
+                set_payout_preference(self, &recipient, payout_asset, &sol_destination).await
+            }
+            ProgramCommand::ResolveStalledVote => resolve_stalled_vote(self, self.epoch).await,
 
             ProgramCommand::CreateBaseRewardRouter => {
                 create_base_reward_router(self, self.epoch).await
@@ -486,6 +776,10 @@ impl CliHandler {
                     NcnFeeGroup::try_from(ncn_fee_group).expect("error parsing fee group");
                 distribute_base_ncn_rewards(self, &operator, ncn_fee_group, self.epoch).await
             }
+            ProgramCommand::MigrateAccount { account } => {
+                let account = Pubkey::from_str(&account).expect("error parsing account");
+                migrate_account(self, &account).await
+            }
 
             // Getters
             ProgramCommand::GetNcn => {
@@ -549,11 +843,17 @@ impl CliHandler {
             ProgramCommand::GetVaultRegistry => {
                 let vault_registry = get_vault_registry(self).await?;
                 info!("{}", vault_registry);
+                for vault_registry_page in get_vault_registry_pages(self, &vault_registry).await? {
+                    info!("{}", vault_registry_page);
+                }
                 Ok(())
             }
             ProgramCommand::GetWeightTable => {
                 let weight_table = get_weight_table(self, self.epoch).await?;
                 info!("{}", weight_table);
+                for weight_table_page in get_weight_table_pages(self, &weight_table).await? {
+                    info!("{}", weight_table_page);
+                }
                 Ok(())
             }
             ProgramCommand::GetEpochState => {
@@ -608,6 +908,11 @@ impl CliHandler {
                 let operator = Pubkey::from_str(&operator).expect("error parsing operator");
                 let operator_snapshot = get_operator_snapshot(self, &operator, self.epoch).await?;
                 info!("{}", operator_snapshot);
+                for operator_snapshot_page in
+                    get_operator_snapshot_pages(self, &operator_snapshot).await?
+                {
+                    info!("{}", operator_snapshot_page);
+                }
                 Ok(())
             }
             ProgramCommand::GetBallotBox => {
@@ -615,6 +920,14 @@ impl CliHandler {
                 info!("{}", ballot_box);
                 Ok(())
             }
+            ProgramCommand::GetPayoutPreference { recipient } => {
+                let recipient = Pubkey::from_str(&recipient).expect("error parsing recipient");
+                match get_payout_preference(self, &recipient).await? {
+                    Some(payout_preference) => info!("{}", payout_preference),
+                    None => info!("No payout preference set, rewards are paid out in JitoSOL"),
+                }
+                Ok(())
+            }
             ProgramCommand::GetBaseRewardReceiverAddress => {
                 let (base_reward_receiver_address, _, _) = BaseRewardReceiver::find_program_address(
                     &self.tip_router_program_id,
@@ -762,8 +1075,16 @@ impl CliHandler {
                 for operator in operators.iter() {
                     let operator_snapshot = get_operator_snapshot(self, operator, self.epoch).await;
                     if let Ok(operator_snapshot) = operator_snapshot {
-                        for vault_operator_stake_weight in
-                            operator_snapshot.vault_operator_stake_weight()
+                        let operator_snapshot_pages =
+                            get_operator_snapshot_pages(self, &operator_snapshot).await?;
+                        for vault_operator_stake_weight in operator_snapshot
+                            .vault_operator_stake_weight()
+                            .iter()
+                            .chain(
+                                operator_snapshot_pages
+                                    .iter()
+                                    .flat_map(|page| page.vault_operator_stake_weight().iter()),
+                            )
                         {
                             let vault = vault_operator_stake_weight.vault();
 
@@ -807,8 +1128,16 @@ impl CliHandler {
                 for operator in operators.iter() {
                     let operator_snapshot = get_operator_snapshot(self, operator, self.epoch).await;
                     if let Ok(operator_snapshot) = operator_snapshot {
-                        for vault_operator_stake_weight in
-                            operator_snapshot.vault_operator_stake_weight()
+                        let operator_snapshot_pages =
+                            get_operator_snapshot_pages(self, &operator_snapshot).await?;
+                        for vault_operator_stake_weight in operator_snapshot
+                            .vault_operator_stake_weight()
+                            .iter()
+                            .chain(
+                                operator_snapshot_pages
+                                    .iter()
+                                    .flat_map(|page| page.vault_operator_stake_weight().iter()),
+                            )
                         {
                             let vault = vault_operator_stake_weight.vault();
                             if *vault == Pubkey::default() {
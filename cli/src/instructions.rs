@@ -2,19 +2,24 @@ use std::{str::FromStr, time::Duration};
 
 use crate::{
     getters::{
-        get_account, get_all_operators_in_ncn, get_all_sorted_operators_for_vault, get_all_vaults,
-        get_all_vaults_in_ncn, get_ballot_box, get_base_reward_receiver_rewards,
-        get_base_reward_router, get_current_slot, get_epoch_snapshot,
-        get_ncn_reward_receiver_rewards, get_ncn_reward_router, get_operator,
-        get_operator_snapshot, get_stake_pool_accounts, get_tip_distribution_accounts_to_migrate,
-        get_tip_router_config, get_vault, get_vault_config, get_vault_registry,
+        get_account, get_admin_multisig, get_admin_proposal, get_all_operators_in_ncn,
+        get_all_sorted_operators_for_vault, get_all_vaults, get_all_vaults_in_ncn, get_ballot_box,
+        get_base_reward_receiver_rewards, get_base_reward_route, get_base_reward_router,
+        get_base_reward_router_for_mint, get_base_reward_router_page_for_operator,
+        get_current_epoch, get_current_slot, get_epoch_snapshot, get_epoch_state,
+        get_epoch_state_page_for_operator, get_ncn, get_ncn_reward_receiver_rewards,
+        get_ncn_reward_router, get_ncn_reward_router_for_mint, get_ncn_vault_ticket, get_operator,
+        get_operator_snapshot, get_operator_snapshot_pages, get_payout_preference,
+        get_receiver_token_rewards, get_restaking_config, get_routing_page_accounts,
+        get_stake_pool_accounts, get_tip_distribution_accounts_to_migrate, get_tip_router_config,
+        get_vault, get_vault_config, get_vault_page, get_vault_registry, get_vault_registry_pages,
         get_vault_update_state_tracker, get_weight_table,
     },
     handler::CliHandler,
     log::print_base58_tx,
 };
 use anyhow::{anyhow, Ok, Result};
-use jito_bytemuck::AccountDeserialize;
+use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_restaking_client::instructions::{
     InitializeNcnBuilder, InitializeNcnOperatorStateBuilder, InitializeNcnVaultTicketBuilder,
     InitializeOperatorBuilder, InitializeOperatorVaultTicketBuilder, NcnWarmupOperatorBuilder,
@@ -29,40 +34,61 @@ use jito_tip_distribution_sdk::{
     derive_merkle_root_upload_authority_address,
     instruction::migrate_tda_merkle_root_upload_authority_ix,
 };
-use jito_tip_router_client::instructions::SwitchboardSetWeightBuilder;
 use jito_tip_router_client::{
     instructions::{
-        AdminRegisterStMintBuilder, AdminSetConfigFeesBuilder, AdminSetNewAdminBuilder,
-        AdminSetParametersBuilder, AdminSetTieBreakerBuilder, AdminSetWeightBuilder,
-        CastVoteBuilder, CloseEpochAccountBuilder, DistributeBaseNcnRewardRouteBuilder,
+        AdminAcceptAdminBuilder, AdminCancelAdminProposalBuilder, AdminCancelScheduledFeesBuilder,
+        AdminDeactivateStMintBuilder, AdminDeactivateVaultBuilder, AdminProposeNewAdminBuilder,
+        AdminRegisterStMintBuilder, AdminSetAdminMultisigBuilder, AdminSetBaseFeeGroupBuilder,
+        AdminSetConfigFeesBuilder, AdminSetNewAdminBuilder, AdminSetParametersBuilder,
+        AdminSetPausedSubsystemsBuilder, AdminSetRewardMintBuilder, AdminSetTieBreakerBuilder,
+        AdminSetWeightBuilder, ApplyStakeWeightCapsBuilder, ApproveAdminActionBuilder,
+        CarryForwardRewardsBuilder, CastVoteBuilder, CloseAdminProposalBuilder,
+        CloseEpochAccountBuilder, CloseOperatorVoteKeyBuilder, DistributeBaseNcnRewardRouteBuilder,
         DistributeBaseRewardsBuilder, DistributeNcnOperatorRewardsBuilder,
-        DistributeNcnVaultRewardsBuilder, InitializeBallotBoxBuilder,
-        InitializeBaseRewardRouterBuilder,
+        DistributeNcnVaultRewardsBuilder, ExecuteAdminActionBuilder, InitializeBallotBoxBuilder,
+        InitializeBallotBoxPageBuilder, InitializeBaseRewardRouterBuilder,
+        InitializeBaseRewardRouterPageBuilder,
         InitializeConfigBuilder as InitializeTipRouterConfigBuilder,
         InitializeEpochSnapshotBuilder, InitializeEpochStateBuilder,
-        InitializeNcnRewardRouterBuilder, InitializeOperatorSnapshotBuilder,
-        InitializeVaultRegistryBuilder, InitializeWeightTableBuilder, ReallocBallotBoxBuilder,
+        InitializeEpochStatePageBuilder, InitializeNcnRewardRouterBuilder,
+        InitializeOperatorSnapshotBuilder, InitializeOperatorSnapshotPageBuilder,
+        InitializeVaultRegistryBuilder, InitializeVaultRegistryPageBuilder,
+        InitializeWeightTableBuilder, InitializeWeightTablePageBuilder, MigrateAccountBuilder,
+        OracleSetWeightBuilder, ProposeAdminActionBuilder, ReallocBallotBoxBuilder,
         ReallocBaseRewardRouterBuilder, ReallocEpochStateBuilder, ReallocOperatorSnapshotBuilder,
         ReallocVaultRegistryBuilder, ReallocWeightTableBuilder, RegisterVaultBuilder,
-        RouteBaseRewardsBuilder, RouteNcnRewardsBuilder, SnapshotVaultOperatorDelegationBuilder,
+        ResolveStalledVoteBuilder, RouteBaseRewardsBuilder, RouteNcnRewardsBuilder,
+        SetOperatorVoteKeyBuilder, SetPayoutPreferenceBuilder,
+        SnapshotVaultOperatorDelegationBuilder, WithdrawVoteBuilder,
     },
-    types::ConfigAdminRole,
+    types::{AdminAction, ConfigAdminRole},
 };
 use jito_tip_router_core::constants::SWITCHBOARD_QUEUE;
 use jito_tip_router_core::{
     account_payer::AccountPayer,
+    admin_multisig::{AdminAction as CoreAdminAction, AdminMultisig, AdminProposal},
     ballot_box::BallotBox,
-    base_fee_group::BaseFeeGroup,
+    ballot_box_page::BallotBoxPage,
+    base_fee_group::{BaseFeeGroup, BaseFeeGroupSettings},
     base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
-    config::Config as TipRouterConfig,
+    base_reward_router_page::BaseRewardRouterPage,
+    config::{Config as TipRouterConfig, ConfigAdminRole as CoreConfigAdminRole},
     constants::MAX_REALLOC_BYTES,
     epoch_marker::EpochMarker,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    epoch_state_page::EpochStatePage,
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    operator_snapshot_page::OperatorSnapshotPage,
+    operator_vote_key::OperatorVoteKey,
+    oracle::OracleType,
+    pause::PausableSubsystem,
+    payout_preference::{PayoutAsset, PayoutPreference},
     vault_registry::VaultRegistry,
+    vault_registry_page::VaultRegistryPage,
     weight_table::WeightTable,
+    weight_table_page::WeightTablePage,
 };
 use jito_vault_client::{
     instructions::{
@@ -86,7 +112,7 @@ use solana_compute_budget_interface::ComputeBudgetInstruction;
 use solana_sdk::epoch_schedule::DEFAULT_SLOTS_PER_EPOCH;
 #[allow(deprecated)]
 use solana_sdk::{
-    instruction::Instruction,
+    instruction::{AccountMeta, Instruction},
     native_token::sol_str_to_lamports,
     program_pack::Pack,
     pubkey::Pubkey,
@@ -199,6 +225,10 @@ pub async fn admin_register_st_mint(
     reward_multiplier_bps: u64,
     switchboard_feed: Option<Pubkey>,
     no_feed_weight: Option<u128>,
+    oracle_type: Option<OracleType>,
+    max_staleness: Option<u64>,
+    max_confidence_bps: Option<u16>,
+    max_weight_change_bps: Option<u16>,
 ) -> Result<()> {
     let keypair = handler.keypair();
 
@@ -231,6 +261,22 @@ pub async fn admin_register_st_mint(
         register_st_mint_builder.no_feed_weight(no_feed_weight);
     }
 
+    if let Some(oracle_type) = oracle_type {
+        register_st_mint_builder.oracle_type(oracle_type as u8);
+    }
+
+    if let Some(max_staleness) = max_staleness {
+        register_st_mint_builder.max_staleness(max_staleness);
+    }
+
+    if let Some(max_confidence_bps) = max_confidence_bps {
+        register_st_mint_builder.max_confidence_bps(max_confidence_bps);
+    }
+
+    if let Some(max_weight_change_bps) = max_weight_change_bps {
+        register_st_mint_builder.max_weight_change_bps(max_weight_change_bps);
+    }
+
     let mut register_st_mint_ix = register_st_mint_builder.instruction();
     register_st_mint_ix.program_id = handler.tip_router_program_id;
 
@@ -253,6 +299,125 @@ pub async fn admin_register_st_mint(
                     switchboard_feed.unwrap_or_default()
                 ),
                 format!("No Feed Weight: {:?}", no_feed_weight.unwrap_or_default()),
+                format!("Oracle Type: {:?}", oracle_type.unwrap_or_default()),
+                format!("Max Staleness: {:?}", max_staleness.unwrap_or_default()),
+                format!(
+                    "Max Confidence BPS: {:?}",
+                    max_confidence_bps.unwrap_or_default()
+                ),
+                format!(
+                    "Max Weight Change BPS: {:?}",
+                    max_weight_change_bps.unwrap_or_default()
+                ),
+            ],
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn admin_deactivate_vault(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    deactivation_epoch: u64,
+) -> Result<()> {
+    let keypair = handler.keypair();
+
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    // Vaults past MAX_VAULTS are deactivated in their page
+    let vault_registry_page = get_vault_page(handler, vault).await?.map(|page| {
+        VaultRegistryPage::find_program_address(&handler.tip_router_program_id, &ncn, page).0
+    });
+
+    let mut deactivate_vault_ix = AdminDeactivateVaultBuilder::new()
+        .config(config)
+        .ncn(ncn)
+        .vault_registry(vault_registry)
+        .admin(keypair.pubkey())
+        .vault_registry_page(vault_registry_page)
+        .vault(*vault)
+        .epoch(deactivation_epoch)
+        .instruction();
+    deactivate_vault_ix.program_id = handler.tip_router_program_id;
+
+    let ixs = &[deactivate_vault_ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
+        send_and_log_transaction(
+            handler,
+            ixs,
+            &[],
+            "Deactivated Vault",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Vault: {:?}", vault),
+                format!("Deactivation Epoch: {:?}", deactivation_epoch),
+            ],
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn admin_deactivate_st_mint(
+    handler: &CliHandler,
+    st_mint: &Pubkey,
+    deactivation_epoch: u64,
+) -> Result<()> {
+    let keypair = handler.keypair();
+
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    // Every vault registry page is checked for active vaults of the ST mint
+    let vault_registry_account = get_vault_registry(handler).await?;
+    let vault_registry_pages: Vec<AccountMeta> = (0..vault_registry_account.page_count())
+        .map(|page| {
+            let (vault_registry_page, _, _) =
+                VaultRegistryPage::find_program_address(&handler.tip_router_program_id, &ncn, page);
+            AccountMeta::new_readonly(vault_registry_page, false)
+        })
+        .collect();
+
+    let mut deactivate_st_mint_ix = AdminDeactivateStMintBuilder::new()
+        .config(config)
+        .ncn(ncn)
+        .vault_registry(vault_registry)
+        .admin(keypair.pubkey())
+        .st_mint(*st_mint)
+        .epoch(deactivation_epoch)
+        .add_remaining_accounts(&vault_registry_pages)
+        .instruction();
+    deactivate_st_mint_ix.program_id = handler.tip_router_program_id;
+
+    let ixs = &[deactivate_st_mint_ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
+        send_and_log_transaction(
+            handler,
+            ixs,
+            &[],
+            "Deactivated ST Mint",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("ST Mint: {:?}", st_mint),
+                format!("Deactivation Epoch: {:?}", deactivation_epoch),
             ],
         )
         .await?;
@@ -382,11 +547,49 @@ pub async fn admin_set_tie_breaker(
     Ok(())
 }
 
+pub async fn resolve_stalled_vote(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let mut resolve_stalled_vote_ix = ResolveStalledVoteBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ballot_box(ballot_box)
+        .ncn(ncn)
+        .epoch_snapshot(epoch_snapshot)
+        .epoch(epoch)
+        .instruction();
+    resolve_stalled_vote_ix.program_id = handler.tip_router_program_id;
+
+    send_and_log_transaction(
+        handler,
+        &[resolve_stalled_vote_ix],
+        &[],
+        "Resolved Stalled Vote",
+        &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
+    )
+    .await?;
+
+    Ok(())
+}
+
 pub async fn admin_set_new_admin(
     handler: &CliHandler,
     new_admin: &Pubkey,
     set_fee_admin: bool,
     set_tie_breaker_admin: bool,
+    set_pause_admin: bool,
 ) -> Result<()> {
     let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
@@ -396,6 +599,7 @@ pub async fn admin_set_new_admin(
     let roles = [
         (set_fee_admin, ConfigAdminRole::FeeAdmin),
         (set_tie_breaker_admin, ConfigAdminRole::TieBreakerAdmin),
+        (set_pause_admin, ConfigAdminRole::PauseAdmin),
     ];
 
     for (should_set, role) in roles.iter() {
@@ -434,41 +638,62 @@ pub async fn admin_set_new_admin(
     Ok(())
 }
 
-pub async fn admin_set_parameters(
+pub async fn admin_propose_new_admin(
     handler: &CliHandler,
-    epochs_before_stall: Option<u64>,
-    epochs_after_consensus_before_close: Option<u64>,
-    valid_slots_after_consensus: Option<u64>,
-    starting_valid_epoch: Option<u64>,
+    role: CoreConfigAdminRole,
+    new_admin: &Pubkey,
 ) -> Result<()> {
     let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
 
     let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
-    let mut ix = AdminSetParametersBuilder::new();
-    ix.config(config_pda).ncn(ncn).ncn_admin(keypair.pubkey());
+    let mut ix = AdminProposeNewAdminBuilder::new()
+        .config(config_pda)
+        .ncn(ncn)
+        .ncn_admin(keypair.pubkey())
+        .new_admin(*new_admin)
+        .role(client_admin_role(role))
+        .instruction();
+    ix.program_id = handler.tip_router_program_id;
 
-    if let Some(epochs) = epochs_before_stall {
-        ix.epochs_before_stall(epochs);
+    let ixs = &[ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
+        send_and_log_transaction(
+            handler,
+            ixs,
+            &[],
+            "Admin Propose New Admin",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("New Admin: {:?}", new_admin),
+                format!("Role: {:?}", role),
+            ],
+        )
+        .await?;
     }
 
-    if let Some(epochs) = epochs_after_consensus_before_close {
-        ix.epochs_after_consensus_before_close(epochs);
-    }
+    Ok(())
+}
 
-    if let Some(slots) = valid_slots_after_consensus {
-        ix.valid_slots_after_consensus(slots);
-    }
+/// Accepts a pending admin handover, the handler's keypair has to be the pending admin
+pub async fn admin_accept_admin(handler: &CliHandler, role: CoreConfigAdminRole) -> Result<()> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
 
-    if let Some(epoch) = starting_valid_epoch {
-        ix.starting_valid_epoch(epoch);
-    }
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
-    let mut admin_set_parameters_ix = ix.instruction();
-    admin_set_parameters_ix.program_id = handler.tip_router_program_id;
+    let mut ix = AdminAcceptAdminBuilder::new()
+        .config(config_pda)
+        .ncn(ncn)
+        .new_admin(keypair.pubkey())
+        .role(client_admin_role(role))
+        .instruction();
+    ix.program_id = handler.tip_router_program_id;
 
-    let ixs = &[admin_set_parameters_ix];
+    let ixs = &[ix];
     if handler.print_tx {
         print_base58_tx(ixs);
     } else {
@@ -476,18 +701,11 @@ pub async fn admin_set_parameters(
             handler,
             ixs,
             &[],
-            "Set Parameters",
+            "Admin Accept Admin",
             &[
                 format!("NCN: {:?}", ncn),
-                format!("Epochs Before Stall: {:?}", epochs_before_stall),
-                format!(
-                    "Epochs After Consensus Before Close: {:?}",
-                    epochs_after_consensus_before_close
-                ),
-                format!(
-                    "Valid Slots After Consensus: {:?}",
-                    valid_slots_after_consensus
-                ),
+                format!("New Admin: {:?}", keypair.pubkey()),
+                format!("Role: {:?}", role),
             ],
         )
         .await?;
@@ -496,21 +714,24 @@ pub async fn admin_set_parameters(
     Ok(())
 }
 
-pub async fn admin_fund_account_payer(handler: &CliHandler, amount: f64) -> Result<()> {
+pub async fn admin_cancel_admin_proposal(
+    handler: &CliHandler,
+    role: CoreConfigAdminRole,
+) -> Result<()> {
     let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
 
-    let (account_payer, _, _) =
-        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
-    let transfer_ix = transfer(
-        &keypair.pubkey(),
-        &account_payer,
-        sol_str_to_lamports(&amount.to_string())
-            .ok_or_else(|| anyhow!("invalid SOL amount: {amount}"))?,
-    );
+    let mut ix = AdminCancelAdminProposalBuilder::new()
+        .config(config_pda)
+        .ncn(ncn)
+        .signer(keypair.pubkey())
+        .role(client_admin_role(role))
+        .instruction();
+    ix.program_id = handler.tip_router_program_id;
 
-    let ixs = &[transfer_ix];
+    let ixs = &[ix];
     if handler.print_tx {
         print_base58_tx(ixs);
     } else {
@@ -518,11 +739,8 @@ pub async fn admin_fund_account_payer(handler: &CliHandler, amount: f64) -> Resu
             handler,
             ixs,
             &[],
-            "Fund Account Payer",
-            &[
-                format!("NCN: {:?}", ncn),
-                format!("Amount: {:?} SOL", amount),
-            ],
+            "Admin Cancel Admin Proposal",
+            &[format!("NCN: {:?}", ncn), format!("Role: {:?}", role)],
         )
         .await?;
     }
@@ -530,77 +748,158 @@ pub async fn admin_fund_account_payer(handler: &CliHandler, amount: f64) -> Resu
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
-pub async fn admin_set_config_fees(
+pub async fn admin_set_parameters(
     handler: &CliHandler,
-    new_block_engine_fee_bps: Option<u16>,
-    base_fee_group: Option<u8>,
-    new_base_fee_wallet: Option<String>,
-    new_base_fee_bps: Option<u16>,
-    ncn_fee_group: Option<u8>,
-    new_ncn_fee_bps: Option<u16>,
-    new_priority_fee_distribution_fee_bps: Option<u16>,
+    epochs_before_stall: Option<u64>,
+    epochs_after_consensus_before_close: Option<u64>,
+    valid_slots_after_consensus: Option<u64>,
+    starting_valid_epoch: Option<u64>,
+    commit_window_slots: Option<u64>,
+    reveal_window_slots: Option<u64>,
+    consensus_threshold_bps: Option<u16>,
+    quorum_operator_count: Option<u16>,
+    quorum_operator_bps: Option<u16>,
+    stall_fallback_threshold_bps: Option<u16>,
+    admin_handover_delay_epochs: Option<u16>,
+    weight_sampling_window_slots: Option<u64>,
+    max_operator_stake_weight_bps: Option<u16>,
+    max_vault_stake_weight_bps: Option<u16>,
+    fee_increase_notice_epochs: Option<u64>,
+    crank_bounty_lamports: Option<u64>,
+    max_crank_bounty_lamports_per_epoch: Option<u64>,
 ) -> Result<()> {
     let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
 
     let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
-    let mut ix = AdminSetConfigFeesBuilder::new();
+    let mut ix = AdminSetParametersBuilder::new();
     ix.config(config_pda).ncn(ncn).ncn_admin(keypair.pubkey());
 
-    if let Some(fee) = new_block_engine_fee_bps {
-        ix.new_block_engine_fee_bps(fee);
+    if let Some(epochs) = epochs_before_stall {
+        ix.epochs_before_stall(epochs);
     }
 
-    if let Some(group) = base_fee_group {
-        ix.base_fee_group(group);
+    if let Some(epochs) = epochs_after_consensus_before_close {
+        ix.epochs_after_consensus_before_close(epochs);
     }
 
-    if let Some(wallet) = &new_base_fee_wallet {
-        let wallet = Pubkey::from_str(wallet).map_err(|_| anyhow!("Invalid wallet address"))?;
-        ix.new_base_fee_wallet(wallet);
+    if let Some(slots) = valid_slots_after_consensus {
+        ix.valid_slots_after_consensus(slots);
     }
 
-    if let Some(fee) = new_base_fee_bps {
-        ix.new_base_fee_bps(fee);
+    if let Some(epoch) = starting_valid_epoch {
+        ix.starting_valid_epoch(epoch);
     }
 
-    if let Some(group) = ncn_fee_group {
-        ix.ncn_fee_group(group);
+    if let Some(slots) = commit_window_slots {
+        ix.commit_window_slots(slots);
     }
 
-    if let Some(fee) = new_ncn_fee_bps {
-        ix.new_ncn_fee_bps(fee);
+    if let Some(slots) = reveal_window_slots {
+        ix.reveal_window_slots(slots);
     }
 
-    if let Some(fee) = new_priority_fee_distribution_fee_bps {
-        ix.new_priority_fee_distribution_fee_bps(fee);
+    if let Some(bps) = consensus_threshold_bps {
+        ix.consensus_threshold_bps(bps);
     }
 
-    let mut admin_set_config_fees_ix = ix.instruction();
-    admin_set_config_fees_ix.program_id = handler.tip_router_program_id;
+    if let Some(count) = quorum_operator_count {
+        ix.quorum_operator_count(count);
+    }
 
-    let ixs = &[admin_set_config_fees_ix];
-    if handler.print_tx {
-        print_base58_tx(ixs);
-    } else {
-        send_and_log_transaction(
-            handler,
-            ixs,
-            &[],
-            "Set Config Fees",
-            &[
-                format!("NCN: {:?}", ncn),
-                format!("New Block Engine Fee BPS: {:?}", new_block_engine_fee_bps),
-                format!("Base Fee Group: {:?}", base_fee_group),
-                format!("New Base Fee Wallet: {:?}", new_base_fee_wallet),
-                format!("New Base Fee BPS: {:?}", new_base_fee_bps),
-                format!("NCN Fee Group: {:?}", ncn_fee_group),
-                format!("New NCN Fee BPS: {:?}", new_ncn_fee_bps),
+    if let Some(bps) = quorum_operator_bps {
+        ix.quorum_operator_bps(bps);
+    }
+
+    if let Some(bps) = stall_fallback_threshold_bps {
+        ix.stall_fallback_threshold_bps(bps);
+    }
+
+    if let Some(epochs) = admin_handover_delay_epochs {
+        ix.admin_handover_delay_epochs(epochs);
+    }
+
+    if let Some(slots) = weight_sampling_window_slots {
+        ix.weight_sampling_window_slots(slots);
+    }
+
+    if let Some(bps) = max_operator_stake_weight_bps {
+        ix.max_operator_stake_weight_bps(bps);
+    }
+
+    if let Some(bps) = max_vault_stake_weight_bps {
+        ix.max_vault_stake_weight_bps(bps);
+    }
+
+    if let Some(epochs) = fee_increase_notice_epochs {
+        ix.fee_increase_notice_epochs(epochs);
+    }
+
+    if let Some(lamports) = crank_bounty_lamports {
+        ix.crank_bounty_lamports(lamports);
+    }
+
+    if let Some(lamports) = max_crank_bounty_lamports_per_epoch {
+        ix.max_crank_bounty_lamports_per_epoch(lamports);
+    }
+
+    let mut admin_set_parameters_ix = ix.instruction();
+    admin_set_parameters_ix.program_id = handler.tip_router_program_id;
+
+    let ixs = &[admin_set_parameters_ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
+        send_and_log_transaction(
+            handler,
+            ixs,
+            &[],
+            "Set Parameters",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Epochs Before Stall: {:?}", epochs_before_stall),
                 format!(
-                    "New Priority Fee Distribution Fee BPS: {:?}",
-                    new_priority_fee_distribution_fee_bps
+                    "Epochs After Consensus Before Close: {:?}",
+                    epochs_after_consensus_before_close
+                ),
+                format!(
+                    "Valid Slots After Consensus: {:?}",
+                    valid_slots_after_consensus
+                ),
+                format!("Commit Window Slots: {:?}", commit_window_slots),
+                format!("Reveal Window Slots: {:?}", reveal_window_slots),
+                format!("Consensus Threshold Bps: {:?}", consensus_threshold_bps),
+                format!("Quorum Operator Count: {:?}", quorum_operator_count),
+                format!("Quorum Operator Bps: {:?}", quorum_operator_bps),
+                format!(
+                    "Stall Fallback Threshold Bps: {:?}",
+                    stall_fallback_threshold_bps
+                ),
+                format!(
+                    "Admin Handover Delay Epochs: {:?}",
+                    admin_handover_delay_epochs
+                ),
+                format!(
+                    "Weight Sampling Window Slots: {:?}",
+                    weight_sampling_window_slots
+                ),
+                format!(
+                    "Max Operator Stake Weight Bps: {:?}",
+                    max_operator_stake_weight_bps
+                ),
+                format!(
+                    "Max Vault Stake Weight Bps: {:?}",
+                    max_vault_stake_weight_bps
+                ),
+                format!(
+                    "Fee Increase Notice Epochs: {:?}",
+                    fee_increase_notice_epochs
+                ),
+                format!("Crank Bounty Lamports: {:?}", crank_bounty_lamports),
+                format!(
+                    "Max Crank Bounty Lamports Per Epoch: {:?}",
+                    max_crank_bounty_lamports_per_epoch
                 ),
             ],
         )
@@ -610,68 +909,102 @@ pub async fn admin_set_config_fees(
     Ok(())
 }
 
-// --------------------- TIP ROUTER ------------------------------
+pub const fn client_admin_role(role: CoreConfigAdminRole) -> ConfigAdminRole {
+    match role {
+        CoreConfigAdminRole::FeeAdmin => ConfigAdminRole::FeeAdmin,
+        CoreConfigAdminRole::TieBreakerAdmin => ConfigAdminRole::TieBreakerAdmin,
+        CoreConfigAdminRole::PauseAdmin => ConfigAdminRole::PauseAdmin,
+    }
+}
 
-pub async fn create_vault_registry(handler: &CliHandler) -> Result<()> {
+pub async fn admin_set_admin_multisig(
+    handler: &CliHandler,
+    role: CoreConfigAdminRole,
+    threshold: u8,
+    signers: Vec<Pubkey>,
+) -> Result<()> {
+    let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
 
-    let (config, _, _) =
-        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
-
-    let (vault_registry, _, _) =
-        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
-
-    let (account_payer, _, _) =
-        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
-
-    let vault_registry_account = get_account(handler, &vault_registry).await?;
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+    let admin_multisig =
+        AdminMultisig::find_program_address(&handler.tip_router_program_id, &ncn, role).0;
+    let account_payer = AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
-    // Skip if vault registry already exists
-    if vault_registry_account.is_none() {
-        let mut initialize_vault_registry_ix = InitializeVaultRegistryBuilder::new()
-            .config(config)
-            .account_payer(account_payer)
-            .ncn(ncn)
-            .vault_registry(vault_registry)
-            .instruction();
-        initialize_vault_registry_ix.program_id = handler.tip_router_program_id;
+    let mut ix = AdminSetAdminMultisigBuilder::new()
+        .config(config_pda)
+        .admin_multisig(admin_multisig)
+        .ncn(ncn)
+        .ncn_admin(keypair.pubkey())
+        .account_payer(account_payer)
+        .system_program(system_program::id())
+        .role(client_admin_role(role))
+        .threshold(threshold)
+        .signers(signers.clone())
+        .instruction();
+    ix.program_id = handler.tip_router_program_id;
 
+    let ixs = &[ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
         send_and_log_transaction(
             handler,
-            &[initialize_vault_registry_ix],
+            ixs,
             &[],
-            "Created Vault Registry",
-            &[format!("NCN: {:?}", ncn)],
+            "Admin Set Admin Multisig",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Role: {:?}", role),
+                format!("Threshold: {:?}", threshold),
+                format!("Signers: {:?}", signers),
+            ],
         )
         .await?;
     }
 
-    // Number of reallocations needed based on VaultRegistry::SIZE
-    let num_reallocs = (VaultRegistry::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+    Ok(())
+}
 
-    let mut realloc_vault_registry_ix = ReallocVaultRegistryBuilder::new()
-        .config(config)
-        .vault_registry(vault_registry)
+pub async fn propose_admin_action(
+    handler: &CliHandler,
+    role: CoreConfigAdminRole,
+    action: AdminAction,
+) -> Result<()> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
+
+    let admin_multisig_account = get_admin_multisig(handler, role).await?;
+    let nonce = admin_multisig_account.proposal_count();
+
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+    let admin_multisig =
+        AdminMultisig::find_program_address(&handler.tip_router_program_id, &ncn, role).0;
+    let admin_proposal =
+        AdminProposal::find_program_address(&handler.tip_router_program_id, &ncn, role, nonce).0;
+
+    let mut ix = ProposeAdminActionBuilder::new()
+        .config(config_pda)
+        .admin_multisig(admin_multisig)
+        .admin_proposal(admin_proposal)
         .ncn(ncn)
-        .account_payer(account_payer)
+        .signer(keypair.pubkey())
         .system_program(system_program::id())
+        .role(client_admin_role(role))
+        .action(action.clone())
         .instruction();
-    realloc_vault_registry_ix.program_id = handler.tip_router_program_id;
-
-    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
-    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
-    for _ in 0..num_reallocs {
-        realloc_ixs.push(realloc_vault_registry_ix.clone());
-    }
+    ix.program_id = handler.tip_router_program_id;
 
     send_and_log_transaction(
         handler,
-        &realloc_ixs,
+        &[ix],
         &[],
-        "Reallocated Vault Registry",
+        "Proposed Admin Action",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Number of reallocations: {:?}", num_reallocs),
+            format!("Role: {:?}", role),
+            format!("Nonce: {:?}", nonce),
+            format!("Action: {:?}", action),
         ],
     )
     .await?;
@@ -679,110 +1012,137 @@ pub async fn create_vault_registry(handler: &CliHandler) -> Result<()> {
     Ok(())
 }
 
-pub async fn register_vault(handler: &CliHandler, vault: &Pubkey) -> Result<()> {
+pub async fn approve_admin_action(
+    handler: &CliHandler,
+    role: CoreConfigAdminRole,
+    nonce: u64,
+) -> Result<()> {
+    let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
-    let vault = *vault;
 
-    let (tip_router_config, _, _) =
-        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
-
-    let (vault_registry, _, _) =
-        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
-
-    let (ncn_vault_ticket, _, _) =
-        NcnVaultTicket::find_program_address(&handler.restaking_program_id, &ncn, &vault);
+    let admin_multisig =
+        AdminMultisig::find_program_address(&handler.tip_router_program_id, &ncn, role).0;
+    let admin_proposal =
+        AdminProposal::find_program_address(&handler.tip_router_program_id, &ncn, role, nonce).0;
 
-    let mut register_vault_ix = RegisterVaultBuilder::new()
-        .config(tip_router_config)
-        .vault_registry(vault_registry)
-        .vault(vault)
+    let mut ix = ApproveAdminActionBuilder::new()
+        .admin_multisig(admin_multisig)
+        .admin_proposal(admin_proposal)
         .ncn(ncn)
-        .ncn_vault_ticket(ncn_vault_ticket)
-        .vault_registry(vault_registry)
+        .signer(keypair.pubkey())
+        .role(client_admin_role(role))
+        .nonce(nonce)
         .instruction();
-    register_vault_ix.program_id = handler.tip_router_program_id;
+    ix.program_id = handler.tip_router_program_id;
 
     send_and_log_transaction(
         handler,
-        &[register_vault_ix],
+        &[ix],
         &[],
-        "Registered Vault",
-        &[format!("NCN: {:?}", ncn), format!("Vault: {:?}", vault)],
+        "Approved Admin Action",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Role: {:?}", role),
+            format!("Nonce: {:?}", nonce),
+        ],
     )
     .await?;
 
     Ok(())
 }
 
-pub async fn create_epoch_state(handler: &CliHandler, epoch: u64) -> Result<()> {
+pub async fn close_admin_action(
+    handler: &CliHandler,
+    role: CoreConfigAdminRole,
+    nonce: u64,
+) -> Result<()> {
+    let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
 
-    let (config, _, _) =
-        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
-
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (account_payer, _, _) =
-        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
-    let (epoch_marker, _, _) =
-        EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+    let admin_multisig =
+        AdminMultisig::find_program_address(&handler.tip_router_program_id, &ncn, role).0;
+    let admin_proposal =
+        AdminProposal::find_program_address(&handler.tip_router_program_id, &ncn, role, nonce).0;
+    let proposer = *get_admin_proposal(handler, role, nonce).await?.proposer();
+
+    let mut ix = CloseAdminProposalBuilder::new()
+        .config(config_pda)
+        .admin_multisig(admin_multisig)
+        .admin_proposal(admin_proposal)
+        .ncn(ncn)
+        .proposer(proposer)
+        .signer(keypair.pubkey())
+        .role(client_admin_role(role))
+        .nonce(nonce)
+        .instruction();
+    ix.program_id = handler.tip_router_program_id;
 
-    let epoch_state_account = get_account(handler, &epoch_state).await?;
+    send_and_log_transaction(
+        handler,
+        &[ix],
+        &[],
+        "Closed Admin Action",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Role: {:?}", role),
+            format!("Nonce: {:?}", nonce),
+        ],
+    )
+    .await?;
 
-    // Skip if ballot box already exists
-    if epoch_state_account.is_none() {
-        // Initialize ballot box
-        let mut initialize_ballot_box_ix = InitializeEpochStateBuilder::new()
-            .epoch_marker(epoch_marker)
-            .config(config)
-            .epoch_state(epoch_state)
-            .ncn(ncn)
-            .epoch(epoch)
-            .account_payer(account_payer)
-            .system_program(system_program::id())
-            .instruction();
-        initialize_ballot_box_ix.program_id = handler.tip_router_program_id;
+    Ok(())
+}
 
-        send_and_log_transaction(
-            handler,
-            &[initialize_ballot_box_ix],
-            &[],
-            "Initialized Epoch State",
-            &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
-        )
-        .await?;
-    }
+pub async fn execute_admin_action(
+    handler: &CliHandler,
+    role: CoreConfigAdminRole,
+    nonce: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
 
-    // Number of reallocations needed based on BallotBox::SIZE
-    let num_reallocs = (EpochState::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+    let admin_proposal_account = get_admin_proposal(handler, role, nonce).await?;
+    let action = admin_proposal_account.action()?;
 
-    // Realloc ballot box
-    let mut realloc_ballot_box_ix = ReallocEpochStateBuilder::new()
-        .config(config)
-        .epoch_state(epoch_state)
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+    let admin_multisig =
+        AdminMultisig::find_program_address(&handler.tip_router_program_id, &ncn, role).0;
+    let admin_proposal =
+        AdminProposal::find_program_address(&handler.tip_router_program_id, &ncn, role, nonce).0;
+
+    let mut ix = ExecuteAdminActionBuilder::new();
+    ix.config(config_pda)
+        .admin_multisig(admin_multisig)
+        .admin_proposal(admin_proposal)
         .ncn(ncn)
-        .epoch(epoch)
-        .account_payer(account_payer)
-        .system_program(system_program::id())
-        .instruction();
-    realloc_ballot_box_ix.program_id = handler.tip_router_program_id;
-
-    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
-    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
-    for _ in 0..num_reallocs {
-        realloc_ixs.push(realloc_ballot_box_ix.clone());
+        .role(client_admin_role(role))
+        .nonce(nonce);
+
+    // Setting the tie breaker also needs the epoch's accounts
+    if let CoreAdminAction::SetTieBreaker { epoch, .. } = action {
+        let epoch_state =
+            EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch).0;
+        let ballot_box =
+            BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch).0;
+        ix.add_remaining_accounts(&[
+            AccountMeta::new(epoch_state, false),
+            AccountMeta::new(ballot_box, false),
+        ]);
     }
 
+    let mut ix = ix.instruction();
+    ix.program_id = handler.tip_router_program_id;
+
     send_and_log_transaction(
         handler,
-        &realloc_ixs,
+        &[ix],
         &[],
-        "Reallocated Epoch State",
+        "Executed Admin Action",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Epoch: {:?}", epoch),
-            format!("Number of reallocations: {:?}", num_reallocs),
+            format!("Role: {:?}", role),
+            format!("Nonce: {:?}", nonce),
+            format!("Action: {:?}", action),
         ],
     )
     .await?;
@@ -790,499 +1150,463 @@ pub async fn create_epoch_state(handler: &CliHandler, epoch: u64) -> Result<()>
     Ok(())
 }
 
-pub async fn create_weight_table(handler: &CliHandler, epoch: u64) -> Result<()> {
+pub async fn admin_fund_account_payer(handler: &CliHandler, amount: f64) -> Result<()> {
+    let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
 
-    let (config, _, _) =
-        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
-
-    let (vault_registry, _, _) =
-        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
-
-    let (weight_table, _, _) =
-        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
     let (account_payer, _, _) =
         AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
-    let (epoch_marker, _, _) =
-        EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
 
-    let weight_table_account = get_account(handler, &weight_table).await?;
-
-    // Skip if weight table already exists
-    if weight_table_account.is_none() {
-        // Initialize weight table
-        let mut initialize_weight_table_ix = InitializeWeightTableBuilder::new()
-            .epoch_marker(epoch_marker)
-            .vault_registry(vault_registry)
-            .ncn(ncn)
-            .epoch_state(epoch_state)
-            .weight_table(weight_table)
-            .account_payer(account_payer)
-            .system_program(system_program::id())
-            .epoch(epoch)
-            .instruction();
-        initialize_weight_table_ix.program_id = handler.tip_router_program_id;
+    let transfer_ix = transfer(
+        &keypair.pubkey(),
+        &account_payer,
+        sol_str_to_lamports(&amount.to_string())
+            .ok_or_else(|| anyhow!("invalid SOL amount: {amount}"))?,
+    );
 
+    let ixs = &[transfer_ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
         send_and_log_transaction(
             handler,
-            &[initialize_weight_table_ix],
+            ixs,
             &[],
-            "Initialized Weight Table",
-            &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
+            "Fund Account Payer",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Amount: {:?} SOL", amount),
+            ],
         )
         .await?;
     }
 
-    // Number of reallocations needed based on WeightTable::SIZE
-    let num_reallocs = (WeightTable::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
-
-    // Realloc weight table
-    let mut realloc_weight_table_ix = ReallocWeightTableBuilder::new()
-        .config(config)
-        .weight_table(weight_table)
-        .ncn(ncn)
-        .epoch_state(epoch_state)
-        .vault_registry(vault_registry)
-        .epoch(epoch)
-        .account_payer(account_payer)
-        .system_program(system_program::id())
-        .instruction();
-    realloc_weight_table_ix.program_id = handler.tip_router_program_id;
-
-    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
-    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
-    for _ in 0..num_reallocs {
-        realloc_ixs.push(realloc_weight_table_ix.clone());
-    }
-
-    send_and_log_transaction(
-        handler,
-        &realloc_ixs,
-        &[],
-        "Reallocated Weight Table",
-        &[
-            format!("NCN: {:?}", ncn),
-            format!("Epoch: {:?}", epoch),
-            format!("Number of reallocations: {:?}", num_reallocs),
-        ],
-    )
-    .await?;
-
     Ok(())
 }
 
-#[allow(deprecated)]
-pub async fn crank_switchboard(handler: &CliHandler, switchboard_feed: &Pubkey) -> Result<()> {
-    async fn wait_for_x_slots_after_epoch(handler: &CliHandler, slots: u64) -> Result<()> {
-        loop {
-            let current_slot = handler.rpc_client().get_slot().await?;
-            if current_slot % DEFAULT_SLOTS_PER_EPOCH > slots {
-                break;
-            }
-            sleep(Duration::from_millis(500)).await;
-        }
-        Ok(())
-    }
+#[allow(clippy::too_many_arguments)]
+pub async fn admin_set_config_fees(
+    handler: &CliHandler,
+    new_block_engine_fee_bps: Option<u16>,
+    base_fee_group: Option<u8>,
+    new_base_fee_wallet: Option<String>,
+    new_base_fee_bps: Option<u16>,
+    ncn_fee_group: Option<u8>,
+    new_ncn_fee_bps: Option<u16>,
+    new_priority_fee_distribution_fee_bps: Option<u16>,
+    activation_epoch: Option<u64>,
+) -> Result<()> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
 
-    let switchboard_context = handler.switchboard_context();
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
-    let client = handler.rpc_client();
-    let payer = handler.keypair();
+    let mut ix = AdminSetConfigFeesBuilder::new();
+    ix.config(config_pda).ncn(ncn).ncn_admin(keypair.pubkey());
 
-    if switchboard_feed.eq(&Pubkey::default()) {
-        return Ok(());
+    if let Some(fee) = new_block_engine_fee_bps {
+        ix.new_block_engine_fee_bps(fee);
     }
 
-    wait_for_x_slots_after_epoch(handler, 400).await?;
+    if let Some(group) = base_fee_group {
+        ix.base_fee_group(group);
+    }
 
-    // STATIC PUBKEY
-    let queue_key = SWITCHBOARD_QUEUE;
+    if let Some(wallet) = &new_base_fee_wallet {
+        let wallet = Pubkey::from_str(wallet).map_err(|_| anyhow!("Invalid wallet address"))?;
+        ix.new_base_fee_wallet(wallet);
+    }
 
-    let mut queue_account = client
-        .get_account_with_commitment(&queue_key, handler.commitment)
-        .await?
-        .value
-        .expect("Queue account not found");
-    let queue_account_info = AccountInfo {
-        key: &queue_key,
-        is_signer: false,
-        is_writable: true,
-        owner: &queue_account.owner,
-        lamports: Rc::new(RefCell::new(&mut queue_account.lamports)),
-        data: Rc::new(RefCell::new(&mut queue_account.data)),
-        executable: false,
-        _unused: 0,
-    };
+    if let Some(fee) = new_base_fee_bps {
+        ix.new_base_fee_bps(fee);
+    }
 
-    let queue = *QueueAccountData::new(&queue_account_info)?;
+    if let Some(group) = ncn_fee_group {
+        ix.ncn_fee_group(group);
+    }
 
-    let gateways = &queue.fetch_gateways(client).await?;
+    if let Some(fee) = new_ncn_fee_bps {
+        ix.new_ncn_fee_bps(fee);
+    }
 
-    if gateways.is_empty() {
-        return Err(anyhow!("No gateways found"));
+    if let Some(fee) = new_priority_fee_distribution_fee_bps {
+        ix.new_priority_fee_distribution_fee_bps(fee);
     }
 
-    let crossbar = CrossbarClient::default();
-    let mut update_ix = None;
-    let mut last_error = None;
-    for (index, gateway) in gateways.iter().enumerate() {
-        log::info!(
-            "Fetching Switchboard update attempt={}/{} gateway={}",
-            index + 1,
-            gateways.len(),
-            gateway.url()
-        );
+    if let Some(epoch) = activation_epoch {
+        ix.activation_epoch(epoch);
+    }
 
-        match PullFeed::fetch_update_ix(
-            switchboard_context.clone(),
-            client,
-            FetchUpdateParams {
-                feed: *switchboard_feed,
-                payer: payer.pubkey(),
-                gateway: gateway.clone(),
-                crossbar: Some(crossbar.clone()),
-                ..Default::default()
-            },
+    let mut admin_set_config_fees_ix = ix.instruction();
+    admin_set_config_fees_ix.program_id = handler.tip_router_program_id;
+
+    let ixs = &[admin_set_config_fees_ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
+        send_and_log_transaction(
+            handler,
+            ixs,
+            &[],
+            "Set Config Fees",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("New Block Engine Fee BPS: {:?}", new_block_engine_fee_bps),
+                format!("Base Fee Group: {:?}", base_fee_group),
+                format!("New Base Fee Wallet: {:?}", new_base_fee_wallet),
+                format!("New Base Fee BPS: {:?}", new_base_fee_bps),
+                format!("NCN Fee Group: {:?}", ncn_fee_group),
+                format!("New NCN Fee BPS: {:?}", new_ncn_fee_bps),
+                format!(
+                    "New Priority Fee Distribution Fee BPS: {:?}",
+                    new_priority_fee_distribution_fee_bps
+                ),
+                format!("Activation Epoch: {:?}", activation_epoch),
+            ],
         )
-        .await
-        {
-            std::result::Result::Ok((ix, _, _, _)) => {
-                update_ix = Some(ix);
-                break;
-            }
-            Err(error) => {
-                log::warn!(
-                    "Failed to fetch Switchboard update gateway={}: {:?}",
-                    gateway.url(),
-                    error
-                );
-                last_error = Some(error);
-            }
-        }
+        .await?;
     }
-    let ix = update_ix.ok_or_else(|| {
-        anyhow!(
-            "Failed to fetch Switchboard update from all {} gateways. Last error: {:?}",
-            gateways.len(),
-            last_error
-        )
-    })?;
-
-    send_and_log_transaction(
-        handler,
-        &[
-            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
-            ix,
-        ],
-        &[],
-        "Crank Switchboard",
-        &[format!("FEED: {:?}", switchboard_feed)],
-    )
-    .await?;
 
     Ok(())
 }
 
-pub async fn set_weight(handler: &CliHandler, vault: &Pubkey, epoch: u64) -> Result<()> {
-    let vault_account = get_vault(handler, vault).await?;
+pub async fn admin_cancel_scheduled_fees(
+    handler: &CliHandler,
+    activation_epoch: u64,
+) -> Result<()> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
 
-    set_weight_with_st_mint(handler, &vault_account.supported_mint, epoch).await
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+
+    let mut ix = AdminCancelScheduledFeesBuilder::new();
+    ix.config(config_pda)
+        .ncn(ncn)
+        .fee_admin(keypair.pubkey())
+        .activation_epoch(activation_epoch);
+
+    let mut admin_cancel_scheduled_fees_ix = ix.instruction();
+    admin_cancel_scheduled_fees_ix.program_id = handler.tip_router_program_id;
+
+    let ixs = &[admin_cancel_scheduled_fees_ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
+        send_and_log_transaction(
+            handler,
+            ixs,
+            &[],
+            "Cancel Scheduled Fees",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Activation Epoch: {:?}", activation_epoch),
+            ],
+        )
+        .await?;
+    }
+
+    Ok(())
 }
 
-pub async fn set_weight_with_st_mint(
+pub async fn admin_set_base_fee_group(
     handler: &CliHandler,
-    st_mint: &Pubkey,
-    epoch: u64,
+    base_fee_group: u8,
+    name: Option<String>,
+    enabled: Option<bool>,
+    payout_asset: Option<PayoutAsset>,
 ) -> Result<()> {
+    let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
 
-    let vault_registry = get_vault_registry(handler).await?;
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
-    let mint_entry = vault_registry.get_mint_entry(st_mint)?;
-    let switchboard_feed = mint_entry.switchboard_feed();
+    let mut ix = AdminSetBaseFeeGroupBuilder::new();
+    ix.config(config_pda)
+        .ncn(ncn)
+        .fee_admin(keypair.pubkey())
+        .base_fee_group(base_fee_group);
 
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    if let Some(name) = &name {
+        ix.name(BaseFeeGroupSettings::name_bytes(name));
+    }
 
-    let (weight_table, _, _) =
-        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    if let Some(enabled) = enabled {
+        ix.enabled(enabled);
+    }
 
-    // Crank Switchboard
-    let result = crank_switchboard(handler, switchboard_feed).await;
-    if let Err(e) = result {
-        let switchboard_feed = format!(
-            "https://ondemand.switchboard.xyz/solana/mainnet/feed/{}",
-            switchboard_feed
-        );
-        log::error!(
-            "Failed to crank Switchboard, manual crank needed feed={}: {:#}",
-            switchboard_feed,
-            e
-        );
+    if let Some(payout_asset) = payout_asset {
+        ix.payout_asset(payout_asset as u8);
     }
 
-    let mut set_weight_ix = SwitchboardSetWeightBuilder::new()
-        .ncn(ncn)
-        .weight_table(weight_table)
-        .epoch_state(epoch_state)
-        .st_mint(*st_mint)
-        .switchboard_feed(*switchboard_feed)
-        .epoch(epoch)
-        .instruction();
-    set_weight_ix.program_id = handler.tip_router_program_id;
+    let mut admin_set_base_fee_group_ix = ix.instruction();
+    admin_set_base_fee_group_ix.program_id = handler.tip_router_program_id;
 
-    send_and_log_transaction(
-        handler,
-        &[set_weight_ix],
-        &[],
-        "Set Weight Using Switchboard Feed",
-        &[
-            format!("NCN: {:?}", ncn),
-            format!("Epoch: {:?}", epoch),
-            format!("ST Mint: {:?}", st_mint),
-            format!("Switchboard Feed: {:?}", switchboard_feed),
-        ],
-    )
-    .await?;
+    let ixs = &[admin_set_base_fee_group_ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
+        send_and_log_transaction(
+            handler,
+            ixs,
+            &[],
+            "Set Base Fee Group",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Base Fee Group: {:?}", base_fee_group),
+                format!("Name: {:?}", name),
+                format!("Enabled: {:?}", enabled),
+                format!("Payout Asset: {:?}", payout_asset),
+            ],
+        )
+        .await?;
+    }
 
     Ok(())
 }
 
-pub async fn create_epoch_snapshot(handler: &CliHandler, epoch: u64) -> Result<()> {
+pub async fn admin_set_paused_subsystems(
+    handler: &CliHandler,
+    paused_subsystems: u8,
+) -> Result<()> {
+    let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
 
-    let (config, _, _) =
-        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
-
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (weight_table, _, _) =
-        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (epoch_snapshot, _, _) =
-        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (account_payer, _, _) =
-        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
-    let (epoch_marker, _, _) =
-        EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
-    let mut initialize_epoch_snapshot_ix = InitializeEpochSnapshotBuilder::new()
-        .epoch_marker(epoch_marker)
-        .config(config)
+    let mut ix = AdminSetPausedSubsystemsBuilder::new()
+        .config(config_pda)
         .ncn(ncn)
-        .epoch_state(epoch_state)
-        .weight_table(weight_table)
-        .epoch_snapshot(epoch_snapshot)
-        .account_payer(account_payer)
-        .system_program(system_program::id())
-        .epoch(epoch)
+        .admin(keypair.pubkey())
+        .paused_subsystems(paused_subsystems)
         .instruction();
-    initialize_epoch_snapshot_ix.program_id = handler.tip_router_program_id;
+    ix.program_id = handler.tip_router_program_id;
 
-    send_and_log_transaction(
-        handler,
-        &[initialize_epoch_snapshot_ix],
-        &[],
-        "Initialized Epoch Snapshot",
-        &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
-    )
-    .await?;
+    let paused = PausableSubsystem::all()
+        .into_iter()
+        .filter(|subsystem| subsystem.is_paused(paused_subsystems))
+        .map(|subsystem| subsystem.to_string())
+        .collect::<Vec<_>>();
+
+    let ixs = &[ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
+        send_and_log_transaction(
+            handler,
+            ixs,
+            &[],
+            "Set Paused Subsystems",
+            &[format!("NCN: {:?}", ncn), format!("Paused: {:?}", paused)],
+        )
+        .await?;
+    }
 
     Ok(())
 }
 
-pub async fn create_operator_snapshot(
+pub async fn admin_set_reward_mint(
     handler: &CliHandler,
-    operator: &Pubkey,
-    epoch: u64,
+    mint: &Pubkey,
+    allowed: bool,
 ) -> Result<()> {
+    let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
 
-    let operator = *operator;
+    let config_pda = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
 
-    let (config, _, _) =
-        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+    let mut ix = AdminSetRewardMintBuilder::new()
+        .config(config_pda)
+        .ncn(ncn)
+        .mint(*mint)
+        .ncn_admin(keypair.pubkey())
+        .allowed(allowed)
+        .instruction();
+    ix.program_id = handler.tip_router_program_id;
 
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    let ixs = &[ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
+        send_and_log_transaction(
+            handler,
+            ixs,
+            &[],
+            "Set Reward Mint",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Mint: {:?}", mint),
+                format!("Allowed: {:?}", allowed),
+            ],
+        )
+        .await?;
+    }
 
-    let (ncn_operator_state, _, _) =
-        NcnOperatorState::find_program_address(&handler.restaking_program_id, &ncn, &operator);
+    Ok(())
+}
 
-    let (epoch_snapshot, _, _) =
-        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+// --------------------- TIP ROUTER ------------------------------
 
-    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
-        &handler.tip_router_program_id,
-        &operator,
-        &ncn,
-        epoch,
-    );
+pub async fn create_vault_registry(handler: &CliHandler) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
 
     let (account_payer, _, _) =
         AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
-    let (epoch_marker, _, _) =
-        EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
 
-    let operator_snapshot_account = get_account(handler, &operator_snapshot).await?;
+    let vault_registry_account = get_account(handler, &vault_registry).await?;
 
-    // Skip if operator snapshot already exists
-    if operator_snapshot_account.is_none() {
-        // Initialize operator snapshot
-        let mut initialize_operator_snapshot_ix = InitializeOperatorSnapshotBuilder::new()
-            .epoch_marker(epoch_marker)
+    // Skip if vault registry already exists
+    if vault_registry_account.is_none() {
+        let mut initialize_vault_registry_ix = InitializeVaultRegistryBuilder::new()
             .config(config)
-            .ncn(ncn)
-            .operator(operator)
-            .epoch_state(epoch_state)
-            .ncn_operator_state(ncn_operator_state)
-            .epoch_snapshot(epoch_snapshot)
-            .operator_snapshot(operator_snapshot)
             .account_payer(account_payer)
-            .system_program(system_program::id())
-            .epoch(epoch)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
             .instruction();
-        initialize_operator_snapshot_ix.program_id = handler.tip_router_program_id;
+        initialize_vault_registry_ix.program_id = handler.tip_router_program_id;
 
         send_and_log_transaction(
             handler,
-            &[initialize_operator_snapshot_ix],
+            &[initialize_vault_registry_ix],
             &[],
-            "Initialized Operator Snapshot",
-            &[
-                format!("NCN: {:?}", ncn),
-                format!("Operator: {:?}", operator),
-                format!("Epoch: {:?}", epoch),
-            ],
+            "Created Vault Registry",
+            &[format!("NCN: {:?}", ncn)],
         )
         .await?;
     }
 
-    // Number of reallocations needed based on OperatorSnapshot::SIZE
-    let num_reallocs = (OperatorSnapshot::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+    // Number of reallocations needed based on VaultRegistry::SIZE
+    let num_reallocs = (VaultRegistry::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
 
-    // Realloc operator snapshot
-    let mut realloc_operator_snapshot_ix = ReallocOperatorSnapshotBuilder::new()
+    let mut realloc_vault_registry_ix = ReallocVaultRegistryBuilder::new()
         .config(config)
-        .restaking_config(RestakingConfig::find_program_address(&handler.restaking_program_id).0)
+        .vault_registry(vault_registry)
         .ncn(ncn)
-        .operator(operator)
-        .epoch_state(epoch_state)
-        .ncn_operator_state(ncn_operator_state)
-        .epoch_snapshot(epoch_snapshot)
-        .operator_snapshot(operator_snapshot)
         .account_payer(account_payer)
         .system_program(system_program::id())
-        .epoch(epoch)
         .instruction();
-    realloc_operator_snapshot_ix.program_id = handler.tip_router_program_id;
+    realloc_vault_registry_ix.program_id = handler.tip_router_program_id;
 
     let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
     realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
     for _ in 0..num_reallocs {
-        realloc_ixs.push(realloc_operator_snapshot_ix.clone());
+        realloc_ixs.push(realloc_vault_registry_ix.clone());
     }
 
     send_and_log_transaction(
         handler,
         &realloc_ixs,
         &[],
-        "Reallocated Operator Snapshot",
+        "Reallocated Vault Registry",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Operator: {:?}", operator),
-            format!("Epoch: {:?}", epoch),
             format!("Number of reallocations: {:?}", num_reallocs),
         ],
     )
     .await?;
 
+    create_vault_registry_pages(handler).await?;
+
     Ok(())
 }
 
-pub async fn snapshot_vault_operator_delegation(
-    handler: &CliHandler,
-    vault: &Pubkey,
-    operator: &Pubkey,
-    epoch: u64,
-) -> Result<()> {
+/// Initializes the vault registry pages the NCN's vaults past MAX_VAULTS need, in order
+pub async fn create_vault_registry_pages(handler: &CliHandler) -> Result<()> {
     let ncn = *handler.ncn()?;
 
-    let vault = *vault;
-    let operator = *operator;
-
     let (config, _, _) =
         TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    let (restaking_config, _, _) =
-        RestakingConfig::find_program_address(&handler.restaking_program_id);
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    let (vault_ncn_ticket, _, _) =
-        VaultNcnTicket::find_program_address(&handler.vault_program_id, &vault, &ncn);
+    let pages_needed = VaultRegistryPage::pages_needed(get_ncn(handler).await?.vault_count());
+    let page_count = get_vault_registry(handler).await?.page_count();
 
-    let (ncn_vault_ticket, _, _) =
-        NcnVaultTicket::find_program_address(&handler.restaking_program_id, &ncn, &vault);
+    for page in page_count..pages_needed {
+        let (vault_registry_page, _, _) =
+            VaultRegistryPage::find_program_address(&handler.tip_router_program_id, &ncn, page);
 
-    let (vault_operator_delegation, _, _) =
-        VaultOperatorDelegation::find_program_address(&handler.vault_program_id, &vault, &operator);
+        let mut initialize_vault_registry_page_ix = InitializeVaultRegistryPageBuilder::new()
+            .config(config)
+            .vault_registry(vault_registry)
+            .vault_registry_page(vault_registry_page)
+            .ncn(ncn)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .page(page)
+            .instruction();
+        initialize_vault_registry_page_ix.program_id = handler.tip_router_program_id;
 
-    let (weight_table, _, _) =
-        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+        send_and_log_transaction(
+            handler,
+            &[initialize_vault_registry_page_ix],
+            &[],
+            "Created Vault Registry Page",
+            &[format!("NCN: {:?}", ncn), format!("Page: {:?}", page)],
+        )
+        .await?;
+    }
 
-    let (epoch_snapshot, _, _) =
-        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    Ok(())
+}
 
-    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
-        &handler.tip_router_program_id,
-        &operator,
-        &ncn,
-        epoch,
-    );
+pub async fn register_vault(handler: &CliHandler, vault: &Pubkey) -> Result<()> {
+    let ncn = *handler.ncn()?;
+    let vault = *vault;
 
-    let mut snapshot_vault_operator_delegation_ix = SnapshotVaultOperatorDelegationBuilder::new()
-        .config(config)
-        .epoch_state(epoch_state)
-        .restaking_config(restaking_config)
-        .ncn(ncn)
-        .operator(operator)
-        .vault(vault)
-        .vault_ncn_ticket(vault_ncn_ticket)
-        .ncn_vault_ticket(ncn_vault_ticket)
-        .vault_operator_delegation(vault_operator_delegation)
-        .weight_table(weight_table)
-        .epoch_snapshot(epoch_snapshot)
-        .operator_snapshot(operator_snapshot)
-        .epoch(epoch)
+    let (tip_router_config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (ncn_vault_ticket, _, _) =
+        NcnVaultTicket::find_program_address(&handler.restaking_program_id, &ncn, &vault);
+
+    // Vaults past MAX_VAULTS are registered to their page
+    let vault_page = get_vault_page(handler, &vault).await?;
+    if let Some(page) = vault_page {
+        if page >= get_vault_registry(handler).await?.page_count() {
+            create_vault_registry_pages(handler).await?;
+        }
+    }
+    let vault_registry_page = vault_page.map(|page| {
+        VaultRegistryPage::find_program_address(&handler.tip_router_program_id, &ncn, page).0
+    });
+
+    let mut register_vault_ix = RegisterVaultBuilder::new()
+        .config(tip_router_config)
+        .vault_registry(vault_registry)
+        .vault(vault)
+        .ncn(ncn)
+        .ncn_vault_ticket(ncn_vault_ticket)
+        .vault_registry(vault_registry)
+        .vault_registry_page(vault_registry_page)
         .instruction();
-    snapshot_vault_operator_delegation_ix.program_id = handler.tip_router_program_id;
+    register_vault_ix.program_id = handler.tip_router_program_id;
 
     send_and_log_transaction(
         handler,
-        &[snapshot_vault_operator_delegation_ix],
+        &[register_vault_ix],
         &[],
-        "Snapshotted Vault Operator Delegation",
-        &[
-            format!("NCN: {:?}", ncn),
-            format!("Vault: {:?}", vault),
-            format!("Operator: {:?}", operator),
-            format!("Epoch: {:?}", epoch),
-        ],
+        "Registered Vault",
+        &[format!("NCN: {:?}", ncn), format!("Vault: {:?}", vault)],
     )
     .await?;
 
     Ok(())
 }
 
-pub async fn create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<()> {
+pub async fn create_epoch_state(handler: &CliHandler, epoch: u64) -> Result<()> {
     let ncn = *handler.ncn()?;
 
     let (config, _, _) =
@@ -1291,24 +1615,20 @@ pub async fn create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<()> {
     let (epoch_state, _, _) =
         EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let (ballot_box, _, _) =
-        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
     let (account_payer, _, _) =
         AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
     let (epoch_marker, _, _) =
         EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
 
-    let ballot_box_account = get_account(handler, &ballot_box).await?;
+    let epoch_state_account = get_account(handler, &epoch_state).await?;
 
     // Skip if ballot box already exists
-    if ballot_box_account.is_none() {
+    if epoch_state_account.is_none() {
         // Initialize ballot box
-        let mut initialize_ballot_box_ix = InitializeBallotBoxBuilder::new()
+        let mut initialize_ballot_box_ix = InitializeEpochStateBuilder::new()
             .epoch_marker(epoch_marker)
             .config(config)
             .epoch_state(epoch_state)
-            .ballot_box(ballot_box)
             .ncn(ncn)
             .epoch(epoch)
             .account_payer(account_payer)
@@ -1320,20 +1640,19 @@ pub async fn create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<()> {
             handler,
             &[initialize_ballot_box_ix],
             &[],
-            "Initialized Ballot Box",
+            "Initialized Epoch State",
             &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
         )
         .await?;
     }
 
     // Number of reallocations needed based on BallotBox::SIZE
-    let num_reallocs = (BallotBox::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+    let num_reallocs = (EpochState::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
 
     // Realloc ballot box
-    let mut realloc_ballot_box_ix = ReallocBallotBoxBuilder::new()
+    let mut realloc_ballot_box_ix = ReallocEpochStateBuilder::new()
         .config(config)
         .epoch_state(epoch_state)
-        .ballot_box(ballot_box)
         .ncn(ncn)
         .epoch(epoch)
         .account_payer(account_payer)
@@ -1351,7 +1670,7 @@ pub async fn create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<()> {
         handler,
         &realloc_ixs,
         &[],
-        "Reallocated Ballot Box",
+        "Reallocated Epoch State",
         &[
             format!("NCN: {:?}", ncn),
             format!("Epoch: {:?}", epoch),
@@ -1360,139 +1679,117 @@ pub async fn create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<()> {
     )
     .await?;
 
+    // Operators past MAX_OPERATORS are tracked in epoch state pages
+    let page_count = get_epoch_state(handler, epoch).await?.page_count();
+    let pages_needed = EpochStatePage::pages_needed(get_ncn(handler).await?.operator_count());
+    for page in page_count..pages_needed {
+        let (epoch_state_page, _, _) =
+            EpochStatePage::find_program_address(&handler.tip_router_program_id, &ncn, epoch, page);
+
+        let mut initialize_epoch_state_page_ix = InitializeEpochStatePageBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .config(config)
+            .epoch_state_page(epoch_state_page)
+            .ncn(ncn)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .page(page)
+            .instruction();
+        initialize_epoch_state_page_ix.program_id = handler.tip_router_program_id;
+
+        send_and_log_transaction(
+            handler,
+            &[initialize_epoch_state_page_ix],
+            &[],
+            "Initialized Epoch State Page",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Epoch: {:?}", epoch),
+                format!("Page: {:?}", page),
+            ],
+        )
+        .await?;
+    }
+
     Ok(())
 }
 
-pub async fn operator_cast_vote(
-    handler: &CliHandler,
-    operator: &Pubkey,
-    epoch: u64,
-    meta_merkle_root: [u8; 32],
-) -> Result<()> {
-    let keypair = handler.keypair();
-
+pub async fn create_weight_table(handler: &CliHandler, epoch: u64) -> Result<()> {
     let ncn = *handler.ncn()?;
 
-    let operator = *operator;
-
     let (config, _, _) =
         TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (ballot_box, _, _) =
-        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (epoch_snapshot, _, _) =
-        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
-        &handler.tip_router_program_id,
-        &operator,
-        &ncn,
-        epoch,
-    );
-
-    let mut cast_vote_ix = CastVoteBuilder::new()
-        .config(config)
-        .epoch_state(epoch_state)
-        .ballot_box(ballot_box)
-        .ncn(ncn)
-        .epoch_snapshot(epoch_snapshot)
-        .operator_snapshot(operator_snapshot)
-        .operator(operator)
-        .operator_voter(keypair.pubkey())
-        .meta_merkle_root(meta_merkle_root)
-        .epoch(epoch)
-        .instruction();
-    cast_vote_ix.program_id = handler.tip_router_program_id;
-
-    send_and_log_transaction(
-        handler,
-        &[cast_vote_ix],
-        &[],
-        "Cast Vote",
-        &[
-            format!("NCN: {:?}", ncn),
-            format!("Operator: {:?}", operator),
-            format!("Meta Merkle Root: {:?}", meta_merkle_root),
-            format!("Epoch: {:?}", epoch),
-        ],
-    )
-    .await?;
-
-    Ok(())
-}
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
 
-pub async fn create_base_reward_router(handler: &CliHandler, epoch: u64) -> Result<()> {
-    let ncn = *handler.ncn()?;
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
     let (epoch_state, _, _) =
         EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let (base_reward_router, _, _) =
-        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (base_reward_receiver, _, _) =
-        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
     let (account_payer, _, _) =
         AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
     let (epoch_marker, _, _) =
         EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
 
-    let base_reward_router_account = get_account(handler, &base_reward_router).await?;
+    let weight_table_account = get_account(handler, &weight_table).await?;
 
-    // Skip if base reward router already exists
-    if base_reward_router_account.is_none() {
-        let mut initialize_base_reward_router_ix = InitializeBaseRewardRouterBuilder::new()
+    // Skip if weight table already exists
+    if weight_table_account.is_none() {
+        // Initialize weight table
+        let mut initialize_weight_table_ix = InitializeWeightTableBuilder::new()
             .epoch_marker(epoch_marker)
+            .vault_registry(vault_registry)
             .ncn(ncn)
             .epoch_state(epoch_state)
-            .base_reward_router(base_reward_router)
-            .base_reward_receiver(base_reward_receiver)
+            .weight_table(weight_table)
             .account_payer(account_payer)
             .system_program(system_program::id())
             .epoch(epoch)
             .instruction();
-        initialize_base_reward_router_ix.program_id = handler.tip_router_program_id;
+        initialize_weight_table_ix.program_id = handler.tip_router_program_id;
 
         send_and_log_transaction(
             handler,
-            &[initialize_base_reward_router_ix],
+            &[initialize_weight_table_ix],
             &[],
-            "Initialized Base Reward Router",
+            "Initialized Weight Table",
             &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
         )
         .await?;
     }
 
-    // Number of reallocations needed based on BaseRewardRouter::SIZE
-    let num_reallocs = (BaseRewardRouter::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+    // Number of reallocations needed based on WeightTable::SIZE
+    let num_reallocs = (WeightTable::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
 
-    let mut realloc_base_reward_router_ix = ReallocBaseRewardRouterBuilder::new()
-        .config(TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0)
-        .epoch_state(epoch_state)
-        .base_reward_router(base_reward_router)
+    // Realloc weight table
+    let mut realloc_weight_table_ix = ReallocWeightTableBuilder::new()
+        .config(config)
+        .weight_table(weight_table)
         .ncn(ncn)
+        .epoch_state(epoch_state)
+        .vault_registry(vault_registry)
         .epoch(epoch)
         .account_payer(account_payer)
         .system_program(system_program::id())
         .instruction();
-    realloc_base_reward_router_ix.program_id = handler.tip_router_program_id;
+    realloc_weight_table_ix.program_id = handler.tip_router_program_id;
 
     let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
     realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
     for _ in 0..num_reallocs {
-        realloc_ixs.push(realloc_base_reward_router_ix.clone());
+        realloc_ixs.push(realloc_weight_table_ix.clone());
     }
 
     send_and_log_transaction(
         handler,
         &realloc_ixs,
         &[],
-        "Reallocated Base Reward Router",
+        "Reallocated Weight Table",
         &[
             format!("NCN: {:?}", ncn),
             format!("Epoch: {:?}", epoch),
@@ -1501,292 +1798,257 @@ pub async fn create_base_reward_router(handler: &CliHandler, epoch: u64) -> Resu
     )
     .await?;
 
+    create_weight_table_pages(handler, epoch).await?;
+
     Ok(())
 }
 
-pub async fn create_ncn_reward_router(
-    handler: &CliHandler,
-    ncn_fee_group: NcnFeeGroup,
-    operator: &Pubkey,
-    epoch: u64,
-) -> Result<()> {
+/// Initializes the weight table pages for the vaults past MAX_VAULTS, in order
+pub async fn create_weight_table_pages(handler: &CliHandler, epoch: u64) -> Result<()> {
     let ncn = *handler.ncn()?;
 
-    let operator = *operator;
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
     let (epoch_state, _, _) =
         EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
-        &handler.tip_router_program_id,
-        &operator,
-        &ncn,
-        epoch,
-    );
-
-    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
-        &handler.tip_router_program_id,
-        ncn_fee_group,
-        &operator,
-        &ncn,
-        epoch,
-    );
-
-    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
-        &handler.tip_router_program_id,
-        ncn_fee_group,
-        &operator,
-        &ncn,
-        epoch,
-    );
-
     let (account_payer, _, _) =
         AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
     let (epoch_marker, _, _) =
-        EpochMarker::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let mut initialize_ncn_reward_router_ix = InitializeNcnRewardRouterBuilder::new()
-        .epoch_marker(epoch_marker)
-        .epoch_state(epoch_state)
-        .ncn(ncn)
-        .operator(operator)
-        .operator_snapshot(operator_snapshot)
-        .ncn_reward_router(ncn_reward_router)
-        .ncn_reward_receiver(ncn_reward_receiver)
-        .account_payer(account_payer)
-        .system_program(system_program::id())
-        .ncn_fee_group(ncn_fee_group.group)
-        .epoch(epoch)
-        .instruction();
-    initialize_ncn_reward_router_ix.program_id = handler.tip_router_program_id;
-
-    send_and_log_transaction(
-        handler,
-        &[initialize_ncn_reward_router_ix],
-        &[],
-        "Initialized NCN Reward Router",
-        &[
-            format!("NCN: {:?}", ncn),
-            format!("Operator: {:?}", operator),
-            format!("NCN Fee Group: {:?}", ncn_fee_group.group),
-            format!("Epoch: {:?}", epoch),
-        ],
-    )
-    .await?;
+        EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
 
-    Ok(())
-}
+    let weight_table_account = get_weight_table(handler, epoch).await?;
+    for page in weight_table_account.pages_initialized()..weight_table_account.page_count() {
+        let (vault_registry_page, _, _) =
+            VaultRegistryPage::find_program_address(&handler.tip_router_program_id, &ncn, page);
 
-pub async fn route_base_rewards(handler: &CliHandler, epoch: u64) -> Result<()> {
-    let ncn = *handler.ncn()?;
+        let (weight_table_page, _, _) = WeightTablePage::find_program_address(
+            &handler.tip_router_program_id,
+            &ncn,
+            epoch,
+            page,
+        );
 
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let config = TipRouterConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-
-    let (epoch_snapshot, _, _) =
-        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (ballot_box, _, _) =
-        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (base_reward_router, _, _) =
-        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (base_reward_receiver, _, _) =
-        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    // Using max iterations defined in BaseRewardRouter
-    let max_iterations: u16 = BaseRewardRouter::MAX_ROUTE_BASE_ITERATIONS;
-
-    let mut still_routing = true;
-    while still_routing {
-        let mut route_base_rewards_ix = RouteBaseRewardsBuilder::new()
+        let mut initialize_weight_table_page_ix = InitializeWeightTablePageBuilder::new()
+            .epoch_marker(epoch_marker)
             .epoch_state(epoch_state)
-            .config(config)
+            .vault_registry_page(vault_registry_page)
             .ncn(ncn)
-            .epoch_snapshot(epoch_snapshot)
-            .ballot_box(ballot_box)
-            .base_reward_router(base_reward_router)
-            .base_reward_receiver(base_reward_receiver)
-            .max_iterations(max_iterations)
+            .weight_table(weight_table)
+            .weight_table_page(weight_table_page)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
             .epoch(epoch)
+            .page(page)
             .instruction();
-        route_base_rewards_ix.program_id = handler.tip_router_program_id;
-
-        let instructions = vec![
-            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
-            route_base_rewards_ix,
-        ];
+        initialize_weight_table_page_ix.program_id = handler.tip_router_program_id;
 
         send_and_log_transaction(
             handler,
-            &instructions,
+            &[initialize_weight_table_page_ix],
             &[],
-            "Routed Base Rewards",
+            "Initialized Weight Table Page",
             &[
                 format!("NCN: {:?}", ncn),
                 format!("Epoch: {:?}", epoch),
-                format!("Max iterations: {:?}", max_iterations),
+                format!("Page: {:?}", page),
             ],
         )
         .await?;
-
-        // Check if we need to continue routing
-        let base_reward_router_account = get_base_reward_router(handler, epoch).await?;
-        still_routing = base_reward_router_account.still_routing();
     }
 
     Ok(())
 }
 
-pub async fn route_ncn_rewards(
-    handler: &CliHandler,
-    operator: &Pubkey,
-    ncn_fee_group: NcnFeeGroup,
-    epoch: u64,
-) -> Result<()> {
-    let ncn = *handler.ncn()?;
+#[allow(deprecated)]
+pub async fn crank_switchboard(handler: &CliHandler, switchboard_feed: &Pubkey) -> Result<()> {
+    async fn wait_for_x_slots_after_epoch(handler: &CliHandler, slots: u64) -> Result<()> {
+        loop {
+            let current_slot = handler.rpc_client().get_slot().await?;
+            if current_slot % DEFAULT_SLOTS_PER_EPOCH > slots {
+                break;
+            }
+            sleep(Duration::from_millis(500)).await;
+        }
+        Ok(())
+    }
 
-    let operator = *operator;
+    let switchboard_context = handler.switchboard_context();
 
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    let client = handler.rpc_client();
+    let payer = handler.keypair();
 
-    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
-        &handler.tip_router_program_id,
-        &operator,
-        &ncn,
-        epoch,
-    );
+    if switchboard_feed.eq(&Pubkey::default()) {
+        return Ok(());
+    }
 
-    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
-        &handler.tip_router_program_id,
-        ncn_fee_group,
-        &operator,
-        &ncn,
-        epoch,
-    );
+    wait_for_x_slots_after_epoch(handler, 400).await?;
 
-    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
-        &handler.tip_router_program_id,
-        ncn_fee_group,
-        &operator,
-        &ncn,
-        epoch,
-    );
+    // STATIC PUBKEY
+    let queue_key = SWITCHBOARD_QUEUE;
 
-    // Using max iterations defined in NcnRewardRouter
-    let max_iterations: u16 = NcnRewardRouter::MAX_ROUTE_NCN_ITERATIONS;
+    let mut queue_account = client
+        .get_account_with_commitment(&queue_key, handler.commitment)
+        .await?
+        .value
+        .expect("Queue account not found");
+    let queue_account_info = AccountInfo {
+        key: &queue_key,
+        is_signer: false,
+        is_writable: true,
+        owner: &queue_account.owner,
+        lamports: Rc::new(RefCell::new(&mut queue_account.lamports)),
+        data: Rc::new(RefCell::new(&mut queue_account.data)),
+        executable: false,
+        _unused: 0,
+    };
 
-    let mut still_routing = true;
-    while still_routing {
-        let mut route_ncn_rewards_ix = RouteNcnRewardsBuilder::new()
-            .epoch_state(epoch_state)
-            .ncn(ncn)
-            .operator(operator)
-            .operator_snapshot(operator_snapshot)
-            .ncn_reward_router(ncn_reward_router)
-            .ncn_reward_receiver(ncn_reward_receiver)
-            .ncn_fee_group(ncn_fee_group.group)
-            .max_iterations(max_iterations)
-            .epoch(epoch)
-            .instruction();
-        route_ncn_rewards_ix.program_id = handler.tip_router_program_id;
+    let queue = *QueueAccountData::new(&queue_account_info)?;
 
-        let instructions = vec![
-            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
-            route_ncn_rewards_ix,
-        ];
+    let gateways = &queue.fetch_gateways(client).await?;
 
-        send_and_log_transaction(
-            handler,
-            &instructions,
-            &[],
-            "Routed NCN Rewards",
-            &[
-                format!("NCN: {:?}", ncn),
-                format!("Operator: {:?}", operator),
-                format!("NCN Fee Group: {:?}", ncn_fee_group.group),
-                format!("Epoch: {:?}", epoch),
-                format!("Max iterations: {:?}", max_iterations),
-            ],
-        )
-        .await?;
+    if gateways.is_empty() {
+        return Err(anyhow!("No gateways found"));
+    }
 
-        // Check if we need to continue routing
-        let ncn_reward_router_account =
-            get_ncn_reward_router(handler, ncn_fee_group, &operator, epoch).await?;
-        still_routing = ncn_reward_router_account.still_routing();
+    let crossbar = CrossbarClient::default();
+    let mut update_ix = None;
+    let mut last_error = None;
+    for (index, gateway) in gateways.iter().enumerate() {
+        log::info!(
+            "Fetching Switchboard update attempt={}/{} gateway={}",
+            index + 1,
+            gateways.len(),
+            gateway.url()
+        );
+
+        match PullFeed::fetch_update_ix(
+            switchboard_context.clone(),
+            client,
+            FetchUpdateParams {
+                feed: *switchboard_feed,
+                payer: payer.pubkey(),
+                gateway: gateway.clone(),
+                crossbar: Some(crossbar.clone()),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            std::result::Result::Ok((ix, _, _, _)) => {
+                update_ix = Some(ix);
+                break;
+            }
+            Err(error) => {
+                log::warn!(
+                    "Failed to fetch Switchboard update gateway={}: {:?}",
+                    gateway.url(),
+                    error
+                );
+                last_error = Some(error);
+            }
+        }
     }
+    let ix = update_ix.ok_or_else(|| {
+        anyhow!(
+            "Failed to fetch Switchboard update from all {} gateways. Last error: {:?}",
+            gateways.len(),
+            last_error
+        )
+    })?;
+
+    send_and_log_transaction(
+        handler,
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            ix,
+        ],
+        &[],
+        "Crank Switchboard",
+        &[format!("FEED: {:?}", switchboard_feed)],
+    )
+    .await?;
 
     Ok(())
 }
 
-pub async fn distribute_base_ncn_rewards(
+pub async fn set_weight(handler: &CliHandler, vault: &Pubkey, epoch: u64) -> Result<()> {
+    let vault_account = get_vault(handler, vault).await?;
+
+    set_weight_with_st_mint(handler, &vault_account.supported_mint, epoch).await
+}
+
+pub async fn set_weight_with_st_mint(
     handler: &CliHandler,
-    operator: &Pubkey,
-    ncn_fee_group: NcnFeeGroup,
+    st_mint: &Pubkey,
     epoch: u64,
 ) -> Result<()> {
     let ncn = *handler.ncn()?;
 
-    let operator = *operator;
+    let vault_registry = get_vault_registry(handler).await?;
+
+    let mint_entry = vault_registry.get_mint_entry(st_mint)?;
+    let oracle_type = mint_entry.oracle_type()?;
+    let oracle_feed = mint_entry.switchboard_feed();
 
     let (epoch_state, _, _) =
         EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let (ncn_config, _, _) =
-        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
-
-    let (base_reward_router, _, _) =
-        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let (base_reward_receiver, _, _) =
-        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    match oracle_type {
+        OracleType::Switchboard => {
+            // Crank Switchboard
+            let result = crank_switchboard(handler, oracle_feed).await;
+            if let Err(e) = result {
+                let switchboard_feed = format!(
+                    "https://ondemand.switchboard.xyz/solana/mainnet/feed/{}",
+                    oracle_feed
+                );
+                log::error!(
+                    "Failed to crank Switchboard, manual crank needed feed={}: {:#}",
+                    switchboard_feed,
+                    e
+                );
+            }
+        }
+        OracleType::Pyth => {
+            // Pyth price feed accounts are kept fresh by the Pyth price pusher
+            log::info!("Using Pyth price feed {}", oracle_feed);
+        }
+        OracleType::SplStakePool => {
+            // The exchange rate is read from the stake pool, which is updated by its own crank
+            log::info!("Using SPL stake pool {}", oracle_feed);
+        }
+    }
 
-    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
-        &handler.tip_router_program_id,
-        ncn_fee_group,
-        &operator,
-        &ncn,
-        epoch,
-    );
+    let (vault_registry, _, _) =
+        VaultRegistry::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
-        &handler.tip_router_program_id,
-        ncn_fee_group,
-        &operator,
-        &ncn,
-        epoch,
-    );
-
-    let mut distribute_base_ncn_rewards_ix = DistributeBaseNcnRewardRouteBuilder::new()
-        .epoch_state(epoch_state)
-        .config(ncn_config)
+    let mut set_weight_ix = OracleSetWeightBuilder::new()
         .ncn(ncn)
-        .operator(operator)
-        .base_reward_router(base_reward_router)
-        .base_reward_receiver(base_reward_receiver)
-        .ncn_reward_router(ncn_reward_router)
-        .ncn_reward_receiver(ncn_reward_receiver)
-        .system_program(system_program::id())
-        .ncn_fee_group(ncn_fee_group.group)
+        .weight_table(weight_table)
+        .epoch_state(epoch_state)
+        .st_mint(*st_mint)
+        .oracle_feed(*oracle_feed)
+        .vault_registry(Some(vault_registry))
         .epoch(epoch)
         .instruction();
-    distribute_base_ncn_rewards_ix.program_id = handler.tip_router_program_id;
+    set_weight_ix.program_id = handler.tip_router_program_id;
 
     send_and_log_transaction(
         handler,
-        &[distribute_base_ncn_rewards_ix],
+        &[set_weight_ix],
         &[],
-        "Distributed Base NCN Rewards",
+        "Set Weight Using Oracle Feed",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Operator: {:?}", operator),
-            format!("NCN Fee Group: {:?}", ncn_fee_group.group),
             format!("Epoch: {:?}", epoch),
+            format!("ST Mint: {:?}", st_mint),
+            format!("Oracle Type: {:?}", oracle_type),
+            format!("Oracle Feed: {:?}", oracle_feed),
         ],
     )
     .await?;
@@ -1794,387 +2056,404 @@ pub async fn distribute_base_ncn_rewards(
     Ok(())
 }
 
-pub async fn distribute_base_rewards(
-    handler: &CliHandler,
-    base_fee_group: BaseFeeGroup,
-    epoch: u64,
-) -> Result<()> {
-    let keypair = handler.keypair();
+pub async fn create_epoch_snapshot(handler: &CliHandler, epoch: u64) -> Result<()> {
     let ncn = *handler.ncn()?;
 
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (ncn_config, _, _) =
+    let (config, _, _) =
         TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    let (base_reward_router, _, _) =
-        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let (base_reward_receiver, _, _) =
-        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let tip_router_config = get_tip_router_config(handler).await?;
-    let base_fee_wallet = tip_router_config
-        .fee_config
-        .base_fee_wallet(base_fee_group)?;
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let stake_pool_accounts = get_stake_pool_accounts(handler).await?;
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let base_fee_wallet_ata =
-        get_associated_token_address(base_fee_wallet, &stake_pool_accounts.stake_pool.pool_mint);
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let create_base_fee_wallet_ata_ix =
-        spl_associated_token_account_interface::instruction::create_associated_token_account_idempotent(
-            &keypair.pubkey(),
-            base_fee_wallet,
-            &stake_pool_accounts.stake_pool.pool_mint,
-            &handler.token_program_id,
-        );
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
 
-    let mut distribute_base_ncn_rewards_ix = DistributeBaseRewardsBuilder::new()
-        .epoch_state(epoch_state)
-        .config(ncn_config)
+    let mut initialize_epoch_snapshot_ix = InitializeEpochSnapshotBuilder::new()
+        .epoch_marker(epoch_marker)
+        .config(config)
         .ncn(ncn)
-        .base_reward_router(base_reward_router)
-        .base_reward_receiver(base_reward_receiver)
+        .epoch_state(epoch_state)
+        .weight_table(weight_table)
+        .epoch_snapshot(epoch_snapshot)
+        .account_payer(account_payer)
         .system_program(system_program::id())
         .epoch(epoch)
-        .base_fee_wallet(*base_fee_wallet)
-        .base_fee_wallet_ata(base_fee_wallet_ata)
-        .base_fee_group(base_fee_group.group)
-        .pool_mint(stake_pool_accounts.stake_pool.pool_mint)
-        .manager_fee_account(stake_pool_accounts.stake_pool.manager_fee_account)
-        .referrer_pool_tokens_account(stake_pool_accounts.referrer_pool_tokens_account)
-        .reserve_stake(stake_pool_accounts.stake_pool.reserve_stake)
-        .stake_pool(stake_pool_accounts.stake_pool_address)
-        .stake_pool_withdraw_authority(stake_pool_accounts.stake_pool_withdraw_authority)
-        .stake_pool_program(stake_pool_accounts.stake_pool_program_id)
         .instruction();
-    distribute_base_ncn_rewards_ix.program_id = handler.tip_router_program_id;
+    initialize_epoch_snapshot_ix.program_id = handler.tip_router_program_id;
 
-    let result = send_and_log_transaction(
+    send_and_log_transaction(
         handler,
-        &[
-            create_base_fee_wallet_ata_ix,
-            distribute_base_ncn_rewards_ix,
-        ],
+        &[initialize_epoch_snapshot_ix],
         &[],
-        "Distributed Base Rewards",
-        &[
-            format!("NCN: {:?}", ncn),
-            format!("Base Fee Group: {:?}", base_fee_group.group),
-            format!("Epoch: {:?}", epoch),
-        ],
+        "Initialized Epoch Snapshot",
+        &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
     )
-    .await;
-
-    if let Err(err) = result {
-        let error_message = format!("{:?}", err);
-        recover_from_low_balance_deposit_base(handler, epoch, &error_message).await?;
-
-        return Err(err);
-    }
+    .await?;
 
     Ok(())
 }
 
-pub async fn distribute_ncn_vault_rewards(
+pub async fn create_operator_snapshot(
     handler: &CliHandler,
-    vault: &Pubkey,
     operator: &Pubkey,
-    ncn_fee_group: NcnFeeGroup,
     epoch: u64,
 ) -> Result<()> {
-    let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
 
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    let operator = *operator;
 
-    let (ncn_config, _, _) =
+    let (config, _, _) =
         TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
-        &handler.tip_router_program_id,
-        ncn_fee_group,
-        operator,
-        &ncn,
-        epoch,
-    );
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
-        &handler.tip_router_program_id,
-        ncn_fee_group,
-        operator,
-        &ncn,
-        epoch,
-    );
+    let (ncn_operator_state, _, _) =
+        NcnOperatorState::find_program_address(&handler.restaking_program_id, &ncn, &operator);
+
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
     let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
         &handler.tip_router_program_id,
-        operator,
+        &operator,
         &ncn,
         epoch,
     );
 
-    let stake_pool_accounts = get_stake_pool_accounts(handler).await?;
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
 
-    let vault = *vault;
-    let vault_ata = get_associated_token_address(&vault, &stake_pool_accounts.stake_pool.pool_mint);
+    let operator_snapshot_account = get_account(handler, &operator_snapshot).await?;
 
-    let create_vault_ata_ix =
-        spl_associated_token_account_interface::instruction::create_associated_token_account_idempotent(
-            &keypair.pubkey(),
-            &vault,
-            &stake_pool_accounts.stake_pool.pool_mint,
-            &handler.token_program_id,
-        );
+    // Skip if operator snapshot already exists
+    if operator_snapshot_account.is_none() {
+        // Initialize operator snapshot
+        let mut initialize_operator_snapshot_ix = InitializeOperatorSnapshotBuilder::new()
+            .epoch_marker(epoch_marker)
+            .config(config)
+            .ncn(ncn)
+            .operator(operator)
+            .epoch_state(epoch_state)
+            .ncn_operator_state(ncn_operator_state)
+            .epoch_snapshot(epoch_snapshot)
+            .operator_snapshot(operator_snapshot)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .instruction();
+        initialize_operator_snapshot_ix.program_id = handler.tip_router_program_id;
 
-    let mut distribute_ncn_vault_rewards_ix = DistributeNcnVaultRewardsBuilder::new()
-        .epoch_state(epoch_state)
-        .config(ncn_config)
+        send_and_log_transaction(
+            handler,
+            &[initialize_operator_snapshot_ix],
+            &[],
+            "Initialized Operator Snapshot",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Operator: {:?}", operator),
+                format!("Epoch: {:?}", epoch),
+            ],
+        )
+        .await?;
+    }
+
+    // Number of reallocations needed based on OperatorSnapshot::SIZE
+    let num_reallocs = (OperatorSnapshot::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+
+    let epoch_state_page = get_epoch_state_page_for_operator(handler, &operator, epoch).await?;
+
+    // Realloc operator snapshot
+    let mut realloc_operator_snapshot_ix = ReallocOperatorSnapshotBuilder::new()
+        .config(config)
+        .restaking_config(RestakingConfig::find_program_address(&handler.restaking_program_id).0)
         .ncn(ncn)
-        .operator(*operator)
-        .vault(vault)
-        .vault_ata(vault_ata)
+        .operator(operator)
+        .epoch_state(epoch_state)
+        .ncn_operator_state(ncn_operator_state)
+        .epoch_snapshot(epoch_snapshot)
         .operator_snapshot(operator_snapshot)
-        .ncn_reward_router(ncn_reward_router)
-        .ncn_reward_receiver(ncn_reward_receiver)
-        .pool_mint(stake_pool_accounts.stake_pool.pool_mint)
-        .manager_fee_account(stake_pool_accounts.stake_pool.manager_fee_account)
-        .referrer_pool_tokens_account(stake_pool_accounts.referrer_pool_tokens_account)
-        .reserve_stake(stake_pool_accounts.stake_pool.reserve_stake)
-        .stake_pool(stake_pool_accounts.stake_pool_address)
-        .stake_pool_withdraw_authority(stake_pool_accounts.stake_pool_withdraw_authority)
-        .stake_pool_program(stake_pool_accounts.stake_pool_program_id)
-        .token_program(handler.token_program_id)
+        .account_payer(account_payer)
         .system_program(system_program::id())
-        .ncn_fee_group(ncn_fee_group.group)
+        .epoch_state_page(epoch_state_page)
         .epoch(epoch)
         .instruction();
-    distribute_ncn_vault_rewards_ix.program_id = handler.tip_router_program_id;
-
-    let vault_account = get_vault(handler, &vault).await?;
-    let st_mint = vault_account.supported_mint;
-    let vrt_mint = vault_account.vrt_mint;
-    let vault_fee_wallet = vault_account.fee_wallet;
-
-    let vault_fee_token_account = get_associated_token_address(&vault_fee_wallet, &vrt_mint);
-    let vault_token_account = get_associated_token_address(&vault, &st_mint);
-
-    let (vault_config, _, _) = VaultConfig::find_program_address(&handler.vault_program_id);
+    realloc_operator_snapshot_ix.program_id = handler.tip_router_program_id;
 
-    let update_vault_balance_ix = UpdateVaultBalanceBuilder::new()
-        .config(vault_config)
-        .vault(vault)
-        .token_program(spl_token_interface::id())
-        .vault_fee_token_account(vault_fee_token_account)
-        .vault_token_account(vault_token_account)
-        .vrt_mint(vrt_mint)
-        .instruction();
+    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
+    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
+    for _ in 0..num_reallocs {
+        realloc_ixs.push(realloc_operator_snapshot_ix.clone());
+    }
 
-    let result = send_and_log_transaction(
+    send_and_log_transaction(
         handler,
-        &[
-            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
-            create_vault_ata_ix,
-            distribute_ncn_vault_rewards_ix,
-            update_vault_balance_ix,
-        ],
+        &realloc_ixs,
         &[],
-        "Distributed NCN Vault Rewards",
+        "Reallocated Operator Snapshot",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Vault: {:?}", vault),
             format!("Operator: {:?}", operator),
-            format!("NCN Fee Group: {:?}", ncn_fee_group.group),
             format!("Epoch: {:?}", epoch),
+            format!("Number of reallocations: {:?}", num_reallocs),
         ],
     )
-    .await;
+    .await?;
 
-    if let Err(err) = result {
-        let error_message = format!("{:?}", err);
-        recover_from_low_balance_deposit_ncn(
-            handler,
-            operator,
-            ncn_fee_group,
-            epoch,
-            &error_message,
-        )
-        .await?;
-
-        return Err(err);
-    }
+    create_operator_snapshot_pages(handler, &operator, epoch).await?;
 
     Ok(())
 }
 
-pub async fn distribute_ncn_operator_rewards(
+/// Initializes the operator snapshot pages for the delegations from vaults past MAX_VAULTS, in
+/// order
+pub async fn create_operator_snapshot_pages(
     handler: &CliHandler,
     operator: &Pubkey,
-    ncn_fee_group: NcnFeeGroup,
     epoch: u64,
 ) -> Result<()> {
-    let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
 
+    let operator = *operator;
+
     let (epoch_state, _, _) =
         EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let (ncn_config, _, _) =
-        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
-
-    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
         &handler.tip_router_program_id,
-        ncn_fee_group,
-        operator,
+        &operator,
         &ncn,
         epoch,
     );
 
-    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
-        &handler.tip_router_program_id,
-        ncn_fee_group,
-        operator,
-        &ncn,
-        epoch,
-    );
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+    // Pages are only needed while the operator snapshot takes delegations
+    let operator_snapshot_account = get_operator_snapshot(handler, &operator, epoch).await?;
+    if !operator_snapshot_account.finalized() {
+        let pages_needed = get_vault_registry(handler).await?.page_count();
+        for page in operator_snapshot_account.page_count()..pages_needed {
+            let (operator_snapshot_page, _, _) = OperatorSnapshotPage::find_program_address(
+                &handler.tip_router_program_id,
+                &operator,
+                &ncn,
+                epoch,
+                page,
+            );
+
+            let mut initialize_operator_snapshot_page_ix =
+                InitializeOperatorSnapshotPageBuilder::new()
+                    .epoch_marker(epoch_marker)
+                    .epoch_state(epoch_state)
+                    .ncn(ncn)
+                    .operator(operator)
+                    .operator_snapshot(operator_snapshot)
+                    .operator_snapshot_page(operator_snapshot_page)
+                    .account_payer(account_payer)
+                    .system_program(system_program::id())
+                    .epoch(epoch)
+                    .page(page)
+                    .instruction();
+            initialize_operator_snapshot_page_ix.program_id = handler.tip_router_program_id;
+
+            send_and_log_transaction(
+                handler,
+                &[initialize_operator_snapshot_page_ix],
+                &[],
+                "Initialized Operator Snapshot Page",
+                &[
+                    format!("NCN: {:?}", ncn),
+                    format!("Operator: {:?}", operator),
+                    format!("Epoch: {:?}", epoch),
+                    format!("Page: {:?}", page),
+                ],
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The operator snapshot pages of the operator, in order, as remaining accounts
+async fn get_operator_snapshot_page_metas(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<Vec<AccountMeta>> {
+    let ncn = *handler.ncn()?;
+    let operator_snapshot_account = get_operator_snapshot(handler, operator, epoch).await?;
+
+    Ok((0..operator_snapshot_account.page_count())
+        .map(|page| {
+            let (operator_snapshot_page, _, _) = OperatorSnapshotPage::find_program_address(
+                &handler.tip_router_program_id,
+                operator,
+                &ncn,
+                epoch,
+                page,
+            );
+            AccountMeta::new_readonly(operator_snapshot_page, false)
+        })
+        .collect())
+}
+
+pub async fn snapshot_vault_operator_delegation(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let vault = *vault;
+    let operator = *operator;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (restaking_config, _, _) =
+        RestakingConfig::find_program_address(&handler.restaking_program_id);
+
+    let (vault_ncn_ticket, _, _) =
+        VaultNcnTicket::find_program_address(&handler.vault_program_id, &vault, &ncn);
+
+    let (ncn_vault_ticket, _, _) =
+        NcnVaultTicket::find_program_address(&handler.restaking_program_id, &ncn, &vault);
+
+    let (vault_operator_delegation, _, _) =
+        VaultOperatorDelegation::find_program_address(&handler.vault_program_id, &vault, &operator);
+
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
     let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
         &handler.tip_router_program_id,
-        operator,
+        &operator,
         &ncn,
         epoch,
     );
 
-    let stake_pool_accounts = get_stake_pool_accounts(handler).await?;
-
-    let operator_ata =
-        get_associated_token_address(operator, &stake_pool_accounts.stake_pool.pool_mint);
+    // Vaults past MAX_VAULTS are snapshotted from their weight table page into their operator
+    // snapshot page
+    let vault_page = get_vault_page(handler, &vault).await?;
+    let weight_table_page = vault_page.map(|page| {
+        WeightTablePage::find_program_address(&handler.tip_router_program_id, &ncn, epoch, page).0
+    });
+    let operator_snapshot_page = vault_page.map(|page| {
+        OperatorSnapshotPage::find_program_address(
+            &handler.tip_router_program_id,
+            &operator,
+            &ncn,
+            epoch,
+            page,
+        )
+        .0
+    });
 
-    let create_operator_ata_ix =
-        spl_associated_token_account_interface::instruction::create_associated_token_account_idempotent(
-            &keypair.pubkey(),
-            operator,
-            &stake_pool_accounts.stake_pool.pool_mint,
-            &handler.token_program_id,
-        );
+    let epoch_state_page = get_epoch_state_page_for_operator(handler, &operator, epoch).await?;
 
-    let mut distribute_ncn_operator_rewards_ix = DistributeNcnOperatorRewardsBuilder::new()
+    let mut snapshot_vault_operator_delegation_ix = SnapshotVaultOperatorDelegationBuilder::new()
+        .config(config)
         .epoch_state(epoch_state)
-        .config(ncn_config)
+        .restaking_config(restaking_config)
         .ncn(ncn)
-        .operator(*operator)
-        .operator_ata(operator_ata)
+        .operator(operator)
+        .vault(vault)
+        .vault_ncn_ticket(vault_ncn_ticket)
+        .ncn_vault_ticket(ncn_vault_ticket)
+        .vault_operator_delegation(vault_operator_delegation)
+        .weight_table(weight_table)
+        .epoch_snapshot(epoch_snapshot)
         .operator_snapshot(operator_snapshot)
-        .ncn_reward_router(ncn_reward_router)
-        .ncn_reward_receiver(ncn_reward_receiver)
-        .pool_mint(stake_pool_accounts.stake_pool.pool_mint)
-        .manager_fee_account(stake_pool_accounts.stake_pool.manager_fee_account)
-        .referrer_pool_tokens_account(stake_pool_accounts.referrer_pool_tokens_account)
-        .reserve_stake(stake_pool_accounts.stake_pool.reserve_stake)
-        .stake_pool(stake_pool_accounts.stake_pool_address)
-        .stake_pool_withdraw_authority(stake_pool_accounts.stake_pool_withdraw_authority)
-        .stake_pool_program(stake_pool_accounts.stake_pool_program_id)
-        .token_program(handler.token_program_id)
-        .system_program(system_program::id())
-        .ncn_fee_group(ncn_fee_group.group)
+        .weight_table_page(weight_table_page)
+        .operator_snapshot_page(operator_snapshot_page)
+        .epoch_state_page(epoch_state_page)
         .epoch(epoch)
         .instruction();
-    distribute_ncn_operator_rewards_ix.program_id = handler.tip_router_program_id;
+    snapshot_vault_operator_delegation_ix.program_id = handler.tip_router_program_id;
 
-    let result = send_and_log_transaction(
+    send_and_log_transaction(
         handler,
-        &[create_operator_ata_ix, distribute_ncn_operator_rewards_ix],
+        &[snapshot_vault_operator_delegation_ix],
         &[],
-        "Distributed NCN Operator Rewards",
+        "Snapshotted Vault Operator Delegation",
         &[
             format!("NCN: {:?}", ncn),
+            format!("Vault: {:?}", vault),
             format!("Operator: {:?}", operator),
-            format!("NCN Fee Group: {:?}", ncn_fee_group.group),
             format!("Epoch: {:?}", epoch),
         ],
     )
-    .await;
-
-    if let Err(err) = result {
-        let error_message = format!("{:?}", err);
-        recover_from_low_balance_deposit_ncn(
-            handler,
-            operator,
-            ncn_fee_group,
-            epoch,
-            &error_message,
-        )
-        .await?;
-
-        return Err(err);
-    }
+    .await?;
 
     Ok(())
 }
 
-pub async fn recover_from_low_balance_deposit_base(
+pub async fn apply_stake_weight_caps(
     handler: &CliHandler,
+    operator: &Pubkey,
     epoch: u64,
-    error_message: &String,
 ) -> Result<()> {
-    let ncn = handler.ncn()?;
+    let ncn = *handler.ncn()?;
 
-    let (ncn_reward_receiver, _, _) =
-        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, ncn, epoch);
+    let operator = *operator;
 
-    recover_from_low_balance_deposit(handler, &ncn_reward_receiver, error_message).await
-}
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-pub async fn recover_from_low_balance_deposit_ncn(
-    handler: &CliHandler,
-    operator: &Pubkey,
-    ncn_fee_group: NcnFeeGroup,
-    epoch: u64,
-    error_message: &String,
-) -> Result<()> {
-    let ncn = handler.ncn()?;
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
         &handler.tip_router_program_id,
-        ncn_fee_group,
-        operator,
-        ncn,
+        &operator,
+        &ncn,
         epoch,
     );
 
-    recover_from_low_balance_deposit(handler, &ncn_reward_receiver, error_message).await
-}
-
-pub async fn recover_from_low_balance_deposit(
-    handler: &CliHandler,
-    to: &Pubkey,
-    error_message: &String,
-) -> Result<()> {
-    const RECOVERY_LAMPORTS: u64 = 1000;
-    const RECOVERABLE_ERROR: &str =
-        "Not enough lamports provided for deposit to result in one pool token";
-
-    if !error_message.contains(RECOVERABLE_ERROR) {
-        info!("Error not recoverable: {}", error_message);
-        return Ok(());
-    }
+    let operator_snapshot_pages =
+        get_operator_snapshot_page_metas(handler, &operator, epoch).await?;
 
-    let keypair = handler.keypair();
-    let transfer_ix = system_instruction::transfer(&keypair.pubkey(), to, RECOVERY_LAMPORTS);
+    let mut apply_stake_weight_caps_ix = ApplyStakeWeightCapsBuilder::new()
+        .epoch_state(epoch_state)
+        .ncn(ncn)
+        .epoch_snapshot(epoch_snapshot)
+        .operator(operator)
+        .operator_snapshot(operator_snapshot)
+        .epoch(epoch)
+        .add_remaining_accounts(&operator_snapshot_pages)
+        .instruction();
+    apply_stake_weight_caps_ix.program_id = handler.tip_router_program_id;
 
     send_and_log_transaction(
         handler,
-        &[transfer_ix],
+        &[apply_stake_weight_caps_ix],
         &[],
-        "Recovered From Low Balance Deposit",
+        "Applied Stake Weight Caps",
         &[
-            format!("To: {:?}", to),
-            format!("From: {:?}", keypair.pubkey()),
-            format!("Amount: {:?}", RECOVERY_LAMPORTS),
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!("Epoch: {:?}", epoch),
         ],
     )
     .await?;
@@ -2182,66 +2461,80 @@ pub async fn recover_from_low_balance_deposit(
     Ok(())
 }
 
-pub async fn close_epoch_account(
-    handler: &CliHandler,
-    ncn: Pubkey,
-    epoch: u64,
-    account_to_close: Pubkey,
-    receiver_to_close: Option<Pubkey>,
-) -> Result<()> {
-    let (epoch_marker, _, _) =
-        EpochMarker::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+pub async fn create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
     let (epoch_state, _, _) =
         EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
     let (account_payer, _, _) =
         AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
 
-    let (config, _, _) =
-        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+    let ballot_box_account = get_account(handler, &ballot_box).await?;
 
-    let account_already_closed = get_account(handler, &account_to_close)
-        .await?
-        .is_none_or(|account| account.data.is_empty() || account.lamports == 0);
-    if account_already_closed {
-        info!("Account already closed account={}", account_to_close);
-        return Ok(());
-    }
+    // Skip if ballot box already exists
+    if ballot_box_account.is_none() {
+        // Initialize ballot box
+        let mut initialize_ballot_box_ix = InitializeBallotBoxBuilder::new()
+            .epoch_marker(epoch_marker)
+            .config(config)
+            .epoch_state(epoch_state)
+            .ballot_box(ballot_box)
+            .ncn(ncn)
+            .epoch(epoch)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .instruction();
+        initialize_ballot_box_ix.program_id = handler.tip_router_program_id;
 
-    let config_account = get_tip_router_config(handler).await?;
-    let dao_wallet = *config_account
-        .fee_config
-        .base_fee_wallet(BaseFeeGroup::dao())?;
+        send_and_log_transaction(
+            handler,
+            &[initialize_ballot_box_ix],
+            &[],
+            "Initialized Ballot Box",
+            &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
+        )
+        .await?;
+    }
 
-    let mut ix = CloseEpochAccountBuilder::new();
+    // Number of reallocations needed based on BallotBox::SIZE
+    let num_reallocs = (BallotBox::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
 
-    ix.account_payer(account_payer)
-        .epoch_marker(epoch_marker)
+    // Realloc ballot box
+    let mut realloc_ballot_box_ix = ReallocBallotBoxBuilder::new()
         .config(config)
-        .account_to_close(account_to_close)
         .epoch_state(epoch_state)
+        .ballot_box(ballot_box)
         .ncn(ncn)
-        .dao_wallet(dao_wallet)
+        .epoch(epoch)
+        .account_payer(account_payer)
         .system_program(system_program::id())
-        .epoch(epoch);
+        .instruction();
+    realloc_ballot_box_ix.program_id = handler.tip_router_program_id;
 
-    if let Some(receiver_to_close) = receiver_to_close {
-        ix.receiver_to_close(Some(receiver_to_close));
+    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
+    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
+    for _ in 0..num_reallocs {
+        realloc_ixs.push(realloc_ballot_box_ix.clone());
     }
-    let mut close_epoch_account_ix = ix.instruction();
-    close_epoch_account_ix.program_id = handler.tip_router_program_id;
 
     send_and_log_transaction(
         handler,
-        &[close_epoch_account_ix],
+        &realloc_ixs,
         &[],
-        "Close Epoch Account",
+        "Reallocated Ballot Box",
         &[
             format!("NCN: {:?}", ncn),
-            format!("Account to Close: {:?}", account_to_close),
-            format!("Receiver to Close: {:?}", receiver_to_close),
             format!("Epoch: {:?}", epoch),
+            format!("Number of reallocations: {:?}", num_reallocs),
         ],
     )
     .await?;
@@ -2249,569 +2542,3643 @@ pub async fn close_epoch_account(
     Ok(())
 }
 
-// --------------------- MIDDLEWARE ------------------------------
-pub const CREATE_TIMEOUT_MS: u64 = 2000;
-pub const CREATE_GET_RETRIES: u64 = 3;
-pub async fn check_created(handler: &CliHandler, address: &Pubkey) -> Result<()> {
-    let mut retries = 0;
-    let mut account = get_account(handler, address).await?;
-    while account.is_none() && retries < CREATE_GET_RETRIES {
-        sleep(Duration::from_millis(CREATE_TIMEOUT_MS * (retries + 1))).await;
-        account = get_account(handler, address).await?;
-        retries += 1;
-    }
-
-    if account.is_none() {
-        return Err(anyhow!(
-            "Failed to get account after creation {:?}",
-            address
-        ));
-    }
-
-    Ok(())
-}
-
-pub async fn update_all_vaults_in_network(handler: &CliHandler) -> Result<()> {
-    let vaults = get_all_vaults(handler).await?;
-    for vault in vaults {
-        full_vault_update(handler, &vault).await?;
-    }
-
-    Ok(())
-}
-
-pub async fn full_vault_update(handler: &CliHandler, vault: &Pubkey) -> Result<()> {
-    let payer = handler.keypair();
-
-    // Get Epoch Info
-    let current_slot = get_current_slot(handler).await?;
-    let (ncn_epoch, epoch_length) = {
-        let vault_config = get_vault_config(handler).await?;
-        let ncn_epoch = vault_config.get_epoch_from_slot(current_slot)?;
-        let epoch_length = vault_config.epoch_length();
-        (ncn_epoch, epoch_length)
-    };
+/// Creates the ballot box pages `start_page..end_page`
+pub async fn create_ballot_box_pages(
+    handler: &CliHandler,
+    epoch: u64,
+    start_page: u64,
+    end_page: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
 
-    // Check Vault
-    let vault_account = get_vault(handler, vault).await?;
-    let current_slot = get_current_slot(handler).await?;
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    if !vault_account.is_update_needed(current_slot, epoch_length)? {
-        return Ok(());
-    }
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    // Initialize Vault Update State Tracker
-    let (vault_config, _, _) = VaultConfig::find_program_address(&handler.vault_program_id);
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let (vault_update_state_tracker, _, _) =
-        VaultUpdateStateTracker::find_program_address(&handler.vault_program_id, vault, ncn_epoch);
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let vault_update_state_tracker_account =
-        get_account(handler, &vault_update_state_tracker).await?;
+    for page in start_page..end_page {
+        let (ballot_box_page, _, _) =
+            BallotBoxPage::find_program_address(&handler.tip_router_program_id, &ncn, epoch, page);
 
-    if vault_update_state_tracker_account.is_none() {
-        let initialize_vault_update_state_tracker_ix =
-            InitializeVaultUpdateStateTrackerBuilder::new()
-                .vault(*vault)
-                .vault_update_state_tracker(vault_update_state_tracker)
-                .system_program(system_program::id())
-                .withdrawal_allocation_method(WithdrawalAllocationMethod::Greedy)
-                .payer(payer.pubkey())
-                .config(vault_config)
-                .instruction();
+        let mut initialize_ballot_box_page_ix = InitializeBallotBoxPageBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .config(config)
+            .ballot_box(ballot_box)
+            .ballot_box_page(ballot_box_page)
+            .ncn(ncn)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .page(page)
+            .instruction();
+        initialize_ballot_box_page_ix.program_id = handler.tip_router_program_id;
 
-        let result = send_and_log_transaction(
+        send_and_log_transaction(
             handler,
-            &[initialize_vault_update_state_tracker_ix],
-            &[payer],
-            "Initialize Vault Update State Tracker",
+            &[initialize_ballot_box_page_ix],
+            &[],
+            "Initialized Ballot Box Page",
             &[
-                format!("VAULT: {:?}", vault),
-                format!("Vault Epoch: {:?}", ncn_epoch),
+                format!("NCN: {:?}", ncn),
+                format!("Epoch: {:?}", epoch),
+                format!("Page: {:?}", page),
             ],
         )
-        .await;
-
-        if let Err(err) = result {
-            log::error!(
-                "Failed to initialize vault update state tracker vault={} vault_epoch={}: {:#}",
-                vault,
-                ncn_epoch,
-                err
-            );
-        }
+        .await?;
     }
 
-    // Crank Vault Update State Tracker
-    let all_operators = get_all_sorted_operators_for_vault(handler, vault).await?;
+    Ok(())
+}
 
-    if !all_operators.is_empty() {
-        let starting_index = {
-            let vault_update_state_tracker_account =
-                get_vault_update_state_tracker(handler, vault, ncn_epoch).await?;
-            let last_updated_index = vault_update_state_tracker_account.last_updated_index();
+pub async fn operator_cast_vote(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    epoch: u64,
+    meta_merkle_root: [u8; 32],
+) -> Result<()> {
+    let keypair = handler.keypair();
 
-            if last_updated_index == u64::MAX {
-                ncn_epoch % all_operators.len() as u64
-            } else {
-                (last_updated_index + 1) % all_operators.len() as u64
-            }
-        };
+    let ncn = *handler.ncn()?;
 
-        for index in 0..all_operators.len() {
-            let current_index = (starting_index as usize + index) % all_operators.len();
-            let operator = all_operators
-                .get(current_index)
-                .expect("current operator index should be in range");
+    let operator = *operator;
 
-            let (vault_operator_delegation, _, _) = VaultOperatorDelegation::find_program_address(
-                &handler.vault_program_id,
-                vault,
-                operator,
-            );
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-            let crank_vault_update_state_tracker_ix = CrankVaultUpdateStateTrackerBuilder::new()
-                .vault(*vault)
-                .operator(*operator)
-                .config(vault_config)
-                .vault_operator_delegation(vault_operator_delegation)
-                .vault_update_state_tracker(vault_update_state_tracker)
-                .instruction();
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-            let result = send_and_log_transaction(
-                handler,
-                &[crank_vault_update_state_tracker_ix],
-                &[payer],
-                "Crank Vault Update State Tracker",
-                &[
-                    format!("VAULT: {:?}", vault),
-                    format!("Operator: {:?}", operator),
-                    format!("Vault Epoch: {:?}", ncn_epoch),
-                ],
-            )
-            .await;
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-            if let Err(err) = result {
-                log::error!(
-                    "Failed to crank vault update state tracker vault={} operator={} vault_epoch={}: {:#}",
-                    vault,
-                    operator,
-                    ncn_epoch,
-                    err
-                );
-            }
-        }
-    }
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    // Close Update State Tracker
-    let vault_update_state_tracker_account =
-        get_account(handler, &vault_update_state_tracker).await?;
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        &handler.tip_router_program_id,
+        &operator,
+        &ncn,
+        epoch,
+    );
 
-    if vault_update_state_tracker_account.is_some() {
-        let close_vault_update_state_tracker_ix = CloseVaultUpdateStateTrackerBuilder::new()
-            .vault(*vault)
-            .vault_update_state_tracker(vault_update_state_tracker)
-            .payer(payer.pubkey())
-            .config(vault_config)
-            .ncn_epoch(ncn_epoch)
-            .instruction();
+    let (operator_vote_key, _, _) =
+        OperatorVoteKey::find_program_address(&handler.tip_router_program_id, &operator, &ncn);
 
-        let result = send_and_log_transaction(
-            handler,
-            &[close_vault_update_state_tracker_ix],
-            &[payer],
-            "Close Vault Update State Tracker",
-            &[
-                format!("VAULT: {:?}", vault),
-                format!("Vault Epoch: {:?}", ncn_epoch),
-            ],
-        )
-        .await;
-
-        if let Err(err) = result {
-            log::error!(
-                "Failed to close vault update state tracker vault={} vault_epoch={}: {:#}",
-                vault,
-                ncn_epoch,
-                err
-            );
-        }
-    }
-
-    Ok(())
-}
+    // Votes of operators past the ballot box are kept in a ballot box page
+    let ballot_box_page = {
+        let operator_snapshot = get_operator_snapshot(handler, &operator, epoch).await?;
+        BallotBoxPage::page_for_operator(operator_snapshot.ncn_operator_index()).map(|page| {
+            BallotBoxPage::find_program_address(&handler.tip_router_program_id, &ncn, epoch, page).0
+        })
+    };
 
-pub async fn get_or_create_weight_table(handler: &CliHandler, epoch: u64) -> Result<WeightTable> {
-    let ncn = *handler.ncn()?;
+    let mut cast_vote_ix = CastVoteBuilder::new()
+        .config(config)
+        .epoch_state(epoch_state)
+        .ballot_box(ballot_box)
+        .ncn(ncn)
+        .epoch_snapshot(epoch_snapshot)
+        .operator_snapshot(operator_snapshot)
+        .operator(operator)
+        .operator_voter(keypair.pubkey())
+        .operator_vote_key(Some(operator_vote_key))
+        .ballot_box_page(ballot_box_page)
+        .meta_merkle_root(meta_merkle_root)
+        .epoch(epoch)
+        .instruction();
+    cast_vote_ix.program_id = handler.tip_router_program_id;
 
-    let (weight_table, _, _) =
-        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    send_and_log_transaction(
+        handler,
+        &[cast_vote_ix],
+        &[],
+        "Cast Vote",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!("Meta Merkle Root: {:?}", meta_merkle_root),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
 
-    if get_account(handler, &weight_table)
-        .await?
-        .is_none_or(|table| table.data.len() < WeightTable::SIZE)
-    {
-        create_weight_table(handler, epoch).await?;
-        check_created(handler, &weight_table).await?;
-    }
-    get_weight_table(handler, epoch).await
+    Ok(())
 }
 
-pub async fn get_or_create_epoch_snapshot(
+/// Withdraws the operator's vote, only possible before consensus is reached
+pub async fn operator_withdraw_vote(
     handler: &CliHandler,
+    operator: &Pubkey,
     epoch: u64,
-) -> Result<EpochSnapshot> {
-    let ncn = *handler.ncn()?;
-    let (epoch_snapshot, _, _) =
-        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+) -> Result<()> {
+    let keypair = handler.keypair();
 
-    if get_account(handler, &epoch_snapshot)
-        .await?
-        .is_none_or(|snapshot| snapshot.data.len() < EpochSnapshot::SIZE)
-    {
-        create_epoch_snapshot(handler, epoch).await?;
-        check_created(handler, &epoch_snapshot).await?;
-    }
+    let ncn = *handler.ncn()?;
 
-    get_epoch_snapshot(handler, epoch).await
-}
+    let operator = *operator;
 
-pub async fn get_or_create_operator_snapshot(
-    handler: &CliHandler,
-    operator: &Pubkey,
-    epoch: u64,
-) -> Result<OperatorSnapshot> {
-    let ncn = *handler.ncn()?;
-    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
-        &handler.tip_router_program_id,
-        operator,
-        &ncn,
-        epoch,
-    );
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    if get_account(handler, &operator_snapshot)
-        .await?
-        .is_none_or(|snapshot| snapshot.data.len() < OperatorSnapshot::SIZE)
-    {
-        create_operator_snapshot(handler, operator, epoch).await?;
-        check_created(handler, &operator_snapshot).await?;
-    }
-    get_operator_snapshot(handler, operator, epoch).await
-}
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-#[allow(clippy::large_stack_frames)]
-pub async fn get_or_create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<BallotBox> {
-    let ncn = *handler.ncn()?;
     let (ballot_box, _, _) =
         BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    if get_account(handler, &ballot_box)
-        .await?
-        .is_none_or(|ballot_box| ballot_box.data.len() < BallotBox::SIZE)
-    {
-        create_ballot_box(handler, epoch).await?;
-        check_created(handler, &ballot_box).await?;
-    }
-    get_ballot_box(handler, epoch).await
-}
+    let (operator_vote_key, _, _) =
+        OperatorVoteKey::find_program_address(&handler.tip_router_program_id, &operator, &ncn);
 
-pub async fn get_or_create_base_reward_router(
-    handler: &CliHandler,
-    epoch: u64,
-) -> Result<BaseRewardRouter> {
-    let ncn = *handler.ncn()?;
-    let (base_reward_router, _, _) =
-        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    // Votes of operators past the ballot box are kept in a ballot box page
+    let ballot_box_page = {
+        let operator_snapshot = get_operator_snapshot(handler, &operator, epoch).await?;
+        BallotBoxPage::page_for_operator(operator_snapshot.ncn_operator_index()).map(|page| {
+            BallotBoxPage::find_program_address(&handler.tip_router_program_id, &ncn, epoch, page).0
+        })
+    };
 
-    if get_account(handler, &base_reward_router)
-        .await?
-        .is_none_or(|router| router.data.len() < BaseRewardRouter::SIZE)
-    {
-        create_base_reward_router(handler, epoch).await?;
-        check_created(handler, &base_reward_router).await?;
-    }
-    get_base_reward_router(handler, epoch).await
+    let mut withdraw_vote_ix = WithdrawVoteBuilder::new()
+        .epoch_state(epoch_state)
+        .config(config)
+        .ballot_box(ballot_box)
+        .ncn(ncn)
+        .operator(operator)
+        .operator_voter(keypair.pubkey())
+        .operator_vote_key(Some(operator_vote_key))
+        .ballot_box_page(ballot_box_page)
+        .epoch(epoch)
+        .instruction();
+    withdraw_vote_ix.program_id = handler.tip_router_program_id;
+
+    send_and_log_transaction(
+        handler,
+        &[withdraw_vote_ix],
+        &[],
+        "Withdraw Vote",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
+
+    Ok(())
 }
 
-pub async fn get_or_create_ncn_reward_router(
+/// Registers the key that can vote for the operator, signed by the operator admin
+pub async fn operator_set_vote_key(
     handler: &CliHandler,
-    ncn_fee_group: NcnFeeGroup,
     operator: &Pubkey,
-    epoch: u64,
-) -> Result<NcnRewardRouter> {
+    vote_key: &Pubkey,
+) -> Result<()> {
+    let keypair = handler.keypair();
     let ncn = *handler.ncn()?;
-    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
-        &handler.tip_router_program_id,
-        operator,
-        &ncn,
-        epoch,
-    );
-
-    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
-        &handler.tip_router_program_id,
-        ncn_fee_group,
-        operator,
-        &ncn,
-        epoch,
-    );
-
-    // If operator snapshot does not exist, we cannot create the ncn reward router
-    if get_account(handler, &operator_snapshot).await?.is_none() {
-        return Err(anyhow!("Invalid Route"));
-    }
 
-    if get_account(handler, &ncn_reward_router)
-        .await?
-        .is_none_or(|router| router.data.len() < NcnRewardRouter::SIZE)
-    {
-        create_ncn_reward_router(handler, ncn_fee_group, operator, epoch).await?;
-        check_created(handler, &ncn_reward_router).await?;
-    }
-    get_ncn_reward_router(handler, ncn_fee_group, operator, epoch).await
-}
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-// --------------------- CRANKERS ------------------------------
+    let (operator_vote_key, _, _) =
+        OperatorVoteKey::find_program_address(&handler.tip_router_program_id, operator, &ncn);
 
-/// Registers unregistered vaults in the NCN system with the vault registry.
-///
-/// - Fetches all vaults by NCN address and currently registered vaults
-/// - Identifies unregistered vaults by comparing two lists
-/// - For each unregistered vault:
-///     - Retrives vault account data
-///     - Validates the vault's supported mint is registered
-///     - Attempts registration if valid
-pub async fn crank_register_vaults(handler: &CliHandler) -> Result<()> {
-    let rpc_client = handler.rpc_client();
-    let all_ncn_vaults = get_all_vaults_in_ncn(handler).await?;
-    let vault_registry = get_vault_registry(handler).await?;
-    let all_registered_vaults: Vec<Pubkey> = vault_registry
-        .get_valid_vault_entries()
-        .iter()
-        .map(|entry| *entry.vault())
-        .collect();
+    let (restaking_config, _, _) =
+        RestakingConfig::find_program_address(&handler.restaking_program_id);
 
-    let vaults_to_register: Vec<Pubkey> = all_ncn_vaults
-        .iter()
-        .filter(|vault| !all_registered_vaults.contains(vault))
-        .copied()
-        .collect();
+    let (ncn_operator_state, _, _) =
+        NcnOperatorState::find_program_address(&handler.restaking_program_id, &ncn, operator);
 
-    for vault in vaults_to_register.iter() {
-        let vault_raw_acc = rpc_client.get_account(vault).await?;
-        let vault_acc = Vault::try_from_slice_unchecked(&vault_raw_acc.data)?;
+    // The operator admin pays for the operator vote key account
+    let mut set_operator_vote_key_ix = SetOperatorVoteKeyBuilder::new()
+        .config(config)
+        .operator_vote_key(operator_vote_key)
+        .ncn(ncn)
+        .operator(*operator)
+        .restaking_config(restaking_config)
+        .ncn_operator_state(ncn_operator_state)
+        .operator_admin(keypair.pubkey())
+        .system_program(system_program::id())
+        .vote_key(*vote_key)
+        .instruction();
+    set_operator_vote_key_ix.program_id = handler.tip_router_program_id;
 
-        if vault_registry.has_st_mint(&vault_acc.supported_mint) {
-            if let Err(err) = register_vault(handler, vault).await {
-                log::error!("Failed to register vault vault={}: {:#}", vault, err);
-            }
-        } else {
-            log::warn!(
-                "Skipping vault registration, st_mint not registered vault={}",
-                vault,
-            );
-        }
-    }
+    send_and_log_transaction(
+        handler,
+        &[set_operator_vote_key_ix],
+        &[],
+        "Set Operator Vote Key",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!("Vote Key: {:?}", vote_key),
+        ],
+    )
+    .await?;
 
     Ok(())
 }
 
-pub async fn crank_set_weight(handler: &CliHandler, epoch: u64) -> Result<()> {
-    let weight_table = get_or_create_weight_table(handler, epoch).await?;
+/// Closes the operator vote key account, refunding its rent to the operator admin
+pub async fn operator_close_vote_key(handler: &CliHandler, operator: &Pubkey) -> Result<()> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
 
-    let st_mints = weight_table
-        .table()
-        .iter()
-        .filter(|entry| !entry.is_empty() && !entry.is_set())
-        .map(|entry| *entry.st_mint())
-        .collect::<Vec<Pubkey>>();
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    for st_mint in st_mints {
-        let result = set_weight_with_st_mint(handler, &st_mint, epoch).await;
+    let (operator_vote_key, _, _) =
+        OperatorVoteKey::find_program_address(&handler.tip_router_program_id, operator, &ncn);
 
-        if let Err(err) = result {
-            log::error!(
-                "Failed to set weight st_mint={} epoch={}: {:#}",
-                st_mint,
-                epoch,
-                err
-            );
-        }
-    }
+    let mut close_operator_vote_key_ix = CloseOperatorVoteKeyBuilder::new()
+        .config(config)
+        .operator_vote_key(operator_vote_key)
+        .ncn(ncn)
+        .operator(*operator)
+        .operator_admin(keypair.pubkey())
+        .instruction();
+    close_operator_vote_key_ix.program_id = handler.tip_router_program_id;
+
+    send_and_log_transaction(
+        handler,
+        &[close_operator_vote_key_ix],
+        &[],
+        "Closed Operator Vote Key",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+        ],
+    )
+    .await?;
 
     Ok(())
 }
 
-pub async fn crank_snapshot(handler: &CliHandler, epoch: u64) -> Result<()> {
-    let vault_registry = get_vault_registry(handler).await?;
+/// Sets the asset an operator or vault is paid its NCN rewards in, signed by its admin
+pub async fn set_payout_preference(
+    handler: &CliHandler,
+    recipient: &Pubkey,
+    payout_asset: PayoutAsset,
+    sol_destination: &Pubkey,
+) -> Result<()> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
 
-    let operators = get_all_operators_in_ncn(handler).await?;
-    let all_vaults: Vec<Pubkey> = vault_registry
-        .get_valid_vault_entries()
-        .iter()
-        .map(|entry| *entry.vault())
-        .collect();
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    let epoch_snapshot = get_or_create_epoch_snapshot(handler, epoch).await?;
-    if epoch_snapshot.finalized() {
-        log::info!(
-            "Epoch snapshot already finalized, skipping snapshot epoch={}",
-            epoch
-        );
-        return Ok(());
-    }
+    let (payout_preference, _, _) =
+        PayoutPreference::find_program_address(&handler.tip_router_program_id, recipient, &ncn);
 
-    for operator in operators.iter() {
-        // Create Vault Operator Delegation
-        let result = get_or_create_operator_snapshot(handler, operator, epoch).await;
+    let mut set_payout_preference_ix = SetPayoutPreferenceBuilder::new()
+        .config(config)
+        .payout_preference(payout_preference)
+        .ncn(ncn)
+        .recipient(*recipient)
+        .admin(keypair.pubkey())
+        .system_program(system_program::id())
+        .payout_asset(payout_asset as u8)
+        .sol_destination(*sol_destination)
+        .instruction();
+    set_payout_preference_ix.program_id = handler.tip_router_program_id;
 
-        if let Err(err) = result {
-            log::error!(
-                "Failed to get or create operator snapshot operator={} epoch={}: {:#}",
-                operator,
-                epoch,
-                err
-            );
-            continue;
-        };
+    send_and_log_transaction(
+        handler,
+        &[set_payout_preference_ix],
+        &[],
+        "Set Payout Preference",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Recipient: {:?}", recipient),
+            format!("Payout Asset: {}", payout_asset),
+            format!("SOL Destination: {:?}", sol_destination),
+        ],
+    )
+    .await?;
 
-        let operator_snapshot = result?;
+    Ok(())
+}
 
-        let vaults_to_run: Vec<Pubkey> = all_vaults
-            .iter()
-            .filter(|vault| !operator_snapshot.contains_vault(vault))
-            .cloned()
-            .collect();
+/// The account the NCN rewards of an operator or vault are paid to under its payout
+/// preference, with the instruction creating it when it is an ATA
+async fn get_payout_destination(
+    handler: &CliHandler,
+    recipient: &Pubkey,
+    jitosol_mint: &Pubkey,
+) -> Result<(Pubkey, Option<Instruction>)> {
+    let keypair = handler.keypair();
 
-        for vault in vaults_to_run.iter() {
-            let result = full_vault_update(handler, vault).await;
+    let payout_preference = get_payout_preference(handler, recipient).await?;
+    let payout_asset = payout_preference
+        .as_ref()
+        .map(|payout_preference| payout_preference.payout_asset())
+        .transpose()?
+        .unwrap_or_default();
 
-            if let Err(err) = result {
-                log::error!("Failed to update vault vault={}: {:#}", vault, err);
-            }
+    let (mint, token_program) = match (payout_asset, payout_preference) {
+        (PayoutAsset::Sol, Some(payout_preference)) => {
+            return Ok((*payout_preference.sol_destination(), None));
+        }
+        (PayoutAsset::WrappedSol, _) => (
+            spl_token_interface::native_mint::id(),
+            spl_token_interface::id(),
+        ),
+        _ => (*jitosol_mint, handler.token_program_id),
+    };
 
-            let result = snapshot_vault_operator_delegation(handler, vault, operator, epoch).await;
+    let ata = get_associated_token_address(recipient, &mint);
+    let create_ata_ix = create_associated_token_account_idempotent(
+        &keypair.pubkey(),
+        recipient,
+        &mint,
+        &token_program,
+    );
 
-            if let Err(err) = result {
-                log::error!(
-                    "Failed to snapshot vault operator delegation vault={} operator={} epoch={}: {:#}",
-                    vault,
-                    operator,
-                    epoch,
-                    err
-                );
-            }
-        }
+    Ok((ata, Some(create_ata_ix)))
+}
+
+pub async fn create_base_reward_router(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (base_reward_router, _, _) =
+        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (base_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+    let base_reward_router_account = get_account(handler, &base_reward_router).await?;
+
+    // Skip if base reward router already exists
+    if base_reward_router_account.is_none() {
+        let mut initialize_base_reward_router_ix = InitializeBaseRewardRouterBuilder::new()
+            .epoch_marker(epoch_marker)
+            .ncn(ncn)
+            .epoch_state(epoch_state)
+            .base_reward_router(base_reward_router)
+            .base_reward_receiver(base_reward_receiver)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .instruction();
+        initialize_base_reward_router_ix.program_id = handler.tip_router_program_id;
+
+        send_and_log_transaction(
+            handler,
+            &[initialize_base_reward_router_ix],
+            &[],
+            "Initialized Base Reward Router",
+            &[format!("NCN: {:?}", ncn), format!("Epoch: {:?}", epoch)],
+        )
+        .await?;
+    }
+
+    // Number of reallocations needed based on BaseRewardRouter::SIZE
+    let num_reallocs = (BaseRewardRouter::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+
+    let mut realloc_base_reward_router_ix = ReallocBaseRewardRouterBuilder::new()
+        .config(TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0)
+        .epoch_state(epoch_state)
+        .base_reward_router(base_reward_router)
+        .ncn(ncn)
+        .epoch(epoch)
+        .account_payer(account_payer)
+        .system_program(system_program::id())
+        .instruction();
+    realloc_base_reward_router_ix.program_id = handler.tip_router_program_id;
+
+    let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
+    realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
+    for _ in 0..num_reallocs {
+        realloc_ixs.push(realloc_base_reward_router_ix.clone());
     }
 
+    send_and_log_transaction(
+        handler,
+        &realloc_ixs,
+        &[],
+        "Reallocated Base Reward Router",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Epoch: {:?}", epoch),
+            format!("Number of reallocations: {:?}", num_reallocs),
+        ],
+    )
+    .await?;
+
     Ok(())
 }
 
-#[allow(clippy::large_stack_frames)]
-pub async fn crank_vote(handler: &CliHandler, epoch: u64, test_vote: bool) -> Result<()> {
-    // VOTE
+/// Creates the base reward router pages `start_page..end_page`, `mint` is the default pubkey for
+/// the lamport router
+pub async fn create_base_reward_router_pages(
+    handler: &CliHandler,
+    mint: &Pubkey,
+    epoch: u64,
+    start_page: u64,
+    end_page: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
 
-    let ballot_box = get_or_create_ballot_box(handler, epoch).await?;
-    if ballot_box.is_consensus_reached() {
-        log::info!("Consensus already reached, skipping voting epoch={}", epoch);
-        return Ok(());
-    }
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    if test_vote {
-        crank_test_vote(handler, epoch).await?;
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (base_reward_router, _, _) = BaseRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        &ncn,
+        mint,
+        epoch,
+    );
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    for page in start_page..end_page {
+        let (base_reward_router_page, _, _) = BaseRewardRouterPage::find_program_address_for_mint(
+            &handler.tip_router_program_id,
+            &ncn,
+            mint,
+            epoch,
+            page,
+        );
+
+        let mut initialize_base_reward_router_page_ix =
+            InitializeBaseRewardRouterPageBuilder::new()
+                .epoch_marker(epoch_marker)
+                .epoch_state(epoch_state)
+                .ncn(ncn)
+                .ballot_box(ballot_box)
+                .base_reward_router(base_reward_router)
+                .base_reward_router_page(base_reward_router_page)
+                .account_payer(account_payer)
+                .system_program(system_program::id())
+                .epoch(epoch)
+                .page(page)
+                .instruction();
+        initialize_base_reward_router_page_ix.program_id = handler.tip_router_program_id;
+
+        send_and_log_transaction(
+            handler,
+            &[initialize_base_reward_router_page_ix],
+            &[],
+            "Initialized Base Reward Router Page",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Mint: {:?}", mint),
+                format!("Epoch: {:?}", epoch),
+                format!("Page: {:?}", page),
+            ],
+        )
+        .await?;
     }
 
     Ok(())
 }
 
-#[allow(clippy::large_stack_frames)]
-pub async fn crank_post_vote_cooldown(_: &CliHandler, _: u64) -> Result<()> {
+pub async fn create_ncn_reward_router(
+    handler: &CliHandler,
+    ncn_fee_group: NcnFeeGroup,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let operator = *operator;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        &handler.tip_router_program_id,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let epoch_state_page = get_epoch_state_page_for_operator(handler, &operator, epoch).await?;
+
+    let mut initialize_ncn_reward_router_ix = InitializeNcnRewardRouterBuilder::new()
+        .epoch_marker(epoch_marker)
+        .epoch_state(epoch_state)
+        .ncn(ncn)
+        .operator(operator)
+        .operator_snapshot(operator_snapshot)
+        .ncn_reward_router(ncn_reward_router)
+        .ncn_reward_receiver(ncn_reward_receiver)
+        .account_payer(account_payer)
+        .system_program(system_program::id())
+        .ncn_fee_group(ncn_fee_group.group)
+        .epoch_state_page(epoch_state_page)
+        .epoch(epoch)
+        .instruction();
+    initialize_ncn_reward_router_ix.program_id = handler.tip_router_program_id;
+
+    send_and_log_transaction(
+        handler,
+        &[initialize_ncn_reward_router_ix],
+        &[],
+        "Initialized NCN Reward Router",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!("NCN Fee Group: {:?}", ncn_fee_group.group),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn route_base_rewards(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let keypair = handler.keypair();
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let config = TipRouterConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (base_reward_router, _, _) =
+        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (base_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let routing_pages = get_routing_page_accounts(handler, &Pubkey::default(), epoch).await?;
+
+    // Using max iterations defined in BaseRewardRouter
+    let max_iterations: u16 = BaseRewardRouter::MAX_ROUTE_BASE_ITERATIONS;
+
+    let mut still_routing = true;
+    while still_routing {
+        let mut route_base_rewards_ix = RouteBaseRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .epoch_snapshot(epoch_snapshot)
+            .ballot_box(ballot_box)
+            .base_reward_router(base_reward_router)
+            .base_reward_receiver(base_reward_receiver)
+            .max_iterations(max_iterations)
+            .epoch(epoch)
+            .account_payer(Some(account_payer))
+            .cranker(Some(keypair.pubkey()))
+            .system_program(Some(system_program::id()))
+            .add_remaining_accounts(&routing_pages)
+            .instruction();
+        route_base_rewards_ix.program_id = handler.tip_router_program_id;
+
+        let instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            route_base_rewards_ix,
+        ];
+
+        send_and_log_transaction(
+            handler,
+            &instructions,
+            &[],
+            "Routed Base Rewards",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Epoch: {:?}", epoch),
+                format!("Max iterations: {:?}", max_iterations),
+            ],
+        )
+        .await?;
+
+        // Check if we need to continue routing
+        let base_reward_router_account = get_base_reward_router(handler, epoch).await?;
+        still_routing = base_reward_router_account.still_routing();
+    }
+
+    Ok(())
+}
+
+pub async fn route_ncn_rewards(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    ncn_fee_group: NcnFeeGroup,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let keypair = handler.keypair();
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let config = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+
+    let operator = *operator;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        &handler.tip_router_program_id,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let operator_snapshot_pages =
+        get_operator_snapshot_page_metas(handler, &operator, epoch).await?;
+
+    // Using max iterations defined in NcnRewardRouter
+    let max_iterations: u16 = NcnRewardRouter::MAX_ROUTE_NCN_ITERATIONS;
+
+    let epoch_state_page = get_epoch_state_page_for_operator(handler, &operator, epoch).await?;
+
+    let mut still_routing = true;
+    while still_routing {
+        let mut route_ncn_rewards_ix = RouteNcnRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_snapshot(operator_snapshot)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .ncn_fee_group(ncn_fee_group.group)
+            .max_iterations(max_iterations)
+            .epoch_state_page(epoch_state_page)
+            .epoch(epoch)
+            .account_payer(Some(account_payer))
+            .cranker(Some(keypair.pubkey()))
+            .system_program(Some(system_program::id()))
+            .add_remaining_accounts(&operator_snapshot_pages)
+            .instruction();
+        route_ncn_rewards_ix.program_id = handler.tip_router_program_id;
+
+        let instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            route_ncn_rewards_ix,
+        ];
+
+        send_and_log_transaction(
+            handler,
+            &instructions,
+            &[],
+            "Routed NCN Rewards",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Operator: {:?}", operator),
+                format!("NCN Fee Group: {:?}", ncn_fee_group.group),
+                format!("Epoch: {:?}", epoch),
+                format!("Max iterations: {:?}", max_iterations),
+            ],
+        )
+        .await?;
+
+        // Check if we need to continue routing
+        let ncn_reward_router_account =
+            get_ncn_reward_router(handler, ncn_fee_group, &operator, epoch).await?;
+        still_routing = ncn_reward_router_account.still_routing();
+    }
+
+    Ok(())
+}
+
+pub async fn distribute_base_ncn_rewards(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    ncn_fee_group: NcnFeeGroup,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let keypair = handler.keypair();
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let operator = *operator;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (base_reward_router, _, _) =
+        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (base_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let base_reward_router_page =
+        get_base_reward_router_page_for_operator(handler, &Pubkey::default(), &operator, epoch)
+            .await?;
+
+    let mut distribute_base_ncn_rewards_ix = DistributeBaseNcnRewardRouteBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ncn(ncn)
+        .operator(operator)
+        .base_reward_router(base_reward_router)
+        .base_reward_receiver(base_reward_receiver)
+        .ncn_reward_router(ncn_reward_router)
+        .ncn_reward_receiver(ncn_reward_receiver)
+        .system_program(system_program::id())
+        .ncn_fee_group(ncn_fee_group.group)
+        .epoch(epoch)
+        .account_payer(Some(account_payer))
+        .cranker(Some(keypair.pubkey()))
+        .base_reward_router_page(base_reward_router_page)
+        .instruction();
+    distribute_base_ncn_rewards_ix.program_id = handler.tip_router_program_id;
+
+    send_and_log_transaction(
+        handler,
+        &[distribute_base_ncn_rewards_ix],
+        &[],
+        "Distributed Base NCN Rewards",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!("NCN Fee Group: {:?}", ncn_fee_group.group),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn migrate_account(handler: &CliHandler, account: &Pubkey) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let mut ix = MigrateAccountBuilder::new()
+        .ncn(ncn)
+        .account_to_migrate(*account)
+        .account_payer(account_payer)
+        .system_program(system_program::id())
+        .instruction();
+    ix.program_id = handler.tip_router_program_id;
+
+    let ixs = &[ix];
+    if handler.print_tx {
+        print_base58_tx(ixs);
+    } else {
+        send_and_log_transaction(
+            handler,
+            ixs,
+            &[],
+            "Migrated Account",
+            &[format!("NCN: {:?}", ncn), format!("Account: {:?}", account)],
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn distribute_base_rewards(
+    handler: &CliHandler,
+    base_fee_group: BaseFeeGroup,
+    epoch: u64,
+) -> Result<()> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (base_reward_router, _, _) =
+        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (base_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let tip_router_config = get_tip_router_config(handler).await?;
+    let base_fee_wallet = tip_router_config
+        .fee_config
+        .base_fee_wallet(base_fee_group)?;
+
+    let payout_asset = tip_router_config
+        .fee_config
+        .base_fee_group_settings(base_fee_group)?
+        .payout_asset()?;
+
+    let stake_pool_accounts = get_stake_pool_accounts(handler).await?;
+
+    // SOL is sent to the base fee wallet itself, JitoSOL and wSOL to its ATA
+    let (base_fee_wallet_ata, create_base_fee_wallet_ata_ix) = match payout_asset {
+        PayoutAsset::Sol => (*base_fee_wallet, None),
+        PayoutAsset::JitoSol | PayoutAsset::WrappedSol => {
+            let (mint, token_program) = if payout_asset == PayoutAsset::WrappedSol {
+                (
+                    spl_token_interface::native_mint::id(),
+                    spl_token_interface::id(),
+                )
+            } else {
+                (
+                    stake_pool_accounts.stake_pool.pool_mint,
+                    handler.token_program_id,
+                )
+            };
+
+            (
+                get_associated_token_address(base_fee_wallet, &mint),
+                Some(create_associated_token_account_idempotent(
+                    &keypair.pubkey(),
+                    base_fee_wallet,
+                    &mint,
+                    &token_program,
+                )),
+            )
+        }
+    };
+
+    let mut distribute_base_ncn_rewards_ix = DistributeBaseRewardsBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ncn(ncn)
+        .base_reward_router(base_reward_router)
+        .base_reward_receiver(base_reward_receiver)
+        .system_program(system_program::id())
+        .epoch(epoch)
+        .base_fee_wallet(*base_fee_wallet)
+        .base_fee_wallet_ata(base_fee_wallet_ata)
+        .base_fee_group(base_fee_group.group)
+        .pool_mint(stake_pool_accounts.stake_pool.pool_mint)
+        .manager_fee_account(stake_pool_accounts.stake_pool.manager_fee_account)
+        .referrer_pool_tokens_account(stake_pool_accounts.referrer_pool_tokens_account)
+        .reserve_stake(stake_pool_accounts.stake_pool.reserve_stake)
+        .stake_pool(stake_pool_accounts.stake_pool_address)
+        .stake_pool_withdraw_authority(stake_pool_accounts.stake_pool_withdraw_authority)
+        .stake_pool_program(stake_pool_accounts.stake_pool_program_id)
+        .account_payer(Some(account_payer))
+        .cranker(Some(keypair.pubkey()))
+        .instruction();
+    distribute_base_ncn_rewards_ix.program_id = handler.tip_router_program_id;
+
+    let result = send_and_log_transaction(
+        handler,
+        &[
+            create_base_fee_wallet_ata_ix.into_iter().collect(),
+            vec![distribute_base_ncn_rewards_ix],
+        ]
+        .concat(),
+        &[],
+        "Distributed Base Rewards",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Base Fee Group: {:?}", base_fee_group.group),
+            format!("Payout Asset: {}", payout_asset),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await;
+
+    if let Err(err) = result {
+        let error_message = format!("{:?}", err);
+        recover_from_low_balance_deposit_base(handler, epoch, &error_message).await?;
+
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+pub async fn distribute_ncn_vault_rewards(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    ncn_fee_group: NcnFeeGroup,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let keypair = handler.keypair();
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        operator,
+        &ncn,
+        epoch,
+    );
+
+    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        operator,
+        &ncn,
+        epoch,
+    );
+
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        &handler.tip_router_program_id,
+        operator,
+        &ncn,
+        epoch,
+    );
+
+    let stake_pool_accounts = get_stake_pool_accounts(handler).await?;
+
+    let vault = *vault;
+    let (vault_ata, create_vault_ata_ix) =
+        get_payout_destination(handler, &vault, &stake_pool_accounts.stake_pool.pool_mint).await?;
+
+    let (payout_preference, _, _) =
+        PayoutPreference::find_program_address(&handler.tip_router_program_id, &vault, &ncn);
+
+    let epoch_state_page = get_epoch_state_page_for_operator(handler, operator, epoch).await?;
+
+    let mut distribute_ncn_vault_rewards_ix = DistributeNcnVaultRewardsBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ncn(ncn)
+        .operator(*operator)
+        .vault(vault)
+        .vault_ata(vault_ata)
+        .operator_snapshot(operator_snapshot)
+        .ncn_reward_router(ncn_reward_router)
+        .ncn_reward_receiver(ncn_reward_receiver)
+        .pool_mint(stake_pool_accounts.stake_pool.pool_mint)
+        .manager_fee_account(stake_pool_accounts.stake_pool.manager_fee_account)
+        .referrer_pool_tokens_account(stake_pool_accounts.referrer_pool_tokens_account)
+        .reserve_stake(stake_pool_accounts.stake_pool.reserve_stake)
+        .stake_pool(stake_pool_accounts.stake_pool_address)
+        .stake_pool_withdraw_authority(stake_pool_accounts.stake_pool_withdraw_authority)
+        .stake_pool_program(stake_pool_accounts.stake_pool_program_id)
+        .token_program(handler.token_program_id)
+        .system_program(system_program::id())
+        .payout_preference(payout_preference)
+        .ncn_fee_group(ncn_fee_group.group)
+        .epoch_state_page(epoch_state_page)
+        .epoch(epoch)
+        .account_payer(Some(account_payer))
+        .cranker(Some(keypair.pubkey()))
+        .instruction();
+    distribute_ncn_vault_rewards_ix.program_id = handler.tip_router_program_id;
+
+    let vault_account = get_vault(handler, &vault).await?;
+    let st_mint = vault_account.supported_mint;
+    let vrt_mint = vault_account.vrt_mint;
+    let vault_fee_wallet = vault_account.fee_wallet;
+
+    let vault_fee_token_account = get_associated_token_address(&vault_fee_wallet, &vrt_mint);
+    let vault_token_account = get_associated_token_address(&vault, &st_mint);
+
+    let (vault_config, _, _) = VaultConfig::find_program_address(&handler.vault_program_id);
+
+    let update_vault_balance_ix = UpdateVaultBalanceBuilder::new()
+        .config(vault_config)
+        .vault(vault)
+        .token_program(spl_token_interface::id())
+        .vault_fee_token_account(vault_fee_token_account)
+        .vault_token_account(vault_token_account)
+        .vrt_mint(vrt_mint)
+        .instruction();
+
+    let result = send_and_log_transaction(
+        handler,
+        &[
+            vec![ComputeBudgetInstruction::set_compute_unit_limit(1_400_000)],
+            create_vault_ata_ix.into_iter().collect(),
+            vec![distribute_ncn_vault_rewards_ix, update_vault_balance_ix],
+        ]
+        .concat(),
+        &[],
+        "Distributed NCN Vault Rewards",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Vault: {:?}", vault),
+            format!("Operator: {:?}", operator),
+            format!("NCN Fee Group: {:?}", ncn_fee_group.group),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await;
+
+    if let Err(err) = result {
+        let error_message = format!("{:?}", err);
+        recover_from_low_balance_deposit_ncn(
+            handler,
+            operator,
+            ncn_fee_group,
+            epoch,
+            &error_message,
+        )
+        .await?;
+
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+pub async fn distribute_ncn_operator_rewards(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    ncn_fee_group: NcnFeeGroup,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let keypair = handler.keypair();
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        operator,
+        &ncn,
+        epoch,
+    );
+
+    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        operator,
+        &ncn,
+        epoch,
+    );
+
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        &handler.tip_router_program_id,
+        operator,
+        &ncn,
+        epoch,
+    );
+
+    let stake_pool_accounts = get_stake_pool_accounts(handler).await?;
+
+    let (operator_ata, create_operator_ata_ix) =
+        get_payout_destination(handler, operator, &stake_pool_accounts.stake_pool.pool_mint)
+            .await?;
+
+    let (payout_preference, _, _) =
+        PayoutPreference::find_program_address(&handler.tip_router_program_id, operator, &ncn);
+
+    let epoch_state_page = get_epoch_state_page_for_operator(handler, operator, epoch).await?;
+
+    let mut distribute_ncn_operator_rewards_ix = DistributeNcnOperatorRewardsBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ncn(ncn)
+        .operator(*operator)
+        .operator_ata(operator_ata)
+        .operator_snapshot(operator_snapshot)
+        .ncn_reward_router(ncn_reward_router)
+        .ncn_reward_receiver(ncn_reward_receiver)
+        .pool_mint(stake_pool_accounts.stake_pool.pool_mint)
+        .manager_fee_account(stake_pool_accounts.stake_pool.manager_fee_account)
+        .referrer_pool_tokens_account(stake_pool_accounts.referrer_pool_tokens_account)
+        .reserve_stake(stake_pool_accounts.stake_pool.reserve_stake)
+        .stake_pool(stake_pool_accounts.stake_pool_address)
+        .stake_pool_withdraw_authority(stake_pool_accounts.stake_pool_withdraw_authority)
+        .stake_pool_program(stake_pool_accounts.stake_pool_program_id)
+        .token_program(handler.token_program_id)
+        .system_program(system_program::id())
+        .payout_preference(payout_preference)
+        .ncn_fee_group(ncn_fee_group.group)
+        .epoch_state_page(epoch_state_page)
+        .epoch(epoch)
+        .account_payer(Some(account_payer))
+        .cranker(Some(keypair.pubkey()))
+        .instruction();
+    distribute_ncn_operator_rewards_ix.program_id = handler.tip_router_program_id;
+
+    let result = send_and_log_transaction(
+        handler,
+        &[
+            create_operator_ata_ix.into_iter().collect(),
+            vec![distribute_ncn_operator_rewards_ix],
+        ]
+        .concat(),
+        &[],
+        "Distributed NCN Operator Rewards",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!("NCN Fee Group: {:?}", ncn_fee_group.group),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await;
+
+    if let Err(err) = result {
+        let error_message = format!("{:?}", err);
+        recover_from_low_balance_deposit_ncn(
+            handler,
+            operator,
+            ncn_fee_group,
+            epoch,
+            &error_message,
+        )
+        .await?;
+
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+pub async fn recover_from_low_balance_deposit_base(
+    handler: &CliHandler,
+    epoch: u64,
+    error_message: &String,
+) -> Result<()> {
+    let ncn = handler.ncn()?;
+
+    let (ncn_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, ncn, epoch);
+
+    recover_from_low_balance_deposit(handler, &ncn_reward_receiver, error_message).await
+}
+
+pub async fn recover_from_low_balance_deposit_ncn(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    ncn_fee_group: NcnFeeGroup,
+    epoch: u64,
+    error_message: &String,
+) -> Result<()> {
+    let ncn = handler.ncn()?;
+
+    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        operator,
+        ncn,
+        epoch,
+    );
+
+    recover_from_low_balance_deposit(handler, &ncn_reward_receiver, error_message).await
+}
+
+pub async fn recover_from_low_balance_deposit(
+    handler: &CliHandler,
+    to: &Pubkey,
+    error_message: &String,
+) -> Result<()> {
+    const RECOVERY_LAMPORTS: u64 = 1000;
+    const RECOVERABLE_ERROR: &str =
+        "Not enough lamports provided for deposit to result in one pool token";
+
+    if !error_message.contains(RECOVERABLE_ERROR) {
+        info!("Error not recoverable: {}", error_message);
+        return Ok(());
+    }
+
+    let keypair = handler.keypair();
+    let transfer_ix = system_instruction::transfer(&keypair.pubkey(), to, RECOVERY_LAMPORTS);
+
+    send_and_log_transaction(
+        handler,
+        &[transfer_ix],
+        &[],
+        "Recovered From Low Balance Deposit",
+        &[
+            format!("To: {:?}", to),
+            format!("From: {:?}", keypair.pubkey()),
+            format!("Amount: {:?}", RECOVERY_LAMPORTS),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn close_epoch_account(
+    handler: &CliHandler,
+    ncn: Pubkey,
+    epoch: u64,
+    account_to_close: Pubkey,
+    receiver_to_close: Option<Pubkey>,
+) -> Result<()> {
+    close_epoch_account_with_remaining_accounts(
+        handler,
+        ncn,
+        epoch,
+        account_to_close,
+        receiver_to_close,
+        &[],
+    )
+    .await
+}
+
+/// Closes an epoch account that takes extra accounts after `receiver_to_close`, such as the
+/// epoch state page of an NCN reward router past `MAX_OPERATORS`
+pub async fn close_epoch_account_with_remaining_accounts(
+    handler: &CliHandler,
+    ncn: Pubkey,
+    epoch: u64,
+    account_to_close: Pubkey,
+    receiver_to_close: Option<Pubkey>,
+    remaining_accounts: &[Pubkey],
+) -> Result<()> {
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let account_already_closed = get_account(handler, &account_to_close)
+        .await?
+        .is_none_or(|account| account.data.is_empty() || account.lamports == 0);
+    if account_already_closed {
+        info!("Account already closed account={}", account_to_close);
+        return Ok(());
+    }
+
+    let config_account = get_tip_router_config(handler).await?;
+    let dao_wallet = *config_account
+        .fee_config
+        .base_fee_wallet(BaseFeeGroup::dao())?;
+
+    let mut ix = CloseEpochAccountBuilder::new();
+
+    ix.account_payer(account_payer)
+        .epoch_marker(epoch_marker)
+        .config(config)
+        .account_to_close(account_to_close)
+        .epoch_state(epoch_state)
+        .ncn(ncn)
+        .dao_wallet(dao_wallet)
+        .system_program(system_program::id())
+        .epoch(epoch);
+
+    if let Some(receiver_to_close) = receiver_to_close {
+        ix.receiver_to_close(Some(receiver_to_close));
+    }
+    let remaining_accounts = remaining_accounts
+        .iter()
+        .map(|account| AccountMeta::new(*account, false))
+        .collect::<Vec<_>>();
+    ix.add_remaining_accounts(&remaining_accounts);
+    let mut close_epoch_account_ix = ix.instruction();
+    close_epoch_account_ix.program_id = handler.tip_router_program_id;
+
+    send_and_log_transaction(
+        handler,
+        &[close_epoch_account_ix],
+        &[],
+        "Close Epoch Account",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Account to Close: {:?}", account_to_close),
+            format!("Receiver to Close: {:?}", receiver_to_close),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn carry_forward_rewards(
+    handler: &CliHandler,
+    ncn: Pubkey,
+    epoch: u64,
+    reward_router: Pubkey,
+    reward_receiver: Pubkey,
+) -> Result<()> {
+    let Some(reward_router_account) = get_account(handler, &reward_router)
+        .await?
+        .filter(|account| !account.data.is_empty() && account.lamports > 0)
+    else {
+        info!("Reward router already closed account={}", reward_router);
+        return Ok(());
+    };
+
+    // Rewards in transit stay with the receiver until they are distributed
+    let rewards_in_transit = match reward_router_account.data[0] {
+        BaseRewardRouter::DISCRIMINATOR => {
+            BaseRewardRouter::try_from_slice_unchecked(reward_router_account.data.as_slice())?
+                .total_rewards_in_transit()?
+        }
+        NcnRewardRouter::DISCRIMINATOR => {
+            NcnRewardRouter::try_from_slice_unchecked(reward_router_account.data.as_slice())?
+                .total_rewards_in_transit()?
+        }
+        _ => 0,
+    };
+
+    let rent = handler
+        .rpc_client()
+        .get_minimum_balance_for_rent_exemption(0)
+        .await?;
+    let receiver_lamports = get_account(handler, &reward_receiver)
+        .await?
+        .map_or(0, |account| account.lamports);
+    if receiver_lamports <= rent.saturating_add(rewards_in_transit) {
+        info!("No rewards to carry forward account={}", reward_router);
+        return Ok(());
+    }
+
+    let current_epoch = get_current_epoch(handler).await?;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (current_epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, current_epoch);
+
+    let (current_base_reward_receiver, _, _) = BaseRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        &ncn,
+        current_epoch,
+    );
+
+    let mut carry_forward_rewards_ix = CarryForwardRewardsBuilder::new()
+        .epoch_state(epoch_state)
+        .config(config)
+        .ncn(ncn)
+        .reward_router(reward_router)
+        .reward_receiver(reward_receiver)
+        .current_epoch_state(current_epoch_state)
+        .current_base_reward_receiver(current_base_reward_receiver)
+        .system_program(system_program::id())
+        .epoch(epoch)
+        .instruction();
+    carry_forward_rewards_ix.program_id = handler.tip_router_program_id;
+
+    send_and_log_transaction(
+        handler,
+        &[carry_forward_rewards_ix],
+        &[],
+        "Carry Forward Rewards",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Reward Router: {:?}", reward_router),
+            format!("Epoch: {:?}", epoch),
+            format!("Current Epoch: {:?}", current_epoch),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+// --------------------- MIDDLEWARE ------------------------------
+pub const CREATE_TIMEOUT_MS: u64 = 2000;
+pub const CREATE_GET_RETRIES: u64 = 3;
+pub async fn check_created(handler: &CliHandler, address: &Pubkey) -> Result<()> {
+    let mut retries = 0;
+    let mut account = get_account(handler, address).await?;
+    while account.is_none() && retries < CREATE_GET_RETRIES {
+        sleep(Duration::from_millis(CREATE_TIMEOUT_MS * (retries + 1))).await;
+        account = get_account(handler, address).await?;
+        retries += 1;
+    }
+
+    if account.is_none() {
+        return Err(anyhow!(
+            "Failed to get account after creation {:?}",
+            address
+        ));
+    }
+
+    Ok(())
+}
+
+pub async fn update_all_vaults_in_network(handler: &CliHandler) -> Result<()> {
+    let vaults = get_all_vaults(handler).await?;
+    for vault in vaults {
+        full_vault_update(handler, &vault).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn full_vault_update(handler: &CliHandler, vault: &Pubkey) -> Result<()> {
+    let payer = handler.keypair();
+
+    // Get Epoch Info
+    let current_slot = get_current_slot(handler).await?;
+    let (ncn_epoch, epoch_length) = {
+        let vault_config = get_vault_config(handler).await?;
+        let ncn_epoch = vault_config.get_epoch_from_slot(current_slot)?;
+        let epoch_length = vault_config.epoch_length();
+        (ncn_epoch, epoch_length)
+    };
+
+    // Check Vault
+    let vault_account = get_vault(handler, vault).await?;
+    let current_slot = get_current_slot(handler).await?;
+
+    if !vault_account.is_update_needed(current_slot, epoch_length)? {
+        return Ok(());
+    }
+
+    // Initialize Vault Update State Tracker
+    let (vault_config, _, _) = VaultConfig::find_program_address(&handler.vault_program_id);
+
+    let (vault_update_state_tracker, _, _) =
+        VaultUpdateStateTracker::find_program_address(&handler.vault_program_id, vault, ncn_epoch);
+
+    let vault_update_state_tracker_account =
+        get_account(handler, &vault_update_state_tracker).await?;
+
+    if vault_update_state_tracker_account.is_none() {
+        let initialize_vault_update_state_tracker_ix =
+            InitializeVaultUpdateStateTrackerBuilder::new()
+                .vault(*vault)
+                .vault_update_state_tracker(vault_update_state_tracker)
+                .system_program(system_program::id())
+                .withdrawal_allocation_method(WithdrawalAllocationMethod::Greedy)
+                .payer(payer.pubkey())
+                .config(vault_config)
+                .instruction();
+
+        let result = send_and_log_transaction(
+            handler,
+            &[initialize_vault_update_state_tracker_ix],
+            &[payer],
+            "Initialize Vault Update State Tracker",
+            &[
+                format!("VAULT: {:?}", vault),
+                format!("Vault Epoch: {:?}", ncn_epoch),
+            ],
+        )
+        .await;
+
+        if let Err(err) = result {
+            log::error!(
+                "Failed to initialize vault update state tracker vault={} vault_epoch={}: {:#}",
+                vault,
+                ncn_epoch,
+                err
+            );
+        }
+    }
+
+    // Crank Vault Update State Tracker
+    let all_operators = get_all_sorted_operators_for_vault(handler, vault).await?;
+
+    if !all_operators.is_empty() {
+        let starting_index = {
+            let vault_update_state_tracker_account =
+                get_vault_update_state_tracker(handler, vault, ncn_epoch).await?;
+            let last_updated_index = vault_update_state_tracker_account.last_updated_index();
+
+            if last_updated_index == u64::MAX {
+                ncn_epoch % all_operators.len() as u64
+            } else {
+                (last_updated_index + 1) % all_operators.len() as u64
+            }
+        };
+
+        for index in 0..all_operators.len() {
+            let current_index = (starting_index as usize + index) % all_operators.len();
+            let operator = all_operators
+                .get(current_index)
+                .expect("current operator index should be in range");
+
+            let (vault_operator_delegation, _, _) = VaultOperatorDelegation::find_program_address(
+                &handler.vault_program_id,
+                vault,
+                operator,
+            );
+
+            let crank_vault_update_state_tracker_ix = CrankVaultUpdateStateTrackerBuilder::new()
+                .vault(*vault)
+                .operator(*operator)
+                .config(vault_config)
+                .vault_operator_delegation(vault_operator_delegation)
+                .vault_update_state_tracker(vault_update_state_tracker)
+                .instruction();
+
+            let result = send_and_log_transaction(
+                handler,
+                &[crank_vault_update_state_tracker_ix],
+                &[payer],
+                "Crank Vault Update State Tracker",
+                &[
+                    format!("VAULT: {:?}", vault),
+                    format!("Operator: {:?}", operator),
+                    format!("Vault Epoch: {:?}", ncn_epoch),
+                ],
+            )
+            .await;
+
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to crank vault update state tracker vault={} operator={} vault_epoch={}: {:#}",
+                    vault,
+                    operator,
+                    ncn_epoch,
+                    err
+                );
+            }
+        }
+    }
+
+    // Close Update State Tracker
+    let vault_update_state_tracker_account =
+        get_account(handler, &vault_update_state_tracker).await?;
+
+    if vault_update_state_tracker_account.is_some() {
+        let close_vault_update_state_tracker_ix = CloseVaultUpdateStateTrackerBuilder::new()
+            .vault(*vault)
+            .vault_update_state_tracker(vault_update_state_tracker)
+            .payer(payer.pubkey())
+            .config(vault_config)
+            .ncn_epoch(ncn_epoch)
+            .instruction();
+
+        let result = send_and_log_transaction(
+            handler,
+            &[close_vault_update_state_tracker_ix],
+            &[payer],
+            "Close Vault Update State Tracker",
+            &[
+                format!("VAULT: {:?}", vault),
+                format!("Vault Epoch: {:?}", ncn_epoch),
+            ],
+        )
+        .await;
+
+        if let Err(err) = result {
+            log::error!(
+                "Failed to close vault update state tracker vault={} vault_epoch={}: {:#}",
+                vault,
+                ncn_epoch,
+                err
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn get_or_create_weight_table(handler: &CliHandler, epoch: u64) -> Result<WeightTable> {
+    let ncn = *handler.ncn()?;
+
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    if get_account(handler, &weight_table)
+        .await?
+        .is_none_or(|table| table.data.len() < WeightTable::SIZE)
+    {
+        create_weight_table(handler, epoch).await?;
+        check_created(handler, &weight_table).await?;
+    }
+
+    let weight_table_account = get_weight_table(handler, epoch).await?;
+    if weight_table_account.pages_initialized() < weight_table_account.page_count() {
+        create_weight_table_pages(handler, epoch).await?;
+        return get_weight_table(handler, epoch).await;
+    }
+
+    Ok(weight_table_account)
+}
+
+pub async fn get_or_create_epoch_snapshot(
+    handler: &CliHandler,
+    epoch: u64,
+) -> Result<EpochSnapshot> {
+    let ncn = *handler.ncn()?;
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    if get_account(handler, &epoch_snapshot)
+        .await?
+        .is_none_or(|snapshot| snapshot.data.len() < EpochSnapshot::SIZE)
+    {
+        create_epoch_snapshot(handler, epoch).await?;
+        check_created(handler, &epoch_snapshot).await?;
+    }
+
+    get_epoch_snapshot(handler, epoch).await
+}
+
+pub async fn get_or_create_operator_snapshot(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<OperatorSnapshot> {
+    let ncn = *handler.ncn()?;
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        &handler.tip_router_program_id,
+        operator,
+        &ncn,
+        epoch,
+    );
+
+    if get_account(handler, &operator_snapshot)
+        .await?
+        .is_none_or(|snapshot| snapshot.data.len() < OperatorSnapshot::SIZE)
+    {
+        create_operator_snapshot(handler, operator, epoch).await?;
+        check_created(handler, &operator_snapshot).await?;
+    }
+
+    let operator_snapshot_account = get_operator_snapshot(handler, operator, epoch).await?;
+    if !operator_snapshot_account.finalized()
+        && operator_snapshot_account.page_count() < get_vault_registry(handler).await?.page_count()
+    {
+        create_operator_snapshot_pages(handler, operator, epoch).await?;
+        return get_operator_snapshot(handler, operator, epoch).await;
+    }
+
+    Ok(operator_snapshot_account)
+}
+
+#[allow(clippy::large_stack_frames)]
+pub async fn get_or_create_ballot_box(handler: &CliHandler, epoch: u64) -> Result<BallotBox> {
+    let ncn = *handler.ncn()?;
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    if get_account(handler, &ballot_box)
+        .await?
+        .is_none_or(|ballot_box| ballot_box.data.len() < BallotBox::SIZE)
+    {
+        create_ballot_box(handler, epoch).await?;
+        check_created(handler, &ballot_box).await?;
+    }
+
+    // Operators past the ballot box vote in its pages
+    let ballot_box_account = get_ballot_box(handler, epoch).await?;
+    let pages_needed = BallotBoxPage::pages_needed(get_ncn(handler).await?.operator_count());
+    if ballot_box_account.page_count() < pages_needed {
+        create_ballot_box_pages(
+            handler,
+            epoch,
+            ballot_box_account.page_count(),
+            pages_needed,
+        )
+        .await?;
+        return get_ballot_box(handler, epoch).await;
+    }
+
+    Ok(ballot_box_account)
+}
+
+pub async fn get_or_create_base_reward_router(
+    handler: &CliHandler,
+    epoch: u64,
+) -> Result<BaseRewardRouter> {
+    let ncn = *handler.ncn()?;
+    let (base_reward_router, _, _) =
+        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    if get_account(handler, &base_reward_router)
+        .await?
+        .is_none_or(|router| router.data.len() < BaseRewardRouter::SIZE)
+    {
+        create_base_reward_router(handler, epoch).await?;
+        check_created(handler, &base_reward_router).await?;
+    }
+
+    // Operators voting in ballot box pages are routed in router pages
+    let base_reward_router_account = get_base_reward_router(handler, epoch).await?;
+    let pages_needed = get_ballot_box(handler, epoch).await?.page_count();
+    if base_reward_router_account.page_count() < pages_needed {
+        create_base_reward_router_pages(
+            handler,
+            &Pubkey::default(),
+            epoch,
+            base_reward_router_account.page_count(),
+            pages_needed,
+        )
+        .await?;
+        return get_base_reward_router(handler, epoch).await;
+    }
+
+    Ok(base_reward_router_account)
+}
+
+pub async fn get_or_create_ncn_reward_router(
+    handler: &CliHandler,
+    ncn_fee_group: NcnFeeGroup,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<NcnRewardRouter> {
+    let ncn = *handler.ncn()?;
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        &handler.tip_router_program_id,
+        operator,
+        &ncn,
+        epoch,
+    );
+
+    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        operator,
+        &ncn,
+        epoch,
+    );
+
+    // If operator snapshot does not exist, we cannot create the ncn reward router
+    if get_account(handler, &operator_snapshot).await?.is_none() {
+        return Err(anyhow!("Invalid Route"));
+    }
+
+    if get_account(handler, &ncn_reward_router)
+        .await?
+        .is_none_or(|router| router.data.len() < NcnRewardRouter::SIZE)
+    {
+        create_ncn_reward_router(handler, ncn_fee_group, operator, epoch).await?;
+        check_created(handler, &ncn_reward_router).await?;
+    }
+    get_ncn_reward_router(handler, ncn_fee_group, operator, epoch).await
+}
+
+// --------------------- CRANKERS ------------------------------
+
+/// Registers unregistered vaults in the NCN system with the vault registry.
+///
+/// - Fetches all vaults by NCN address and currently registered vaults
+/// - Identifies unregistered vaults by comparing two lists
+/// - For each unregistered vault:
+///     - Retrives vault account data
+///     - Validates the vault's supported mint is registered
+///     - Attempts registration if valid
+pub async fn crank_register_vaults(handler: &CliHandler) -> Result<()> {
+    let rpc_client = handler.rpc_client();
+    let all_ncn_vaults = get_all_vaults_in_ncn(handler).await?;
+    let vault_registry = get_vault_registry(handler).await?;
+    let vault_registry_pages = get_vault_registry_pages(handler, &vault_registry).await?;
+    // Deactivated vaults are still registered
+    let all_registered_vaults: Vec<Pubkey> = vault_registry
+        .get_vault_entries()
+        .iter()
+        .chain(
+            vault_registry_pages
+                .iter()
+                .flat_map(|page| page.get_vault_entries().iter()),
+        )
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| *entry.vault())
+        .collect();
+
+    let vaults_to_register: Vec<Pubkey> = all_ncn_vaults
+        .iter()
+        .filter(|vault| !all_registered_vaults.contains(vault))
+        .copied()
+        .collect();
+
+    let current_slot = get_current_slot(handler).await?;
+    let epoch_length = get_restaking_config(handler).await?.epoch_length();
+
+    for vault in vaults_to_register.iter() {
+        // Registration needs the NCN <> vault ticket to be active
+        let ncn_vault_ticket = get_ncn_vault_ticket(handler, vault).await?;
+        if !ncn_vault_ticket
+            .state
+            .is_active(current_slot, epoch_length)?
+        {
+            log::warn!(
+                "Skipping vault registration, ncn vault ticket not active vault={}",
+                vault,
+            );
+            continue;
+        }
+
+        let vault_raw_acc = rpc_client.get_account(vault).await?;
+        let vault_acc = Vault::try_from_slice_unchecked(&vault_raw_acc.data)?;
+
+        if vault_registry.has_st_mint(&vault_acc.supported_mint) {
+            if let Err(err) = register_vault(handler, vault).await {
+                log::error!("Failed to register vault vault={}: {:#}", vault, err);
+            }
+        } else {
+            log::warn!(
+                "Skipping vault registration, st_mint not registered vault={}",
+                vault,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn crank_set_weight(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let weight_table = get_or_create_weight_table(handler, epoch).await?;
+
+    let st_mints = weight_table
+        .table()
+        .iter()
+        .filter(|entry| !entry.is_empty() && !entry.is_set())
+        .map(|entry| *entry.st_mint())
+        .collect::<Vec<Pubkey>>();
+
+    for st_mint in st_mints {
+        let result = set_weight_with_st_mint(handler, &st_mint, epoch).await;
+
+        if let Err(err) = result {
+            log::error!(
+                "Failed to set weight st_mint={} epoch={}: {:#}",
+                st_mint,
+                epoch,
+                err
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn crank_snapshot(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let vault_registry = get_vault_registry(handler).await?;
+    let vault_registry_pages = get_vault_registry_pages(handler, &vault_registry).await?;
+
+    let operators = get_all_operators_in_ncn(handler).await?;
+    let all_vaults: Vec<Pubkey> = vault_registry
+        .get_valid_vault_entries(epoch)
+        .iter()
+        .chain(
+            vault_registry_pages
+                .iter()
+                .flat_map(|page| page.get_vault_entries().iter())
+                .filter(|entry| entry.is_active(epoch)),
+        )
+        .map(|entry| *entry.vault())
+        .collect();
+
+    let epoch_snapshot = get_or_create_epoch_snapshot(handler, epoch).await?;
+    if epoch_snapshot.finalized() {
+        log::info!(
+            "Epoch snapshot already finalized, skipping snapshot epoch={}",
+            epoch
+        );
+        return Ok(());
+    }
+
+    for operator in operators.iter() {
+        // Create Vault Operator Delegation
+        let result = get_or_create_operator_snapshot(handler, operator, epoch).await;
+
+        if let Err(err) = result {
+            log::error!(
+                "Failed to get or create operator snapshot operator={} epoch={}: {:#}",
+                operator,
+                epoch,
+                err
+            );
+            continue;
+        };
+
+        let operator_snapshot = result?;
+        let operator_snapshot_pages =
+            get_operator_snapshot_pages(handler, &operator_snapshot).await?;
+
+        let vaults_to_run: Vec<Pubkey> = all_vaults
+            .iter()
+            .filter(|vault| {
+                !operator_snapshot.contains_vault(vault)
+                    && !operator_snapshot_pages
+                        .iter()
+                        .any(|page| page.contains_vault(vault))
+            })
+            .cloned()
+            .collect();
+
+        for vault in vaults_to_run.iter() {
+            let result = full_vault_update(handler, vault).await;
+
+            if let Err(err) = result {
+                log::error!("Failed to update vault vault={}: {:#}", vault, err);
+            }
+
+            let result = snapshot_vault_operator_delegation(handler, vault, operator, epoch).await;
+
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to snapshot vault operator delegation vault={} operator={} epoch={}: {:#}",
+                    vault,
+                    operator,
+                    epoch,
+                    err
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::large_stack_frames)]
+pub async fn crank_vote(handler: &CliHandler, epoch: u64, test_vote: bool) -> Result<()> {
+    // VOTE
+
+    let ballot_box = get_or_create_ballot_box(handler, epoch).await?;
+    if ballot_box.is_consensus_reached() {
+        log::info!("Consensus already reached, skipping voting epoch={}", epoch);
+        return Ok(());
+    }
+
+    // Votes are weighed once every operator's stake weight caps are applied
+    crank_stake_weight_caps(handler, epoch).await?;
+
+    if test_vote {
+        crank_test_vote(handler, epoch).await?;
+    }
+
+    // Once voting has stalled, finalize the leading ballot if it clears the fallback threshold
+    let config = get_tip_router_config(handler).await?;
+    let current_epoch = get_current_epoch(handler).await?;
+    let ballot_box = get_ballot_box(handler, epoch).await?;
+    if !ballot_box.is_consensus_reached()
+        && config.is_stall_fallback_enabled()
+        && current_epoch >= epoch.saturating_add(config.epochs_before_stall())
+    {
+        if let Err(err) = resolve_stalled_vote(handler, epoch).await {
+            log::error!("Failed to resolve stalled vote epoch={}: {:#}", epoch, err);
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn crank_stake_weight_caps(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let epoch_snapshot = get_epoch_snapshot(handler, epoch).await?;
+    if !epoch_snapshot.finalized() || epoch_snapshot.stake_weight_caps_applied() {
+        return Ok(());
+    }
+
+    let operators = get_all_operators_in_ncn(handler).await?;
+
+    for operator in operators.iter() {
+        let operator_snapshot = get_operator_snapshot(handler, operator, epoch).await?;
+        if operator_snapshot.stake_weight_caps_applied() {
+            continue;
+        }
+
+        let result = apply_stake_weight_caps(handler, operator, epoch).await;
+
+        if let Err(err) = result {
+            log::error!(
+                "Failed to apply stake weight caps operator={} epoch={}: {:#}",
+                operator,
+                epoch,
+                err
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::large_stack_frames)]
+pub async fn crank_post_vote_cooldown(_: &CliHandler, _: u64) -> Result<()> {
+    Ok(())
+}
+
+#[allow(clippy::large_stack_frames)]
+pub async fn crank_test_vote(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let voter = handler.keypair().pubkey();
+    let meta_merkle_root = [8; 32];
+    let operators = get_all_operators_in_ncn(handler).await?;
+
+    for operator in operators.iter() {
+        let operator_account = get_operator(handler, operator).await?;
+
+        if operator_account.voter.ne(&voter) {
+            continue;
+        }
+
+        let result = operator_cast_vote(handler, operator, epoch, meta_merkle_root).await;
+
+        if let Err(err) = result {
+            log::error!(
+                "Failed to cast vote operator={} epoch={}: {:#}",
+                operator,
+                epoch,
+                err
+            );
+        }
+    }
+
+    let ballot_box = get_or_create_ballot_box(handler, epoch).await?;
+
+    // Send 'Test' Rewards
+    if ballot_box.is_consensus_reached() {
+        let (base_reward_receiver_address, _, _) = BaseRewardReceiver::find_program_address(
+            &handler.tip_router_program_id,
+            handler.ncn()?,
+            epoch,
+        );
+
+        let base_reward_receiver = get_account(handler, &base_reward_receiver_address).await?;
+
+        if base_reward_receiver.is_none() {
+            let keypair = handler.keypair();
+
+            let lamports =
+                sol_str_to_lamports("0.1").expect("hard-coded SOL amount should be valid");
+            let transfer_ix = transfer(&keypair.pubkey(), &base_reward_receiver_address, lamports);
+
+            send_and_log_transaction(
+                handler,
+                &[transfer_ix],
+                &[],
+                "Sent Test Rewards",
+                &[format!("Epoch: {:?}", epoch)],
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+//TODO Multi-thread sending the TXs
+pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let operators = get_all_operators_in_ncn(handler).await?;
+
+    let epoch_snapshot = get_or_create_epoch_snapshot(handler, epoch).await?;
+    let fees = epoch_snapshot.fees();
+
+    let base_reward_router = get_or_create_base_reward_router(handler, epoch).await?;
+
+    let base_reward_receiver_rewards = get_base_reward_receiver_rewards(handler, epoch).await?;
+    if base_reward_receiver_rewards > 0 {
+        route_base_rewards(handler, epoch).await?;
+    }
+
+    for group in BaseFeeGroup::all_groups() {
+        if fees.base_fee_bps(group)? == 0 {
+            continue;
+        }
+
+        if base_reward_router.base_fee_group_reward(group)? != 0 {
+            let result = distribute_base_rewards(handler, group, epoch).await;
+
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to distribute base rewards group={:?} epoch={}: {:#}",
+                    group,
+                    epoch,
+                    err
+                );
+            }
+        }
+    }
+
+    for operator in operators.iter() {
+        for group in NcnFeeGroup::all_groups() {
+            if fees.ncn_fee_bps(group)? == 0 {
+                continue;
+            }
+
+            let result = get_or_create_ncn_reward_router(handler, group, operator, epoch).await;
+            if let Err(err) = result {
+                log::info!(
+                    "Skipping NCN reward router operator={} group={:?} epoch={}: {:#}",
+                    operator,
+                    group,
+                    epoch,
+                    err
+                );
+                continue;
+            }
+
+            let Some(route) =
+                get_base_reward_route(handler, &Pubkey::default(), operator, epoch).await?
+            else {
+                log::info!(
+                    "Skipping reward route, none exists operator={} group={:?} epoch={}",
+                    operator,
+                    group,
+                    epoch,
+                );
+                continue;
+            };
+
+            if route.rewards(group)? != 0 {
+                let result = distribute_base_ncn_rewards(handler, operator, group, epoch).await;
+
+                if let Err(err) = result {
+                    log::error!(
+                        "Failed to distribute base NCN rewards operator={} group={:?} epoch={}: {:#}",
+                        operator,
+                        group,
+                        epoch,
+                        err
+                    );
+                    continue;
+                }
+            }
+
+            let ncn_reward_receiver_rewards =
+                get_ncn_reward_receiver_rewards(handler, group, operator, epoch).await?;
+
+            if ncn_reward_receiver_rewards > 0 {
+                let result = route_ncn_rewards(handler, operator, group, epoch).await;
+
+                if let Err(err) = result {
+                    log::error!(
+                        "Failed to route NCN rewards operator={} group={:?} epoch={}: {:#}",
+                        operator,
+                        group,
+                        epoch,
+                        err
+                    );
+                    continue;
+                }
+            }
+
+            let result = get_or_create_ncn_reward_router(handler, group, operator, epoch).await;
+            if let Err(err) = result {
+                log::info!(
+                    "Skipping NCN reward router operator={} group={:?} epoch={}: {:#}",
+                    operator,
+                    group,
+                    epoch,
+                    err
+                );
+                continue;
+            }
+            let ncn_reward_router = result?;
+
+            if ncn_reward_router.operator_rewards() != 0 {
+                let result = distribute_ncn_operator_rewards(handler, operator, group, epoch).await;
+
+                if let Err(err) = result {
+                    log::error!(
+                        "Failed to distribute NCN operator rewards operator={} group={:?} epoch={}: {:#}",
+                        operator,
+                        group,
+                        epoch,
+                        err
+                    );
+                    continue;
+                }
+            }
+
+            let vaults_to_route = ncn_reward_router
+                .vault_reward_routes()
+                .iter()
+                .filter(|route| !route.is_empty() && route.has_rewards())
+                .map(|route| route.vault())
+                .collect::<Vec<Pubkey>>();
+
+            for vault in vaults_to_route {
+                let result: std::result::Result<(), anyhow::Error> =
+                    distribute_ncn_vault_rewards(handler, &vault, operator, group, epoch).await;
+
+                if let Err(err) = result {
+                    log::error!(
+                        "Failed to distribute NCN vault rewards vault={} operator={} group={:?} epoch={}: {:#}",
+                        vault,
+                        operator,
+                        group,
+                        epoch,
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Closes the pages of a base reward router from the last one, they have to be closed before
+/// the router itself
+async fn close_base_reward_router_pages(
+    handler: &CliHandler,
+    ncn: Pubkey,
+    mint: &Pubkey,
+    epoch: u64,
+    base_reward_router: Pubkey,
+) {
+    let Some(page_count) = get_base_reward_router_for_mint(handler, mint, epoch)
+        .await
+        .ok()
+        .map(|base_reward_router| base_reward_router.page_count())
+    else {
+        return;
+    };
+
+    for page in (0..page_count).rev() {
+        let (base_reward_router_page, _, _) = BaseRewardRouterPage::find_program_address_for_mint(
+            &handler.tip_router_program_id,
+            &ncn,
+            mint,
+            epoch,
+            page,
+        );
+
+        let result = close_epoch_account(
+            handler,
+            ncn,
+            epoch,
+            base_reward_router_page,
+            Some(base_reward_router),
+        )
+        .await;
+
+        if let Err(err) = result {
+            log::error!(
+                "Failed to close base reward router page account={} epoch={}: {:#}",
+                base_reward_router_page,
+                epoch,
+                err
+            );
+        }
+    }
+}
+
+pub async fn crank_close_epoch_accounts(handler: &CliHandler, epoch: u64) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    // One last distribution crank
+    let result = crank_distribute(handler, epoch).await;
+    if let Err(err) = result {
+        log::error!(
+            "Failed to distribute rewards before closing epoch={}: {:#}",
+            epoch,
+            err
+        );
+    }
+
+    // Close NCN Reward Routers
+    let operators = get_all_operators_in_ncn(handler).await?;
+    for operator in operators.iter() {
+        // Operators past MAX_OPERATORS also take their epoch state page
+        let epoch_state_page = get_epoch_state_page_for_operator(handler, operator, epoch).await?;
+
+        for group in NcnFeeGroup::all_groups() {
+            let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+                &handler.tip_router_program_id,
+                group,
+                operator,
+                &ncn,
+                epoch,
+            );
+
+            let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+                &handler.tip_router_program_id,
+                group,
+                operator,
+                &ncn,
+                epoch,
+            );
+
+            // Whatever could not be distributed is routed again in the current epoch
+            let result =
+                carry_forward_rewards(handler, ncn, epoch, ncn_reward_router, ncn_reward_receiver)
+                    .await;
+
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to carry forward NCN rewards account={} epoch={}: {:#}",
+                    ncn_reward_router,
+                    epoch,
+                    err
+                );
+            }
+
+            let result = close_epoch_account_with_remaining_accounts(
+                handler,
+                ncn,
+                epoch,
+                ncn_reward_router,
+                Some(ncn_reward_receiver),
+                epoch_state_page.as_slice(),
+            )
+            .await;
+
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to close NCN reward router account={} epoch={}: {:#}",
+                    ncn_reward_router,
+                    epoch,
+                    err
+                );
+            }
+        }
+    }
+
+    // Close Base Reward Router
+    let (base_reward_router, _, _) =
+        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (base_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let result = carry_forward_rewards(
+        handler,
+        ncn,
+        epoch,
+        base_reward_router,
+        base_reward_receiver,
+    )
+    .await;
+
+    if let Err(err) = result {
+        log::error!(
+            "Failed to carry forward base rewards account={} epoch={}: {:#}",
+            base_reward_router,
+            epoch,
+            err
+        );
+    }
+
+    close_base_reward_router_pages(handler, ncn, &Pubkey::default(), epoch, base_reward_router)
+        .await;
+
+    let result = close_epoch_account(
+        handler,
+        ncn,
+        epoch,
+        base_reward_router,
+        Some(base_reward_receiver),
+    )
+    .await;
+
+    if let Err(err) = result {
+        log::error!(
+            "Failed to close base reward router account={} epoch={}: {:#}",
+            base_reward_router,
+            epoch,
+            err
+        );
+    }
+
+    // Close Ballot Box
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    // Its pages have to be closed first, from the last one
+    if let std::result::Result::Ok(ballot_box_account) = get_ballot_box(handler, epoch).await {
+        for page in (0..ballot_box_account.page_count()).rev() {
+            let (ballot_box_page, _, _) = BallotBoxPage::find_program_address(
+                &handler.tip_router_program_id,
+                &ncn,
+                epoch,
+                page,
+            );
+
+            let result =
+                close_epoch_account(handler, ncn, epoch, ballot_box_page, Some(ballot_box)).await;
+
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to close ballot box page account={} epoch={}: {:#}",
+                    ballot_box_page,
+                    epoch,
+                    err
+                );
+            }
+        }
+    }
+
+    let result = close_epoch_account(handler, ncn, epoch, ballot_box, None).await;
+
+    if let Err(err) = result {
+        log::error!(
+            "Failed to close ballot box account={} epoch={}: {:#}",
+            ballot_box,
+            epoch,
+            err
+        );
+    }
+
+    // Close Operator Snapshots
+    for operator in operators.iter() {
+        let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+            &handler.tip_router_program_id,
+            operator,
+            &ncn,
+            epoch,
+        );
+
+        // Its pages have to be closed first, from the last one
+        if let std::result::Result::Ok(operator_snapshot_account) =
+            get_operator_snapshot(handler, operator, epoch).await
+        {
+            for page in (0..operator_snapshot_account.page_count()).rev() {
+                let (operator_snapshot_page, _, _) = OperatorSnapshotPage::find_program_address(
+                    &handler.tip_router_program_id,
+                    operator,
+                    &ncn,
+                    epoch,
+                    page,
+                );
+
+                let result = close_epoch_account(
+                    handler,
+                    ncn,
+                    epoch,
+                    operator_snapshot_page,
+                    Some(operator_snapshot),
+                )
+                .await;
+
+                if let Err(err) = result {
+                    log::error!(
+                        "Failed to close operator snapshot page account={} epoch={}: {:#}",
+                        operator_snapshot_page,
+                        epoch,
+                        err
+                    );
+                }
+            }
+        }
+
+        // Operators past MAX_OPERATORS take their epoch state page as the receiver
+        let epoch_state_page = get_epoch_state_page_for_operator(handler, operator, epoch).await?;
+        let result =
+            close_epoch_account(handler, ncn, epoch, operator_snapshot, epoch_state_page).await;
+
+        if let Err(err) = result {
+            log::error!(
+                "Failed to close operator snapshot account={} epoch={}: {:#}",
+                operator_snapshot,
+                epoch,
+                err
+            );
+        }
+    }
+
+    // Close Epoch Snapshot
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let result = close_epoch_account(handler, ncn, epoch, epoch_snapshot, None).await;
+
+    if let Err(err) = result {
+        log::error!(
+            "Failed to close epoch snapshot account={} epoch={}: {:#}",
+            epoch_snapshot,
+            epoch,
+            err
+        );
+    }
+
+    // Close Weight Table
+    let (weight_table, _, _) =
+        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    // Its pages have to be closed first, from the last one
+    if let std::result::Result::Ok(weight_table_account) = get_weight_table(handler, epoch).await {
+        for page in (0..weight_table_account.pages_initialized()).rev() {
+            let (weight_table_page, _, _) = WeightTablePage::find_program_address(
+                &handler.tip_router_program_id,
+                &ncn,
+                epoch,
+                page,
+            );
+
+            let result =
+                close_epoch_account(handler, ncn, epoch, weight_table_page, Some(weight_table))
+                    .await;
+
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to close weight table page account={} epoch={}: {:#}",
+                    weight_table_page,
+                    epoch,
+                    err
+                );
+            }
+        }
+    }
+
+    let result = close_epoch_account(handler, ncn, epoch, weight_table, None).await;
+
+    if let Err(err) = result {
+        log::error!(
+            "Failed to close weight table account={} epoch={}: {:#}",
+            weight_table,
+            epoch,
+            err
+        );
+    }
+
+    // Close Epoch State
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    // Its pages have to be closed first, from the last one
+    if let std::result::Result::Ok(epoch_state_account) = get_epoch_state(handler, epoch).await {
+        for page in (0..epoch_state_account.page_count()).rev() {
+            let (epoch_state_page, _, _) = EpochStatePage::find_program_address(
+                &handler.tip_router_program_id,
+                &ncn,
+                epoch,
+                page,
+            );
+
+            let result = close_epoch_account(handler, ncn, epoch, epoch_state_page, None).await;
+
+            if let Err(err) = result {
+                log::error!(
+                    "Failed to close epoch state page account={} epoch={}: {:#}",
+                    epoch_state_page,
+                    epoch,
+                    err
+                );
+            }
+        }
+    }
+
+    let result = close_epoch_account(handler, ncn, epoch, epoch_state, None).await;
+
+    if let Err(err) = result {
+        log::error!(
+            "Failed to close epoch state account={} epoch={}: {:#}",
+            epoch_state,
+            epoch,
+            err
+        );
+    }
+
+    Ok(())
+}
+
+// --------------------- TOKEN REWARDS ----------------------------
+
+/// Creates the base reward router for SPL token rewards of `mint`, and the base reward
+/// receiver's ATA the rewards are sent to
+pub async fn get_or_create_token_base_reward_router(
+    handler: &CliHandler,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<BaseRewardRouter> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (base_reward_router, _, _) = BaseRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        &ncn,
+        mint,
+        epoch,
+    );
+
+    let (base_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let base_reward_router_account = get_account(handler, &base_reward_router).await?;
+
+    if base_reward_router_account.is_none() {
+        let create_receiver_ata_ix = create_associated_token_account_idempotent(
+            &keypair.pubkey(),
+            &base_reward_receiver,
+            mint,
+            &handler.token_program_id,
+        );
+
+        let mut initialize_base_reward_router_ix = InitializeBaseRewardRouterBuilder::new()
+            .epoch_marker(epoch_marker)
+            .ncn(ncn)
+            .epoch_state(epoch_state)
+            .base_reward_router(base_reward_router)
+            .base_reward_receiver(base_reward_receiver)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .mint(Some(*mint))
+            .config(Some(config))
+            .epoch(epoch)
+            .instruction();
+        initialize_base_reward_router_ix.program_id = handler.tip_router_program_id;
+
+        send_and_log_transaction(
+            handler,
+            &[create_receiver_ata_ix, initialize_base_reward_router_ix],
+            &[],
+            "Initialized Token Base Reward Router",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Mint: {:?}", mint),
+                format!("Epoch: {:?}", epoch),
+            ],
+        )
+        .await?;
+    }
+
+    if base_reward_router_account.is_none_or(|router| router.data.len() < BaseRewardRouter::SIZE) {
+        // Number of reallocations needed based on BaseRewardRouter::SIZE
+        let num_reallocs =
+            (BaseRewardRouter::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+
+        let mut realloc_base_reward_router_ix = ReallocBaseRewardRouterBuilder::new()
+            .config(TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0)
+            .epoch_state(epoch_state)
+            .base_reward_router(base_reward_router)
+            .ncn(ncn)
+            .epoch(epoch)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .mint(Some(*mint))
+            .instruction();
+        realloc_base_reward_router_ix.program_id = handler.tip_router_program_id;
+
+        let mut realloc_ixs = Vec::with_capacity(num_reallocs as usize);
+        realloc_ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
+        for _ in 0..num_reallocs {
+            realloc_ixs.push(realloc_base_reward_router_ix.clone());
+        }
+
+        send_and_log_transaction(
+            handler,
+            &realloc_ixs,
+            &[],
+            "Reallocated Token Base Reward Router",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Mint: {:?}", mint),
+                format!("Epoch: {:?}", epoch),
+                format!("Number of reallocations: {:?}", num_reallocs),
+            ],
+        )
+        .await?;
+
+        check_created(handler, &base_reward_router).await?;
+    }
+
+    // Operators voting in ballot box pages are routed in router pages
+    let base_reward_router_account = get_base_reward_router_for_mint(handler, mint, epoch).await?;
+    let pages_needed = get_ballot_box(handler, epoch).await?.page_count();
+    if base_reward_router_account.page_count() < pages_needed {
+        create_base_reward_router_pages(
+            handler,
+            mint,
+            epoch,
+            base_reward_router_account.page_count(),
+            pages_needed,
+        )
+        .await?;
+        return get_base_reward_router_for_mint(handler, mint, epoch).await;
+    }
+
+    Ok(base_reward_router_account)
+}
+
+/// Creates the NCN reward router for SPL token rewards of `mint`, and the NCN reward receiver's
+/// ATA the rewards are sent to
+pub async fn get_or_create_token_ncn_reward_router(
+    handler: &CliHandler,
+    ncn_fee_group: NcnFeeGroup,
+    operator: &Pubkey,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<NcnRewardRouter> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
+
+    let operator = *operator;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        &handler.tip_router_program_id,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        mint,
+        epoch,
+    );
+
+    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    // If operator snapshot does not exist, we cannot create the ncn reward router
+    if get_account(handler, &operator_snapshot).await?.is_none() {
+        return Err(anyhow!("Invalid Route"));
+    }
+
+    if get_account(handler, &ncn_reward_router).await?.is_none() {
+        let create_receiver_ata_ix = create_associated_token_account_idempotent(
+            &keypair.pubkey(),
+            &ncn_reward_receiver,
+            mint,
+            &handler.token_program_id,
+        );
+
+        let mut initialize_ncn_reward_router_ix = InitializeNcnRewardRouterBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_snapshot(operator_snapshot)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .mint(Some(*mint))
+            .config(Some(config))
+            .ncn_fee_group(ncn_fee_group.group)
+            .epoch(epoch)
+            .instruction();
+        initialize_ncn_reward_router_ix.program_id = handler.tip_router_program_id;
+
+        send_and_log_transaction(
+            handler,
+            &[create_receiver_ata_ix, initialize_ncn_reward_router_ix],
+            &[],
+            "Initialized Token NCN Reward Router",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Operator: {:?}", operator),
+                format!("NCN Fee Group: {:?}", ncn_fee_group.group),
+                format!("Mint: {:?}", mint),
+                format!("Epoch: {:?}", epoch),
+            ],
+        )
+        .await?;
+
+        check_created(handler, &ncn_reward_router).await?;
+    }
+
+    get_ncn_reward_router_for_mint(handler, ncn_fee_group, &operator, mint, epoch).await
+}
+
+pub async fn route_token_base_rewards(
+    handler: &CliHandler,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let keypair = handler.keypair();
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let config = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+
+    let (epoch_snapshot, _, _) =
+        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ballot_box, _, _) =
+        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (base_reward_router, _, _) = BaseRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        &ncn,
+        mint,
+        epoch,
+    );
+
+    let (base_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let base_reward_receiver_token_account =
+        BaseRewardReceiver::token_account(&handler.tip_router_program_id, &ncn, epoch, mint);
+
+    let routing_pages = get_routing_page_accounts(handler, mint, epoch).await?;
+
+    // Using max iterations defined in BaseRewardRouter
+    let max_iterations: u16 = BaseRewardRouter::MAX_ROUTE_BASE_ITERATIONS;
+
+    let mut still_routing = true;
+    while still_routing {
+        let mut route_base_rewards_ix = RouteBaseRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .epoch_snapshot(epoch_snapshot)
+            .ballot_box(ballot_box)
+            .base_reward_router(base_reward_router)
+            .base_reward_receiver(base_reward_receiver)
+            .mint(Some(*mint))
+            .base_reward_receiver_token_account(Some(base_reward_receiver_token_account))
+            .max_iterations(max_iterations)
+            .epoch(epoch)
+            .account_payer(Some(account_payer))
+            .cranker(Some(keypair.pubkey()))
+            .system_program(Some(system_program::id()))
+            .add_remaining_accounts(&routing_pages)
+            .instruction();
+        route_base_rewards_ix.program_id = handler.tip_router_program_id;
+
+        let instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            route_base_rewards_ix,
+        ];
+
+        send_and_log_transaction(
+            handler,
+            &instructions,
+            &[],
+            "Routed Token Base Rewards",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Mint: {:?}", mint),
+                format!("Epoch: {:?}", epoch),
+                format!("Max iterations: {:?}", max_iterations),
+            ],
+        )
+        .await?;
+
+        // Check if we need to continue routing
+        let base_reward_router_account =
+            get_base_reward_router_for_mint(handler, mint, epoch).await?;
+        still_routing = base_reward_router_account.still_routing();
+    }
+
+    Ok(())
+}
+
+pub async fn route_token_ncn_rewards(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    ncn_fee_group: NcnFeeGroup,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let keypair = handler.keypair();
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let config = TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn).0;
+
+    let operator = *operator;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        &handler.tip_router_program_id,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        mint,
+        epoch,
+    );
+
+    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let ncn_reward_receiver_token_account = NcnRewardReceiver::token_account(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        epoch,
+        mint,
+    );
+
+    let operator_snapshot_pages =
+        get_operator_snapshot_page_metas(handler, &operator, epoch).await?;
+
+    // Using max iterations defined in NcnRewardRouter
+    let max_iterations: u16 = NcnRewardRouter::MAX_ROUTE_NCN_ITERATIONS;
+
+    let mut still_routing = true;
+    while still_routing {
+        let mut route_ncn_rewards_ix = RouteNcnRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_snapshot(operator_snapshot)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .mint(Some(*mint))
+            .ncn_reward_receiver_token_account(Some(ncn_reward_receiver_token_account))
+            .ncn_fee_group(ncn_fee_group.group)
+            .max_iterations(max_iterations)
+            .epoch(epoch)
+            .account_payer(Some(account_payer))
+            .cranker(Some(keypair.pubkey()))
+            .system_program(Some(system_program::id()))
+            .add_remaining_accounts(&operator_snapshot_pages)
+            .instruction();
+        route_ncn_rewards_ix.program_id = handler.tip_router_program_id;
+
+        let instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            route_ncn_rewards_ix,
+        ];
+
+        send_and_log_transaction(
+            handler,
+            &instructions,
+            &[],
+            "Routed Token NCN Rewards",
+            &[
+                format!("NCN: {:?}", ncn),
+                format!("Operator: {:?}", operator),
+                format!("NCN Fee Group: {:?}", ncn_fee_group.group),
+                format!("Mint: {:?}", mint),
+                format!("Epoch: {:?}", epoch),
+                format!("Max iterations: {:?}", max_iterations),
+            ],
+        )
+        .await?;
+
+        // Check if we need to continue routing
+        let ncn_reward_router_account =
+            get_ncn_reward_router_for_mint(handler, ncn_fee_group, &operator, mint, epoch).await?;
+        still_routing = ncn_reward_router_account.still_routing();
+    }
+
+    Ok(())
+}
+
+pub async fn distribute_token_base_rewards(
+    handler: &CliHandler,
+    base_fee_group: BaseFeeGroup,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (base_reward_router, _, _) = BaseRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        &ncn,
+        mint,
+        epoch,
+    );
+
+    let (base_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let base_reward_receiver_token_account =
+        BaseRewardReceiver::token_account(&handler.tip_router_program_id, &ncn, epoch, mint);
+
+    let tip_router_config = get_tip_router_config(handler).await?;
+    let base_fee_wallet = tip_router_config
+        .fee_config
+        .base_fee_wallet(base_fee_group)?;
+
+    // The stake pool accounts are unused for token rewards but still have to be passed
+    let stake_pool_accounts = get_stake_pool_accounts(handler).await?;
+
+    let base_fee_wallet_ata = get_associated_token_address(base_fee_wallet, mint);
+
+    let create_base_fee_wallet_ata_ix = create_associated_token_account_idempotent(
+        &keypair.pubkey(),
+        base_fee_wallet,
+        mint,
+        &handler.token_program_id,
+    );
+
+    let mut distribute_base_rewards_ix = DistributeBaseRewardsBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ncn(ncn)
+        .base_reward_router(base_reward_router)
+        .base_reward_receiver(base_reward_receiver)
+        .system_program(system_program::id())
+        .epoch(epoch)
+        .base_fee_wallet(*base_fee_wallet)
+        .base_fee_wallet_ata(base_fee_wallet_ata)
+        .base_fee_group(base_fee_group.group)
+        .pool_mint(stake_pool_accounts.stake_pool.pool_mint)
+        .manager_fee_account(stake_pool_accounts.stake_pool.manager_fee_account)
+        .referrer_pool_tokens_account(stake_pool_accounts.referrer_pool_tokens_account)
+        .reserve_stake(stake_pool_accounts.stake_pool.reserve_stake)
+        .stake_pool(stake_pool_accounts.stake_pool_address)
+        .stake_pool_withdraw_authority(stake_pool_accounts.stake_pool_withdraw_authority)
+        .stake_pool_program(stake_pool_accounts.stake_pool_program_id)
+        .token_program(handler.token_program_id)
+        .mint(Some(*mint))
+        .base_reward_receiver_token_account(Some(base_reward_receiver_token_account))
+        .account_payer(Some(account_payer))
+        .cranker(Some(keypair.pubkey()))
+        .instruction();
+    distribute_base_rewards_ix.program_id = handler.tip_router_program_id;
+
+    send_and_log_transaction(
+        handler,
+        &[create_base_fee_wallet_ata_ix, distribute_base_rewards_ix],
+        &[],
+        "Distributed Token Base Rewards",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Base Fee Group: {:?}", base_fee_group.group),
+            format!("Mint: {:?}", mint),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn distribute_token_base_ncn_rewards(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    ncn_fee_group: NcnFeeGroup,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
+
+    let keypair = handler.keypair();
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let operator = *operator;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (base_reward_router, _, _) = BaseRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        &ncn,
+        mint,
+        epoch,
+    );
+
+    let (base_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        mint,
+        epoch,
+    );
+
+    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let base_reward_router_page =
+        get_base_reward_router_page_for_operator(handler, mint, &operator, epoch).await?;
+
+    let mut distribute_base_ncn_rewards_ix = DistributeBaseNcnRewardRouteBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ncn(ncn)
+        .operator(operator)
+        .base_reward_router(base_reward_router)
+        .base_reward_receiver(base_reward_receiver)
+        .ncn_reward_router(ncn_reward_router)
+        .ncn_reward_receiver(ncn_reward_receiver)
+        .system_program(system_program::id())
+        .mint(Some(*mint))
+        .base_reward_receiver_token_account(Some(BaseRewardReceiver::token_account(
+            &handler.tip_router_program_id,
+            &ncn,
+            epoch,
+            mint,
+        )))
+        .ncn_reward_receiver_token_account(Some(NcnRewardReceiver::token_account(
+            &handler.tip_router_program_id,
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+            mint,
+        )))
+        .token_program(Some(handler.token_program_id))
+        .ncn_fee_group(ncn_fee_group.group)
+        .epoch(epoch)
+        .account_payer(Some(account_payer))
+        .cranker(Some(keypair.pubkey()))
+        .base_reward_router_page(base_reward_router_page)
+        .instruction();
+    distribute_base_ncn_rewards_ix.program_id = handler.tip_router_program_id;
+
+    send_and_log_transaction(
+        handler,
+        &[distribute_base_ncn_rewards_ix],
+        &[],
+        "Distributed Token Base NCN Rewards",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!("NCN Fee Group: {:?}", ncn_fee_group.group),
+            format!("Mint: {:?}", mint),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn distribute_token_ncn_operator_rewards(
+    handler: &CliHandler,
+    operator: &Pubkey,
+    ncn_fee_group: NcnFeeGroup,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let operator = *operator;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        mint,
+        epoch,
+    );
+
+    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        &handler.tip_router_program_id,
+        &operator,
+        &ncn,
+        epoch,
+    );
+
+    let (payout_preference, _, _) =
+        PayoutPreference::find_program_address(&handler.tip_router_program_id, &operator, &ncn);
+
+    // The stake pool accounts are unused for token rewards but still have to be passed
+    let stake_pool_accounts = get_stake_pool_accounts(handler).await?;
+
+    let operator_ata = get_associated_token_address(&operator, mint);
+    let create_operator_ata_ix = create_associated_token_account_idempotent(
+        &keypair.pubkey(),
+        &operator,
+        mint,
+        &handler.token_program_id,
+    );
+
+    let mut distribute_ncn_operator_rewards_ix = DistributeNcnOperatorRewardsBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ncn(ncn)
+        .operator(operator)
+        .operator_ata(operator_ata)
+        .operator_snapshot(operator_snapshot)
+        .ncn_reward_router(ncn_reward_router)
+        .ncn_reward_receiver(ncn_reward_receiver)
+        .pool_mint(stake_pool_accounts.stake_pool.pool_mint)
+        .manager_fee_account(stake_pool_accounts.stake_pool.manager_fee_account)
+        .referrer_pool_tokens_account(stake_pool_accounts.referrer_pool_tokens_account)
+        .reserve_stake(stake_pool_accounts.stake_pool.reserve_stake)
+        .stake_pool(stake_pool_accounts.stake_pool_address)
+        .stake_pool_withdraw_authority(stake_pool_accounts.stake_pool_withdraw_authority)
+        .stake_pool_program(stake_pool_accounts.stake_pool_program_id)
+        .token_program(handler.token_program_id)
+        .system_program(system_program::id())
+        .payout_preference(payout_preference)
+        .mint(Some(*mint))
+        .ncn_reward_receiver_token_account(Some(NcnRewardReceiver::token_account(
+            &handler.tip_router_program_id,
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+            mint,
+        )))
+        .ncn_fee_group(ncn_fee_group.group)
+        .epoch(epoch)
+        .account_payer(Some(account_payer))
+        .cranker(Some(keypair.pubkey()))
+        .instruction();
+    distribute_ncn_operator_rewards_ix.program_id = handler.tip_router_program_id;
+
+    send_and_log_transaction(
+        handler,
+        &[create_operator_ata_ix, distribute_ncn_operator_rewards_ix],
+        &[],
+        "Distributed Token NCN Operator Rewards",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Operator: {:?}", operator),
+            format!("NCN Fee Group: {:?}", ncn_fee_group.group),
+            format!("Mint: {:?}", mint),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn distribute_token_ncn_vault_rewards(
+    handler: &CliHandler,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    ncn_fee_group: NcnFeeGroup,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let keypair = handler.keypair();
+    let ncn = *handler.ncn()?;
+
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let vault = *vault;
+
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+
+    let (ncn_config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
+
+    let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        operator,
+        &ncn,
+        mint,
+        epoch,
+    );
+
+    let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        &handler.tip_router_program_id,
+        ncn_fee_group,
+        operator,
+        &ncn,
+        epoch,
+    );
+
+    let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+        &handler.tip_router_program_id,
+        operator,
+        &ncn,
+        epoch,
+    );
+
+    let (payout_preference, _, _) =
+        PayoutPreference::find_program_address(&handler.tip_router_program_id, &vault, &ncn);
+
+    // The stake pool accounts are unused for token rewards but still have to be passed
+    let stake_pool_accounts = get_stake_pool_accounts(handler).await?;
+
+    let vault_ata = get_associated_token_address(&vault, mint);
+    let create_vault_ata_ix = create_associated_token_account_idempotent(
+        &keypair.pubkey(),
+        &vault,
+        mint,
+        &handler.token_program_id,
+    );
+
+    let mut distribute_ncn_vault_rewards_ix = DistributeNcnVaultRewardsBuilder::new()
+        .epoch_state(epoch_state)
+        .config(ncn_config)
+        .ncn(ncn)
+        .operator(*operator)
+        .vault(vault)
+        .vault_ata(vault_ata)
+        .operator_snapshot(operator_snapshot)
+        .ncn_reward_router(ncn_reward_router)
+        .ncn_reward_receiver(ncn_reward_receiver)
+        .pool_mint(stake_pool_accounts.stake_pool.pool_mint)
+        .manager_fee_account(stake_pool_accounts.stake_pool.manager_fee_account)
+        .referrer_pool_tokens_account(stake_pool_accounts.referrer_pool_tokens_account)
+        .reserve_stake(stake_pool_accounts.stake_pool.reserve_stake)
+        .stake_pool(stake_pool_accounts.stake_pool_address)
+        .stake_pool_withdraw_authority(stake_pool_accounts.stake_pool_withdraw_authority)
+        .stake_pool_program(stake_pool_accounts.stake_pool_program_id)
+        .token_program(handler.token_program_id)
+        .system_program(system_program::id())
+        .payout_preference(payout_preference)
+        .mint(Some(*mint))
+        .ncn_reward_receiver_token_account(Some(NcnRewardReceiver::token_account(
+            &handler.tip_router_program_id,
+            ncn_fee_group,
+            operator,
+            &ncn,
+            epoch,
+            mint,
+        )))
+        .ncn_fee_group(ncn_fee_group.group)
+        .epoch(epoch)
+        .account_payer(Some(account_payer))
+        .cranker(Some(keypair.pubkey()))
+        .instruction();
+    distribute_ncn_vault_rewards_ix.program_id = handler.tip_router_program_id;
+
+    send_and_log_transaction(
+        handler,
+        &[create_vault_ata_ix, distribute_ncn_vault_rewards_ix],
+        &[],
+        "Distributed Token NCN Vault Rewards",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Vault: {:?}", vault),
+            format!("Operator: {:?}", operator),
+            format!("NCN Fee Group: {:?}", ncn_fee_group.group),
+            format!("Mint: {:?}", mint),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
+
     Ok(())
 }
 
-#[allow(clippy::large_stack_frames)]
-pub async fn crank_test_vote(handler: &CliHandler, epoch: u64) -> Result<()> {
-    let voter = handler.keypair().pubkey();
-    let meta_merkle_root = [8; 32];
-    let operators = get_all_operators_in_ncn(handler).await?;
-
-    for operator in operators.iter() {
-        let operator_account = get_operator(handler, operator).await?;
+/// Closes a token reward router, sending what is left in its receiver's ATA to the DAO wallet's
+/// ATA
+async fn close_token_reward_router(
+    handler: &CliHandler,
+    ncn: Pubkey,
+    epoch: u64,
+    mint: &Pubkey,
+    reward_router: Pubkey,
+    reward_receiver: Pubkey,
+) -> Result<()> {
+    let keypair = handler.keypair();
 
-        if operator_account.voter.ne(&voter) {
-            continue;
-        }
+    let account_already_closed = get_account(handler, &reward_router)
+        .await?
+        .is_none_or(|account| account.data.is_empty() || account.lamports == 0);
+    if account_already_closed {
+        info!("Account already closed account={}", reward_router);
+        return Ok(());
+    }
 
-        let result = operator_cast_vote(handler, operator, epoch, meta_merkle_root).await;
+    let (epoch_marker, _, _) =
+        EpochMarker::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-        if let Err(err) = result {
-            log::error!(
-                "Failed to cast vote operator={} epoch={}: {:#}",
-                operator,
-                epoch,
-                err
-            );
-        }
-    }
+    let (epoch_state, _, _) =
+        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let ballot_box = get_or_create_ballot_box(handler, epoch).await?;
+    let (account_payer, _, _) =
+        AccountPayer::find_program_address(&handler.tip_router_program_id, &ncn);
 
-    // Send 'Test' Rewards
-    if ballot_box.is_consensus_reached() {
-        let (base_reward_receiver_address, _, _) = BaseRewardReceiver::find_program_address(
-            &handler.tip_router_program_id,
-            handler.ncn()?,
-            epoch,
-        );
+    let (config, _, _) =
+        TipRouterConfig::find_program_address(&handler.tip_router_program_id, &ncn);
 
-        let base_reward_receiver = get_account(handler, &base_reward_receiver_address).await?;
+    let config_account = get_tip_router_config(handler).await?;
+    let dao_wallet = *config_account
+        .fee_config
+        .base_fee_wallet(BaseFeeGroup::dao())?;
 
-        if base_reward_receiver.is_none() {
-            let keypair = handler.keypair();
+    let create_dao_wallet_ata_ix = create_associated_token_account_idempotent(
+        &keypair.pubkey(),
+        &dao_wallet,
+        mint,
+        &handler.token_program_id,
+    );
 
-            let lamports =
-                sol_str_to_lamports("0.1").expect("hard-coded SOL amount should be valid");
-            let transfer_ix = transfer(&keypair.pubkey(), &base_reward_receiver_address, lamports);
+    let mut close_epoch_account_ix = CloseEpochAccountBuilder::new()
+        .account_payer(account_payer)
+        .epoch_marker(epoch_marker)
+        .config(config)
+        .account_to_close(reward_router)
+        .epoch_state(epoch_state)
+        .ncn(ncn)
+        .dao_wallet(dao_wallet)
+        .system_program(system_program::id())
+        .receiver_to_close(Some(reward_receiver))
+        .add_remaining_accounts(&[
+            AccountMeta::new(get_associated_token_address(&reward_receiver, mint), false),
+            AccountMeta::new(get_associated_token_address(&dao_wallet, mint), false),
+            AccountMeta::new_readonly(handler.token_program_id, false),
+        ])
+        .epoch(epoch)
+        .instruction();
+    close_epoch_account_ix.program_id = handler.tip_router_program_id;
 
-            send_and_log_transaction(
-                handler,
-                &[transfer_ix],
-                &[],
-                "Sent Test Rewards",
-                &[format!("Epoch: {:?}", epoch)],
-            )
-            .await?;
-        }
-    }
+    send_and_log_transaction(
+        handler,
+        &[create_dao_wallet_ata_ix, close_epoch_account_ix],
+        &[],
+        "Close Token Reward Router",
+        &[
+            format!("NCN: {:?}", ncn),
+            format!("Account to Close: {:?}", reward_router),
+            format!("Receiver: {:?}", reward_receiver),
+            format!("Mint: {:?}", mint),
+            format!("Epoch: {:?}", epoch),
+        ],
+    )
+    .await?;
 
     Ok(())
 }
 
+/// Routes and distributes the SPL token rewards of `mint` sent to the reward receivers' ATAs.
+/// Token rewards are split like SOL rewards, but paid out in the token itself regardless of the
+/// recipients' payout preferences.
 //TODO Multi-thread sending the TXs
-pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
+pub async fn crank_distribute_token_rewards(
+    handler: &CliHandler,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
+    let ncn = *handler.ncn()?;
     let operators = get_all_operators_in_ncn(handler).await?;
 
     let epoch_snapshot = get_or_create_epoch_snapshot(handler, epoch).await?;
     let fees = epoch_snapshot.fees();
 
-    let base_reward_router = get_or_create_base_reward_router(handler, epoch).await?;
+    get_or_create_token_base_reward_router(handler, mint, epoch).await?;
 
-    let base_reward_receiver_rewards = get_base_reward_receiver_rewards(handler, epoch).await?;
+    let (base_reward_receiver, _, _) =
+        BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    let base_reward_receiver_rewards =
+        get_receiver_token_rewards(handler, &base_reward_receiver, mint).await?;
     if base_reward_receiver_rewards > 0 {
-        route_base_rewards(handler, epoch).await?;
+        route_token_base_rewards(handler, mint, epoch).await?;
     }
 
+    let base_reward_router = get_base_reward_router_for_mint(handler, mint, epoch).await?;
+
     for group in BaseFeeGroup::all_groups() {
         if fees.base_fee_bps(group)? == 0 {
             continue;
         }
 
         if base_reward_router.base_fee_group_reward(group)? != 0 {
-            let result = distribute_base_rewards(handler, group, epoch).await;
+            let result = distribute_token_base_rewards(handler, group, mint, epoch).await;
 
             if let Err(err) = result {
                 log::error!(
-                    "Failed to distribute base rewards group={:?} epoch={}: {:#}",
+                    "Failed to distribute token base rewards group={:?} mint={} epoch={}: {:#}",
                     group,
+                    mint,
                     epoch,
                     err
                 );
@@ -2825,42 +6192,32 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
                 continue;
             }
 
-            let result = get_or_create_ncn_reward_router(handler, group, operator, epoch).await;
+            let result =
+                get_or_create_token_ncn_reward_router(handler, group, operator, mint, epoch).await;
             if let Err(err) = result {
                 log::info!(
-                    "Skipping NCN reward router operator={} group={:?} epoch={}: {:#}",
+                    "Skipping token NCN reward router operator={} group={:?} mint={} epoch={}: {:#}",
                     operator,
                     group,
+                    mint,
                     epoch,
                     err
                 );
                 continue;
             }
 
-            let result = base_reward_router.ncn_fee_group_reward_route(operator);
-
-            if result.is_err() {
-                log::info!(
-                    "Skipping reward route, none exists operator={} group={:?} epoch={}",
-                    operator,
-                    group,
-                    epoch,
-                );
-                continue;
-            }
+            let route = get_base_reward_route(handler, mint, operator, epoch).await?;
 
-            if base_reward_router
-                .ncn_fee_group_reward_route(operator)?
-                .rewards(group)?
-                != 0
-            {
-                let result = distribute_base_ncn_rewards(handler, operator, group, epoch).await;
+            if route.is_some_and(|route| route.rewards(group).is_ok_and(|rewards| rewards != 0)) {
+                let result =
+                    distribute_token_base_ncn_rewards(handler, operator, group, mint, epoch).await;
 
                 if let Err(err) = result {
                     log::error!(
-                        "Failed to distribute base NCN rewards operator={} group={:?} epoch={}: {:#}",
+                        "Failed to distribute token base NCN rewards operator={} group={:?} mint={} epoch={}: {:#}",
                         operator,
                         group,
+                        mint,
                         epoch,
                         err
                     );
@@ -2868,17 +6225,25 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
                 }
             }
 
+            let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+                &handler.tip_router_program_id,
+                group,
+                operator,
+                &ncn,
+                epoch,
+            );
             let ncn_reward_receiver_rewards =
-                get_ncn_reward_receiver_rewards(handler, group, operator, epoch).await?;
+                get_receiver_token_rewards(handler, &ncn_reward_receiver, mint).await?;
 
             if ncn_reward_receiver_rewards > 0 {
-                let result = route_ncn_rewards(handler, operator, group, epoch).await;
+                let result = route_token_ncn_rewards(handler, operator, group, mint, epoch).await;
 
                 if let Err(err) = result {
                     log::error!(
-                        "Failed to route NCN rewards operator={} group={:?} epoch={}: {:#}",
+                        "Failed to route token NCN rewards operator={} group={:?} mint={} epoch={}: {:#}",
                         operator,
                         group,
+                        mint,
                         epoch,
                         err
                     );
@@ -2886,27 +6251,20 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
                 }
             }
 
-            let result = get_or_create_ncn_reward_router(handler, group, operator, epoch).await;
-            if let Err(err) = result {
-                log::info!(
-                    "Skipping NCN reward router operator={} group={:?} epoch={}: {:#}",
-                    operator,
-                    group,
-                    epoch,
-                    err
-                );
-                continue;
-            }
-            let ncn_reward_router = result?;
+            let ncn_reward_router =
+                get_ncn_reward_router_for_mint(handler, group, operator, mint, epoch).await?;
 
             if ncn_reward_router.operator_rewards() != 0 {
-                let result = distribute_ncn_operator_rewards(handler, operator, group, epoch).await;
+                let result =
+                    distribute_token_ncn_operator_rewards(handler, operator, group, mint, epoch)
+                        .await;
 
                 if let Err(err) = result {
                     log::error!(
-                        "Failed to distribute NCN operator rewards operator={} group={:?} epoch={}: {:#}",
+                        "Failed to distribute token NCN operator rewards operator={} group={:?} mint={} epoch={}: {:#}",
                         operator,
                         group,
+                        mint,
                         epoch,
                         err
                     );
@@ -2922,15 +6280,18 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
                 .collect::<Vec<Pubkey>>();
 
             for vault in vaults_to_route {
-                let result: std::result::Result<(), anyhow::Error> =
-                    distribute_ncn_vault_rewards(handler, &vault, operator, group, epoch).await;
+                let result = distribute_token_ncn_vault_rewards(
+                    handler, &vault, operator, group, mint, epoch,
+                )
+                .await;
 
                 if let Err(err) = result {
                     log::error!(
-                        "Failed to distribute NCN vault rewards vault={} operator={} group={:?} epoch={}: {:#}",
+                        "Failed to distribute token NCN vault rewards vault={} operator={} group={:?} mint={} epoch={}: {:#}",
                         vault,
                         operator,
                         group,
+                        mint,
                         epoch,
                         err
                     );
@@ -2942,14 +6303,20 @@ pub async fn crank_distribute(handler: &CliHandler, epoch: u64) -> Result<()> {
     Ok(())
 }
 
-pub async fn crank_close_epoch_accounts(handler: &CliHandler, epoch: u64) -> Result<()> {
+/// Closes the token reward routers of `mint`, they have to be closed before the epoch state can be
+pub async fn crank_close_token_reward_routers(
+    handler: &CliHandler,
+    mint: &Pubkey,
+    epoch: u64,
+) -> Result<()> {
     let ncn = *handler.ncn()?;
 
     // One last distribution crank
-    let result = crank_distribute(handler, epoch).await;
+    let result = crank_distribute_token_rewards(handler, mint, epoch).await;
     if let Err(err) = result {
         log::error!(
-            "Failed to distribute rewards before closing epoch={}: {:#}",
+            "Failed to distribute token rewards before closing mint={} epoch={}: {:#}",
+            mint,
             epoch,
             err
         );
@@ -2959,11 +6326,12 @@ pub async fn crank_close_epoch_accounts(handler: &CliHandler, epoch: u64) -> Res
     let operators = get_all_operators_in_ncn(handler).await?;
     for operator in operators.iter() {
         for group in NcnFeeGroup::all_groups() {
-            let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+            let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address_for_mint(
                 &handler.tip_router_program_id,
                 group,
                 operator,
                 &ncn,
+                mint,
                 epoch,
             );
 
@@ -2975,18 +6343,19 @@ pub async fn crank_close_epoch_accounts(handler: &CliHandler, epoch: u64) -> Res
                 epoch,
             );
 
-            let result = close_epoch_account(
+            let result = close_token_reward_router(
                 handler,
                 ncn,
                 epoch,
+                mint,
                 ncn_reward_router,
-                Some(ncn_reward_receiver),
+                ncn_reward_receiver,
             )
             .await;
 
             if let Err(err) = result {
                 log::error!(
-                    "Failed to close NCN reward router account={} epoch={}: {:#}",
+                    "Failed to close token NCN reward router account={} epoch={}: {:#}",
                     ncn_reward_router,
                     epoch,
                     err
@@ -2996,111 +6365,37 @@ pub async fn crank_close_epoch_accounts(handler: &CliHandler, epoch: u64) -> Res
     }
 
     // Close Base Reward Router
-    let (base_reward_router, _, _) =
-        BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
+    let (base_reward_router, _, _) = BaseRewardRouter::find_program_address_for_mint(
+        &handler.tip_router_program_id,
+        &ncn,
+        mint,
+        epoch,
+    );
 
     let (base_reward_receiver, _, _) =
         BaseRewardReceiver::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
 
-    let result = close_epoch_account(
+    close_base_reward_router_pages(handler, ncn, mint, epoch, base_reward_router).await;
+
+    let result = close_token_reward_router(
         handler,
         ncn,
         epoch,
+        mint,
         base_reward_router,
-        Some(base_reward_receiver),
+        base_reward_receiver,
     )
     .await;
 
     if let Err(err) = result {
         log::error!(
-            "Failed to close base reward router account={} epoch={}: {:#}",
+            "Failed to close token base reward router account={} epoch={}: {:#}",
             base_reward_router,
             epoch,
             err
         );
     }
 
-    // Close Ballot Box
-    let (ballot_box, _, _) =
-        BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let result = close_epoch_account(handler, ncn, epoch, ballot_box, None).await;
-
-    if let Err(err) = result {
-        log::error!(
-            "Failed to close ballot box account={} epoch={}: {:#}",
-            ballot_box,
-            epoch,
-            err
-        );
-    }
-
-    // Close Operator Snapshots
-    for operator in operators.iter() {
-        let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
-            &handler.tip_router_program_id,
-            operator,
-            &ncn,
-            epoch,
-        );
-
-        let result = close_epoch_account(handler, ncn, epoch, operator_snapshot, None).await;
-
-        if let Err(err) = result {
-            log::error!(
-                "Failed to close operator snapshot account={} epoch={}: {:#}",
-                operator_snapshot,
-                epoch,
-                err
-            );
-        }
-    }
-
-    // Close Epoch Snapshot
-    let (epoch_snapshot, _, _) =
-        EpochSnapshot::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let result = close_epoch_account(handler, ncn, epoch, epoch_snapshot, None).await;
-
-    if let Err(err) = result {
-        log::error!(
-            "Failed to close epoch snapshot account={} epoch={}: {:#}",
-            epoch_snapshot,
-            epoch,
-            err
-        );
-    }
-
-    // Close Weight Table
-    let (weight_table, _, _) =
-        WeightTable::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let result = close_epoch_account(handler, ncn, epoch, weight_table, None).await;
-
-    if let Err(err) = result {
-        log::error!(
-            "Failed to close weight table account={} epoch={}: {:#}",
-            weight_table,
-            epoch,
-            err
-        );
-    }
-
-    // Close Epoch State
-    let (epoch_state, _, _) =
-        EpochState::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
-
-    let result = close_epoch_account(handler, ncn, epoch, epoch_state, None).await;
-
-    if let Err(err) = result {
-        log::error!(
-            "Failed to close epoch state account={} epoch={}: {:#}",
-            epoch_state,
-            epoch,
-            err
-        );
-    }
-
     Ok(())
 }
 
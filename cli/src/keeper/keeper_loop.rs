@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use crate::{
-    getters::get_guaranteed_epoch_and_slot,
+    getters::{get_guaranteed_epoch_and_slot, get_tip_router_config},
     handler::CliHandler,
     instructions::{
         crank_close_epoch_accounts, crank_distribute, crank_post_vote_cooldown,
@@ -14,7 +14,7 @@ use crate::{
     },
 };
 use anyhow::Result;
-use jito_tip_router_core::epoch_state::State;
+use jito_tip_router_core::{epoch_state::State, pause::PausableSubsystem};
 use log::info;
 use solana_metrics::set_host_id;
 use std::process::Command;
@@ -46,6 +46,15 @@ pub async fn progress_epoch(
     (keeper_epoch, false)
 }
 
+/// Subsystems that must be unpaused for the keeper to crank `state`
+pub const fn required_subsystems(state: State) -> &'static [PausableSubsystem] {
+    match state {
+        State::Vote => &[PausableSubsystem::Voting],
+        State::Distribute => &[PausableSubsystem::Routing, PausableSubsystem::Distribution],
+        State::SetWeight | State::Snapshot | State::PostVoteCooldown | State::Close => &[],
+    }
+}
+
 #[allow(clippy::future_not_send)]
 pub async fn check_and_timeout_error<T>(
     title: String,
@@ -353,6 +362,36 @@ pub async fn startup_keeper(
                     continue;
                 }
             };
+            // Back off instead of cranking into a paused subsystem
+            let required_subsystems = required_subsystems(current_state);
+            if !required_subsystems.is_empty() {
+                let config = get_tip_router_config(handler).await;
+                if check_and_timeout_error(
+                    "Read Config".to_string(),
+                    &config,
+                    error_timeout_ms,
+                    state.epoch,
+                    &cluster_name,
+                )
+                .await
+                {
+                    continue;
+                }
+
+                let config = config?;
+                if let Some(subsystem) = required_subsystems
+                    .iter()
+                    .find(|subsystem| config.is_paused(**subsystem))
+                {
+                    info!(
+                        "Subsystem paused, backing off step=crank_state state={:?} subsystem={} epoch={}",
+                        current_state, subsystem, current_keeper_epoch
+                    );
+                    timeout_keeper(loop_timeout_ms).await;
+                    continue;
+                }
+            }
+
             info!(
                 "Cranking state step=crank_state state={:?} epoch={}",
                 current_state, current_keeper_epoch
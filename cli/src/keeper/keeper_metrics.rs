@@ -11,12 +11,14 @@ use solana_sdk::{clock::DEFAULT_SLOTS_PER_EPOCH, native_token::LAMPORTS_PER_SOL}
 
 use crate::{
     getters::{
-        get_account_payer, get_all_operators_in_ncn, get_all_opted_in_validators, get_all_tickets,
-        get_all_vaults_in_ncn, get_ballot_box, get_base_reward_receiver, get_base_reward_router,
-        get_current_epoch_and_slot, get_epoch_snapshot, get_epoch_state, get_is_epoch_completed,
-        get_ncn_reward_receiver, get_ncn_reward_router, get_operator, get_operator_snapshot,
+        get_account_payer, get_all_operator_votes, get_all_operators_in_ncn,
+        get_all_opted_in_validators, get_all_tickets, get_all_vaults_in_ncn, get_ballot_box,
+        get_base_reward_receiver, get_base_reward_router, get_current_epoch_and_slot,
+        get_epoch_snapshot, get_epoch_state, get_is_epoch_completed, get_ncn_reward_receiver,
+        get_ncn_reward_router, get_operator, get_operator_snapshot,
         get_tip_distribution_accounts_to_migrate, get_tip_router_config, get_vault,
-        get_vault_config, get_vault_operator_delegation, get_vault_registry, get_weight_table,
+        get_vault_config, get_vault_operator_delegation, get_vault_registry,
+        get_vault_registry_pages, get_weight_table,
     },
     handler::CliHandler,
 };
@@ -303,16 +305,19 @@ pub async fn emit_ncn_metrics_vault_operator_delegation(
 pub async fn emit_ncn_metrics_operators(handler: &CliHandler, cluster_name: &str) -> Result<()> {
     let (current_epoch, current_slot) = get_current_epoch_and_slot(handler).await?;
     let all_operators = get_all_operators_in_ncn(handler).await?;
-    let ballot_box_result = get_ballot_box(handler, current_epoch).await;
+    let operator_votes = match get_ballot_box(handler, current_epoch).await {
+        Ok(ballot_box) => get_all_operator_votes(handler, &ballot_box)
+            .await
+            .unwrap_or_default(),
+        Err(_) => Vec::default(),
+    };
 
     for operator in all_operators {
         let operator_account = get_operator(handler, &operator).await?;
 
         // Emitting here so all operators get a trackable has_voted metric for alerts to avoid NoData issue
-        let operator_has_voted = ballot_box_result.as_ref().is_ok_and(|ballot_box| {
-            ballot_box.operator_votes().iter().any(|operator_vote| {
-                operator_vote.operator() == &operator && !operator_vote.is_empty()
-            })
+        let operator_has_voted = operator_votes.iter().any(|operator_vote| {
+            operator_vote.operator() == &operator && operator_vote.is_revealed()
         });
 
         datapoint_info!(
@@ -341,17 +346,35 @@ pub async fn emit_ncn_metrics_vault_registry(
 ) -> Result<()> {
     let (current_epoch, current_slot) = get_current_epoch_and_slot(handler).await?;
     let vault_registry = get_vault_registry(handler).await?;
+    let vault_registry_pages = get_vault_registry_pages(handler, &vault_registry).await?;
 
     datapoint_info!(
         "tr-beta-em-vault-registry",
         ("current-epoch", current_epoch, i64),
         ("current-slot", current_slot, i64),
         ("st-mints", vault_registry.st_mint_count(), i64),
-        ("vaults", vault_registry.vault_count(), i64),
+        (
+            "vaults",
+            vault_registry.vault_count() + vault_registry.paged_vault_count(),
+            i64
+        ),
+        ("pages", vault_registry.page_count(), i64),
+        (
+            "removed-vaults",
+            vault_registry.removed_vault_count(),
+            i64
+        ),
         "cluster" => cluster_name,
     );
 
-    for vault in vault_registry.vault_list {
+    // Vaults past MAX_VAULTS are kept in the vault registry pages
+    let vaults = vault_registry.vault_list.iter().chain(
+        vault_registry_pages
+            .iter()
+            .flat_map(|page| page.get_vault_entries().iter()),
+    );
+
+    for vault in vaults {
         if vault.is_empty() {
             continue;
         }
@@ -365,6 +388,7 @@ pub async fn emit_ncn_metrics_vault_registry(
             ("vault", vault.vault().to_string(), String),
             ("st-mint", vault.st_mint().to_string(), String),
             ("index", vault.vault_index(), i64),
+            ("deactivation-epoch", vault.deactivation_epoch(), i64),
             (
                 "tokens-deposited",
                 format_token_amount(vault_account.tokens_deposited()),
@@ -388,11 +412,17 @@ pub async fn emit_ncn_metrics_vault_registry(
             ("current-slot", current_slot, i64),
             ("st-mint", st_mint.st_mint().to_string(), String),
             ("ncn-fee-group", st_mint.ncn_fee_group().group, i64),
+            ("deactivation-epoch", st_mint.deactivation_epoch(), i64),
             (
                 "switchboard-feed",
                 st_mint.switchboard_feed().to_string(),
                 String
             ),
+            (
+                "oracle-type",
+                format!("{:?}", st_mint.oracle_type()),
+                String
+            ),
             (
                 "no-feed-weight",
                 st_mint.no_feed_weight().to_string(),
@@ -819,8 +849,9 @@ pub async fn emit_epoch_metrics_ballot_box(
         if let Ok(epoch_snapshot) = epoch_snapshot_result {
             let total_stake_weight = epoch_snapshot.stake_weights().stake_weight();
 
-            for operator_vote in ballot_box.operator_votes() {
-                if operator_vote.is_empty() {
+            for operator_vote in get_all_operator_votes(handler, &ballot_box).await? {
+                // Commitments without a reveal have no ballot yet
+                if !operator_vote.is_revealed() {
                     continue;
                 }
 
@@ -911,6 +942,7 @@ pub async fn emit_epoch_metrics_ballot_box(
                 ("keeper-epoch", epoch, i64),
                 ("unique-ballots", ballot_box.unique_ballots(), i64),
                 ("operators-voted", ballot_box.operators_voted(), i64),
+                ("page-count", ballot_box.page_count(), i64),
                 ("has-winning-ballot", ballot_box.has_winning_ballot(), bool),
                 ("winning-ballot", winning_ballot_string, String),
                 (
@@ -919,6 +951,11 @@ pub async fn emit_epoch_metrics_ballot_box(
                     f64
                 ),
                 ("winning-tally", winning_tally, i64),
+                (
+                    "consensus-threshold-bps",
+                    ballot_box.consensus_threshold_bps(),
+                    i64
+                ),
                 (
                     "total-stake-weight",
                     format_stake_weight(total_stake_weight),
@@ -990,6 +1027,26 @@ pub async fn emit_epoch_metrics_operator_snapshot(
                     format_stake_weight(operator_snapshot.stake_weights().stake_weight()),
                     f64
                 ),
+                (
+                    "capped-stake-weight",
+                    format_stake_weight(operator_snapshot.capped_stake_weight()),
+                    f64
+                ),
+                (
+                    "stake-weight-caps-applied",
+                    operator_snapshot.stake_weight_caps_applied(),
+                    bool
+                ),
+                (
+                    "max-operator-stake-weight-bps",
+                    operator_snapshot.max_operator_stake_weight_bps(),
+                    i64
+                ),
+                (
+                    "max-vault-stake-weight-bps",
+                    operator_snapshot.max_vault_stake_weight_bps(),
+                    i64
+                ),
                 ("slot-finalized", operator_snapshot.slot_finalized(), i64),
                 "cluster" => cluster_name,
             );
@@ -1034,6 +1091,12 @@ pub async fn emit_epoch_metrics_epoch_snapshot(
                 i64
             ),
             ("operator-count", epoch_snapshot.operator_count(), i64),
+            ("operators-capped", epoch_snapshot.operators_capped(), i64),
+            (
+                "capped-stake-weight",
+                format_stake_weight(epoch_snapshot.capped_stake_weight()),
+                f64
+            ),
             ("vault-count", epoch_snapshot.vault_count(), i64),
             (
                 "base-fee-bps",
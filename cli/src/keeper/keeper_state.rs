@@ -10,6 +10,7 @@ use jito_bytemuck::AccountDeserialize;
 
 use jito_tip_router_core::{
     ballot_box::BallotBox,
+    ballot_box_page::BallotBoxPage,
     base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
     config::Config as TipRouterConfig,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
@@ -34,6 +35,7 @@ pub struct KeeperState {
     pub epoch_snapshot_address: Pubkey,
     pub operator_snapshots_address: Vec<Pubkey>,
     pub ballot_box_address: Pubkey,
+    pub ballot_box_page_addresses: Vec<Pubkey>,
     pub base_reward_router_address: Pubkey,
     pub base_reward_receiver_address: Pubkey,
     pub ncn_reward_routers_address: Vec<Vec<Pubkey>>,
@@ -91,6 +93,20 @@ impl KeeperState {
             BallotBox::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
         self.ballot_box_address = ballot_box_address;
 
+        // Operators past the ballot box vote in its pages
+        let pages_needed = BallotBoxPage::pages_needed(self.operators.len() as u64);
+        self.ballot_box_page_addresses = (0..pages_needed)
+            .map(|page| {
+                BallotBoxPage::find_program_address(
+                    &handler.tip_router_program_id,
+                    &ncn,
+                    epoch,
+                    page,
+                )
+                .0
+            })
+            .collect();
+
         let (base_reward_router_address, _, _) =
             BaseRewardRouter::find_program_address(&handler.tip_router_program_id, &ncn, epoch);
         self.base_reward_router_address = base_reward_router_address;
@@ -254,6 +270,23 @@ impl KeeperState {
         }
     }
 
+    /// The ballot box pages that exist, in order
+    pub async fn ballot_box_pages(&self, handler: &CliHandler) -> Result<Vec<Box<BallotBoxPage>>> {
+        let mut pages = Vec::with_capacity(self.ballot_box_page_addresses.len());
+
+        for address in self.ballot_box_page_addresses.iter() {
+            let Some(raw_account) = get_account(handler, address).await? else {
+                break;
+            };
+
+            pages.push(Box::new(*BallotBoxPage::try_from_slice_unchecked(
+                raw_account.data.as_slice(),
+            )?));
+        }
+
+        Ok(pages)
+    }
+
     pub async fn base_reward_router(
         &self,
         handler: &CliHandler,
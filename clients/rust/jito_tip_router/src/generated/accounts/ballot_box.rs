@@ -25,8 +25,17 @@ pub struct BallotBox {
     pub bump: u8,
     pub slot_created: u64,
     pub slot_consensus_reached: u64,
+    pub commit_phase_end_slot: u64,
+    pub reveal_phase_end_slot: u64,
+    pub operators_committed: u64,
+    pub consensus_threshold_bps: u16,
+    pub quorum_operator_count: u16,
+    pub quorum_operator_bps: u16,
+    pub stall_fallback_used: bool,
+    pub page_count: u64,
+    pub stall_fallback_threshold_bps: u16,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 87],
     pub operators_voted: u64,
     pub unique_ballots: u64,
     pub winning_ballot: Ballot,
@@ -26,8 +26,16 @@ pub struct BaseRewardRouter {
     pub total_rewards: u64,
     pub reward_pool: u64,
     pub rewards_processed: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mint: Pubkey,
+    pub rewards_carried_forward: u64,
+    pub last_rewards_routed: u64,
+    pub page_count: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 72],
     pub last_ncn_group_index: u8,
     pub last_vote_index: u16,
     pub last_rewards_to_process: u64,
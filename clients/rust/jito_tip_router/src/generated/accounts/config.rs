@@ -1,4 +1,5 @@
 use crate::generated::types::FeeConfig;
+use crate::generated::types::PendingAdminHandover;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -28,8 +29,29 @@ pub struct Config {
     pub bump: u8,
     pub epochs_after_consensus_before_close: u64,
     pub starting_valid_epoch: u64,
-    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 111],
+    pub commit_window_slots: u64,
+    pub reveal_window_slots: u64,
+    pub consensus_threshold_bps: u16,
+    pub quorum_operator_count: u16,
+    pub quorum_operator_bps: u16,
+    pub stall_fallback_threshold_bps: u16,
+    pub admin_handover_delay_epochs: u16,
+    pub pending_admins: [PendingAdminHandover; 3],
+    pub scheduled_admin_handover_delay_epochs: u16,
+    pub scheduled_admin_handover_delay_epoch: u64,
+    pub weight_sampling_window_slots: u64,
+    pub max_operator_stake_weight_bps: u16,
+    pub max_vault_stake_weight_bps: u16,
+    pub crank_bounty_lamports: u64,
+    pub max_crank_bounty_lamports_per_epoch: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pause_admin: Pubkey,
+    pub paused_subsystems: u8,
+    pub reward_mints: [Pubkey; 8],
+    pub reserved: [u8; 6],
 }
 
 impl Config {
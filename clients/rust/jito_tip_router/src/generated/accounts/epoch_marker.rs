@@ -13,6 +13,16 @@ pub struct EpochMarker {
     pub ncn: Pubkey,
     pub epoch: u64,
     pub slot_closed: u64,
+    pub meta_merkle_root: [u8; 32],
+    pub slot_consensus_reached: u64,
+    pub was_tie_breaker_set: bool,
+    pub total_stake_weight: u128,
+    pub operators_voted: u64,
+    pub total_rewards: u64,
+    pub base_rewards: u64,
+    pub ncn_rewards: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    pub reserved: [u8; 64],
 }
 
 impl EpochMarker {
@@ -30,8 +30,11 @@ pub struct EpochSnapshot {
     pub operators_registered: u64,
     pub valid_operator_vault_delegations: u64,
     pub stake_weights: StakeWeights,
+    pub active_operator_count: u64,
+    pub operators_capped: u64,
+    pub capped_stake_weight: u128,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 96],
 }
 
 impl EpochSnapshot {
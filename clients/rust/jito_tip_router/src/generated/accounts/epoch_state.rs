@@ -40,8 +40,20 @@ pub struct EpochState {
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub ncn_distribution_progress: [Progress; 2048],
     pub is_closing: bool,
+    pub consensus_threshold_bps: u16,
+    pub was_stall_fallback_used: bool,
+    pub winning_meta_merkle_root: [u8; 32],
+    pub total_stake_weight: u128,
+    pub total_rewards: u64,
+    pub base_rewards_distributed: u64,
+    pub ncn_rewards_distributed: u64,
+    pub open_token_reward_routers: u16,
+    pub rewards_carried_forward: u64,
+    pub rewards_carried_in: u64,
+    pub crank_bounties_paid: u64,
+    pub page_count: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 1023],
+    pub reserved: [u8; 914],
 }
 
 impl EpochState {
@@ -5,24 +5,44 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+pub(crate) mod r#admin_multisig;
+pub(crate) mod r#admin_proposal;
 pub(crate) mod r#ballot_box;
+pub(crate) mod r#ballot_box_page;
 pub(crate) mod r#base_reward_router;
+pub(crate) mod r#base_reward_router_page;
 pub(crate) mod r#config;
 pub(crate) mod r#epoch_marker;
 pub(crate) mod r#epoch_snapshot;
 pub(crate) mod r#epoch_state;
+pub(crate) mod r#epoch_state_page;
 pub(crate) mod r#ncn_reward_router;
 pub(crate) mod r#operator_snapshot;
+pub(crate) mod r#operator_snapshot_page;
+pub(crate) mod r#operator_vote_key;
+pub(crate) mod r#payout_preference;
 pub(crate) mod r#vault_registry;
+pub(crate) mod r#vault_registry_page;
 pub(crate) mod r#weight_table;
+pub(crate) mod r#weight_table_page;
 
+pub use self::r#admin_multisig::*;
+pub use self::r#admin_proposal::*;
 pub use self::r#ballot_box::*;
+pub use self::r#ballot_box_page::*;
 pub use self::r#base_reward_router::*;
+pub use self::r#base_reward_router_page::*;
 pub use self::r#config::*;
 pub use self::r#epoch_marker::*;
 pub use self::r#epoch_snapshot::*;
 pub use self::r#epoch_state::*;
+pub use self::r#epoch_state_page::*;
 pub use self::r#ncn_reward_router::*;
 pub use self::r#operator_snapshot::*;
+pub use self::r#operator_snapshot_page::*;
+pub use self::r#operator_vote_key::*;
+pub use self::r#payout_preference::*;
 pub use self::r#vault_registry::*;
+pub use self::r#vault_registry_page::*;
 pub use self::r#weight_table::*;
+pub use self::r#weight_table_page::*;
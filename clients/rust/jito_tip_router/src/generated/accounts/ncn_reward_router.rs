@@ -34,8 +34,15 @@ pub struct NcnRewardRouter {
     pub reward_pool: u64,
     pub rewards_processed: u64,
     pub operator_rewards: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mint: Pubkey,
+    pub rewards_carried_forward: u64,
+    pub last_rewards_routed: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 80],
     pub last_rewards_to_process: u64,
     pub last_vault_operator_delegation_index: u16,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
@@ -30,8 +30,13 @@ pub struct OperatorSnapshot {
     pub vault_operator_delegations_registered: u64,
     pub valid_operator_vault_delegations: u64,
     pub stake_weights: StakeWeights,
+    pub max_operator_stake_weight_bps: u16,
+    pub max_vault_stake_weight_bps: u16,
+    pub capped_stake_weight: u128,
+    pub stake_weight_caps_applied: bool,
+    pub page_count: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 256],
+    pub reserved: [u8; 227],
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub vault_operator_stake_weight: [VaultOperatorStakeWeight; 64],
 }
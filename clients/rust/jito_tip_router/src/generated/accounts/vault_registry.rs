@@ -14,8 +14,11 @@ pub struct VaultRegistry {
     )]
     pub ncn: Pubkey,
     pub bump: u8,
+    pub removed_vault_count: u64,
+    pub page_count: u64,
+    pub paged_vault_count: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 127],
+    pub reserved: [u8; 103],
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub st_mint_list: [StMintEntry; 64],
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
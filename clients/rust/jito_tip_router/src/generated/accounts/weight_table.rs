@@ -17,8 +17,12 @@ pub struct WeightTable {
     pub slot_created: u64,
     pub vault_count: u64,
     pub bump: u8,
+    pub sampling_end_slot: u64,
+    pub page_count: u64,
+    pub pages_initialized: u64,
+    pub paged_vault_count: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 96],
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub vault_registry: [VaultEntry; 64],
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
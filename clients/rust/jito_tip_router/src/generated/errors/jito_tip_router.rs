@@ -276,4 +276,250 @@ pub enum JitoTipRouterError {
     /// 8785 - Marker exists
     #[error("Marker exists")]
     MarkerExists = 0x2251,
+    /// 8786 - Invalid commit window
+    #[error("Invalid commit window")]
+    InvalidCommitWindow = 0x2252,
+    /// 8787 - Invalid reveal window
+    #[error("Invalid reveal window")]
+    InvalidRevealWindow = 0x2253,
+    /// 8788 - Commit-reveal voting is not enabled for this ballot box
+    #[error("Commit-reveal voting is not enabled for this ballot box")]
+    CommitRevealNotEnabled = 0x2254,
+    /// 8789 - Votes must be committed and revealed for this ballot box
+    #[error("Votes must be committed and revealed for this ballot box")]
+    CommitRevealRequired = 0x2255,
+    /// 8790 - Commit phase has ended
+    #[error("Commit phase has ended")]
+    CommitPhaseEnded = 0x2256,
+    /// 8791 - Reveal phase has not started
+    #[error("Reveal phase has not started")]
+    RevealPhaseNotStarted = 0x2257,
+    /// 8792 - Reveal phase has ended
+    #[error("Reveal phase has ended")]
+    RevealPhaseEnded = 0x2258,
+    /// 8793 - Invalid vote commitment
+    #[error("Invalid vote commitment")]
+    InvalidVoteCommitment = 0x2259,
+    /// 8794 - Vote commitment not found
+    #[error("Vote commitment not found")]
+    VoteCommitmentNotFound = 0x225A,
+    /// 8795 - Revealed vote does not match commitment
+    #[error("Revealed vote does not match commitment")]
+    VoteCommitmentMismatch = 0x225B,
+    /// 8796 - Vote already revealed
+    #[error("Vote already revealed")]
+    VoteAlreadyRevealed = 0x225C,
+    /// 8797 - Invalid consensus threshold
+    #[error("Invalid consensus threshold")]
+    InvalidConsensusThreshold = 0x225D,
+    /// 8798 - Invalid quorum
+    #[error("Invalid quorum")]
+    InvalidQuorum = 0x225E,
+    /// 8799 - Invalid stall fallback threshold
+    #[error("Invalid stall fallback threshold")]
+    InvalidStallFallbackThreshold = 0x225F,
+    /// 8800 - Stall fallback is not enabled
+    #[error("Stall fallback is not enabled")]
+    StallFallbackNotEnabled = 0x2260,
+    /// 8801 - Leading ballot does not meet the stall fallback threshold
+    #[error("Leading ballot does not meet the stall fallback threshold")]
+    StallFallbackThresholdNotMet = 0x2261,
+    /// 8802 - Invalid admin multisig signers or threshold
+    #[error("Invalid admin multisig signers or threshold")]
+    InvalidAdminMultisig = 0x2262,
+    /// 8803 - Signer is not part of the admin multisig
+    #[error("Signer is not part of the admin multisig")]
+    AdminMultisigSignerNotFound = 0x2263,
+    /// 8804 - Admin multisig is not the admin for this role
+    #[error("Admin multisig is not the admin for this role")]
+    AdminMultisigNotActive = 0x2264,
+    /// 8805 - Admin action does not belong to this role
+    #[error("Admin action does not belong to this role")]
+    AdminActionRoleMismatch = 0x2265,
+    /// 8806 - Admin action is too large
+    #[error("Admin action is too large")]
+    AdminActionTooLarge = 0x2266,
+    /// 8807 - Invalid admin action
+    #[error("Invalid admin action")]
+    InvalidAdminAction = 0x2267,
+    /// 8808 - Admin proposal already approved by this signer
+    #[error("Admin proposal already approved by this signer")]
+    AdminProposalAlreadyApproved = 0x2268,
+    /// 8809 - Admin proposal already executed
+    #[error("Admin proposal already executed")]
+    AdminProposalAlreadyExecuted = 0x2269,
+    /// 8810 - Admin proposal does not have enough approvals
+    #[error("Admin proposal does not have enough approvals")]
+    AdminProposalThresholdNotMet = 0x226A,
+    /// 8811 - Admin multisig signers changed since the proposal was created
+    #[error("Admin multisig signers changed since the proposal was created")]
+    AdminProposalStale = 0x226B,
+    /// 8812 - Invalid admin handover delay
+    #[error("Invalid admin handover delay")]
+    InvalidAdminHandoverDelay = 0x226C,
+    /// 8813 - Admin handover delay is set, propose and accept the new admin instead
+    #[error("Admin handover delay is set, propose and accept the new admin instead")]
+    AdminHandoverRequired = 0x226D,
+    /// 8814 - Another admin handover is pending
+    #[error("Another admin handover is pending")]
+    AdminHandoverPending = 0x226E,
+    /// 8815 - No admin handover is pending for this role
+    #[error("No admin handover is pending for this role")]
+    NoPendingAdminHandover = 0x226F,
+    /// 8816 - Signer is not the pending admin
+    #[error("Signer is not the pending admin")]
+    InvalidPendingAdmin = 0x2270,
+    /// 8817 - Admin handover delay has not elapsed
+    #[error("Admin handover delay has not elapsed")]
+    AdminHandoverDelayNotElapsed = 0x2271,
+    /// 8818 - Incorrect operator admin
+    #[error("Incorrect operator admin")]
+    IncorrectOperatorAdmin = 0x2272,
+    /// 8819 - Operator vote not found
+    #[error("Operator vote not found")]
+    OperatorVoteNotFound = 0x2273,
+    /// 8820 - Invalid oracle type
+    #[error("Invalid oracle type")]
+    InvalidOracleType = 0x2274,
+    /// 8821 - Switchboard confidence interval is too wide
+    #[error("Switchboard confidence interval is too wide")]
+    SwitchboardConfidenceTooWide = 0x2275,
+    /// 8822 - Bad pyth price update
+    #[error("Bad pyth price update")]
+    BadPythPriceUpdate = 0x2276,
+    /// 8823 - Bad pyth price
+    #[error("Bad pyth price")]
+    BadPythPrice = 0x2277,
+    /// 8824 - Stale pyth price
+    #[error("Stale pyth price")]
+    StalePythPrice = 0x2278,
+    /// 8825 - Pyth confidence interval is too wide
+    #[error("Pyth confidence interval is too wide")]
+    PythConfidenceTooWide = 0x2279,
+    /// 8826 - Oracle weight changed too much since the last epoch
+    #[error("Oracle weight changed too much since the last epoch")]
+    OracleWeightChangeTooLarge = 0x227A,
+    /// 8827 - Invalid oracle guard
+    #[error("Invalid oracle guard")]
+    InvalidOracleGuard = 0x227B,
+    /// 8828 - Bad stake pool
+    #[error("Bad stake pool")]
+    BadStakePool = 0x227C,
+    /// 8829 - Stake pool has not been updated this epoch
+    #[error("Stake pool has not been updated this epoch")]
+    StaleStakePool = 0x227D,
+    /// 8830 - Stake pool mint does not match the ST mint
+    #[error("Stake pool mint does not match the ST mint")]
+    StakePoolMintMismatch = 0x227E,
+    /// 8831 - Invalid weight sampling window
+    #[error("Invalid weight sampling window")]
+    InvalidWeightSamplingWindow = 0x227F,
+    /// 8832 - No weight samples
+    #[error("No weight samples")]
+    NoWeightSamples = 0x2280,
+    /// 8833 - Invalid stake weight cap
+    #[error("Invalid stake weight cap")]
+    InvalidStakeWeightCap = 0x2281,
+    /// 8834 - Invalid deactivation epoch
+    #[error("Invalid deactivation epoch")]
+    InvalidDeactivationEpoch = 0x2282,
+    /// 8835 - ST mint has active vaults
+    #[error("ST mint has active vaults")]
+    StMintHasActiveVaults = 0x2283,
+    /// 8836 - ST mint is deactivated
+    #[error("ST mint is deactivated")]
+    StMintDeactivated = 0x2284,
+    /// 8837 - Invalid ballot box page
+    #[error("Invalid ballot box page")]
+    InvalidBallotBoxPage = 0x2285,
+    /// 8838 - Incorrect vault admin
+    #[error("Incorrect vault admin")]
+    IncorrectVaultAdmin = 0x2286,
+    /// 8839 - Invalid payout asset
+    #[error("Invalid payout asset")]
+    InvalidPayoutAsset = 0x2287,
+    /// 8840 - Invalid payout destination
+    #[error("Invalid payout destination")]
+    InvalidPayoutDestination = 0x2288,
+    /// 8841 - Cannot carry rewards forward
+    #[error("Cannot carry rewards forward")]
+    CannotCarryForwardRewards = 0x2289,
+    /// 8842 - Invalid fee activation epoch
+    #[error("Invalid fee activation epoch")]
+    InvalidFeeActivationEpoch = 0x228A,
+    /// 8843 - Fee schedule is full
+    #[error("Fee schedule is full")]
+    FeeScheduleFull = 0x228B,
+    /// 8844 - No fees scheduled for this epoch
+    #[error("No fees scheduled for this epoch")]
+    FeesNotScheduled = 0x228C,
+    /// 8845 - Fee increase activates before the notice period
+    #[error("Fee increase activates before the notice period")]
+    FeeIncreaseNoticeTooShort = 0x228D,
+    /// 8846 - Invalid fee increase notice epochs
+    #[error("Invalid fee increase notice epochs")]
+    InvalidFeeIncreaseNoticeEpochs = 0x228E,
+    /// 8847 - Base fee group is not enabled
+    #[error("Base fee group is not enabled")]
+    BaseFeeGroupDisabled = 0x228F,
+    /// 8848 - Base fee group is still charged a fee
+    #[error("Base fee group is still charged a fee")]
+    BaseFeeGroupInUse = 0x2290,
+    /// 8849 - Paused
+    #[error("Paused")]
+    Paused = 0x2291,
+    /// 8850 - Invalid paused subsystems
+    #[error("Invalid paused subsystems")]
+    InvalidPausedSubsystems = 0x2292,
+    /// 8851 - Incorrect pause admin
+    #[error("Incorrect pause admin")]
+    IncorrectPauseAdmin = 0x2293,
+    /// 8852 - Invalid account version
+    #[error("Invalid account version")]
+    InvalidAccountVersion = 0x2294,
+    /// 8853 - Account has no versioned layout
+    #[error("Account has no versioned layout")]
+    AccountNotVersioned = 0x2295,
+    /// 8854 - Signers of an active admin multisig change through an admin action
+    #[error("Signers of an active admin multisig change through an admin action")]
+    AdminMultisigActive = 0x2296,
+    /// 8855 - Only the proposer can close an open admin proposal
+    #[error("Only the proposer can close an open admin proposal")]
+    AdminProposalStillOpen = 0x2297,
+    /// 8856 - Vault rewards can only be paid to accounts owned by the vault
+    #[error("Vault rewards can only be paid to accounts owned by the vault")]
+    VaultSolPayoutNotAllowed = 0x2298,
+    /// 8857 - Mint is not an allowed reward mint
+    #[error("Mint is not an allowed reward mint")]
+    RewardMintNotAllowed = 0x2299,
+    /// 8858 - Too many reward mints
+    #[error("Too many reward mints")]
+    TooManyRewardMints = 0x229A,
+    /// 8859 - Operator snapshot not finalized
+    #[error("Operator snapshot not finalized")]
+    OperatorSnapshotNotFinalized = 0x229B,
+    /// 8860 - Stake weight caps already applied
+    #[error("Stake weight caps already applied")]
+    StakeWeightCapsAlreadyApplied = 0x229C,
+    /// 8861 - Stake weight caps not applied
+    #[error("Stake weight caps not applied")]
+    StakeWeightCapsNotApplied = 0x229D,
+    /// 8862 - Invalid vault page
+    #[error("Invalid vault page")]
+    InvalidVaultPage = 0x229E,
+    /// 8863 - Operator is not active in the NCN
+    #[error("Operator is not active in the NCN")]
+    OperatorNotActiveInNcn = 0x229F,
+    /// 8864 - The vault registry is required to record weights with a weight change guard
+    #[error("The vault registry is required to record weights with a weight change guard")]
+    VaultRegistryRequiredForWeightChangeGuard = 0x22A0,
+    /// 8865 - Invalid epoch state page
+    #[error("Invalid epoch state page")]
+    InvalidEpochStatePage = 0x22A1,
+    /// 8866 - Invalid base reward router page
+    #[error("Invalid base reward router page")]
+    InvalidBaseRewardRouterPage = 0x22A2,
+    /// 8867 - Incorrect admin proposal proposer
+    #[error("Incorrect admin proposal proposer")]
+    IncorrectAdminProposer = 0x22A3,
 }
@@ -91,6 +91,10 @@ pub struct AdminRegisterStMintInstructionArgs {
     pub reward_multiplier_bps: u64,
     pub switchboard_feed: Option<Pubkey>,
     pub no_feed_weight: Option<u128>,
+    pub oracle_type: Option<u8>,
+    pub max_staleness: Option<u64>,
+    pub max_confidence_bps: Option<u16>,
+    pub max_weight_change_bps: Option<u16>,
 }
 
 /// Instruction builder for `AdminRegisterStMint`.
@@ -113,6 +117,10 @@ pub struct AdminRegisterStMintBuilder {
     reward_multiplier_bps: Option<u64>,
     switchboard_feed: Option<Pubkey>,
     no_feed_weight: Option<u128>,
+    oracle_type: Option<u8>,
+    max_staleness: Option<u64>,
+    max_confidence_bps: Option<u16>,
+    max_weight_change_bps: Option<u16>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -167,6 +175,30 @@ impl AdminRegisterStMintBuilder {
         self.no_feed_weight = Some(no_feed_weight);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn oracle_type(&mut self, oracle_type: u8) -> &mut Self {
+        self.oracle_type = Some(oracle_type);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_staleness(&mut self, max_staleness: u64) -> &mut Self {
+        self.max_staleness = Some(max_staleness);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_confidence_bps(&mut self, max_confidence_bps: u16) -> &mut Self {
+        self.max_confidence_bps = Some(max_confidence_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_weight_change_bps(&mut self, max_weight_change_bps: u16) -> &mut Self {
+        self.max_weight_change_bps = Some(max_weight_change_bps);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -205,6 +237,10 @@ impl AdminRegisterStMintBuilder {
                 .expect("reward_multiplier_bps is not set"),
             switchboard_feed: self.switchboard_feed.clone(),
             no_feed_weight: self.no_feed_weight.clone(),
+            oracle_type: self.oracle_type.clone(),
+            max_staleness: self.max_staleness.clone(),
+            max_confidence_bps: self.max_confidence_bps.clone(),
+            max_weight_change_bps: self.max_weight_change_bps.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -374,6 +410,10 @@ impl<'a, 'b> AdminRegisterStMintCpiBuilder<'a, 'b> {
             reward_multiplier_bps: None,
             switchboard_feed: None,
             no_feed_weight: None,
+            oracle_type: None,
+            max_staleness: None,
+            max_confidence_bps: None,
+            max_weight_change_bps: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -434,6 +474,30 @@ impl<'a, 'b> AdminRegisterStMintCpiBuilder<'a, 'b> {
         self.instruction.no_feed_weight = Some(no_feed_weight);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn oracle_type(&mut self, oracle_type: u8) -> &mut Self {
+        self.instruction.oracle_type = Some(oracle_type);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_staleness(&mut self, max_staleness: u64) -> &mut Self {
+        self.instruction.max_staleness = Some(max_staleness);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_confidence_bps(&mut self, max_confidence_bps: u16) -> &mut Self {
+        self.instruction.max_confidence_bps = Some(max_confidence_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_weight_change_bps(&mut self, max_weight_change_bps: u16) -> &mut Self {
+        self.instruction.max_weight_change_bps = Some(max_weight_change_bps);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -488,6 +552,10 @@ impl<'a, 'b> AdminRegisterStMintCpiBuilder<'a, 'b> {
                 .expect("reward_multiplier_bps is not set"),
             switchboard_feed: self.instruction.switchboard_feed.clone(),
             no_feed_weight: self.instruction.no_feed_weight.clone(),
+            oracle_type: self.instruction.oracle_type.clone(),
+            max_staleness: self.instruction.max_staleness.clone(),
+            max_confidence_bps: self.instruction.max_confidence_bps.clone(),
+            max_weight_change_bps: self.instruction.max_weight_change_bps.clone(),
         };
         let instruction = AdminRegisterStMintCpi {
             __program: self.instruction.__program,
@@ -525,6 +593,10 @@ struct AdminRegisterStMintCpiBuilderInstruction<'a, 'b> {
     reward_multiplier_bps: Option<u64>,
     switchboard_feed: Option<Pubkey>,
     no_feed_weight: Option<u128>,
+    oracle_type: Option<u8>,
+    max_staleness: Option<u64>,
+    max_confidence_bps: Option<u16>,
+    max_weight_change_bps: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -0,0 +1,455 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct AdminSetBaseFeeGroup {
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub ncn: solana_program::pubkey::Pubkey,
+
+    pub fee_admin: solana_program::pubkey::Pubkey,
+}
+
+impl AdminSetBaseFeeGroup {
+    pub fn instruction(
+        &self,
+        args: AdminSetBaseFeeGroupInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AdminSetBaseFeeGroupInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.fee_admin,
+            true,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&AdminSetBaseFeeGroupInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct AdminSetBaseFeeGroupInstructionData {
+    discriminator: u8,
+}
+
+impl AdminSetBaseFeeGroupInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 54 }
+    }
+}
+
+impl Default for AdminSetBaseFeeGroupInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdminSetBaseFeeGroupInstructionArgs {
+    pub base_fee_group: u8,
+    pub name: Option<[u8; 32]>,
+    pub enabled: Option<bool>,
+    pub payout_asset: Option<u8>,
+}
+
+/// Instruction builder for `AdminSetBaseFeeGroup`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` config
+///   1. `[]` ncn
+///   2. `[signer]` fee_admin
+#[derive(Clone, Debug, Default)]
+pub struct AdminSetBaseFeeGroupBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    ncn: Option<solana_program::pubkey::Pubkey>,
+    fee_admin: Option<solana_program::pubkey::Pubkey>,
+    base_fee_group: Option<u8>,
+    name: Option<[u8; 32]>,
+    enabled: Option<bool>,
+    payout_asset: Option<u8>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AdminSetBaseFeeGroupBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_admin(&mut self, fee_admin: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.fee_admin = Some(fee_admin);
+        self
+    }
+    #[inline(always)]
+    pub fn base_fee_group(&mut self, base_fee_group: u8) -> &mut Self {
+        self.base_fee_group = Some(base_fee_group);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn name(&mut self, name: [u8; 32]) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = Some(enabled);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn payout_asset(&mut self, payout_asset: u8) -> &mut Self {
+        self.payout_asset = Some(payout_asset);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AdminSetBaseFeeGroup {
+            config: self.config.expect("config is not set"),
+            ncn: self.ncn.expect("ncn is not set"),
+            fee_admin: self.fee_admin.expect("fee_admin is not set"),
+        };
+        let args = AdminSetBaseFeeGroupInstructionArgs {
+            base_fee_group: self
+                .base_fee_group
+                .clone()
+                .expect("base_fee_group is not set"),
+            name: self.name.clone(),
+            enabled: self.enabled.clone(),
+            payout_asset: self.payout_asset.clone(),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `admin_set_base_fee_group` CPI accounts.
+pub struct AdminSetBaseFeeGroupCpiAccounts<'a, 'b> {
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub fee_admin: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `admin_set_base_fee_group` CPI instruction.
+pub struct AdminSetBaseFeeGroupCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub fee_admin: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AdminSetBaseFeeGroupInstructionArgs,
+}
+
+impl<'a, 'b> AdminSetBaseFeeGroupCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AdminSetBaseFeeGroupCpiAccounts<'a, 'b>,
+        args: AdminSetBaseFeeGroupInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            ncn: accounts.ncn,
+            fee_admin: accounts.fee_admin,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.fee_admin.key,
+            true,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&AdminSetBaseFeeGroupInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.ncn.clone());
+        account_infos.push(self.fee_admin.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AdminSetBaseFeeGroup` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` config
+///   1. `[]` ncn
+///   2. `[signer]` fee_admin
+#[derive(Clone, Debug)]
+pub struct AdminSetBaseFeeGroupCpiBuilder<'a, 'b> {
+    instruction: Box<AdminSetBaseFeeGroupCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AdminSetBaseFeeGroupCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AdminSetBaseFeeGroupCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            ncn: None,
+            fee_admin: None,
+            base_fee_group: None,
+            name: None,
+            enabled: None,
+            payout_asset: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_admin(
+        &mut self,
+        fee_admin: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_admin = Some(fee_admin);
+        self
+    }
+    #[inline(always)]
+    pub fn base_fee_group(&mut self, base_fee_group: u8) -> &mut Self {
+        self.instruction.base_fee_group = Some(base_fee_group);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn name(&mut self, name: [u8; 32]) -> &mut Self {
+        self.instruction.name = Some(name);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.instruction.enabled = Some(enabled);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn payout_asset(&mut self, payout_asset: u8) -> &mut Self {
+        self.instruction.payout_asset = Some(payout_asset);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = AdminSetBaseFeeGroupInstructionArgs {
+            base_fee_group: self
+                .instruction
+                .base_fee_group
+                .clone()
+                .expect("base_fee_group is not set"),
+            name: self.instruction.name.clone(),
+            enabled: self.instruction.enabled.clone(),
+            payout_asset: self.instruction.payout_asset.clone(),
+        };
+        let instruction = AdminSetBaseFeeGroupCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            ncn: self.instruction.ncn.expect("ncn is not set"),
+
+            fee_admin: self.instruction.fee_admin.expect("fee_admin is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AdminSetBaseFeeGroupCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base_fee_group: Option<u8>,
+    name: Option<[u8; 32]>,
+    enabled: Option<bool>,
+    payout_asset: Option<u8>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
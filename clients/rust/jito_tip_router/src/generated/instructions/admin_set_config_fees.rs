@@ -83,6 +83,7 @@ pub struct AdminSetConfigFeesInstructionArgs {
     pub ncn_fee_group: Option<u8>,
     pub new_ncn_fee_bps: Option<u16>,
     pub new_priority_fee_distribution_fee_bps: Option<u16>,
+    pub activation_epoch: Option<u64>,
 }
 
 /// Instruction builder for `AdminSetConfigFees`.
@@ -104,6 +105,7 @@ pub struct AdminSetConfigFeesBuilder {
     ncn_fee_group: Option<u8>,
     new_ncn_fee_bps: Option<u16>,
     new_priority_fee_distribution_fee_bps: Option<u16>,
+    activation_epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -171,6 +173,12 @@ impl AdminSetConfigFeesBuilder {
         self.new_priority_fee_distribution_fee_bps = Some(new_priority_fee_distribution_fee_bps);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn activation_epoch(&mut self, activation_epoch: u64) -> &mut Self {
+        self.activation_epoch = Some(activation_epoch);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -206,6 +214,7 @@ impl AdminSetConfigFeesBuilder {
             new_priority_fee_distribution_fee_bps: self
                 .new_priority_fee_distribution_fee_bps
                 .clone(),
+            activation_epoch: self.activation_epoch.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -354,6 +363,7 @@ impl<'a, 'b> AdminSetConfigFeesCpiBuilder<'a, 'b> {
             ncn_fee_group: None,
             new_ncn_fee_bps: None,
             new_priority_fee_distribution_fee_bps: None,
+            activation_epoch: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -425,6 +435,12 @@ impl<'a, 'b> AdminSetConfigFeesCpiBuilder<'a, 'b> {
             Some(new_priority_fee_distribution_fee_bps);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn activation_epoch(&mut self, activation_epoch: u64) -> &mut Self {
+        self.instruction.activation_epoch = Some(activation_epoch);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -477,6 +493,7 @@ impl<'a, 'b> AdminSetConfigFeesCpiBuilder<'a, 'b> {
                 .instruction
                 .new_priority_fee_distribution_fee_bps
                 .clone(),
+            activation_epoch: self.instruction.activation_epoch.clone(),
         };
         let instruction = AdminSetConfigFeesCpi {
             __program: self.instruction.__program,
@@ -508,6 +525,7 @@ struct AdminSetConfigFeesCpiBuilderInstruction<'a, 'b> {
     ncn_fee_group: Option<u8>,
     new_ncn_fee_bps: Option<u16>,
     new_priority_fee_distribution_fee_bps: Option<u16>,
+    activation_epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
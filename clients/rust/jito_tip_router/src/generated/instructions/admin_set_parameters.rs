@@ -79,6 +79,19 @@ pub struct AdminSetParametersInstructionArgs {
     pub epochs_before_stall: Option<u64>,
     pub epochs_after_consensus_before_close: Option<u64>,
     pub valid_slots_after_consensus: Option<u64>,
+    pub commit_window_slots: Option<u64>,
+    pub reveal_window_slots: Option<u64>,
+    pub consensus_threshold_bps: Option<u16>,
+    pub quorum_operator_count: Option<u16>,
+    pub quorum_operator_bps: Option<u16>,
+    pub stall_fallback_threshold_bps: Option<u16>,
+    pub admin_handover_delay_epochs: Option<u16>,
+    pub weight_sampling_window_slots: Option<u64>,
+    pub max_operator_stake_weight_bps: Option<u16>,
+    pub max_vault_stake_weight_bps: Option<u16>,
+    pub fee_increase_notice_epochs: Option<u64>,
+    pub crank_bounty_lamports: Option<u64>,
+    pub max_crank_bounty_lamports_per_epoch: Option<u64>,
 }
 
 /// Instruction builder for `AdminSetParameters`.
@@ -97,6 +110,19 @@ pub struct AdminSetParametersBuilder {
     epochs_before_stall: Option<u64>,
     epochs_after_consensus_before_close: Option<u64>,
     valid_slots_after_consensus: Option<u64>,
+    commit_window_slots: Option<u64>,
+    reveal_window_slots: Option<u64>,
+    consensus_threshold_bps: Option<u16>,
+    quorum_operator_count: Option<u16>,
+    quorum_operator_bps: Option<u16>,
+    stall_fallback_threshold_bps: Option<u16>,
+    admin_handover_delay_epochs: Option<u16>,
+    weight_sampling_window_slots: Option<u64>,
+    max_operator_stake_weight_bps: Option<u16>,
+    max_vault_stake_weight_bps: Option<u16>,
+    fee_increase_notice_epochs: Option<u64>,
+    crank_bounty_lamports: Option<u64>,
+    max_crank_bounty_lamports_per_epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -146,6 +172,90 @@ impl AdminSetParametersBuilder {
         self.valid_slots_after_consensus = Some(valid_slots_after_consensus);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn commit_window_slots(&mut self, commit_window_slots: u64) -> &mut Self {
+        self.commit_window_slots = Some(commit_window_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn reveal_window_slots(&mut self, reveal_window_slots: u64) -> &mut Self {
+        self.reveal_window_slots = Some(reveal_window_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn consensus_threshold_bps(&mut self, consensus_threshold_bps: u16) -> &mut Self {
+        self.consensus_threshold_bps = Some(consensus_threshold_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn quorum_operator_count(&mut self, quorum_operator_count: u16) -> &mut Self {
+        self.quorum_operator_count = Some(quorum_operator_count);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn quorum_operator_bps(&mut self, quorum_operator_bps: u16) -> &mut Self {
+        self.quorum_operator_bps = Some(quorum_operator_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn stall_fallback_threshold_bps(&mut self, stall_fallback_threshold_bps: u16) -> &mut Self {
+        self.stall_fallback_threshold_bps = Some(stall_fallback_threshold_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn admin_handover_delay_epochs(&mut self, admin_handover_delay_epochs: u16) -> &mut Self {
+        self.admin_handover_delay_epochs = Some(admin_handover_delay_epochs);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn weight_sampling_window_slots(&mut self, weight_sampling_window_slots: u64) -> &mut Self {
+        self.weight_sampling_window_slots = Some(weight_sampling_window_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_operator_stake_weight_bps(
+        &mut self,
+        max_operator_stake_weight_bps: u16,
+    ) -> &mut Self {
+        self.max_operator_stake_weight_bps = Some(max_operator_stake_weight_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_vault_stake_weight_bps(&mut self, max_vault_stake_weight_bps: u16) -> &mut Self {
+        self.max_vault_stake_weight_bps = Some(max_vault_stake_weight_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn fee_increase_notice_epochs(&mut self, fee_increase_notice_epochs: u64) -> &mut Self {
+        self.fee_increase_notice_epochs = Some(fee_increase_notice_epochs);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn crank_bounty_lamports(&mut self, crank_bounty_lamports: u64) -> &mut Self {
+        self.crank_bounty_lamports = Some(crank_bounty_lamports);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_crank_bounty_lamports_per_epoch(
+        &mut self,
+        max_crank_bounty_lamports_per_epoch: u64,
+    ) -> &mut Self {
+        self.max_crank_bounty_lamports_per_epoch = Some(max_crank_bounty_lamports_per_epoch);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -176,6 +286,19 @@ impl AdminSetParametersBuilder {
             epochs_before_stall: self.epochs_before_stall.clone(),
             epochs_after_consensus_before_close: self.epochs_after_consensus_before_close.clone(),
             valid_slots_after_consensus: self.valid_slots_after_consensus.clone(),
+            commit_window_slots: self.commit_window_slots.clone(),
+            reveal_window_slots: self.reveal_window_slots.clone(),
+            consensus_threshold_bps: self.consensus_threshold_bps.clone(),
+            quorum_operator_count: self.quorum_operator_count.clone(),
+            quorum_operator_bps: self.quorum_operator_bps.clone(),
+            stall_fallback_threshold_bps: self.stall_fallback_threshold_bps.clone(),
+            admin_handover_delay_epochs: self.admin_handover_delay_epochs.clone(),
+            weight_sampling_window_slots: self.weight_sampling_window_slots.clone(),
+            max_operator_stake_weight_bps: self.max_operator_stake_weight_bps.clone(),
+            max_vault_stake_weight_bps: self.max_vault_stake_weight_bps.clone(),
+            fee_increase_notice_epochs: self.fee_increase_notice_epochs.clone(),
+            crank_bounty_lamports: self.crank_bounty_lamports.clone(),
+            max_crank_bounty_lamports_per_epoch: self.max_crank_bounty_lamports_per_epoch.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -321,6 +444,19 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
             epochs_before_stall: None,
             epochs_after_consensus_before_close: None,
             valid_slots_after_consensus: None,
+            commit_window_slots: None,
+            reveal_window_slots: None,
+            consensus_threshold_bps: None,
+            quorum_operator_count: None,
+            quorum_operator_bps: None,
+            stall_fallback_threshold_bps: None,
+            admin_handover_delay_epochs: None,
+            weight_sampling_window_slots: None,
+            max_operator_stake_weight_bps: None,
+            max_vault_stake_weight_bps: None,
+            fee_increase_notice_epochs: None,
+            crank_bounty_lamports: None,
+            max_crank_bounty_lamports_per_epoch: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -374,6 +510,91 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
         self.instruction.valid_slots_after_consensus = Some(valid_slots_after_consensus);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn commit_window_slots(&mut self, commit_window_slots: u64) -> &mut Self {
+        self.instruction.commit_window_slots = Some(commit_window_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn reveal_window_slots(&mut self, reveal_window_slots: u64) -> &mut Self {
+        self.instruction.reveal_window_slots = Some(reveal_window_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn consensus_threshold_bps(&mut self, consensus_threshold_bps: u16) -> &mut Self {
+        self.instruction.consensus_threshold_bps = Some(consensus_threshold_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn quorum_operator_count(&mut self, quorum_operator_count: u16) -> &mut Self {
+        self.instruction.quorum_operator_count = Some(quorum_operator_count);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn quorum_operator_bps(&mut self, quorum_operator_bps: u16) -> &mut Self {
+        self.instruction.quorum_operator_bps = Some(quorum_operator_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn stall_fallback_threshold_bps(&mut self, stall_fallback_threshold_bps: u16) -> &mut Self {
+        self.instruction.stall_fallback_threshold_bps = Some(stall_fallback_threshold_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn admin_handover_delay_epochs(&mut self, admin_handover_delay_epochs: u16) -> &mut Self {
+        self.instruction.admin_handover_delay_epochs = Some(admin_handover_delay_epochs);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn weight_sampling_window_slots(&mut self, weight_sampling_window_slots: u64) -> &mut Self {
+        self.instruction.weight_sampling_window_slots = Some(weight_sampling_window_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_operator_stake_weight_bps(
+        &mut self,
+        max_operator_stake_weight_bps: u16,
+    ) -> &mut Self {
+        self.instruction.max_operator_stake_weight_bps = Some(max_operator_stake_weight_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_vault_stake_weight_bps(&mut self, max_vault_stake_weight_bps: u16) -> &mut Self {
+        self.instruction.max_vault_stake_weight_bps = Some(max_vault_stake_weight_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn fee_increase_notice_epochs(&mut self, fee_increase_notice_epochs: u64) -> &mut Self {
+        self.instruction.fee_increase_notice_epochs = Some(fee_increase_notice_epochs);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn crank_bounty_lamports(&mut self, crank_bounty_lamports: u64) -> &mut Self {
+        self.instruction.crank_bounty_lamports = Some(crank_bounty_lamports);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_crank_bounty_lamports_per_epoch(
+        &mut self,
+        max_crank_bounty_lamports_per_epoch: u64,
+    ) -> &mut Self {
+        self.instruction.max_crank_bounty_lamports_per_epoch =
+            Some(max_crank_bounty_lamports_per_epoch);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -423,6 +644,22 @@ impl<'a, 'b> AdminSetParametersCpiBuilder<'a, 'b> {
                 .epochs_after_consensus_before_close
                 .clone(),
             valid_slots_after_consensus: self.instruction.valid_slots_after_consensus.clone(),
+            commit_window_slots: self.instruction.commit_window_slots.clone(),
+            reveal_window_slots: self.instruction.reveal_window_slots.clone(),
+            consensus_threshold_bps: self.instruction.consensus_threshold_bps.clone(),
+            quorum_operator_count: self.instruction.quorum_operator_count.clone(),
+            quorum_operator_bps: self.instruction.quorum_operator_bps.clone(),
+            stall_fallback_threshold_bps: self.instruction.stall_fallback_threshold_bps.clone(),
+            admin_handover_delay_epochs: self.instruction.admin_handover_delay_epochs.clone(),
+            weight_sampling_window_slots: self.instruction.weight_sampling_window_slots.clone(),
+            max_operator_stake_weight_bps: self.instruction.max_operator_stake_weight_bps.clone(),
+            max_vault_stake_weight_bps: self.instruction.max_vault_stake_weight_bps.clone(),
+            fee_increase_notice_epochs: self.instruction.fee_increase_notice_epochs.clone(),
+            crank_bounty_lamports: self.instruction.crank_bounty_lamports.clone(),
+            max_crank_bounty_lamports_per_epoch: self
+                .instruction
+                .max_crank_bounty_lamports_per_epoch
+                .clone(),
         };
         let instruction = AdminSetParametersCpi {
             __program: self.instruction.__program,
@@ -451,6 +688,19 @@ struct AdminSetParametersCpiBuilderInstruction<'a, 'b> {
     epochs_before_stall: Option<u64>,
     epochs_after_consensus_before_close: Option<u64>,
     valid_slots_after_consensus: Option<u64>,
+    commit_window_slots: Option<u64>,
+    reveal_window_slots: Option<u64>,
+    consensus_threshold_bps: Option<u16>,
+    quorum_operator_count: Option<u16>,
+    quorum_operator_bps: Option<u16>,
+    stall_fallback_threshold_bps: Option<u16>,
+    admin_handover_delay_epochs: Option<u16>,
+    weight_sampling_window_slots: Option<u64>,
+    max_operator_stake_weight_bps: Option<u16>,
+    max_vault_stake_weight_bps: Option<u16>,
+    fee_increase_notice_epochs: Option<u64>,
+    crank_bounty_lamports: Option<u64>,
+    max_crank_bounty_lamports_per_epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -86,6 +86,10 @@ pub struct AdminSetStMintInstructionArgs {
     pub reward_multiplier_bps: Option<u64>,
     pub switchboard_feed: Option<Pubkey>,
     pub no_feed_weight: Option<u128>,
+    pub oracle_type: Option<u8>,
+    pub max_staleness: Option<u64>,
+    pub max_confidence_bps: Option<u16>,
+    pub max_weight_change_bps: Option<u16>,
 }
 
 /// Instruction builder for `AdminSetStMint`.
@@ -107,6 +111,10 @@ pub struct AdminSetStMintBuilder {
     reward_multiplier_bps: Option<u64>,
     switchboard_feed: Option<Pubkey>,
     no_feed_weight: Option<u128>,
+    oracle_type: Option<u8>,
+    max_staleness: Option<u64>,
+    max_confidence_bps: Option<u16>,
+    max_weight_change_bps: Option<u16>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -163,6 +171,30 @@ impl AdminSetStMintBuilder {
         self.no_feed_weight = Some(no_feed_weight);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn oracle_type(&mut self, oracle_type: u8) -> &mut Self {
+        self.oracle_type = Some(oracle_type);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_staleness(&mut self, max_staleness: u64) -> &mut Self {
+        self.max_staleness = Some(max_staleness);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_confidence_bps(&mut self, max_confidence_bps: u16) -> &mut Self {
+        self.max_confidence_bps = Some(max_confidence_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_weight_change_bps(&mut self, max_weight_change_bps: u16) -> &mut Self {
+        self.max_weight_change_bps = Some(max_weight_change_bps);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -195,6 +227,10 @@ impl AdminSetStMintBuilder {
             reward_multiplier_bps: self.reward_multiplier_bps.clone(),
             switchboard_feed: self.switchboard_feed.clone(),
             no_feed_weight: self.no_feed_weight.clone(),
+            oracle_type: self.oracle_type.clone(),
+            max_staleness: self.max_staleness.clone(),
+            max_confidence_bps: self.max_confidence_bps.clone(),
+            max_weight_change_bps: self.max_weight_change_bps.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -353,6 +389,10 @@ impl<'a, 'b> AdminSetStMintCpiBuilder<'a, 'b> {
             reward_multiplier_bps: None,
             switchboard_feed: None,
             no_feed_weight: None,
+            oracle_type: None,
+            max_staleness: None,
+            max_confidence_bps: None,
+            max_weight_change_bps: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -412,6 +452,30 @@ impl<'a, 'b> AdminSetStMintCpiBuilder<'a, 'b> {
         self.instruction.no_feed_weight = Some(no_feed_weight);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn oracle_type(&mut self, oracle_type: u8) -> &mut Self {
+        self.instruction.oracle_type = Some(oracle_type);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_staleness(&mut self, max_staleness: u64) -> &mut Self {
+        self.instruction.max_staleness = Some(max_staleness);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_confidence_bps(&mut self, max_confidence_bps: u16) -> &mut Self {
+        self.instruction.max_confidence_bps = Some(max_confidence_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_weight_change_bps(&mut self, max_weight_change_bps: u16) -> &mut Self {
+        self.instruction.max_weight_change_bps = Some(max_weight_change_bps);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -463,6 +527,10 @@ impl<'a, 'b> AdminSetStMintCpiBuilder<'a, 'b> {
             reward_multiplier_bps: self.instruction.reward_multiplier_bps.clone(),
             switchboard_feed: self.instruction.switchboard_feed.clone(),
             no_feed_weight: self.instruction.no_feed_weight.clone(),
+            oracle_type: self.instruction.oracle_type.clone(),
+            max_staleness: self.instruction.max_staleness.clone(),
+            max_confidence_bps: self.instruction.max_confidence_bps.clone(),
+            max_weight_change_bps: self.instruction.max_weight_change_bps.clone(),
         };
         let instruction = AdminSetStMintCpi {
             __program: self.instruction.__program,
@@ -498,6 +566,10 @@ struct AdminSetStMintCpiBuilderInstruction<'a, 'b> {
     reward_multiplier_bps: Option<u64>,
     switchboard_feed: Option<Pubkey>,
     no_feed_weight: Option<u128>,
+    oracle_type: Option<u8>,
+    max_staleness: Option<u64>,
+    max_confidence_bps: Option<u16>,
+    max_weight_change_bps: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -25,6 +25,10 @@ pub struct CastVote {
     pub operator: solana_program::pubkey::Pubkey,
 
     pub operator_voter: solana_program::pubkey::Pubkey,
+
+    pub operator_vote_key: Option<solana_program::pubkey::Pubkey>,
+
+    pub ballot_box_page: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl CastVote {
@@ -40,7 +44,7 @@ impl CastVote {
         args: CastVoteInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -72,6 +76,28 @@ impl CastVote {
             self.operator_voter,
             true,
         ));
+        if let Some(operator_vote_key) = self.operator_vote_key {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                operator_vote_key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(ballot_box_page) = self.ballot_box_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                ballot_box_page,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&CastVoteInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -121,6 +147,8 @@ pub struct CastVoteInstructionArgs {
 ///   5. `[]` operator_snapshot
 ///   6. `[]` operator
 ///   7. `[signer]` operator_voter
+///   8. `[optional]` operator_vote_key
+///   9. `[writable, optional]` ballot_box_page
 #[derive(Clone, Debug, Default)]
 pub struct CastVoteBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -131,6 +159,8 @@ pub struct CastVoteBuilder {
     operator_snapshot: Option<solana_program::pubkey::Pubkey>,
     operator: Option<solana_program::pubkey::Pubkey>,
     operator_voter: Option<solana_program::pubkey::Pubkey>,
+    operator_vote_key: Option<solana_program::pubkey::Pubkey>,
+    ballot_box_page: Option<solana_program::pubkey::Pubkey>,
     meta_merkle_root: Option<[u8; 32]>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -183,6 +213,24 @@ impl CastVoteBuilder {
         self.operator_voter = Some(operator_voter);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_vote_key(
+        &mut self,
+        operator_vote_key: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.operator_vote_key = operator_vote_key;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ballot_box_page(
+        &mut self,
+        ballot_box_page: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.ballot_box_page = ballot_box_page;
+        self
+    }
     #[inline(always)]
     pub fn meta_merkle_root(&mut self, meta_merkle_root: [u8; 32]) -> &mut Self {
         self.meta_merkle_root = Some(meta_merkle_root);
@@ -224,6 +272,8 @@ impl CastVoteBuilder {
                 .expect("operator_snapshot is not set"),
             operator: self.operator.expect("operator is not set"),
             operator_voter: self.operator_voter.expect("operator_voter is not set"),
+            operator_vote_key: self.operator_vote_key,
+            ballot_box_page: self.ballot_box_page,
         };
         let args = CastVoteInstructionArgs {
             meta_merkle_root: self
@@ -254,6 +304,10 @@ pub struct CastVoteCpiAccounts<'a, 'b> {
     pub operator: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub operator_voter: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator_vote_key: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ballot_box_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `cast_vote` CPI instruction.
@@ -276,6 +330,10 @@ pub struct CastVoteCpi<'a, 'b> {
     pub operator: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub operator_voter: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator_vote_key: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ballot_box_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CastVoteInstructionArgs,
 }
@@ -296,6 +354,8 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
             operator_snapshot: accounts.operator_snapshot,
             operator: accounts.operator,
             operator_voter: accounts.operator_voter,
+            operator_vote_key: accounts.operator_vote_key,
+            ballot_box_page: accounts.ballot_box_page,
             __args: args,
         }
     }
@@ -332,7 +392,7 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -365,6 +425,28 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
             *self.operator_voter.key,
             true,
         ));
+        if let Some(operator_vote_key) = self.operator_vote_key {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *operator_vote_key.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(ballot_box_page) = self.ballot_box_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *ballot_box_page.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -381,7 +463,7 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -391,6 +473,12 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
         account_infos.push(self.operator_snapshot.clone());
         account_infos.push(self.operator.clone());
         account_infos.push(self.operator_voter.clone());
+        if let Some(operator_vote_key) = self.operator_vote_key {
+            account_infos.push(operator_vote_key.clone());
+        }
+        if let Some(ballot_box_page) = self.ballot_box_page {
+            account_infos.push(ballot_box_page.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -415,6 +503,8 @@ impl<'a, 'b> CastVoteCpi<'a, 'b> {
 ///   5. `[]` operator_snapshot
 ///   6. `[]` operator
 ///   7. `[signer]` operator_voter
+///   8. `[optional]` operator_vote_key
+///   9. `[writable, optional]` ballot_box_page
 #[derive(Clone, Debug)]
 pub struct CastVoteCpiBuilder<'a, 'b> {
     instruction: Box<CastVoteCpiBuilderInstruction<'a, 'b>>,
@@ -432,6 +522,8 @@ impl<'a, 'b> CastVoteCpiBuilder<'a, 'b> {
             operator_snapshot: None,
             operator: None,
             operator_voter: None,
+            operator_vote_key: None,
+            ballot_box_page: None,
             meta_merkle_root: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
@@ -499,6 +591,24 @@ impl<'a, 'b> CastVoteCpiBuilder<'a, 'b> {
         self.instruction.operator_voter = Some(operator_voter);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_vote_key(
+        &mut self,
+        operator_vote_key: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.operator_vote_key = operator_vote_key;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ballot_box_page(
+        &mut self,
+        ballot_box_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.ballot_box_page = ballot_box_page;
+        self
+    }
     #[inline(always)]
     pub fn meta_merkle_root(&mut self, meta_merkle_root: [u8; 32]) -> &mut Self {
         self.instruction.meta_merkle_root = Some(meta_merkle_root);
@@ -588,6 +698,10 @@ impl<'a, 'b> CastVoteCpiBuilder<'a, 'b> {
                 .instruction
                 .operator_voter
                 .expect("operator_voter is not set"),
+
+            operator_vote_key: self.instruction.operator_vote_key,
+
+            ballot_box_page: self.instruction.ballot_box_page,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -608,6 +722,8 @@ struct CastVoteCpiBuilderInstruction<'a, 'b> {
     operator_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator_voter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_vote_key: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ballot_box_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     meta_merkle_root: Option<[u8; 32]>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -0,0 +1,528 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use crate::generated::types::ConfigAdminRole;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct CloseAdminProposal {
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub admin_multisig: solana_program::pubkey::Pubkey,
+
+    pub admin_proposal: solana_program::pubkey::Pubkey,
+
+    pub ncn: solana_program::pubkey::Pubkey,
+
+    pub proposer: solana_program::pubkey::Pubkey,
+
+    pub signer: solana_program::pubkey::Pubkey,
+}
+
+impl CloseAdminProposal {
+    pub fn instruction(
+        &self,
+        args: CloseAdminProposalInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: CloseAdminProposalInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.admin_multisig,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.admin_proposal,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.proposer,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.signer,
+            true,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&CloseAdminProposalInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct CloseAdminProposalInstructionData {
+    discriminator: u8,
+}
+
+impl CloseAdminProposalInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 57 }
+    }
+}
+
+impl Default for CloseAdminProposalInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CloseAdminProposalInstructionArgs {
+    pub role: ConfigAdminRole,
+    pub nonce: u64,
+}
+
+/// Instruction builder for `CloseAdminProposal`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` admin_multisig
+///   2. `[writable]` admin_proposal
+///   3. `[]` ncn
+///   4. `[writable]` proposer
+///   5. `[signer]` signer
+#[derive(Clone, Debug, Default)]
+pub struct CloseAdminProposalBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    admin_multisig: Option<solana_program::pubkey::Pubkey>,
+    admin_proposal: Option<solana_program::pubkey::Pubkey>,
+    ncn: Option<solana_program::pubkey::Pubkey>,
+    proposer: Option<solana_program::pubkey::Pubkey>,
+    signer: Option<solana_program::pubkey::Pubkey>,
+    role: Option<ConfigAdminRole>,
+    nonce: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CloseAdminProposalBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn admin_multisig(&mut self, admin_multisig: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.admin_multisig = Some(admin_multisig);
+        self
+    }
+    #[inline(always)]
+    pub fn admin_proposal(&mut self, admin_proposal: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.admin_proposal = Some(admin_proposal);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn proposer(&mut self, proposer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.proposer = Some(proposer);
+        self
+    }
+    #[inline(always)]
+    pub fn signer(&mut self, signer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.signer = Some(signer);
+        self
+    }
+    #[inline(always)]
+    pub fn role(&mut self, role: ConfigAdminRole) -> &mut Self {
+        self.role = Some(role);
+        self
+    }
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.nonce = Some(nonce);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CloseAdminProposal {
+            config: self.config.expect("config is not set"),
+            admin_multisig: self.admin_multisig.expect("admin_multisig is not set"),
+            admin_proposal: self.admin_proposal.expect("admin_proposal is not set"),
+            ncn: self.ncn.expect("ncn is not set"),
+            proposer: self.proposer.expect("proposer is not set"),
+            signer: self.signer.expect("signer is not set"),
+        };
+        let args = CloseAdminProposalInstructionArgs {
+            role: self.role.clone().expect("role is not set"),
+            nonce: self.nonce.clone().expect("nonce is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `close_admin_proposal` CPI accounts.
+pub struct CloseAdminProposalCpiAccounts<'a, 'b> {
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin_multisig: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub proposer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `close_admin_proposal` CPI instruction.
+pub struct CloseAdminProposalCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin_multisig: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub proposer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub signer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: CloseAdminProposalInstructionArgs,
+}
+
+impl<'a, 'b> CloseAdminProposalCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CloseAdminProposalCpiAccounts<'a, 'b>,
+        args: CloseAdminProposalInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            admin_multisig: accounts.admin_multisig,
+            admin_proposal: accounts.admin_proposal,
+            ncn: accounts.ncn,
+            proposer: accounts.proposer,
+            signer: accounts.signer,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.admin_multisig.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.admin_proposal.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.proposer.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.signer.key,
+            true,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&CloseAdminProposalInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_TIP_ROUTER_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.admin_multisig.clone());
+        account_infos.push(self.admin_proposal.clone());
+        account_infos.push(self.ncn.clone());
+        account_infos.push(self.proposer.clone());
+        account_infos.push(self.signer.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CloseAdminProposal` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` admin_multisig
+///   2. `[writable]` admin_proposal
+///   3. `[]` ncn
+///   4. `[writable]` proposer
+///   5. `[signer]` signer
+#[derive(Clone, Debug)]
+pub struct CloseAdminProposalCpiBuilder<'a, 'b> {
+    instruction: Box<CloseAdminProposalCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CloseAdminProposalCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CloseAdminProposalCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            admin_multisig: None,
+            admin_proposal: None,
+            ncn: None,
+            proposer: None,
+            signer: None,
+            role: None,
+            nonce: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn admin_multisig(
+        &mut self,
+        admin_multisig: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.admin_multisig = Some(admin_multisig);
+        self
+    }
+    #[inline(always)]
+    pub fn admin_proposal(
+        &mut self,
+        admin_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.admin_proposal = Some(admin_proposal);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn proposer(
+        &mut self,
+        proposer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.proposer = Some(proposer);
+        self
+    }
+    #[inline(always)]
+    pub fn signer(
+        &mut self,
+        signer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.signer = Some(signer);
+        self
+    }
+    #[inline(always)]
+    pub fn role(&mut self, role: ConfigAdminRole) -> &mut Self {
+        self.instruction.role = Some(role);
+        self
+    }
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.instruction.nonce = Some(nonce);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = CloseAdminProposalInstructionArgs {
+            role: self.instruction.role.clone().expect("role is not set"),
+            nonce: self.instruction.nonce.clone().expect("nonce is not set"),
+        };
+        let instruction = CloseAdminProposalCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            admin_multisig: self
+                .instruction
+                .admin_multisig
+                .expect("admin_multisig is not set"),
+
+            admin_proposal: self
+                .instruction
+                .admin_proposal
+                .expect("admin_proposal is not set"),
+
+            ncn: self.instruction.ncn.expect("ncn is not set"),
+
+            proposer: self.instruction.proposer.expect("proposer is not set"),
+
+            signer: self.instruction.signer.expect("signer is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CloseAdminProposalCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    admin_multisig: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    admin_proposal: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    proposer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    role: Option<ConfigAdminRole>,
+    nonce: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
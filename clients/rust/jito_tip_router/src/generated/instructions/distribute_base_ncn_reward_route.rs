@@ -27,6 +27,20 @@ pub struct DistributeBaseNcnRewardRoute {
     pub ncn_reward_receiver: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub mint: Option<solana_program::pubkey::Pubkey>,
+
+    pub base_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub token_program: Option<solana_program::pubkey::Pubkey>,
+
+    pub account_payer: Option<solana_program::pubkey::Pubkey>,
+
+    pub cranker: Option<solana_program::pubkey::Pubkey>,
+
+    pub base_reward_router_page: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl DistributeBaseNcnRewardRoute {
@@ -42,7 +56,7 @@ impl DistributeBaseNcnRewardRoute {
         args: DistributeBaseNcnRewardRouteInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -78,6 +92,79 @@ impl DistributeBaseNcnRewardRoute {
             self.system_program,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(base_reward_receiver_token_account) = self.base_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                base_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                ncn_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(account_payer) = self.account_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                account_payer,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(cranker, true));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(base_reward_router_page) = self.base_reward_router_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                base_reward_router_page,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&DistributeBaseNcnRewardRouteInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -128,6 +215,13 @@ pub struct DistributeBaseNcnRewardRouteInstructionArgs {
 ///   6. `[]` ncn_reward_router
 ///   7. `[writable]` ncn_reward_receiver
 ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   9. `[optional]` mint
+///   10. `[writable, optional]` base_reward_receiver_token_account
+///   11. `[writable, optional]` ncn_reward_receiver_token_account
+///   12. `[optional]` token_program
+///   13. `[writable, optional]` account_payer
+///   14. `[writable, signer, optional]` cranker
+///   15. `[writable, optional]` base_reward_router_page
 #[derive(Clone, Debug, Default)]
 pub struct DistributeBaseNcnRewardRouteBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -139,6 +233,13 @@ pub struct DistributeBaseNcnRewardRouteBuilder {
     ncn_reward_router: Option<solana_program::pubkey::Pubkey>,
     ncn_reward_receiver: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    base_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    account_payer: Option<solana_program::pubkey::Pubkey>,
+    cranker: Option<solana_program::pubkey::Pubkey>,
+    base_reward_router_page: Option<solana_program::pubkey::Pubkey>,
     ncn_fee_group: Option<u8>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -206,6 +307,63 @@ impl DistributeBaseNcnRewardRouteBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn base_reward_receiver_token_account(
+        &mut self,
+        base_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.base_reward_receiver_token_account = base_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn account_payer(
+        &mut self,
+        account_payer: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.account_payer = account_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(&mut self, cranker: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn base_reward_router_page(
+        &mut self,
+        base_reward_router_page: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.base_reward_router_page = base_reward_router_page;
+        self
+    }
     #[inline(always)]
     pub fn ncn_fee_group(&mut self, ncn_fee_group: u8) -> &mut Self {
         self.ncn_fee_group = Some(ncn_fee_group);
@@ -256,6 +414,13 @@ impl DistributeBaseNcnRewardRouteBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            mint: self.mint,
+            base_reward_receiver_token_account: self.base_reward_receiver_token_account,
+            ncn_reward_receiver_token_account: self.ncn_reward_receiver_token_account,
+            token_program: self.token_program,
+            account_payer: self.account_payer,
+            cranker: self.cranker,
+            base_reward_router_page: self.base_reward_router_page,
         };
         let args = DistributeBaseNcnRewardRouteInstructionArgs {
             ncn_fee_group: self
@@ -288,6 +453,22 @@ pub struct DistributeBaseNcnRewardRouteCpiAccounts<'a, 'b> {
     pub ncn_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub base_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub base_reward_router_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `distribute_base_ncn_reward_route` CPI instruction.
@@ -312,6 +493,22 @@ pub struct DistributeBaseNcnRewardRouteCpi<'a, 'b> {
     pub ncn_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub base_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub base_reward_router_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DistributeBaseNcnRewardRouteInstructionArgs,
 }
@@ -333,6 +530,13 @@ impl<'a, 'b> DistributeBaseNcnRewardRouteCpi<'a, 'b> {
             ncn_reward_router: accounts.ncn_reward_router,
             ncn_reward_receiver: accounts.ncn_reward_receiver,
             system_program: accounts.system_program,
+            mint: accounts.mint,
+            base_reward_receiver_token_account: accounts.base_reward_receiver_token_account,
+            ncn_reward_receiver_token_account: accounts.ncn_reward_receiver_token_account,
+            token_program: accounts.token_program,
+            account_payer: accounts.account_payer,
+            cranker: accounts.cranker,
+            base_reward_router_page: accounts.base_reward_router_page,
             __args: args,
         }
     }
@@ -369,7 +573,7 @@ impl<'a, 'b> DistributeBaseNcnRewardRouteCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -406,6 +610,82 @@ impl<'a, 'b> DistributeBaseNcnRewardRouteCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(base_reward_receiver_token_account) = self.base_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *base_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *ncn_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(account_payer) = self.account_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *account_payer.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *cranker.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(base_reward_router_page) = self.base_reward_router_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *base_reward_router_page.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -422,7 +702,7 @@ impl<'a, 'b> DistributeBaseNcnRewardRouteCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(16 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -433,6 +713,27 @@ impl<'a, 'b> DistributeBaseNcnRewardRouteCpi<'a, 'b> {
         account_infos.push(self.ncn_reward_router.clone());
         account_infos.push(self.ncn_reward_receiver.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(mint) = self.mint {
+            account_infos.push(mint.clone());
+        }
+        if let Some(base_reward_receiver_token_account) = self.base_reward_receiver_token_account {
+            account_infos.push(base_reward_receiver_token_account.clone());
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            account_infos.push(ncn_reward_receiver_token_account.clone());
+        }
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
+        if let Some(account_payer) = self.account_payer {
+            account_infos.push(account_payer.clone());
+        }
+        if let Some(cranker) = self.cranker {
+            account_infos.push(cranker.clone());
+        }
+        if let Some(base_reward_router_page) = self.base_reward_router_page {
+            account_infos.push(base_reward_router_page.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -458,6 +759,13 @@ impl<'a, 'b> DistributeBaseNcnRewardRouteCpi<'a, 'b> {
 ///   6. `[]` ncn_reward_router
 ///   7. `[writable]` ncn_reward_receiver
 ///   8. `[]` system_program
+///   9. `[optional]` mint
+///   10. `[writable, optional]` base_reward_receiver_token_account
+///   11. `[writable, optional]` ncn_reward_receiver_token_account
+///   12. `[optional]` token_program
+///   13. `[writable, optional]` account_payer
+///   14. `[writable, signer, optional]` cranker
+///   15. `[writable, optional]` base_reward_router_page
 #[derive(Clone, Debug)]
 pub struct DistributeBaseNcnRewardRouteCpiBuilder<'a, 'b> {
     instruction: Box<DistributeBaseNcnRewardRouteCpiBuilderInstruction<'a, 'b>>,
@@ -476,6 +784,13 @@ impl<'a, 'b> DistributeBaseNcnRewardRouteCpiBuilder<'a, 'b> {
             ncn_reward_router: None,
             ncn_reward_receiver: None,
             system_program: None,
+            mint: None,
+            base_reward_receiver_token_account: None,
+            ncn_reward_receiver_token_account: None,
+            token_program: None,
+            account_payer: None,
+            cranker: None,
+            base_reward_router_page: None,
             ncn_fee_group: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
@@ -551,6 +866,73 @@ impl<'a, 'b> DistributeBaseNcnRewardRouteCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(
+        &mut self,
+        mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn base_reward_receiver_token_account(
+        &mut self,
+        base_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.base_reward_receiver_token_account = base_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn account_payer(
+        &mut self,
+        account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.account_payer = account_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(
+        &mut self,
+        cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn base_reward_router_page(
+        &mut self,
+        base_reward_router_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.base_reward_router_page = base_reward_router_page;
+        self
+    }
     #[inline(always)]
     pub fn ncn_fee_group(&mut self, ncn_fee_group: u8) -> &mut Self {
         self.instruction.ncn_fee_group = Some(ncn_fee_group);
@@ -648,6 +1030,20 @@ impl<'a, 'b> DistributeBaseNcnRewardRouteCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            mint: self.instruction.mint,
+
+            base_reward_receiver_token_account: self.instruction.base_reward_receiver_token_account,
+
+            ncn_reward_receiver_token_account: self.instruction.ncn_reward_receiver_token_account,
+
+            token_program: self.instruction.token_program,
+
+            account_payer: self.instruction.account_payer,
+
+            cranker: self.instruction.cranker,
+
+            base_reward_router_page: self.instruction.base_reward_router_page,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -669,6 +1065,13 @@ struct DistributeBaseNcnRewardRouteCpiBuilderInstruction<'a, 'b> {
     ncn_reward_router: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base_reward_receiver_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_reward_receiver_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base_reward_router_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_fee_group: Option<u8>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -41,6 +41,14 @@ pub struct DistributeBaseRewards {
     pub token_program: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub mint: Option<solana_program::pubkey::Pubkey>,
+
+    pub base_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub account_payer: Option<solana_program::pubkey::Pubkey>,
+
+    pub cranker: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl DistributeBaseRewards {
@@ -56,7 +64,7 @@ impl DistributeBaseRewards {
         args: DistributeBaseRewardsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -120,6 +128,46 @@ impl DistributeBaseRewards {
             self.system_program,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(base_reward_receiver_token_account) = self.base_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                base_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(account_payer) = self.account_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                account_payer,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(cranker, true));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&DistributeBaseRewardsInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -177,6 +225,10 @@ pub struct DistributeBaseRewardsInstructionArgs {
 ///   13. `[writable]` pool_mint
 ///   14. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   15. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   16. `[optional]` mint
+///   17. `[writable, optional]` base_reward_receiver_token_account
+///   18. `[writable, optional]` account_payer
+///   19. `[writable, signer, optional]` cranker
 #[derive(Clone, Debug, Default)]
 pub struct DistributeBaseRewardsBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -195,6 +247,10 @@ pub struct DistributeBaseRewardsBuilder {
     pool_mint: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    base_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    account_payer: Option<solana_program::pubkey::Pubkey>,
+    cranker: Option<solana_program::pubkey::Pubkey>,
     base_fee_group: Option<u8>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -310,6 +366,36 @@ impl DistributeBaseRewardsBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn base_reward_receiver_token_account(
+        &mut self,
+        base_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.base_reward_receiver_token_account = base_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn account_payer(
+        &mut self,
+        account_payer: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.account_payer = account_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(&mut self, cranker: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.cranker = cranker;
+        self
+    }
     #[inline(always)]
     pub fn base_fee_group(&mut self, base_fee_group: u8) -> &mut Self {
         self.base_fee_group = Some(base_fee_group);
@@ -375,6 +461,10 @@ impl DistributeBaseRewardsBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            mint: self.mint,
+            base_reward_receiver_token_account: self.base_reward_receiver_token_account,
+            account_payer: self.account_payer,
+            cranker: self.cranker,
         };
         let args = DistributeBaseRewardsInstructionArgs {
             base_fee_group: self
@@ -421,6 +511,15 @@ pub struct DistributeBaseRewardsCpiAccounts<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub base_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `distribute_base_rewards` CPI instruction.
@@ -459,6 +558,15 @@ pub struct DistributeBaseRewardsCpi<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub base_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DistributeBaseRewardsInstructionArgs,
 }
@@ -487,6 +595,10 @@ impl<'a, 'b> DistributeBaseRewardsCpi<'a, 'b> {
             pool_mint: accounts.pool_mint,
             token_program: accounts.token_program,
             system_program: accounts.system_program,
+            mint: accounts.mint,
+            base_reward_receiver_token_account: accounts.base_reward_receiver_token_account,
+            account_payer: accounts.account_payer,
+            cranker: accounts.cranker,
             __args: args,
         }
     }
@@ -523,7 +635,7 @@ impl<'a, 'b> DistributeBaseRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -588,6 +700,49 @@ impl<'a, 'b> DistributeBaseRewardsCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(base_reward_receiver_token_account) = self.base_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *base_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(account_payer) = self.account_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *account_payer.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *cranker.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -604,7 +759,7 @@ impl<'a, 'b> DistributeBaseRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(16 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(20 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -622,6 +777,18 @@ impl<'a, 'b> DistributeBaseRewardsCpi<'a, 'b> {
         account_infos.push(self.pool_mint.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(mint) = self.mint {
+            account_infos.push(mint.clone());
+        }
+        if let Some(base_reward_receiver_token_account) = self.base_reward_receiver_token_account {
+            account_infos.push(base_reward_receiver_token_account.clone());
+        }
+        if let Some(account_payer) = self.account_payer {
+            account_infos.push(account_payer.clone());
+        }
+        if let Some(cranker) = self.cranker {
+            account_infos.push(cranker.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -654,6 +821,10 @@ impl<'a, 'b> DistributeBaseRewardsCpi<'a, 'b> {
 ///   13. `[writable]` pool_mint
 ///   14. `[]` token_program
 ///   15. `[]` system_program
+///   16. `[optional]` mint
+///   17. `[writable, optional]` base_reward_receiver_token_account
+///   18. `[writable, optional]` account_payer
+///   19. `[writable, signer, optional]` cranker
 #[derive(Clone, Debug)]
 pub struct DistributeBaseRewardsCpiBuilder<'a, 'b> {
     instruction: Box<DistributeBaseRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -679,6 +850,10 @@ impl<'a, 'b> DistributeBaseRewardsCpiBuilder<'a, 'b> {
             pool_mint: None,
             token_program: None,
             system_program: None,
+            mint: None,
+            base_reward_receiver_token_account: None,
+            account_payer: None,
+            cranker: None,
             base_fee_group: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
@@ -810,6 +985,44 @@ impl<'a, 'b> DistributeBaseRewardsCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(
+        &mut self,
+        mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn base_reward_receiver_token_account(
+        &mut self,
+        base_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.base_reward_receiver_token_account = base_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn account_payer(
+        &mut self,
+        account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.account_payer = account_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(
+        &mut self,
+        cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.cranker = cranker;
+        self
+    }
     #[inline(always)]
     pub fn base_fee_group(&mut self, base_fee_group: u8) -> &mut Self {
         self.instruction.base_fee_group = Some(base_fee_group);
@@ -939,6 +1152,14 @@ impl<'a, 'b> DistributeBaseRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            mint: self.instruction.mint,
+
+            base_reward_receiver_token_account: self.instruction.base_reward_receiver_token_account,
+
+            account_payer: self.instruction.account_payer,
+
+            cranker: self.instruction.cranker,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -967,6 +1188,10 @@ struct DistributeBaseRewardsCpiBuilderInstruction<'a, 'b> {
     pool_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base_reward_receiver_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     base_fee_group: Option<u8>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -45,6 +45,18 @@ pub struct DistributeNcnVaultRewards {
     pub token_program: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub payout_preference: solana_program::pubkey::Pubkey,
+
+    pub mint: Option<solana_program::pubkey::Pubkey>,
+
+    pub ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub account_payer: Option<solana_program::pubkey::Pubkey>,
+
+    pub cranker: Option<solana_program::pubkey::Pubkey>,
+
+    pub epoch_state_page: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl DistributeNcnVaultRewards {
@@ -60,7 +72,7 @@ impl DistributeNcnVaultRewards {
         args: DistributeNcnVaultRewardsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(18 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(24 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -131,6 +143,63 @@ impl DistributeNcnVaultRewards {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.payout_preference,
+            false,
+        ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                ncn_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(account_payer) = self.account_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                account_payer,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                cranker, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                epoch_state_page,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&DistributeNcnVaultRewardsInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -190,6 +259,12 @@ pub struct DistributeNcnVaultRewardsInstructionArgs {
 ///   15. `[writable]` pool_mint
 ///   16. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   17. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   18. `[]` payout_preference
+///   19. `[optional]` mint
+///   20. `[writable, optional]` ncn_reward_receiver_token_account
+///   21. `[writable, optional]` account_payer
+///   22. `[writable, signer, optional]` cranker
+///   23. `[writable, optional]` epoch_state_page
 #[derive(Clone, Debug, Default)]
 pub struct DistributeNcnVaultRewardsBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -210,6 +285,12 @@ pub struct DistributeNcnVaultRewardsBuilder {
     pool_mint: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    payout_preference: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    account_payer: Option<solana_program::pubkey::Pubkey>,
+    cranker: Option<solana_program::pubkey::Pubkey>,
+    epoch_state_page: Option<solana_program::pubkey::Pubkey>,
     ncn_fee_group: Option<u8>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -320,7 +401,6 @@ impl DistributeNcnVaultRewardsBuilder {
         self.pool_mint = Some(pool_mint);
         self
     }
-    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
     #[inline(always)]
     pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
         self.token_program = Some(token_program);
@@ -333,6 +413,53 @@ impl DistributeNcnVaultRewardsBuilder {
         self
     }
     #[inline(always)]
+    pub fn payout_preference(
+        &mut self,
+        payout_preference: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.payout_preference = Some(payout_preference);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn account_payer(
+        &mut self,
+        account_payer: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.account_payer = account_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(&mut self, cranker: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn epoch_state_page(
+        &mut self,
+        epoch_state_page: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.epoch_state_page = epoch_state_page;
+        self
+    }
+    #[inline(always)]
     pub fn ncn_fee_group(&mut self, ncn_fee_group: u8) -> &mut Self {
         self.ncn_fee_group = Some(ncn_fee_group);
         self
@@ -393,12 +520,18 @@ impl DistributeNcnVaultRewardsBuilder {
                 .referrer_pool_tokens_account
                 .expect("referrer_pool_tokens_account is not set"),
             pool_mint: self.pool_mint.expect("pool_mint is not set"),
-            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
-                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-            )),
+            token_program: self.token_program.expect("token_program is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            payout_preference: self
+                .payout_preference
+                .expect("payout_preference is not set"),
+            mint: self.mint,
+            ncn_reward_receiver_token_account: self.ncn_reward_receiver_token_account,
+            account_payer: self.account_payer,
+            cranker: self.cranker,
+            epoch_state_page: self.epoch_state_page,
         };
         let args = DistributeNcnVaultRewardsInstructionArgs {
             ncn_fee_group: self
@@ -449,6 +582,19 @@ pub struct DistributeNcnVaultRewardsCpiAccounts<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payout_preference: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `distribute_ncn_vault_rewards` CPI instruction.
@@ -491,6 +637,19 @@ pub struct DistributeNcnVaultRewardsCpi<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payout_preference: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DistributeNcnVaultRewardsInstructionArgs,
 }
@@ -521,6 +680,12 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
             pool_mint: accounts.pool_mint,
             token_program: accounts.token_program,
             system_program: accounts.system_program,
+            payout_preference: accounts.payout_preference,
+            mint: accounts.mint,
+            ncn_reward_receiver_token_account: accounts.ncn_reward_receiver_token_account,
+            account_payer: accounts.account_payer,
+            cranker: accounts.cranker,
+            epoch_state_page: accounts.epoch_state_page,
             __args: args,
         }
     }
@@ -557,7 +722,7 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(18 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(24 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -630,6 +795,64 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.payout_preference.key,
+            false,
+        ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *ncn_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(account_payer) = self.account_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *account_payer.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *cranker.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *epoch_state_page.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -646,7 +869,7 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(18 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(24 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -666,6 +889,22 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
         account_infos.push(self.pool_mint.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.payout_preference.clone());
+        if let Some(mint) = self.mint {
+            account_infos.push(mint.clone());
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            account_infos.push(ncn_reward_receiver_token_account.clone());
+        }
+        if let Some(account_payer) = self.account_payer {
+            account_infos.push(account_payer.clone());
+        }
+        if let Some(cranker) = self.cranker {
+            account_infos.push(cranker.clone());
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            account_infos.push(epoch_state_page.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -700,6 +939,12 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpi<'a, 'b> {
 ///   15. `[writable]` pool_mint
 ///   16. `[]` token_program
 ///   17. `[]` system_program
+///   18. `[]` payout_preference
+///   19. `[optional]` mint
+///   20. `[writable, optional]` ncn_reward_receiver_token_account
+///   21. `[writable, optional]` account_payer
+///   22. `[writable, signer, optional]` cranker
+///   23. `[writable, optional]` epoch_state_page
 #[derive(Clone, Debug)]
 pub struct DistributeNcnVaultRewardsCpiBuilder<'a, 'b> {
     instruction: Box<DistributeNcnVaultRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -727,6 +972,12 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpiBuilder<'a, 'b> {
             pool_mint: None,
             token_program: None,
             system_program: None,
+            payout_preference: None,
+            mint: None,
+            ncn_reward_receiver_token_account: None,
+            account_payer: None,
+            cranker: None,
+            epoch_state_page: None,
             ncn_fee_group: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
@@ -872,6 +1123,61 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn payout_preference(
+        &mut self,
+        payout_preference: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.payout_preference = Some(payout_preference);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(
+        &mut self,
+        mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn account_payer(
+        &mut self,
+        account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.account_payer = account_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(
+        &mut self,
+        cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn epoch_state_page(
+        &mut self,
+        epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.epoch_state_page = epoch_state_page;
+        self
+    }
+    #[inline(always)]
     pub fn ncn_fee_group(&mut self, ncn_fee_group: u8) -> &mut Self {
         self.instruction.ncn_fee_group = Some(ncn_fee_group);
         self
@@ -1001,6 +1307,21 @@ impl<'a, 'b> DistributeNcnVaultRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            payout_preference: self
+                .instruction
+                .payout_preference
+                .expect("payout_preference is not set"),
+
+            mint: self.instruction.mint,
+
+            ncn_reward_receiver_token_account: self.instruction.ncn_reward_receiver_token_account,
+
+            account_payer: self.instruction.account_payer,
+
+            cranker: self.instruction.cranker,
+
+            epoch_state_page: self.instruction.epoch_state_page,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -1031,6 +1352,12 @@ struct DistributeNcnVaultRewardsCpiBuilderInstruction<'a, 'b> {
     pool_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payout_preference: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_reward_receiver_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_fee_group: Option<u8>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
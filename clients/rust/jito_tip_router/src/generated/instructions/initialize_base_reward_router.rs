@@ -23,6 +23,10 @@ pub struct InitializeBaseRewardRouter {
     pub account_payer: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub mint: Option<solana_program::pubkey::Pubkey>,
+
+    pub config: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl InitializeBaseRewardRouter {
@@ -38,7 +42,7 @@ impl InitializeBaseRewardRouter {
         args: InitializeBaseRewardRouterInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.epoch_marker,
             false,
@@ -66,6 +70,26 @@ impl InitializeBaseRewardRouter {
             self.system_program,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(config) = self.config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                config, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&InitializeBaseRewardRouterInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -113,6 +137,8 @@ pub struct InitializeBaseRewardRouterInstructionArgs {
 ///   4. `[writable]` base_reward_receiver
 ///   5. `[writable]` account_payer
 ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[optional]` mint
+///   8. `[optional]` config
 #[derive(Clone, Debug, Default)]
 pub struct InitializeBaseRewardRouterBuilder {
     epoch_marker: Option<solana_program::pubkey::Pubkey>,
@@ -122,6 +148,8 @@ pub struct InitializeBaseRewardRouterBuilder {
     base_reward_receiver: Option<solana_program::pubkey::Pubkey>,
     account_payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    config: Option<solana_program::pubkey::Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -172,6 +200,17 @@ impl InitializeBaseRewardRouterBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.mint = mint;
+        self
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.config = config;
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
@@ -211,6 +250,8 @@ impl InitializeBaseRewardRouterBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            mint: self.mint,
+            config: self.config,
         };
         let args = InitializeBaseRewardRouterInstructionArgs {
             epoch: self.epoch.clone().expect("epoch is not set"),
@@ -235,6 +276,10 @@ pub struct InitializeBaseRewardRouterCpiAccounts<'a, 'b> {
     pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `initialize_base_reward_router` CPI instruction.
@@ -255,6 +300,10 @@ pub struct InitializeBaseRewardRouterCpi<'a, 'b> {
     pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: InitializeBaseRewardRouterInstructionArgs,
 }
@@ -274,6 +323,8 @@ impl<'a, 'b> InitializeBaseRewardRouterCpi<'a, 'b> {
             base_reward_receiver: accounts.base_reward_receiver,
             account_payer: accounts.account_payer,
             system_program: accounts.system_program,
+            mint: accounts.mint,
+            config: accounts.config,
             __args: args,
         }
     }
@@ -310,7 +361,7 @@ impl<'a, 'b> InitializeBaseRewardRouterCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.epoch_marker.key,
             false,
@@ -339,6 +390,27 @@ impl<'a, 'b> InitializeBaseRewardRouterCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(config) = self.config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *config.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -355,7 +427,7 @@ impl<'a, 'b> InitializeBaseRewardRouterCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_marker.clone());
         account_infos.push(self.epoch_state.clone());
@@ -364,6 +436,12 @@ impl<'a, 'b> InitializeBaseRewardRouterCpi<'a, 'b> {
         account_infos.push(self.base_reward_receiver.clone());
         account_infos.push(self.account_payer.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(mint) = self.mint {
+            account_infos.push(mint.clone());
+        }
+        if let Some(config) = self.config {
+            account_infos.push(config.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -387,6 +465,8 @@ impl<'a, 'b> InitializeBaseRewardRouterCpi<'a, 'b> {
 ///   4. `[writable]` base_reward_receiver
 ///   5. `[writable]` account_payer
 ///   6. `[]` system_program
+///   7. `[optional]` mint
+///   8. `[optional]` config
 #[derive(Clone, Debug)]
 pub struct InitializeBaseRewardRouterCpiBuilder<'a, 'b> {
     instruction: Box<InitializeBaseRewardRouterCpiBuilderInstruction<'a, 'b>>,
@@ -403,6 +483,8 @@ impl<'a, 'b> InitializeBaseRewardRouterCpiBuilder<'a, 'b> {
             base_reward_receiver: None,
             account_payer: None,
             system_program: None,
+            mint: None,
+            config: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -461,6 +543,23 @@ impl<'a, 'b> InitializeBaseRewardRouterCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(
+        &mut self,
+        mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint = mint;
+        self
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.config = config;
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
@@ -544,6 +643,10 @@ impl<'a, 'b> InitializeBaseRewardRouterCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            mint: self.instruction.mint,
+
+            config: self.instruction.config,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -563,6 +666,8 @@ struct InitializeBaseRewardRouterCpiBuilderInstruction<'a, 'b> {
     base_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
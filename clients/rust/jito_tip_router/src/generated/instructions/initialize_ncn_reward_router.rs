@@ -27,6 +27,12 @@ pub struct InitializeNcnRewardRouter {
     pub account_payer: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub mint: Option<solana_program::pubkey::Pubkey>,
+
+    pub config: Option<solana_program::pubkey::Pubkey>,
+
+    pub epoch_state_page: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl InitializeNcnRewardRouter {
@@ -42,7 +48,7 @@ impl InitializeNcnRewardRouter {
         args: InitializeNcnRewardRouterInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.epoch_marker,
             false,
@@ -78,6 +84,37 @@ impl InitializeNcnRewardRouter {
             self.system_program,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(config) = self.config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                config, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                epoch_state_page,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&InitializeNcnRewardRouterInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -128,6 +165,9 @@ pub struct InitializeNcnRewardRouterInstructionArgs {
 ///   6. `[writable]` ncn_reward_receiver
 ///   7. `[writable]` account_payer
 ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   9. `[optional]` mint
+///   10. `[optional]` config
+///   11. `[writable, optional]` epoch_state_page
 #[derive(Clone, Debug, Default)]
 pub struct InitializeNcnRewardRouterBuilder {
     epoch_marker: Option<solana_program::pubkey::Pubkey>,
@@ -139,6 +179,9 @@ pub struct InitializeNcnRewardRouterBuilder {
     ncn_reward_receiver: Option<solana_program::pubkey::Pubkey>,
     account_payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    config: Option<solana_program::pubkey::Pubkey>,
+    epoch_state_page: Option<solana_program::pubkey::Pubkey>,
     ncn_fee_group: Option<u8>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -203,6 +246,27 @@ impl InitializeNcnRewardRouterBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn config(&mut self, config: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.config = config;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn epoch_state_page(
+        &mut self,
+        epoch_state_page: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.epoch_state_page = epoch_state_page;
+        self
+    }
     #[inline(always)]
     pub fn ncn_fee_group(&mut self, ncn_fee_group: u8) -> &mut Self {
         self.ncn_fee_group = Some(ncn_fee_group);
@@ -251,6 +315,9 @@ impl InitializeNcnRewardRouterBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            mint: self.mint,
+            config: self.config,
+            epoch_state_page: self.epoch_state_page,
         };
         let args = InitializeNcnRewardRouterInstructionArgs {
             ncn_fee_group: self
@@ -283,6 +350,12 @@ pub struct InitializeNcnRewardRouterCpiAccounts<'a, 'b> {
     pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `initialize_ncn_reward_router` CPI instruction.
@@ -307,6 +380,12 @@ pub struct InitializeNcnRewardRouterCpi<'a, 'b> {
     pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: InitializeNcnRewardRouterInstructionArgs,
 }
@@ -328,6 +407,9 @@ impl<'a, 'b> InitializeNcnRewardRouterCpi<'a, 'b> {
             ncn_reward_receiver: accounts.ncn_reward_receiver,
             account_payer: accounts.account_payer,
             system_program: accounts.system_program,
+            mint: accounts.mint,
+            config: accounts.config,
+            epoch_state_page: accounts.epoch_state_page,
             __args: args,
         }
     }
@@ -364,7 +446,7 @@ impl<'a, 'b> InitializeNcnRewardRouterCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.epoch_marker.key,
             false,
@@ -401,6 +483,38 @@ impl<'a, 'b> InitializeNcnRewardRouterCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(config) = self.config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *config.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *epoch_state_page.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -417,7 +531,7 @@ impl<'a, 'b> InitializeNcnRewardRouterCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_marker.clone());
         account_infos.push(self.epoch_state.clone());
@@ -428,6 +542,15 @@ impl<'a, 'b> InitializeNcnRewardRouterCpi<'a, 'b> {
         account_infos.push(self.ncn_reward_receiver.clone());
         account_infos.push(self.account_payer.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(mint) = self.mint {
+            account_infos.push(mint.clone());
+        }
+        if let Some(config) = self.config {
+            account_infos.push(config.clone());
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            account_infos.push(epoch_state_page.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -453,6 +576,9 @@ impl<'a, 'b> InitializeNcnRewardRouterCpi<'a, 'b> {
 ///   6. `[writable]` ncn_reward_receiver
 ///   7. `[writable]` account_payer
 ///   8. `[]` system_program
+///   9. `[optional]` mint
+///   10. `[optional]` config
+///   11. `[writable, optional]` epoch_state_page
 #[derive(Clone, Debug)]
 pub struct InitializeNcnRewardRouterCpiBuilder<'a, 'b> {
     instruction: Box<InitializeNcnRewardRouterCpiBuilderInstruction<'a, 'b>>,
@@ -471,6 +597,9 @@ impl<'a, 'b> InitializeNcnRewardRouterCpiBuilder<'a, 'b> {
             ncn_reward_receiver: None,
             account_payer: None,
             system_program: None,
+            mint: None,
+            config: None,
+            epoch_state_page: None,
             ncn_fee_group: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
@@ -546,6 +675,33 @@ impl<'a, 'b> InitializeNcnRewardRouterCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(
+        &mut self,
+        mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.config = config;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn epoch_state_page(
+        &mut self,
+        epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.epoch_state_page = epoch_state_page;
+        self
+    }
     #[inline(always)]
     pub fn ncn_fee_group(&mut self, ncn_fee_group: u8) -> &mut Self {
         self.instruction.ncn_fee_group = Some(ncn_fee_group);
@@ -646,6 +802,12 @@ impl<'a, 'b> InitializeNcnRewardRouterCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            mint: self.instruction.mint,
+
+            config: self.instruction.config,
+
+            epoch_state_page: self.instruction.epoch_state_page,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -667,6 +829,9 @@ struct InitializeNcnRewardRouterCpiBuilderInstruction<'a, 'b> {
     ncn_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_fee_group: Option<u8>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
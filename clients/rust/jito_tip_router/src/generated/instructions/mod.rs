@@ -5,29 +5,55 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+pub(crate) mod r#admin_accept_admin;
+pub(crate) mod r#admin_cancel_admin_proposal;
+pub(crate) mod r#admin_cancel_scheduled_fees;
+pub(crate) mod r#admin_deactivate_st_mint;
+pub(crate) mod r#admin_deactivate_vault;
+pub(crate) mod r#admin_propose_new_admin;
 pub(crate) mod r#admin_register_st_mint;
+pub(crate) mod r#admin_set_admin_multisig;
+pub(crate) mod r#admin_set_base_fee_group;
 pub(crate) mod r#admin_set_config_fees;
 pub(crate) mod r#admin_set_new_admin;
 pub(crate) mod r#admin_set_parameters;
+pub(crate) mod r#admin_set_paused_subsystems;
+pub(crate) mod r#admin_set_reward_mint;
 pub(crate) mod r#admin_set_st_mint;
 pub(crate) mod r#admin_set_tie_breaker;
 pub(crate) mod r#admin_set_weight;
+pub(crate) mod r#apply_stake_weight_caps;
+pub(crate) mod r#approve_admin_action;
+pub(crate) mod r#carry_forward_rewards;
 pub(crate) mod r#cast_vote;
 pub(crate) mod r#claim_with_payer;
+pub(crate) mod r#close_admin_proposal;
 pub(crate) mod r#close_epoch_account;
+pub(crate) mod r#close_operator_vote_key;
+pub(crate) mod r#commit_vote;
 pub(crate) mod r#distribute_base_ncn_reward_route;
 pub(crate) mod r#distribute_base_rewards;
 pub(crate) mod r#distribute_ncn_operator_rewards;
 pub(crate) mod r#distribute_ncn_vault_rewards;
+pub(crate) mod r#execute_admin_action;
 pub(crate) mod r#initialize_ballot_box;
+pub(crate) mod r#initialize_ballot_box_page;
 pub(crate) mod r#initialize_base_reward_router;
+pub(crate) mod r#initialize_base_reward_router_page;
 pub(crate) mod r#initialize_config;
 pub(crate) mod r#initialize_epoch_snapshot;
 pub(crate) mod r#initialize_epoch_state;
+pub(crate) mod r#initialize_epoch_state_page;
 pub(crate) mod r#initialize_ncn_reward_router;
 pub(crate) mod r#initialize_operator_snapshot;
+pub(crate) mod r#initialize_operator_snapshot_page;
 pub(crate) mod r#initialize_vault_registry;
+pub(crate) mod r#initialize_vault_registry_page;
 pub(crate) mod r#initialize_weight_table;
+pub(crate) mod r#initialize_weight_table_page;
+pub(crate) mod r#migrate_account;
+pub(crate) mod r#oracle_set_weight;
+pub(crate) mod r#propose_admin_action;
 pub(crate) mod r#realloc_ballot_box;
 pub(crate) mod r#realloc_base_reward_router;
 pub(crate) mod r#realloc_epoch_state;
@@ -35,35 +61,66 @@ pub(crate) mod r#realloc_operator_snapshot;
 pub(crate) mod r#realloc_vault_registry;
 pub(crate) mod r#realloc_weight_table;
 pub(crate) mod r#register_vault;
+pub(crate) mod r#resolve_stalled_vote;
+pub(crate) mod r#reveal_vote;
 pub(crate) mod r#route_base_rewards;
 pub(crate) mod r#route_ncn_rewards;
 pub(crate) mod r#set_merkle_root;
+pub(crate) mod r#set_operator_vote_key;
+pub(crate) mod r#set_payout_preference;
 pub(crate) mod r#snapshot_vault_operator_delegation;
 pub(crate) mod r#switchboard_set_weight;
+pub(crate) mod r#withdraw_vote;
 
+pub use self::r#admin_accept_admin::*;
+pub use self::r#admin_cancel_admin_proposal::*;
+pub use self::r#admin_cancel_scheduled_fees::*;
+pub use self::r#admin_deactivate_st_mint::*;
+pub use self::r#admin_deactivate_vault::*;
+pub use self::r#admin_propose_new_admin::*;
 pub use self::r#admin_register_st_mint::*;
+pub use self::r#admin_set_admin_multisig::*;
+pub use self::r#admin_set_base_fee_group::*;
 pub use self::r#admin_set_config_fees::*;
 pub use self::r#admin_set_new_admin::*;
 pub use self::r#admin_set_parameters::*;
+pub use self::r#admin_set_paused_subsystems::*;
+pub use self::r#admin_set_reward_mint::*;
 pub use self::r#admin_set_st_mint::*;
 pub use self::r#admin_set_tie_breaker::*;
 pub use self::r#admin_set_weight::*;
+pub use self::r#apply_stake_weight_caps::*;
+pub use self::r#approve_admin_action::*;
+pub use self::r#carry_forward_rewards::*;
 pub use self::r#cast_vote::*;
 pub use self::r#claim_with_payer::*;
+pub use self::r#close_admin_proposal::*;
 pub use self::r#close_epoch_account::*;
+pub use self::r#close_operator_vote_key::*;
+pub use self::r#commit_vote::*;
 pub use self::r#distribute_base_ncn_reward_route::*;
 pub use self::r#distribute_base_rewards::*;
 pub use self::r#distribute_ncn_operator_rewards::*;
 pub use self::r#distribute_ncn_vault_rewards::*;
+pub use self::r#execute_admin_action::*;
 pub use self::r#initialize_ballot_box::*;
+pub use self::r#initialize_ballot_box_page::*;
 pub use self::r#initialize_base_reward_router::*;
+pub use self::r#initialize_base_reward_router_page::*;
 pub use self::r#initialize_config::*;
 pub use self::r#initialize_epoch_snapshot::*;
 pub use self::r#initialize_epoch_state::*;
+pub use self::r#initialize_epoch_state_page::*;
 pub use self::r#initialize_ncn_reward_router::*;
 pub use self::r#initialize_operator_snapshot::*;
+pub use self::r#initialize_operator_snapshot_page::*;
 pub use self::r#initialize_vault_registry::*;
+pub use self::r#initialize_vault_registry_page::*;
 pub use self::r#initialize_weight_table::*;
+pub use self::r#initialize_weight_table_page::*;
+pub use self::r#migrate_account::*;
+pub use self::r#oracle_set_weight::*;
+pub use self::r#propose_admin_action::*;
 pub use self::r#realloc_ballot_box::*;
 pub use self::r#realloc_base_reward_router::*;
 pub use self::r#realloc_epoch_state::*;
@@ -71,8 +128,13 @@ pub use self::r#realloc_operator_snapshot::*;
 pub use self::r#realloc_vault_registry::*;
 pub use self::r#realloc_weight_table::*;
 pub use self::r#register_vault::*;
+pub use self::r#resolve_stalled_vote::*;
+pub use self::r#reveal_vote::*;
 pub use self::r#route_base_rewards::*;
 pub use self::r#route_ncn_rewards::*;
 pub use self::r#set_merkle_root::*;
+pub use self::r#set_operator_vote_key::*;
+pub use self::r#set_payout_preference::*;
 pub use self::r#snapshot_vault_operator_delegation::*;
 pub use self::r#switchboard_set_weight::*;
+pub use self::r#withdraw_vote::*;
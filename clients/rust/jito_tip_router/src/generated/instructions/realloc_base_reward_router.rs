@@ -21,6 +21,8 @@ pub struct ReallocBaseRewardRouter {
     pub account_payer: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub mint: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl ReallocBaseRewardRouter {
@@ -36,7 +38,7 @@ impl ReallocBaseRewardRouter {
         args: ReallocBaseRewardRouterInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -60,6 +62,16 @@ impl ReallocBaseRewardRouter {
             self.system_program,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&ReallocBaseRewardRouterInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -106,6 +118,7 @@ pub struct ReallocBaseRewardRouterInstructionArgs {
 ///   3. `[]` ncn
 ///   4. `[writable]` account_payer
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` mint
 #[derive(Clone, Debug, Default)]
 pub struct ReallocBaseRewardRouterBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -114,6 +127,7 @@ pub struct ReallocBaseRewardRouterBuilder {
     ncn: Option<solana_program::pubkey::Pubkey>,
     account_payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -156,6 +170,12 @@ impl ReallocBaseRewardRouterBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.mint = mint;
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
@@ -192,6 +212,7 @@ impl ReallocBaseRewardRouterBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            mint: self.mint,
         };
         let args = ReallocBaseRewardRouterInstructionArgs {
             epoch: self.epoch.clone().expect("epoch is not set"),
@@ -214,6 +235,8 @@ pub struct ReallocBaseRewardRouterCpiAccounts<'a, 'b> {
     pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `realloc_base_reward_router` CPI instruction.
@@ -232,6 +255,8 @@ pub struct ReallocBaseRewardRouterCpi<'a, 'b> {
     pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: ReallocBaseRewardRouterInstructionArgs,
 }
@@ -250,6 +275,7 @@ impl<'a, 'b> ReallocBaseRewardRouterCpi<'a, 'b> {
             ncn: accounts.ncn,
             account_payer: accounts.account_payer,
             system_program: accounts.system_program,
+            mint: accounts.mint,
             __args: args,
         }
     }
@@ -286,7 +312,7 @@ impl<'a, 'b> ReallocBaseRewardRouterCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -311,6 +337,16 @@ impl<'a, 'b> ReallocBaseRewardRouterCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -327,7 +363,7 @@ impl<'a, 'b> ReallocBaseRewardRouterCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -335,6 +371,9 @@ impl<'a, 'b> ReallocBaseRewardRouterCpi<'a, 'b> {
         account_infos.push(self.ncn.clone());
         account_infos.push(self.account_payer.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(mint) = self.mint {
+            account_infos.push(mint.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -357,6 +396,7 @@ impl<'a, 'b> ReallocBaseRewardRouterCpi<'a, 'b> {
 ///   3. `[]` ncn
 ///   4. `[writable]` account_payer
 ///   5. `[]` system_program
+///   6. `[optional]` mint
 #[derive(Clone, Debug)]
 pub struct ReallocBaseRewardRouterCpiBuilder<'a, 'b> {
     instruction: Box<ReallocBaseRewardRouterCpiBuilderInstruction<'a, 'b>>,
@@ -372,6 +412,7 @@ impl<'a, 'b> ReallocBaseRewardRouterCpiBuilder<'a, 'b> {
             ncn: None,
             account_payer: None,
             system_program: None,
+            mint: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -422,6 +463,15 @@ impl<'a, 'b> ReallocBaseRewardRouterCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(
+        &mut self,
+        mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint = mint;
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
@@ -497,6 +547,8 @@ impl<'a, 'b> ReallocBaseRewardRouterCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            mint: self.instruction.mint,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -515,6 +567,7 @@ struct ReallocBaseRewardRouterCpiBuilderInstruction<'a, 'b> {
     ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
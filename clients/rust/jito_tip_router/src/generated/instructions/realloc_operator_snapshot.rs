@@ -29,6 +29,8 @@ pub struct ReallocOperatorSnapshot {
     pub account_payer: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub epoch_state_page: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl ReallocOperatorSnapshot {
@@ -44,7 +46,7 @@ impl ReallocOperatorSnapshot {
         args: ReallocOperatorSnapshotInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -84,6 +86,17 @@ impl ReallocOperatorSnapshot {
             self.system_program,
             false,
         ));
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                epoch_state_page,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&ReallocOperatorSnapshotInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -134,6 +147,7 @@ pub struct ReallocOperatorSnapshotInstructionArgs {
 ///   7. `[writable]` operator_snapshot
 ///   8. `[writable]` account_payer
 ///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   10. `[writable, optional]` epoch_state_page
 #[derive(Clone, Debug, Default)]
 pub struct ReallocOperatorSnapshotBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -146,6 +160,7 @@ pub struct ReallocOperatorSnapshotBuilder {
     operator_snapshot: Option<solana_program::pubkey::Pubkey>,
     account_payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    epoch_state_page: Option<solana_program::pubkey::Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -214,6 +229,15 @@ impl ReallocOperatorSnapshotBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn epoch_state_page(
+        &mut self,
+        epoch_state_page: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.epoch_state_page = epoch_state_page;
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
@@ -256,6 +280,7 @@ impl ReallocOperatorSnapshotBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            epoch_state_page: self.epoch_state_page,
         };
         let args = ReallocOperatorSnapshotInstructionArgs {
             epoch: self.epoch.clone().expect("epoch is not set"),
@@ -286,6 +311,8 @@ pub struct ReallocOperatorSnapshotCpiAccounts<'a, 'b> {
     pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `realloc_operator_snapshot` CPI instruction.
@@ -312,6 +339,8 @@ pub struct ReallocOperatorSnapshotCpi<'a, 'b> {
     pub account_payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: ReallocOperatorSnapshotInstructionArgs,
 }
@@ -334,6 +363,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
             operator_snapshot: accounts.operator_snapshot,
             account_payer: accounts.account_payer,
             system_program: accounts.system_program,
+            epoch_state_page: accounts.epoch_state_page,
             __args: args,
         }
     }
@@ -370,7 +400,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -411,6 +441,17 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *epoch_state_page.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -427,7 +468,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -439,6 +480,9 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
         account_infos.push(self.operator_snapshot.clone());
         account_infos.push(self.account_payer.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            account_infos.push(epoch_state_page.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -465,6 +509,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpi<'a, 'b> {
 ///   7. `[writable]` operator_snapshot
 ///   8. `[writable]` account_payer
 ///   9. `[]` system_program
+///   10. `[writable, optional]` epoch_state_page
 #[derive(Clone, Debug)]
 pub struct ReallocOperatorSnapshotCpiBuilder<'a, 'b> {
     instruction: Box<ReallocOperatorSnapshotCpiBuilderInstruction<'a, 'b>>,
@@ -484,6 +529,7 @@ impl<'a, 'b> ReallocOperatorSnapshotCpiBuilder<'a, 'b> {
             operator_snapshot: None,
             account_payer: None,
             system_program: None,
+            epoch_state_page: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -566,6 +612,15 @@ impl<'a, 'b> ReallocOperatorSnapshotCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn epoch_state_page(
+        &mut self,
+        epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.epoch_state_page = epoch_state_page;
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
@@ -658,6 +713,8 @@ impl<'a, 'b> ReallocOperatorSnapshotCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            epoch_state_page: self.instruction.epoch_state_page,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -680,6 +737,7 @@ struct ReallocOperatorSnapshotCpiBuilderInstruction<'a, 'b> {
     operator_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -19,6 +19,8 @@ pub struct RegisterVault {
     pub vault: solana_program::pubkey::Pubkey,
 
     pub ncn_vault_ticket: solana_program::pubkey::Pubkey,
+
+    pub vault_registry_page: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl RegisterVault {
@@ -30,7 +32,7 @@ impl RegisterVault {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -49,6 +51,17 @@ impl RegisterVault {
             self.ncn_vault_ticket,
             false,
         ));
+        if let Some(vault_registry_page) = self.vault_registry_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                vault_registry_page,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&RegisterVaultInstructionData::new()).unwrap();
 
@@ -86,6 +99,7 @@ impl Default for RegisterVaultInstructionData {
 ///   2. `[]` ncn
 ///   3. `[]` vault
 ///   4. `[]` ncn_vault_ticket
+///   5. `[writable, optional]` vault_registry_page
 #[derive(Clone, Debug, Default)]
 pub struct RegisterVaultBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -93,6 +107,7 @@ pub struct RegisterVaultBuilder {
     ncn: Option<solana_program::pubkey::Pubkey>,
     vault: Option<solana_program::pubkey::Pubkey>,
     ncn_vault_ticket: Option<solana_program::pubkey::Pubkey>,
+    vault_registry_page: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -128,6 +143,15 @@ impl RegisterVaultBuilder {
         self.ncn_vault_ticket = Some(ncn_vault_ticket);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn vault_registry_page(
+        &mut self,
+        vault_registry_page: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_registry_page = vault_registry_page;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -154,6 +178,7 @@ impl RegisterVaultBuilder {
             ncn: self.ncn.expect("ncn is not set"),
             vault: self.vault.expect("vault is not set"),
             ncn_vault_ticket: self.ncn_vault_ticket.expect("ncn_vault_ticket is not set"),
+            vault_registry_page: self.vault_registry_page,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -171,6 +196,8 @@ pub struct RegisterVaultCpiAccounts<'a, 'b> {
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_registry_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `register_vault` CPI instruction.
@@ -187,6 +214,8 @@ pub struct RegisterVaultCpi<'a, 'b> {
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_registry_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
@@ -201,6 +230,7 @@ impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
             ncn: accounts.ncn,
             vault: accounts.vault,
             ncn_vault_ticket: accounts.ncn_vault_ticket,
+            vault_registry_page: accounts.vault_registry_page,
         }
     }
     #[inline(always)]
@@ -236,7 +266,7 @@ impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -257,6 +287,17 @@ impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
             *self.ncn_vault_ticket.key,
             false,
         ));
+        if let Some(vault_registry_page) = self.vault_registry_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *vault_registry_page.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -271,13 +312,16 @@ impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault_registry.clone());
         account_infos.push(self.ncn.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.ncn_vault_ticket.clone());
+        if let Some(vault_registry_page) = self.vault_registry_page {
+            account_infos.push(vault_registry_page.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -299,6 +343,7 @@ impl<'a, 'b> RegisterVaultCpi<'a, 'b> {
 ///   2. `[]` ncn
 ///   3. `[]` vault
 ///   4. `[]` ncn_vault_ticket
+///   5. `[writable, optional]` vault_registry_page
 #[derive(Clone, Debug)]
 pub struct RegisterVaultCpiBuilder<'a, 'b> {
     instruction: Box<RegisterVaultCpiBuilderInstruction<'a, 'b>>,
@@ -313,6 +358,7 @@ impl<'a, 'b> RegisterVaultCpiBuilder<'a, 'b> {
             ncn: None,
             vault: None,
             ncn_vault_ticket: None,
+            vault_registry_page: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -351,6 +397,15 @@ impl<'a, 'b> RegisterVaultCpiBuilder<'a, 'b> {
         self.instruction.ncn_vault_ticket = Some(ncn_vault_ticket);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn vault_registry_page(
+        &mut self,
+        vault_registry_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_registry_page = vault_registry_page;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -410,6 +465,8 @@ impl<'a, 'b> RegisterVaultCpiBuilder<'a, 'b> {
                 .instruction
                 .ncn_vault_ticket
                 .expect("ncn_vault_ticket is not set"),
+
+            vault_registry_page: self.instruction.vault_registry_page,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -426,6 +483,7 @@ struct RegisterVaultCpiBuilderInstruction<'a, 'b> {
     ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_vault_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_registry_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -23,6 +23,16 @@ pub struct RouteBaseRewards {
     pub base_reward_router: solana_program::pubkey::Pubkey,
 
     pub base_reward_receiver: solana_program::pubkey::Pubkey,
+
+    pub mint: Option<solana_program::pubkey::Pubkey>,
+
+    pub base_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub account_payer: Option<solana_program::pubkey::Pubkey>,
+
+    pub cranker: Option<solana_program::pubkey::Pubkey>,
+
+    pub system_program: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl RouteBaseRewards {
@@ -38,7 +48,7 @@ impl RouteBaseRewards {
         args: RouteBaseRewardsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -66,6 +76,57 @@ impl RouteBaseRewards {
             self.base_reward_receiver,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(base_reward_receiver_token_account) = self.base_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                base_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(account_payer) = self.account_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                account_payer,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(cranker, true));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&RouteBaseRewardsInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -114,6 +175,11 @@ pub struct RouteBaseRewardsInstructionArgs {
 ///   4. `[]` ballot_box
 ///   5. `[writable]` base_reward_router
 ///   6. `[writable]` base_reward_receiver
+///   7. `[optional]` mint
+///   8. `[optional]` base_reward_receiver_token_account
+///   9. `[writable, optional]` account_payer
+///   10. `[writable, signer, optional]` cranker
+///   11. `[optional]` system_program
 #[derive(Clone, Debug, Default)]
 pub struct RouteBaseRewardsBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -123,6 +189,11 @@ pub struct RouteBaseRewardsBuilder {
     ballot_box: Option<solana_program::pubkey::Pubkey>,
     base_reward_router: Option<solana_program::pubkey::Pubkey>,
     base_reward_receiver: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    base_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    account_payer: Option<solana_program::pubkey::Pubkey>,
+    cranker: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
     max_iterations: Option<u16>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -173,6 +244,45 @@ impl RouteBaseRewardsBuilder {
         self.base_reward_receiver = Some(base_reward_receiver);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn base_reward_receiver_token_account(
+        &mut self,
+        base_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.base_reward_receiver_token_account = base_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn account_payer(
+        &mut self,
+        account_payer: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.account_payer = account_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(&mut self, cranker: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
     #[inline(always)]
     pub fn max_iterations(&mut self, max_iterations: u16) -> &mut Self {
         self.max_iterations = Some(max_iterations);
@@ -215,6 +325,11 @@ impl RouteBaseRewardsBuilder {
             base_reward_receiver: self
                 .base_reward_receiver
                 .expect("base_reward_receiver is not set"),
+            mint: self.mint,
+            base_reward_receiver_token_account: self.base_reward_receiver_token_account,
+            account_payer: self.account_payer,
+            cranker: self.cranker,
+            system_program: self.system_program,
         };
         let args = RouteBaseRewardsInstructionArgs {
             max_iterations: self
@@ -243,6 +358,17 @@ pub struct RouteBaseRewardsCpiAccounts<'a, 'b> {
     pub base_reward_router: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub base_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub base_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `route_base_rewards` CPI instruction.
@@ -263,6 +389,17 @@ pub struct RouteBaseRewardsCpi<'a, 'b> {
     pub base_reward_router: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub base_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub base_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: RouteBaseRewardsInstructionArgs,
 }
@@ -282,6 +419,11 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
             ballot_box: accounts.ballot_box,
             base_reward_router: accounts.base_reward_router,
             base_reward_receiver: accounts.base_reward_receiver,
+            mint: accounts.mint,
+            base_reward_receiver_token_account: accounts.base_reward_receiver_token_account,
+            account_payer: accounts.account_payer,
+            cranker: accounts.cranker,
+            system_program: accounts.system_program,
             __args: args,
         }
     }
@@ -318,7 +460,7 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -347,6 +489,60 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
             *self.base_reward_receiver.key,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(base_reward_receiver_token_account) = self.base_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *base_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(account_payer) = self.account_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *account_payer.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *cranker.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -363,7 +559,7 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -372,6 +568,21 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
         account_infos.push(self.ballot_box.clone());
         account_infos.push(self.base_reward_router.clone());
         account_infos.push(self.base_reward_receiver.clone());
+        if let Some(mint) = self.mint {
+            account_infos.push(mint.clone());
+        }
+        if let Some(base_reward_receiver_token_account) = self.base_reward_receiver_token_account {
+            account_infos.push(base_reward_receiver_token_account.clone());
+        }
+        if let Some(account_payer) = self.account_payer {
+            account_infos.push(account_payer.clone());
+        }
+        if let Some(cranker) = self.cranker {
+            account_infos.push(cranker.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -395,6 +606,11 @@ impl<'a, 'b> RouteBaseRewardsCpi<'a, 'b> {
 ///   4. `[]` ballot_box
 ///   5. `[writable]` base_reward_router
 ///   6. `[writable]` base_reward_receiver
+///   7. `[optional]` mint
+///   8. `[optional]` base_reward_receiver_token_account
+///   9. `[writable, optional]` account_payer
+///   10. `[writable, signer, optional]` cranker
+///   11. `[optional]` system_program
 #[derive(Clone, Debug)]
 pub struct RouteBaseRewardsCpiBuilder<'a, 'b> {
     instruction: Box<RouteBaseRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -411,6 +627,11 @@ impl<'a, 'b> RouteBaseRewardsCpiBuilder<'a, 'b> {
             ballot_box: None,
             base_reward_router: None,
             base_reward_receiver: None,
+            mint: None,
+            base_reward_receiver_token_account: None,
+            account_payer: None,
+            cranker: None,
+            system_program: None,
             max_iterations: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
@@ -470,6 +691,53 @@ impl<'a, 'b> RouteBaseRewardsCpiBuilder<'a, 'b> {
         self.instruction.base_reward_receiver = Some(base_reward_receiver);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(
+        &mut self,
+        mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn base_reward_receiver_token_account(
+        &mut self,
+        base_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.base_reward_receiver_token_account = base_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn account_payer(
+        &mut self,
+        account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.account_payer = account_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(
+        &mut self,
+        cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
     #[inline(always)]
     pub fn max_iterations(&mut self, max_iterations: u16) -> &mut Self {
         self.instruction.max_iterations = Some(max_iterations);
@@ -557,6 +825,16 @@ impl<'a, 'b> RouteBaseRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .base_reward_receiver
                 .expect("base_reward_receiver is not set"),
+
+            mint: self.instruction.mint,
+
+            base_reward_receiver_token_account: self.instruction.base_reward_receiver_token_account,
+
+            account_payer: self.instruction.account_payer,
+
+            cranker: self.instruction.cranker,
+
+            system_program: self.instruction.system_program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -576,6 +854,11 @@ struct RouteBaseRewardsCpiBuilderInstruction<'a, 'b> {
     ballot_box: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     base_reward_router: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     base_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base_reward_receiver_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     max_iterations: Option<u16>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
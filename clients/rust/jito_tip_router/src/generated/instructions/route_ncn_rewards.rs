@@ -12,6 +12,8 @@ use borsh::BorshSerialize;
 pub struct RouteNcnRewards {
     pub epoch_state: solana_program::pubkey::Pubkey,
 
+    pub config: solana_program::pubkey::Pubkey,
+
     pub ncn: solana_program::pubkey::Pubkey,
 
     pub operator: solana_program::pubkey::Pubkey,
@@ -21,6 +23,18 @@ pub struct RouteNcnRewards {
     pub ncn_reward_router: solana_program::pubkey::Pubkey,
 
     pub ncn_reward_receiver: solana_program::pubkey::Pubkey,
+
+    pub mint: Option<solana_program::pubkey::Pubkey>,
+
+    pub ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub account_payer: Option<solana_program::pubkey::Pubkey>,
+
+    pub cranker: Option<solana_program::pubkey::Pubkey>,
+
+    pub system_program: Option<solana_program::pubkey::Pubkey>,
+
+    pub epoch_state_page: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl RouteNcnRewards {
@@ -36,11 +50,15 @@ impl RouteNcnRewards {
         args: RouteNcnRewardsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.ncn, false,
         ));
@@ -60,6 +78,70 @@ impl RouteNcnRewards {
             self.ncn_reward_receiver,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                ncn_reward_receiver_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(account_payer) = self.account_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                account_payer,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                cranker, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                epoch_state_page,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&RouteNcnRewardsInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -103,19 +185,33 @@ pub struct RouteNcnRewardsInstructionArgs {
 /// ### Accounts:
 ///
 ///   0. `[writable]` epoch_state
-///   1. `[]` ncn
-///   2. `[]` operator
-///   3. `[]` operator_snapshot
-///   4. `[writable]` ncn_reward_router
-///   5. `[writable]` ncn_reward_receiver
+///   1. `[]` config
+///   2. `[]` ncn
+///   3. `[]` operator
+///   4. `[]` operator_snapshot
+///   5. `[writable]` ncn_reward_router
+///   6. `[writable]` ncn_reward_receiver
+///   7. `[optional]` mint
+///   8. `[optional]` ncn_reward_receiver_token_account
+///   9. `[writable, optional]` account_payer
+///   10. `[writable, signer, optional]` cranker
+///   11. `[optional]` system_program
+///   12. `[writable, optional]` epoch_state_page
 #[derive(Clone, Debug, Default)]
 pub struct RouteNcnRewardsBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
+    config: Option<solana_program::pubkey::Pubkey>,
     ncn: Option<solana_program::pubkey::Pubkey>,
     operator: Option<solana_program::pubkey::Pubkey>,
     operator_snapshot: Option<solana_program::pubkey::Pubkey>,
     ncn_reward_router: Option<solana_program::pubkey::Pubkey>,
     ncn_reward_receiver: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    account_payer: Option<solana_program::pubkey::Pubkey>,
+    cranker: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    epoch_state_page: Option<solana_program::pubkey::Pubkey>,
     ncn_fee_group: Option<u8>,
     max_iterations: Option<u16>,
     epoch: Option<u64>,
@@ -132,6 +228,11 @@ impl RouteNcnRewardsBuilder {
         self
     }
     #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
     pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
         self.ncn = Some(ncn);
         self
@@ -165,6 +266,55 @@ impl RouteNcnRewardsBuilder {
         self.ncn_reward_receiver = Some(ncn_reward_receiver);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn account_payer(
+        &mut self,
+        account_payer: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.account_payer = account_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(&mut self, cranker: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.cranker = cranker;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn epoch_state_page(
+        &mut self,
+        epoch_state_page: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.epoch_state_page = epoch_state_page;
+        self
+    }
     #[inline(always)]
     pub fn ncn_fee_group(&mut self, ncn_fee_group: u8) -> &mut Self {
         self.ncn_fee_group = Some(ncn_fee_group);
@@ -202,6 +352,7 @@ impl RouteNcnRewardsBuilder {
     pub fn instruction(&self) -> solana_program::instruction::Instruction {
         let accounts = RouteNcnRewards {
             epoch_state: self.epoch_state.expect("epoch_state is not set"),
+            config: self.config.expect("config is not set"),
             ncn: self.ncn.expect("ncn is not set"),
             operator: self.operator.expect("operator is not set"),
             operator_snapshot: self
@@ -213,6 +364,12 @@ impl RouteNcnRewardsBuilder {
             ncn_reward_receiver: self
                 .ncn_reward_receiver
                 .expect("ncn_reward_receiver is not set"),
+            mint: self.mint,
+            ncn_reward_receiver_token_account: self.ncn_reward_receiver_token_account,
+            account_payer: self.account_payer,
+            cranker: self.cranker,
+            system_program: self.system_program,
+            epoch_state_page: self.epoch_state_page,
         };
         let args = RouteNcnRewardsInstructionArgs {
             ncn_fee_group: self
@@ -234,6 +391,8 @@ impl RouteNcnRewardsBuilder {
 pub struct RouteNcnRewardsCpiAccounts<'a, 'b> {
     pub epoch_state: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub operator: &'b solana_program::account_info::AccountInfo<'a>,
@@ -243,6 +402,19 @@ pub struct RouteNcnRewardsCpiAccounts<'a, 'b> {
     pub ncn_reward_router: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ncn_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `route_ncn_rewards` CPI instruction.
@@ -252,6 +424,8 @@ pub struct RouteNcnRewardsCpi<'a, 'b> {
 
     pub epoch_state: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub operator: &'b solana_program::account_info::AccountInfo<'a>,
@@ -261,6 +435,19 @@ pub struct RouteNcnRewardsCpi<'a, 'b> {
     pub ncn_reward_router: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub ncn_reward_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub ncn_reward_receiver_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: RouteNcnRewardsInstructionArgs,
 }
@@ -274,11 +461,18 @@ impl<'a, 'b> RouteNcnRewardsCpi<'a, 'b> {
         Self {
             __program: program,
             epoch_state: accounts.epoch_state,
+            config: accounts.config,
             ncn: accounts.ncn,
             operator: accounts.operator,
             operator_snapshot: accounts.operator_snapshot,
             ncn_reward_router: accounts.ncn_reward_router,
             ncn_reward_receiver: accounts.ncn_reward_receiver,
+            mint: accounts.mint,
+            ncn_reward_receiver_token_account: accounts.ncn_reward_receiver_token_account,
+            account_payer: accounts.account_payer,
+            cranker: accounts.cranker,
+            system_program: accounts.system_program,
+            epoch_state_page: accounts.epoch_state_page,
             __args: args,
         }
     }
@@ -315,11 +509,15 @@ impl<'a, 'b> RouteNcnRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.ncn.key,
             false,
@@ -340,6 +538,71 @@ impl<'a, 'b> RouteNcnRewardsCpi<'a, 'b> {
             *self.ncn_reward_receiver.key,
             false,
         ));
+        if let Some(mint) = self.mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *ncn_reward_receiver_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(account_payer) = self.account_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *account_payer.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *cranker.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *epoch_state_page.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -356,14 +619,33 @@ impl<'a, 'b> RouteNcnRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
+        account_infos.push(self.config.clone());
         account_infos.push(self.ncn.clone());
         account_infos.push(self.operator.clone());
         account_infos.push(self.operator_snapshot.clone());
         account_infos.push(self.ncn_reward_router.clone());
         account_infos.push(self.ncn_reward_receiver.clone());
+        if let Some(mint) = self.mint {
+            account_infos.push(mint.clone());
+        }
+        if let Some(ncn_reward_receiver_token_account) = self.ncn_reward_receiver_token_account {
+            account_infos.push(ncn_reward_receiver_token_account.clone());
+        }
+        if let Some(account_payer) = self.account_payer {
+            account_infos.push(account_payer.clone());
+        }
+        if let Some(cranker) = self.cranker {
+            account_infos.push(cranker.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            account_infos.push(epoch_state_page.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -381,11 +663,18 @@ impl<'a, 'b> RouteNcnRewardsCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[writable]` epoch_state
-///   1. `[]` ncn
-///   2. `[]` operator
-///   3. `[]` operator_snapshot
-///   4. `[writable]` ncn_reward_router
-///   5. `[writable]` ncn_reward_receiver
+///   1. `[]` config
+///   2. `[]` ncn
+///   3. `[]` operator
+///   4. `[]` operator_snapshot
+///   5. `[writable]` ncn_reward_router
+///   6. `[writable]` ncn_reward_receiver
+///   7. `[optional]` mint
+///   8. `[optional]` ncn_reward_receiver_token_account
+///   9. `[writable, optional]` account_payer
+///   10. `[writable, signer, optional]` cranker
+///   11. `[optional]` system_program
+///   12. `[writable, optional]` epoch_state_page
 #[derive(Clone, Debug)]
 pub struct RouteNcnRewardsCpiBuilder<'a, 'b> {
     instruction: Box<RouteNcnRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -396,11 +685,18 @@ impl<'a, 'b> RouteNcnRewardsCpiBuilder<'a, 'b> {
         let instruction = Box::new(RouteNcnRewardsCpiBuilderInstruction {
             __program: program,
             epoch_state: None,
+            config: None,
             ncn: None,
             operator: None,
             operator_snapshot: None,
             ncn_reward_router: None,
             ncn_reward_receiver: None,
+            mint: None,
+            ncn_reward_receiver_token_account: None,
+            account_payer: None,
+            cranker: None,
+            system_program: None,
+            epoch_state_page: None,
             ncn_fee_group: None,
             max_iterations: None,
             epoch: None,
@@ -417,6 +713,14 @@ impl<'a, 'b> RouteNcnRewardsCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
     pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.ncn = Some(ncn);
         self
@@ -453,6 +757,62 @@ impl<'a, 'b> RouteNcnRewardsCpiBuilder<'a, 'b> {
         self.instruction.ncn_reward_receiver = Some(ncn_reward_receiver);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint(
+        &mut self,
+        mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn ncn_reward_receiver_token_account(
+        &mut self,
+        ncn_reward_receiver_token_account: Option<
+            &'b solana_program::account_info::AccountInfo<'a>,
+        >,
+    ) -> &mut Self {
+        self.instruction.ncn_reward_receiver_token_account = ncn_reward_receiver_token_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn account_payer(
+        &mut self,
+        account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.account_payer = account_payer;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn cranker(
+        &mut self,
+        cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn epoch_state_page(
+        &mut self,
+        epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.epoch_state_page = epoch_state_page;
+        self
+    }
     #[inline(always)]
     pub fn ncn_fee_group(&mut self, ncn_fee_group: u8) -> &mut Self {
         self.instruction.ncn_fee_group = Some(ncn_fee_group);
@@ -530,6 +890,8 @@ impl<'a, 'b> RouteNcnRewardsCpiBuilder<'a, 'b> {
                 .epoch_state
                 .expect("epoch_state is not set"),
 
+            config: self.instruction.config.expect("config is not set"),
+
             ncn: self.instruction.ncn.expect("ncn is not set"),
 
             operator: self.instruction.operator.expect("operator is not set"),
@@ -548,6 +910,18 @@ impl<'a, 'b> RouteNcnRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .ncn_reward_receiver
                 .expect("ncn_reward_receiver is not set"),
+
+            mint: self.instruction.mint,
+
+            ncn_reward_receiver_token_account: self.instruction.ncn_reward_receiver_token_account,
+
+            account_payer: self.instruction.account_payer,
+
+            cranker: self.instruction.cranker,
+
+            system_program: self.instruction.system_program,
+
+            epoch_state_page: self.instruction.epoch_state_page,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -561,11 +935,18 @@ impl<'a, 'b> RouteNcnRewardsCpiBuilder<'a, 'b> {
 struct RouteNcnRewardsCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     epoch_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_reward_router: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_reward_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_reward_receiver_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    account_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_fee_group: Option<u8>,
     max_iterations: Option<u16>,
     epoch: Option<u64>,
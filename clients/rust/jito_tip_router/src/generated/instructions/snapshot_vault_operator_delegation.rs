@@ -33,6 +33,12 @@ pub struct SnapshotVaultOperatorDelegation {
     pub epoch_snapshot: solana_program::pubkey::Pubkey,
 
     pub operator_snapshot: solana_program::pubkey::Pubkey,
+
+    pub weight_table_page: Option<solana_program::pubkey::Pubkey>,
+
+    pub operator_snapshot_page: Option<solana_program::pubkey::Pubkey>,
+
+    pub epoch_state_page: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl SnapshotVaultOperatorDelegation {
@@ -48,7 +54,7 @@ impl SnapshotVaultOperatorDelegation {
         args: SnapshotVaultOperatorDelegationInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.epoch_state,
             false,
@@ -95,6 +101,39 @@ impl SnapshotVaultOperatorDelegation {
             self.operator_snapshot,
             false,
         ));
+        if let Some(weight_table_page) = self.weight_table_page {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                weight_table_page,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(operator_snapshot_page) = self.operator_snapshot_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                operator_snapshot_page,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                epoch_state_page,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data =
             borsh::to_vec(&SnapshotVaultOperatorDelegationInstructionData::new()).unwrap();
@@ -148,6 +187,9 @@ pub struct SnapshotVaultOperatorDelegationInstructionArgs {
 ///   9. `[]` weight_table
 ///   10. `[writable]` epoch_snapshot
 ///   11. `[writable]` operator_snapshot
+///   12. `[optional]` weight_table_page
+///   13. `[writable, optional]` operator_snapshot_page
+///   14. `[writable, optional]` epoch_state_page
 #[derive(Clone, Debug, Default)]
 pub struct SnapshotVaultOperatorDelegationBuilder {
     epoch_state: Option<solana_program::pubkey::Pubkey>,
@@ -162,6 +204,9 @@ pub struct SnapshotVaultOperatorDelegationBuilder {
     weight_table: Option<solana_program::pubkey::Pubkey>,
     epoch_snapshot: Option<solana_program::pubkey::Pubkey>,
     operator_snapshot: Option<solana_program::pubkey::Pubkey>,
+    weight_table_page: Option<solana_program::pubkey::Pubkey>,
+    operator_snapshot_page: Option<solana_program::pubkey::Pubkey>,
+    epoch_state_page: Option<solana_program::pubkey::Pubkey>,
     epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -245,6 +290,33 @@ impl SnapshotVaultOperatorDelegationBuilder {
         self.operator_snapshot = Some(operator_snapshot);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn weight_table_page(
+        &mut self,
+        weight_table_page: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.weight_table_page = weight_table_page;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_snapshot_page(
+        &mut self,
+        operator_snapshot_page: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.operator_snapshot_page = operator_snapshot_page;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn epoch_state_page(
+        &mut self,
+        epoch_state_page: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.epoch_state_page = epoch_state_page;
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.epoch = Some(epoch);
@@ -287,6 +359,9 @@ impl SnapshotVaultOperatorDelegationBuilder {
             operator_snapshot: self
                 .operator_snapshot
                 .expect("operator_snapshot is not set"),
+            weight_table_page: self.weight_table_page,
+            operator_snapshot_page: self.operator_snapshot_page,
+            epoch_state_page: self.epoch_state_page,
         };
         let args = SnapshotVaultOperatorDelegationInstructionArgs {
             epoch: self.epoch.clone().expect("epoch is not set"),
@@ -321,6 +396,12 @@ pub struct SnapshotVaultOperatorDelegationCpiAccounts<'a, 'b> {
     pub epoch_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub operator_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub weight_table_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub operator_snapshot_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `snapshot_vault_operator_delegation` CPI instruction.
@@ -351,6 +432,12 @@ pub struct SnapshotVaultOperatorDelegationCpi<'a, 'b> {
     pub epoch_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub operator_snapshot: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub weight_table_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub operator_snapshot_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: SnapshotVaultOperatorDelegationInstructionArgs,
 }
@@ -375,6 +462,9 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
             weight_table: accounts.weight_table,
             epoch_snapshot: accounts.epoch_snapshot,
             operator_snapshot: accounts.operator_snapshot,
+            weight_table_page: accounts.weight_table_page,
+            operator_snapshot_page: accounts.operator_snapshot_page,
+            epoch_state_page: accounts.epoch_state_page,
             __args: args,
         }
     }
@@ -411,7 +501,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.epoch_state.key,
             false,
@@ -460,6 +550,39 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
             *self.operator_snapshot.key,
             false,
         ));
+        if let Some(weight_table_page) = self.weight_table_page {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *weight_table_page.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(operator_snapshot_page) = self.operator_snapshot_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *operator_snapshot_page.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *epoch_state_page.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_TIP_ROUTER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -477,7 +600,7 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(15 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.epoch_state.clone());
         account_infos.push(self.config.clone());
@@ -491,6 +614,15 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
         account_infos.push(self.weight_table.clone());
         account_infos.push(self.epoch_snapshot.clone());
         account_infos.push(self.operator_snapshot.clone());
+        if let Some(weight_table_page) = self.weight_table_page {
+            account_infos.push(weight_table_page.clone());
+        }
+        if let Some(operator_snapshot_page) = self.operator_snapshot_page {
+            account_infos.push(operator_snapshot_page.clone());
+        }
+        if let Some(epoch_state_page) = self.epoch_state_page {
+            account_infos.push(epoch_state_page.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -519,6 +651,9 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpi<'a, 'b> {
 ///   9. `[]` weight_table
 ///   10. `[writable]` epoch_snapshot
 ///   11. `[writable]` operator_snapshot
+///   12. `[optional]` weight_table_page
+///   13. `[writable, optional]` operator_snapshot_page
+///   14. `[writable, optional]` epoch_state_page
 #[derive(Clone, Debug)]
 pub struct SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
     instruction: Box<SnapshotVaultOperatorDelegationCpiBuilderInstruction<'a, 'b>>,
@@ -540,6 +675,9 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
             weight_table: None,
             epoch_snapshot: None,
             operator_snapshot: None,
+            weight_table_page: None,
+            operator_snapshot_page: None,
+            epoch_state_page: None,
             epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -635,6 +773,33 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
         self.instruction.operator_snapshot = Some(operator_snapshot);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn weight_table_page(
+        &mut self,
+        weight_table_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.weight_table_page = weight_table_page;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operator_snapshot_page(
+        &mut self,
+        operator_snapshot_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.operator_snapshot_page = operator_snapshot_page;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn epoch_state_page(
+        &mut self,
+        epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.epoch_state_page = epoch_state_page;
+        self
+    }
     #[inline(always)]
     pub fn epoch(&mut self, epoch: u64) -> &mut Self {
         self.instruction.epoch = Some(epoch);
@@ -734,6 +899,12 @@ impl<'a, 'b> SnapshotVaultOperatorDelegationCpiBuilder<'a, 'b> {
                 .instruction
                 .operator_snapshot
                 .expect("operator_snapshot is not set"),
+
+            weight_table_page: self.instruction.weight_table_page,
+
+            operator_snapshot_page: self.instruction.operator_snapshot_page,
+
+            epoch_state_page: self.instruction.epoch_state_page,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -758,6 +929,9 @@ struct SnapshotVaultOperatorDelegationCpiBuilderInstruction<'a, 'b> {
     weight_table: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator_snapshot: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    weight_table_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_snapshot_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch_state_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
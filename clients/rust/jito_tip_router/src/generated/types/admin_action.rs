@@ -0,0 +1,49 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use crate::generated::types::ConfigAdminRole;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AdminAction {
+    SetTieBreaker {
+        meta_merkle_root: [u8; 32],
+        epoch: u64,
+    },
+    SetConfigFees {
+        new_block_engine_fee_bps: Option<u16>,
+        base_fee_group: Option<u8>,
+        new_base_fee_wallet: Option<Pubkey>,
+        new_base_fee_bps: Option<u16>,
+        ncn_fee_group: Option<u8>,
+        new_ncn_fee_bps: Option<u16>,
+        new_priority_fee_distribution_fee_bps: Option<u16>,
+        activation_epoch: Option<u64>,
+    },
+    SetNewAdmin {
+        role: ConfigAdminRole,
+        new_admin: Pubkey,
+    },
+    SetBaseFeeGroup {
+        base_fee_group: u8,
+        name: Option<[u8; 32]>,
+        enabled: Option<bool>,
+        payout_asset: Option<u8>,
+    },
+    SetMultisigSigner {
+        role: ConfigAdminRole,
+        signer: Pubkey,
+        is_signer: bool,
+        threshold: u8,
+    },
+    SetPausedSubsystems {
+        paused_subsystems: u8,
+    },
+}
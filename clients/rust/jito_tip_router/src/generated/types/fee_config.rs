@@ -5,6 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+use crate::generated::types::BaseFeeGroupSettings;
 use crate::generated::types::Fees;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -15,8 +16,9 @@ use solana_program::pubkey::Pubkey;
 pub struct FeeConfig {
     pub block_engine_fee_bps: u16,
     pub base_fee_wallets: [Pubkey; 8],
+    pub base_fee_groups: [BaseFeeGroupSettings; 8],
+    pub fee_increase_notice_epochs: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 128],
-    pub fee1: Fees,
-    pub fee2: Fees,
+    pub reserved: [u8; 120],
+    pub fees: [Fees; 8],
 }
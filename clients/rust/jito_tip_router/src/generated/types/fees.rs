@@ -14,8 +14,9 @@ use borsh::BorshSerialize;
 pub struct Fees {
     pub activation_epoch: u64,
     pub priority_fee_distribution_fee_bps: Fee,
+    pub is_scheduled: bool,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 126],
+    pub reserved: [u8; 125],
     pub base_fee_groups_bps: [Fee; 8],
     pub ncn_fee_groups_bps: [Fee; 8],
 }
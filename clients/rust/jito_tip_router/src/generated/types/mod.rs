@@ -5,9 +5,11 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+pub(crate) mod r#admin_action;
 pub(crate) mod r#ballot;
 pub(crate) mod r#ballot_tally;
 pub(crate) mod r#base_fee_group;
+pub(crate) mod r#base_fee_group_settings;
 pub(crate) mod r#base_reward_router_rewards;
 pub(crate) mod r#config_admin_role;
 pub(crate) mod r#epoch_account_status;
@@ -18,6 +20,7 @@ pub(crate) mod r#ncn_fee_group;
 pub(crate) mod r#ncn_fee_group_weight;
 pub(crate) mod r#ncn_reward_route;
 pub(crate) mod r#operator_vote;
+pub(crate) mod r#pending_admin_handover;
 pub(crate) mod r#progress;
 pub(crate) mod r#st_mint_entry;
 pub(crate) mod r#stake_pool_instruction;
@@ -27,9 +30,11 @@ pub(crate) mod r#vault_operator_stake_weight;
 pub(crate) mod r#vault_reward_route;
 pub(crate) mod r#weight_entry;
 
+pub use self::r#admin_action::*;
 pub use self::r#ballot::*;
 pub use self::r#ballot_tally::*;
 pub use self::r#base_fee_group::*;
+pub use self::r#base_fee_group_settings::*;
 pub use self::r#base_reward_router_rewards::*;
 pub use self::r#config_admin_role::*;
 pub use self::r#epoch_account_status::*;
@@ -40,6 +45,7 @@ pub use self::r#ncn_fee_group::*;
 pub use self::r#ncn_fee_group_weight::*;
 pub use self::r#ncn_reward_route::*;
 pub use self::r#operator_vote::*;
+pub use self::r#pending_admin_handover::*;
 pub use self::r#progress::*;
 pub use self::r#st_mint_entry::*;
 pub use self::r#stake_pool_instruction::*;
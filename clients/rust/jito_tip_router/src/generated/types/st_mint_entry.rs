@@ -26,6 +26,13 @@ pub struct StMintEntry {
     )]
     pub switchboard_feed: Pubkey,
     pub no_feed_weight: u128,
+    pub oracle_type: u8,
+    pub max_staleness: u64,
+    pub max_confidence_bps: u16,
+    pub max_weight_change_bps: u16,
+    pub last_weight: u128,
+    pub last_weight_epoch: u64,
+    pub deactivation_epoch: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 83],
 }
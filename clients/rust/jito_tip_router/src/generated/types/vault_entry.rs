@@ -24,6 +24,7 @@ pub struct VaultEntry {
     pub st_mint: Pubkey,
     pub vault_index: u64,
     pub slot_registered: u64,
+    pub deactivation_epoch: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 120],
 }
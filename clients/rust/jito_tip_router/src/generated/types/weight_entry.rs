@@ -16,6 +16,12 @@ pub struct WeightEntry {
     pub weight: u128,
     pub slot_set: u64,
     pub slot_updated: u64,
+    pub sample_count: u64,
+    pub first_sample_slot: u64,
+    pub last_sample_slot: u64,
+    pub last_sample_weight: u128,
+    pub weight_slot_sum: u128,
+    pub sampled_slots: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 64],
 }
@@ -0,0 +1,89 @@
+//! Layout versions of the tip router accounts.
+//!
+//! The byte after the discriminator in the 8 byte account header holds the version of the layout
+//! the account data is written in, accounts written before versions were tracked read as
+//! version 0. Loaders only accept accounts on the version of the running program and
+//! `MigrateAccount` moves an account up one version at a time.
+
+use std::mem::size_of;
+
+use bytemuck::Pod;
+use jito_bytemuck::Discriminator;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::TipRouterError;
+
+/// Index of the layout version in the account header
+pub const ACCOUNT_VERSION_INDEX: usize = 1;
+
+/// An account whose layout version is tracked in its header
+pub trait VersionedAccount: Discriminator + Pod {
+    /// The layout version this program reads and writes
+    const ACCOUNT_VERSION: u8;
+
+    /// Offset of the NCN pubkey in the account data, the same in every version
+    const NCN_OFFSET: usize;
+
+    /// Size of the account data, header included, in the layout of `version`. By default every
+    /// version has the size of the current layout.
+    fn version_size(version: u8) -> Result<usize, TipRouterError> {
+        if version > Self::ACCOUNT_VERSION {
+            return Err(TipRouterError::InvalidAccountVersion);
+        }
+
+        Ok(8 + size_of::<Self>())
+    }
+
+    /// Rewrites `data`, header included, from the layout of `version` to the layout of
+    /// `version + 1`. `data` is already resized to the size of the next version. By default
+    /// nothing moves, which covers new fields taken from zeroed reserved space.
+    fn migrate_from(version: u8, data: &mut [u8]) -> Result<(), TipRouterError> {
+        if version >= Self::ACCOUNT_VERSION || data.len() < Self::version_size(version + 1)? {
+            return Err(TipRouterError::InvalidAccountVersion);
+        }
+
+        Ok(())
+    }
+
+    /// The NCN the account belongs to
+    fn ncn_from_data(data: &[u8]) -> Result<Pubkey, TipRouterError> {
+        data.get(Self::NCN_OFFSET..Self::NCN_OFFSET + 32)
+            .and_then(|ncn| Pubkey::try_from(ncn).ok())
+            .ok_or(TipRouterError::InvalidAccountVersion)
+    }
+}
+
+/// The layout version in the account header, 0 when the data is too short to hold one
+pub fn account_version(data: &[u8]) -> u8 {
+    data.get(ACCOUNT_VERSION_INDEX).copied().unwrap_or_default()
+}
+
+/// Rejects an account that is not on the layout version of the running program
+pub fn check_account_version<T: VersionedAccount>(
+    account: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let version = account_version(&account.try_borrow_data()?);
+
+    if version != T::ACCOUNT_VERSION {
+        msg!(
+            "Account is on layout version {} but version {} is expected, upgrade it with MigrateAccount",
+            version,
+            T::ACCOUNT_VERSION
+        );
+        return Err(TipRouterError::InvalidAccountVersion.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_version() {
+        assert_eq!(account_version(&[]), 0);
+        assert_eq!(account_version(&[0x01]), 0);
+        assert_eq!(account_version(&[0x01, 0x02, 0, 0, 0, 0, 0, 0]), 2);
+    }
+}
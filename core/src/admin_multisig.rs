@@ -0,0 +1,775 @@
+use core::fmt;
+use std::mem::size_of;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{
+    types::{PodBool, PodU16, PodU64},
+    AccountDeserialize, Discriminator,
+};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    config::ConfigAdminRole,
+    constants::{MAX_ADMIN_ACTION_LEN, MAX_ADMIN_MULTISIG_SIGNERS},
+    discriminators::Discriminators,
+    error::TipRouterError,
+    loaders::check_load,
+};
+
+/// An admin action that a role's multisig can propose, approve and execute
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum AdminAction {
+    /// Same as `AdminSetTieBreaker`, requires the tie breaker admin role
+    SetTieBreaker {
+        meta_merkle_root: [u8; 32],
+        epoch: u64,
+    },
+    /// Same as `AdminSetConfigFees`, requires the fee admin role
+    SetConfigFees {
+        new_block_engine_fee_bps: Option<u16>,
+        base_fee_group: Option<u8>,
+        new_base_fee_wallet: Option<Pubkey>,
+        new_base_fee_bps: Option<u16>,
+        ncn_fee_group: Option<u8>,
+        new_ncn_fee_bps: Option<u16>,
+        new_priority_fee_distribution_fee_bps: Option<u16>,
+        activation_epoch: Option<u64>,
+    },
+    /// Same as `AdminSetNewAdmin`, hands the role over to `new_admin`
+    SetNewAdmin {
+        role: ConfigAdminRole,
+        new_admin: Pubkey,
+    },
+    /// Same as `AdminSetBaseFeeGroup`, requires the fee admin role
+    SetBaseFeeGroup {
+        base_fee_group: u8,
+        name: Option<[u8; 32]>,
+        enabled: Option<bool>,
+        payout_asset: Option<u8>,
+    },
+    /// Adds or removes one signer of the role's multisig and sets its threshold. Signers of an
+    /// active multisig can only change through this action.
+    SetMultisigSigner {
+        role: ConfigAdminRole,
+        signer: Pubkey,
+        is_signer: bool,
+        threshold: u8,
+    },
+    /// Same as `AdminSetPausedSubsystems`, requires the pause admin role so it can only pause
+    /// more subsystems
+    SetPausedSubsystems { paused_subsystems: u8 },
+}
+
+impl AdminAction {
+    /// The admin role whose multisig has to approve this action
+    pub const fn role(&self) -> ConfigAdminRole {
+        match self {
+            Self::SetTieBreaker { .. } => ConfigAdminRole::TieBreakerAdmin,
+            Self::SetConfigFees { .. } => ConfigAdminRole::FeeAdmin,
+            Self::SetNewAdmin { role, .. } => *role,
+            Self::SetBaseFeeGroup { .. } => ConfigAdminRole::FeeAdmin,
+            Self::SetMultisigSigner { role, .. } => *role,
+            Self::SetPausedSubsystems { .. } => ConfigAdminRole::PauseAdmin,
+        }
+    }
+}
+
+/// A set of signers that together act as the admin for one `ConfigAdminRole`.
+/// The multisig is active while the role's admin in the `Config` is this account's address.
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct AdminMultisig {
+    /// The NCN
+    ncn: Pubkey,
+    /// The `ConfigAdminRole` this multisig acts for
+    role: u8,
+    /// Number of approvals needed to execute a proposal
+    threshold: u8,
+    /// Number of signers in use
+    signer_count: u8,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Incremented every time the signers change, invalidating open proposals
+    version: PodU64,
+    /// Number of proposals created, used as the nonce of the next proposal
+    proposal_count: PodU64,
+    /// The signers
+    signers: [Pubkey; 10],
+    /// Reserved space
+    reserved: [u8; 128],
+}
+
+impl Discriminator for AdminMultisig {
+    const DISCRIMINATOR: u8 = Discriminators::AdminMultisig as u8;
+}
+
+impl AdminMultisig {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(ncn: &Pubkey, role: ConfigAdminRole, bump: u8) -> Self {
+        Self {
+            ncn: *ncn,
+            role: role as u8,
+            threshold: 0,
+            signer_count: 0,
+            bump,
+            version: PodU64::from(0),
+            proposal_count: PodU64::from(0),
+            signers: [Pubkey::default(); MAX_ADMIN_MULTISIG_SIGNERS],
+            reserved: [0; 128],
+        }
+    }
+
+    pub fn seeds(ncn: &Pubkey, role: ConfigAdminRole) -> Vec<Vec<u8>> {
+        vec![
+            b"admin_multisig".to_vec(),
+            ncn.to_bytes().to_vec(),
+            vec![role as u8],
+        ]
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        role: ConfigAdminRole,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, role);
+        let (address, bump) = Pubkey::find_program_address(
+            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
+            program_id,
+        );
+        (address, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        role: ConfigAdminRole,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ncn, role).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub const fn role(&self) -> u8 {
+        self.role
+    }
+
+    pub const fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version.into()
+    }
+
+    pub fn proposal_count(&self) -> u64 {
+        self.proposal_count.into()
+    }
+
+    pub fn signers(&self) -> &[Pubkey] {
+        &self.signers[..self.signer_count as usize]
+    }
+
+    pub fn signer_index(&self, signer: &Pubkey) -> Result<usize, TipRouterError> {
+        self.signers()
+            .iter()
+            .position(|s| s.eq(signer))
+            .ok_or(TipRouterError::AdminMultisigSignerNotFound)
+    }
+
+    /// Replaces the signers and threshold, invalidating any open proposals
+    pub fn set_signers(&mut self, signers: &[Pubkey], threshold: u8) -> Result<(), TipRouterError> {
+        if signers.is_empty()
+            || signers.len() > MAX_ADMIN_MULTISIG_SIGNERS
+            || threshold == 0
+            || threshold as usize > signers.len()
+        {
+            msg!(
+                "Invalid multisig: {} signers with a threshold of {}",
+                signers.len(),
+                threshold
+            );
+            return Err(TipRouterError::InvalidAdminMultisig);
+        }
+
+        for (index, signer) in signers.iter().enumerate() {
+            if *signer == Pubkey::default() || signers[..index].contains(signer) {
+                msg!("Invalid or duplicate multisig signer {}", signer);
+                return Err(TipRouterError::InvalidAdminMultisig);
+            }
+        }
+
+        self.signers = [Pubkey::default(); MAX_ADMIN_MULTISIG_SIGNERS];
+        self.signers[..signers.len()].copy_from_slice(signers);
+        self.signer_count = signers.len() as u8;
+        self.threshold = threshold;
+        self.version = PodU64::from(
+            self.version()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Whether the multisig already has exactly these signers and threshold
+    pub fn has_signers(&self, signers: &[Pubkey], threshold: u8) -> bool {
+        self.threshold == threshold && self.signers() == signers
+    }
+
+    /// Adds or removes `signer` and sets the threshold, invalidating any open proposals
+    pub fn update_signer(
+        &mut self,
+        signer: &Pubkey,
+        is_signer: bool,
+        threshold: u8,
+    ) -> Result<(), TipRouterError> {
+        let mut signers: Vec<Pubkey> = self
+            .signers()
+            .iter()
+            .filter(|s| s.ne(&signer))
+            .copied()
+            .collect();
+        if is_signer {
+            signers.push(*signer);
+        }
+
+        self.set_signers(&signers, threshold)
+    }
+
+    /// Returns the nonce for a new proposal
+    pub fn next_proposal_nonce(&mut self) -> Result<u64, TipRouterError> {
+        let nonce = self.proposal_count();
+        self.proposal_count = PodU64::from(
+            nonce
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        Ok(nonce)
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for AdminMultisig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Admin Multisig -------------")?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Role:                         {}", self.role)?;
+        writeln!(f, "  Threshold:                    {}", self.threshold)?;
+        writeln!(f, "  Version:                      {}", self.version())?;
+        writeln!(f, "  Proposal Count:               {}", self.proposal_count())?;
+        writeln!(f, "  Signers:")?;
+        for signer in self.signers() {
+            writeln!(f, "    {}", signer)?;
+        }
+
+        writeln!(f, "\n")?;
+        Ok(())
+    }
+}
+
+/// An admin action waiting for approvals from the signers of a role's multisig
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct AdminProposal {
+    /// The NCN
+    ncn: Pubkey,
+    /// The `ConfigAdminRole` of the multisig
+    role: u8,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Whether the action has been executed
+    executed: PodBool,
+    /// Bitmap of the multisig signer indices that approved
+    approvals: PodU16,
+    /// The proposal nonce
+    nonce: PodU64,
+    /// The multisig version the approvals refer to
+    multisig_version: PodU64,
+    /// The signer who created the proposal
+    proposer: Pubkey,
+    /// Slot the proposal was created
+    slot_proposed: PodU64,
+    /// Slot the proposal was executed
+    slot_executed: PodU64,
+    /// Length of the serialized action
+    action_len: PodU16,
+    /// The borsh serialized `AdminAction`
+    action: [u8; 64],
+    /// Reserved space
+    reserved: [u8; 64],
+}
+
+impl Discriminator for AdminProposal {
+    const DISCRIMINATOR: u8 = Discriminators::AdminProposal as u8;
+}
+
+impl AdminProposal {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        ncn: &Pubkey,
+        role: ConfigAdminRole,
+        nonce: u64,
+        multisig_version: u64,
+        proposer: &Pubkey,
+        action: &AdminAction,
+        slot_proposed: u64,
+        bump: u8,
+    ) -> Result<Self, TipRouterError> {
+        let action_data = borsh::to_vec(action).map_err(|_| TipRouterError::InvalidAdminAction)?;
+        if action_data.len() > MAX_ADMIN_ACTION_LEN {
+            return Err(TipRouterError::AdminActionTooLarge);
+        }
+
+        let mut action_bytes = [0; MAX_ADMIN_ACTION_LEN];
+        action_bytes[..action_data.len()].copy_from_slice(&action_data);
+
+        Ok(Self {
+            ncn: *ncn,
+            role: role as u8,
+            bump,
+            executed: PodBool::from(false),
+            approvals: PodU16::from(0),
+            nonce: PodU64::from(nonce),
+            multisig_version: PodU64::from(multisig_version),
+            proposer: *proposer,
+            slot_proposed: PodU64::from(slot_proposed),
+            slot_executed: PodU64::from(0),
+            action_len: PodU16::from(action_data.len() as u16),
+            action: action_bytes,
+            reserved: [0; 64],
+        })
+    }
+
+    pub fn seeds(ncn: &Pubkey, role: ConfigAdminRole, nonce: u64) -> Vec<Vec<u8>> {
+        vec![
+            b"admin_proposal".to_vec(),
+            ncn.to_bytes().to_vec(),
+            vec![role as u8],
+            nonce.to_le_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        role: ConfigAdminRole,
+        nonce: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, role, nonce);
+        let (address, bump) = Pubkey::find_program_address(
+            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
+            program_id,
+        );
+        (address, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        role: ConfigAdminRole,
+        nonce: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ncn, role, nonce).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn nonce(&self) -> u64 {
+        self.nonce.into()
+    }
+
+    pub fn multisig_version(&self) -> u64 {
+        self.multisig_version.into()
+    }
+
+    pub const fn proposer(&self) -> &Pubkey {
+        &self.proposer
+    }
+
+    pub fn slot_proposed(&self) -> u64 {
+        self.slot_proposed.into()
+    }
+
+    pub fn slot_executed(&self) -> u64 {
+        self.slot_executed.into()
+    }
+
+    pub fn executed(&self) -> bool {
+        self.executed.into()
+    }
+
+    pub fn approval_count(&self) -> u32 {
+        u16::from(self.approvals).count_ones()
+    }
+
+    pub fn is_approved_by(&self, signer_index: usize) -> bool {
+        u16::from(self.approvals) & (1 << signer_index) != 0
+    }
+
+    pub fn action(&self) -> Result<AdminAction, TipRouterError> {
+        let action_len = u16::from(self.action_len) as usize;
+        AdminAction::try_from_slice(&self.action[..action_len])
+            .map_err(|_| TipRouterError::InvalidAdminAction)
+    }
+
+    fn check_open(&self, multisig: &AdminMultisig) -> Result<(), TipRouterError> {
+        if self.executed() {
+            return Err(TipRouterError::AdminProposalAlreadyExecuted);
+        }
+
+        if self.multisig_version() != multisig.version() {
+            return Err(TipRouterError::AdminProposalStale);
+        }
+
+        Ok(())
+    }
+
+    /// Whether the proposal can no longer be executed, either because it already was or because
+    /// the multisig's signers changed since it was proposed
+    pub fn is_closed(&self, multisig: &AdminMultisig) -> bool {
+        self.check_open(multisig).is_err()
+    }
+
+    pub fn approve(
+        &mut self,
+        multisig: &AdminMultisig,
+        signer: &Pubkey,
+    ) -> Result<(), TipRouterError> {
+        self.check_open(multisig)?;
+
+        let signer_index = multisig.signer_index(signer)?;
+        if self.is_approved_by(signer_index) {
+            return Err(TipRouterError::AdminProposalAlreadyApproved);
+        }
+
+        self.approvals = PodU16::from(u16::from(self.approvals) | (1 << signer_index));
+
+        Ok(())
+    }
+
+    /// Marks the proposal as executed, returning the action to run
+    pub fn execute(
+        &mut self,
+        multisig: &AdminMultisig,
+        current_slot: u64,
+    ) -> Result<AdminAction, TipRouterError> {
+        self.check_open(multisig)?;
+
+        if self.approval_count() < multisig.threshold() as u32 {
+            msg!(
+                "Proposal has {} of {} approvals",
+                self.approval_count(),
+                multisig.threshold()
+            );
+            return Err(TipRouterError::AdminProposalThresholdNotMet);
+        }
+
+        self.executed = PodBool::from(true);
+        self.slot_executed = PodU64::from(current_slot);
+
+        self.action()
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for AdminProposal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Admin Proposal -------------")?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Role:                         {}", self.role)?;
+        writeln!(f, "  Nonce:                        {}", self.nonce())?;
+        writeln!(f, "  Multisig Version:             {}", self.multisig_version())?;
+        writeln!(f, "  Proposer:                     {}", self.proposer)?;
+        writeln!(f, "  Approvals:                    {}", self.approval_count())?;
+        writeln!(f, "  Executed:                     {}", self.executed())?;
+        writeln!(f, "  Slot Proposed:                {}", self.slot_proposed())?;
+        writeln!(f, "  Slot Executed:                {}", self.slot_executed())?;
+        writeln!(f, "  Action:                       {:?}", self.action())?;
+
+        writeln!(f, "\n")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        use std::mem::size_of;
+
+        let expected_total = size_of::<Pubkey>() // ncn
+            + 1 // role
+            + 1 // threshold
+            + 1 // signer_count
+            + 1 // bump
+            + size_of::<PodU64>() // version
+            + size_of::<PodU64>() // proposal_count
+            + size_of::<Pubkey>() * MAX_ADMIN_MULTISIG_SIGNERS // signers
+            + 128; // reserved
+
+        assert_eq!(size_of::<AdminMultisig>(), expected_total);
+
+        let expected_total = size_of::<Pubkey>() // ncn
+            + 1 // role
+            + 1 // bump
+            + size_of::<PodBool>() // executed
+            + size_of::<PodU16>() // approvals
+            + size_of::<PodU64>() // nonce
+            + size_of::<PodU64>() // multisig_version
+            + size_of::<Pubkey>() // proposer
+            + size_of::<PodU64>() // slot_proposed
+            + size_of::<PodU64>() // slot_executed
+            + size_of::<PodU16>() // action_len
+            + MAX_ADMIN_ACTION_LEN // action
+            + 64; // reserved
+
+        assert_eq!(size_of::<AdminProposal>(), expected_total);
+    }
+
+    #[test]
+    fn test_set_signers() {
+        let ncn = Pubkey::new_unique();
+        let mut multisig = AdminMultisig::new(&ncn, ConfigAdminRole::FeeAdmin, 0);
+        let signers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+        assert_eq!(
+            multisig.set_signers(&[], 1),
+            Err(TipRouterError::InvalidAdminMultisig)
+        );
+        assert_eq!(
+            multisig.set_signers(&signers, 0),
+            Err(TipRouterError::InvalidAdminMultisig)
+        );
+        assert_eq!(
+            multisig.set_signers(&signers, 4),
+            Err(TipRouterError::InvalidAdminMultisig)
+        );
+        assert_eq!(
+            multisig.set_signers(&[signers[0], signers[0]], 1),
+            Err(TipRouterError::InvalidAdminMultisig)
+        );
+        assert_eq!(
+            multisig.set_signers(&[Pubkey::default()], 1),
+            Err(TipRouterError::InvalidAdminMultisig)
+        );
+        let too_many: Vec<Pubkey> = (0..MAX_ADMIN_MULTISIG_SIGNERS + 1)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        assert_eq!(
+            multisig.set_signers(&too_many, 1),
+            Err(TipRouterError::InvalidAdminMultisig)
+        );
+
+        multisig.set_signers(&signers, 2).unwrap();
+        assert_eq!(multisig.signers(), signers.as_slice());
+        assert_eq!(multisig.threshold(), 2);
+        assert_eq!(multisig.version(), 1);
+        assert_eq!(multisig.signer_index(&signers[2]), Ok(2));
+        assert_eq!(
+            multisig.signer_index(&Pubkey::new_unique()),
+            Err(TipRouterError::AdminMultisigSignerNotFound)
+        );
+
+        // Shrinking the set clears the old signers
+        multisig.set_signers(&signers[..1], 1).unwrap();
+        assert_eq!(multisig.signers(), &signers[..1]);
+        assert_eq!(multisig.version(), 2);
+        assert_eq!(
+            multisig.signer_index(&signers[2]),
+            Err(TipRouterError::AdminMultisigSignerNotFound)
+        );
+    }
+
+    #[test]
+    fn test_update_signer() {
+        let ncn = Pubkey::new_unique();
+        let mut multisig = AdminMultisig::new(&ncn, ConfigAdminRole::FeeAdmin, 0);
+        let signers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        multisig.set_signers(&signers[..2], 2).unwrap();
+        assert!(multisig.has_signers(&signers[..2], 2));
+        assert!(!multisig.has_signers(&signers[..2], 1));
+
+        multisig.update_signer(&signers[2], true, 3).unwrap();
+        assert_eq!(multisig.signers(), signers.as_slice());
+        assert_eq!(multisig.threshold(), 3);
+        assert_eq!(multisig.version(), 2);
+
+        // Adding an existing signer keeps a single entry
+        multisig.update_signer(&signers[0], true, 2).unwrap();
+        assert_eq!(multisig.signers(), &[signers[1], signers[2], signers[0]]);
+
+        multisig.update_signer(&signers[1], false, 2).unwrap();
+        assert_eq!(multisig.signers(), &[signers[2], signers[0]]);
+
+        // The threshold can't exceed the remaining signers
+        assert_eq!(
+            multisig.update_signer(&signers[2], false, 2),
+            Err(TipRouterError::InvalidAdminMultisig)
+        );
+    }
+
+    #[test]
+    fn test_proposal_approve_and_execute() {
+        let ncn = Pubkey::new_unique();
+        let mut multisig = AdminMultisig::new(&ncn, ConfigAdminRole::TieBreakerAdmin, 0);
+        let signers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        multisig.set_signers(&signers, 2).unwrap();
+
+        let action = AdminAction::SetTieBreaker {
+            meta_merkle_root: [1; 32],
+            epoch: 10,
+        };
+        assert_eq!(action.role(), ConfigAdminRole::TieBreakerAdmin);
+
+        let nonce = multisig.next_proposal_nonce().unwrap();
+        assert_eq!(nonce, 0);
+        assert_eq!(multisig.proposal_count(), 1);
+
+        let mut proposal = AdminProposal::new(
+            &ncn,
+            ConfigAdminRole::TieBreakerAdmin,
+            nonce,
+            multisig.version(),
+            &signers[0],
+            &action,
+            100,
+            0,
+        )
+        .unwrap();
+        assert_eq!(proposal.action(), Ok(action.clone()));
+
+        proposal.approve(&multisig, &signers[0]).unwrap();
+        assert_eq!(
+            proposal.approve(&multisig, &signers[0]),
+            Err(TipRouterError::AdminProposalAlreadyApproved)
+        );
+        assert_eq!(
+            proposal.approve(&multisig, &Pubkey::new_unique()),
+            Err(TipRouterError::AdminMultisigSignerNotFound)
+        );
+
+        assert!(!proposal.is_closed(&multisig));
+
+        // 1 of 2 approvals
+        assert_eq!(
+            proposal.execute(&multisig, 200),
+            Err(TipRouterError::AdminProposalThresholdNotMet)
+        );
+
+        proposal.approve(&multisig, &signers[2]).unwrap();
+        assert_eq!(proposal.approval_count(), 2);
+        assert!(proposal.is_approved_by(2));
+        assert!(!proposal.is_approved_by(1));
+
+        assert_eq!(proposal.execute(&multisig, 200), Ok(action));
+        assert!(proposal.executed());
+        assert_eq!(proposal.slot_executed(), 200);
+        assert!(proposal.is_closed(&multisig));
+
+        assert_eq!(
+            proposal.execute(&multisig, 201),
+            Err(TipRouterError::AdminProposalAlreadyExecuted)
+        );
+        assert_eq!(
+            proposal.approve(&multisig, &signers[1]),
+            Err(TipRouterError::AdminProposalAlreadyExecuted)
+        );
+    }
+
+    #[test]
+    fn test_proposal_stale_after_signers_change() {
+        let ncn = Pubkey::new_unique();
+        let mut multisig = AdminMultisig::new(&ncn, ConfigAdminRole::FeeAdmin, 0);
+        let signers: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        multisig.set_signers(&signers, 1).unwrap();
+
+        let action = AdminAction::SetNewAdmin {
+            role: ConfigAdminRole::FeeAdmin,
+            new_admin: Pubkey::new_unique(),
+        };
+        let mut proposal = AdminProposal::new(
+            &ncn,
+            ConfigAdminRole::FeeAdmin,
+            multisig.next_proposal_nonce().unwrap(),
+            multisig.version(),
+            &signers[0],
+            &action,
+            100,
+            0,
+        )
+        .unwrap();
+        proposal.approve(&multisig, &signers[0]).unwrap();
+
+        multisig.set_signers(&signers, 2).unwrap();
+
+        assert_eq!(
+            proposal.approve(&multisig, &signers[1]),
+            Err(TipRouterError::AdminProposalStale)
+        );
+        assert_eq!(
+            proposal.execute(&multisig, 200),
+            Err(TipRouterError::AdminProposalStale)
+        );
+        assert!(proposal.is_closed(&multisig));
+    }
+
+    #[test]
+    fn test_largest_action_fits() {
+        let action = AdminAction::SetConfigFees {
+            new_block_engine_fee_bps: Some(u16::MAX),
+            base_fee_group: Some(u8::MAX),
+            new_base_fee_wallet: Some(Pubkey::new_unique()),
+            new_base_fee_bps: Some(u16::MAX),
+            ncn_fee_group: Some(u8::MAX),
+            new_ncn_fee_bps: Some(u16::MAX),
+            new_priority_fee_distribution_fee_bps: Some(u16::MAX),
+            activation_epoch: Some(u64::MAX),
+        };
+        assert!(borsh::to_vec(&action).unwrap().len() <= MAX_ADMIN_ACTION_LEN);
+        assert_eq!(action.role(), ConfigAdminRole::FeeAdmin);
+
+        let action = AdminAction::SetMultisigSigner {
+            role: ConfigAdminRole::PauseAdmin,
+            signer: Pubkey::new_unique(),
+            is_signer: true,
+            threshold: u8::MAX,
+        };
+        assert!(borsh::to_vec(&action).unwrap().len() <= MAX_ADMIN_ACTION_LEN);
+        assert_eq!(action.role(), ConfigAdminRole::PauseAdmin);
+
+        let action = AdminAction::SetPausedSubsystems {
+            paused_subsystems: u8::MAX,
+        };
+        assert_eq!(action.role(), ConfigAdminRole::PauseAdmin);
+    }
+}
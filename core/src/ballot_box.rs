@@ -1,5 +1,5 @@
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
@@ -14,7 +14,11 @@ use solana_program::{
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
-    constants::{precise_consensus, DEFAULT_CONSENSUS_REACHED_SLOT, MAX_OPERATORS},
+    account_version::{check_account_version, VersionedAccount},
+    ballot_box_page::BallotBoxPage,
+    constants::{
+        precise_consensus, DEFAULT_CONSENSUS_REACHED_SLOT, MAX_OPERATORS, MAX_QUORUM_OPERATOR_BPS,
+    },
     discriminators::Discriminators,
     error::TipRouterError,
     loaders::check_load,
@@ -178,8 +182,12 @@ pub struct OperatorVote {
     stake_weights: StakeWeights,
     /// The index of the ballot in the ballot_tallies
     ballot_index: PodU16,
+    /// Hash of (meta_merkle_root, salt, operator) when voting with commit-reveal
+    commitment: [u8; 32],
+    /// The slot the operator committed
+    slot_committed: PodU64,
     /// Reserved space
-    reserved: [u8; 64],
+    reserved: [u8; 24],
 }
 
 impl Default for OperatorVote {
@@ -189,7 +197,9 @@ impl Default for OperatorVote {
             slot_voted: PodU64::from(0),
             stake_weights: StakeWeights::default(),
             ballot_index: PodU16::from(u16::MAX),
-            reserved: [0; 64],
+            commitment: [0; 32],
+            slot_committed: PodU64::from(0),
+            reserved: [0; 24],
         }
     }
 }
@@ -206,10 +216,30 @@ impl OperatorVote {
             ballot_index: PodU16::from(ballot_index as u16),
             slot_voted: PodU64::from(current_slot),
             stake_weights: *stake_weights,
-            reserved: [0; 64],
+            commitment: [0; 32],
+            slot_committed: PodU64::from(0),
+            reserved: [0; 24],
         }
     }
 
+    pub fn new_commitment(operator: &Pubkey, commitment: &[u8; 32], current_slot: u64) -> Self {
+        Self {
+            operator: *operator,
+            commitment: *commitment,
+            slot_committed: PodU64::from(current_slot),
+            ..Self::default()
+        }
+    }
+
+    /// Hash an operator commits to before revealing their vote
+    pub fn commitment_hash(
+        meta_merkle_root: &[u8; 32],
+        salt: &[u8; 32],
+        operator: &Pubkey,
+    ) -> [u8; 32] {
+        hashv(&[meta_merkle_root, salt, operator.as_ref()]).to_bytes()
+    }
+
     pub const fn operator(&self) -> &Pubkey {
         &self.operator
     }
@@ -226,8 +256,24 @@ impl OperatorVote {
         self.ballot_index.into()
     }
 
+    pub const fn commitment(&self) -> &[u8; 32] {
+        &self.commitment
+    }
+
+    pub fn slot_committed(&self) -> u64 {
+        self.slot_committed.into()
+    }
+
+    pub fn has_commitment(&self) -> bool {
+        self.commitment.iter().any(|byte| *byte != 0)
+    }
+
+    pub fn is_revealed(&self) -> bool {
+        self.ballot_index() != u16::MAX
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.ballot_index() == u16::MAX
+        !self.is_revealed() && !self.has_commitment()
     }
 }
 
@@ -245,13 +291,31 @@ pub struct BallotBox {
     slot_created: PodU64,
     /// Slot when consensus was reached
     slot_consensus_reached: PodU64,
+    /// Commitments are accepted before this slot, 0 when commit-reveal voting is disabled
+    commit_phase_end_slot: PodU64,
+    /// Reveals are accepted from the end of the commit phase until this slot
+    reveal_phase_end_slot: PodU64,
+    /// Number of operators that have committed to a vote
+    operators_committed: PodU64,
+    /// Consensus threshold in bps copied from the epoch state, 0 uses the default of 2/3
+    consensus_threshold_bps: PodU16,
+    /// Minimum number of operators that must vote before consensus is reached, 0 disables
+    quorum_operator_count: PodU16,
+    /// Minimum share in bps of the active operators that must vote before consensus is reached, 0 disables
+    quorum_operator_bps: PodU16,
+    /// Whether the winning ballot was finalized through the permissionless stall fallback
+    stall_fallback_used: PodBool,
+    /// Number of ballot box pages created for operators past `MAX_OPERATORS`
+    page_count: PodU64,
+    /// Stall fallback threshold in bps copied from the config, 0 leaves stalls to the tie breaker admin
+    stall_fallback_threshold_bps: PodU16,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 87],
     /// Number of operators that have voted
     operators_voted: PodU64,
     /// Number of unique ballots
     unique_ballots: PodU64,
-    /// The ballot that got at least 66% of votes
+    /// The ballot that reached the consensus threshold
     winning_ballot: Ballot,
     /// Operator votes
     operator_votes: [OperatorVote; 256],
@@ -263,6 +327,11 @@ impl Discriminator for BallotBox {
     const DISCRIMINATOR: u8 = Discriminators::BallotBox as u8;
 }
 
+impl VersionedAccount for BallotBox {
+    const ACCOUNT_VERSION: u8 = 1;
+    const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+}
+
 impl BallotBox {
     pub const SIZE: usize = 8 + size_of::<Self>();
 
@@ -273,12 +342,21 @@ impl BallotBox {
             bump,
             slot_created: PodU64::from(current_slot),
             slot_consensus_reached: PodU64::from(DEFAULT_CONSENSUS_REACHED_SLOT),
+            commit_phase_end_slot: PodU64::from(0),
+            reveal_phase_end_slot: PodU64::from(0),
+            operators_committed: PodU64::from(0),
+            consensus_threshold_bps: PodU16::from(0),
+            quorum_operator_count: PodU16::from(0),
+            quorum_operator_bps: PodU16::from(0),
+            stall_fallback_used: PodBool::from(false),
             operators_voted: PodU64::from(0),
             unique_ballots: PodU64::from(0),
             winning_ballot: Ballot::default(),
             operator_votes: [OperatorVote::default(); MAX_OPERATORS],
             ballot_tallies: [BallotTally::default(); MAX_OPERATORS],
-            reserved: [0; 128],
+            page_count: PodU64::from(0),
+            stall_fallback_threshold_bps: PodU16::from(0),
+            reserved: [0; 87],
         }
     }
 
@@ -289,12 +367,46 @@ impl BallotBox {
         self.bump = bump;
         self.slot_created = PodU64::from(current_slot);
         self.slot_consensus_reached = PodU64::from(DEFAULT_CONSENSUS_REACHED_SLOT);
+        self.commit_phase_end_slot = PodU64::from(0);
+        self.reveal_phase_end_slot = PodU64::from(0);
+        self.operators_committed = PodU64::from(0);
+        self.consensus_threshold_bps = PodU16::from(0);
+        self.quorum_operator_count = PodU16::from(0);
+        self.quorum_operator_bps = PodU16::from(0);
+        self.stall_fallback_used = PodBool::from(false);
         self.operators_voted = PodU64::from(0);
         self.unique_ballots = PodU64::from(0);
         self.winning_ballot = Ballot::default();
         self.operator_votes = [OperatorVote::default(); MAX_OPERATORS];
         self.ballot_tallies = [BallotTally::default(); MAX_OPERATORS];
-        self.reserved = [0; 128];
+        self.page_count = PodU64::from(0);
+        self.stall_fallback_threshold_bps = PodU16::from(0);
+        self.reserved = [0; 87];
+    }
+
+    /// Enables commit-reveal voting, starting the commit phase at the slot the ballot box was created.
+    /// A `commit_window_slots` of 0 leaves the ballot box in direct voting mode.
+    pub fn set_commit_reveal_windows(
+        &mut self,
+        commit_window_slots: u64,
+        reveal_window_slots: u64,
+    ) -> Result<(), TipRouterError> {
+        if commit_window_slots == 0 {
+            return Ok(());
+        }
+
+        let commit_phase_end_slot = self
+            .slot_created()
+            .checked_add(commit_window_slots)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+        let reveal_phase_end_slot = commit_phase_end_slot
+            .checked_add(reveal_window_slots)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        self.commit_phase_end_slot = PodU64::from(commit_phase_end_slot);
+        self.reveal_phase_end_slot = PodU64::from(reveal_phase_end_slot);
+
+        Ok(())
     }
 
     pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
@@ -334,7 +446,8 @@ impl BallotBox {
             &expected_pda,
             Some(Self::DISCRIMINATOR),
             expect_writable,
-        )
+        )?;
+        check_account_version::<Self>(account)
     }
 
     pub fn load_to_close(
@@ -346,14 +459,96 @@ impl BallotBox {
         Self::load(program_id, account_to_close, ncn, epoch, true)
     }
 
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
     pub fn epoch(&self) -> u64 {
         self.epoch.into()
     }
 
+    pub fn slot_created(&self) -> u64 {
+        self.slot_created.into()
+    }
+
     pub fn slot_consensus_reached(&self) -> u64 {
         self.slot_consensus_reached.into()
     }
 
+    pub fn commit_phase_end_slot(&self) -> u64 {
+        self.commit_phase_end_slot.into()
+    }
+
+    pub fn reveal_phase_end_slot(&self) -> u64 {
+        self.reveal_phase_end_slot.into()
+    }
+
+    pub fn operators_committed(&self) -> u64 {
+        self.operators_committed.into()
+    }
+
+    pub fn consensus_threshold_bps(&self) -> u16 {
+        self.consensus_threshold_bps.into()
+    }
+
+    pub fn set_consensus_threshold_bps(&mut self, consensus_threshold_bps: u16) {
+        self.consensus_threshold_bps = PodU16::from(consensus_threshold_bps);
+    }
+
+    pub fn quorum_operator_count(&self) -> u16 {
+        self.quorum_operator_count.into()
+    }
+
+    pub fn quorum_operator_bps(&self) -> u16 {
+        self.quorum_operator_bps.into()
+    }
+
+    pub fn set_quorum(&mut self, quorum_operator_count: u16, quorum_operator_bps: u16) {
+        self.quorum_operator_count = PodU16::from(quorum_operator_count);
+        self.quorum_operator_bps = PodU16::from(quorum_operator_bps);
+    }
+
+    pub fn has_quorum(&self) -> bool {
+        self.quorum_operator_count() > 0 || self.quorum_operator_bps() > 0
+    }
+
+    /// The number of operators that must vote before consensus can be reached. Capped at the
+    /// number of active operators so a quorum larger than the operator set can't block consensus.
+    pub fn quorum_operators_required(
+        &self,
+        active_operator_count: u64,
+    ) -> Result<u64, TipRouterError> {
+        let operators_from_share = (active_operator_count as u128)
+            .checked_mul(self.quorum_operator_bps() as u128)
+            .and_then(|operators| operators.checked_add(MAX_QUORUM_OPERATOR_BPS as u128 - 1))
+            .and_then(|operators| operators.checked_div(MAX_QUORUM_OPERATOR_BPS as u128))
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+        let operators_from_share =
+            u64::try_from(operators_from_share).map_err(|_| TipRouterError::ArithmeticOverflow)?;
+
+        Ok(operators_from_share
+            .max(self.quorum_operator_count() as u64)
+            .min(active_operator_count))
+    }
+
+    pub fn is_quorum_reached(&self, active_operator_count: u64) -> Result<bool, TipRouterError> {
+        Ok(self.operators_voted() >= self.quorum_operators_required(active_operator_count)?)
+    }
+
+    pub fn is_commit_reveal(&self) -> bool {
+        self.commit_phase_end_slot() != 0
+    }
+
+    pub fn is_commit_phase(&self, current_slot: u64) -> bool {
+        self.is_commit_reveal() && current_slot < self.commit_phase_end_slot()
+    }
+
+    pub fn is_reveal_phase(&self, current_slot: u64) -> bool {
+        self.is_commit_reveal()
+            && current_slot >= self.commit_phase_end_slot()
+            && current_slot < self.reveal_phase_end_slot()
+    }
+
     pub fn unique_ballots(&self) -> u64 {
         self.unique_ballots.into()
     }
@@ -380,6 +575,32 @@ impl BallotBox {
             && self.winning_ballot.is_valid()
     }
 
+    pub fn stall_fallback_used(&self) -> bool {
+        self.stall_fallback_used.into()
+    }
+
+    pub fn stall_fallback_threshold_bps(&self) -> u16 {
+        self.stall_fallback_threshold_bps.into()
+    }
+
+    pub fn set_stall_fallback_threshold_bps(&mut self, stall_fallback_threshold_bps: u16) {
+        self.stall_fallback_threshold_bps = PodU16::from(stall_fallback_threshold_bps);
+    }
+
+    /// Voting is stalled once `epochs_before_stall` epochs have passed without consensus
+    pub fn is_voting_stalled(
+        &self,
+        current_epoch: u64,
+        epochs_before_stall: u64,
+    ) -> Result<bool, TipRouterError> {
+        let stall_epoch = self
+            .epoch()
+            .checked_add(epochs_before_stall)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        Ok(!self.is_consensus_reached() && current_epoch >= stall_epoch)
+    }
+
     pub fn get_winning_ballot(&self) -> Result<&Ballot, TipRouterError> {
         if !self.winning_ballot.is_valid() {
             Err(TipRouterError::ConsensusNotReached)
@@ -414,6 +635,61 @@ impl BallotBox {
         self.winning_ballot = *ballot;
     }
 
+    pub fn page_count(&self) -> u64 {
+        self.page_count.into()
+    }
+
+    /// Records a new page, pages are created in order
+    pub fn add_page(&mut self, page: u64) -> Result<(), TipRouterError> {
+        if page != self.page_count() {
+            return Err(TipRouterError::InvalidBallotBoxPage);
+        }
+
+        self.page_count = PodU64::from(
+            page.checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Records a closed page, pages are closed from the last one and the ballot box can only
+    /// be closed once all of them are
+    pub fn remove_page(&mut self, page: u64) -> Result<(), TipRouterError> {
+        if page.checked_add(1) != Some(self.page_count()) {
+            return Err(TipRouterError::InvalidBallotBoxPage);
+        }
+
+        self.page_count = PodU64::from(page);
+
+        Ok(())
+    }
+
+    /// Checks that the page extends this ballot box
+    pub(crate) fn check_page(&self, page: Option<&BallotBoxPage>) -> Result<(), TipRouterError> {
+        if let Some(page) = page {
+            if page.ncn().ne(&self.ncn)
+                || page.epoch() != self.epoch()
+                || page.page() >= self.page_count()
+            {
+                return Err(TipRouterError::InvalidBallotBoxPage);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The votes of operators past `MAX_OPERATORS` are kept in the page instead of the ballot box
+    fn operator_votes_mut<'a>(
+        operator_votes: &'a mut [OperatorVote; MAX_OPERATORS],
+        page: Option<&'a mut BallotBoxPage>,
+    ) -> &'a mut [OperatorVote] {
+        match page {
+            Some(page) => page.operator_votes_mut(),
+            None => operator_votes,
+        }
+    }
+
     fn increment_or_create_ballot_tally(
         &mut self,
         ballot: &Ballot,
@@ -455,6 +731,32 @@ impl BallotBox {
         current_slot: u64,
         valid_slots_after_consensus: u64,
     ) -> Result<(), TipRouterError> {
+        self.cast_vote_with_page(
+            None,
+            operator,
+            ballot,
+            stake_weights,
+            current_slot,
+            valid_slots_after_consensus,
+        )
+    }
+
+    /// `cast_vote` for an operator whose vote is kept in a ballot box page
+    pub fn cast_vote_with_page(
+        &mut self,
+        mut page: Option<&mut BallotBoxPage>,
+        operator: &Pubkey,
+        ballot: &Ballot,
+        stake_weights: &StakeWeights,
+        current_slot: u64,
+        valid_slots_after_consensus: u64,
+    ) -> Result<(), TipRouterError> {
+        self.check_page(page.as_deref())?;
+
+        if self.is_commit_reveal() {
+            return Err(TipRouterError::CommitRevealRequired);
+        }
+
         if !self.is_voting_valid(current_slot, valid_slots_after_consensus)? {
             return Err(TipRouterError::VotingNotValid);
         }
@@ -468,81 +770,278 @@ impl BallotBox {
         let unique_ballots = self.unique_ballots();
         let consensus_reached = self.is_consensus_reached();
 
-        for vote in self.operator_votes.iter_mut() {
-            if vote.operator().eq(operator) {
-                if consensus_reached {
-                    return Err(TipRouterError::ConsensusAlreadyReached);
-                }
+        let votes = Self::operator_votes_mut(&mut self.operator_votes, page.as_deref_mut());
+        let vote_index = votes
+            .iter()
+            .position(|vote| vote.operator().eq(operator) || vote.is_empty())
+            .ok_or(TipRouterError::OperatorVotesFull)?;
+        let vote = votes[vote_index];
+
+        if vote.is_empty() {
+            self.operators_voted = PodU64::from(
+                self.operators_voted()
+                    .checked_add(1)
+                    .ok_or(TipRouterError::ArithmeticOverflow)?,
+            );
+        } else {
+            if consensus_reached {
+                return Err(TipRouterError::ConsensusAlreadyReached);
+            }
 
-                // If the operator has already voted, we need to decrement their vote from the previous ballot
-                let prev_ballot_index = vote.ballot_index();
-                if let Some(prev_tally) = self.ballot_tallies.get_mut(prev_ballot_index as usize) {
-                    prev_tally.decrement_tally(vote.stake_weights())?;
-
-                    // If no more operators voting for the previous ballot, wipe and decrement the unique ballots
-                    if prev_tally.tally() == 0 {
-                        *prev_tally = BallotTally::default();
-                        self.unique_ballots = PodU64::from(
-                            unique_ballots
-                                .checked_sub(1)
-                                .ok_or(TipRouterError::ArithmeticUnderflowError)?,
-                        );
-                    }
+            // If the operator has already voted, we need to decrement their vote from the previous ballot
+            let prev_ballot_index = vote.ballot_index();
+            if let Some(prev_tally) = self.ballot_tallies.get_mut(prev_ballot_index as usize) {
+                prev_tally.decrement_tally(vote.stake_weights())?;
+
+                // If no more operators voting for the previous ballot, wipe and decrement the unique ballots
+                if prev_tally.tally() == 0 {
+                    *prev_tally = BallotTally::default();
+                    self.unique_ballots = PodU64::from(
+                        unique_ballots
+                            .checked_sub(1)
+                            .ok_or(TipRouterError::ArithmeticUnderflowError)?,
+                    );
                 }
-
-                let operator_vote =
-                    OperatorVote::new(ballot_index, operator, current_slot, stake_weights);
-                *vote = operator_vote;
-                return Ok(());
             }
+        }
 
-            if vote.is_empty() {
-                let operator_vote =
-                    OperatorVote::new(ballot_index, operator, current_slot, stake_weights);
-                *vote = operator_vote;
+        Self::operator_votes_mut(&mut self.operator_votes, page)[vote_index] =
+            OperatorVote::new(ballot_index, operator, current_slot, stake_weights);
 
-                self.operators_voted = PodU64::from(
-                    self.operators_voted()
-                        .checked_add(1)
-                        .ok_or(TipRouterError::ArithmeticOverflow)?,
+        Ok(())
+    }
+
+    /// Removes an operator's vote, or unrevealed commitment, before consensus is reached.
+    /// The last vote is moved into the freed slot so the operator votes stay contiguous.
+    pub fn withdraw_vote(&mut self, operator: &Pubkey) -> Result<(), TipRouterError> {
+        self.withdraw_vote_with_page(None, operator)
+    }
+
+    /// `withdraw_vote` for a vote kept in a ballot box page, the page stays contiguous
+    pub fn withdraw_vote_with_page(
+        &mut self,
+        mut page: Option<&mut BallotBoxPage>,
+        operator: &Pubkey,
+    ) -> Result<(), TipRouterError> {
+        self.check_page(page.as_deref())?;
+
+        if self.is_consensus_reached() {
+            return Err(TipRouterError::ConsensusAlreadyReached);
+        }
+
+        let votes = Self::operator_votes_mut(&mut self.operator_votes, page.as_deref_mut());
+        let vote_index = votes
+            .iter()
+            .position(|vote| !vote.is_empty() && vote.operator().eq(operator))
+            .ok_or(TipRouterError::OperatorVoteNotFound)?;
+        let vote = votes[vote_index];
+
+        if vote.is_revealed() {
+            let tally = self
+                .ballot_tallies
+                .get_mut(vote.ballot_index() as usize)
+                .ok_or(TipRouterError::BallotTallyNotFoundFull)?;
+            tally.decrement_tally(vote.stake_weights())?;
+
+            // If no more operators voting for the ballot, wipe and decrement the unique ballots
+            if tally.tally() == 0 {
+                *tally = BallotTally::default();
+                self.unique_ballots = PodU64::from(
+                    self.unique_ballots()
+                        .checked_sub(1)
+                        .ok_or(TipRouterError::ArithmeticUnderflowError)?,
                 );
-                return Ok(());
             }
+
+            self.operators_voted = PodU64::from(
+                self.operators_voted()
+                    .checked_sub(1)
+                    .ok_or(TipRouterError::ArithmeticUnderflowError)?,
+            );
+        }
+
+        if vote.has_commitment() {
+            self.operators_committed = PodU64::from(
+                self.operators_committed()
+                    .checked_sub(1)
+                    .ok_or(TipRouterError::ArithmeticUnderflowError)?,
+            );
         }
 
-        Err(TipRouterError::OperatorVotesFull)
+        let votes = Self::operator_votes_mut(&mut self.operator_votes, page);
+        let last_vote_index = votes
+            .iter()
+            .rposition(|vote| !vote.is_empty())
+            .unwrap_or(vote_index);
+        votes[vote_index] = votes[last_vote_index];
+        votes[last_vote_index] = OperatorVote::default();
+
+        Ok(())
+    }
+
+    /// Records an operator's commitment to a vote during the commit phase.
+    /// Commitments can be replaced until the commit phase ends.
+    pub fn commit_vote(
+        &mut self,
+        operator: &Pubkey,
+        commitment: &[u8; 32],
+        current_slot: u64,
+    ) -> Result<(), TipRouterError> {
+        self.commit_vote_with_page(None, operator, commitment, current_slot)
+    }
+
+    /// `commit_vote` for an operator whose vote is kept in a ballot box page
+    pub fn commit_vote_with_page(
+        &mut self,
+        page: Option<&mut BallotBoxPage>,
+        operator: &Pubkey,
+        commitment: &[u8; 32],
+        current_slot: u64,
+    ) -> Result<(), TipRouterError> {
+        self.check_page(page.as_deref())?;
+
+        if !self.is_commit_reveal() {
+            return Err(TipRouterError::CommitRevealNotEnabled);
+        }
+
+        if !self.is_commit_phase(current_slot) {
+            return Err(TipRouterError::CommitPhaseEnded);
+        }
+
+        if commitment.iter().all(|byte| *byte == 0) {
+            return Err(TipRouterError::InvalidVoteCommitment);
+        }
+
+        let votes = Self::operator_votes_mut(&mut self.operator_votes, page);
+        let vote = votes
+            .iter_mut()
+            .find(|vote| vote.operator().eq(operator) || vote.is_empty())
+            .ok_or(TipRouterError::OperatorVotesFull)?;
+
+        if vote.is_empty() {
+            self.operators_committed = PodU64::from(
+                u64::from(self.operators_committed)
+                    .checked_add(1)
+                    .ok_or(TipRouterError::ArithmeticOverflow)?,
+            );
+        }
+
+        *vote = OperatorVote::new_commitment(operator, commitment, current_slot);
+
+        Ok(())
+    }
+
+    /// Reveals a committed vote during the reveal phase. Only revealed votes are tallied,
+    /// commitments that are never revealed count as non-participation.
+    pub fn reveal_vote(
+        &mut self,
+        operator: &Pubkey,
+        ballot: &Ballot,
+        salt: &[u8; 32],
+        stake_weights: &StakeWeights,
+        current_slot: u64,
+        valid_slots_after_consensus: u64,
+    ) -> Result<(), TipRouterError> {
+        self.reveal_vote_with_page(
+            None,
+            operator,
+            ballot,
+            salt,
+            stake_weights,
+            current_slot,
+            valid_slots_after_consensus,
+        )
+    }
+
+    /// `reveal_vote` for an operator whose vote is kept in a ballot box page
+    #[allow(clippy::too_many_arguments)]
+    pub fn reveal_vote_with_page(
+        &mut self,
+        mut page: Option<&mut BallotBoxPage>,
+        operator: &Pubkey,
+        ballot: &Ballot,
+        salt: &[u8; 32],
+        stake_weights: &StakeWeights,
+        current_slot: u64,
+        valid_slots_after_consensus: u64,
+    ) -> Result<(), TipRouterError> {
+        self.check_page(page.as_deref())?;
+
+        if !self.is_commit_reveal() {
+            return Err(TipRouterError::CommitRevealNotEnabled);
+        }
+
+        if current_slot < self.commit_phase_end_slot() {
+            return Err(TipRouterError::RevealPhaseNotStarted);
+        }
+
+        if current_slot >= self.reveal_phase_end_slot() {
+            return Err(TipRouterError::RevealPhaseEnded);
+        }
+
+        if !self.is_voting_valid(current_slot, valid_slots_after_consensus)? {
+            return Err(TipRouterError::VotingNotValid);
+        }
+
+        if !ballot.is_valid() {
+            return Err(TipRouterError::BadBallot);
+        }
+
+        let votes = Self::operator_votes_mut(&mut self.operator_votes, page.as_deref_mut());
+        let vote_index = votes
+            .iter()
+            .position(|vote| vote.operator().eq(operator) && vote.has_commitment())
+            .ok_or(TipRouterError::VoteCommitmentNotFound)?;
+
+        let vote = votes[vote_index];
+        if vote.is_revealed() {
+            return Err(TipRouterError::VoteAlreadyRevealed);
+        }
+
+        if OperatorVote::commitment_hash(&ballot.root(), salt, operator) != *vote.commitment() {
+            return Err(TipRouterError::VoteCommitmentMismatch);
+        }
+
+        let ballot_index = self.increment_or_create_ballot_tally(ballot, stake_weights)?;
+
+        let operator_vote =
+            &mut Self::operator_votes_mut(&mut self.operator_votes, page)[vote_index];
+        operator_vote.ballot_index = PodU16::from(ballot_index as u16);
+        operator_vote.slot_voted = PodU64::from(current_slot);
+        operator_vote.stake_weights = *stake_weights;
+
+        self.operators_voted = PodU64::from(
+            self.operators_voted()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
     }
 
     // Should be called anytime a new vote is cast
     pub fn tally_votes(
         &mut self,
         total_stake_weight: u128,
+        active_operator_count: u64,
         current_slot: u64,
     ) -> Result<(), TipRouterError> {
         if self.slot_consensus_reached() != DEFAULT_CONSENSUS_REACHED_SLOT {
             return Ok(());
         }
 
-        let max_tally = self
-            .ballot_tallies
-            .iter()
-            .max_by_key(|t| t.stake_weights().stake_weight())
-            .ok_or(TipRouterError::BallotTallyNotFoundFull)?;
-
-        let ballot_stake_weight = max_tally.stake_weights().stake_weight();
-        let precise_ballot_stake_weight =
-            PreciseNumber::new(ballot_stake_weight).ok_or(TipRouterError::NewPreciseNumberError)?;
-        let precise_total_stake_weight =
-            PreciseNumber::new(total_stake_weight).ok_or(TipRouterError::NewPreciseNumberError)?;
+        let max_tally = *self.leading_ballot_tally()?;
 
-        let ballot_percentage_of_total = precise_ballot_stake_weight
-            .checked_div(&precise_total_stake_weight)
-            .ok_or(TipRouterError::DenominatorIsZero)?;
+        let ballot_percentage_of_total = Self::percentage_of_total(
+            max_tally.stake_weights().stake_weight(),
+            total_stake_weight,
+        )?;
 
-        let target_precise_percentage = precise_consensus()?;
+        let target_precise_percentage = precise_consensus(self.consensus_threshold_bps())?;
 
-        let consensus_reached =
-            ballot_percentage_of_total.greater_than_or_equal(&target_precise_percentage);
+        let consensus_reached = ballot_percentage_of_total
+            .greater_than_or_equal(&target_precise_percentage)
+            && self.is_quorum_reached(active_operator_count)?;
 
         if consensus_reached && !self.winning_ballot.is_valid() {
             self.slot_consensus_reached = PodU64::from(current_slot);
@@ -554,6 +1053,29 @@ impl BallotBox {
         Ok(())
     }
 
+    fn leading_ballot_tally(&self) -> Result<&BallotTally, TipRouterError> {
+        self.ballot_tallies
+            .iter()
+            .max_by_key(|t| t.stake_weights().stake_weight())
+            .ok_or(TipRouterError::BallotTallyNotFoundFull)
+    }
+
+    fn percentage_of_total(
+        stake_weight: u128,
+        total_stake_weight: u128,
+    ) -> Result<PreciseNumber, TipRouterError> {
+        let precise_stake_weight =
+            PreciseNumber::new(stake_weight).ok_or(TipRouterError::NewPreciseNumberError)?;
+        let precise_total_stake_weight =
+            PreciseNumber::new(total_stake_weight).ok_or(TipRouterError::NewPreciseNumberError)?;
+
+        precise_stake_weight
+            .checked_div(&precise_total_stake_weight)
+            .ok_or(TipRouterError::DenominatorIsZero)
+    }
+
+    /// Sets the tie breaker admin's ballot on a stalled vote. The admin can replace a ballot
+    /// finalized through the stall fallback, but not one that reached consensus.
     pub fn set_tie_breaker_ballot(
         &mut self,
         meta_merkle_root: &[u8; 32],
@@ -561,18 +1083,13 @@ impl BallotBox {
         epochs_before_stall: u64,
     ) -> Result<(), TipRouterError> {
         // Check that consensus has not been reached
-        if self.is_consensus_reached() {
+        if self.is_consensus_reached() && !self.stall_fallback_used() {
             msg!("Consensus already reached");
             return Err(TipRouterError::ConsensusAlreadyReached);
         }
 
         // Check if voting is stalled and setting the tie breaker is eligible
-        if current_epoch
-            < self
-                .epoch()
-                .checked_add(epochs_before_stall)
-                .ok_or(TipRouterError::ArithmeticOverflow)?
-        {
+        if !self.is_voting_stalled(current_epoch, epochs_before_stall)? {
             return Err(TipRouterError::VotingNotFinalized);
         }
 
@@ -584,6 +1101,52 @@ impl BallotBox {
         }
 
         self.set_winning_ballot(&finalized_ballot);
+        self.stall_fallback_used = PodBool::from(false);
+        Ok(())
+    }
+
+    /// Permissionless resolution of a stalled vote. Finalizes the ballot with the most stake
+    /// weight as long as it meets the fallback threshold copied when the ballot box was created.
+    /// The tie breaker admin can still resolve the vote first, or replace the result afterwards.
+    pub fn resolve_stalled_vote(
+        &mut self,
+        total_stake_weight: u128,
+        current_epoch: u64,
+        epochs_before_stall: u64,
+    ) -> Result<(), TipRouterError> {
+        if self.is_consensus_reached() {
+            msg!("Consensus already reached");
+            return Err(TipRouterError::ConsensusAlreadyReached);
+        }
+
+        let stall_fallback_threshold_bps = self.stall_fallback_threshold_bps();
+        if stall_fallback_threshold_bps == 0 {
+            return Err(TipRouterError::StallFallbackNotEnabled);
+        }
+
+        if !self.is_voting_stalled(current_epoch, epochs_before_stall)? {
+            return Err(TipRouterError::VotingNotFinalized);
+        }
+
+        let leading_tally = *self.leading_ballot_tally()?;
+        if !leading_tally.is_valid() {
+            return Err(TipRouterError::StallFallbackThresholdNotMet);
+        }
+
+        let ballot_percentage_of_total = Self::percentage_of_total(
+            leading_tally.stake_weights().stake_weight(),
+            total_stake_weight,
+        )?;
+        let fallback_precise_percentage = precise_consensus(stall_fallback_threshold_bps)?;
+
+        if !ballot_percentage_of_total.greater_than_or_equal(&fallback_precise_percentage) {
+            return Err(TipRouterError::StallFallbackThresholdNotMet);
+        }
+
+        let leading_ballot = *leading_tally.ballot();
+        self.set_winning_ballot(&leading_ballot);
+        self.stall_fallback_used = PodBool::from(true);
+
         Ok(())
     }
 
@@ -649,11 +1212,28 @@ impl fmt::Display for BallotBox {
        writeln!(f, "  Epoch:                        {}", self.epoch())?;
        writeln!(f, "  Bump:                         {}", self.bump)?;
        writeln!(f, "  Slot Consensus Reached:       {}", self.slot_consensus_reached())?;
+       writeln!(f, "  Consensus Threshold Bps:      {}", self.consensus_threshold_bps())?;
+       if self.has_quorum() {
+           writeln!(f, "  Quorum Operator Count:        {}", self.quorum_operator_count())?;
+           writeln!(f, "  Quorum Operator Bps:          {}", self.quorum_operator_bps())?;
+       }
+       if self.is_commit_reveal() {
+           writeln!(f, "  Commit Phase End Slot:        {}", self.commit_phase_end_slot())?;
+           writeln!(f, "  Reveal Phase End Slot:        {}", self.reveal_phase_end_slot())?;
+           writeln!(f, "  Operators Committed:          {}", self.operators_committed())?;
+       }
        writeln!(f, "  Operators Voted:              {}", self.operators_voted())?;
        writeln!(f, "  Unique Ballots:               {}", self.unique_ballots())?;
+       if self.page_count() > 0 {
+           writeln!(f, "  Page Count:                   {}", self.page_count())?;
+       }
        writeln!(f, "  IS Consensus Reached:         {}", self.is_consensus_reached())?;
        if self.is_consensus_reached() {
            writeln!(f, "  Tie Breaker Set:              {}", self.tie_breaker_set())?;
+           writeln!(f, "  Stall Fallback Used:          {}", self.stall_fallback_used())?;
+           if self.stall_fallback_used() {
+               writeln!(f, "  Stall Fallback Threshold Bps: {}", self.stall_fallback_threshold_bps())?;
+           }
            if let Ok(winning_ballot) = self.get_winning_ballot() {
                writeln!(f, "  Winning Ballot:               {}", winning_ballot)?;
            }
@@ -663,6 +1243,13 @@ impl fmt::Display for BallotBox {
        for vote in self.operator_votes().iter() {
            if !vote.is_empty() {
                writeln!(f, "  Operator:                     {}", vote.operator())?;
+               if vote.has_commitment() {
+                   writeln!(f, "    Slot Committed:             {}", vote.slot_committed())?;
+                   writeln!(f, "    Revealed:                   {}", vote.is_revealed())?;
+               }
+               if !vote.is_revealed() {
+                   continue;
+               }
                writeln!(f, "    Slot Voted:                 {}", vote.slot_voted())?;
                writeln!(f, "    Ballot Index:               {}", vote.ballot_index())?;
                writeln!(f, "    Stake Weights:")?;
@@ -715,7 +1302,16 @@ mod tests {
             + 1 // bump
             + size_of::<PodU64>() // slot_created
             + size_of::<PodU64>() // slot_consensus_reached
-            + 128 // reserved
+            + size_of::<PodU64>() // commit_phase_end_slot
+            + size_of::<PodU64>() // reveal_phase_end_slot
+            + size_of::<PodU64>() // operators_committed
+            + size_of::<PodU16>() // consensus_threshold_bps
+            + size_of::<PodU16>() // quorum_operator_count
+            + size_of::<PodU16>() // quorum_operator_bps
+            + size_of::<PodBool>() // stall_fallback_used
+            + size_of::<PodU64>() // page_count
+            + size_of::<PodU16>() // stall_fallback_threshold_bps
+            + 87 // reserved
             + size_of::<PodU64>() // operators_voted
             + size_of::<PodU64>() // unique_ballots
             + size_of::<Ballot>() // winning_ballot
@@ -1026,7 +1622,7 @@ mod tests {
             .increment_or_create_ballot_tally(&ballot, &half_stake_weights)
             .unwrap();
         ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(total_stake_weight, MAX_OPERATORS as u64, current_slot)
             .unwrap();
         assert!(!ballot_box.is_consensus_reached());
         assert_eq!(
@@ -1043,7 +1639,7 @@ mod tests {
             .increment_or_create_ballot_tally(&ballot, &half_stake_weights)
             .unwrap();
         ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(total_stake_weight, MAX_OPERATORS as u64, current_slot)
             .unwrap();
         assert!(ballot_box.is_consensus_reached());
         assert_eq!(ballot_box.slot_consensus_reached(), current_slot);
@@ -1058,7 +1654,7 @@ mod tests {
             .increment_or_create_ballot_tally(&ballot2, &full_stake_weights)
             .unwrap();
         ballot_box
-            .tally_votes(total_stake_weight, current_slot + 1)
+            .tally_votes(total_stake_weight, MAX_OPERATORS as u64, current_slot + 1)
             .unwrap();
         assert!(ballot_box.is_consensus_reached());
         assert_eq!(ballot_box.slot_consensus_reached(), current_slot);
@@ -1084,7 +1680,7 @@ mod tests {
             .unwrap();
 
         ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(total_stake_weight, MAX_OPERATORS as u64, current_slot)
             .unwrap();
         assert!(!ballot_box.is_consensus_reached());
 
@@ -1093,7 +1689,7 @@ mod tests {
             .increment_or_create_ballot_tally(&ballot3, &half_stake_weights)
             .unwrap();
         ballot_box
-            .tally_votes(total_stake_weight, current_slot)
+            .tally_votes(total_stake_weight, MAX_OPERATORS as u64, current_slot)
             .unwrap();
         assert!(ballot_box.is_consensus_reached());
         assert_eq!(
@@ -1102,6 +1698,137 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tally_votes_with_consensus_threshold() {
+        let ncn = Pubkey::new_unique();
+        let current_slot = 100;
+        let total_stake_weight: u128 = 1000;
+        let ballot = Ballot::new(&[1; 32]);
+
+        // 70% of stake passes the default 2/3 threshold
+        let mut ballot_box = BallotBox::new(&ncn, 1, 0, current_slot);
+        ballot_box
+            .increment_or_create_ballot_tally(&ballot, &StakeWeights::new(700))
+            .unwrap();
+        ballot_box
+            .tally_votes(total_stake_weight, MAX_OPERATORS as u64, current_slot)
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+
+        // But not a 75% threshold
+        let mut ballot_box = BallotBox::new(&ncn, 1, 0, current_slot);
+        ballot_box.set_consensus_threshold_bps(7_500);
+        ballot_box
+            .increment_or_create_ballot_tally(&ballot, &StakeWeights::new(700))
+            .unwrap();
+        ballot_box
+            .tally_votes(total_stake_weight, MAX_OPERATORS as u64, current_slot)
+            .unwrap();
+        assert!(!ballot_box.is_consensus_reached());
+
+        ballot_box
+            .increment_or_create_ballot_tally(&ballot, &StakeWeights::new(50))
+            .unwrap();
+        ballot_box
+            .tally_votes(total_stake_weight, MAX_OPERATORS as u64, current_slot + 1)
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.slot_consensus_reached(), current_slot + 1);
+
+        // 60% of stake only passes a lower threshold
+        let mut ballot_box = BallotBox::new(&ncn, 1, 0, current_slot);
+        ballot_box.set_consensus_threshold_bps(6_000);
+        ballot_box
+            .increment_or_create_ballot_tally(&ballot, &StakeWeights::new(600))
+            .unwrap();
+        ballot_box
+            .tally_votes(total_stake_weight, MAX_OPERATORS as u64, current_slot)
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+    }
+
+    #[test]
+    fn test_quorum_operators_required() {
+        let mut ballot_box = BallotBox::new(&Pubkey::new_unique(), 1, 0, 100);
+        assert!(!ballot_box.has_quorum());
+        assert_eq!(ballot_box.quorum_operators_required(10).unwrap(), 0);
+
+        // Share of active operators rounds up
+        ballot_box.set_quorum(0, 5_000);
+        assert_eq!(ballot_box.quorum_operators_required(10).unwrap(), 5);
+        assert_eq!(ballot_box.quorum_operators_required(9).unwrap(), 5);
+
+        // The larger of the count and the share applies
+        ballot_box.set_quorum(7, 5_000);
+        assert_eq!(ballot_box.quorum_operators_required(10).unwrap(), 7);
+        assert_eq!(ballot_box.quorum_operators_required(20).unwrap(), 10);
+
+        // Never more than the active operators
+        assert_eq!(ballot_box.quorum_operators_required(3).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_tally_votes_with_quorum() {
+        let ncn = Pubkey::new_unique();
+        let current_slot = 100;
+        let epoch = 1;
+        let total_stake_weight: u128 = 1000;
+        let active_operator_count = 4;
+        let ballot = Ballot::new(&[1; 32]);
+
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
+        ballot_box.set_quorum(3, 0);
+
+        // A single operator with 90% of the stake isn't enough
+        ballot_box
+            .cast_vote(
+                &Pubkey::new_unique(),
+                &ballot,
+                &StakeWeights::new(900),
+                current_slot,
+                0,
+            )
+            .unwrap();
+        ballot_box
+            .tally_votes(total_stake_weight, active_operator_count, current_slot)
+            .unwrap();
+        assert!(!ballot_box.is_consensus_reached());
+
+        ballot_box
+            .cast_vote(
+                &Pubkey::new_unique(),
+                &ballot,
+                &StakeWeights::new(50),
+                current_slot + 1,
+                0,
+            )
+            .unwrap();
+        ballot_box
+            .tally_votes(total_stake_weight, active_operator_count, current_slot + 1)
+            .unwrap();
+        assert!(!ballot_box.is_consensus_reached());
+
+        // Consensus is reached once enough operators have voted
+        ballot_box
+            .cast_vote(
+                &Pubkey::new_unique(),
+                &Ballot::new(&[2; 32]),
+                &StakeWeights::new(50),
+                current_slot + 2,
+                0,
+            )
+            .unwrap();
+        ballot_box
+            .tally_votes(total_stake_weight, active_operator_count, current_slot + 2)
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.slot_consensus_reached(), current_slot + 2);
+        assert_eq!(
+            *ballot_box.get_winning_ballot_tally().unwrap().ballot(),
+            ballot
+        );
+    }
+
     #[test]
     fn test_cast_bad_ballot() {
         let ncn = Pubkey::new_unique();
@@ -1146,77 +1873,269 @@ mod tests {
         let ballot1 = Ballot::new(&[1; 32]);
         let ballot2 = Ballot::new(&[2; 32]);
 
-        // Operator 1 votes for ballot1 initially
-        ballot_box
-            .cast_vote(
+        // Operator 1 votes for ballot1 initially
+        ballot_box
+            .cast_vote(
+                &operator1,
+                &ballot1,
+                &stake_weights,
+                current_slot,
+                valid_slots_after_consensus,
+            )
+            .unwrap();
+
+        assert_eq!(ballot_box.unique_ballots(), 1);
+
+        // Operator 1 changes vote to ballot2
+        ballot_box
+            .cast_vote(
+                &operator1,
+                &ballot2,
+                &stake_weights,
+                current_slot + 1,
+                valid_slots_after_consensus,
+            )
+            .unwrap();
+
+        // Ballot1 should be removed and ballot2 should be the only ballot
+        assert_eq!(ballot_box.unique_ballots(), 1);
+
+        // Operator 2 votes for ballot2
+        ballot_box
+            .cast_vote(
+                &operator2,
+                &ballot2,
+                &stake_weights,
+                current_slot + 2,
+                valid_slots_after_consensus,
+            )
+            .unwrap();
+        assert_eq!(ballot_box.unique_ballots(), 1);
+
+        // Operator 3 votes for ballot2
+        ballot_box
+            .cast_vote(
+                &operator3,
+                &ballot2,
+                &stake_weights,
+                current_slot + 3,
+                valid_slots_after_consensus,
+            )
+            .unwrap();
+
+        // Still only one unique ballot
+        assert_eq!(ballot_box.unique_ballots(), 1);
+
+        // Check total stake weight
+        let winning_tally = ballot_box
+            .ballot_tallies
+            .iter()
+            .find(|t| t.ballot().eq(&ballot2))
+            .unwrap();
+
+        assert_eq!(winning_tally.stake_weights().stake_weight(), {
+            total_stake_weight
+        });
+        assert_eq!(winning_tally.tally(), 3);
+
+        // Verify ballot2 wins consensus with all votes
+        ballot_box
+            .tally_votes(total_stake_weight, MAX_OPERATORS as u64, current_slot + 4)
+            .unwrap();
+        assert!(ballot_box.has_winning_ballot());
+        assert_eq!(*ballot_box.get_winning_ballot().unwrap(), ballot2);
+    }
+
+    #[test]
+    fn test_withdraw_vote() {
+        let ncn = Pubkey::new_unique();
+        let current_slot = 100;
+        let epoch = 1;
+        let valid_slots_after_consensus = 10;
+        let total_stake_weight = 3000;
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
+
+        let operator1 = Pubkey::new_unique();
+        let operator2 = Pubkey::new_unique();
+        let operator3 = Pubkey::new_unique();
+
+        let stake_weights = StakeWeights::new(1000);
+        let ballot1 = Ballot::new(&[1; 32]);
+        let ballot2 = Ballot::new(&[2; 32]);
+
+        assert_eq!(
+            ballot_box.withdraw_vote(&operator1),
+            Err(TipRouterError::OperatorVoteNotFound)
+        );
+
+        for (operator, ballot) in [(operator1, ballot1), (operator2, ballot2)] {
+            ballot_box
+                .cast_vote(
+                    &operator,
+                    &ballot,
+                    &stake_weights,
+                    current_slot,
+                    valid_slots_after_consensus,
+                )
+                .unwrap();
+        }
+
+        assert_eq!(ballot_box.operators_voted(), 2);
+        assert_eq!(ballot_box.unique_ballots(), 2);
+
+        // Withdrawing the only vote for a ballot removes its tally
+        ballot_box.withdraw_vote(&operator1).unwrap();
+        assert_eq!(ballot_box.operators_voted(), 1);
+        assert_eq!(ballot_box.unique_ballots(), 1);
+        assert!(!ballot_box.has_ballot(&ballot1));
+        assert_eq!(*ballot_box.operator_votes()[0].operator(), operator2);
+        assert!(ballot_box.operator_votes()[1].is_empty());
+
+        assert_eq!(
+            ballot_box.withdraw_vote(&operator1),
+            Err(TipRouterError::OperatorVoteNotFound)
+        );
+
+        // The withdrawn operator can vote again
+        for operator in [operator1, operator3] {
+            ballot_box
+                .cast_vote(
+                    &operator,
+                    &ballot2,
+                    &stake_weights,
+                    current_slot,
+                    valid_slots_after_consensus,
+                )
+                .unwrap();
+        }
+        assert_eq!(ballot_box.operators_voted(), 3);
+
+        ballot_box
+            .tally_votes(total_stake_weight, 3, current_slot)
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.get_winning_ballot_tally().unwrap().tally(), 3);
+
+        assert_eq!(
+            ballot_box.withdraw_vote(&operator2),
+            Err(TipRouterError::ConsensusAlreadyReached)
+        );
+    }
+
+    #[test]
+    fn test_vote_with_page() {
+        let ncn = Pubkey::new_unique();
+        let current_slot = 100;
+        let epoch = 1;
+        let valid_slots_after_consensus = 10;
+        let total_stake_weight = 3000;
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
+        let mut page = BallotBoxPage::new(&ncn, epoch, 0, 0);
+
+        let operator1 = Pubkey::new_unique();
+        let operator2 = Pubkey::new_unique();
+        let stake_weights = StakeWeights::new(1000);
+        let ballot = Ballot::new(&[1; 32]);
+
+        // The page has to be added to the ballot box first
+        assert_eq!(
+            ballot_box.cast_vote_with_page(
+                Some(&mut page),
                 &operator1,
-                &ballot1,
+                &ballot,
                 &stake_weights,
                 current_slot,
                 valid_slots_after_consensus,
-            )
-            .unwrap();
+            ),
+            Err(TipRouterError::InvalidBallotBoxPage)
+        );
 
-        assert_eq!(ballot_box.unique_ballots(), 1);
+        assert_eq!(
+            ballot_box.add_page(1),
+            Err(TipRouterError::InvalidBallotBoxPage)
+        );
+        ballot_box.add_page(0).unwrap();
+        assert_eq!(ballot_box.page_count(), 1);
 
-        // Operator 1 changes vote to ballot2
-        ballot_box
-            .cast_vote(
+        // Pages of another ballot box are rejected
+        let mut other_page = BallotBoxPage::new(&Pubkey::new_unique(), epoch, 0, 0);
+        assert_eq!(
+            ballot_box.cast_vote_with_page(
+                Some(&mut other_page),
                 &operator1,
-                &ballot2,
+                &ballot,
                 &stake_weights,
-                current_slot + 1,
+                current_slot,
                 valid_slots_after_consensus,
-            )
-            .unwrap();
-
-        // Ballot1 should be removed and ballot2 should be the only ballot
-        assert_eq!(ballot_box.unique_ballots(), 1);
+            ),
+            Err(TipRouterError::InvalidBallotBoxPage)
+        );
 
-        // Operator 2 votes for ballot2
         ballot_box
-            .cast_vote(
-                &operator2,
-                &ballot2,
+            .cast_vote_with_page(
+                Some(&mut page),
+                &operator1,
+                &ballot,
                 &stake_weights,
-                current_slot + 2,
+                current_slot,
                 valid_slots_after_consensus,
             )
             .unwrap();
-        assert_eq!(ballot_box.unique_ballots(), 1);
-
-        // Operator 3 votes for ballot2
         ballot_box
             .cast_vote(
-                &operator3,
-                &ballot2,
+                &operator2,
+                &ballot,
                 &stake_weights,
-                current_slot + 3,
+                current_slot,
                 valid_slots_after_consensus,
             )
             .unwrap();
 
-        // Still only one unique ballot
-        assert_eq!(ballot_box.unique_ballots(), 1);
-
-        // Check total stake weight
-        let winning_tally = ballot_box
-            .ballot_tallies
+        // Paged votes are tallied with the ballot box votes
+        assert_eq!(ballot_box.operators_voted(), 2);
+        assert_eq!(page.operators_voted(), 1);
+        assert_eq!(*page.operator_votes()[0].operator(), operator1);
+        assert!(ballot_box
+            .operator_votes()
             .iter()
-            .find(|t| t.ballot().eq(&ballot2))
-            .unwrap();
+            .all(|vote| vote.operator().ne(&operator1)));
 
-        assert_eq!(winning_tally.stake_weights().stake_weight(), {
-            total_stake_weight
-        });
-        assert_eq!(winning_tally.tally(), 3);
+        // A paged vote is only found in its page
+        assert_eq!(
+            ballot_box.withdraw_vote(&operator1),
+            Err(TipRouterError::OperatorVoteNotFound)
+        );
+        ballot_box
+            .withdraw_vote_with_page(Some(&mut page), &operator1)
+            .unwrap();
+        assert_eq!(ballot_box.operators_voted(), 1);
+        assert!(page.operator_votes()[0].is_empty());
 
-        // Verify ballot2 wins consensus with all votes
         ballot_box
-            .tally_votes(total_stake_weight, current_slot + 4)
+            .cast_vote_with_page(
+                Some(&mut page),
+                &operator1,
+                &ballot,
+                &stake_weights,
+                current_slot,
+                valid_slots_after_consensus,
+            )
             .unwrap();
-        assert!(ballot_box.has_winning_ballot());
-        assert_eq!(*ballot_box.get_winning_ballot().unwrap(), ballot2);
+        ballot_box
+            .tally_votes(total_stake_weight, 3, current_slot)
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.get_winning_ballot_tally().unwrap().tally(), 2);
+
+        // Pages are closed from the last one
+        ballot_box.add_page(1).unwrap();
+        assert_eq!(
+            ballot_box.remove_page(0),
+            Err(TipRouterError::InvalidBallotBoxPage)
+        );
+        ballot_box.remove_page(1).unwrap();
+        ballot_box.remove_page(0).unwrap();
+        assert_eq!(ballot_box.page_count(), 0);
     }
 
     #[test]
@@ -1275,7 +2194,11 @@ mod tests {
             .increment_or_create_ballot_tally(&ballot1, &double_stake_weights)
             .unwrap();
         ballot_box
-            .tally_votes(double_stake_weights.stake_weight(), current_slot)
+            .tally_votes(
+                double_stake_weights.stake_weight(),
+                MAX_OPERATORS as u64,
+                current_slot,
+            )
             .unwrap();
         assert!(ballot_box.is_consensus_reached());
         assert_eq!(
@@ -1284,6 +2207,118 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolve_stalled_vote() {
+        let ncn = Pubkey::new_unique();
+        let epoch = 0;
+        let current_slot = 1000;
+        let epochs_before_stall = 3;
+        let total_stake_weight = 1000;
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
+
+        let ballot1 = Ballot::new(&[1; 32]);
+        let ballot2 = Ballot::new(&[2; 32]);
+        ballot_box
+            .increment_or_create_ballot_tally(&ballot1, &StakeWeights::new(600))
+            .unwrap();
+        ballot_box
+            .increment_or_create_ballot_tally(&ballot2, &StakeWeights::new(300))
+            .unwrap();
+
+        // Fallback disabled
+        assert_eq!(
+            ballot_box.resolve_stalled_vote(
+                total_stake_weight,
+                epoch + epochs_before_stall,
+                epochs_before_stall
+            ),
+            Err(TipRouterError::StallFallbackNotEnabled)
+        );
+
+        // Voting not stalled yet
+        ballot_box.set_stall_fallback_threshold_bps(5_500);
+        assert_eq!(
+            ballot_box.resolve_stalled_vote(total_stake_weight, epoch + 1, epochs_before_stall),
+            Err(TipRouterError::VotingNotFinalized)
+        );
+
+        // Leading ballot below the fallback threshold
+        ballot_box.set_stall_fallback_threshold_bps(6_100);
+        assert_eq!(
+            ballot_box.resolve_stalled_vote(
+                total_stake_weight,
+                epoch + epochs_before_stall,
+                epochs_before_stall
+            ),
+            Err(TipRouterError::StallFallbackThresholdNotMet)
+        );
+        assert!(!ballot_box.is_consensus_reached());
+
+        // Leading ballot clears the fallback threshold
+        ballot_box.set_stall_fallback_threshold_bps(6_000);
+        ballot_box
+            .resolve_stalled_vote(
+                total_stake_weight,
+                epoch + epochs_before_stall,
+                epochs_before_stall,
+            )
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert!(ballot_box.stall_fallback_used());
+        assert!(ballot_box.tie_breaker_set());
+        assert_eq!(
+            *ballot_box.get_winning_ballot_tally().unwrap().ballot(),
+            ballot1
+        );
+
+        // Cannot resolve twice
+        assert_eq!(
+            ballot_box.resolve_stalled_vote(
+                total_stake_weight,
+                epoch + epochs_before_stall,
+                epochs_before_stall
+            ),
+            Err(TipRouterError::ConsensusAlreadyReached)
+        );
+
+        // The tie breaker admin can replace the fallback result
+        ballot_box
+            .set_tie_breaker_ballot(
+                &ballot2.root(),
+                epoch + epochs_before_stall,
+                epochs_before_stall,
+            )
+            .unwrap();
+        assert!(!ballot_box.stall_fallback_used());
+        assert!(ballot_box.tie_breaker_set());
+        assert_eq!(
+            *ballot_box.get_winning_ballot_tally().unwrap().ballot(),
+            ballot2
+        );
+
+        // But not a tie breaker ballot
+        assert_eq!(
+            ballot_box.set_tie_breaker_ballot(
+                &ballot1.root(),
+                epoch + epochs_before_stall,
+                epochs_before_stall,
+            ),
+            Err(TipRouterError::ConsensusAlreadyReached)
+        );
+
+        // No votes at all
+        let mut ballot_box = BallotBox::new(&ncn, epoch, 0, current_slot);
+        ballot_box.set_stall_fallback_threshold_bps(5_500);
+        assert_eq!(
+            ballot_box.resolve_stalled_vote(
+                total_stake_weight,
+                epoch + epochs_before_stall,
+                epochs_before_stall
+            ),
+            Err(TipRouterError::StallFallbackThresholdNotMet)
+        );
+    }
+
     #[test]
     fn test_cast_vote_stake_weight_accounting() {
         let ncn = Pubkey::new_unique();
@@ -1470,7 +2505,9 @@ mod fuzz_tests {
 
                     // Periodically check consensus
                     if i % 10 == 0 {
-                        ballot_box.tally_votes(total_stake, slot).unwrap();
+                        ballot_box
+                            .tally_votes(total_stake, MAX_OPERATORS as u64, slot)
+                            .unwrap();
 
                         if ballot_box.is_consensus_reached() {
                             let winning_tally = ballot_box.get_winning_ballot_tally().unwrap();
@@ -2021,7 +3058,11 @@ mod zero_stake_tests {
         // Calculate consensus with only zero stake votes
         let total_stake = 1000u128;
         ballot_box
-            .tally_votes(total_stake, current_slot + num_zero_stake as u64)
+            .tally_votes(
+                total_stake,
+                MAX_OPERATORS as u64,
+                current_slot + num_zero_stake as u64,
+            )
             .unwrap();
         assert!(
             !ballot_box.is_consensus_reached(),
@@ -2057,7 +3098,11 @@ mod zero_stake_tests {
 
         // Check consensus again
         ballot_box
-            .tally_votes(total_stake, current_slot + num_zero_stake as u64 + 1)
+            .tally_votes(
+                total_stake,
+                MAX_OPERATORS as u64,
+                current_slot + num_zero_stake as u64 + 1,
+            )
             .unwrap();
         assert!(
             ballot_box.is_consensus_reached(),
@@ -2144,7 +3189,9 @@ mod zero_stake_tests {
 
         // Check consensus
         let total_stake = 1000u128;
-        ballot_box.tally_votes(total_stake, current_slot).unwrap();
+        ballot_box
+            .tally_votes(total_stake, MAX_OPERATORS as u64, current_slot)
+            .unwrap();
 
         // Neither ballot should have consensus yet
         assert!(!ballot_box.is_consensus_reached());
@@ -2162,9 +3209,228 @@ mod zero_stake_tests {
             )
             .unwrap();
 
-        ballot_box.tally_votes(total_stake, current_slot).unwrap();
+        ballot_box
+            .tally_votes(total_stake, MAX_OPERATORS as u64, current_slot)
+            .unwrap();
 
         assert!(ballot_box.is_consensus_reached());
         assert_eq!(ballot_box.get_winning_ballot().unwrap(), &ballot2);
     }
 }
+
+#[cfg(test)]
+mod commit_reveal_tests {
+    use super::*;
+
+    #[test]
+    fn test_commit_reveal_vote() {
+        let slot_created = 100;
+        let commit_window_slots = 50;
+        let reveal_window_slots = 50;
+        let valid_slots_after_consensus = 10;
+        let mut ballot_box = BallotBox::new(&Pubkey::new_unique(), 1, 0, slot_created);
+        ballot_box
+            .set_commit_reveal_windows(commit_window_slots, reveal_window_slots)
+            .unwrap();
+
+        assert!(ballot_box.is_commit_reveal());
+        assert!(ballot_box.is_commit_phase(slot_created));
+        assert!(ballot_box.is_reveal_phase(slot_created + commit_window_slots));
+
+        let operator = Pubkey::new_unique();
+        let root = [1; 32];
+        let salt = [7; 32];
+        let ballot = Ballot::new(&root);
+        let stake_weights = StakeWeights::new(1000);
+        let commitment = OperatorVote::commitment_hash(&root, &salt, &operator);
+
+        // Direct votes are rejected in commit-reveal mode
+        let result = ballot_box.cast_vote(
+            &operator,
+            &ballot,
+            &stake_weights,
+            slot_created,
+            valid_slots_after_consensus,
+        );
+        assert_eq!(result, Err(TipRouterError::CommitRevealRequired));
+
+        // Cannot reveal before committing or during the commit phase
+        let result = ballot_box.reveal_vote(
+            &operator,
+            &ballot,
+            &salt,
+            &stake_weights,
+            slot_created + 1,
+            valid_slots_after_consensus,
+        );
+        assert_eq!(result, Err(TipRouterError::RevealPhaseNotStarted));
+
+        ballot_box
+            .commit_vote(&operator, &commitment, slot_created + 1)
+            .unwrap();
+        assert_eq!(ballot_box.operators_committed(), 1);
+        assert_eq!(ballot_box.operators_voted(), 0);
+        assert_eq!(ballot_box.unique_ballots(), 0);
+
+        // Re-committing replaces the commitment without counting twice
+        ballot_box
+            .commit_vote(&operator, &commitment, slot_created + 2)
+            .unwrap();
+        assert_eq!(ballot_box.operators_committed(), 1);
+
+        // Commitments are closed once the commit phase ends
+        let late_operator = Pubkey::new_unique();
+        let result = ballot_box.commit_vote(
+            &late_operator,
+            &OperatorVote::commitment_hash(&root, &salt, &late_operator),
+            slot_created + commit_window_slots,
+        );
+        assert_eq!(result, Err(TipRouterError::CommitPhaseEnded));
+
+        let reveal_slot = slot_created + commit_window_slots;
+
+        // Wrong salt does not match the commitment
+        let result = ballot_box.reveal_vote(
+            &operator,
+            &ballot,
+            &[8; 32],
+            &stake_weights,
+            reveal_slot,
+            valid_slots_after_consensus,
+        );
+        assert_eq!(result, Err(TipRouterError::VoteCommitmentMismatch));
+
+        // Wrong root does not match the commitment
+        let result = ballot_box.reveal_vote(
+            &operator,
+            &Ballot::new(&[2; 32]),
+            &salt,
+            &stake_weights,
+            reveal_slot,
+            valid_slots_after_consensus,
+        );
+        assert_eq!(result, Err(TipRouterError::VoteCommitmentMismatch));
+
+        // Operators without a commitment cannot reveal
+        let result = ballot_box.reveal_vote(
+            &late_operator,
+            &ballot,
+            &salt,
+            &stake_weights,
+            reveal_slot,
+            valid_slots_after_consensus,
+        );
+        assert_eq!(result, Err(TipRouterError::VoteCommitmentNotFound));
+
+        ballot_box
+            .reveal_vote(
+                &operator,
+                &ballot,
+                &salt,
+                &stake_weights,
+                reveal_slot,
+                valid_slots_after_consensus,
+            )
+            .unwrap();
+        assert_eq!(ballot_box.operators_voted(), 1);
+        assert_eq!(ballot_box.unique_ballots(), 1);
+
+        let vote = ballot_box
+            .operator_votes()
+            .iter()
+            .find(|v| v.operator().eq(&operator))
+            .unwrap();
+        assert!(vote.is_revealed());
+        assert_eq!(vote.slot_voted(), reveal_slot);
+
+        let result = ballot_box.reveal_vote(
+            &operator,
+            &ballot,
+            &salt,
+            &stake_weights,
+            reveal_slot,
+            valid_slots_after_consensus,
+        );
+        assert_eq!(result, Err(TipRouterError::VoteAlreadyRevealed));
+
+        ballot_box
+            .tally_votes(1000, MAX_OPERATORS as u64, reveal_slot)
+            .unwrap();
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.get_winning_ballot().unwrap(), &ballot);
+    }
+
+    #[test]
+    fn test_unrevealed_commitments_are_not_tallied() {
+        let slot_created = 100;
+        let commit_window_slots = 50;
+        let reveal_window_slots = 50;
+        let valid_slots_after_consensus = 10;
+        let mut ballot_box = BallotBox::new(&Pubkey::new_unique(), 1, 0, slot_created);
+        ballot_box
+            .set_commit_reveal_windows(commit_window_slots, reveal_window_slots)
+            .unwrap();
+
+        let root = [1; 32];
+        let salt = [7; 32];
+        let ballot = Ballot::new(&root);
+        let stake_weights = StakeWeights::new(400);
+
+        let operators: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        for operator in operators.iter() {
+            let commitment = OperatorVote::commitment_hash(&root, &salt, operator);
+            ballot_box
+                .commit_vote(operator, &commitment, slot_created)
+                .unwrap();
+        }
+        assert_eq!(ballot_box.operators_committed(), 2);
+
+        // Only the first operator reveals
+        let reveal_slot = slot_created + commit_window_slots;
+        ballot_box
+            .reveal_vote(
+                &operators[0],
+                &ballot,
+                &salt,
+                &stake_weights,
+                reveal_slot,
+                valid_slots_after_consensus,
+            )
+            .unwrap();
+
+        ballot_box
+            .tally_votes(1000, MAX_OPERATORS as u64, reveal_slot)
+            .unwrap();
+        assert!(!ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.operators_voted(), 1);
+
+        let tally = ballot_box
+            .ballot_tallies()
+            .iter()
+            .find(|t| t.ballot().eq(&ballot))
+            .unwrap();
+        assert_eq!(tally.tally(), 1);
+        assert_eq!(tally.stake_weights().stake_weight(), 400);
+
+        // Reveals are closed once the reveal phase ends
+        let result = ballot_box.reveal_vote(
+            &operators[1],
+            &ballot,
+            &salt,
+            &stake_weights,
+            slot_created + commit_window_slots + reveal_window_slots,
+            valid_slots_after_consensus,
+        );
+        assert_eq!(result, Err(TipRouterError::RevealPhaseEnded));
+    }
+
+    #[test]
+    fn test_commit_vote_requires_commit_reveal() {
+        let mut ballot_box = BallotBox::new(&Pubkey::new_unique(), 1, 0, 100);
+        ballot_box.set_commit_reveal_windows(0, 100).unwrap();
+        assert!(!ballot_box.is_commit_reveal());
+
+        let result = ballot_box.commit_vote(&Pubkey::new_unique(), &[1; 32], 100);
+        assert_eq!(result, Err(TipRouterError::CommitRevealNotEnabled));
+    }
+}
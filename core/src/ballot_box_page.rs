@@ -0,0 +1,258 @@
+use core::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    ballot_box::OperatorVote,
+    constants::{MAX_OPERATORS, OPERATOR_VOTES_PER_BALLOT_BOX_PAGE},
+    discriminators::Discriminators,
+    error::TipRouterError,
+    loaders::check_load,
+};
+
+/// Extension of a ballot box holding the votes of operators whose NCN operator index is past
+/// `MAX_OPERATORS`. Page `n` holds the operators from
+/// `MAX_OPERATORS + n * OPERATOR_VOTES_PER_BALLOT_BOX_PAGE` onward, so an operator's vote is
+/// always in exactly one place.
+// PDA'd ["ballot_box_page", NCN, NCN_EPOCH_SLOT, PAGE]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct BallotBoxPage {
+    /// The NCN account this page is for
+    ncn: Pubkey,
+    /// The epoch this page is for
+    epoch: PodU64,
+    /// Index of the page within the ballot box
+    page: PodU64,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Reserved space
+    reserved: [u8; 127],
+    /// Operator votes
+    operator_votes: [OperatorVote; 32],
+}
+
+impl Discriminator for BallotBoxPage {
+    const DISCRIMINATOR: u8 = Discriminators::BallotBoxPage as u8;
+}
+
+impl BallotBoxPage {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(ncn: &Pubkey, epoch: u64, page: u64, bump: u8) -> Self {
+        Self {
+            ncn: *ncn,
+            epoch: PodU64::from(epoch),
+            page: PodU64::from(page),
+            bump,
+            reserved: [0; 127],
+            operator_votes: [OperatorVote::default(); OPERATOR_VOTES_PER_BALLOT_BOX_PAGE],
+        }
+    }
+
+    pub fn seeds(ncn: &Pubkey, epoch: u64, page: u64) -> Vec<Vec<u8>> {
+        vec![
+            b"ballot_box_page".to_vec(),
+            ncn.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+            page.to_le_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        page: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, epoch, page);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+        page: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ncn, epoch, page).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads any page of the ballot box, the page index is read from the account itself
+    pub fn load_any_page(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let page = {
+            let account_data = account.try_borrow_data()?;
+            Self::try_from_slice_unchecked(&account_data)?.page()
+        };
+
+        Self::load(program_id, account, ncn, epoch, page, expect_writable)
+    }
+
+    pub fn load_to_close(
+        program_id: &Pubkey,
+        account_to_close: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+    ) -> Result<(), ProgramError> {
+        Self::load_any_page(program_id, account_to_close, ncn, epoch, true)
+    }
+
+    /// Loads the page holding the vote of the operator at `ncn_operator_index`. The page is
+    /// required for operators past `MAX_OPERATORS` and rejected for the others.
+    pub fn load_for_operator<'a, 'info>(
+        program_id: &Pubkey,
+        account: Option<&'a AccountInfo<'info>>,
+        ncn: &Pubkey,
+        epoch: u64,
+        ncn_operator_index: u64,
+    ) -> Result<Option<&'a AccountInfo<'info>>, ProgramError> {
+        match (Self::page_for_operator(ncn_operator_index), account) {
+            (None, None) => Ok(None),
+            (Some(page), Some(account)) => {
+                Self::load(program_id, account, ncn, epoch, page, true)?;
+                Ok(Some(account))
+            }
+            (page, _) => {
+                msg!(
+                    "Operator {} votes in ballot box page {:?}",
+                    ncn_operator_index,
+                    page
+                );
+                Err(TipRouterError::InvalidBallotBoxPage.into())
+            }
+        }
+    }
+
+    /// The page holding the vote of an operator, `None` when it is kept in the ballot box
+    pub fn page_for_operator(ncn_operator_index: u64) -> Option<u64> {
+        ncn_operator_index
+            .checked_sub(MAX_OPERATORS as u64)
+            .map(|index| index / OPERATOR_VOTES_PER_BALLOT_BOX_PAGE as u64)
+    }
+
+    /// Number of pages needed to hold the votes of `operator_count` operators
+    pub fn pages_needed(operator_count: u64) -> u64 {
+        operator_count
+            .saturating_sub(MAX_OPERATORS as u64)
+            .div_ceil(OPERATOR_VOTES_PER_BALLOT_BOX_PAGE as u64)
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn page(&self) -> u64 {
+        self.page.into()
+    }
+
+    pub const fn operator_votes(&self) -> &[OperatorVote; OPERATOR_VOTES_PER_BALLOT_BOX_PAGE] {
+        &self.operator_votes
+    }
+
+    pub(crate) fn operator_votes_mut(&mut self) -> &mut [OperatorVote] {
+        &mut self.operator_votes
+    }
+
+    pub fn operators_voted(&self) -> u64 {
+        self.operator_votes
+            .iter()
+            .filter(|vote| vote.is_revealed())
+            .count() as u64
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for BallotBoxPage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Ballot Box Page -------------")?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Epoch:                        {}", self.epoch())?;
+        writeln!(f, "  Page:                         {}", self.page())?;
+        writeln!(f, "  Operators Voted:              {}", self.operators_voted())?;
+
+        writeln!(f, "\nOperator Votes:")?;
+        for vote in self.operator_votes().iter() {
+            if !vote.is_empty() {
+                writeln!(f, "  Operator:                     {}", vote.operator())?;
+                writeln!(f, "    Revealed:                   {}", vote.is_revealed())?;
+                if vote.is_revealed() {
+                    writeln!(f, "    Slot Voted:                 {}", vote.slot_voted())?;
+                    writeln!(f, "    Ballot Index:               {}", vote.ballot_index())?;
+                }
+            }
+        }
+
+        writeln!(f, "\n")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MAX_REALLOC_BYTES;
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // ncn
+            + size_of::<PodU64>() // epoch
+            + size_of::<PodU64>() // page
+            + 1 // bump
+            + 127 // reserved
+            + size_of::<OperatorVote>() * OPERATOR_VOTES_PER_BALLOT_BOX_PAGE; // operator_votes
+
+        assert_eq!(size_of::<BallotBoxPage>(), expected_total);
+
+        // Pages are created in a single instruction
+        assert!(BallotBoxPage::SIZE <= MAX_REALLOC_BYTES as usize);
+    }
+
+    #[test]
+    fn test_page_for_operator() {
+        let per_page = OPERATOR_VOTES_PER_BALLOT_BOX_PAGE as u64;
+        let max_operators = MAX_OPERATORS as u64;
+
+        assert_eq!(BallotBoxPage::page_for_operator(0), None);
+        assert_eq!(BallotBoxPage::page_for_operator(max_operators - 1), None);
+        assert_eq!(BallotBoxPage::page_for_operator(max_operators), Some(0));
+        assert_eq!(
+            BallotBoxPage::page_for_operator(max_operators + per_page - 1),
+            Some(0)
+        );
+        assert_eq!(
+            BallotBoxPage::page_for_operator(max_operators + per_page),
+            Some(1)
+        );
+
+        assert_eq!(BallotBoxPage::pages_needed(0), 0);
+        assert_eq!(BallotBoxPage::pages_needed(max_operators), 0);
+        assert_eq!(BallotBoxPage::pages_needed(max_operators + 1), 1);
+        assert_eq!(BallotBoxPage::pages_needed(max_operators + per_page), 1);
+        assert_eq!(BallotBoxPage::pages_needed(max_operators + per_page + 1), 2);
+    }
+}
@@ -1,7 +1,8 @@
 use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::types::PodBool;
 use shank::ShankType;
 
-use crate::error::TipRouterError;
+use crate::{error::TipRouterError, payout_preference::PayoutAsset};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -102,6 +103,66 @@ impl BaseFeeGroup {
     }
 }
 
+/// How a base fee group is used, its fee and wallet are kept in the `FeeConfig`
+#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
+#[repr(C)]
+pub struct BaseFeeGroupSettings {
+    /// Name of the group, UTF-8 padded with zeros
+    name: [u8; 32],
+    /// Only enabled groups can be charged a fee
+    is_enabled: PodBool,
+    /// The `PayoutAsset` the group's rewards are paid out in
+    payout_asset: u8,
+    /// Reserved space
+    reserved: [u8; 30],
+}
+
+impl BaseFeeGroupSettings {
+    pub fn new(name: &str) -> Self {
+        let mut settings = Self::zeroed();
+        settings.set_name(&Self::name_bytes(name));
+        settings.set_is_enabled(true);
+        settings
+    }
+
+    /// Pads `name` with zeros, cutting it at 32 bytes
+    pub fn name_bytes(name: &str) -> [u8; 32] {
+        let mut name_bytes = [0; 32];
+        let len = name.len().min(name_bytes.len());
+        name_bytes[..len].copy_from_slice(&name.as_bytes()[..len]);
+        name_bytes
+    }
+
+    pub fn name(&self) -> String {
+        let len = self
+            .name
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(self.name.len());
+        String::from_utf8_lossy(&self.name[..len]).into_owned()
+    }
+
+    pub fn set_name(&mut self, name: &[u8; 32]) {
+        self.name = *name;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled.into()
+    }
+
+    pub fn set_is_enabled(&mut self, is_enabled: bool) {
+        self.is_enabled = PodBool::from(is_enabled);
+    }
+
+    pub fn payout_asset(&self) -> Result<PayoutAsset, TipRouterError> {
+        PayoutAsset::try_from(self.payout_asset)
+    }
+
+    pub fn set_payout_asset(&mut self, payout_asset: PayoutAsset) {
+        self.payout_asset = payout_asset as u8;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,6 +305,27 @@ mod tests {
         assert_eq!(all_groups, expected_groups);
     }
 
+    #[test]
+    fn test_base_fee_group_settings() {
+        assert_eq!(size_of::<BaseFeeGroupSettings>(), 64);
+
+        let mut settings = BaseFeeGroupSettings::new("Treasury");
+        assert_eq!(settings.name(), "Treasury");
+        assert!(settings.is_enabled());
+        assert_eq!(settings.payout_asset().unwrap(), PayoutAsset::JitoSol);
+
+        settings.set_name(&BaseFeeGroupSettings::name_bytes(
+            "A name that is longer than thirty two bytes",
+        ));
+        assert_eq!(settings.name(), "A name that is longer than thirt");
+
+        settings.set_payout_asset(PayoutAsset::Sol);
+        assert_eq!(settings.payout_asset().unwrap(), PayoutAsset::Sol);
+
+        assert_eq!(BaseFeeGroupSettings::zeroed().name(), "");
+        assert!(!BaseFeeGroupSettings::zeroed().is_enabled());
+    }
+
     #[test]
     fn test_fee_group_count_constant() {
         // Verify FEE_GROUP_COUNT matches number of enum variants
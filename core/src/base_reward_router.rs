@@ -1,4 +1,7 @@
-use core::{fmt, mem::size_of};
+use core::{
+    fmt,
+    mem::{offset_of, size_of},
+};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
@@ -12,11 +15,20 @@ use solana_program::{
 };
 use solana_system_interface::instruction as system_instruction;
 use solana_system_interface::program as system_program;
+use spl_associated_token_account_interface::address::get_associated_token_address;
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
-    ballot_box::BallotBox, base_fee_group::BaseFeeGroup, constants::MAX_OPERATORS,
-    discriminators::Discriminators, error::TipRouterError, fees::Fees, loaders::check_load,
+    account_version::{check_account_version, VersionedAccount},
+    ballot_box::{BallotBox, OperatorVote},
+    ballot_box_page::BallotBoxPage,
+    base_fee_group::BaseFeeGroup,
+    base_reward_router_page::BaseRewardRouterPage,
+    constants::{MAX_OPERATORS, OPERATOR_VOTES_PER_BALLOT_BOX_PAGE},
+    discriminators::Discriminators,
+    error::TipRouterError,
+    fees::Fees,
+    loaders::check_load,
     ncn_fee_group::NcnFeeGroup,
 };
 
@@ -38,8 +50,16 @@ pub struct BaseRewardRouter {
     reward_pool: PodU64,
     /// Amount of rewards processed ( in lamports )
     rewards_processed: PodU64,
+    /// SPL token mint of the rewards routed, the default pubkey when routing lamports
+    mint: Pubkey,
+    /// Rewards carried forward into a later epoch's base reward receiver ( in lamports )
+    rewards_carried_forward: PodU64,
+    /// Rewards picked up by the last routing round ( in lamports )
+    last_rewards_routed: PodU64,
+    /// Number of base reward router pages, routing the votes kept in ballot box pages
+    page_count: PodU64,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 72],
 
     // route state tracking - to recover from unfinished routing
     /// Last NCN fee group index
@@ -61,6 +81,11 @@ impl Discriminator for BaseRewardRouter {
     const DISCRIMINATOR: u8 = Discriminators::BaseRewardRouter as u8;
 }
 
+impl VersionedAccount for BaseRewardRouter {
+    const ACCOUNT_VERSION: u8 = 1;
+    const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+}
+
 impl BaseRewardRouter {
     pub const SIZE: usize = 8 + size_of::<Self>();
 
@@ -78,7 +103,11 @@ impl BaseRewardRouter {
             total_rewards: PodU64::from(0),
             reward_pool: PodU64::from(0),
             rewards_processed: PodU64::from(0),
-            reserved: [0; 128],
+            mint: Pubkey::default(),
+            rewards_carried_forward: PodU64::from(0),
+            last_rewards_routed: PodU64::from(0),
+            page_count: PodU64::from(0),
+            reserved: [0; 72],
             last_ncn_group_index: Self::NO_LAST_NCN_GROUP_INDEX,
             last_vote_index: PodU16::from(Self::NO_LAST_VOTE_INDEX),
             last_rewards_to_process: PodU64::from(Self::NO_LAST_REWARDS_TO_PROCESS),
@@ -90,7 +119,14 @@ impl BaseRewardRouter {
         }
     }
 
-    pub fn initialize(&mut self, ncn: &Pubkey, ncn_epoch: u64, bump: u8, current_slot: u64) {
+    pub fn initialize(
+        &mut self,
+        ncn: &Pubkey,
+        mint: &Pubkey,
+        ncn_epoch: u64,
+        bump: u8,
+        current_slot: u64,
+    ) {
         // Initializes field by field to avoid overflowing stack
         self.ncn = *ncn;
         self.epoch = PodU64::from(ncn_epoch);
@@ -99,7 +135,11 @@ impl BaseRewardRouter {
         self.total_rewards = PodU64::from(0);
         self.reward_pool = PodU64::from(0);
         self.rewards_processed = PodU64::from(0);
-        self.reserved = [0; 128];
+        self.mint = *mint;
+        self.rewards_carried_forward = PodU64::from(0);
+        self.last_rewards_routed = PodU64::from(0);
+        self.page_count = PodU64::from(0);
+        self.reserved = [0; 72];
         self.base_fee_group_rewards =
             [BaseRewardRouterRewards::default(); NcnFeeGroup::FEE_GROUP_COUNT];
         self.ncn_fee_group_rewards =
@@ -110,15 +150,22 @@ impl BaseRewardRouter {
     }
 
     pub fn seeds(ncn: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
-        Vec::from_iter(
-            [
-                b"base_reward_router".to_vec(),
-                ncn.to_bytes().to_vec(),
-                ncn_epoch.to_le_bytes().to_vec(),
-            ]
-            .iter()
-            .cloned(),
-        )
+        Self::seeds_for_mint(ncn, &Pubkey::default(), ncn_epoch)
+    }
+
+    /// Token routers are seeded with their mint, the lamport router keeps the original seeds
+    pub fn seeds_for_mint(ncn: &Pubkey, mint: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
+        let mut seeds = vec![
+            b"base_reward_router".to_vec(),
+            ncn.to_bytes().to_vec(),
+            ncn_epoch.to_le_bytes().to_vec(),
+        ];
+
+        if mint.ne(&Pubkey::default()) {
+            seeds.push(mint.to_bytes().to_vec());
+        }
+
+        seeds
     }
 
     pub fn find_program_address(
@@ -126,7 +173,16 @@ impl BaseRewardRouter {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds: Vec<Vec<u8>> = Self::seeds(ncn, epoch);
+        Self::find_program_address_for_mint(program_id, ncn, &Pubkey::default(), epoch)
+    }
+
+    pub fn find_program_address_for_mint(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        mint: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds: Vec<Vec<u8>> = Self::seeds_for_mint(ncn, mint, epoch);
         let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
         let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
         (pda, bump, seeds)
@@ -139,14 +195,33 @@ impl BaseRewardRouter {
         epoch: u64,
         expect_writable: bool,
     ) -> Result<(), ProgramError> {
-        let expected_pda = Self::find_program_address(program_id, ncn, epoch).0;
+        Self::load_for_mint(
+            program_id,
+            account,
+            ncn,
+            &Pubkey::default(),
+            epoch,
+            expect_writable,
+        )
+    }
+
+    pub fn load_for_mint(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        mint: &Pubkey,
+        epoch: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address_for_mint(program_id, ncn, mint, epoch).0;
         check_load(
             program_id,
             account,
             &expected_pda,
             Some(Self::DISCRIMINATOR),
             expect_writable,
-        )
+        )?;
+        check_account_version::<Self>(account)
     }
 
     pub fn load_to_close(
@@ -155,7 +230,55 @@ impl BaseRewardRouter {
         ncn: &Pubkey,
         epoch: u64,
     ) -> Result<(), ProgramError> {
-        Self::load(program_id, account_to_close, ncn, epoch, true)
+        let account_data = account_to_close.try_borrow_data()?;
+        let account_struct = Self::try_from_slice_unchecked(&account_data)?;
+        let mint = *account_struct.mint();
+
+        Self::load_for_mint(program_id, account_to_close, ncn, &mint, epoch, true)
+    }
+
+    // ----------------- PAGES -----------------------------
+    pub fn page_count(&self) -> u64 {
+        self.page_count.into()
+    }
+
+    /// Records a new page, pages are created in order
+    pub fn add_page(&mut self, page: u64) -> Result<(), TipRouterError> {
+        if page != self.page_count() {
+            return Err(TipRouterError::InvalidBaseRewardRouterPage);
+        }
+
+        self.page_count = PodU64::from(
+            page.checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Records a closed page, pages are closed from the last one and the router can only be
+    /// closed once all of them are
+    pub fn remove_page(&mut self, page: u64) -> Result<(), TipRouterError> {
+        if page.checked_add(1) != Some(self.page_count()) {
+            return Err(TipRouterError::InvalidBaseRewardRouterPage);
+        }
+
+        self.page_count = PodU64::from(page);
+
+        Ok(())
+    }
+
+    /// Checks that the page extends this router
+    fn check_page(&self, page: &BaseRewardRouterPage) -> Result<(), TipRouterError> {
+        if page.ncn().ne(&self.ncn)
+            || page.epoch() != self.epoch()
+            || page.mint().ne(&self.mint)
+            || page.page() >= self.page_count()
+        {
+            return Err(TipRouterError::InvalidBaseRewardRouterPage);
+        }
+
+        Ok(())
     }
 
     // ----------------- ROUTE STATE TRACKING --------------
@@ -223,6 +346,7 @@ impl BaseRewardRouter {
             .ok_or(TipRouterError::ArithmeticUnderflowError)?;
 
         self.route_to_reward_pool(rewards_to_route)?;
+        self.last_rewards_routed = PodU64::from(rewards_to_route);
 
         Ok(())
     }
@@ -270,6 +394,59 @@ impl BaseRewardRouter {
         ballot_box: &BallotBox,
         max_iterations: u16,
     ) -> Result<(), TipRouterError> {
+        self.route_ncn_fee_group_rewards_with_pages(ballot_box, &[], &mut [], max_iterations)
+    }
+
+    /// `route_ncn_fee_group_rewards` for a ballot box with pages. Every ballot box page and
+    /// base reward router page has to be passed, in order, so that no winning vote is skipped.
+    /// Votes are indexed past the ballot box's `MAX_OPERATORS` votes into its pages.
+    pub fn route_ncn_fee_group_rewards_with_pages(
+        &mut self,
+        ballot_box: &BallotBox,
+        ballot_box_pages: &[&BallotBoxPage],
+        router_pages: &mut [&mut BaseRewardRouterPage],
+        max_iterations: u16,
+    ) -> Result<(), TipRouterError> {
+        if ballot_box_pages.len() as u64 != ballot_box.page_count() {
+            msg!(
+                "Expected {} ballot box pages, got {}",
+                ballot_box.page_count(),
+                ballot_box_pages.len()
+            );
+            return Err(TipRouterError::InvalidBallotBoxPage);
+        }
+        for (index, page) in ballot_box_pages.iter().enumerate() {
+            ballot_box.check_page(Some(*page))?;
+            if page.page() != index as u64 {
+                return Err(TipRouterError::InvalidBallotBoxPage);
+            }
+        }
+
+        if router_pages.len() != ballot_box_pages.len() {
+            msg!(
+                "Expected {} base reward router pages, got {}",
+                ballot_box_pages.len(),
+                router_pages.len()
+            );
+            return Err(TipRouterError::InvalidBaseRewardRouterPage);
+        }
+        for (index, page) in router_pages.iter().enumerate() {
+            self.check_page(page)?;
+            if page.page() != index as u64 {
+                return Err(TipRouterError::InvalidBaseRewardRouterPage);
+            }
+        }
+
+        let vote_count =
+            MAX_OPERATORS + ballot_box_pages.len() * OPERATOR_VOTES_PER_BALLOT_BOX_PAGE;
+        let vote_at = |vote_index: usize| -> OperatorVote {
+            match vote_index.checked_sub(MAX_OPERATORS) {
+                None => ballot_box.operator_votes()[vote_index],
+                Some(index) => ballot_box_pages[index / OPERATOR_VOTES_PER_BALLOT_BOX_PAGE]
+                    .operator_votes()[index % OPERATOR_VOTES_PER_BALLOT_BOX_PAGE],
+            }
+        };
+
         let winning_ballot = ballot_box.get_winning_ballot_tally()?;
         let winning_stake_weight = winning_ballot.stake_weights();
 
@@ -295,8 +472,8 @@ impl BaseRewardRouter {
                 continue;
             }
 
-            for vote_index in starting_vote_index..ballot_box.operator_votes().len() {
-                let votes = ballot_box.operator_votes()[vote_index];
+            for vote_index in starting_vote_index..vote_count {
+                let votes = vote_at(vote_index);
 
                 if votes.ballot_index() == winning_ballot.index() {
                     // Update iteration state
@@ -330,11 +507,19 @@ impl BaseRewardRouter {
                     )?;
 
                     self.route_from_ncn_fee_group_rewards(group, ncn_fee_group_route_reward)?;
-                    self.route_to_ncn_fee_group_reward_route(
-                        group,
-                        operator,
-                        ncn_fee_group_route_reward,
-                    )?;
+                    match vote_index.checked_sub(MAX_OPERATORS) {
+                        None => self.route_to_ncn_fee_group_reward_route(
+                            group,
+                            operator,
+                            ncn_fee_group_route_reward,
+                        )?,
+                        Some(index) => router_pages[index / OPERATOR_VOTES_PER_BALLOT_BOX_PAGE]
+                            .route_to_ncn_fee_group_reward_route(
+                                group,
+                                operator,
+                                ncn_fee_group_route_reward,
+                            )?,
+                    }
                 }
             }
 
@@ -462,6 +647,15 @@ impl BaseRewardRouter {
         &self.ncn
     }
 
+    pub const fn mint(&self) -> &Pubkey {
+        &self.mint
+    }
+
+    /// Whether the router routes SPL token rewards instead of lamports
+    pub fn is_token_router(&self) -> bool {
+        self.mint.ne(&Pubkey::default())
+    }
+
     pub fn epoch(&self) -> u64 {
         self.epoch.into()
     }
@@ -470,6 +664,49 @@ impl BaseRewardRouter {
         self.slot_created.into()
     }
 
+    pub fn rewards_carried_forward(&self) -> u64 {
+        self.rewards_carried_forward.into()
+    }
+
+    pub fn last_rewards_routed(&self) -> u64 {
+        self.last_rewards_routed.into()
+    }
+
+    /// Returns the part of `receiver_rewards`, the receiver's balance above rent, that was never
+    /// routed into a tally. Rewards in transit stay behind and can still be distributed.
+    pub fn carry_forward_rewards(&mut self, receiver_rewards: u64) -> Result<u64, TipRouterError> {
+        if self.still_routing() {
+            msg!("Cannot carry rewards forward while routing");
+            return Err(TipRouterError::CannotCarryForwardRewards);
+        }
+
+        let rewards_in_transit = self.total_rewards_in_transit()?;
+        if rewards_in_transit > receiver_rewards {
+            msg!("Reward receiver holds less than the rewards in transit");
+            return Err(TipRouterError::ArithmeticUnderflowError);
+        }
+
+        let rewards = receiver_rewards
+            .checked_sub(rewards_in_transit)
+            .ok_or(TipRouterError::ArithmeticUnderflowError)?;
+
+        self.increment_rewards_carried_forward(rewards)?;
+
+        Ok(rewards)
+    }
+
+    pub fn increment_rewards_carried_forward(
+        &mut self,
+        rewards: u64,
+    ) -> Result<(), TipRouterError> {
+        self.rewards_carried_forward = PodU64::from(
+            self.rewards_carried_forward()
+                .checked_add(rewards)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        Ok(())
+    }
+
     pub fn route_to_reward_pool(&mut self, rewards: u64) -> Result<(), TipRouterError> {
         if rewards == 0 {
             return Ok(());
@@ -661,25 +898,12 @@ impl BaseRewardRouter {
         operator: &Pubkey,
         rewards: u64,
     ) -> Result<(), TipRouterError> {
-        if rewards == 0 {
-            return Ok(());
-        }
-
-        for ncn_route_reward in self.ncn_fee_group_reward_routes.iter_mut() {
-            if ncn_route_reward.operator.eq(operator) {
-                ncn_route_reward.increment_rewards(ncn_fee_group, rewards)?;
-                return Ok(());
-            }
-        }
-
-        for ncn_route_reward in self.ncn_fee_group_reward_routes.iter_mut() {
-            if ncn_route_reward.operator.eq(&Pubkey::default()) {
-                *ncn_route_reward = NcnRewardRoute::new(operator, ncn_fee_group, rewards)?;
-                return Ok(());
-            }
-        }
-
-        Err(TipRouterError::OperatorRewardListFull)
+        NcnRewardRoute::route_to(
+            &mut self.ncn_fee_group_reward_routes,
+            ncn_fee_group,
+            operator,
+            rewards,
+        )
     }
 
     pub fn distribute_ncn_fee_group_reward_route(
@@ -687,17 +911,31 @@ impl BaseRewardRouter {
         ncn_fee_group: NcnFeeGroup,
         operator: &Pubkey,
     ) -> Result<u64, TipRouterError> {
-        for route in self.ncn_fee_group_reward_routes.iter_mut() {
-            if route.operator.eq(operator) {
-                let rewards = route.rewards(ncn_fee_group)?;
-                route.decrement_rewards(ncn_fee_group, rewards)?;
-                self.decrement_rewards_processed(rewards)?;
+        self.distribute_ncn_fee_group_reward_route_with_page(None, ncn_fee_group, operator)
+    }
 
-                return Ok(rewards);
+    /// `distribute_ncn_fee_group_reward_route` for an operator routed in a base reward router
+    /// page
+    pub fn distribute_ncn_fee_group_reward_route_with_page(
+        &mut self,
+        page: Option<&mut BaseRewardRouterPage>,
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+    ) -> Result<u64, TipRouterError> {
+        let rewards = match page {
+            Some(page) => {
+                self.check_page(page)?;
+                page.distribute_ncn_fee_group_reward_route(ncn_fee_group, operator)?
             }
-        }
+            None => NcnRewardRoute::distribute_from(
+                &mut self.ncn_fee_group_reward_routes,
+                ncn_fee_group,
+                operator,
+            )?,
+        };
+        self.decrement_rewards_processed(rewards)?;
 
-        Err(TipRouterError::OperatorRewardNotFound)
+        Ok(rewards)
     }
 }
 
@@ -706,6 +944,9 @@ impl fmt::Display for BaseRewardRouter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "\n\n----------- Base Reward Router -------------")?;
         writeln!(f, "  NCN:                          {}", self.ncn)?;
+        if self.is_token_router() {
+            writeln!(f, "  Mint:                         {}", self.mint)?;
+        }
         writeln!(f, "  Epoch:                        {}", self.epoch())?;
         writeln!(f, "  Bump:                         {}", self.bump)?;
         writeln!(f, "  Slot Created:                 {}", self.slot_created())?;
@@ -713,6 +954,10 @@ impl fmt::Display for BaseRewardRouter {
         writeln!(f, "  Total Rewards:                {}", self.total_rewards())?;
         writeln!(f, "  Reward Pool:                  {}", self.reward_pool())?;
         writeln!(f, "  Rewards Processed:            {}", self.rewards_processed())?;
+        writeln!(f, "  Rewards Carried Forward:      {}", self.rewards_carried_forward())?;
+        if self.page_count() > 0 {
+            writeln!(f, "  Page Count:                   {}", self.page_count())?;
+        }
 
         if self.still_routing() {
             writeln!(f, "\nRouting State:")?;
@@ -856,6 +1101,52 @@ impl NcnRewardRoute {
 
         self.set_rewards(ncn_fee_group, new_rewards)
     }
+
+    /// Adds `rewards` to the operator's route, taking the first empty route if it has none
+    pub(crate) fn route_to(
+        routes: &mut [Self],
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+        rewards: u64,
+    ) -> Result<(), TipRouterError> {
+        if rewards == 0 {
+            return Ok(());
+        }
+
+        for route in routes.iter_mut() {
+            if route.operator.eq(operator) {
+                route.increment_rewards(ncn_fee_group, rewards)?;
+                return Ok(());
+            }
+        }
+
+        for route in routes.iter_mut() {
+            if route.operator.eq(&Pubkey::default()) {
+                *route = Self::new(operator, ncn_fee_group, rewards)?;
+                return Ok(());
+            }
+        }
+
+        Err(TipRouterError::OperatorRewardListFull)
+    }
+
+    /// Takes all of the operator's rewards of `ncn_fee_group` out of its route
+    pub(crate) fn distribute_from(
+        routes: &mut [Self],
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+    ) -> Result<u64, TipRouterError> {
+        for route in routes.iter_mut() {
+            if route.operator.eq(operator) {
+                let rewards = route.rewards(ncn_fee_group)?;
+                route.decrement_rewards(ncn_fee_group, rewards)?;
+
+                return Ok(rewards);
+            }
+        }
+
+        Err(TipRouterError::OperatorRewardNotFound)
+    }
 }
 
 /// Uninitiatilized, no-data account used to hold SOL for routing rewards to BaseRewardRouter
@@ -976,6 +1267,116 @@ impl BaseRewardReceiver {
         )?;
         Ok(())
     }
+
+    /// The receiver's ATA, which holds the rewards of a token router
+    pub fn token_account(program_id: &Pubkey, ncn: &Pubkey, epoch: u64, mint: &Pubkey) -> Pubkey {
+        let base_reward_receiver = Self::find_program_address(program_id, ncn, epoch).0;
+        get_associated_token_address(&base_reward_receiver, mint)
+    }
+
+    /// Sends any tokens left in the receiver's token account to the DAO wallet's ATA and closes
+    /// it, returning its rent to the account payer
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn close_token_account<'a, 'info>(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        base_reward_receiver: &'a AccountInfo<'info>,
+        token_account: &'a AccountInfo<'info>,
+        dao_wallet_token_account: &'a AccountInfo<'info>,
+        account_payer: &'a AccountInfo<'info>,
+        token_program: &'a AccountInfo<'info>,
+        amount: u64,
+    ) -> ProgramResult {
+        if amount > 0 {
+            Self::transfer_tokens(
+                program_id,
+                ncn,
+                epoch,
+                base_reward_receiver,
+                token_account,
+                dao_wallet_token_account,
+                token_program,
+                amount,
+            )?;
+        }
+
+        let seeds = Self::signer_seeds(program_id, ncn, epoch, base_reward_receiver)?;
+        invoke_signed(
+            &spl_token_interface::instruction::close_account(
+                token_program.key,
+                token_account.key,
+                account_payer.key,
+                base_reward_receiver.key,
+                &[],
+            )?,
+            &[
+                token_account.clone(),
+                account_payer.clone(),
+                base_reward_receiver.clone(),
+                token_program.clone(),
+            ],
+            &[seeds
+                .iter()
+                .map(|seed| seed.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_slice()],
+        )
+    }
+
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer_tokens<'a, 'info>(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        base_reward_receiver: &'a AccountInfo<'info>,
+        from: &'a AccountInfo<'info>,
+        to: &'a AccountInfo<'info>,
+        token_program: &'a AccountInfo<'info>,
+        amount: u64,
+    ) -> ProgramResult {
+        let seeds = Self::signer_seeds(program_id, ncn, epoch, base_reward_receiver)?;
+        invoke_signed(
+            &spl_token_interface::instruction::transfer(
+                token_program.key,
+                from.key,
+                to.key,
+                base_reward_receiver.key,
+                &[],
+                amount,
+            )?,
+            &[
+                from.clone(),
+                to.clone(),
+                base_reward_receiver.clone(),
+                token_program.clone(),
+            ],
+            &[seeds
+                .iter()
+                .map(|seed| seed.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_slice()],
+        )
+    }
+
+    fn signer_seeds(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        base_reward_receiver: &AccountInfo,
+    ) -> Result<Vec<Vec<u8>>, ProgramError> {
+        let (address, bump, mut seeds) = Self::find_program_address(program_id, ncn, epoch);
+        seeds.push(vec![bump]);
+
+        if address.ne(base_reward_receiver.key) {
+            msg!("Incorrect base reward receiver PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(seeds)
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Copy, Zeroable, ShankType, Pod)]
@@ -1062,7 +1463,11 @@ mod tests {
             + size_of::<PodU64>() // total_rewards
             + size_of::<PodU64>() // reward_pool
             + size_of::<PodU64>() // rewards_processed
-            + 128 // reserved
+            + size_of::<Pubkey>() // mint
+            + size_of::<PodU64>() // rewards_carried_forward
+            + size_of::<PodU64>() // last_rewards_routed
+            + size_of::<PodU64>() // page_count
+            + 72 // reserved
             + 1 // last_ncn_group_index
             + size_of::<PodU16>() // last_vote_index
             + size_of::<PodU64>() // last_rewards_to_process
@@ -1209,6 +1614,7 @@ mod tests {
         assert_eq!(router.total_rewards(), 1000);
         assert_eq!(router.reward_pool(), 1000);
         assert_eq!(router.rewards_processed(), 0);
+        assert_eq!(router.last_rewards_routed(), 1000);
 
         // Test routing additional 500 lamports
         let account_balance = 1500;
@@ -1218,6 +1624,8 @@ mod tests {
         assert_eq!(router.total_rewards(), 1500);
         assert_eq!(router.reward_pool(), 1500);
         assert_eq!(router.rewards_processed(), 0);
+        // Only the new rewards count towards the crank bounty
+        assert_eq!(router.last_rewards_routed(), 500);
 
         // Test attempting to route with lower balance (should fail)
         let result = router.route_incoming_rewards(0, 1000);
@@ -1261,6 +1669,68 @@ mod tests {
         assert_eq!(router.ncn_fee_group_rewards(ncn_group).unwrap(), 100);
     }
 
+    #[test]
+    fn test_carry_forward_rewards() {
+        let mut router = BaseRewardRouter::new(
+            &Pubkey::new_unique(), // ncn
+            1,                     // ncn_epoch
+            1,                     // bump
+            100,                   // slot_created
+        );
+
+        let operator = Pubkey::new_unique();
+        let ncn_group = NcnFeeGroup::default();
+        let fees = Fees::new(900, 100, 1).unwrap();
+
+        router.route_incoming_rewards(0, 1000).unwrap();
+        router.route_reward_pool(&fees).unwrap();
+        router
+            .route_from_ncn_fee_group_rewards(ncn_group, 100)
+            .unwrap();
+        router
+            .route_to_ncn_fee_group_reward_route(ncn_group, &operator, 100)
+            .unwrap();
+
+        // Only the NCN fee group reward route is left undistributed
+        router
+            .distribute_base_fee_group_rewards(BaseFeeGroup::default())
+            .unwrap();
+        assert_eq!(router.total_rewards_in_transit().unwrap(), 100);
+
+        // Only lamports the receiver holds above the rewards in transit are carried forward
+        assert_eq!(router.carry_forward_rewards(150).unwrap(), 50);
+        assert_eq!(router.rewards_carried_forward(), 50);
+        assert_eq!(
+            router.carry_forward_rewards(99),
+            Err(TipRouterError::ArithmeticUnderflowError)
+        );
+
+        // The assigned rewards stay distributable
+        assert_eq!(router.total_rewards_in_transit().unwrap(), 100);
+        assert!(router
+            .ncn_fee_group_reward_route(&operator)
+            .unwrap()
+            .has_rewards()
+            .unwrap());
+        assert_eq!(
+            router
+                .distribute_ncn_fee_group_reward_route(ncn_group, &operator)
+                .unwrap(),
+            100
+        );
+        assert_eq!(router.total_rewards_in_transit().unwrap(), 0);
+
+        // The total routed is kept for the epoch's records
+        assert_eq!(router.total_rewards(), 1000);
+
+        // Can't carry forward mid routing
+        router.save_routing_state(0, 0, 100);
+        assert_eq!(
+            router.carry_forward_rewards(0),
+            Err(TipRouterError::CannotCarryForwardRewards)
+        );
+    }
+
     #[test]
     fn test_route_reward_pool_multiple_groups() {
         const INCOMING_REWARDS: u64 = 1600;
@@ -1449,7 +1919,11 @@ mod tests {
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(
+                    total_stake_weights.stake_weight(),
+                    MAX_OPERATORS as u64,
+                    TEST_CURRENT_SLOT,
+                )
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
@@ -1526,7 +2000,11 @@ mod tests {
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(
+                    total_stake_weights.stake_weight(),
+                    MAX_OPERATORS as u64,
+                    TEST_CURRENT_SLOT,
+                )
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
@@ -1592,7 +2070,11 @@ mod tests {
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(
+                    total_stake_weights.stake_weight(),
+                    MAX_OPERATORS as u64,
+                    TEST_CURRENT_SLOT,
+                )
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
@@ -1616,6 +2098,111 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_route_to_operators_in_pages() {
+        const INCOMING_REWARDS: u64 = 257_000;
+
+        let mut ballot_box = get_test_ballot_box();
+        let ncn = *ballot_box.ncn();
+        let mut router = BaseRewardRouter::new(&ncn, TEST_EPOCH, 1, 100);
+
+        let fees = Fees::new(0, 100, 1).unwrap();
+        router.route_incoming_rewards(0, INCOMING_REWARDS).unwrap();
+        router.route_reward_pool(&fees).unwrap();
+
+        for _ in 0..MAX_OPERATORS {
+            cast_test_vote(&mut ballot_box, NcnFeeGroup::default(), 200, 1, 1);
+        }
+
+        // The operator at index MAX_OPERATORS votes in the first ballot box page
+        let paged_operator = Pubkey::new_unique();
+        let mut ballot_box_page = BallotBoxPage::new(&ncn, TEST_EPOCH, 0, 0);
+        ballot_box.add_page(0).unwrap();
+        ballot_box
+            .cast_vote_with_page(
+                Some(&mut ballot_box_page),
+                &paged_operator,
+                &Ballot::new(&[1; 32]),
+                &StakeWeights::snapshot(NcnFeeGroup::default(), 200, 1).unwrap(),
+                TEST_CURRENT_SLOT,
+                TEST_VALID_SLOTS_AFTER_CONSENSUS,
+            )
+            .unwrap();
+        ballot_box
+            .tally_votes(
+                200 * (MAX_OPERATORS as u128 + 1),
+                MAX_OPERATORS as u64 + 1,
+                TEST_CURRENT_SLOT,
+            )
+            .unwrap();
+
+        // Every page has to be passed so no vote is skipped
+        assert_eq!(
+            router.route_ncn_fee_group_rewards(&ballot_box, 1000),
+            Err(TipRouterError::InvalidBallotBoxPage)
+        );
+
+        let mut router_page = BaseRewardRouterPage::new(&ncn, &Pubkey::default(), TEST_EPOCH, 0, 0);
+        assert_eq!(
+            router.route_ncn_fee_group_rewards_with_pages(
+                &ballot_box,
+                &[&ballot_box_page],
+                &mut [&mut router_page],
+                1000,
+            ),
+            Err(TipRouterError::InvalidBaseRewardRouterPage)
+        );
+        router.add_page(0).unwrap();
+
+        router
+            .route_ncn_fee_group_rewards_with_pages(
+                &ballot_box,
+                &[&ballot_box_page],
+                &mut [&mut router_page],
+                1000,
+            )
+            .unwrap();
+        assert!(!router.still_routing());
+
+        for operator in get_test_operators(&ballot_box).iter() {
+            let route = router.ncn_fee_group_reward_route(operator).unwrap();
+            assert_eq!(route.rewards(NcnFeeGroup::default()).unwrap(), 1000);
+        }
+
+        // The paged operator is routed in the router page
+        assert_eq!(
+            router.ncn_fee_group_reward_route(&paged_operator),
+            Err(TipRouterError::NcnRewardRouteNotFound)
+        );
+        assert_eq!(
+            router_page
+                .ncn_fee_group_reward_route(&paged_operator)
+                .unwrap()
+                .rewards(NcnFeeGroup::default())
+                .unwrap(),
+            1000
+        );
+
+        let rewards_processed = router.rewards_processed();
+        assert_eq!(
+            router.distribute_ncn_fee_group_reward_route_with_page(
+                Some(&mut router_page),
+                NcnFeeGroup::default(),
+                &paged_operator,
+            ),
+            Ok(1000)
+        );
+        assert_eq!(router.rewards_processed(), rewards_processed - 1000);
+
+        // Pages are closed from the last one
+        assert_eq!(
+            router.remove_page(1),
+            Err(TipRouterError::InvalidBaseRewardRouterPage)
+        );
+        router.remove_page(0).unwrap();
+        assert_eq!(router.page_count(), 0);
+    }
+
     #[test]
     fn test_route_with_interruption() {
         const INCOMING_REWARDS: u64 = 256_000;
@@ -1672,7 +2259,11 @@ mod tests {
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(
+                    total_stake_weights.stake_weight(),
+                    MAX_OPERATORS as u64,
+                    TEST_CURRENT_SLOT,
+                )
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
@@ -1758,7 +2349,11 @@ mod tests {
             let total_stake_weights = get_test_total_stake_weights(&ballot_box);
 
             ballot_box
-                .tally_votes(total_stake_weights.stake_weight(), TEST_CURRENT_SLOT)
+                .tally_votes(
+                    total_stake_weights.stake_weight(),
+                    MAX_OPERATORS as u64,
+                    TEST_CURRENT_SLOT,
+                )
                 .unwrap();
 
             (ballot_box, get_test_operators(&ballot_box))
@@ -0,0 +1,311 @@
+use core::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    ballot_box_page::BallotBoxPage, base_reward_router::NcnRewardRoute,
+    constants::OPERATOR_VOTES_PER_BALLOT_BOX_PAGE, discriminators::Discriminators,
+    error::TipRouterError, loaders::check_load, ncn_fee_group::NcnFeeGroup,
+};
+
+/// Extension of a base reward router holding the reward routes of the operators voting in the
+/// ballot box page of the same index, so every winning vote has a route.
+// PDA'd ["base_reward_router_page", NCN, NCN_EPOCH_SLOT, PAGE] + MINT for token routers
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct BaseRewardRouterPage {
+    /// The NCN this page is for
+    ncn: Pubkey,
+    /// The epoch this page is for
+    epoch: PodU64,
+    /// Index of the page within the base reward router
+    page: PodU64,
+    /// SPL token mint of the base reward router, the default pubkey when routing lamports
+    mint: Pubkey,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Reserved space
+    reserved: [u8; 127],
+    /// NCN Fee Group Reward Routes
+    ncn_fee_group_reward_routes: [NcnRewardRoute; 32],
+}
+
+impl Discriminator for BaseRewardRouterPage {
+    const DISCRIMINATOR: u8 = Discriminators::BaseRewardRouterPage as u8;
+}
+
+impl BaseRewardRouterPage {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(ncn: &Pubkey, mint: &Pubkey, epoch: u64, page: u64, bump: u8) -> Self {
+        Self {
+            ncn: *ncn,
+            epoch: PodU64::from(epoch),
+            page: PodU64::from(page),
+            mint: *mint,
+            bump,
+            reserved: [0; 127],
+            ncn_fee_group_reward_routes: [NcnRewardRoute::default();
+                OPERATOR_VOTES_PER_BALLOT_BOX_PAGE],
+        }
+    }
+
+    /// Pages of token routers are seeded with their mint, like the router itself
+    pub fn seeds_for_mint(ncn: &Pubkey, mint: &Pubkey, epoch: u64, page: u64) -> Vec<Vec<u8>> {
+        let mut seeds = vec![
+            b"base_reward_router_page".to_vec(),
+            ncn.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+            page.to_le_bytes().to_vec(),
+        ];
+
+        if mint.ne(&Pubkey::default()) {
+            seeds.push(mint.to_bytes().to_vec());
+        }
+
+        seeds
+    }
+
+    pub fn find_program_address_for_mint(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        mint: &Pubkey,
+        epoch: u64,
+        page: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds_for_mint(ncn, mint, epoch, page);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn load_for_mint(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        mint: &Pubkey,
+        epoch: u64,
+        page: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda =
+            Self::find_program_address_for_mint(program_id, ncn, mint, epoch, page).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads any page of the base reward router, the page index and mint are read from the
+    /// account itself
+    pub fn load_any_page(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let (mint, page) = {
+            let account_data = account.try_borrow_data()?;
+            let account_struct = Self::try_from_slice_unchecked(&account_data)?;
+            (*account_struct.mint(), account_struct.page())
+        };
+
+        Self::load_for_mint(
+            program_id,
+            account,
+            ncn,
+            &mint,
+            epoch,
+            page,
+            expect_writable,
+        )
+    }
+
+    pub fn load_to_close(
+        program_id: &Pubkey,
+        account_to_close: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+    ) -> Result<(), ProgramError> {
+        Self::load_any_page(program_id, account_to_close, ncn, epoch, true)
+    }
+
+    /// Loads the page holding the reward route of the operator at `ncn_operator_index`. The
+    /// page is required for operators past `MAX_OPERATORS` and rejected for the others.
+    pub fn load_for_operator<'a, 'info>(
+        program_id: &Pubkey,
+        account: Option<&'a AccountInfo<'info>>,
+        ncn: &Pubkey,
+        mint: &Pubkey,
+        epoch: u64,
+        ncn_operator_index: u64,
+    ) -> Result<Option<&'a AccountInfo<'info>>, ProgramError> {
+        match (Self::page_for_operator(ncn_operator_index), account) {
+            (None, None) => Ok(None),
+            (Some(page), Some(account)) => {
+                Self::load_for_mint(program_id, account, ncn, mint, epoch, page, true)?;
+                Ok(Some(account))
+            }
+            (page, _) => {
+                msg!(
+                    "Operator {} is routed in base reward router page {:?}",
+                    ncn_operator_index,
+                    page
+                );
+                Err(TipRouterError::InvalidBaseRewardRouterPage.into())
+            }
+        }
+    }
+
+    /// The page holding the route of an operator, the same page its vote is kept in
+    pub fn page_for_operator(ncn_operator_index: u64) -> Option<u64> {
+        BallotBoxPage::page_for_operator(ncn_operator_index)
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn page(&self) -> u64 {
+        self.page.into()
+    }
+
+    pub const fn mint(&self) -> &Pubkey {
+        &self.mint
+    }
+
+    pub fn ncn_fee_group_reward_route(
+        &self,
+        operator: &Pubkey,
+    ) -> Result<&NcnRewardRoute, TipRouterError> {
+        self.ncn_fee_group_reward_routes
+            .iter()
+            .find(|route| route.operator().eq(operator))
+            .ok_or(TipRouterError::NcnRewardRouteNotFound)
+    }
+
+    pub const fn ncn_fee_group_reward_routes(
+        &self,
+    ) -> &[NcnRewardRoute; OPERATOR_VOTES_PER_BALLOT_BOX_PAGE] {
+        &self.ncn_fee_group_reward_routes
+    }
+
+    pub(crate) fn route_to_ncn_fee_group_reward_route(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+        rewards: u64,
+    ) -> Result<(), TipRouterError> {
+        NcnRewardRoute::route_to(
+            &mut self.ncn_fee_group_reward_routes,
+            ncn_fee_group,
+            operator,
+            rewards,
+        )
+    }
+
+    pub(crate) fn distribute_ncn_fee_group_reward_route(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+    ) -> Result<u64, TipRouterError> {
+        NcnRewardRoute::distribute_from(
+            &mut self.ncn_fee_group_reward_routes,
+            ncn_fee_group,
+            operator,
+        )
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for BaseRewardRouterPage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Base Reward Router Page -------------")?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        if self.mint.ne(&Pubkey::default()) {
+            writeln!(f, "  Mint:                         {}", self.mint)?;
+        }
+        writeln!(f, "  Epoch:                        {}", self.epoch())?;
+        writeln!(f, "  Page:                         {}", self.page())?;
+
+        writeln!(f, "\nNCN Fee Group Reward Routes:")?;
+        for route in self.ncn_fee_group_reward_routes().iter() {
+            if !route.is_empty() {
+                writeln!(f, "  Operator:                     {}", route.operator())?;
+                for group in NcnFeeGroup::all_groups().iter() {
+                    if let Ok(rewards) = route.rewards(*group) {
+                        if rewards > 0 {
+                            writeln!(f, "      Group {}:                  {}", group.group, rewards)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        writeln!(f, "\n")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MAX_REALLOC_BYTES;
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // ncn
+            + size_of::<PodU64>() // epoch
+            + size_of::<PodU64>() // page
+            + size_of::<Pubkey>() // mint
+            + 1 // bump
+            + 127 // reserved
+            + size_of::<NcnRewardRoute>() * OPERATOR_VOTES_PER_BALLOT_BOX_PAGE; // ncn_fee_group_reward_routes
+
+        assert_eq!(size_of::<BaseRewardRouterPage>(), expected_total);
+
+        // Pages are created in a single instruction
+        assert!(BaseRewardRouterPage::SIZE <= MAX_REALLOC_BYTES as usize);
+    }
+
+    #[test]
+    fn test_route_and_distribute() {
+        let operator = Pubkey::new_unique();
+        let group = NcnFeeGroup::default();
+        let mut page =
+            BaseRewardRouterPage::new(&Pubkey::new_unique(), &Pubkey::default(), 1, 0, 0);
+
+        page.route_to_ncn_fee_group_reward_route(group, &operator, 100)
+            .unwrap();
+        page.route_to_ncn_fee_group_reward_route(group, &operator, 50)
+            .unwrap();
+        assert_eq!(
+            page.ncn_fee_group_reward_route(&operator)
+                .unwrap()
+                .rewards(group)
+                .unwrap(),
+            150
+        );
+
+        assert_eq!(
+            page.distribute_ncn_fee_group_reward_route(group, &operator),
+            Ok(150)
+        );
+        assert_eq!(
+            page.distribute_ncn_fee_group_reward_route(group, &Pubkey::new_unique()),
+            Err(TipRouterError::OperatorRewardNotFound)
+        );
+    }
+}
@@ -1,21 +1,56 @@
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
-use shank::ShankAccount;
+use jito_bytemuck::{
+    types::{PodU16, PodU64},
+    AccountDeserialize, Discriminator,
+};
+use shank::{ShankAccount, ShankType};
 use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
-    base_fee_group::BaseFeeGroup, discriminators::Discriminators, fees::FeeConfig,
-    loaders::check_load, ncn_fee_group::NcnFeeGroup,
+    account_version::{check_account_version, VersionedAccount},
+    base_fee_group::BaseFeeGroup,
+    constants::MAX_REWARD_MINTS,
+    discriminators::Discriminators,
+    error::TipRouterError,
+    fees::{FeeConfig, FeeConfigV0},
+    loaders::check_load,
+    ncn_fee_group::NcnFeeGroup,
+    pause::PausableSubsystem,
 };
 
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub enum ConfigAdminRole {
     FeeAdmin,
     TieBreakerAdmin,
+    PauseAdmin,
+}
+
+impl ConfigAdminRole {
+    pub const ROLE_COUNT: usize = 3;
+}
+
+/// An admin proposed for one `ConfigAdminRole`
+#[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
+#[repr(C)]
+pub struct PendingAdminHandover {
+    /// The proposed admin, the default pubkey when no handover is pending
+    admin: Pubkey,
+    /// First epoch the proposed admin can accept the handover
+    accept_epoch: PodU64,
+}
+
+impl PendingAdminHandover {
+    pub fn admin(&self) -> Option<&Pubkey> {
+        self.admin.ne(&Pubkey::default()).then_some(&self.admin)
+    }
+
+    pub fn accept_epoch(&self) -> u64 {
+        self.accept_epoch.into()
+    }
 }
 
 #[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
@@ -39,14 +74,87 @@ pub struct Config {
     pub epochs_after_consensus_before_close: PodU64,
     /// Only epochs after this epoch are valid for voting
     pub starting_valid_epoch: PodU64,
+    /// Number of slots after the ballot box is created during which operators commit to their votes, 0 disables commit-reveal voting
+    pub commit_window_slots: PodU64,
+    /// Number of slots after the commit window during which operators reveal their votes
+    pub reveal_window_slots: PodU64,
+    /// Share of stake weight in bps a ballot needs to reach consensus, 0 uses the default of 2/3.
+    /// Copied into the epoch state when it is created, so epochs already in progress keep theirs
+    pub consensus_threshold_bps: PodU16,
+    /// Minimum number of operators that must vote before consensus is reached, 0 disables
+    pub quorum_operator_count: PodU16,
+    /// Minimum share in bps of the epoch's active operators that must vote before consensus is reached, 0 disables
+    pub quorum_operator_bps: PodU16,
+    /// Share of stake weight in bps the leading ballot needs to be finalized by anyone once voting has stalled, 0 disables
+    pub stall_fallback_threshold_bps: PodU16,
+    /// Epochs a proposed admin has to wait before accepting the handover, 0 also allows `AdminSetNewAdmin`. Can be raised at any time, see `scheduled_admin_handover_delay_epochs` for lowering it
+    pub admin_handover_delay_epochs: PodU16,
+    /// Pending admin handover of each `ConfigAdminRole`, indexed by the role
+    pending_admins: [PendingAdminHandover; 3],
+    /// Lowered handover delay that replaces `admin_handover_delay_epochs` once the old delay has passed
+    pub scheduled_admin_handover_delay_epochs: PodU16,
+    /// Epoch the scheduled handover delay takes effect, 0 when none is scheduled
+    pub scheduled_admin_handover_delay_epoch: PodU64,
+    /// Number of slots after the weight table is created during which oracle prices are sampled and averaged, 0 sets weights from a single price
+    pub weight_sampling_window_slots: PodU64,
+    /// Max share in bps of the total stake weight an operator votes with, 0 disables
+    pub max_operator_stake_weight_bps: PodU16,
+    /// Max share in bps of the total stake weight a single vault delegation adds to an operator's vote, 0 disables
+    pub max_vault_stake_weight_bps: PodU16,
+    /// Lamports the account payer pays the signer of each successful crank step, 0 disables bounties
+    pub crank_bounty_lamports: PodU64,
+    /// Max lamports paid out in crank bounties per epoch
+    pub max_crank_bounty_lamports_per_epoch: PodU64,
+    /// The admin that can pause subsystems, only the NCN admin can unpause them
+    pub pause_admin: Pubkey,
+    /// Bitmask of the paused `PausableSubsystem`s
+    paused_subsystems: u8,
+    /// SPL token mints reward routers can be created for, default pubkeys are empty slots
+    reward_mints: [Pubkey; 8],
     /// Reserved space
-    reserved: [u8; 111],
+    reserved: [u8; 6],
 }
 
 impl Discriminator for Config {
     const DISCRIMINATOR: u8 = Discriminators::Config as u8;
 }
 
+impl VersionedAccount for Config {
+    const ACCOUNT_VERSION: u8 = 1;
+    const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+
+    fn version_size(version: u8) -> Result<usize, TipRouterError> {
+        match version {
+            0 => Ok(8 + size_of::<ConfigV0>()),
+            1 => Ok(Self::SIZE),
+            _ => Err(TipRouterError::InvalidAccountVersion),
+        }
+    }
+
+    fn migrate_from(version: u8, data: &mut [u8]) -> Result<(), TipRouterError> {
+        match version {
+            0 => Self::migrate_from_v0(data),
+            _ => Err(TipRouterError::InvalidAccountVersion),
+        }
+    }
+}
+
+/// Layout of version 0 `Config` accounts, before the fee timeline
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+#[repr(C)]
+pub struct ConfigV0 {
+    pub ncn: Pubkey,
+    pub tie_breaker_admin: Pubkey,
+    pub fee_admin: Pubkey,
+    pub valid_slots_after_consensus: PodU64,
+    pub epochs_before_stall: PodU64,
+    pub fee_config: FeeConfigV0,
+    pub bump: u8,
+    pub epochs_after_consensus_before_close: PodU64,
+    pub starting_valid_epoch: PodU64,
+    pub reserved: [u8; 111],
+}
+
 impl Config {
     pub const SIZE: usize = 8 + size_of::<Self>();
 
@@ -72,7 +180,25 @@ impl Config {
             epochs_after_consensus_before_close: PodU64::from(epochs_after_consensus_before_close),
             fee_config: *fee_config,
             bump,
-            reserved: [0; 111],
+            commit_window_slots: PodU64::from(0),
+            reveal_window_slots: PodU64::from(0),
+            consensus_threshold_bps: PodU16::from(0),
+            quorum_operator_count: PodU16::from(0),
+            quorum_operator_bps: PodU16::from(0),
+            stall_fallback_threshold_bps: PodU16::from(0),
+            admin_handover_delay_epochs: PodU16::from(0),
+            pending_admins: [PendingAdminHandover::zeroed(); ConfigAdminRole::ROLE_COUNT],
+            scheduled_admin_handover_delay_epochs: PodU16::from(0),
+            scheduled_admin_handover_delay_epoch: PodU64::from(0),
+            weight_sampling_window_slots: PodU64::from(0),
+            max_operator_stake_weight_bps: PodU16::from(0),
+            max_vault_stake_weight_bps: PodU16::from(0),
+            crank_bounty_lamports: PodU64::from(0),
+            max_crank_bounty_lamports_per_epoch: PodU64::from(0),
+            pause_admin: Pubkey::default(),
+            paused_subsystems: 0,
+            reward_mints: [Pubkey::default(); MAX_REWARD_MINTS],
+            reserved: [0; 6],
         }
     }
 
@@ -112,7 +238,53 @@ impl Config {
             &expected_pda,
             Some(Self::DISCRIMINATOR),
             expect_writable,
-        )
+        )?;
+        check_account_version::<Self>(account)
+    }
+
+    /// Loads the NCN [`Config`] account, failing with `Paused` while `subsystem` is paused
+    pub fn load_unpaused(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        subsystem: PausableSubsystem,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        Self::load(program_id, account, ncn, expect_writable)?;
+
+        let account_data = account.try_borrow_data()?;
+        let config = Self::try_from_slice_unchecked(&account_data)?;
+        config.check_not_paused(subsystem)?;
+
+        Ok(())
+    }
+
+    /// Rewrites the data of a version 0 config, every field added since starts out zeroed
+    fn migrate_from_v0(data: &mut [u8]) -> Result<(), TipRouterError> {
+        // Copied out first, the new layout overlaps the old one
+        let legacy_data = data
+            .get(8..Self::version_size(0)?)
+            .ok_or(TipRouterError::InvalidAccountVersion)?
+            .to_vec();
+        let legacy = bytemuck::try_from_bytes::<ConfigV0>(&legacy_data)
+            .map_err(|_| TipRouterError::InvalidAccountVersion)?;
+
+        let config_data = data
+            .get_mut(8..Self::SIZE)
+            .ok_or(TipRouterError::InvalidAccountVersion)?;
+        config_data.fill(0);
+        let config = bytemuck::try_from_bytes_mut::<Self>(config_data)
+            .map_err(|_| TipRouterError::InvalidAccountVersion)?;
+
+        config.ncn = legacy.ncn;
+        config.tie_breaker_admin = legacy.tie_breaker_admin;
+        config.fee_admin = legacy.fee_admin;
+        config.valid_slots_after_consensus = legacy.valid_slots_after_consensus;
+        config.epochs_before_stall = legacy.epochs_before_stall;
+        config.bump = legacy.bump;
+        config.epochs_after_consensus_before_close = legacy.epochs_after_consensus_before_close;
+        config.starting_valid_epoch = legacy.starting_valid_epoch;
+        config.fee_config.migrate_from_v0(&legacy.fee_config)
     }
 
     pub fn starting_valid_epoch(&self) -> u64 {
@@ -130,6 +302,298 @@ impl Config {
     pub fn epochs_after_consensus_before_close(&self) -> u64 {
         self.epochs_after_consensus_before_close.into()
     }
+
+    pub fn commit_window_slots(&self) -> u64 {
+        self.commit_window_slots.into()
+    }
+
+    pub fn reveal_window_slots(&self) -> u64 {
+        self.reveal_window_slots.into()
+    }
+
+    pub fn is_commit_reveal_enabled(&self) -> bool {
+        self.commit_window_slots() > 0
+    }
+
+    pub fn consensus_threshold_bps(&self) -> u16 {
+        self.consensus_threshold_bps.into()
+    }
+
+    pub fn quorum_operator_count(&self) -> u16 {
+        self.quorum_operator_count.into()
+    }
+
+    pub fn quorum_operator_bps(&self) -> u16 {
+        self.quorum_operator_bps.into()
+    }
+
+    pub fn stall_fallback_threshold_bps(&self) -> u16 {
+        self.stall_fallback_threshold_bps.into()
+    }
+
+    pub fn is_stall_fallback_enabled(&self) -> bool {
+        self.stall_fallback_threshold_bps() > 0
+    }
+
+    /// The handover delay in effect at `current_epoch`
+    pub fn admin_handover_delay_epochs(&self, current_epoch: u64) -> u64 {
+        let epochs: u16 = if self.is_admin_handover_delay_scheduled()
+            && current_epoch >= self.scheduled_admin_handover_delay_epoch()
+        {
+            self.scheduled_admin_handover_delay_epochs.into()
+        } else {
+            self.admin_handover_delay_epochs.into()
+        };
+        epochs as u64
+    }
+
+    pub fn scheduled_admin_handover_delay_epoch(&self) -> u64 {
+        self.scheduled_admin_handover_delay_epoch.into()
+    }
+
+    pub fn is_admin_handover_delay_scheduled(&self) -> bool {
+        self.scheduled_admin_handover_delay_epoch() > 0
+    }
+
+    /// Raises the handover delay right away, a lower delay only takes effect once the delay in
+    /// effect has passed so it can't be used to skip the timelock. Returns the epoch the new delay
+    /// takes effect.
+    pub fn set_admin_handover_delay_epochs(
+        &mut self,
+        epochs: u16,
+        current_epoch: u64,
+    ) -> Result<u64, TipRouterError> {
+        let delay_in_effect = self.admin_handover_delay_epochs(current_epoch);
+        self.admin_handover_delay_epochs = PodU16::from(delay_in_effect as u16);
+        self.scheduled_admin_handover_delay_epochs = PodU16::from(0);
+        self.scheduled_admin_handover_delay_epoch = PodU64::from(0);
+
+        if epochs as u64 >= delay_in_effect {
+            self.admin_handover_delay_epochs = PodU16::from(epochs);
+            return Ok(current_epoch);
+        }
+
+        let effective_epoch = current_epoch
+            .checked_add(delay_in_effect)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        self.scheduled_admin_handover_delay_epochs = PodU16::from(epochs);
+        self.scheduled_admin_handover_delay_epoch = PodU64::from(effective_epoch);
+
+        Ok(effective_epoch)
+    }
+
+    pub fn weight_sampling_window_slots(&self) -> u64 {
+        self.weight_sampling_window_slots.into()
+    }
+
+    pub fn is_weight_sampling_enabled(&self) -> bool {
+        self.weight_sampling_window_slots() > 0
+    }
+
+    pub fn max_operator_stake_weight_bps(&self) -> u16 {
+        self.max_operator_stake_weight_bps.into()
+    }
+
+    pub fn max_vault_stake_weight_bps(&self) -> u16 {
+        self.max_vault_stake_weight_bps.into()
+    }
+
+    pub fn crank_bounty_lamports(&self) -> u64 {
+        self.crank_bounty_lamports.into()
+    }
+
+    pub fn max_crank_bounty_lamports_per_epoch(&self) -> u64 {
+        self.max_crank_bounty_lamports_per_epoch.into()
+    }
+
+    pub const fn paused_subsystems(&self) -> u8 {
+        self.paused_subsystems
+    }
+
+    pub const fn is_paused(&self, subsystem: PausableSubsystem) -> bool {
+        subsystem.is_paused(self.paused_subsystems)
+    }
+
+    pub const fn check_not_paused(
+        &self,
+        subsystem: PausableSubsystem,
+    ) -> Result<(), TipRouterError> {
+        if self.is_paused(subsystem) {
+            return Err(TipRouterError::Paused);
+        }
+        Ok(())
+    }
+
+    /// Replaces the paused subsystems, `paused_subsystems` is a bitmask of `PausableSubsystem`s
+    pub fn set_paused_subsystems(&mut self, paused_subsystems: u8) -> Result<(), TipRouterError> {
+        if paused_subsystems & !PausableSubsystem::ALL != 0 {
+            return Err(TipRouterError::InvalidPausedSubsystems);
+        }
+        self.paused_subsystems = paused_subsystems;
+        Ok(())
+    }
+
+    /// The SPL token mints reward routers can be created for
+    pub fn reward_mints(&self) -> impl Iterator<Item = &Pubkey> {
+        self.reward_mints
+            .iter()
+            .filter(|mint| mint.ne(&&Pubkey::default()))
+    }
+
+    pub fn is_reward_mint(&self, mint: &Pubkey) -> bool {
+        self.reward_mints().any(|reward_mint| reward_mint.eq(mint))
+    }
+
+    /// Adds `mint` to or removes it from the allowed reward mints
+    pub fn set_reward_mint(&mut self, mint: &Pubkey, allowed: bool) -> Result<(), TipRouterError> {
+        if mint.eq(&Pubkey::default()) {
+            return Err(TipRouterError::RewardMintNotAllowed);
+        }
+
+        if allowed {
+            if self.is_reward_mint(mint) {
+                return Ok(());
+            }
+
+            let slot = self
+                .reward_mints
+                .iter_mut()
+                .find(|reward_mint| reward_mint.eq(&&Pubkey::default()))
+                .ok_or(TipRouterError::TooManyRewardMints)?;
+            *slot = *mint;
+        } else {
+            let slot = self
+                .reward_mints
+                .iter_mut()
+                .find(|reward_mint| reward_mint.eq(&mint))
+                .ok_or(TipRouterError::RewardMintNotAllowed)?;
+            *slot = Pubkey::default();
+        }
+
+        Ok(())
+    }
+
+    /// Whether a handover is pending for any role
+    pub fn has_pending_admin(&self) -> bool {
+        self.pending_admins
+            .iter()
+            .any(|pending_admin| pending_admin.admin().is_some())
+    }
+
+    /// The admin pending for `role`, if any
+    pub fn pending_admin(&self, role: ConfigAdminRole) -> Option<&Pubkey> {
+        self.pending_admins[role as usize].admin()
+    }
+
+    /// First epoch the admin pending for `role` can accept the handover
+    pub fn pending_admin_accept_epoch(&self, role: ConfigAdminRole) -> u64 {
+        self.pending_admins[role as usize].accept_epoch()
+    }
+
+    /// Starts handing `role` over to `new_admin`, replacing a pending handover for the same role.
+    /// Each role has its own pending handover.
+    pub fn propose_admin_handover(
+        &mut self,
+        role: ConfigAdminRole,
+        new_admin: &Pubkey,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
+        if new_admin.eq(&Pubkey::default()) {
+            return Err(TipRouterError::InvalidPendingAdmin);
+        }
+
+        let accept_epoch = current_epoch
+            .checked_add(self.admin_handover_delay_epochs(current_epoch))
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        self.pending_admins[role as usize] = PendingAdminHandover {
+            admin: *new_admin,
+            accept_epoch: PodU64::from(accept_epoch),
+        };
+
+        Ok(())
+    }
+
+    /// Completes the pending handover of `role` once the delay has passed
+    pub fn accept_admin_handover(
+        &mut self,
+        role: ConfigAdminRole,
+        new_admin: &Pubkey,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
+        let pending_admin = *self
+            .pending_admin(role)
+            .ok_or(TipRouterError::NoPendingAdminHandover)?;
+
+        if pending_admin.ne(new_admin) {
+            return Err(TipRouterError::InvalidPendingAdmin);
+        }
+
+        if current_epoch < self.pending_admin_accept_epoch(role) {
+            return Err(TipRouterError::AdminHandoverDelayNotElapsed);
+        }
+
+        self.set_admin(role, &pending_admin);
+        self.clear_pending_admin(role);
+
+        Ok(())
+    }
+
+    /// Hands `role` over to `new_admin` through the pending handover, completing it right away
+    /// when the delay has already passed. Returns whether `new_admin` holds the role afterwards.
+    pub fn hand_over_admin(
+        &mut self,
+        role: ConfigAdminRole,
+        new_admin: &Pubkey,
+        current_epoch: u64,
+    ) -> Result<bool, TipRouterError> {
+        if self.admin(role).eq(new_admin) {
+            return Ok(true);
+        }
+
+        if self.pending_admin(role).ne(&Some(new_admin)) {
+            self.propose_admin_handover(role, new_admin, current_epoch)?;
+        }
+
+        if current_epoch < self.pending_admin_accept_epoch(role) {
+            return Ok(false);
+        }
+
+        self.accept_admin_handover(role, new_admin, current_epoch)?;
+
+        Ok(true)
+    }
+
+    pub fn cancel_admin_handover(&mut self, role: ConfigAdminRole) -> Result<(), TipRouterError> {
+        if self.pending_admin(role).is_none() {
+            return Err(TipRouterError::NoPendingAdminHandover);
+        }
+
+        self.clear_pending_admin(role);
+
+        Ok(())
+    }
+
+    fn clear_pending_admin(&mut self, role: ConfigAdminRole) {
+        self.pending_admins[role as usize] = PendingAdminHandover::zeroed();
+    }
+
+    pub const fn admin(&self, role: ConfigAdminRole) -> &Pubkey {
+        match role {
+            ConfigAdminRole::FeeAdmin => &self.fee_admin,
+            ConfigAdminRole::TieBreakerAdmin => &self.tie_breaker_admin,
+            ConfigAdminRole::PauseAdmin => &self.pause_admin,
+        }
+    }
+
+    pub fn set_admin(&mut self, role: ConfigAdminRole, new_admin: &Pubkey) {
+        match role {
+            ConfigAdminRole::FeeAdmin => self.fee_admin = *new_admin,
+            ConfigAdminRole::TieBreakerAdmin => self.tie_breaker_admin = *new_admin,
+            ConfigAdminRole::PauseAdmin => self.pause_admin = *new_admin,
+        }
+    }
 }
 
 #[rustfmt::skip]
@@ -143,6 +607,35 @@ impl fmt::Display for Config {
         writeln!(f, "  Epochs Before Stall:          {}", self.epochs_before_stall())?;
         writeln!(f, "  Starting Valid Epochs:        {}", self.starting_valid_epoch())?;
         writeln!(f, "  Close Epoch:                  {}", self.epochs_after_consensus_before_close())?;
+        writeln!(f, "  Commit Window Slots:          {}", self.commit_window_slots())?;
+        writeln!(f, "  Reveal Window Slots:          {}", self.reveal_window_slots())?;
+        writeln!(f, "  Consensus Threshold Bps:      {}", self.consensus_threshold_bps())?;
+        writeln!(f, "  Quorum Operator Count:        {}", self.quorum_operator_count())?;
+        writeln!(f, "  Quorum Operator Bps:          {}", self.quorum_operator_bps())?;
+        writeln!(f, "  Stall Fallback Threshold Bps: {}", self.stall_fallback_threshold_bps())?;
+        writeln!(f, "  Admin Handover Delay Epochs:  {}", u16::from(self.admin_handover_delay_epochs))?;
+        if self.is_admin_handover_delay_scheduled() {
+            writeln!(f, "  Scheduled Handover Delay:     {} (from epoch {})", u16::from(self.scheduled_admin_handover_delay_epochs), self.scheduled_admin_handover_delay_epoch())?;
+        }
+        writeln!(f, "  Weight Sampling Window Slots: {}", self.weight_sampling_window_slots())?;
+        writeln!(f, "  Max Operator Stake Bps:       {}", self.max_operator_stake_weight_bps())?;
+        writeln!(f, "  Max Vault Stake Bps:          {}", self.max_vault_stake_weight_bps())?;
+        writeln!(f, "  Crank Bounty Lamports:        {}", self.crank_bounty_lamports())?;
+        writeln!(f, "  Max Crank Bounty Per Epoch:   {}", self.max_crank_bounty_lamports_per_epoch())?;
+        writeln!(f, "  Pause Admin:                  {}", self.pause_admin)?;
+        for subsystem in PausableSubsystem::all() {
+            if self.is_paused(subsystem) {
+                writeln!(f, "  Paused:                       {}", subsystem)?;
+            }
+        }
+        for mint in self.reward_mints() {
+            writeln!(f, "  Reward Mint:                  {}", mint)?;
+        }
+        for role in [ConfigAdminRole::FeeAdmin, ConfigAdminRole::TieBreakerAdmin, ConfigAdminRole::PauseAdmin] {
+            if let Some(pending_admin) = self.pending_admin(role) {
+                writeln!(f, "  Pending {:<22}{} (from epoch {})", format!("{role:?}:"), pending_admin, self.pending_admin_accept_epoch(role))?;
+            }
+        }
         writeln!(f, "  Fees:")?;
         writeln!(f, "    Block Engine Fee:           {}", self.fee_config.block_engine_fee_bps())?;
         for group in BaseFeeGroup::all_groups() {
@@ -152,38 +645,32 @@ impl fmt::Display for Config {
                 .map(|wallet| format!("{wallet:?}"))
                 .unwrap_or_else(|err| format!("<error: {err}>"));
             writeln!(f, "    Base Fee Wallet [{:?}]:        {}", group.group, wallet)?;
+            if let Ok(settings) = self.fee_config.base_fee_group_settings(group) {
+                let payout_asset = settings
+                    .payout_asset()
+                    .map(|payout_asset| payout_asset.to_string())
+                    .unwrap_or_else(|err| format!("<error: {err}>"));
+                writeln!(f, "    Base Fee Group [{:?}]:         {} (enabled: {}, paid in {})", group.group, settings.name(), settings.is_enabled(), payout_asset)?;
+            }
         }
-        for group in BaseFeeGroup::all_groups() {
-            let fee = self
-                .fee_config
-                .base_fee_bps(group, u64::MAX)
-                .map(|fee| fee.to_string())
-                .unwrap_or_else(|err| format!("<error: {err}>"));
-            writeln!(f, "    Late Base Fee [{:?}]:          {}", group.group, fee)?;
-        }
-        for group in NcnFeeGroup::all_groups() {
-            let fee = self
-                .fee_config
-                .ncn_fee_bps(group, u64::MAX)
-                .map(|fee| fee.to_string())
-                .unwrap_or_else(|err| format!("<error: {err}>"));
-            writeln!(f, "    Late NCN Fee [{:?}]:           {}", group.group, fee)?;
-        }
-        for group in BaseFeeGroup::all_groups() {
-            let fee = self
-                .fee_config
-                .base_fee_bps(group, 0)
-                .map(|fee| fee.to_string())
-                .unwrap_or_else(|err| format!("<error: {err}>"));
-            writeln!(f, "    Current Base Fee [{:?}]:       {}", group.group, fee)?;
-        }
-        for group in NcnFeeGroup::all_groups() {
-            let fee = self
-                .fee_config
-                .ncn_fee_bps(group, 0)
-                .map(|fee| fee.to_string())
-                .unwrap_or_else(|err| format!("<error: {err}>"));
-            writeln!(f, "    Current NCN Fee [{:?}]:        {}", group.group, fee)?;
+        writeln!(f, "    Fee Increase Notice Epochs: {}", self.fee_config.fee_increase_notice_epochs())?;
+        for fees in self.fee_config.fee_entries() {
+            writeln!(f, "    From Epoch {}:", fees.activation_epoch())?;
+            for group in BaseFeeGroup::all_groups() {
+                let fee = fees
+                    .base_fee_bps(group)
+                    .map(|fee| fee.to_string())
+                    .unwrap_or_else(|err| format!("<error: {err}>"));
+                writeln!(f, "      Base Fee [{:?}]:             {}", group.group, fee)?;
+            }
+            for group in NcnFeeGroup::all_groups() {
+                let fee = fees
+                    .ncn_fee_bps(group)
+                    .map(|fee| fee.to_string())
+                    .unwrap_or_else(|err| format!("<error: {err}>"));
+                writeln!(f, "      NCN Fee [{:?}]:              {}", group.group, fee)?;
+            }
+            writeln!(f, "      Priority Fee Distribution: {}", fees.priority_fee_distribution_fee_bps())?;
         }
 
         Ok(())
@@ -207,9 +694,340 @@ mod tests {
             + 1 // bump
             + size_of::<PodU64>() //TODO move up before deploy epochs_after_consensus_before_close
             + size_of::<PodU64>() //TODO starting_valid_epoch
-            + 111; // reserved
+            + size_of::<PodU64>() // commit_window_slots
+            + size_of::<PodU64>() // reveal_window_slots
+            + size_of::<PodU16>() // consensus_threshold_bps
+            + size_of::<PodU16>() // quorum_operator_count
+            + size_of::<PodU16>() // quorum_operator_bps
+            + size_of::<PodU16>() // stall_fallback_threshold_bps
+            + size_of::<PodU16>() // admin_handover_delay_epochs
+            + size_of::<PendingAdminHandover>() * ConfigAdminRole::ROLE_COUNT // pending_admins
+            + size_of::<PodU16>() // scheduled_admin_handover_delay_epochs
+            + size_of::<PodU64>() // scheduled_admin_handover_delay_epoch
+            + size_of::<PodU64>() // weight_sampling_window_slots
+            + size_of::<PodU16>() // max_operator_stake_weight_bps
+            + size_of::<PodU16>() // max_vault_stake_weight_bps
+            + size_of::<PodU64>() // crank_bounty_lamports
+            + size_of::<PodU64>() // max_crank_bounty_lamports_per_epoch
+            + size_of::<Pubkey>() // pause_admin
+            + 1 // paused_subsystems
+            + size_of::<Pubkey>() * MAX_REWARD_MINTS // reward_mints
+            + 6; // reserved
 
         assert_eq!(size_of::<Config>(), expected_total);
         assert_eq!(size_of::<Config>() + 8, Config::SIZE);
     }
+
+    #[test]
+    fn test_migrate_from_v0() {
+        use crate::fees::Fees;
+
+        // Size of the config before versions were tracked
+        assert_eq!(Config::version_size(0).unwrap(), 970);
+
+        let ncn = Pubkey::new_unique();
+        let dao_wallet = Pubkey::new_unique();
+        let fees = Fees::new(270, 15, 10).unwrap();
+        let legacy = ConfigV0 {
+            ncn,
+            tie_breaker_admin: Pubkey::new_unique(),
+            fee_admin: Pubkey::new_unique(),
+            valid_slots_after_consensus: PodU64::from(1000),
+            epochs_before_stall: PodU64::from(3),
+            fee_config: FeeConfigV0 {
+                block_engine_fee_bps: PodU16::from(300),
+                base_fee_wallets: [dao_wallet; BaseFeeGroup::FEE_GROUP_COUNT],
+                reserved: [0; 128],
+                fee_1: fees,
+                fee_2: fees,
+            },
+            bump: 254,
+            epochs_after_consensus_before_close: PodU64::from(10),
+            starting_valid_epoch: PodU64::from(5),
+            reserved: [0; 111],
+        };
+
+        let mut data = vec![0; Config::SIZE];
+        data[0] = Config::DISCRIMINATOR;
+        data[8..Config::version_size(0).unwrap()].copy_from_slice(bytemuck::bytes_of(&legacy));
+
+        assert_eq!(Config::ncn_from_data(&data), Ok(ncn));
+        assert_eq!(
+            Config::migrate_from(1, &mut data),
+            Err(TipRouterError::InvalidAccountVersion)
+        );
+        Config::migrate_from(0, &mut data).unwrap();
+
+        let config = Config::try_from_slice_unchecked(&data).unwrap();
+        assert_eq!(config.ncn, ncn);
+        assert_eq!(config.tie_breaker_admin, legacy.tie_breaker_admin);
+        assert_eq!(config.fee_admin, legacy.fee_admin);
+        assert_eq!(config.valid_slots_after_consensus(), 1000);
+        assert_eq!(config.epochs_before_stall(), 3);
+        assert_eq!(config.bump, 254);
+        assert_eq!(config.epochs_after_consensus_before_close(), 10);
+        assert_eq!(config.starting_valid_epoch(), 5);
+        assert_eq!(config.fee_config.block_engine_fee_bps(), 300);
+        assert_eq!(
+            config.fee_config.base_fee_wallet(BaseFeeGroup::dao()),
+            Ok(&dao_wallet)
+        );
+        assert_eq!(
+            config
+                .fee_config
+                .current_fees(10)
+                .base_fee_bps(BaseFeeGroup::dao()),
+            Ok(270)
+        );
+        assert_eq!(config.pause_admin, Pubkey::default());
+        assert_eq!(config.paused_subsystems(), 0);
+        assert_eq!(config.crank_bounty_lamports(), 0);
+    }
+
+    fn test_config() -> Config {
+        Config::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &FeeConfig::new(&Pubkey::new_unique(), 100, 100, 100, 0).unwrap(),
+            0,
+            1000,
+            3,
+            10,
+            0,
+        )
+    }
+
+    #[test]
+    fn test_admin_handover() {
+        let mut config = test_config();
+        config.set_admin_handover_delay_epochs(2, 10).unwrap();
+
+        let new_fee_admin = Pubkey::new_unique();
+        config
+            .propose_admin_handover(ConfigAdminRole::FeeAdmin, &new_fee_admin, 10)
+            .unwrap();
+        assert_eq!(
+            config.pending_admin(ConfigAdminRole::FeeAdmin),
+            Some(&new_fee_admin)
+        );
+        assert_eq!(config.pending_admin(ConfigAdminRole::TieBreakerAdmin), None);
+        assert_eq!(
+            config.pending_admin_accept_epoch(ConfigAdminRole::FeeAdmin),
+            12
+        );
+
+        // Each role has its own pending handover
+        let new_tie_breaker_admin = Pubkey::new_unique();
+        config
+            .propose_admin_handover(ConfigAdminRole::TieBreakerAdmin, &new_tie_breaker_admin, 11)
+            .unwrap();
+        assert_eq!(
+            config.pending_admin(ConfigAdminRole::TieBreakerAdmin),
+            Some(&new_tie_breaker_admin)
+        );
+        assert_eq!(
+            config.pending_admin_accept_epoch(ConfigAdminRole::TieBreakerAdmin),
+            13
+        );
+        assert_eq!(
+            config.accept_admin_handover(ConfigAdminRole::FeeAdmin, &new_fee_admin, 11),
+            Err(TipRouterError::AdminHandoverDelayNotElapsed)
+        );
+        assert_eq!(
+            config.accept_admin_handover(ConfigAdminRole::FeeAdmin, &Pubkey::new_unique(), 12),
+            Err(TipRouterError::InvalidPendingAdmin)
+        );
+        assert_eq!(
+            config.accept_admin_handover(ConfigAdminRole::PauseAdmin, &new_fee_admin, 12),
+            Err(TipRouterError::NoPendingAdminHandover)
+        );
+
+        config
+            .accept_admin_handover(ConfigAdminRole::FeeAdmin, &new_fee_admin, 12)
+            .unwrap();
+        assert_eq!(config.fee_admin, new_fee_admin);
+        assert_eq!(config.pending_admin(ConfigAdminRole::FeeAdmin), None);
+        assert!(config.has_pending_admin());
+
+        config
+            .accept_admin_handover(ConfigAdminRole::TieBreakerAdmin, &new_tie_breaker_admin, 13)
+            .unwrap();
+        assert_eq!(config.tie_breaker_admin, new_tie_breaker_admin);
+        assert!(!config.has_pending_admin());
+    }
+
+    #[test]
+    fn test_cancel_admin_handover() {
+        let mut config = test_config();
+        let tie_breaker_admin = config.tie_breaker_admin;
+
+        assert_eq!(
+            config.cancel_admin_handover(ConfigAdminRole::TieBreakerAdmin),
+            Err(TipRouterError::NoPendingAdminHandover)
+        );
+
+        let new_tie_breaker_admin = Pubkey::new_unique();
+        config
+            .propose_admin_handover(ConfigAdminRole::TieBreakerAdmin, &new_tie_breaker_admin, 10)
+            .unwrap();
+        assert_eq!(
+            config.cancel_admin_handover(ConfigAdminRole::FeeAdmin),
+            Err(TipRouterError::NoPendingAdminHandover)
+        );
+        config
+            .cancel_admin_handover(ConfigAdminRole::TieBreakerAdmin)
+            .unwrap();
+
+        assert!(!config.has_pending_admin());
+        assert_eq!(
+            config.accept_admin_handover(
+                ConfigAdminRole::TieBreakerAdmin,
+                &new_tie_breaker_admin,
+                10
+            ),
+            Err(TipRouterError::NoPendingAdminHandover)
+        );
+        assert_eq!(config.tie_breaker_admin, tie_breaker_admin);
+    }
+
+    #[test]
+    fn test_lower_admin_handover_delay() {
+        let mut config = test_config();
+
+        // Raising the delay applies right away
+        assert_eq!(config.set_admin_handover_delay_epochs(3, 10), Ok(10));
+        assert_eq!(config.admin_handover_delay_epochs(10), 3);
+
+        // Lowering it waits out the delay in effect
+        assert_eq!(config.set_admin_handover_delay_epochs(0, 10), Ok(13));
+        assert_eq!(config.admin_handover_delay_epochs(10), 3);
+        assert_eq!(config.admin_handover_delay_epochs(12), 3);
+        assert_eq!(config.admin_handover_delay_epochs(13), 0);
+
+        let new_fee_admin = Pubkey::new_unique();
+        assert_eq!(
+            config.hand_over_admin(ConfigAdminRole::FeeAdmin, &new_fee_admin, 10),
+            Ok(false)
+        );
+        assert_eq!(
+            config.pending_admin_accept_epoch(ConfigAdminRole::FeeAdmin),
+            13
+        );
+
+        // Raising it again cancels the scheduled decrease
+        assert_eq!(config.set_admin_handover_delay_epochs(5, 11), Ok(11));
+        assert!(!config.is_admin_handover_delay_scheduled());
+        assert_eq!(config.admin_handover_delay_epochs(20), 5);
+    }
+
+    #[test]
+    fn test_hand_over_admin() {
+        let mut config = test_config();
+        let new_fee_admin = Pubkey::new_unique();
+
+        // Without a delay the handover completes right away
+        assert_eq!(
+            config.hand_over_admin(ConfigAdminRole::FeeAdmin, &new_fee_admin, 10),
+            Ok(true)
+        );
+        assert_eq!(config.fee_admin, new_fee_admin);
+        assert!(!config.has_pending_admin());
+
+        config.set_admin_handover_delay_epochs(2, 10).unwrap();
+
+        let next_fee_admin = Pubkey::new_unique();
+        assert_eq!(
+            config.hand_over_admin(ConfigAdminRole::FeeAdmin, &next_fee_admin, 10),
+            Ok(false)
+        );
+        assert_eq!(config.fee_admin, new_fee_admin);
+
+        // Handing over again keeps the pending accept epoch
+        assert_eq!(
+            config.hand_over_admin(ConfigAdminRole::FeeAdmin, &next_fee_admin, 11),
+            Ok(false)
+        );
+        assert_eq!(
+            config.pending_admin_accept_epoch(ConfigAdminRole::FeeAdmin),
+            12
+        );
+        assert_eq!(
+            config.hand_over_admin(ConfigAdminRole::FeeAdmin, &next_fee_admin, 12),
+            Ok(true)
+        );
+        assert_eq!(config.fee_admin, next_fee_admin);
+    }
+
+    #[test]
+    fn test_paused_subsystems() {
+        let mut config = test_config();
+        for subsystem in PausableSubsystem::all() {
+            assert_eq!(config.check_not_paused(subsystem), Ok(()));
+        }
+
+        config
+            .set_paused_subsystems(
+                PausableSubsystem::Voting.flag() | PausableSubsystem::Distribution.flag(),
+            )
+            .unwrap();
+        assert_eq!(
+            config.check_not_paused(PausableSubsystem::Voting),
+            Err(TipRouterError::Paused)
+        );
+        assert_eq!(
+            config.check_not_paused(PausableSubsystem::Distribution),
+            Err(TipRouterError::Paused)
+        );
+        assert_eq!(config.check_not_paused(PausableSubsystem::Routing), Ok(()));
+
+        assert_eq!(
+            config.set_paused_subsystems(PausableSubsystem::ALL + 1),
+            Err(TipRouterError::InvalidPausedSubsystems)
+        );
+        assert_eq!(
+            config.paused_subsystems(),
+            PausableSubsystem::Voting.flag() | PausableSubsystem::Distribution.flag()
+        );
+
+        config.set_paused_subsystems(0).unwrap();
+        assert!(!config.is_paused(PausableSubsystem::Voting));
+    }
+
+    #[test]
+    fn test_reward_mints() {
+        let mut config = test_config();
+        let mints: Vec<Pubkey> = (0..=MAX_REWARD_MINTS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+
+        assert!(!config.is_reward_mint(&mints[0]));
+        assert_eq!(
+            config.set_reward_mint(&Pubkey::default(), true),
+            Err(TipRouterError::RewardMintNotAllowed)
+        );
+
+        for mint in mints.iter().take(MAX_REWARD_MINTS) {
+            config.set_reward_mint(mint, true).unwrap();
+        }
+        // Allowing a mint twice doesn't take another slot
+        config.set_reward_mint(&mints[0], true).unwrap();
+        assert_eq!(
+            config.set_reward_mint(&mints[MAX_REWARD_MINTS], true),
+            Err(TipRouterError::TooManyRewardMints)
+        );
+        assert_eq!(config.reward_mints().count(), MAX_REWARD_MINTS);
+
+        config.set_reward_mint(&mints[1], false).unwrap();
+        assert!(!config.is_reward_mint(&mints[1]));
+        assert_eq!(
+            config.set_reward_mint(&mints[1], false),
+            Err(TipRouterError::RewardMintNotAllowed)
+        );
+
+        config
+            .set_reward_mint(&mints[MAX_REWARD_MINTS], true)
+            .unwrap();
+        assert!(config.is_reward_mint(&mints[MAX_REWARD_MINTS]));
+    }
 }
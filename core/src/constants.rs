@@ -6,24 +6,66 @@ use spl_math::precise_number::PreciseNumber;
 use crate::error::TipRouterError;
 
 pub const MAX_FEE_BPS: u64 = 10_000;
+pub const MAX_SCHEDULED_FEES: usize = 8;
+pub const MAX_FEE_INCREASE_NOTICE_EPOCHS: u64 = 50;
 pub const MAX_ST_MINTS: usize = 64;
 pub const MAX_VAULTS: usize = 64;
+pub const MAX_REWARD_MINTS: usize = 8;
 pub const MAX_OPERATORS: usize = 256;
+// Votes of operators past `MAX_OPERATORS` are kept in ballot box pages
+pub const OPERATOR_VOTES_PER_BALLOT_BOX_PAGE: usize = 32;
+// Epoch progress of operators past `MAX_OPERATORS` is kept in epoch state pages
+pub const OPERATORS_PER_EPOCH_STATE_PAGE: usize = 32;
+// Vaults past `MAX_VAULTS` are kept in vault registry, weight table and operator snapshot pages
+pub const VAULTS_PER_PAGE: usize = 32;
 pub const MIN_EPOCHS_BEFORE_STALL: u64 = 0;
 pub const MAX_EPOCHS_BEFORE_STALL: u64 = 50;
 pub const MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE: u64 = 10;
 pub const MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE: u64 = 100;
 pub const MIN_VALID_SLOTS_AFTER_CONSENSUS: u64 = 1000;
 pub const MAX_VALID_SLOTS_AFTER_CONSENSUS: u64 = 50 * DEFAULT_SLOTS_PER_EPOCH;
+pub const MIN_COMMIT_WINDOW_SLOTS: u64 = 1000;
+pub const MAX_COMMIT_WINDOW_SLOTS: u64 = DEFAULT_SLOTS_PER_EPOCH;
+pub const MIN_REVEAL_WINDOW_SLOTS: u64 = 1000;
+pub const MAX_REVEAL_WINDOW_SLOTS: u64 = 10 * DEFAULT_SLOTS_PER_EPOCH;
+pub const MIN_WEIGHT_SAMPLING_WINDOW_SLOTS: u64 = 100;
+// Leaves most of the epoch for snapshotting and voting
+pub const MAX_WEIGHT_SAMPLING_WINDOW_SLOTS: u64 = DEFAULT_SLOTS_PER_EPOCH / 4;
+// Keeps a single outlier sample from dominating the average when the next sample comes late
+pub const MAX_SLOTS_PER_WEIGHT_SAMPLE: u64 = 10;
+// Anything at or below half of the stake could let two ballots reach consensus
+pub const MIN_CONSENSUS_THRESHOLD_BPS: u16 = 5_001;
+pub const MAX_CONSENSUS_THRESHOLD_BPS: u16 = 10_000;
+pub const MAX_QUORUM_OPERATOR_BPS: u16 = 10_000;
+pub const MIN_STALL_FALLBACK_THRESHOLD_BPS: u16 = 5_001;
+pub const MAX_STALL_FALLBACK_THRESHOLD_BPS: u16 = 10_000;
+// A lower cap could leave too little voting power to reach consensus
+pub const MIN_STAKE_WEIGHT_CAP_BPS: u16 = 100;
+pub const MAX_STAKE_WEIGHT_CAP_BPS: u16 = 10_000;
+pub const MAX_ADMIN_MULTISIG_SIGNERS: usize = 10;
+pub const MAX_ADMIN_ACTION_LEN: usize = 64;
+pub const MAX_ADMIN_HANDOVER_DELAY_EPOCHS: u16 = 50;
+// Crank steps have to move at least this multiple of the bounty to be paid one
+pub const MIN_CRANK_BOUNTY_REWARDS_MULTIPLE: u64 = 10;
+// Weight tables of epochs before a deactivation can still be created until the entry is removed
+pub const DEACTIVATED_ENTRY_RETENTION_EPOCHS: u64 = MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE;
 const PRECISE_CONSENSUS_NUMERATOR: u128 = 2;
 const PRECISE_CONSENSUS_DENOMINATOR: u128 = 3;
-pub fn precise_consensus() -> Result<PreciseNumber, TipRouterError> {
-    PreciseNumber::new(PRECISE_CONSENSUS_NUMERATOR)
-        .ok_or(TipRouterError::NewPreciseNumberError)?
-        .checked_div(
-            &PreciseNumber::new(PRECISE_CONSENSUS_DENOMINATOR)
-                .ok_or(TipRouterError::NewPreciseNumberError)?,
+/// The share of stake weight a ballot needs to reach consensus, a threshold of 0 bps
+/// falls back to the default of 2/3
+pub fn precise_consensus(consensus_threshold_bps: u16) -> Result<PreciseNumber, TipRouterError> {
+    let (numerator, denominator) = if consensus_threshold_bps == 0 {
+        (PRECISE_CONSENSUS_NUMERATOR, PRECISE_CONSENSUS_DENOMINATOR)
+    } else {
+        (
+            consensus_threshold_bps as u128,
+            MAX_CONSENSUS_THRESHOLD_BPS as u128,
         )
+    };
+
+    PreciseNumber::new(numerator)
+        .ok_or(TipRouterError::NewPreciseNumberError)?
+        .checked_div(&PreciseNumber::new(denominator).ok_or(TipRouterError::NewPreciseNumberError)?)
         .ok_or(TipRouterError::DenominatorIsZero)
 }
 
@@ -33,6 +75,13 @@ pub const MAX_REALLOC_BYTES: u64 = MAX_PERMITTED_DATA_INCREASE as u64;
 
 pub const WEIGHT_PRECISION: u128 = 1_000_000_000;
 pub const SWITCHBOARD_MAX_STALE_SLOTS: u64 = 100;
+/// Max standard deviation of a switchboard result, relative to its value
+pub const SWITCHBOARD_MAX_STD_DEV_BPS: u64 = 100;
+pub const PYTH_MAX_STALE_SECONDS: u64 = 60;
+/// Max confidence interval of a pyth price, relative to the price
+pub const PYTH_MAX_CONFIDENCE_BPS: u64 = 100;
+/// Stake pools must be updated in the current epoch by default
+pub const SPL_STAKE_POOL_MAX_STALE_EPOCHS: u64 = 0;
 pub const JTO_SOL_FEED: Pubkey = pubkey!("5S7ErPSkFmyXuq2aE3rZ6ofwVyZpwzUt6w7m6kqekvMe");
 pub const JITOSOL_SOL_FEED: Pubkey = pubkey!("4Z1SLH9g4ikNBV8uP2ZctEouqjYmVqB2Tz5SZxKYBN7z");
 
@@ -3,14 +3,21 @@ pub enum Discriminators {
     // Configs
     Config = 0x01,
     VaultRegistry = 0x02,
+    AdminMultisig = 0x03,
+    AdminProposal = 0x04,
+    VaultRegistryPage = 0x05,
 
     // Snapshots
     WeightTable = 0x10,
     EpochSnapshot = 0x11,
     OperatorSnapshot = 0x12,
+    WeightTablePage = 0x13,
+    OperatorSnapshotPage = 0x14,
 
     // Voting
     BallotBox = 0x20,
+    OperatorVoteKey = 0x21,
+    BallotBoxPage = 0x22,
 
     // Validation and Consensus
     // - Reserved for future use
@@ -18,8 +25,11 @@ pub enum Discriminators {
     // Distribution
     BaseRewardRouter = 0x40,
     NcnRewardRouter = 0x41,
+    PayoutPreference = 0x42,
+    BaseRewardRouterPage = 0x43,
 
     // State Tracking
     EpochState = 0x50,
     EpochMarker = 0x51,
+    EpochStatePage = 0x52,
 }
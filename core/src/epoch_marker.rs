@@ -1,19 +1,42 @@
 use std::mem::size_of;
 
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodBool, PodU128, PodU64},
+    AccountDeserialize, Discriminator,
+};
 use shank::{ShankAccount, ShankType};
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{discriminators::Discriminators, error::TipRouterError};
+use crate::{discriminators::Discriminators, epoch_state::EpochState, error::TipRouterError};
 
-/// 56-byte account to mark that an epoch's accounts have all been closed
+/// Marks that an epoch's accounts have all been closed, and archives the epoch's consensus
+/// and reward totals so they can be read after the epoch accounts are gone.
+/// Markers created before the archive was added are only 56 bytes.
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod, AccountDeserialize, ShankAccount)]
 #[repr(C)]
 pub struct EpochMarker {
     ncn: Pubkey,
     epoch: PodU64,
     slot_closed: PodU64,
+    /// Winning meta merkle root, all zeros if consensus was never reached
+    meta_merkle_root: [u8; 32],
+    /// Slot consensus was reached
+    slot_consensus_reached: PodU64,
+    /// Was the tie breaker used to reach consensus
+    was_tie_breaker_set: PodBool,
+    /// Total stake weight of the epoch snapshot
+    total_stake_weight: PodU128,
+    /// Number of operators that voted
+    operators_voted: PodU64,
+    /// Total rewards routed into the base reward router
+    total_rewards: PodU64,
+    /// Rewards distributed to the base fee groups
+    base_rewards: PodU64,
+    /// Rewards distributed to the NCN reward routers
+    ncn_rewards: PodU64,
+    /// Reserved space
+    reserved: [u8; 64],
 }
 
 impl Discriminator for EpochMarker {
@@ -28,9 +51,30 @@ impl EpochMarker {
             ncn: *ncn,
             epoch: PodU64::from(epoch),
             slot_closed: PodU64::from(slot_closed),
+            meta_merkle_root: [0; 32],
+            slot_consensus_reached: PodU64::from(0),
+            was_tie_breaker_set: PodBool::from(false),
+            total_stake_weight: PodU128::from(0),
+            operators_voted: PodU64::from(0),
+            total_rewards: PodU64::from(0),
+            base_rewards: PodU64::from(0),
+            ncn_rewards: PodU64::from(0),
+            reserved: [0; 64],
         }
     }
 
+    /// Copies the archived consensus and reward totals from the closing epoch state
+    pub fn archive(&mut self, epoch_state: &EpochState) {
+        self.meta_merkle_root = *epoch_state.winning_meta_merkle_root();
+        self.slot_consensus_reached = PodU64::from(epoch_state.slot_consensus_reached());
+        self.was_tie_breaker_set = PodBool::from(epoch_state.was_tie_breaker_set());
+        self.total_stake_weight = PodU128::from(epoch_state.total_stake_weight());
+        self.operators_voted = PodU64::from(epoch_state.voting_progress().tally());
+        self.total_rewards = PodU64::from(epoch_state.total_rewards());
+        self.base_rewards = PodU64::from(epoch_state.base_rewards_distributed());
+        self.ncn_rewards = PodU64::from(epoch_state.ncn_rewards_distributed());
+    }
+
     pub const fn ncn(&self) -> &Pubkey {
         &self.ncn
     }
@@ -43,6 +87,38 @@ impl EpochMarker {
         self.slot_closed.into()
     }
 
+    pub const fn meta_merkle_root(&self) -> &[u8; 32] {
+        &self.meta_merkle_root
+    }
+
+    pub fn slot_consensus_reached(&self) -> u64 {
+        self.slot_consensus_reached.into()
+    }
+
+    pub fn was_tie_breaker_set(&self) -> bool {
+        self.was_tie_breaker_set.into()
+    }
+
+    pub fn total_stake_weight(&self) -> u128 {
+        self.total_stake_weight.into()
+    }
+
+    pub fn operators_voted(&self) -> u64 {
+        self.operators_voted.into()
+    }
+
+    pub fn total_rewards(&self) -> u64 {
+        self.total_rewards.into()
+    }
+
+    pub fn base_rewards(&self) -> u64 {
+        self.base_rewards.into()
+    }
+
+    pub fn ncn_rewards(&self) -> u64 {
+        self.ncn_rewards.into()
+    }
+
     pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
         // Note: The second NCN is an error from the original code, most presumably a copy/paste or Claude error
         vec![
@@ -92,3 +168,54 @@ impl EpochMarker {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // ncn
+            + size_of::<PodU64>() // epoch
+            + size_of::<PodU64>() // slot_closed
+            + 32 // meta_merkle_root
+            + size_of::<PodU64>() // slot_consensus_reached
+            + size_of::<PodBool>() // was_tie_breaker_set
+            + size_of::<PodU128>() // total_stake_weight
+            + size_of::<PodU64>() // operators_voted
+            + size_of::<PodU64>() // total_rewards
+            + size_of::<PodU64>() // base_rewards
+            + size_of::<PodU64>() // ncn_rewards
+            + 64; // reserved
+
+        assert_eq!(size_of::<EpochMarker>(), expected_total);
+        assert_eq!(size_of::<EpochMarker>() + 8, EpochMarker::SIZE);
+    }
+
+    #[test]
+    fn test_archive() {
+        let ncn = Pubkey::new_unique();
+        let epoch = 10;
+        let mut epoch_state = EpochState::new(&ncn, epoch, 255, 0);
+
+        epoch_state.archive_epoch_snapshot(3_000);
+        epoch_state.update_cast_vote(2, true, 100).unwrap();
+        epoch_state.archive_ballot_box(&[1; 32]);
+        epoch_state.update_distribute_base_rewards(300).unwrap();
+        epoch_state.update_distribute_base_ncn_rewards(700).unwrap();
+        epoch_state.archive_base_reward_router(1_000);
+
+        let mut epoch_marker = EpochMarker::new(&ncn, epoch, 500);
+        epoch_marker.archive(&epoch_state);
+
+        assert_eq!(epoch_marker.slot_closed(), 500);
+        assert_eq!(*epoch_marker.meta_merkle_root(), [1; 32]);
+        assert_eq!(epoch_marker.slot_consensus_reached(), 100);
+        assert!(!epoch_marker.was_tie_breaker_set());
+        assert_eq!(epoch_marker.total_stake_weight(), 3_000);
+        assert_eq!(epoch_marker.operators_voted(), 2);
+        assert_eq!(epoch_marker.total_rewards(), 1_000);
+        assert_eq!(epoch_marker.base_rewards(), 300);
+        assert_eq!(epoch_marker.ncn_rewards(), 700);
+    }
+}
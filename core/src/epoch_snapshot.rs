@@ -1,20 +1,28 @@
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
-    types::{PodBool, PodU16, PodU64},
+    types::{PodBool, PodU128, PodU16, PodU64},
     AccountDeserialize, Discriminator,
 };
 use jito_vault_core::vault_operator_delegation::VaultOperatorDelegation;
 use shank::{ShankAccount, ShankType};
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
-    base_fee_group::BaseFeeGroup, constants::MAX_VAULTS, discriminators::Discriminators,
-    error::TipRouterError, fees::Fees, loaders::check_load, ncn_fee_group::NcnFeeGroup,
-    stake_weight::StakeWeights, weight_table::WeightTable,
+    account_version::{check_account_version, VersionedAccount},
+    base_fee_group::BaseFeeGroup,
+    constants::{MAX_FEE_BPS, MAX_VAULTS},
+    discriminators::Discriminators,
+    error::TipRouterError,
+    fees::Fees,
+    loaders::check_load,
+    ncn_fee_group::NcnFeeGroup,
+    operator_snapshot_page::OperatorSnapshotPage,
+    stake_weight::StakeWeights,
+    weight_table::WeightTable,
 };
 
 // PDA'd ["epoch_snapshot", NCN, NCN_EPOCH_SLOT]
@@ -43,14 +51,25 @@ pub struct EpochSnapshot {
     valid_operator_vault_delegations: PodU64,
     /// Tallies the total stake weights for all vault operator delegations
     stake_weights: StakeWeights,
+    /// Number of registered operators that were active for the NCN in the epoch
+    active_operator_count: PodU64,
+    /// Number of operators whose stake weight caps have been applied
+    operators_capped: PodU64,
+    /// Total voting stake weight removed by the stake weight caps
+    capped_stake_weight: PodU128,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 96],
 }
 
 impl Discriminator for EpochSnapshot {
     const DISCRIMINATOR: u8 = Discriminators::EpochSnapshot as u8;
 }
 
+impl VersionedAccount for EpochSnapshot {
+    const ACCOUNT_VERSION: u8 = 1;
+    const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+}
+
 impl EpochSnapshot {
     pub const SIZE: usize = 8 + size_of::<Self>();
 
@@ -75,7 +94,10 @@ impl EpochSnapshot {
             operators_registered: PodU64::from(0),
             valid_operator_vault_delegations: PodU64::from(0),
             stake_weights: StakeWeights::default(),
-            reserved: [0; 128],
+            active_operator_count: PodU64::from(0),
+            operators_capped: PodU64::from(0),
+            capped_stake_weight: PodU128::from(0),
+            reserved: [0; 96],
         }
     }
 
@@ -116,7 +138,8 @@ impl EpochSnapshot {
             &expected_pda,
             Some(Self::DISCRIMINATOR),
             expect_writable,
-        )
+        )?;
+        check_account_version::<Self>(account)
     }
 
     pub fn load_to_close(
@@ -144,14 +167,38 @@ impl EpochSnapshot {
         self.operators_registered.into()
     }
 
+    pub fn active_operator_count(&self) -> u64 {
+        self.active_operator_count.into()
+    }
+
     pub fn valid_operator_vault_delegations(&self) -> u64 {
         self.valid_operator_vault_delegations.into()
     }
 
+    pub fn operators_capped(&self) -> u64 {
+        self.operators_capped.into()
+    }
+
+    pub fn capped_stake_weight(&self) -> u128 {
+        self.capped_stake_weight.into()
+    }
+
     pub const fn stake_weights(&self) -> &StakeWeights {
         &self.stake_weights
     }
 
+    /// The total stake weight votes are tallied against, once every operator's caps are applied
+    pub fn voting_stake_weight(&self) -> Result<u128, TipRouterError> {
+        if !self.stake_weight_caps_applied() {
+            return Err(TipRouterError::StakeWeightCapsNotApplied);
+        }
+
+        self.stake_weights
+            .stake_weight()
+            .checked_sub(self.capped_stake_weight())
+            .ok_or(TipRouterError::ArithmeticUnderflowError)
+    }
+
     pub const fn fees(&self) -> &Fees {
         &self.fees
     }
@@ -164,9 +211,37 @@ impl EpochSnapshot {
         self.operators_registered() == self.operator_count()
     }
 
+    pub fn stake_weight_caps_applied(&self) -> bool {
+        self.finalized() && self.operators_capped() == self.operator_count()
+    }
+
+    pub fn increment_operators_capped(
+        &mut self,
+        capped_stake_weight: u128,
+    ) -> Result<(), TipRouterError> {
+        if self.stake_weight_caps_applied() {
+            return Err(TipRouterError::StakeWeightCapsAlreadyApplied);
+        }
+
+        self.operators_capped = PodU64::from(
+            self.operators_capped()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        self.capped_stake_weight = PodU128::from(
+            self.capped_stake_weight()
+                .checked_add(capped_stake_weight)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
     pub fn increment_operator_registration(
         &mut self,
         current_slot: u64,
+        is_active: bool,
         vault_operator_delegations: u64,
         stake_weight: &StakeWeights,
     ) -> Result<(), TipRouterError> {
@@ -180,6 +255,14 @@ impl EpochSnapshot {
                 .ok_or(TipRouterError::ArithmeticOverflow)?,
         );
 
+        if is_active {
+            self.active_operator_count = PodU64::from(
+                self.active_operator_count()
+                    .checked_add(1)
+                    .ok_or(TipRouterError::ArithmeticOverflow)?,
+            );
+        }
+
         self.valid_operator_vault_delegations = PodU64::from(
             self.valid_operator_vault_delegations()
                 .checked_add(vault_operator_delegations)
@@ -219,7 +302,18 @@ pub struct OperatorSnapshot {
     valid_operator_vault_delegations: PodU64,
 
     stake_weights: StakeWeights,
-    reserved: [u8; 256],
+
+    /// Snapshot of the config's max share in bps of the total stake weight the operator votes with
+    max_operator_stake_weight_bps: PodU16,
+    /// Snapshot of the config's max share in bps of the total stake weight per vault delegation
+    max_vault_stake_weight_bps: PodU16,
+    /// Voting stake weight removed by the caps, set once the epoch snapshot is finalized
+    capped_stake_weight: PodU128,
+    /// Whether the stake weight caps have been applied against the epoch's total stake weight
+    stake_weight_caps_applied: PodBool,
+    /// Number of operator snapshot pages holding the vaults past `MAX_VAULTS`
+    page_count: PodU64,
+    reserved: [u8; 227],
 
     vault_operator_stake_weight: [VaultOperatorStakeWeight; 64],
 }
@@ -228,6 +322,11 @@ impl Discriminator for OperatorSnapshot {
     const DISCRIMINATOR: u8 = Discriminators::OperatorSnapshot as u8;
 }
 
+impl VersionedAccount for OperatorSnapshot {
+    const ACCOUNT_VERSION: u8 = 1;
+    const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+}
+
 impl OperatorSnapshot {
     pub const SIZE: usize = 8 + size_of::<Self>();
 
@@ -244,10 +343,6 @@ impl OperatorSnapshot {
         operator_fee_bps: u16,
         vault_operator_delegation_count: u64,
     ) -> Result<Self, TipRouterError> {
-        if vault_operator_delegation_count > MAX_VAULTS as u64 {
-            return Err(TipRouterError::TooManyVaultOperatorDelegations);
-        }
-
         Ok(Self {
             operator: *operator,
             ncn: *ncn,
@@ -263,7 +358,12 @@ impl OperatorSnapshot {
             vault_operator_delegations_registered: PodU64::from(0),
             valid_operator_vault_delegations: PodU64::from(0),
             stake_weights: StakeWeights::default(),
-            reserved: [0; 256],
+            max_operator_stake_weight_bps: PodU16::from(0),
+            max_vault_stake_weight_bps: PodU16::from(0),
+            capped_stake_weight: PodU128::from(0),
+            stake_weight_caps_applied: PodBool::from(false),
+            page_count: PodU64::from(0),
+            reserved: [0; 227],
             vault_operator_stake_weight: [VaultOperatorStakeWeight::default(); MAX_VAULTS],
         })
     }
@@ -282,9 +382,6 @@ impl OperatorSnapshot {
         operator_fee_bps: u16,
         vault_operator_delegation_count: u64,
     ) -> Result<(), TipRouterError> {
-        if vault_operator_delegation_count > MAX_VAULTS as u64 {
-            return Err(TipRouterError::TooManyVaultOperatorDelegations);
-        }
         let slot_finalized = if !is_active { current_slot } else { 0 };
         let operator_fee_bps = if is_active { operator_fee_bps } else { 0 };
         let vault_operator_delegation_count = if is_active {
@@ -308,7 +405,12 @@ impl OperatorSnapshot {
         self.vault_operator_delegations_registered = PodU64::from(0);
         self.valid_operator_vault_delegations = PodU64::from(0);
         self.stake_weights = StakeWeights::default();
-        self.reserved = [0; 256];
+        self.max_operator_stake_weight_bps = PodU16::from(0);
+        self.max_vault_stake_weight_bps = PodU16::from(0);
+        self.capped_stake_weight = PodU128::from(0);
+        self.stake_weight_caps_applied = PodBool::from(false);
+        self.page_count = PodU64::from(0);
+        self.reserved = [0; 227];
         self.vault_operator_stake_weight = [VaultOperatorStakeWeight::default(); MAX_VAULTS];
 
         Ok(())
@@ -354,7 +456,8 @@ impl OperatorSnapshot {
             &expected_pda,
             Some(Self::DISCRIMINATOR),
             expect_writable,
-        )
+        )?;
+        check_account_version::<Self>(account)
     }
 
     pub fn load_to_close(
@@ -418,6 +521,191 @@ impl OperatorSnapshot {
         self.vault_operator_delegations_registered() == self.vault_operator_delegation_count()
     }
 
+    pub fn max_operator_stake_weight_bps(&self) -> u16 {
+        self.max_operator_stake_weight_bps.into()
+    }
+
+    pub fn max_vault_stake_weight_bps(&self) -> u16 {
+        self.max_vault_stake_weight_bps.into()
+    }
+
+    pub fn capped_stake_weight(&self) -> u128 {
+        self.capped_stake_weight.into()
+    }
+
+    pub fn stake_weight_caps_applied(&self) -> bool {
+        self.stake_weight_caps_applied.into()
+    }
+
+    pub fn page_count(&self) -> u64 {
+        self.page_count.into()
+    }
+
+    /// Pages are added in order
+    pub fn add_page(&mut self, page: u64) -> Result<(), TipRouterError> {
+        if page != self.page_count() {
+            msg!("Expected operator snapshot page {}", self.page_count());
+            return Err(TipRouterError::InvalidVaultPage);
+        }
+
+        self.page_count = PodU64::from(
+            self.page_count()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Pages are removed last first
+    pub fn remove_page(&mut self, page: u64) -> Result<(), TipRouterError> {
+        if self.page_count() == 0 || page != self.page_count() - 1 {
+            msg!("Operator snapshot pages are closed last first");
+            return Err(TipRouterError::InvalidVaultPage);
+        }
+
+        self.page_count = PodU64::from(self.page_count() - 1);
+
+        Ok(())
+    }
+
+    /// Fixes the stake weight caps for the epoch, 0 disables a cap
+    pub fn set_stake_weight_caps(
+        &mut self,
+        max_operator_stake_weight_bps: u16,
+        max_vault_stake_weight_bps: u16,
+    ) {
+        self.max_operator_stake_weight_bps = PodU16::from(max_operator_stake_weight_bps);
+        self.max_vault_stake_weight_bps = PodU16::from(max_vault_stake_weight_bps);
+    }
+
+    /// Whether applying the caps depends on the epoch's total stake weight. Operators without
+    /// stake weight or caps can have their caps applied as soon as they are finalized.
+    pub fn has_stake_weight_caps(&self) -> bool {
+        self.stake_weights.stake_weight() > 0
+            && (self.max_operator_stake_weight_bps() > 0 || self.max_vault_stake_weight_bps() > 0)
+    }
+
+    /// The stake weights the operator votes with, once the caps have been applied. Only the
+    /// voting stake weight is capped, the NCN fee group weights used for rewards are left as is.
+    pub fn voting_stake_weights(&self) -> Result<StakeWeights, TipRouterError> {
+        if !self.stake_weight_caps_applied() {
+            return Err(TipRouterError::StakeWeightCapsNotApplied);
+        }
+
+        let stake_weight = self
+            .stake_weights
+            .stake_weight()
+            .checked_sub(self.capped_stake_weight())
+            .ok_or(TipRouterError::ArithmeticUnderflowError)?;
+
+        Ok(self.stake_weights.with_stake_weight(stake_weight))
+    }
+
+    /// Caps the voting stake weight against the finalized epoch's total stake weight. Each vault
+    /// delegation is capped at `max_vault_stake_weight_bps` of the total, then their sum is capped
+    /// at `max_operator_stake_weight_bps`. Returns the stake weight removed by the caps.
+    ///
+    /// `pages` has to hold all of the operator snapshot pages in order when the vault cap is set.
+    pub fn apply_stake_weight_caps(
+        &mut self,
+        total_stake_weight: u128,
+        pages: &[&OperatorSnapshotPage],
+    ) -> Result<u128, TipRouterError> {
+        if !self.finalized() {
+            return Err(TipRouterError::OperatorSnapshotNotFinalized);
+        }
+
+        if self.stake_weight_caps_applied() {
+            return Err(TipRouterError::StakeWeightCapsAlreadyApplied);
+        }
+
+        let max_vault_stake_weight =
+            Self::stake_weight_cap(total_stake_weight, self.max_vault_stake_weight_bps())?;
+        let max_operator_stake_weight =
+            Self::stake_weight_cap(total_stake_weight, self.max_operator_stake_weight_bps())?;
+
+        // Without a vault cap, or stake weight, the sum of the vault stake weights is the
+        // operator's stake weight
+        let stake_weight =
+            if self.max_vault_stake_weight_bps() == 0 || self.stake_weights.stake_weight() == 0 {
+                self.stake_weights.stake_weight()
+            } else {
+                self.check_pages(pages)?;
+
+                let mut stake_weight: u128 = 0;
+                for vault_operator_stake_weight in self.vault_operator_stake_weight.iter().chain(
+                    pages
+                        .iter()
+                        .flat_map(|page| page.vault_operator_stake_weight()),
+                ) {
+                    if vault_operator_stake_weight.is_empty() {
+                        continue;
+                    }
+
+                    let vault_stake_weight = vault_operator_stake_weight
+                        .stake_weights()
+                        .stake_weight()
+                        .min(max_vault_stake_weight);
+
+                    stake_weight = stake_weight
+                        .checked_add(vault_stake_weight)
+                        .ok_or(TipRouterError::ArithmeticOverflow)?;
+                }
+
+                stake_weight
+            };
+
+        let stake_weight = stake_weight.min(max_operator_stake_weight);
+
+        let capped_stake_weight = self
+            .stake_weights
+            .stake_weight()
+            .checked_sub(stake_weight)
+            .ok_or(TipRouterError::ArithmeticUnderflowError)?;
+
+        self.capped_stake_weight = PodU128::from(capped_stake_weight);
+        self.stake_weight_caps_applied = PodBool::from(true);
+
+        Ok(capped_stake_weight)
+    }
+
+    /// Checks `pages` holds every page of this operator snapshot, in order
+    pub fn check_pages(&self, pages: &[&OperatorSnapshotPage]) -> Result<(), TipRouterError> {
+        if pages.len() as u64 != self.page_count() {
+            msg!(
+                "Expected {} operator snapshot pages, got {}",
+                self.page_count(),
+                pages.len()
+            );
+            return Err(TipRouterError::InvalidVaultPage);
+        }
+
+        for (i, page) in pages.iter().enumerate() {
+            if page.operator().ne(&self.operator)
+                || page.ncn().ne(&self.ncn)
+                || page.epoch() != self.epoch()
+                || page.page() != i as u64
+            {
+                return Err(TipRouterError::InvalidVaultPage);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `cap_bps` of `total_stake_weight`, unlimited when `cap_bps` is 0
+    fn stake_weight_cap(total_stake_weight: u128, cap_bps: u16) -> Result<u128, TipRouterError> {
+        if cap_bps == 0 {
+            return Ok(u128::MAX);
+        }
+
+        total_stake_weight
+            .checked_mul(cap_bps as u128)
+            .and_then(|weight| weight.checked_div(MAX_FEE_BPS as u128))
+            .ok_or(TipRouterError::ArithmeticOverflow)
+    }
+
     pub fn contains_vault_index(&self, vault_index: u64) -> bool {
         self.vault_operator_stake_weight
             .iter()
@@ -441,20 +729,19 @@ impl OperatorSnapshot {
         ncn_fee_group: NcnFeeGroup,
         stake_weights: &StakeWeights,
     ) -> Result<(), TipRouterError> {
-        if self
-            .vault_operator_delegations_registered()
-            .checked_add(1)
-            .ok_or(TipRouterError::ArithmeticOverflow)?
-            > MAX_VAULTS as u64
-        {
-            return Err(TipRouterError::TooManyVaultOperatorDelegations);
-        }
-
         if self.contains_vault_index(vault_index) {
             return Err(TipRouterError::DuplicateVaultOperatorDelegation);
         }
 
-        self.vault_operator_stake_weight[self.vault_operator_delegations_registered() as usize] =
+        // Vaults past `MAX_VAULTS` are kept in the operator snapshot pages, so the header isn't
+        // filled in registration order
+        let vault_operator_stake_weight = self
+            .vault_operator_stake_weight
+            .iter_mut()
+            .find(|v| v.is_empty())
+            .ok_or(TipRouterError::TooManyVaultOperatorDelegations)?;
+
+        *vault_operator_stake_weight =
             VaultOperatorStakeWeight::new(vault, vault_index, ncn_fee_group, stake_weights);
 
         Ok(())
@@ -474,6 +761,27 @@ impl OperatorSnapshot {
 
         self.insert_vault_operator_stake_weight(vault, vault_index, ncn_fee_group, stake_weights)?;
 
+        self.register_vault_operator_delegation(current_slot, stake_weights)
+    }
+
+    /// Counts a vault operator delegation whose stake weight is kept in an operator snapshot page
+    pub fn increment_paged_vault_operator_delegation_registration(
+        &mut self,
+        current_slot: u64,
+        stake_weights: &StakeWeights,
+    ) -> Result<(), TipRouterError> {
+        if self.finalized() {
+            return Err(TipRouterError::VaultOperatorDelegationFinalized);
+        }
+
+        self.register_vault_operator_delegation(current_slot, stake_weights)
+    }
+
+    fn register_vault_operator_delegation(
+        &mut self,
+        current_slot: u64,
+        stake_weights: &StakeWeights,
+    ) -> Result<(), TipRouterError> {
         self.vault_operator_delegations_registered = PodU64::from(
             self.vault_operator_delegations_registered()
                 .checked_add(1)
@@ -592,9 +900,12 @@ impl fmt::Display for EpochSnapshot {
        writeln!(f, "  Operator Count:               {}", self.operator_count())?;
        writeln!(f, "  Vault Count:                  {}", self.vault_count())?;
        writeln!(f, "  Operators Registered:         {}", self.operators_registered())?;
+       writeln!(f, "  Active Operators:             {}", self.active_operator_count())?;
        writeln!(f, "  Valid Delegations:            {}", self.valid_operator_vault_delegations())?;
        writeln!(f, "  Slot Finalized:               {}", self.slot_finalized())?;
        writeln!(f, "  Finalized:                    {}", self.finalized())?;
+       writeln!(f, "  Operators Capped:             {}", self.operators_capped())?;
+       writeln!(f, "  Capped Stake Weight:          {}", self.capped_stake_weight())?;
 
        writeln!(f, "\nFees:")?;
        writeln!(f, "\n  Base Fee Group Fees:")?;
@@ -644,6 +955,10 @@ impl fmt::Display for OperatorSnapshot {
 
        let stake_weights = self.stake_weights();
        writeln!(f, "\nTotal Stake Weight: {}", stake_weights.stake_weight())?;
+       writeln!(f, "  Max Operator Stake Bps:       {}", self.max_operator_stake_weight_bps())?;
+       writeln!(f, "  Max Vault Stake Bps:          {}", self.max_vault_stake_weight_bps())?;
+       writeln!(f, "  Capped Stake Weight:          {}", self.capped_stake_weight())?;
+       writeln!(f, "  Caps Applied:                 {}", self.stake_weight_caps_applied())?;
        writeln!(f, "\nStake Weights by Group:")?;
        for group in NcnFeeGroup::all_groups() {
            if let Ok(weight) = stake_weights.ncn_fee_group_stake_weight(group) {
@@ -697,7 +1012,12 @@ mod tests {
             + size_of::<PodU64>() // vault_operator_delegations_registered
             + size_of::<PodU64>() // valid_operator_vault_delegations
             + size_of::<StakeWeights>() // stake_weight
-            + 256 // reserved
+            + size_of::<PodU16>() // max_operator_stake_weight_bps
+            + size_of::<PodU16>() // max_vault_stake_weight_bps
+            + size_of::<PodU128>() // capped_stake_weight
+            + size_of::<PodBool>() // stake_weight_caps_applied
+            + size_of::<PodU64>() // page_count
+            + 227 // reserved
             + size_of::<VaultOperatorStakeWeight>() * MAX_VAULTS; // vault_operator_stake_weight
 
         assert_eq!(size_of::<OperatorSnapshot>(), expected_total);
@@ -771,7 +1091,147 @@ mod tests {
     }
 
     #[test]
-    fn test_initialize_too_many_vault_operator_delegations() {
+    fn test_voting_stake_weights() {
+        let mut snapshot = OperatorSnapshot::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            1,
+            1,
+            100,
+            true,
+            0,
+            0,
+            100,
+            2,
+        )
+        .unwrap();
+
+        // 300 from one vault, 100 from another, with a reward multiplier of 2x
+        for (vault_index, stake_weight) in [(0, 300), (1, 100)] {
+            let stake_weights =
+                StakeWeights::snapshot(NcnFeeGroup::default(), stake_weight, 20_000).unwrap();
+            snapshot
+                .increment_vault_operator_delegation_registration(
+                    200,
+                    &Pubkey::new_unique(),
+                    vault_index,
+                    NcnFeeGroup::default(),
+                    &stake_weights,
+                )
+                .unwrap();
+        }
+        assert!(snapshot.finalized());
+
+        let total_stake_weight = 1_000;
+
+        // Votes can't be weighed before the caps are applied
+        assert_eq!(
+            snapshot.voting_stake_weights().unwrap_err(),
+            TipRouterError::StakeWeightCapsNotApplied
+        );
+
+        // No caps
+        let mut uncapped = snapshot;
+        assert!(!uncapped.has_stake_weight_caps());
+        assert_eq!(
+            uncapped.apply_stake_weight_caps(total_stake_weight, &[]),
+            Ok(0)
+        );
+        assert_eq!(uncapped.voting_stake_weights().unwrap().stake_weight(), 400);
+
+        // Caps are only applied once
+        assert_eq!(
+            uncapped.apply_stake_weight_caps(total_stake_weight, &[]),
+            Err(TipRouterError::StakeWeightCapsAlreadyApplied)
+        );
+
+        // Each vault delegation at most 20% of the total
+        let mut vault_capped = snapshot;
+        vault_capped.set_stake_weight_caps(0, 2_000);
+        assert!(vault_capped.has_stake_weight_caps());
+        assert_eq!(
+            vault_capped.apply_stake_weight_caps(total_stake_weight, &[]),
+            Ok(100)
+        );
+        assert_eq!(
+            vault_capped.voting_stake_weights().unwrap().stake_weight(),
+            300
+        );
+
+        // And the operator at most 25% of the total
+        let mut operator_capped = snapshot;
+        operator_capped.set_stake_weight_caps(2_500, 2_000);
+        assert_eq!(
+            operator_capped.apply_stake_weight_caps(total_stake_weight, &[]),
+            Ok(150)
+        );
+        let voting_stake_weights = operator_capped.voting_stake_weights().unwrap();
+        assert_eq!(voting_stake_weights.stake_weight(), 250);
+        assert_eq!(operator_capped.capped_stake_weight(), 150);
+
+        // Reward weights are not capped
+        assert_eq!(
+            voting_stake_weights
+                .ncn_fee_group_stake_weight(NcnFeeGroup::default())
+                .unwrap(),
+            400 * 20_000
+        );
+        assert_eq!(operator_capped.stake_weights().stake_weight(), 400);
+    }
+
+    #[test]
+    fn test_apply_stake_weight_caps_not_finalized() {
+        let mut snapshot = OperatorSnapshot::new(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            1,
+            1,
+            100,
+            true,
+            0,
+            0,
+            100,
+            1,
+        )
+        .unwrap();
+
+        assert_eq!(
+            snapshot.apply_stake_weight_caps(1_000, &[]),
+            Err(TipRouterError::OperatorSnapshotNotFinalized)
+        );
+    }
+
+    #[test]
+    fn test_epoch_snapshot_voting_stake_weight() {
+        let fees = Fees::new(900, 100, 1).unwrap();
+        let mut epoch_snapshot = EpochSnapshot::new(&Pubkey::new_unique(), 1, 1, 100, &fees, 2, 1);
+
+        let stake_weights = StakeWeights::snapshot(NcnFeeGroup::default(), 500, 1).unwrap();
+        for _ in 0..2 {
+            epoch_snapshot
+                .increment_operator_registration(200, true, 1, &stake_weights)
+                .unwrap();
+        }
+        assert!(epoch_snapshot.finalized());
+        assert!(!epoch_snapshot.stake_weight_caps_applied());
+        assert_eq!(
+            epoch_snapshot.voting_stake_weight(),
+            Err(TipRouterError::StakeWeightCapsNotApplied)
+        );
+
+        epoch_snapshot.increment_operators_capped(0).unwrap();
+        epoch_snapshot.increment_operators_capped(200).unwrap();
+        assert!(epoch_snapshot.stake_weight_caps_applied());
+        assert_eq!(epoch_snapshot.voting_stake_weight(), Ok(800));
+
+        assert_eq!(
+            epoch_snapshot.increment_operators_capped(0),
+            Err(TipRouterError::StakeWeightCapsAlreadyApplied)
+        );
+    }
+
+    #[test]
+    fn test_initialize_vault_operator_delegations_past_max_vaults() {
         // Create an operator snapshot
         let mut snapshot = OperatorSnapshot::new(
             &Pubkey::new_unique(),
@@ -787,7 +1247,7 @@ mod tests {
         )
         .unwrap();
 
-        // Try to initialize with vault_operator_delegation_count > MAX_VAULTS
+        // Vaults past MAX_VAULTS are kept in operator snapshot pages
         let result = snapshot.initialize(
             &Pubkey::new_unique(),   // operator
             &Pubkey::new_unique(),   // ncn
@@ -801,10 +1261,10 @@ mod tests {
             (MAX_VAULTS as u64) + 1, // vault_operator_delegation_count > MAX_VAULTS
         );
 
-        // Verify we get the expected error
+        assert!(result.is_ok());
         assert_eq!(
-            result.unwrap_err(),
-            TipRouterError::TooManyVaultOperatorDelegations
+            snapshot.vault_operator_delegation_count(),
+            MAX_VAULTS as u64 + 1
         );
     }
 
@@ -821,17 +1281,26 @@ mod tests {
             0,
             0,
             100,
-            1,
+            MAX_VAULTS as u64 + 1,
         )
         .unwrap();
 
-        // Set the registered delegations to MAX_VAULTS
-        snapshot.vault_operator_delegations_registered = PodU64::from(MAX_VAULTS as u64);
+        // Fill every slot of the header
+        for vault_index in 0..MAX_VAULTS as u64 {
+            snapshot
+                .insert_vault_operator_stake_weight(
+                    &Pubkey::new_unique(),
+                    vault_index,
+                    NcnFeeGroup::default(),
+                    &StakeWeights::default(),
+                )
+                .unwrap();
+        }
 
         // Try to insert another vault operator stake weight
         let result = snapshot.insert_vault_operator_stake_weight(
             &Pubkey::new_unique(),
-            1,
+            MAX_VAULTS as u64,
             NcnFeeGroup::default(),
             &StakeWeights::default(),
         );
@@ -891,8 +1360,8 @@ mod tests {
     }
 
     #[test]
-    fn test_operator_snapshot_new_too_many_delegations() {
-        // Try to create a new OperatorSnapshot with vault_operator_delegation_count > MAX_VAULTS
+    fn test_operator_snapshot_new_delegations_past_max_vaults() {
+        // Vaults past MAX_VAULTS are kept in operator snapshot pages
         let result = OperatorSnapshot::new(
             &Pubkey::new_unique(),
             &Pubkey::new_unique(),
@@ -906,11 +1375,70 @@ mod tests {
             (MAX_VAULTS as u64) + 1, // vault_operator_delegation_count exceeds MAX_VAULTS
         );
 
-        // Verify we get the expected error
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_stake_weight_caps_with_pages() {
+        let operator = Pubkey::new_unique();
+        let ncn = Pubkey::new_unique();
+        let mut snapshot =
+            OperatorSnapshot::new(&operator, &ncn, 1, 1, 100, true, 0, 0, 100, 2).unwrap();
+
+        // One vault in the header and one past MAX_VAULTS
+        let stake_weights = StakeWeights::snapshot(NcnFeeGroup::default(), 300, 10_000).unwrap();
+        snapshot
+            .increment_vault_operator_delegation_registration(
+                200,
+                &Pubkey::new_unique(),
+                0,
+                NcnFeeGroup::default(),
+                &stake_weights,
+            )
+            .unwrap();
+
+        snapshot.add_page(0).unwrap();
+        assert_eq!(snapshot.add_page(0), Err(TipRouterError::InvalidVaultPage));
+
+        let mut page = OperatorSnapshotPage::new(&operator, &ncn, 1, 0, 0);
+        page.insert_vault_operator_stake_weight(
+            MAX_VAULTS as u64,
+            &Pubkey::new_unique(),
+            70,
+            NcnFeeGroup::default(),
+            &stake_weights,
+        )
+        .unwrap();
+        snapshot
+            .increment_paged_vault_operator_delegation_registration(200, &stake_weights)
+            .unwrap();
+        assert!(snapshot.finalized());
+
+        // Each vault delegation at most 20% of the total
+        snapshot.set_stake_weight_caps(0, 2_000);
+
+        // The pages are needed to cap the vault delegations
+        let mut missing_pages = snapshot;
         assert_eq!(
-            result.unwrap_err(),
-            TipRouterError::TooManyVaultOperatorDelegations
+            missing_pages.apply_stake_weight_caps(1_000, &[]),
+            Err(TipRouterError::InvalidVaultPage)
         );
+
+        let other_page = OperatorSnapshotPage::new(&Pubkey::new_unique(), &ncn, 1, 0, 0);
+        assert_eq!(
+            missing_pages.apply_stake_weight_caps(1_000, &[&other_page]),
+            Err(TipRouterError::InvalidVaultPage)
+        );
+
+        assert_eq!(snapshot.apply_stake_weight_caps(1_000, &[&page]), Ok(200));
+        assert_eq!(snapshot.voting_stake_weights().unwrap().stake_weight(), 400);
+
+        assert_eq!(
+            snapshot.remove_page(1),
+            Err(TipRouterError::InvalidVaultPage)
+        );
+        snapshot.remove_page(0).unwrap();
+        assert_eq!(snapshot.page_count(), 0);
     }
 
     #[test]
@@ -932,8 +1460,9 @@ mod tests {
 
         // Try to increment operator registration when already finalized
         let result = snapshot.increment_operator_registration(
-            200, // current_slot
-            1,   // vault_operator_delegations
+            200,  // current_slot
+            true, // is_active
+            1,    // vault_operator_delegations
             &StakeWeights::default(),
         );
 
@@ -3,7 +3,7 @@ use std::mem::size_of;
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
-    types::{PodBool, PodU64},
+    types::{PodBool, PodU128, PodU16, PodU64},
     AccountDeserialize, Discriminator,
 };
 use shank::{ShankAccount, ShankType};
@@ -13,8 +13,9 @@ use solana_program::{
 };
 
 use crate::{
-    constants::{DEFAULT_CONSENSUS_REACHED_SLOT, MAX_OPERATORS},
+    constants::{DEFAULT_CONSENSUS_REACHED_SLOT, MAX_OPERATORS, MIN_CRANK_BOUNTY_REWARDS_MULTIPLE},
     discriminators::Discriminators,
+    epoch_state_page::EpochStatePage,
     error::TipRouterError,
     loaders::check_load,
     ncn_fee_group::NcnFeeGroup,
@@ -80,8 +81,13 @@ impl EpochAccountStatus {
         Self::get_account_status(self.epoch_snapshot)
     }
 
-    pub const fn operator_snapshot(&self, index: usize) -> Result<AccountStatus, TipRouterError> {
-        Self::get_account_status(self.operator_snapshot[index])
+    /// Operators past `MAX_OPERATORS` are tracked in an `EpochStatePage`
+    pub fn operator_snapshot(&self, index: usize) -> Result<AccountStatus, TipRouterError> {
+        let status = self
+            .operator_snapshot
+            .get(index)
+            .ok_or(TipRouterError::InvalidEpochStatePage)?;
+        Self::get_account_status(*status)
     }
 
     pub const fn ballot_box(&self) -> Result<AccountStatus, TipRouterError> {
@@ -97,9 +103,11 @@ impl EpochAccountStatus {
         index: usize,
         group: NcnFeeGroup,
     ) -> Result<AccountStatus, TipRouterError> {
-        Self::get_account_status(
-            self.ncn_reward_router[EpochState::get_ncn_reward_router_index(index, group)?],
-        )
+        let status = self
+            .ncn_reward_router
+            .get(EpochState::get_ncn_reward_router_index(index, group)?)
+            .ok_or(TipRouterError::InvalidEpochStatePage)?;
+        Self::get_account_status(*status)
     }
 
     pub const fn set_epoch_state(&mut self, status: AccountStatus) {
@@ -114,8 +122,17 @@ impl EpochAccountStatus {
         self.epoch_snapshot = status as u8;
     }
 
-    pub const fn set_operator_snapshot(&mut self, index: usize, status: AccountStatus) {
-        self.operator_snapshot[index] = status as u8;
+    pub fn set_operator_snapshot(
+        &mut self,
+        index: usize,
+        status: AccountStatus,
+    ) -> Result<(), TipRouterError> {
+        let operator_snapshot = self
+            .operator_snapshot
+            .get_mut(index)
+            .ok_or(TipRouterError::InvalidEpochStatePage)?;
+        *operator_snapshot = status as u8;
+        Ok(())
     }
 
     pub const fn set_ballot_box(&mut self, status: AccountStatus) {
@@ -132,8 +149,11 @@ impl EpochAccountStatus {
         group: NcnFeeGroup,
         status: AccountStatus,
     ) -> Result<(), TipRouterError> {
-        self.ncn_reward_router[EpochState::get_ncn_reward_router_index(index, group)?] =
-            status as u8;
+        let ncn_reward_router = self
+            .ncn_reward_router
+            .get_mut(EpochState::get_ncn_reward_router_index(index, group)?)
+            .ok_or(TipRouterError::InvalidEpochStatePage)?;
+        *ncn_reward_router = status as u8;
         Ok(())
     }
 
@@ -314,8 +334,44 @@ pub struct EpochState {
     /// Is closing
     is_closing: PodBool,
 
+    /// Consensus threshold in bps taken from the config when this epoch state was created, 0 uses the default of 2/3
+    consensus_threshold_bps: PodU16,
+
+    /// Was the stalled vote finalized through the permissionless fallback
+    was_stall_fallback_used: PodBool,
+
+    /// Winning meta merkle root, recorded when the ballot box is closed
+    winning_meta_merkle_root: [u8; 32],
+
+    /// Total stake weight, recorded when the epoch snapshot is closed
+    total_stake_weight: PodU128,
+
+    /// Total rewards routed into the base reward router, recorded when it is closed
+    total_rewards: PodU64,
+
+    /// Rewards distributed from the base reward router to the base fee groups
+    base_rewards_distributed: PodU64,
+
+    /// Rewards distributed from the base reward router to the NCN reward routers
+    ncn_rewards_distributed: PodU64,
+
+    /// Number of SPL token base and NCN reward routers that are not closed yet
+    open_token_reward_routers: PodU16,
+
+    /// Undistributed rewards of this epoch carried forward into a later epoch
+    rewards_carried_forward: PodU64,
+
+    /// Rewards carried forward into this epoch from earlier epochs
+    rewards_carried_in: PodU64,
+
+    /// Lamports paid out in crank bounties for this epoch
+    crank_bounties_paid: PodU64,
+
+    /// Number of epoch state pages, tracking the operators past `MAX_OPERATORS`
+    page_count: PodU64,
+
     /// Reserved space
-    reserved: [u8; 1023],
+    reserved: [u8; 914],
 }
 
 impl Discriminator for EpochState {
@@ -347,7 +403,19 @@ impl EpochState {
             ncn_distribution_progress: [Progress::default();
                 MAX_OPERATORS * NcnFeeGroup::FEE_GROUP_COUNT],
             is_closing: PodBool::from(false),
-            reserved: [0; 1023],
+            consensus_threshold_bps: PodU16::from(0),
+            was_stall_fallback_used: PodBool::from(false),
+            winning_meta_merkle_root: [0; 32],
+            total_stake_weight: PodU128::from(0),
+            total_rewards: PodU64::from(0),
+            base_rewards_distributed: PodU64::from(0),
+            ncn_rewards_distributed: PodU64::from(0),
+            open_token_reward_routers: PodU16::from(0),
+            rewards_carried_forward: PodU64::from(0),
+            rewards_carried_in: PodU64::from(0),
+            crank_bounties_paid: PodU64::from(0),
+            page_count: PodU64::from(0),
+            reserved: [0; 914],
         }
     }
 
@@ -358,7 +426,19 @@ impl EpochState {
         self.epoch = PodU64::from(epoch);
         self.slot_created = PodU64::from(slot_created);
         self.slot_consensus_reached = PodU64::from(DEFAULT_CONSENSUS_REACHED_SLOT);
-        self.reserved = [0; 1023];
+        self.consensus_threshold_bps = PodU16::from(0);
+        self.was_stall_fallback_used = PodBool::from(false);
+        self.winning_meta_merkle_root = [0; 32];
+        self.total_stake_weight = PodU128::from(0);
+        self.total_rewards = PodU64::from(0);
+        self.base_rewards_distributed = PodU64::from(0);
+        self.ncn_rewards_distributed = PodU64::from(0);
+        self.open_token_reward_routers = PodU16::from(0);
+        self.rewards_carried_forward = PodU64::from(0);
+        self.rewards_carried_in = PodU64::from(0);
+        self.crank_bounties_paid = PodU64::from(0);
+        self.page_count = PodU64::from(0);
+        self.reserved = [0; 914];
     }
 
     pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
@@ -422,6 +502,16 @@ impl EpochState {
             return Err(TipRouterError::CannotCloseEpochStateAccount.into());
         }
 
+        if account_to_close.open_token_reward_routers() > 0 {
+            msg!("Cannot close Epoch State until all token reward routers are closed");
+            return Err(TipRouterError::CannotCloseEpochStateAccount.into());
+        }
+
+        if account_to_close.page_count() > 0 {
+            msg!("Cannot close Epoch State until all of its pages are closed");
+            return Err(TipRouterError::CannotCloseEpochStateAccount.into());
+        }
+
         Ok(())
     }
 
@@ -457,6 +547,81 @@ impl EpochState {
         Ok(index)
     }
 
+    /// Checks that the page extends this epoch state
+    fn check_page(&self, page: Option<&EpochStatePage>) -> Result<(), TipRouterError> {
+        if let Some(page) = page {
+            if page.ncn().ne(&self.ncn)
+                || page.epoch() != self.epoch()
+                || page.page() >= self.page_count()
+            {
+                return Err(TipRouterError::InvalidEpochStatePage);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The operator snapshot progress, kept in the page for operators past `MAX_OPERATORS`
+    fn operator_snapshot_progress_mut<'a>(
+        operator_snapshot_progress: &'a mut [Progress; MAX_OPERATORS],
+        page: Option<&'a mut EpochStatePage>,
+        ncn_operator_index: usize,
+    ) -> Result<&'a mut Progress, TipRouterError> {
+        match page {
+            Some(page) => page.operator_snapshot_progress_mut(ncn_operator_index),
+            None => operator_snapshot_progress
+                .get_mut(ncn_operator_index)
+                .ok_or(TipRouterError::InvalidEpochStatePage),
+        }
+    }
+
+    /// The NCN distribution progress, kept in the page for operators past `MAX_OPERATORS`
+    fn ncn_distribution_progress_mut<'a>(
+        ncn_distribution_progress: &'a mut [Progress; MAX_OPERATORS * NcnFeeGroup::FEE_GROUP_COUNT],
+        page: Option<&'a mut EpochStatePage>,
+        ncn_operator_index: usize,
+        group: NcnFeeGroup,
+    ) -> Result<&'a mut Progress, TipRouterError> {
+        match page {
+            Some(page) => page.ncn_distribution_progress_mut(ncn_operator_index, group),
+            None => ncn_distribution_progress
+                .get_mut(Self::get_ncn_reward_router_index(
+                    ncn_operator_index,
+                    group,
+                )?)
+                .ok_or(TipRouterError::InvalidEpochStatePage),
+        }
+    }
+
+    fn set_operator_snapshot_status(
+        &mut self,
+        page: Option<&mut EpochStatePage>,
+        ncn_operator_index: usize,
+        status: AccountStatus,
+    ) -> Result<(), TipRouterError> {
+        match page {
+            Some(page) => page.set_operator_snapshot(ncn_operator_index, status),
+            None => self
+                .account_status
+                .set_operator_snapshot(ncn_operator_index, status),
+        }
+    }
+
+    fn set_ncn_reward_router_status(
+        &mut self,
+        page: Option<&mut EpochStatePage>,
+        ncn_operator_index: usize,
+        group: NcnFeeGroup,
+        status: AccountStatus,
+    ) -> Result<(), TipRouterError> {
+        match page {
+            Some(page) => page.set_ncn_reward_router(ncn_operator_index, group, status),
+            None => self
+                .account_status
+                .set_ncn_reward_router(ncn_operator_index, group, status),
+        }
+    }
+
     pub fn _set_upload_progress(&mut self) {
         self.upload_progress = Progress::new(1);
     }
@@ -479,6 +644,10 @@ impl EpochState {
         self.was_tie_breaker_set.into()
     }
 
+    pub fn was_stall_fallback_used(&self) -> bool {
+        self.was_stall_fallback_used.into()
+    }
+
     pub fn is_consensus_reached(&self) -> bool {
         self.slot_consensus_reached() != DEFAULT_CONSENSUS_REACHED_SLOT
     }
@@ -491,6 +660,10 @@ impl EpochState {
         self.is_closing.into()
     }
 
+    pub fn consensus_threshold_bps(&self) -> u16 {
+        self.consensus_threshold_bps.into()
+    }
+
     pub fn get_slot_consensus_reached(&self) -> Result<u64, TipRouterError> {
         if self.slot_consensus_reached() == DEFAULT_CONSENSUS_REACHED_SLOT {
             Err(TipRouterError::ConsensusNotReached)
@@ -513,6 +686,89 @@ impl EpochState {
         self.operator_count.into()
     }
 
+    pub const fn winning_meta_merkle_root(&self) -> &[u8; 32] {
+        &self.winning_meta_merkle_root
+    }
+
+    pub fn total_stake_weight(&self) -> u128 {
+        self.total_stake_weight.into()
+    }
+
+    pub fn total_rewards(&self) -> u64 {
+        self.total_rewards.into()
+    }
+
+    pub fn base_rewards_distributed(&self) -> u64 {
+        self.base_rewards_distributed.into()
+    }
+
+    pub fn ncn_rewards_distributed(&self) -> u64 {
+        self.ncn_rewards_distributed.into()
+    }
+
+    pub fn open_token_reward_routers(&self) -> u16 {
+        self.open_token_reward_routers.into()
+    }
+
+    pub fn rewards_carried_forward(&self) -> u64 {
+        self.rewards_carried_forward.into()
+    }
+
+    pub fn rewards_carried_in(&self) -> u64 {
+        self.rewards_carried_in.into()
+    }
+
+    pub fn crank_bounties_paid(&self) -> u64 {
+        self.crank_bounties_paid.into()
+    }
+
+    pub fn page_count(&self) -> u64 {
+        self.page_count.into()
+    }
+
+    /// Records a new page, pages are created in order
+    pub fn add_page(&mut self, page: u64) -> Result<(), TipRouterError> {
+        if page != self.page_count() {
+            return Err(TipRouterError::InvalidEpochStatePage);
+        }
+
+        self.page_count = PodU64::from(
+            page.checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Records a closed page, pages are closed from the last one and the epoch state can only
+    /// be closed once all of them are
+    pub fn remove_page(&mut self, page: u64) -> Result<(), TipRouterError> {
+        if page.checked_add(1) != Some(self.page_count()) {
+            return Err(TipRouterError::InvalidEpochStatePage);
+        }
+
+        self.page_count = PodU64::from(page);
+
+        Ok(())
+    }
+
+    /// The bounty owed for a crank step that moved `rewards`, capped by what is left of the
+    /// per-epoch bounty budget. Steps moving less than `MIN_CRANK_BOUNTY_REWARDS_MULTIPLE` times
+    /// the bounty are not paid, so dust rewards cannot be cranked to drain the budget.
+    pub fn crank_bounty(
+        &self,
+        bounty_lamports: u64,
+        max_bounty_lamports_per_epoch: u64,
+        rewards: u64,
+    ) -> u64 {
+        if rewards < bounty_lamports.saturating_mul(MIN_CRANK_BOUNTY_REWARDS_MULTIPLE) {
+            return 0;
+        }
+
+        bounty_lamports
+            .min(max_bounty_lamports_per_epoch.saturating_sub(self.crank_bounties_paid()))
+    }
+
     pub fn vault_count(&self) -> u64 {
         self.vault_count.into()
     }
@@ -529,8 +785,21 @@ impl EpochState {
         self.epoch_snapshot_progress
     }
 
-    pub const fn operator_snapshot_progress(&self, ncn_operator_index: usize) -> Progress {
-        self.operator_snapshot_progress[ncn_operator_index]
+    pub fn operator_snapshot_progress(
+        &self,
+        ncn_operator_index: usize,
+        page: Option<&EpochStatePage>,
+    ) -> Result<Progress, TipRouterError> {
+        self.check_page(page)?;
+
+        match page {
+            Some(page) => page.operator_snapshot_progress(ncn_operator_index),
+            None => self
+                .operator_snapshot_progress
+                .get(ncn_operator_index)
+                .copied()
+                .ok_or(TipRouterError::InvalidEpochStatePage),
+        }
     }
 
     pub const fn voting_progress(&self) -> Progress {
@@ -555,16 +824,31 @@ impl EpochState {
 
     pub fn ncn_distribution_progress(
         &self,
-        ncn_ncn_operator_index: usize,
+        ncn_operator_index: usize,
         group: NcnFeeGroup,
+        page: Option<&EpochStatePage>,
     ) -> Result<Progress, TipRouterError> {
-        let index = Self::get_ncn_reward_router_index(ncn_ncn_operator_index, group)?;
-        Ok(self.ncn_distribution_progress[index])
+        self.check_page(page)?;
+
+        match page {
+            Some(page) => page.ncn_distribution_progress(ncn_operator_index, group),
+            None => self
+                .ncn_distribution_progress
+                .get(Self::get_ncn_reward_router_index(
+                    ncn_operator_index,
+                    group,
+                )?)
+                .copied()
+                .ok_or(TipRouterError::InvalidEpochStatePage),
+        }
     }
 
     // ------------ UPDATERS ------------
-    pub const fn update_realloc_epoch_state(&mut self) {
+    pub fn update_realloc_epoch_state(&mut self, consensus_threshold_bps: u16) {
         self.account_status.set_epoch_state(AccountStatus::Created);
+
+        // Fixed for the epoch so later config changes don't affect a live vote
+        self.consensus_threshold_bps = PodU16::from(consensus_threshold_bps);
     }
 
     pub fn update_realloc_weight_table(&mut self, vault_count: u64, st_mint_count: u64) {
@@ -574,6 +858,19 @@ impl EpochState {
         self.set_weight_progress = Progress::new(st_mint_count);
     }
 
+    pub fn update_initialize_weight_table_page(
+        &mut self,
+        vault_count: u64,
+    ) -> Result<(), TipRouterError> {
+        self.vault_count = PodU64::from(
+            self.vault_count()
+                .checked_add(vault_count)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
     pub fn update_set_weight(&mut self, weights_set: u64, st_mint_count: u64) {
         self.set_weight_progress.set_tally(weights_set);
         self.set_weight_progress.set_total(st_mint_count)
@@ -591,16 +888,27 @@ impl EpochState {
         &mut self,
         ncn_operator_index: usize,
         is_active: bool,
+        mut page: Option<&mut EpochStatePage>,
     ) -> Result<(), TipRouterError> {
-        self.account_status
-            .set_operator_snapshot(ncn_operator_index, AccountStatus::Created);
+        self.check_page(page.as_deref())?;
+        self.set_operator_snapshot_status(
+            page.as_deref_mut(),
+            ncn_operator_index,
+            AccountStatus::Created,
+        )?;
+
+        let vault_count = self.vault_count();
+        let progress = Self::operator_snapshot_progress_mut(
+            &mut self.operator_snapshot_progress,
+            page,
+            ncn_operator_index,
+        )?;
 
         if is_active {
-            self.operator_snapshot_progress[ncn_operator_index] =
-                Progress::new(self.vault_count.into());
+            *progress = Progress::new(vault_count);
         } else {
-            self.operator_snapshot_progress[ncn_operator_index] = Progress::new(1);
-            self.operator_snapshot_progress[ncn_operator_index].increment_one()?;
+            *progress = Progress::new(1);
+            progress.increment_one()?;
             self.epoch_snapshot_progress.increment_one()?;
         }
 
@@ -611,8 +919,15 @@ impl EpochState {
         &mut self,
         ncn_operator_index: usize,
         finalized: bool,
+        page: Option<&mut EpochStatePage>,
     ) -> Result<(), TipRouterError> {
-        self.operator_snapshot_progress[ncn_operator_index].increment_one()?;
+        self.check_page(page.as_deref())?;
+        Self::operator_snapshot_progress_mut(
+            &mut self.operator_snapshot_progress,
+            page,
+            ncn_operator_index,
+        )?
+        .increment_one()?;
         if finalized {
             self.epoch_snapshot_progress.increment_one()?;
         }
@@ -633,7 +948,9 @@ impl EpochState {
         is_consensus_reached: bool,
         current_slot: u64,
     ) -> Result<(), TipRouterError> {
-        if is_consensus_reached && !self.is_consensus_reached() {
+        // The tie breaker admin can replace a stall fallback result
+        if is_consensus_reached && (!self.is_consensus_reached() || self.was_stall_fallback_used())
+        {
             self.slot_consensus_reached = PodU64::from(current_slot);
         }
 
@@ -642,14 +959,20 @@ impl EpochState {
         Ok(())
     }
 
+    pub fn update_withdraw_vote(&mut self, operators_voted: u64) {
+        self.voting_progress.set_tally(operators_voted);
+    }
+
     pub fn update_set_tie_breaker(
         &mut self,
         is_consensus_reached: bool,
+        is_stall_fallback: bool,
         current_slot: u64,
     ) -> Result<(), TipRouterError> {
         if is_consensus_reached && !self.is_consensus_reached() {
             self.slot_consensus_reached = PodU64::from(current_slot);
             self.was_tie_breaker_set = PodBool::from(true);
+            self.was_stall_fallback_used = PodBool::from(is_stall_fallback);
         }
 
         Ok(())
@@ -672,18 +995,34 @@ impl EpochState {
         &mut self,
         ncn_operator_index: usize,
         group: NcnFeeGroup,
+        mut page: Option<&mut EpochStatePage>,
     ) -> Result<(), TipRouterError> {
-        self.account_status.set_ncn_reward_router(
+        self.check_page(page.as_deref())?;
+        self.set_ncn_reward_router_status(
+            page.as_deref_mut(),
             ncn_operator_index,
             group,
             AccountStatus::CreatedWithReceiver,
         )?;
-        self.ncn_distribution_progress
-            [Self::get_ncn_reward_router_index(ncn_operator_index, group)?] = Progress::new(0);
+        *Self::ncn_distribution_progress_mut(
+            &mut self.ncn_distribution_progress,
+            page,
+            ncn_operator_index,
+            group,
+        )? = Progress::new(0);
 
         Ok(())
     }
 
+    pub fn update_initialize_token_reward_router(&mut self) -> Result<(), TipRouterError> {
+        self.open_token_reward_routers = PodU16::from(
+            self.open_token_reward_routers()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        Ok(())
+    }
+
     pub fn update_route_base_rewards(&mut self, total_rewards: u64) {
         self.total_distribution_progress.set_total(total_rewards);
         self.base_distribution_progress.set_total(total_rewards);
@@ -694,9 +1033,15 @@ impl EpochState {
         ncn_operator_index: usize,
         group: NcnFeeGroup,
         total_rewards: u64,
+        page: Option<&mut EpochStatePage>,
     ) -> Result<(), TipRouterError> {
-        self.ncn_distribution_progress
-            [Self::get_ncn_reward_router_index(ncn_operator_index, group)?]
+        self.check_page(page.as_deref())?;
+        Self::ncn_distribution_progress_mut(
+            &mut self.ncn_distribution_progress,
+            page,
+            ncn_operator_index,
+            group,
+        )?
         .set_total(total_rewards);
         Ok(())
     }
@@ -704,6 +1049,11 @@ impl EpochState {
     pub fn update_distribute_base_rewards(&mut self, rewards: u64) -> Result<(), TipRouterError> {
         self.total_distribution_progress.increment(rewards)?;
         self.base_distribution_progress.increment(rewards)?;
+        self.base_rewards_distributed = PodU64::from(
+            self.base_rewards_distributed()
+                .checked_add(rewards)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
         Ok(())
     }
 
@@ -712,6 +1062,11 @@ impl EpochState {
         rewards: u64,
     ) -> Result<(), TipRouterError> {
         self.base_distribution_progress.increment(rewards)?;
+        self.ncn_rewards_distributed = PodU64::from(
+            self.ncn_rewards_distributed()
+                .checked_add(rewards)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
         Ok(())
     }
 
@@ -720,15 +1075,51 @@ impl EpochState {
         ncn_operator_index: usize,
         group: NcnFeeGroup,
         rewards: u64,
+        page: Option<&mut EpochStatePage>,
     ) -> Result<(), TipRouterError> {
+        self.check_page(page.as_deref())?;
         self.total_distribution_progress.increment(rewards)?;
 
-        self.ncn_distribution_progress
-            [Self::get_ncn_reward_router_index(ncn_operator_index, group)?]
+        Self::ncn_distribution_progress_mut(
+            &mut self.ncn_distribution_progress,
+            page,
+            ncn_operator_index,
+            group,
+        )?
         .increment(rewards)?;
         Ok(())
     }
 
+    /// Unassigned rewards moved to a later epoch, rewards in transit are left to be distributed
+    pub fn update_carry_forward_rewards(&mut self, rewards: u64) -> Result<(), TipRouterError> {
+        self.rewards_carried_forward = PodU64::from(
+            self.rewards_carried_forward()
+                .checked_add(rewards)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        Ok(())
+    }
+
+    /// Rewards carried forward from an earlier epoch, they are routed like any other rewards
+    /// sent to this epoch's base reward receiver
+    pub fn update_carry_in_rewards(&mut self, rewards: u64) -> Result<(), TipRouterError> {
+        self.rewards_carried_in = PodU64::from(
+            self.rewards_carried_in()
+                .checked_add(rewards)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        Ok(())
+    }
+
+    pub fn update_crank_bounty_paid(&mut self, lamports: u64) -> Result<(), TipRouterError> {
+        self.crank_bounties_paid = PodU64::from(
+            self.crank_bounties_paid()
+                .checked_add(lamports)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        Ok(())
+    }
+
     // ---------- CLOSERS ----------
     pub fn set_is_closing(&mut self) {
         self.is_closing = PodBool::from(true);
@@ -742,14 +1133,34 @@ impl EpochState {
         self.account_status.set_weight_table(AccountStatus::Closed);
     }
 
+    // ---------- ARCHIVE ----------
+    // Kept on the epoch state while the other epoch accounts are closed, and copied into
+    // the `EpochMarker` when the epoch state itself is closed
+
+    pub fn archive_epoch_snapshot(&mut self, total_stake_weight: u128) {
+        self.total_stake_weight = PodU128::from(total_stake_weight);
+    }
+
+    pub const fn archive_ballot_box(&mut self, winning_meta_merkle_root: &[u8; 32]) {
+        self.winning_meta_merkle_root = *winning_meta_merkle_root;
+    }
+
+    pub fn archive_base_reward_router(&mut self, total_rewards: u64) {
+        self.total_rewards = PodU64::from(total_rewards);
+    }
+
     pub const fn close_epoch_snapshot(&mut self) {
         self.account_status
             .set_epoch_snapshot(AccountStatus::Closed);
     }
 
-    pub const fn close_operator_snapshot(&mut self, ncn_operator_index: usize) {
-        self.account_status
-            .set_operator_snapshot(ncn_operator_index, AccountStatus::Closed);
+    pub fn close_operator_snapshot(
+        &mut self,
+        ncn_operator_index: usize,
+        page: Option<&mut EpochStatePage>,
+    ) -> Result<(), TipRouterError> {
+        self.check_page(page.as_deref())?;
+        self.set_operator_snapshot_status(page, ncn_operator_index, AccountStatus::Closed)
     }
 
     pub const fn close_ballot_box(&mut self) {
@@ -761,13 +1172,23 @@ impl EpochState {
             .set_base_reward_router(AccountStatus::Closed);
     }
 
+    pub fn close_token_reward_router(&mut self) -> Result<(), TipRouterError> {
+        self.open_token_reward_routers = PodU16::from(
+            self.open_token_reward_routers()
+                .checked_sub(1)
+                .ok_or(TipRouterError::ArithmeticUnderflowError)?,
+        );
+        Ok(())
+    }
+
     pub fn close_ncn_reward_router(
         &mut self,
         ncn_operator_index: usize,
         group: NcnFeeGroup,
+        page: Option<&mut EpochStatePage>,
     ) -> Result<(), TipRouterError> {
-        self.account_status
-            .set_ncn_reward_router(ncn_operator_index, group, AccountStatus::Closed)
+        self.check_page(page.as_deref())?;
+        self.set_ncn_reward_router_status(page, ncn_operator_index, group, AccountStatus::Closed)
     }
 
     // ------------ STATE ------------
@@ -901,9 +1322,21 @@ impl fmt::Display for EpochState {
        writeln!(f, "  Bump:                         {}", self.bump)?;
        writeln!(f, "  Slot Created:                 {}", self.slot_created())?;
        writeln!(f, "  Was Tie Breaker Set:          {}", self.was_tie_breaker_set())?;
+       writeln!(f, "  Was Stall Fallback Used:      {}", self.was_stall_fallback_used())?;
        writeln!(f, "  Slot Consensus Reached:       {}", self.slot_consensus_reached())?;
+       writeln!(f, "  Consensus Threshold Bps:      {}", self.consensus_threshold_bps())?;
        writeln!(f, "  Operator Count:               {}", self.operator_count())?;
        writeln!(f, "  Vault Count:                  {}", self.vault_count())?;
+       writeln!(f, "  Winning Meta Merkle Root:     {:?}", self.winning_meta_merkle_root())?;
+       writeln!(f, "  Total Stake Weight:           {}", self.total_stake_weight())?;
+       writeln!(f, "  Total Rewards:                {}", self.total_rewards())?;
+       writeln!(f, "  Base Rewards Distributed:     {}", self.base_rewards_distributed())?;
+       writeln!(f, "  NCN Rewards Distributed:      {}", self.ncn_rewards_distributed())?;
+       writeln!(f, "  Open Token Reward Routers:    {}", self.open_token_reward_routers())?;
+       writeln!(f, "  Rewards Carried Forward:      {}", self.rewards_carried_forward())?;
+       writeln!(f, "  Rewards Carried In:           {}", self.rewards_carried_in())?;
+       writeln!(f, "  Crank Bounties Paid:          {}", self.crank_bounties_paid())?;
+       writeln!(f, "  Page Count:                   {}", self.page_count())?;
 
        writeln!(f, "\nAccount Status:")?;
        let epoch_state = self
@@ -963,8 +1396,9 @@ impl fmt::Display for EpochState {
        
        writeln!(f, "\nOperator Snapshot Progress:")?;
        for i in 0..MAX_OPERATORS {
-            if self.operator_snapshot_progress(i).total() > 0 {
-                writeln!(f, "  Operator {}:                   {}/{}", i, self.operator_snapshot_progress(i).tally(), self.operator_snapshot_progress(i).total())?;                
+            let progress = self.operator_snapshot_progress[i];
+            if progress.total() > 0 {
+                writeln!(f, "  Operator {}:                   {}/{}", i, progress.tally(), progress.total())?;
             }
        }
 
@@ -977,7 +1411,7 @@ impl fmt::Display for EpochState {
        writeln!(f, "\nNCN Distribution Progress:")?;
        for i in 0..MAX_OPERATORS {
            for group in NcnFeeGroup::all_groups() {
-               if let Ok(progress) = self.ncn_distribution_progress(i, group) {
+               if let Ok(progress) = self.ncn_distribution_progress(i, group, None) {
                     if progress.total() > 0 {
                         writeln!(f, "  Operator {} Group {}:           {}/{}", i, group.group, progress.tally(), progress.total())?;
                     } 
@@ -0,0 +1,411 @@
+use core::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::{MAX_OPERATORS, OPERATORS_PER_EPOCH_STATE_PAGE},
+    discriminators::Discriminators,
+    epoch_state::{AccountStatus, EpochAccountStatus, Progress},
+    error::TipRouterError,
+    loaders::check_load,
+    ncn_fee_group::NcnFeeGroup,
+};
+
+/// Extension of an epoch state tracking the operator snapshot and NCN reward router accounts of
+/// operators whose NCN operator index is past `MAX_OPERATORS`. Page `n` holds the operators from
+/// `MAX_OPERATORS + n * OPERATORS_PER_EPOCH_STATE_PAGE` onward.
+// PDA'd ["epoch_state_page", NCN, NCN_EPOCH_SLOT, PAGE]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct EpochStatePage {
+    /// The NCN this page is for
+    ncn: Pubkey,
+    /// The epoch this page is for
+    epoch: PodU64,
+    /// Index of the page within the epoch state
+    page: PodU64,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Reserved space
+    reserved: [u8; 127],
+    /// Status of the operator snapshots
+    operator_snapshot: [u8; 32],
+    /// Status of the NCN reward routers
+    ncn_reward_router: [u8; 256],
+    /// Progress on snapshotting operators
+    operator_snapshot_progress: [Progress; 32],
+    /// NCN distribution progress
+    ncn_distribution_progress: [Progress; 256],
+}
+
+impl Discriminator for EpochStatePage {
+    const DISCRIMINATOR: u8 = Discriminators::EpochStatePage as u8;
+}
+
+impl EpochStatePage {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(ncn: &Pubkey, epoch: u64, page: u64, bump: u8) -> Self {
+        Self {
+            ncn: *ncn,
+            epoch: PodU64::from(epoch),
+            page: PodU64::from(page),
+            bump,
+            reserved: [0; 127],
+            operator_snapshot: [AccountStatus::DNE as u8; OPERATORS_PER_EPOCH_STATE_PAGE],
+            ncn_reward_router: [AccountStatus::DNE as u8;
+                OPERATORS_PER_EPOCH_STATE_PAGE * NcnFeeGroup::FEE_GROUP_COUNT],
+            operator_snapshot_progress: [Progress::default(); OPERATORS_PER_EPOCH_STATE_PAGE],
+            ncn_distribution_progress: [Progress::default();
+                OPERATORS_PER_EPOCH_STATE_PAGE * NcnFeeGroup::FEE_GROUP_COUNT],
+        }
+    }
+
+    pub fn seeds(ncn: &Pubkey, epoch: u64, page: u64) -> Vec<Vec<u8>> {
+        vec![
+            b"epoch_state_page".to_vec(),
+            ncn.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+            page.to_le_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        page: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, epoch, page);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+        page: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ncn, epoch, page).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads any page of the epoch state, the page index is read from the account itself
+    pub fn load_any_page(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let page = {
+            let account_data = account.try_borrow_data()?;
+            Self::try_from_slice_unchecked(&account_data)?.page()
+        };
+
+        Self::load(program_id, account, ncn, epoch, page, expect_writable)
+    }
+
+    /// Pages can only be closed once all of the accounts they track are closed
+    pub fn load_to_close(
+        program_id: &Pubkey,
+        account_to_close: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+    ) -> Result<(), ProgramError> {
+        Self::load_any_page(program_id, account_to_close, ncn, epoch, true)?;
+
+        let account_data = account_to_close.try_borrow_data()?;
+        if !Self::try_from_slice_unchecked(&account_data)?.are_all_closed() {
+            msg!("Cannot close Epoch State Page until all of its accounts are closed");
+            return Err(TipRouterError::CannotCloseEpochStateAccount.into());
+        }
+
+        Ok(())
+    }
+
+    /// Loads the page tracking the operator at `ncn_operator_index`. The page is required for
+    /// operators past `MAX_OPERATORS` and rejected for the others.
+    pub fn load_for_operator<'a, 'info>(
+        program_id: &Pubkey,
+        account: Option<&'a AccountInfo<'info>>,
+        ncn: &Pubkey,
+        epoch: u64,
+        ncn_operator_index: u64,
+        expect_writable: bool,
+    ) -> Result<Option<&'a AccountInfo<'info>>, ProgramError> {
+        match (Self::page_for_operator(ncn_operator_index), account) {
+            (None, None) => Ok(None),
+            (Some(page), Some(account)) => {
+                Self::load(program_id, account, ncn, epoch, page, expect_writable)?;
+                Ok(Some(account))
+            }
+            (page, _) => {
+                msg!(
+                    "Operator {} is tracked in epoch state page {:?}",
+                    ncn_operator_index,
+                    page
+                );
+                Err(TipRouterError::InvalidEpochStatePage.into())
+            }
+        }
+    }
+
+    /// The page tracking an operator, `None` when it is tracked in the epoch state
+    pub fn page_for_operator(ncn_operator_index: u64) -> Option<u64> {
+        ncn_operator_index
+            .checked_sub(MAX_OPERATORS as u64)
+            .map(|index| index / OPERATORS_PER_EPOCH_STATE_PAGE as u64)
+    }
+
+    /// Number of pages needed to track `operator_count` operators
+    pub fn pages_needed(operator_count: u64) -> u64 {
+        operator_count
+            .saturating_sub(MAX_OPERATORS as u64)
+            .div_ceil(OPERATORS_PER_EPOCH_STATE_PAGE as u64)
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn page(&self) -> u64 {
+        self.page.into()
+    }
+
+    /// Position of the operator within this page
+    fn operator_offset(&self, ncn_operator_index: usize) -> Result<usize, TipRouterError> {
+        if Self::page_for_operator(ncn_operator_index as u64) != Some(self.page()) {
+            msg!(
+                "Operator {} is not tracked in epoch state page {}",
+                ncn_operator_index,
+                self.page()
+            );
+            return Err(TipRouterError::InvalidEpochStatePage);
+        }
+
+        Ok((ncn_operator_index - MAX_OPERATORS) % OPERATORS_PER_EPOCH_STATE_PAGE)
+    }
+
+    fn ncn_reward_router_offset(
+        &self,
+        ncn_operator_index: usize,
+        group: NcnFeeGroup,
+    ) -> Result<usize, TipRouterError> {
+        let offset = self.operator_offset(ncn_operator_index)?;
+        Ok(offset * NcnFeeGroup::FEE_GROUP_COUNT + group.group as usize)
+    }
+
+    pub fn operator_snapshot(
+        &self,
+        ncn_operator_index: usize,
+    ) -> Result<AccountStatus, TipRouterError> {
+        let offset = self.operator_offset(ncn_operator_index)?;
+        EpochAccountStatus::get_account_status(self.operator_snapshot[offset])
+    }
+
+    pub fn ncn_reward_router(
+        &self,
+        ncn_operator_index: usize,
+        group: NcnFeeGroup,
+    ) -> Result<AccountStatus, TipRouterError> {
+        let offset = self.ncn_reward_router_offset(ncn_operator_index, group)?;
+        EpochAccountStatus::get_account_status(self.ncn_reward_router[offset])
+    }
+
+    pub fn operator_snapshot_progress(
+        &self,
+        ncn_operator_index: usize,
+    ) -> Result<Progress, TipRouterError> {
+        let offset = self.operator_offset(ncn_operator_index)?;
+        Ok(self.operator_snapshot_progress[offset])
+    }
+
+    pub fn ncn_distribution_progress(
+        &self,
+        ncn_operator_index: usize,
+        group: NcnFeeGroup,
+    ) -> Result<Progress, TipRouterError> {
+        let offset = self.ncn_reward_router_offset(ncn_operator_index, group)?;
+        Ok(self.ncn_distribution_progress[offset])
+    }
+
+    pub(crate) fn set_operator_snapshot(
+        &mut self,
+        ncn_operator_index: usize,
+        status: AccountStatus,
+    ) -> Result<(), TipRouterError> {
+        let offset = self.operator_offset(ncn_operator_index)?;
+        self.operator_snapshot[offset] = status as u8;
+        Ok(())
+    }
+
+    pub(crate) fn set_ncn_reward_router(
+        &mut self,
+        ncn_operator_index: usize,
+        group: NcnFeeGroup,
+        status: AccountStatus,
+    ) -> Result<(), TipRouterError> {
+        let offset = self.ncn_reward_router_offset(ncn_operator_index, group)?;
+        self.ncn_reward_router[offset] = status as u8;
+        Ok(())
+    }
+
+    pub(crate) fn operator_snapshot_progress_mut(
+        &mut self,
+        ncn_operator_index: usize,
+    ) -> Result<&mut Progress, TipRouterError> {
+        let offset = self.operator_offset(ncn_operator_index)?;
+        Ok(&mut self.operator_snapshot_progress[offset])
+    }
+
+    pub(crate) fn ncn_distribution_progress_mut(
+        &mut self,
+        ncn_operator_index: usize,
+        group: NcnFeeGroup,
+    ) -> Result<&mut Progress, TipRouterError> {
+        let offset = self.ncn_reward_router_offset(ncn_operator_index, group)?;
+        Ok(&mut self.ncn_distribution_progress[offset])
+    }
+
+    pub fn are_all_closed(&self) -> bool {
+        self.operator_snapshot
+            .iter()
+            .chain(self.ncn_reward_router.iter())
+            .all(|status| {
+                *status == AccountStatus::DNE as u8 || *status == AccountStatus::Closed as u8
+            })
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for EpochStatePage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Epoch State Page -------------")?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Epoch:                        {}", self.epoch())?;
+        writeln!(f, "  Page:                         {}", self.page())?;
+
+        let first_operator = MAX_OPERATORS + self.page() as usize * OPERATORS_PER_EPOCH_STATE_PAGE;
+        let operators = first_operator..first_operator + OPERATORS_PER_EPOCH_STATE_PAGE;
+
+        writeln!(f, "\nOperator Snapshots:")?;
+        for i in operators.clone() {
+            if let Ok(status) = self.operator_snapshot(i) {
+                if status != AccountStatus::DNE {
+                    let progress = self.operator_snapshot_progress[i - first_operator];
+                    writeln!(f, "  Operator {}:                   {:?} {}/{}", i, status, progress.tally(), progress.total())?;
+                }
+            }
+        }
+
+        writeln!(f, "\nNCN Reward Routers:")?;
+        for i in operators {
+            for group in NcnFeeGroup::all_groups() {
+                if let (Ok(status), Ok(progress)) = (self.ncn_reward_router(i, group), self.ncn_distribution_progress(i, group)) {
+                    if status != AccountStatus::DNE {
+                        writeln!(f, "  Operator {} Group {}:           {:?} {}/{}", i, group.group, status, progress.tally(), progress.total())?;
+                    }
+                }
+            }
+        }
+
+        writeln!(f, "\n")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MAX_REALLOC_BYTES;
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // ncn
+            + size_of::<PodU64>() // epoch
+            + size_of::<PodU64>() // page
+            + 1 // bump
+            + 127 // reserved
+            + OPERATORS_PER_EPOCH_STATE_PAGE // operator_snapshot
+            + OPERATORS_PER_EPOCH_STATE_PAGE * NcnFeeGroup::FEE_GROUP_COUNT // ncn_reward_router
+            + size_of::<Progress>() * OPERATORS_PER_EPOCH_STATE_PAGE // operator_snapshot_progress
+            + size_of::<Progress>() * OPERATORS_PER_EPOCH_STATE_PAGE * NcnFeeGroup::FEE_GROUP_COUNT; // ncn_distribution_progress
+
+        assert_eq!(size_of::<EpochStatePage>(), expected_total);
+
+        // Pages are created in a single instruction
+        assert!(EpochStatePage::SIZE <= MAX_REALLOC_BYTES as usize);
+    }
+
+    #[test]
+    fn test_operator_offset() {
+        let per_page = OPERATORS_PER_EPOCH_STATE_PAGE;
+        let page = EpochStatePage::new(&Pubkey::new_unique(), 1, 1, 0);
+
+        assert_eq!(
+            EpochStatePage::page_for_operator(MAX_OPERATORS as u64 - 1),
+            None
+        );
+        assert_eq!(
+            EpochStatePage::page_for_operator(MAX_OPERATORS as u64),
+            Some(0)
+        );
+        assert_eq!(EpochStatePage::pages_needed(MAX_OPERATORS as u64), 0);
+        assert_eq!(EpochStatePage::pages_needed(MAX_OPERATORS as u64 + 1), 1);
+
+        assert_eq!(page.operator_offset(MAX_OPERATORS + per_page), Ok(0));
+        assert_eq!(
+            page.operator_offset(MAX_OPERATORS + 2 * per_page - 1),
+            Ok(per_page - 1)
+        );
+        assert_eq!(
+            page.operator_offset(MAX_OPERATORS + per_page - 1),
+            Err(TipRouterError::InvalidEpochStatePage)
+        );
+        assert_eq!(
+            page.operator_offset(0),
+            Err(TipRouterError::InvalidEpochStatePage)
+        );
+    }
+
+    #[test]
+    fn test_are_all_closed() {
+        let operator = MAX_OPERATORS + 3;
+        let group = NcnFeeGroup::default();
+        let mut page = EpochStatePage::new(&Pubkey::new_unique(), 1, 0, 0);
+        assert!(page.are_all_closed());
+
+        page.set_operator_snapshot(operator, AccountStatus::Created)
+            .unwrap();
+        page.set_ncn_reward_router(operator, group, AccountStatus::CreatedWithReceiver)
+            .unwrap();
+        assert!(!page.are_all_closed());
+
+        page.set_operator_snapshot(operator, AccountStatus::Closed)
+            .unwrap();
+        assert!(!page.are_all_closed());
+
+        page.set_ncn_reward_router(operator, group, AccountStatus::Closed)
+            .unwrap();
+        assert!(page.are_all_closed());
+    }
+}
@@ -189,6 +189,170 @@ pub enum TipRouterError {
     EpochIsClosingDown,
     #[error("Marker exists")]
     MarkerExists,
+    #[error("Invalid commit window")]
+    InvalidCommitWindow,
+    #[error("Invalid reveal window")]
+    InvalidRevealWindow,
+    #[error("Commit-reveal voting is not enabled for this ballot box")]
+    CommitRevealNotEnabled,
+    #[error("Votes must be committed and revealed for this ballot box")]
+    CommitRevealRequired,
+    #[error("Commit phase has ended")]
+    CommitPhaseEnded,
+    #[error("Reveal phase has not started")]
+    RevealPhaseNotStarted,
+    #[error("Reveal phase has ended")]
+    RevealPhaseEnded,
+    #[error("Invalid vote commitment")]
+    InvalidVoteCommitment,
+    #[error("Vote commitment not found")]
+    VoteCommitmentNotFound,
+    #[error("Revealed vote does not match commitment")]
+    VoteCommitmentMismatch,
+    #[error("Vote already revealed")]
+    VoteAlreadyRevealed,
+    #[error("Invalid consensus threshold")]
+    InvalidConsensusThreshold,
+    #[error("Invalid quorum")]
+    InvalidQuorum,
+    #[error("Invalid stall fallback threshold")]
+    InvalidStallFallbackThreshold,
+    #[error("Stall fallback is not enabled")]
+    StallFallbackNotEnabled,
+    #[error("Leading ballot does not meet the stall fallback threshold")]
+    StallFallbackThresholdNotMet,
+    #[error("Invalid admin multisig signers or threshold")]
+    InvalidAdminMultisig,
+    #[error("Signer is not part of the admin multisig")]
+    AdminMultisigSignerNotFound,
+    #[error("Admin multisig is not the admin for this role")]
+    AdminMultisigNotActive,
+    #[error("Admin action does not belong to this role")]
+    AdminActionRoleMismatch,
+    #[error("Admin action is too large")]
+    AdminActionTooLarge,
+    #[error("Invalid admin action")]
+    InvalidAdminAction,
+    #[error("Admin proposal already approved by this signer")]
+    AdminProposalAlreadyApproved,
+    #[error("Admin proposal already executed")]
+    AdminProposalAlreadyExecuted,
+    #[error("Admin proposal does not have enough approvals")]
+    AdminProposalThresholdNotMet,
+    #[error("Admin multisig signers changed since the proposal was created")]
+    AdminProposalStale,
+    #[error("Invalid admin handover delay")]
+    InvalidAdminHandoverDelay,
+    #[error("Admin handover delay is set, propose and accept the new admin instead")]
+    AdminHandoverRequired,
+    #[error("Another admin handover is pending")]
+    AdminHandoverPending,
+    #[error("No admin handover is pending for this role")]
+    NoPendingAdminHandover,
+    #[error("Signer is not the pending admin")]
+    InvalidPendingAdmin,
+    #[error("Admin handover delay has not elapsed")]
+    AdminHandoverDelayNotElapsed,
+    #[error("Incorrect operator admin")]
+    IncorrectOperatorAdmin,
+    #[error("Operator vote not found")]
+    OperatorVoteNotFound,
+    #[error("Invalid oracle type")]
+    InvalidOracleType,
+    #[error("Switchboard confidence interval is too wide")]
+    SwitchboardConfidenceTooWide,
+    #[error("Bad pyth price update")]
+    BadPythPriceUpdate,
+    #[error("Bad pyth price")]
+    BadPythPrice,
+    #[error("Stale pyth price")]
+    StalePythPrice,
+    #[error("Pyth confidence interval is too wide")]
+    PythConfidenceTooWide,
+    #[error("Oracle weight changed too much since the last epoch")]
+    OracleWeightChangeTooLarge,
+    #[error("Invalid oracle guard")]
+    InvalidOracleGuard,
+    #[error("Bad stake pool")]
+    BadStakePool,
+    #[error("Stake pool has not been updated this epoch")]
+    StaleStakePool,
+    #[error("Stake pool mint does not match the ST mint")]
+    StakePoolMintMismatch,
+    #[error("Invalid weight sampling window")]
+    InvalidWeightSamplingWindow,
+    #[error("No weight samples")]
+    NoWeightSamples,
+    #[error("Invalid stake weight cap")]
+    InvalidStakeWeightCap,
+    #[error("Invalid deactivation epoch")]
+    InvalidDeactivationEpoch,
+    #[error("ST mint has active vaults")]
+    StMintHasActiveVaults,
+    #[error("ST mint is deactivated")]
+    StMintDeactivated,
+    #[error("Invalid ballot box page")]
+    InvalidBallotBoxPage,
+    #[error("Incorrect vault admin")]
+    IncorrectVaultAdmin,
+    #[error("Invalid payout asset")]
+    InvalidPayoutAsset,
+    #[error("Invalid payout destination")]
+    InvalidPayoutDestination,
+    #[error("Cannot carry rewards forward")]
+    CannotCarryForwardRewards,
+    #[error("Invalid fee activation epoch")]
+    InvalidFeeActivationEpoch,
+    #[error("Fee schedule is full")]
+    FeeScheduleFull,
+    #[error("No fees scheduled for this epoch")]
+    FeesNotScheduled,
+    #[error("Fee increase activates before the notice period")]
+    FeeIncreaseNoticeTooShort,
+    #[error("Invalid fee increase notice epochs")]
+    InvalidFeeIncreaseNoticeEpochs,
+    #[error("Base fee group is not enabled")]
+    BaseFeeGroupDisabled,
+    #[error("Base fee group is still charged a fee")]
+    BaseFeeGroupInUse,
+    #[error("Paused")]
+    Paused,
+    #[error("Invalid paused subsystems")]
+    InvalidPausedSubsystems,
+    #[error("Incorrect pause admin")]
+    IncorrectPauseAdmin,
+    #[error("Invalid account version")]
+    InvalidAccountVersion,
+    #[error("Account has no versioned layout")]
+    AccountNotVersioned,
+    #[error("Signers of an active admin multisig change through an admin action")]
+    AdminMultisigActive,
+    #[error("Only the proposer can close an open admin proposal")]
+    AdminProposalStillOpen,
+    #[error("Vault rewards can only be paid to accounts owned by the vault")]
+    VaultSolPayoutNotAllowed,
+    #[error("Mint is not an allowed reward mint")]
+    RewardMintNotAllowed,
+    #[error("Too many reward mints")]
+    TooManyRewardMints,
+    #[error("Operator snapshot not finalized")]
+    OperatorSnapshotNotFinalized,
+    #[error("Stake weight caps already applied")]
+    StakeWeightCapsAlreadyApplied,
+    #[error("Stake weight caps not applied")]
+    StakeWeightCapsNotApplied,
+    #[error("Invalid vault page")]
+    InvalidVaultPage,
+    #[error("Operator is not active in the NCN")]
+    OperatorNotActiveInNcn,
+    #[error("The vault registry is required to record weights with a weight change guard")]
+    VaultRegistryRequiredForWeightChangeGuard,
+    #[error("Invalid epoch state page")]
+    InvalidEpochStatePage,
+    #[error("Invalid base reward router page")]
+    InvalidBaseRewardRouterPage,
+    #[error("Incorrect admin proposal proposer")]
+    IncorrectAdminProposer,
 }
 
 #[allow(deprecated)]
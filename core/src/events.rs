@@ -0,0 +1,389 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+
+use crate::config::ConfigAdminRole;
+
+/// Prefix of the `Program data:` log lines written by `sol_log_data`
+pub const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+
+/// Prefix of the `Program <id> invoke [n]`, `Program <id> success` and `Program <id> failed: ...`
+/// log lines written by the runtime
+const PROGRAM_LOG_PREFIX: &str = "Program ";
+
+/// First field of every event, separates tip router events from other `sol_log_data` output
+pub const EVENT_DISCRIMINATOR: &[u8; 8] = b"tr_event";
+
+/// A state transition of the tip router, logged with `sol_log_data` as
+/// `[EVENT_DISCRIMINATOR, borsh(TipRouterEvent)]` so indexers don't have to parse `msg!` logs.
+/// New events are appended to keep the borsh variant indexes stable.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum TipRouterEvent {
+    /// An operator cast or revealed a vote
+    VoteCast {
+        ncn: Pubkey,
+        epoch: u64,
+        operator: Pubkey,
+        meta_merkle_root: [u8; 32],
+        stake_weight: u128,
+        slot: u64,
+    },
+    /// An operator committed to a vote without revealing it
+    VoteCommitted {
+        ncn: Pubkey,
+        epoch: u64,
+        operator: Pubkey,
+        slot: u64,
+    },
+    /// An operator withdrew their vote
+    VoteWithdrawn {
+        ncn: Pubkey,
+        epoch: u64,
+        operator: Pubkey,
+    },
+    /// The ballot box reached consensus
+    ConsensusReached {
+        ncn: Pubkey,
+        epoch: u64,
+        meta_merkle_root: [u8; 32],
+        slot: u64,
+    },
+    /// The tie breaker admin, or the stalled vote fallback, decided the winning ballot
+    TieBreakerSet {
+        ncn: Pubkey,
+        epoch: u64,
+        meta_merkle_root: [u8; 32],
+        is_stall_fallback: bool,
+    },
+    /// A validator's tip or priority fee distribution merkle root was uploaded
+    MerkleRootSet {
+        ncn: Pubkey,
+        epoch: u64,
+        vote_account: Pubkey,
+        distribution_account: Pubkey,
+        merkle_root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    },
+    /// Rewards were routed through the base reward router
+    BaseRewardsRouted {
+        ncn: Pubkey,
+        epoch: u64,
+        total_rewards: u64,
+    },
+    /// Rewards were routed through an operator's NCN reward router
+    NcnRewardsRouted {
+        ncn: Pubkey,
+        epoch: u64,
+        operator: Pubkey,
+        ncn_fee_group: u8,
+        total_rewards: u64,
+    },
+    /// Rewards of a base fee group were distributed to its wallet
+    BaseRewardsDistributed {
+        ncn: Pubkey,
+        epoch: u64,
+        base_fee_group: u8,
+        recipient: Pubkey,
+        rewards: u64,
+    },
+    /// Rewards of an NCN fee group were moved to an operator's NCN reward receiver
+    BaseNcnRewardsDistributed {
+        ncn: Pubkey,
+        epoch: u64,
+        operator: Pubkey,
+        ncn_fee_group: u8,
+        rewards: u64,
+    },
+    /// Rewards of an operator's NCN reward router were distributed to the operator or a vault
+    NcnRewardsDistributed {
+        ncn: Pubkey,
+        epoch: u64,
+        operator: Pubkey,
+        ncn_fee_group: u8,
+        recipient: Pubkey,
+        rewards: u64,
+    },
+    /// The fee config was updated
+    ConfigFeesUpdated {
+        ncn: Pubkey,
+        epoch: u64,
+        new_block_engine_fee_bps: Option<u16>,
+        base_fee_group: Option<u8>,
+        new_base_fee_wallet: Option<Pubkey>,
+        new_base_fee_bps: Option<u16>,
+        ncn_fee_group: Option<u8>,
+        new_ncn_fee_bps: Option<u16>,
+        new_priority_fee_distribution_fee_bps: Option<u16>,
+        activation_epoch: Option<u64>,
+    },
+    /// The config parameters were updated
+    ConfigParametersUpdated { ncn: Pubkey },
+    /// A config admin role was handed over
+    AdminUpdated {
+        ncn: Pubkey,
+        role: ConfigAdminRole,
+        new_admin: Pubkey,
+    },
+    /// An epoch account was closed
+    AccountClosed {
+        ncn: Pubkey,
+        epoch: u64,
+        account: Pubkey,
+        discriminator: u8,
+    },
+    /// Rewards left in a finished epoch's reward router were moved into a later epoch
+    RewardsCarriedForward {
+        ncn: Pubkey,
+        epoch: u64,
+        to_epoch: u64,
+        reward_router: Pubkey,
+        rewards: u64,
+    },
+    /// Fees scheduled to activate at `activation_epoch` were cancelled
+    ScheduledFeesCancelled {
+        ncn: Pubkey,
+        epoch: u64,
+        activation_epoch: u64,
+    },
+    /// A base fee group was updated
+    BaseFeeGroupUpdated {
+        ncn: Pubkey,
+        epoch: u64,
+        base_fee_group: u8,
+        name: Option<[u8; 32]>,
+        enabled: Option<bool>,
+        payout_asset: Option<u8>,
+    },
+    /// The account payer paid a cranker for a crank step
+    CrankBountyPaid {
+        ncn: Pubkey,
+        epoch: u64,
+        cranker: Pubkey,
+        lamports: u64,
+    },
+    /// The paused subsystems were updated
+    PausedSubsystemsUpdated {
+        ncn: Pubkey,
+        epoch: u64,
+        admin: Pubkey,
+        paused_subsystems: u8,
+    },
+    /// An account was upgraded to the next layout version
+    AccountMigrated {
+        ncn: Pubkey,
+        account: Pubkey,
+        discriminator: u8,
+        account_version: u8,
+    },
+    /// A claimant's tips or priority fees were claimed with the account payer paying rent
+    RewardsClaimed {
+        ncn: Pubkey,
+        distribution_program: Pubkey,
+        distribution_account: Pubkey,
+        claimant: Pubkey,
+        amount: u64,
+    },
+    /// The weight table admin set an ST mint's weight
+    AdminWeightSet {
+        ncn: Pubkey,
+        epoch: u64,
+        st_mint: Pubkey,
+        weight: u128,
+    },
+    /// An ST mint was registered in the vault registry
+    StMintRegistered {
+        ncn: Pubkey,
+        st_mint: Pubkey,
+        ncn_fee_group: u8,
+        reward_multiplier_bps: u64,
+    },
+    /// An ST mint's entry in the vault registry was updated
+    StMintUpdated { ncn: Pubkey, st_mint: Pubkey },
+    /// An ST mint was left out of the weight tables from `epoch` onward
+    StMintDeactivated {
+        ncn: Pubkey,
+        st_mint: Pubkey,
+        epoch: u64,
+    },
+    /// A vault was left out of the weight tables from `epoch` onward
+    VaultDeactivated {
+        ncn: Pubkey,
+        vault: Pubkey,
+        epoch: u64,
+    },
+    /// A config admin handover was proposed, the new admin can accept from `accept_epoch`
+    AdminHandoverProposed {
+        ncn: Pubkey,
+        role: ConfigAdminRole,
+        new_admin: Pubkey,
+        accept_epoch: u64,
+    },
+    /// A pending config admin handover was cancelled
+    AdminHandoverCancelled {
+        ncn: Pubkey,
+        role: ConfigAdminRole,
+        pending_admin: Pubkey,
+    },
+    /// An operator or vault set the asset its NCN rewards are paid out in
+    PayoutPreferenceSet {
+        ncn: Pubkey,
+        recipient: Pubkey,
+        payout_asset: u8,
+        sol_destination: Pubkey,
+    },
+}
+
+impl TipRouterEvent {
+    /// Logs the event with `sol_log_data`
+    pub fn emit(&self) {
+        // Serializing into a Vec can only fail on io errors
+        let data = borsh::to_vec(self).unwrap_or_default();
+        sol_log_data(&[EVENT_DISCRIMINATOR, &data]);
+    }
+
+    /// Decodes the fields of a `sol_log_data` entry, `None` when it is not a tip router event
+    pub fn decode(fields: &[&[u8]]) -> Option<Self> {
+        let [discriminator, data] = fields else {
+            return None;
+        };
+
+        if discriminator.ne(&EVENT_DISCRIMINATOR.as_slice()) {
+            return None;
+        }
+
+        Self::try_from_slice(data).ok()
+    }
+
+    /// Decodes a `Program data: ...` log line, `None` when it is not a tip router event
+    pub fn from_log(log: &str) -> Option<Self> {
+        let fields = log
+            .strip_prefix(PROGRAM_DATA_LOG_PREFIX)?
+            .split(' ')
+            .map(|field| STANDARD.decode(field).ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        Self::decode(&fields.iter().map(Vec::as_slice).collect::<Vec<_>>())
+    }
+
+    /// Decodes every event the tip router at `program_id` logged in a transaction's logs, in
+    /// order. The `Program <id> invoke` / `success` / `failed` lines are tracked so data logged
+    /// by other programs, including programs the tip router invokes, is skipped.
+    pub fn from_logs<S: AsRef<str>>(program_id: &Pubkey, logs: &[S]) -> Vec<Self> {
+        let program_id = program_id.to_string();
+        let mut invoke_stack: Vec<&str> = Vec::new();
+        let mut events = Vec::new();
+
+        for log in logs.iter().map(|log| log.as_ref()) {
+            if log.starts_with(PROGRAM_DATA_LOG_PREFIX) {
+                if invoke_stack.last() == Some(&program_id.as_str()) {
+                    events.extend(Self::from_log(log));
+                }
+                continue;
+            }
+
+            let Some(program_log) = log.strip_prefix(PROGRAM_LOG_PREFIX) else {
+                continue;
+            };
+
+            let mut words = program_log.split(' ');
+            match (words.next(), words.next()) {
+                (Some(program), Some("invoke")) => invoke_stack.push(program),
+                (Some(_), Some("success" | "failed:")) => {
+                    invoke_stack.pop();
+                }
+                _ => {}
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_log() {
+        let event = TipRouterEvent::VoteCast {
+            ncn: Pubkey::new_unique(),
+            epoch: 10,
+            operator: Pubkey::new_unique(),
+            meta_merkle_root: [1; 32],
+            stake_weight: 1_000,
+            slot: 100,
+        };
+
+        let data = borsh::to_vec(&event).unwrap();
+        let log = format!(
+            "{}{} {}",
+            PROGRAM_DATA_LOG_PREFIX,
+            STANDARD.encode(EVENT_DISCRIMINATOR),
+            STANDARD.encode(&data)
+        );
+
+        assert_eq!(TipRouterEvent::from_log(&log), Some(event.clone()));
+    }
+
+    #[test]
+    fn test_from_logs() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+
+        let event_log = |epoch: u64| {
+            let event = TipRouterEvent::VoteWithdrawn {
+                ncn: Pubkey::default(),
+                epoch,
+                operator: Pubkey::default(),
+            };
+            let log = format!(
+                "{}{} {}",
+                PROGRAM_DATA_LOG_PREFIX,
+                STANDARD.encode(EVENT_DISCRIMINATOR),
+                STANDARD.encode(borsh::to_vec(&event).unwrap())
+            );
+            (event, log)
+        };
+
+        let (event_1, log_1) = event_log(1);
+        let (_, spoofed_log) = event_log(2);
+        let (event_3, log_3) = event_log(3);
+
+        let logs = vec![
+            format!("Program {} invoke [1]", program_id),
+            "Program log: Instruction: CastVote".to_string(),
+            log_1,
+            // Programs invoked by the tip router can log the same data
+            format!("Program {} invoke [2]", other_program_id),
+            spoofed_log.clone(),
+            format!("Program {} success", other_program_id),
+            log_3,
+            format!(
+                "Program {} consumed 1000 of 200000 compute units",
+                program_id
+            ),
+            format!("Program {} success", program_id),
+            // As can other instructions of the transaction
+            format!("Program {} invoke [1]", other_program_id),
+            spoofed_log.clone(),
+            format!(
+                "Program {} failed: custom program error: 0x1",
+                other_program_id
+            ),
+            spoofed_log,
+        ];
+
+        assert_eq!(
+            TipRouterEvent::from_logs(&program_id, &logs),
+            vec![event_1, event_3]
+        );
+    }
+
+    #[test]
+    fn test_decode_other_data() {
+        assert_eq!(TipRouterEvent::decode(&[b"other", &[0; 8]]), None);
+        assert_eq!(TipRouterEvent::decode(&[EVENT_DISCRIMINATOR, &[255]]), None);
+        assert_eq!(TipRouterEvent::from_log("Program data: not base64!"), None);
+    }
+}
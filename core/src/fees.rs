@@ -1,12 +1,15 @@
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::types::{PodU16, PodU64};
+use jito_bytemuck::types::{PodBool, PodU16, PodU64};
 use shank::ShankType;
-use solana_program::pubkey::Pubkey;
+use solana_program::{msg, pubkey::Pubkey};
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
-    base_fee_group::BaseFeeGroup, constants::MAX_FEE_BPS, error::TipRouterError,
+    base_fee_group::{BaseFeeGroup, BaseFeeGroupSettings},
+    constants::{MAX_FEE_BPS, MAX_FEE_INCREASE_NOTICE_EPOCHS, MAX_SCHEDULED_FEES},
+    error::TipRouterError,
     ncn_fee_group::NcnFeeGroup,
+    payout_preference::PayoutAsset,
 };
 
 #[allow(clippy::too_long_first_doc_paragraph)]
@@ -20,14 +23,16 @@ pub struct FeeConfig {
     block_engine_fee_bps: PodU16,
     /// Base fee wallets - one for each base fee group
     base_fee_wallets: [Pubkey; 8],
+    /// Base fee group settings - one for each base fee group
+    base_fee_groups: [BaseFeeGroupSettings; 8],
+    /// Minimum number of epochs between scheduling a fee increase and its activation
+    fee_increase_notice_epochs: PodU64,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 120],
 
-    // Two fees so that we can update one and use the other, on the epoch boundary we switch
-    /// Fee 1
-    fee_1: Fees,
-    /// Fee 2
-    fee_2: Fees,
+    // Each entry is in effect from its activation epoch until the next entry activates
+    /// Fee timeline, the fees in effect and the ones scheduled to take over
+    fees: [Fees; 8],
 }
 
 impl FeeConfig {
@@ -48,12 +53,19 @@ impl FeeConfig {
 
         let fee = Fees::new(dao_fee_bps, default_ncn_fee_bps, current_epoch)?;
 
+        let mut fees = [Fees::zeroed(); MAX_SCHEDULED_FEES];
+        fees[0] = fee;
+
+        let mut base_fee_groups = [BaseFeeGroupSettings::zeroed(); BaseFeeGroup::FEE_GROUP_COUNT];
+        base_fee_groups[BaseFeeGroup::dao().group_index()?] = BaseFeeGroupSettings::new("DAO");
+
         let mut fee_config = Self {
             block_engine_fee_bps: PodU16::from(block_engine_fee_bps),
             base_fee_wallets: [*dao_fee_wallet; BaseFeeGroup::FEE_GROUP_COUNT],
-            reserved: [0; 128],
-            fee_1: fee,
-            fee_2: fee,
+            base_fee_groups,
+            fee_increase_notice_epochs: PodU64::from(0),
+            reserved: [0; 120],
+            fees,
         };
 
         fee_config.set_base_fee_wallet(BaseFeeGroup::default(), dao_fee_wallet)?;
@@ -63,50 +75,88 @@ impl FeeConfig {
         Ok(fee_config)
     }
 
-    // ------------- Getters -------------
-    pub fn current_fees(&self, current_epoch: u64) -> &Fees {
-        // If either fee is not yet active, return the other one
-        if self.fee_1.activation_epoch() > current_epoch {
-            return &self.fee_2;
-        }
-        if self.fee_2.activation_epoch() > current_epoch {
-            return &self.fee_1;
+    /// Fills a zeroed fee config from a version 0 one. Both fee entries join the fee timeline,
+    /// the second only when it activates at another epoch, and every base fee group either entry
+    /// charges is enabled.
+    pub fn migrate_from_v0(&mut self, legacy: &FeeConfigV0) -> Result<(), TipRouterError> {
+        self.block_engine_fee_bps = legacy.block_engine_fee_bps;
+        self.base_fee_wallets = legacy.base_fee_wallets;
+
+        self.fees[0] = legacy.fee_1;
+        self.fees[0].set_is_scheduled(true);
+        if legacy.fee_2.activation_epoch() != legacy.fee_1.activation_epoch() {
+            self.fees[1] = legacy.fee_2;
+            self.fees[1].set_is_scheduled(true);
         }
 
-        // Otherwise return the one with higher activation epoch
-        if self.fee_1.activation_epoch() >= self.fee_2.activation_epoch() {
-            &self.fee_1
-        } else {
-            &self.fee_2
+        for (index, settings) in self.base_fee_groups.iter_mut().enumerate() {
+            let is_charged = [&legacy.fee_1, &legacy.fee_2]
+                .iter()
+                .any(|fees| fees.base_fee_groups_bps[index].fee() > 0);
+            settings.set_is_enabled(is_charged);
         }
+
+        self.base_fee_groups[BaseFeeGroup::dao().group_index()?] = BaseFeeGroupSettings::new("DAO");
+
+        Ok(())
     }
 
-    fn updatable_fees(&mut self, current_epoch: u64) -> &mut Fees {
-        // If either fee is scheduled for next epoch, return that one
-        if self.fee_1.activation_epoch() > current_epoch {
-            return &mut self.fee_1;
-        }
-        if self.fee_2.activation_epoch() > current_epoch {
-            return &mut self.fee_2;
-        }
+    // ------------- Getters -------------
+    pub fn current_fees(&self, current_epoch: u64) -> &Fees {
+        &self.fees[self.current_fees_index(current_epoch)]
+    }
 
-        // Otherwise return the one with lower activation epoch
-        if self.fee_1.activation_epoch() <= self.fee_2.activation_epoch() {
-            &mut self.fee_1
-        } else {
-            &mut self.fee_2
-        }
+    /// The latest entry activated by `current_epoch`, or the earliest entry if none is active yet
+    fn current_fees_index(&self, current_epoch: u64) -> usize {
+        let entries = || {
+            self.fees
+                .iter()
+                .enumerate()
+                .filter(|(_, fees)| fees.is_scheduled())
+        };
+
+        entries()
+            .filter(|(_, fees)| fees.activation_epoch() <= current_epoch)
+            .max_by_key(|(_, fees)| fees.activation_epoch())
+            .or_else(|| entries().min_by_key(|(_, fees)| fees.activation_epoch()))
+            .map_or(0, |(index, _)| index)
     }
 
-    fn update_updatable_epoch(&mut self, current_epoch: u64) -> Result<(), TipRouterError> {
-        let next_epoch = current_epoch
-            .checked_add(1)
-            .ok_or(TipRouterError::ArithmeticOverflow)?;
+    /// Entries that activate after `current_epoch`, by activation epoch
+    pub fn scheduled_fees(&self, current_epoch: u64) -> Vec<&Fees> {
+        let current_fees = self.current_fees(current_epoch);
 
-        let updatable_fees = self.updatable_fees(current_epoch);
-        updatable_fees.set_activation_epoch(next_epoch);
+        let mut scheduled_fees: Vec<&Fees> = self
+            .fees
+            .iter()
+            .filter(|fees| fees.is_scheduled() && fees.activation_epoch() > current_epoch)
+            .filter(|fees| !core::ptr::eq(*fees, current_fees))
+            .collect();
+        scheduled_fees.sort_by_key(|fees| fees.activation_epoch());
 
-        Ok(())
+        scheduled_fees
+    }
+
+    /// The fees in effect at `current_epoch` followed by every scheduled entry
+    pub fn fee_timeline(&self, current_epoch: u64) -> Vec<&Fees> {
+        let mut fee_timeline = vec![self.current_fees(current_epoch)];
+        fee_timeline.extend(self.scheduled_fees(current_epoch));
+        fee_timeline
+    }
+
+    /// Every entry on the fee timeline by activation epoch, including the ones already superseded
+    pub fn fee_entries(&self) -> Vec<&Fees> {
+        let mut fee_entries: Vec<&Fees> = self
+            .fees
+            .iter()
+            .filter(|fees| fees.is_scheduled())
+            .collect();
+        fee_entries.sort_by_key(|fees| fees.activation_epoch());
+        fee_entries
+    }
+
+    pub fn fee_increase_notice_epochs(&self) -> u64 {
+        self.fee_increase_notice_epochs.into()
     }
 
     // ------------------- TOTALS -------------------
@@ -191,16 +241,6 @@ impl FeeConfig {
         self.adjusted_precise_fee_bps(fee)
     }
 
-    pub fn set_base_fee_bps(
-        &mut self,
-        base_fee_group: BaseFeeGroup,
-        value: u16,
-        current_epoch: u64,
-    ) -> Result<(), TipRouterError> {
-        let updateable_fees = self.updatable_fees(current_epoch);
-        updateable_fees.set_base_fee_bps(base_fee_group, value)
-    }
-
     // ------------------- NCN -------------------
 
     pub fn ncn_fee_bps(
@@ -241,27 +281,6 @@ impl FeeConfig {
         self.adjusted_precise_fee_bps(fee)
     }
 
-    pub fn set_ncn_fee_bps(
-        &mut self,
-        ncn_fee_group: NcnFeeGroup,
-        value: u16,
-        current_epoch: u64,
-    ) -> Result<(), TipRouterError> {
-        let updateable_fees = self.updatable_fees(current_epoch);
-        updateable_fees.set_ncn_fee_bps(ncn_fee_group, value)
-    }
-
-    // ------------------- PRIORITY FEE -------------------
-
-    pub fn set_priority_fee_distribution_fee_bps(
-        &mut self,
-        value: u16,
-        current_epoch: u64,
-    ) -> Result<(), TipRouterError> {
-        let updateable_fees = self.updatable_fees(current_epoch);
-        updateable_fees.set_priority_fee_distribution_fee_bps(value)
-    }
-
     // ------------------- WALLETS -------------------
 
     pub fn base_fee_wallet(&self, base_fee_group: BaseFeeGroup) -> Result<&Pubkey, TipRouterError> {
@@ -279,23 +298,127 @@ impl FeeConfig {
         Ok(())
     }
 
+    // ------------------- BASE FEE GROUPS -------------------
+
+    pub fn base_fee_group_settings(
+        &self,
+        base_fee_group: BaseFeeGroup,
+    ) -> Result<&BaseFeeGroupSettings, TipRouterError> {
+        let group_index = base_fee_group.group_index()?;
+        Ok(&self.base_fee_groups[group_index])
+    }
+
+    /// Names, enables or disables a base fee group and sets the asset it is paid out in. A group
+    /// can only be disabled once no fee it is charged is in effect or scheduled.
+    pub fn update_base_fee_group(
+        &mut self,
+        base_fee_group: BaseFeeGroup,
+        name: Option<[u8; 32]>,
+        is_enabled: Option<bool>,
+        payout_asset: Option<PayoutAsset>,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
+        if is_enabled == Some(false) {
+            for fees in self.fee_timeline(current_epoch) {
+                if fees.base_fee_bps(base_fee_group)? > 0 {
+                    msg!(
+                        "Base fee group is charged a fee from epoch {}",
+                        fees.activation_epoch()
+                    );
+                    return Err(TipRouterError::BaseFeeGroupInUse);
+                }
+            }
+        }
+
+        let group_index = base_fee_group.group_index()?;
+        let settings = &mut self.base_fee_groups[group_index];
+
+        if let Some(name) = name {
+            settings.set_name(&name);
+        }
+
+        if let Some(is_enabled) = is_enabled {
+            settings.set_is_enabled(is_enabled);
+        }
+
+        if let Some(payout_asset) = payout_asset {
+            settings.set_payout_asset(payout_asset);
+        }
+
+        Ok(())
+    }
+
     // ------------- Setters -------------
 
-    fn set_fees_to_current(&mut self, current_epoch: u64) -> Result<(), TipRouterError> {
-        if self.fee_1.activation_epoch() > current_epoch
-            || self.fee_2.activation_epoch() > current_epoch
+    pub fn set_fee_increase_notice_epochs(&mut self, value: u64) -> Result<(), TipRouterError> {
+        if value > MAX_FEE_INCREASE_NOTICE_EPOCHS {
+            return Err(TipRouterError::InvalidFeeIncreaseNoticeEpochs);
+        }
+
+        self.fee_increase_notice_epochs = PodU64::from(value);
+        Ok(())
+    }
+
+    /// Returns the entry activating at `activation_epoch`, a new entry starts as a copy of the
+    /// fees that would otherwise be in effect then
+    fn scheduled_fees_mut(
+        &mut self,
+        activation_epoch: u64,
+        current_epoch: u64,
+    ) -> Result<&mut Fees, TipRouterError> {
+        if let Some(index) = self
+            .fees
+            .iter()
+            .position(|fees| fees.is_scheduled() && fees.activation_epoch() == activation_epoch)
         {
-            return Err(TipRouterError::FeeNotActive);
+            return Ok(&mut self.fees[index]);
         }
 
-        let cloned_current_fees = *self.current_fees(current_epoch);
-        let updatable_fees = self.updatable_fees(current_epoch);
-        *updatable_fees = cloned_current_fees;
+        // Unused entries first, then entries that have been taken over, oldest first
+        let current_fees_index = self.current_fees_index(current_epoch);
+        let free_index = self
+            .fees
+            .iter()
+            .enumerate()
+            .filter(|(index, fees)| {
+                !fees.is_scheduled()
+                    || (*index != current_fees_index && fees.activation_epoch() <= current_epoch)
+            })
+            .min_by_key(|(_, fees)| (fees.is_scheduled(), fees.activation_epoch()))
+            .map(|(index, _)| index)
+            .ok_or(TipRouterError::FeeScheduleFull)?;
+
+        let mut new_fees = *self.current_fees(activation_epoch);
+        new_fees.set_activation_epoch(activation_epoch);
+        new_fees.set_is_scheduled(true);
+
+        self.fees[free_index] = new_fees;
+        Ok(&mut self.fees[free_index])
+    }
+
+    /// Cancels the entry scheduled to activate at `activation_epoch`
+    pub fn cancel_scheduled_fees(
+        &mut self,
+        activation_epoch: u64,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
+        let index = self
+            .fees
+            .iter()
+            .position(|fees| {
+                fees.is_scheduled()
+                    && fees.activation_epoch() == activation_epoch
+                    && activation_epoch > current_epoch
+            })
+            .ok_or(TipRouterError::FeesNotScheduled)?;
 
-        Ok(())
+        self.fees[index] = Fees::zeroed();
+
+        self.check_fees_okay(current_epoch)
     }
 
-    /// Updates the Fee Config
+    /// Updates the Fee Config. Fee changes are scheduled for `activation_epoch`, the next epoch by
+    /// default, the block engine fee and fee wallets change right away.
     #[allow(clippy::too_many_arguments)]
     pub fn update_fee_config(
         &mut self,
@@ -307,13 +430,16 @@ impl FeeConfig {
         new_ncn_fee_bps: Option<u16>,
         current_epoch: u64,
         priority_fee_distribution_fee_bps: Option<u16>,
+        activation_epoch: Option<u64>,
     ) -> Result<(), TipRouterError> {
-        // IF NEW CHANGES, COPY OVER CURRENT FEES
-        {
-            let updatable_fees = self.updatable_fees(current_epoch);
-            if updatable_fees.activation_epoch() <= current_epoch {
-                self.set_fees_to_current(current_epoch)?;
-            }
+        // ACTIVATION EPOCH
+        let next_epoch = current_epoch
+            .checked_add(1)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+        let activation_epoch = activation_epoch.unwrap_or(next_epoch);
+        if activation_epoch < next_epoch {
+            msg!("Fees can only be scheduled from epoch {}", next_epoch);
+            return Err(TipRouterError::InvalidFeeActivationEpoch);
         }
 
         // BLOCK ENGINE
@@ -328,52 +454,66 @@ impl FeeConfig {
             self.set_base_fee_wallet(base_fee_group, &new_base_fee_wallet)?;
         }
 
-        if let Some(new_base_fee_bps) = new_base_fee_bps {
-            self.set_base_fee_bps(base_fee_group, new_base_fee_bps, current_epoch)?;
-        }
+        // SCHEDULED FEES
+        if new_base_fee_bps.is_some()
+            || priority_fee_distribution_fee_bps.is_some()
+            || new_ncn_fee_bps.is_some()
+        {
+            let ncn_fee_group = ncn_fee_group.unwrap_or_default();
+            let base_fee_group_enabled = self.base_fee_group_settings(base_fee_group)?.is_enabled();
+            let scheduled_fees = self.scheduled_fees_mut(activation_epoch, current_epoch)?;
+
+            if let Some(new_base_fee_bps) = new_base_fee_bps {
+                if new_base_fee_bps > 0 && !base_fee_group_enabled {
+                    return Err(TipRouterError::BaseFeeGroupDisabled);
+                }
+                scheduled_fees.set_base_fee_bps(base_fee_group, new_base_fee_bps)?;
+            }
 
-        // PRIORITY DISTRIBUTION FEE
-        if let Some(fee_bps) = priority_fee_distribution_fee_bps {
-            self.set_priority_fee_distribution_fee_bps(fee_bps, current_epoch)?;
-        }
+            // PRIORITY DISTRIBUTION FEE
+            if let Some(fee_bps) = priority_fee_distribution_fee_bps {
+                scheduled_fees.set_priority_fee_distribution_fee_bps(fee_bps)?;
+            }
 
-        // NCN FEE
-        let ncn_fee_group = ncn_fee_group.unwrap_or_default();
+            // NCN FEE
+            if let Some(new_ncn_fee_bps) = new_ncn_fee_bps {
+                scheduled_fees.set_ncn_fee_bps(ncn_fee_group, new_ncn_fee_bps)?;
+            }
 
-        if let Some(new_ncn_fee_bps) = new_ncn_fee_bps {
-            self.set_ncn_fee_bps(ncn_fee_group, new_ncn_fee_bps, current_epoch)?;
+            self.check_fee_increase_notice(activation_epoch, current_epoch)?;
         }
 
-        // ACTIVATION EPOCH
-        self.update_updatable_epoch(current_epoch)?;
-
         // CHECK FEES
         self.check_fees_okay(current_epoch)?;
-        self.check_fees_okay(
-            current_epoch
-                .checked_add(1)
-                .ok_or(TipRouterError::ArithmeticOverflow)?,
-        )?;
 
         Ok(())
     }
 
     // ------ Helpers -----------------
 
+    /// Checks the fees in effect and every scheduled entry
     pub fn check_fees_okay(&self, current_epoch: u64) -> Result<(), TipRouterError> {
         if self.block_engine_fee_bps() as u64 > MAX_FEE_BPS {
             return Err(TipRouterError::FeeCapExceeded);
         }
 
+        for fees in self.fee_timeline(current_epoch) {
+            self.check_scheduled_fees_okay(fees)?;
+        }
+
+        Ok(())
+    }
+
+    fn check_scheduled_fees_okay(&self, fees: &Fees) -> Result<(), TipRouterError> {
         for group in BaseFeeGroup::all_groups().iter() {
-            let _ = self.adjusted_precise_base_fee_bps(*group, current_epoch)?;
+            let _ = self.adjusted_precise_fee_bps(fees.base_fee_bps(*group)?)?;
         }
 
         for group in NcnFeeGroup::all_groups().iter() {
-            let _ = self.adjusted_precise_ncn_fee_bps(*group, current_epoch)?;
+            let _ = self.adjusted_precise_fee_bps(fees.ncn_fee_bps(*group)?)?;
         }
 
-        let total_fees_bps = self.total_fees_bps(current_epoch)?;
+        let total_fees_bps = fees.total_fees_bps()?;
         if total_fees_bps > MAX_FEE_BPS {
             return Err(TipRouterError::FeeCapExceeded);
         }
@@ -385,6 +525,36 @@ impl FeeConfig {
         Ok(())
     }
 
+    /// A fee increase has to be scheduled at least `fee_increase_notice_epochs` ahead
+    fn check_fee_increase_notice(
+        &self,
+        activation_epoch: u64,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
+        let notice_epoch = current_epoch
+            .checked_add(self.fee_increase_notice_epochs())
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+        if activation_epoch >= notice_epoch {
+            return Ok(());
+        }
+
+        let previous_epoch = activation_epoch
+            .checked_sub(1)
+            .ok_or(TipRouterError::ArithmeticUnderflowError)?;
+        let previous_fees = self.current_fees(previous_epoch);
+        let scheduled_fees = self.current_fees(activation_epoch);
+
+        if scheduled_fees.is_increase_over(previous_fees)? {
+            msg!(
+                "Fee increases have to be scheduled from epoch {}",
+                notice_epoch
+            );
+            return Err(TipRouterError::FeeIncreaseNoticeTooShort);
+        }
+
+        Ok(())
+    }
+
     fn adjusted_fee_bps(&self, fee: u16) -> Result<u64, TipRouterError> {
         let remaining_bps = MAX_FEE_BPS
             .checked_sub(self.block_engine_fee_bps() as u64)
@@ -416,6 +586,18 @@ impl FeeConfig {
     }
 }
 
+/// Layout of the `FeeConfig` in version 0 `Config` accounts, which switched between two fee
+/// entries on the epoch boundary
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+#[repr(C)]
+pub struct FeeConfigV0 {
+    pub block_engine_fee_bps: PodU16,
+    pub base_fee_wallets: [Pubkey; 8],
+    pub reserved: [u8; 128],
+    pub fee_1: Fees,
+    pub fee_2: Fees,
+}
+
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
 #[repr(C)]
 pub struct Fees {
@@ -423,7 +605,9 @@ pub struct Fees {
     /// TipRouter fee used to determine the TipRouter claimant amount for Priority Fee
     /// Distributions.
     priority_fee_distribution_fee_bps: Fee,
-    reserved: [u8; 126],
+    /// Whether this entry is part of the fee timeline
+    is_scheduled: PodBool,
+    reserved: [u8; 125],
     /// The groups and split of the Base fee. Currently the DAO base group (index one), is the only
     /// group and takes 100% of the base fees. 2.7% (the base fee) of total MEV tips gets directed
     /// to the DAO.
@@ -442,7 +626,8 @@ impl Fees {
         let mut fees = Self {
             activation_epoch: PodU64::from(epoch),
             priority_fee_distribution_fee_bps: Fee::default(),
-            reserved: [0; 126],
+            is_scheduled: PodBool::from(true),
+            reserved: [0; 125],
             base_fee_groups_bps: [Fee::default(); BaseFeeGroup::FEE_GROUP_COUNT],
             ncn_fee_groups_bps: [Fee::default(); NcnFeeGroup::FEE_GROUP_COUNT],
         };
@@ -458,6 +643,10 @@ impl Fees {
         self.activation_epoch.into()
     }
 
+    pub fn is_scheduled(&self) -> bool {
+        self.is_scheduled.into()
+    }
+
     pub fn priority_fee_distribution_fee_bps(&self) -> u64 {
         self.priority_fee_distribution_fee_bps.fee().into()
     }
@@ -519,11 +708,36 @@ impl Fees {
         PreciseNumber::new(total_fee_bps.into()).ok_or(TipRouterError::NewPreciseNumberError)
     }
 
+    /// Whether any fee is higher than in `other`
+    pub fn is_increase_over(&self, other: &Self) -> Result<bool, TipRouterError> {
+        if self.priority_fee_distribution_fee_bps() > other.priority_fee_distribution_fee_bps() {
+            return Ok(true);
+        }
+
+        for group in BaseFeeGroup::all_groups().iter() {
+            if self.base_fee_bps(*group)? > other.base_fee_bps(*group)? {
+                return Ok(true);
+            }
+        }
+
+        for group in NcnFeeGroup::all_groups().iter() {
+            if self.ncn_fee_bps(*group)? > other.ncn_fee_bps(*group)? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     // ------ Setters -----------------
     fn set_activation_epoch(&mut self, value: u64) {
         self.activation_epoch = PodU64::from(value);
     }
 
+    fn set_is_scheduled(&mut self, value: bool) {
+        self.is_scheduled = PodBool::from(value);
+    }
+
     pub fn set_priority_fee_distribution_fee_bps(
         &mut self,
         value: u16,
@@ -634,25 +848,21 @@ mod tests {
         );
 
         assert_eq!(
-            fee_config.fee_1.base_fee_bps(dao_fee_group).unwrap(),
-            DAO_FEE
-        );
-        assert_eq!(
-            fee_config.fee_2.base_fee_bps(dao_fee_group).unwrap(),
+            fee_config.fees[0].base_fee_bps(dao_fee_group).unwrap(),
             DAO_FEE
         );
 
         let default_ncn_fee_group = NcnFeeGroup::default();
 
         assert_eq!(
-            fee_config.fee_1.ncn_fee_bps(default_ncn_fee_group).unwrap(),
+            fee_config.fees[0]
+                .ncn_fee_bps(default_ncn_fee_group)
+                .unwrap(),
             DEFAULT_NCN_FEE
         );
 
-        assert_eq!(
-            fee_config.fee_2.ncn_fee_bps(default_ncn_fee_group).unwrap(),
-            DEFAULT_NCN_FEE
-        );
+        assert_eq!(fee_config.fee_timeline(STARTING_EPOCH).len(), 1);
+        assert!(fee_config.scheduled_fees(STARTING_EPOCH).is_empty());
     }
 
     #[test]
@@ -718,6 +928,7 @@ mod tests {
                 Some(NEW_DEFAULT_NCN_FEE),
                 STARTING_EPOCH,
                 Some(NEW_PRIORITY_DISTRIBUTION_FEE),
+                None,
             )
             .unwrap();
 
@@ -766,6 +977,7 @@ mod tests {
                 Some(NEW_NEW_DEFAULT_NCN_FEE),
                 STARTING_EPOCH + 1,
                 None,
+                None,
             )
             .unwrap();
 
@@ -821,7 +1033,17 @@ mod tests {
         .unwrap();
 
         fee_config
-            .update_fee_config(None, None, None, None, None, None, STARTING_EPOCH, None)
+            .update_fee_config(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                STARTING_EPOCH,
+                None,
+                None,
+            )
             .unwrap();
 
         assert_eq!(fee_config.block_engine_fee_bps(), BLOCK_ENGINE_FEE);
@@ -876,6 +1098,10 @@ mod tests {
         .unwrap();
 
         for base_fee_group in BaseFeeGroup::all_groups().iter() {
+            fee_config
+                .update_base_fee_group(*base_fee_group, None, Some(true), None, STARTING_EPOCH)
+                .unwrap();
+
             fee_config
                 .update_fee_config(
                     None,
@@ -886,6 +1112,7 @@ mod tests {
                     None,
                     STARTING_EPOCH,
                     None,
+                    None,
                 )
                 .unwrap();
 
@@ -920,6 +1147,7 @@ mod tests {
                     Some(NEW_NCN_FEE),
                     STARTING_EPOCH,
                     None,
+                    None,
                 )
                 .unwrap();
 
@@ -944,6 +1172,86 @@ mod tests {
         assert_eq!(fee_config.block_engine_fee_bps(), BLOCK_ENGINE_FEE);
     }
 
+    #[test]
+    fn test_base_fee_groups() {
+        const STARTING_EPOCH: u64 = 10;
+
+        let mut fee_config =
+            FeeConfig::new(&Pubkey::new_unique(), 100, 200, 300, STARTING_EPOCH).unwrap();
+
+        let dao_settings = fee_config
+            .base_fee_group_settings(BaseFeeGroup::dao())
+            .unwrap();
+        assert_eq!(dao_settings.name(), "DAO");
+        assert!(dao_settings.is_enabled());
+        assert_eq!(dao_settings.payout_asset().unwrap(), PayoutAsset::JitoSol);
+
+        let group = BaseFeeGroup::try_from(1).unwrap();
+        assert!(!fee_config
+            .base_fee_group_settings(group)
+            .unwrap()
+            .is_enabled());
+
+        // Disabled groups can't be charged a fee
+        let error = fee_config.update_fee_config(
+            None,
+            Some(group),
+            None,
+            Some(50),
+            None,
+            None,
+            STARTING_EPOCH,
+            None,
+            None,
+        );
+        assert_eq!(error, Err(TipRouterError::BaseFeeGroupDisabled));
+
+        fee_config
+            .update_base_fee_group(
+                group,
+                Some(BaseFeeGroupSettings::name_bytes("Grants")),
+                Some(true),
+                Some(PayoutAsset::Sol),
+                STARTING_EPOCH,
+            )
+            .unwrap();
+
+        let settings = fee_config.base_fee_group_settings(group).unwrap();
+        assert_eq!(settings.name(), "Grants");
+        assert!(settings.is_enabled());
+        assert_eq!(settings.payout_asset().unwrap(), PayoutAsset::Sol);
+
+        fee_config
+            .update_fee_config(
+                None,
+                Some(group),
+                None,
+                Some(50),
+                None,
+                None,
+                STARTING_EPOCH,
+                None,
+                Some(STARTING_EPOCH + 2),
+            )
+            .unwrap();
+
+        // The scheduled fee keeps the group in use
+        let error =
+            fee_config.update_base_fee_group(group, None, Some(false), None, STARTING_EPOCH);
+        assert_eq!(error, Err(TipRouterError::BaseFeeGroupInUse));
+
+        fee_config
+            .cancel_scheduled_fees(STARTING_EPOCH + 2, STARTING_EPOCH)
+            .unwrap();
+        fee_config
+            .update_base_fee_group(group, None, Some(false), None, STARTING_EPOCH)
+            .unwrap();
+        assert!(!fee_config
+            .base_fee_group_settings(group)
+            .unwrap()
+            .is_enabled());
+    }
+
     #[test]
     fn test_check_fees_okay() {
         const BLOCK_ENGINE_FEE: u16 = 100;
@@ -994,6 +1302,7 @@ mod tests {
             None,
             STARTING_EPOCH,
             None,
+            None,
         );
 
         assert!(result.is_err());
@@ -1007,6 +1316,7 @@ mod tests {
             None,
             STARTING_EPOCH,
             None,
+            None,
         );
 
         assert!(result.is_err());
@@ -1020,6 +1330,7 @@ mod tests {
             Some(MAX_FEE_BPS + 1),
             STARTING_EPOCH,
             None,
+            None,
         );
 
         assert!(result.is_err());
@@ -1030,51 +1341,188 @@ mod tests {
         let mut fee_config = FeeConfig::new(&Pubkey::new_unique(), 100, 200, 300, 5).unwrap();
 
         assert_eq!(fee_config.current_fees(5).activation_epoch(), 5);
+        // Before the first entry activates it is still used
+        assert_eq!(fee_config.current_fees(0).activation_epoch(), 5);
 
-        fee_config.fee_1.set_activation_epoch(10);
+        fee_config.fees[1] = fee_config.fees[0];
+        fee_config.fees[1].set_activation_epoch(10);
 
         assert_eq!(fee_config.current_fees(5).activation_epoch(), 5);
         assert_eq!(fee_config.current_fees(10).activation_epoch(), 10);
 
-        fee_config.fee_2.set_activation_epoch(15);
+        fee_config.fees[2] = fee_config.fees[0];
+        fee_config.fees[2].set_activation_epoch(15);
 
         assert_eq!(fee_config.current_fees(12).activation_epoch(), 10);
         assert_eq!(fee_config.current_fees(15).activation_epoch(), 15);
+
+        // Unscheduled entries are ignored
+        fee_config.fees[2].set_is_scheduled(false);
+        assert_eq!(fee_config.current_fees(15).activation_epoch(), 10);
     }
 
     #[test]
-    fn test_get_updatable_fee_mut() {
+    fn test_scheduled_fees_mut() {
         let mut fee_config = FeeConfig::new(&Pubkey::new_unique(), 100, 200, 300, 5).unwrap();
 
         let base_fee_group = BaseFeeGroup::default();
 
-        let fees = fee_config.updatable_fees(10);
+        let fees = fee_config.scheduled_fees_mut(11, 10).unwrap();
         fees.set_base_fee_bps(base_fee_group, 400).unwrap();
+        assert_eq!(fees.activation_epoch(), 11);
+
+        // The same entry is returned for the same activation epoch
+        let fees = fee_config.scheduled_fees_mut(11, 10).unwrap();
+        assert_eq!(fees.base_fee_bps(base_fee_group).unwrap(), 400);
         fees.set_priority_fee_distribution_fee_bps(150).unwrap();
-        fees.set_activation_epoch(11);
 
-        assert_eq!(fee_config.fee_1.base_fee_bps(base_fee_group).unwrap(), 400);
+        // A new entry starts from the fees in effect before it
+        let fees = fee_config.scheduled_fees_mut(13, 10).unwrap();
+        assert_eq!(fees.base_fee_bps(base_fee_group).unwrap(), 400);
+        assert_eq!(fees.priority_fee_distribution_fee_bps(), 150);
+        fees.set_base_fee_bps(base_fee_group, 500).unwrap();
+
+        assert_eq!(fee_config.scheduled_fees(10).len(), 2);
+        assert_eq!(fee_config.base_fee_bps(base_fee_group, 12).unwrap(), 400);
+        assert_eq!(fee_config.base_fee_bps(base_fee_group, 13).unwrap(), 500);
+
+        // Fill every entry, taken over entries are reused once they are not in effect
+        for epoch in 14..(14 + MAX_SCHEDULED_FEES as u64 - 3) {
+            fee_config.scheduled_fees_mut(epoch, 10).unwrap();
+        }
         assert_eq!(
-            fee_config.fee_1.priority_fee_distribution_fee_bps.fee(),
-            150
+            fee_config.scheduled_fees_mut(100, 10).err(),
+            Some(TipRouterError::FeeScheduleFull)
         );
-        assert_eq!(fee_config.fee_1.activation_epoch(), 11);
 
-        fee_config.fee_2.set_activation_epoch(13);
+        let fees = fee_config.scheduled_fees_mut(100, 12).unwrap();
+        assert_eq!(fees.activation_epoch(), 100);
+        assert_eq!(fee_config.current_fees(12).activation_epoch(), 11);
+    }
 
-        let fees = fee_config.updatable_fees(12);
-        fees.set_base_fee_bps(base_fee_group, 500).unwrap();
-        fees.set_priority_fee_distribution_fee_bps(200).unwrap();
-        fees.set_activation_epoch(13);
+    #[test]
+    fn test_schedule_fees() {
+        const DAO_FEE: u16 = 200;
+        const STARTING_EPOCH: u64 = 10;
+
+        let base_fee_group = BaseFeeGroup::default();
+
+        let mut fee_config =
+            FeeConfig::new(&Pubkey::new_unique(), 100, DAO_FEE, 300, STARTING_EPOCH).unwrap();
+
+        // Scheduling a second change keeps the first one
+        for (fee, activation_epoch) in [(400, STARTING_EPOCH + 5), (500, STARTING_EPOCH + 2)] {
+            fee_config
+                .update_fee_config(
+                    None,
+                    None,
+                    None,
+                    Some(fee),
+                    None,
+                    None,
+                    STARTING_EPOCH,
+                    None,
+                    Some(activation_epoch),
+                )
+                .unwrap();
+        }
+
+        let fee_timeline: Vec<(u64, u16)> = fee_config
+            .fee_timeline(STARTING_EPOCH)
+            .iter()
+            .map(|fees| {
+                (
+                    fees.activation_epoch(),
+                    fees.base_fee_bps(base_fee_group).unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            fee_timeline,
+            vec![
+                (STARTING_EPOCH, DAO_FEE),
+                (STARTING_EPOCH + 2, 500),
+                (STARTING_EPOCH + 5, 400)
+            ]
+        );
+
+        // Only future epochs can be scheduled
+        assert_eq!(
+            fee_config.update_fee_config(
+                None,
+                None,
+                None,
+                Some(600),
+                None,
+                None,
+                STARTING_EPOCH,
+                None,
+                Some(STARTING_EPOCH),
+            ),
+            Err(TipRouterError::InvalidFeeActivationEpoch)
+        );
 
-        assert_eq!(fee_config.fee_2.base_fee_bps(base_fee_group).unwrap(), 500);
+        // Cancel
+        assert_eq!(
+            fee_config.cancel_scheduled_fees(STARTING_EPOCH + 3, STARTING_EPOCH),
+            Err(TipRouterError::FeesNotScheduled)
+        );
         assert_eq!(
-            fee_config.fee_2.priority_fee_distribution_fee_bps.fee(),
-            200
+            fee_config.cancel_scheduled_fees(STARTING_EPOCH + 2, STARTING_EPOCH + 2),
+            Err(TipRouterError::FeesNotScheduled)
         );
-        assert_eq!(fee_config.fee_2.activation_epoch(), 13);
 
-        assert_eq!(fee_config.updatable_fees(u64::MAX).activation_epoch(), 11);
+        fee_config
+            .cancel_scheduled_fees(STARTING_EPOCH + 2, STARTING_EPOCH)
+            .unwrap();
+        assert_eq!(
+            fee_config
+                .base_fee_bps(base_fee_group, STARTING_EPOCH + 2)
+                .unwrap(),
+            DAO_FEE
+        );
+        assert_eq!(fee_config.scheduled_fees(STARTING_EPOCH).len(), 1);
+    }
+
+    #[test]
+    fn test_fee_increase_notice() {
+        const STARTING_EPOCH: u64 = 10;
+        const NOTICE_EPOCHS: u64 = 3;
+
+        let mut fee_config =
+            FeeConfig::new(&Pubkey::new_unique(), 100, 200, 300, STARTING_EPOCH).unwrap();
+
+        assert_eq!(
+            fee_config.set_fee_increase_notice_epochs(MAX_FEE_INCREASE_NOTICE_EPOCHS + 1),
+            Err(TipRouterError::InvalidFeeIncreaseNoticeEpochs)
+        );
+        fee_config
+            .set_fee_increase_notice_epochs(NOTICE_EPOCHS)
+            .unwrap();
+
+        let mut schedule_base_fee = |fee: u16, activation_epoch: Option<u64>| {
+            fee_config.update_fee_config(
+                None,
+                None,
+                None,
+                Some(fee),
+                None,
+                None,
+                STARTING_EPOCH,
+                None,
+                activation_epoch,
+            )
+        };
+
+        // Decreases can activate next epoch
+        schedule_base_fee(100, None).unwrap();
+        schedule_base_fee(300, Some(STARTING_EPOCH + NOTICE_EPOCHS)).unwrap();
+
+        // Still below the fees in effect, but an increase over the entry scheduled before it
+        assert_eq!(
+            schedule_base_fee(150, Some(STARTING_EPOCH + NOTICE_EPOCHS - 1)),
+            Err(TipRouterError::FeeIncreaseNoticeTooShort)
+        );
     }
 
     #[test]
@@ -1294,6 +1742,69 @@ mod tests {
         assert!(precise_total.eq(&expected));
     }
 
+    #[test]
+    fn test_migrate_from_v0() {
+        let dao_wallet = Pubkey::new_unique();
+        let dao = BaseFeeGroup::dao();
+        let group_1 = BaseFeeGroup::try_from(1).unwrap();
+        let group_2 = BaseFeeGroup::try_from(2).unwrap();
+
+        let mut fee_1 = Fees::new(270, 15, 10).unwrap();
+        fee_1.set_is_scheduled(false);
+        let mut fee_2 = Fees::new(250, 15, 12).unwrap();
+        fee_2.set_base_fee_bps(group_1, 20).unwrap();
+        fee_2.set_is_scheduled(false);
+
+        let mut legacy = FeeConfigV0 {
+            block_engine_fee_bps: PodU16::from(100),
+            base_fee_wallets: [dao_wallet; BaseFeeGroup::FEE_GROUP_COUNT],
+            reserved: [0; 128],
+            fee_1,
+            fee_2,
+        };
+
+        let mut fee_config = FeeConfig::zeroed();
+        fee_config.migrate_from_v0(&legacy).unwrap();
+
+        assert_eq!(fee_config.block_engine_fee_bps(), 100);
+        assert_eq!(fee_config.base_fee_wallet(dao).unwrap(), &dao_wallet);
+        assert_eq!(fee_config.current_fees(11).base_fee_bps(dao).unwrap(), 270);
+        assert_eq!(fee_config.current_fees(12).base_fee_bps(dao).unwrap(), 250);
+        assert_eq!(fee_config.scheduled_fees(11).len(), 1);
+        fee_config.check_fees_okay(11).unwrap();
+
+        // The raw entries keep the superseded one, the timeline starts at the current one
+        let activation_epochs: Vec<u64> = fee_config
+            .fee_entries()
+            .iter()
+            .map(|fees| fees.activation_epoch())
+            .collect();
+        assert_eq!(activation_epochs, vec![10, 12]);
+        assert_eq!(fee_config.fee_timeline(12).len(), 1);
+
+        let dao_settings = fee_config.base_fee_group_settings(dao).unwrap();
+        assert!(dao_settings.is_enabled());
+        assert_eq!(dao_settings.name(), "DAO");
+        assert!(fee_config
+            .base_fee_group_settings(group_1)
+            .unwrap()
+            .is_enabled());
+        assert!(!fee_config
+            .base_fee_group_settings(group_2)
+            .unwrap()
+            .is_enabled());
+
+        // Entries activating at the same epoch are one entry
+        legacy.fee_2 = legacy.fee_1;
+        let mut fee_config = FeeConfig::zeroed();
+        fee_config.migrate_from_v0(&legacy).unwrap();
+        assert_eq!(fee_config.fee_timeline(10).len(), 1);
+        assert!(!fee_config
+            .base_fee_group_settings(group_1)
+            .unwrap()
+            .is_enabled());
+    }
+
     use std::mem::size_of;
 
     #[test]
@@ -2,7 +2,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankInstruction;
 use solana_program::pubkey::Pubkey;
 
-use crate::config::ConfigAdminRole;
+use crate::{admin_multisig::AdminAction, config::ConfigAdminRole};
 
 #[rustfmt::skip]
 #[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction)]
@@ -44,12 +44,14 @@ pub enum TipRouterInstruction {
     #[account(4, name = "system_program")]
     ReallocVaultRegistry,
 
-    /// Registers a vault to the vault registry
+    /// Registers a vault to the vault registry, vaults past `MAX_VAULTS` are registered to their
+    /// vault registry page
     #[account(0, name = "config")]
     #[account(1, writable, name = "vault_registry")]
     #[account(2, name = "ncn")]
     #[account(3, name = "vault")]
     #[account(4, name = "ncn_vault_ticket")]
+    #[account(5, optional, writable, name = "vault_registry_page")]
     RegisterVault,
 
     // ---------------------------------------------------- //
@@ -100,7 +102,7 @@ pub enum TipRouterInstruction {
         epoch: u64,
     },
 
-    // Sets the weight table for a given epoch
+    // Sets the weight table for a given epoch, same as OracleSetWeight
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "ncn")]
     #[account(2, writable, name = "weight_table")]
@@ -150,11 +152,13 @@ pub enum TipRouterInstruction {
     #[account(7, writable, name = "operator_snapshot")]
     #[account(8, writable, name = "account_payer")]
     #[account(9, name = "system_program")]
+    #[account(10, writable, optional, name = "epoch_state_page")]
     ReallocOperatorSnapshot {
         epoch: u64,
     },
     
-    /// Snapshots the vault operator delegation
+    /// Snapshots the vault operator delegation. Vaults past `MAX_VAULTS` take their weight table
+    /// and operator snapshot pages
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "restaking_config")]
@@ -167,6 +171,9 @@ pub enum TipRouterInstruction {
     #[account(9, name = "weight_table")]
     #[account(10, writable, name = "epoch_snapshot")]
     #[account(11, writable, name = "operator_snapshot")]
+    #[account(12, optional, name = "weight_table_page")]
+    #[account(13, writable, optional, name = "operator_snapshot_page")]
+    #[account(14, writable, optional, name = "epoch_state_page")]
     SnapshotVaultOperatorDelegation{
         epoch: u64,
     },
@@ -197,7 +204,7 @@ pub enum TipRouterInstruction {
         epoch: u64,
     },
 
-    /// Cast a vote for a merkle root
+    /// Cast a vote for a merkle root, signed by the operator's voter or its registered vote key
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, writable, name = "ballot_box")]
@@ -206,6 +213,8 @@ pub enum TipRouterInstruction {
     #[account(5, name = "operator_snapshot")]
     #[account(6, name = "operator")]
     #[account(7, signer, name = "operator_voter")]
+    #[account(8, optional, name = "operator_vote_key")]
+    #[account(9, optional, writable, name = "ballot_box_page")]
     CastVote {
         meta_merkle_root: [u8; 32],
         epoch: u64,
@@ -231,7 +240,8 @@ pub enum TipRouterInstruction {
     // ---------------------------------------------------- //
     //                ROUTE AND DISTRIBUTE                  //
     // ---------------------------------------------------- //
-    /// Initializes the base reward router
+    /// Initializes the base reward router, or the router for SPL token rewards of `mint`. Token
+    /// routers need the `config` to check the mint is an allowed reward mint
     #[account(0, name = "epoch_marker")]
     #[account(1, name = "epoch_state")]
     #[account(2, name = "ncn")]
@@ -239,6 +249,8 @@ pub enum TipRouterInstruction {
     #[account(4, writable, name = "base_reward_receiver")]
     #[account(5, writable, name = "account_payer")]
     #[account(6, name = "system_program")]
+    #[account(7, optional, name = "mint")]
+    #[account(8, optional, name = "config")]
     InitializeBaseRewardRouter{
         epoch: u64,
     },
@@ -250,11 +262,13 @@ pub enum TipRouterInstruction {
     #[account(3, name = "ncn")]
     #[account(4, writable, name = "account_payer")]
     #[account(5, name = "system_program")]
+    #[account(6, optional, name = "mint")]
     ReallocBaseRewardRouter {
         epoch: u64,
     },
 
-    /// Initializes the ncn reward router
+    /// Initializes the ncn reward router, or the router for SPL token rewards of `mint`. Token
+    /// routers need the `config` to check the mint is an allowed reward mint
     #[account(0, name = "epoch_marker")]
     #[account(1, writable, name = "epoch_state")]
     #[account(2, name = "ncn")]
@@ -264,12 +278,17 @@ pub enum TipRouterInstruction {
     #[account(6, writable, name = "ncn_reward_receiver")]
     #[account(7, writable, name = "account_payer")]
     #[account(8, name = "system_program")]
+    #[account(9, optional, name = "mint")]
+    #[account(10, optional, name = "config")]
+    #[account(11, writable, optional, name = "epoch_state_page")]
     InitializeNcnRewardRouter{
         ncn_fee_group: u8,
         epoch: u64,
     },
 
-    /// Routes base reward router
+    /// Routes base reward router, token routers route the receiver's ATA for `mint`. Pays the
+    /// optional `cranker` the crank bounty from `account_payer` when lamports were routed
+    /// Remaining accounts: every ballot box page, then every base reward router page, in order
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -277,25 +296,40 @@ pub enum TipRouterInstruction {
     #[account(4, name = "ballot_box")]
     #[account(5, writable, name = "base_reward_router")]
     #[account(6, writable, name = "base_reward_receiver")]
+    #[account(7, optional, name = "mint")]
+    #[account(8, optional, name = "base_reward_receiver_token_account")]
+    #[account(9, writable, optional, name = "account_payer")]
+    #[account(10, writable, signer, optional, name = "cranker")]
+    #[account(11, optional, name = "system_program")]
     RouteBaseRewards{
         max_iterations: u16,
         epoch: u64,
     },
 
-    /// Routes ncn reward router
+    /// Routes ncn reward router, token routers route the receiver's ATA for `mint`. Pays the
+    /// optional `cranker` the crank bounty from `account_payer` when lamports were routed. The
+    /// operator snapshot pages are passed in order as remaining accounts
     #[account(0, writable, name = "epoch_state")]
-    #[account(1, name = "ncn")]
-    #[account(2, name = "operator")]
-    #[account(3, name = "operator_snapshot")]
-    #[account(4, writable, name = "ncn_reward_router")]
-    #[account(5, writable, name = "ncn_reward_receiver")]
+    #[account(1, name = "config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "operator_snapshot")]
+    #[account(5, writable, name = "ncn_reward_router")]
+    #[account(6, writable, name = "ncn_reward_receiver")]
+    #[account(7, optional, name = "mint")]
+    #[account(8, optional, name = "ncn_reward_receiver_token_account")]
+    #[account(9, writable, optional, name = "account_payer")]
+    #[account(10, writable, signer, optional, name = "cranker")]
+    #[account(11, optional, name = "system_program")]
+    #[account(12, writable, optional, name = "epoch_state_page")]
     RouteNcnRewards{
         ncn_fee_group: u8,
         max_iterations: u16,
         epoch: u64,
     },
 
-    /// Distributes base rewards
+    /// Distributes base rewards. Token routers transfer `mint` to `base_fee_wallet_ata`, the fee
+    /// wallet's ATA for that mint. Pays the optional `cranker` the crank bounty for lamports
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -313,12 +347,18 @@ pub enum TipRouterInstruction {
     #[account(13, writable, name = "pool_mint")]
     #[account(14, name = "token_program")]
     #[account(15, name = "system_program")]
+    #[account(16, optional, name = "mint")]
+    #[account(17, writable, optional, name = "base_reward_receiver_token_account")]
+    #[account(18, writable, optional, name = "account_payer")]
+    #[account(19, writable, signer, optional, name = "cranker")]
     DistributeBaseRewards{
         base_fee_group: u8,
         epoch: u64,
     },
 
-    /// Distributes base ncn reward routes
+    /// Distributes base ncn reward routes, token routers transfer `mint` between the receivers' ATAs.
+    /// Pays the optional `cranker` the crank bounty for lamports. Operators past `MAX_OPERATORS`
+    /// are routed in the `base_reward_router_page`
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -328,12 +368,22 @@ pub enum TipRouterInstruction {
     #[account(6, name = "ncn_reward_router")]
     #[account(7, writable, name = "ncn_reward_receiver")]
     #[account(8, name = "system_program")]
+    #[account(9, optional, name = "mint")]
+    #[account(10, writable, optional, name = "base_reward_receiver_token_account")]
+    #[account(11, writable, optional, name = "ncn_reward_receiver_token_account")]
+    #[account(12, optional, name = "token_program")]
+    #[account(13, writable, optional, name = "account_payer")]
+    #[account(14, writable, signer, optional, name = "cranker")]
+    #[account(15, writable, optional, name = "base_reward_router_page")]
     DistributeBaseNcnRewardRoute{
         ncn_fee_group: u8,
         epoch: u64,
     },
 
-    /// Distributes ncn operator rewards
+    /// Distributes ncn operator rewards in the operator's payout asset. `operator_ata` is the
+    /// operator's JitoSOL or wSOL ATA, or the SOL destination of its payout preference. Token
+    /// routers always pay out `mint` to the operator's ATA for that mint. Pays the optional
+    /// `cranker` the crank bounty for lamports
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -352,12 +402,21 @@ pub enum TipRouterInstruction {
     #[account(14, writable, name = "pool_mint")]
     #[account(15, name = "token_program")]
     #[account(16, name = "system_program")]
+    #[account(17, name = "payout_preference")]
+    #[account(18, optional, name = "mint")]
+    #[account(19, writable, optional, name = "ncn_reward_receiver_token_account")]
+    #[account(20, writable, optional, name = "account_payer")]
+    #[account(21, writable, signer, optional, name = "cranker")]
+    #[account(22, writable, optional, name = "epoch_state_page")]
     DistributeNcnOperatorRewards{
         ncn_fee_group: u8,
         epoch: u64,
     },
 
-    /// Distributes ncn vault rewards
+    /// Distributes ncn vault rewards in the vault's payout asset. `vault_ata` is the vault's
+    /// JitoSOL or wSOL ATA, or the SOL destination of its payout preference. Token routers always
+    /// pay out `mint` to the vault's ATA for that mint. Pays the optional `cranker` the crank
+    /// bounty for lamports
     #[account(0, writable, name = "epoch_state")]
     #[account(1, name = "config")]
     #[account(2, name = "ncn")]
@@ -377,6 +436,12 @@ pub enum TipRouterInstruction {
     #[account(15, writable, name = "pool_mint")]
     #[account(16, name = "token_program")]
     #[account(17, name = "system_program")]
+    #[account(18, name = "payout_preference")]
+    #[account(19, optional, name = "mint")]
+    #[account(20, writable, optional, name = "ncn_reward_receiver_token_account")]
+    #[account(21, writable, optional, name = "account_payer")]
+    #[account(22, writable, signer, optional, name = "cranker")]
+    #[account(23, writable, optional, name = "epoch_state_page")]
     DistributeNcnVaultRewards{
         ncn_fee_group: u8,
         epoch: u64,
@@ -398,7 +463,11 @@ pub enum TipRouterInstruction {
         bump: u8,
     },
 
-    /// Close an epoch account
+    /// Close an epoch account. Reward routers are closed with their receiver passed as
+    /// `receiver_to_close`, ballot box, weight table and operator snapshot pages with their parent account. Token reward routers also
+    /// take the receiver's ATA, the DAO wallet's ATA and the token program as remaining accounts.
+    /// Operator snapshots of operators past `MAX_OPERATORS` take their epoch state page as
+    /// `receiver_to_close`, their NCN reward routers take it as a remaining account.
     #[account(0, writable, name = "epoch_marker")]
     #[account(1, writable, name = "epoch_state")]
     #[account(2, name = "config")]
@@ -424,9 +493,23 @@ pub enum TipRouterInstruction {
         epochs_before_stall: Option<u64>,
         epochs_after_consensus_before_close: Option<u64>,
         valid_slots_after_consensus: Option<u64>,
-    },
-
-    /// Updates the fee configuration
+        commit_window_slots: Option<u64>,
+        reveal_window_slots: Option<u64>,
+        consensus_threshold_bps: Option<u16>,
+        quorum_operator_count: Option<u16>,
+        quorum_operator_bps: Option<u16>,
+        stall_fallback_threshold_bps: Option<u16>,
+        admin_handover_delay_epochs: Option<u16>,
+        weight_sampling_window_slots: Option<u64>,
+        max_operator_stake_weight_bps: Option<u16>,
+        max_vault_stake_weight_bps: Option<u16>,
+        fee_increase_notice_epochs: Option<u64>,
+        crank_bounty_lamports: Option<u64>,
+        max_crank_bounty_lamports_per_epoch: Option<u64>,
+    },
+
+    /// Updates the fee configuration, fee changes are scheduled for `activation_epoch`, the next
+    /// epoch by default
     #[account(0, writable, name = "config")]
     #[account(1, name = "ncn")]
     #[account(2, signer, name = "ncn_admin")]
@@ -438,9 +521,10 @@ pub enum TipRouterInstruction {
         ncn_fee_group: Option<u8>,
         new_ncn_fee_bps: Option<u16>,
         new_priority_fee_distribution_fee_bps: Option<u16>,
+        activation_epoch: Option<u64>,
     },
 
-    /// Sets a new secondary admin for the NCN
+    /// Sets a new secondary admin for the NCN, only while no admin handover delay is set
     #[account(0, writable, name = "config")]
     #[account(1, name = "ncn")]
     #[account(2, signer, name = "ncn_admin")]
@@ -482,6 +566,10 @@ pub enum TipRouterInstruction {
         reward_multiplier_bps: u64,
         switchboard_feed: Option<Pubkey>,
         no_feed_weight: Option<u128>,
+        oracle_type: Option<u8>,
+        max_staleness: Option<u64>,
+        max_confidence_bps: Option<u16>,
+        max_weight_change_bps: Option<u16>,
     },
 
     /// Updates an ST mint in the Vault Registry
@@ -495,5 +583,374 @@ pub enum TipRouterInstruction {
         reward_multiplier_bps: Option<u64>,
         switchboard_feed: Option<Pubkey>,
         no_feed_weight: Option<u128>,
+        oracle_type: Option<u8>,
+        max_staleness: Option<u64>,
+        max_confidence_bps: Option<u16>,
+        max_weight_change_bps: Option<u16>,
+    },
+
+    /// Commit to a vote without revealing the merkle root, when commit-reveal voting is enabled
+    #[account(0, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, writable, name = "ballot_box")]
+    #[account(3, name = "ncn")]
+    #[account(4, name = "epoch_snapshot")]
+    #[account(5, name = "operator_snapshot")]
+    #[account(6, name = "operator")]
+    #[account(7, signer, name = "operator_voter")]
+    #[account(8, optional, name = "operator_vote_key")]
+    #[account(9, optional, writable, name = "ballot_box_page")]
+    CommitVote {
+        vote_commitment: [u8; 32],
+        epoch: u64,
+    },
+
+    /// Reveal a previously committed vote for a merkle root
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, writable, name = "ballot_box")]
+    #[account(3, name = "ncn")]
+    #[account(4, name = "epoch_snapshot")]
+    #[account(5, name = "operator_snapshot")]
+    #[account(6, name = "operator")]
+    #[account(7, signer, name = "operator_voter")]
+    #[account(8, optional, name = "operator_vote_key")]
+    #[account(9, optional, writable, name = "ballot_box_page")]
+    RevealVote {
+        meta_merkle_root: [u8; 32],
+        salt: [u8; 32],
+        epoch: u64,
+    },
+
+    /// Permissionlessly finalizes the leading ballot of a stalled vote
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, writable, name = "ballot_box")]
+    #[account(3, name = "ncn")]
+    #[account(4, name = "epoch_snapshot")]
+    ResolveStalledVote {
+        epoch: u64,
+    },
+
+    /// Sets the signers of a role's multisig and makes it the admin for that role
+    #[account(0, writable, name = "config")]
+    #[account(1, writable, name = "admin_multisig")]
+    #[account(2, name = "ncn")]
+    #[account(3, signer, name = "ncn_admin")]
+    #[account(4, writable, name = "account_payer")]
+    #[account(5, name = "system_program")]
+    AdminSetAdminMultisig {
+        role: ConfigAdminRole,
+        threshold: u8,
+        signers: Vec<Pubkey>,
+    },
+
+    /// Proposes an admin action for a role's multisig, approved by the proposer who pays for the
+    /// proposal account
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "admin_multisig")]
+    #[account(2, writable, name = "admin_proposal")]
+    #[account(3, name = "ncn")]
+    #[account(4, writable, signer, name = "signer")]
+    #[account(5, name = "system_program")]
+    ProposeAdminAction {
+        role: ConfigAdminRole,
+        action: AdminAction,
+    },
+
+    /// Approves a proposed admin action
+    #[account(0, name = "admin_multisig")]
+    #[account(1, writable, name = "admin_proposal")]
+    #[account(2, name = "ncn")]
+    #[account(3, signer, name = "signer")]
+    ApproveAdminAction {
+        role: ConfigAdminRole,
+        nonce: u64,
+    },
+
+    /// Executes a proposed admin action once enough signers have approved
+    /// Remaining accounts: the epoch state and ballot box of the epoch, when setting the tie breaker
+    #[account(0, writable, name = "config")]
+    #[account(1, writable, name = "admin_multisig")]
+    #[account(2, writable, name = "admin_proposal")]
+    #[account(3, name = "ncn")]
+    ExecuteAdminAction {
+        role: ConfigAdminRole,
+        nonce: u64,
+    },
+
+    /// Proposes a new secondary admin, who has to accept once the handover delay has passed
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "ncn_admin")]
+    #[account(3, name = "new_admin")]
+    AdminProposeNewAdmin {
+        role: ConfigAdminRole,
+    },
+
+    /// Accepts a pending admin handover
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "new_admin")]
+    AdminAcceptAdmin {
+        role: ConfigAdminRole,
+    },
+
+    /// Cancels a pending admin handover, signed by either the NCN admin or the pending admin
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "signer")]
+    AdminCancelAdminProposal {
+        role: ConfigAdminRole,
+    },
+
+    /// Sets the key that can vote for an operator in place of the restaking operator's voter, the
+    /// operator admin pays for the operator vote key account
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "operator_vote_key")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "restaking_config")]
+    #[account(5, name = "ncn_operator_state")]
+    #[account(6, writable, signer, name = "operator_admin")]
+    #[account(7, name = "system_program")]
+    SetOperatorVoteKey {
+        vote_key: Pubkey,
+    },
+
+    /// Withdraws an operator's vote before consensus is reached
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, writable, name = "ballot_box")]
+    #[account(3, name = "ncn")]
+    #[account(4, name = "operator")]
+    #[account(5, signer, name = "operator_voter")]
+    #[account(6, optional, name = "operator_vote_key")]
+    #[account(7, optional, writable, name = "ballot_box_page")]
+    WithdrawVote {
+        epoch: u64,
+    },
+
+    /// Sets the weight of an ST mint from its price feed, read by the oracle of the mint entry
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "weight_table")]
+    #[account(3, name = "oracle_feed")]
+    #[account(4, optional, writable, name = "vault_registry")]
+    OracleSetWeight {
+        st_mint: Pubkey,
+        epoch: u64,
+    },
+
+    /// Leaves a vault out of the weight tables of `epoch` onward, vaults past `MAX_VAULTS` are
+    /// deactivated in their vault registry page
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "vault_registry")]
+    #[account(3, signer, name = "admin")]
+    #[account(4, optional, writable, name = "vault_registry_page")]
+    AdminDeactivateVault {
+        vault: Pubkey,
+        epoch: u64,
+    },
+
+    /// Leaves an ST mint out of the weight tables of `epoch` onward, its vaults have to be deactivated first.
+    /// Every vault registry page has to be passed in order as remaining accounts
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "vault_registry")]
+    #[account(3, signer, name = "admin")]
+    AdminDeactivateStMint {
+        st_mint: Pubkey,
+        epoch: u64,
+    },
+
+    /// Initializes a ballot box page for the votes of operators past `MAX_OPERATORS`
+    #[account(0, name = "epoch_marker")]
+    #[account(1, name = "epoch_state")]
+    #[account(2, name = "config")]
+    #[account(3, writable, name = "ballot_box")]
+    #[account(4, writable, name = "ballot_box_page")]
+    #[account(5, name = "ncn")]
+    #[account(6, writable, name = "account_payer")]
+    #[account(7, name = "system_program")]
+    InitializeBallotBoxPage {
+        epoch: u64,
+        page: u64,
+    },
+
+    /// Sets the asset an operator or vault is paid its NCN rewards in, only the operator or
+    /// vault admin can sign. The admin pays for the payout preference account
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "payout_preference")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "recipient")]
+    #[account(4, writable, signer, name = "admin")]
+    #[account(5, name = "system_program")]
+    SetPayoutPreference {
+        payout_asset: u8,
+        sol_destination: Pubkey,
+    },
+
+    /// Moves the rewards left in a finished epoch's base or NCN reward router into the current
+    /// epoch's base reward receiver
+    #[account(0, writable, name = "epoch_state")]
+    #[account(1, name = "config")]
+    #[account(2, name = "ncn")]
+    #[account(3, writable, name = "reward_router")]
+    #[account(4, writable, name = "reward_receiver")]
+    #[account(5, writable, name = "current_epoch_state")]
+    #[account(6, writable, name = "current_base_reward_receiver")]
+    #[account(7, name = "system_program")]
+    CarryForwardRewards {
+        epoch: u64,
+    },
+
+    /// Cancels the fees scheduled to activate at `activation_epoch`
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "fee_admin")]
+    AdminCancelScheduledFees {
+        activation_epoch: u64,
+    },
+
+    /// Names, enables or disables a base fee group and sets the `PayoutAsset` its rewards are
+    /// paid out in
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "fee_admin")]
+    AdminSetBaseFeeGroup {
+        base_fee_group: u8,
+        name: Option<[u8; 32]>,
+        enabled: Option<bool>,
+        payout_asset: Option<u8>,
+    },
+
+    /// Sets the bitmask of paused `PausableSubsystem`s. The pause admin can only pause
+    /// subsystems, unpausing requires the NCN admin
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, signer, name = "admin")]
+    AdminSetPausedSubsystems {
+        paused_subsystems: u8,
+    },
+
+    /// Upgrades a versioned account to its next layout version, the account payer covers the
+    /// rent when the new layout is larger
+    #[account(0, name = "ncn")]
+    #[account(1, writable, name = "account_to_migrate")]
+    #[account(2, writable, name = "account_payer")]
+    #[account(3, name = "system_program")]
+    MigrateAccount,
+
+    /// Closes an executed or stale admin proposal, returning the rent to the proposer. The
+    /// proposer can also close (cancel) a proposal that is still open
+    #[account(0, name = "config")]
+    #[account(1, name = "admin_multisig")]
+    #[account(2, writable, name = "admin_proposal")]
+    #[account(3, name = "ncn")]
+    #[account(4, writable, name = "proposer")]
+    #[account(5, signer, name = "signer")]
+    CloseAdminProposal {
+        role: ConfigAdminRole,
+        nonce: u64,
+    },
+
+    /// Allows or disallows an SPL token mint for token reward routers
+    #[account(0, writable, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "mint")]
+    #[account(3, signer, name = "ncn_admin")]
+    AdminSetRewardMint {
+        allowed: bool,
+    },
+
+    /// Caps the operator's voting stake weight against the finalized epoch snapshot's total stake
+    /// weight. Operators without stake weight caps are capped when their snapshot is finalized.
+    /// The operator snapshot pages are passed in order as remaining accounts
+    #[account(0, name = "epoch_state")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "epoch_snapshot")]
+    #[account(3, name = "operator")]
+    #[account(4, writable, name = "operator_snapshot")]
+    ApplyStakeWeightCaps {
+        epoch: u64,
+    },
+
+    /// Initializes a vault registry page for the vaults past `MAX_VAULTS`
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault_registry")]
+    #[account(2, writable, name = "vault_registry_page")]
+    #[account(3, name = "ncn")]
+    #[account(4, writable, name = "account_payer")]
+    #[account(5, name = "system_program")]
+    InitializeVaultRegistryPage {
+        page: u64,
+    },
+
+    /// Initializes a weight table page, a snapshot of the vault registry page of the same index
+    #[account(0, name = "epoch_marker")]
+    #[account(1, writable, name = "epoch_state")]
+    #[account(2, name = "vault_registry_page")]
+    #[account(3, name = "ncn")]
+    #[account(4, writable, name = "weight_table")]
+    #[account(5, writable, name = "weight_table_page")]
+    #[account(6, writable, name = "account_payer")]
+    #[account(7, name = "system_program")]
+    InitializeWeightTablePage {
+        epoch: u64,
+        page: u64,
+    },
+
+    /// Initializes an operator snapshot page for the operator's delegations from vaults past
+    /// `MAX_VAULTS`
+    #[account(0, name = "epoch_marker")]
+    #[account(1, name = "epoch_state")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, writable, name = "operator_snapshot")]
+    #[account(5, writable, name = "operator_snapshot_page")]
+    #[account(6, writable, name = "account_payer")]
+    #[account(7, name = "system_program")]
+    InitializeOperatorSnapshotPage {
+        epoch: u64,
+        page: u64,
+    },
+
+    /// Closes the operator vote key account, refunding its rent to the operator admin
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "operator_vote_key")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, writable, signer, name = "operator_admin")]
+    CloseOperatorVoteKey,
+
+    /// Initializes the next epoch state page, tracking the epoch accounts of operators past
+    /// `MAX_OPERATORS`
+    #[account(0, name = "epoch_marker")]
+    #[account(1, writable, name = "epoch_state")]
+    #[account(2, name = "config")]
+    #[account(3, writable, name = "epoch_state_page")]
+    #[account(4, name = "ncn")]
+    #[account(5, writable, name = "account_payer")]
+    #[account(6, name = "system_program")]
+    InitializeEpochStatePage {
+        epoch: u64,
+        page: u64,
+    },
+
+    /// Initializes the next base reward router page, routing the rewards of operators voting in
+    /// ballot box pages
+    #[account(0, name = "epoch_marker")]
+    #[account(1, name = "epoch_state")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "ballot_box")]
+    #[account(4, writable, name = "base_reward_router")]
+    #[account(5, writable, name = "base_reward_router_page")]
+    #[account(6, writable, name = "account_payer")]
+    #[account(7, name = "system_program")]
+    InitializeBaseRewardRouterPage {
+        epoch: u64,
+        page: u64,
     },
 }
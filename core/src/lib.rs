@@ -1,24 +1,37 @@
 pub mod account_payer;
+pub mod account_version;
+pub mod admin_multisig;
 pub mod ballot_box;
+pub mod ballot_box_page;
 pub mod base_fee_group;
 pub mod base_reward_router;
+pub mod base_reward_router_page;
 pub mod config;
 pub mod constants;
 pub mod discriminators;
 pub mod epoch_marker;
 pub mod epoch_snapshot;
 pub mod epoch_state;
+pub mod epoch_state_page;
 pub mod error;
+pub mod events;
 pub mod fees;
 pub mod instruction;
 pub mod loaders;
 pub mod ncn_fee_group;
 pub mod ncn_reward_router;
+pub mod operator_snapshot_page;
+pub mod operator_vote_key;
+pub mod oracle;
+pub mod pause;
+pub mod payout_preference;
 pub mod spl_stake_pool;
 pub mod stake_weight;
 pub mod utils;
 pub mod vault_registry;
+pub mod vault_registry_page;
 pub mod weight_entry;
 pub mod weight_table;
+pub mod weight_table_page;
 
 pub use constants::ID;
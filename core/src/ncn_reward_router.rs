@@ -1,4 +1,7 @@
-use core::{fmt, mem::size_of};
+use core::{
+    fmt,
+    mem::{offset_of, size_of},
+};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
@@ -13,11 +16,18 @@ use solana_program::{
 };
 use solana_system_interface::instruction::transfer as transfer_ix;
 use solana_system_interface::program as system_program;
+use spl_associated_token_account_interface::address::get_associated_token_address;
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
-    constants::MAX_VAULTS, discriminators::Discriminators, epoch_snapshot::OperatorSnapshot,
-    error::TipRouterError, loaders::check_load, ncn_fee_group::NcnFeeGroup,
+    account_version::{check_account_version, VersionedAccount},
+    constants::MAX_VAULTS,
+    discriminators::Discriminators,
+    epoch_snapshot::OperatorSnapshot,
+    error::TipRouterError,
+    loaders::check_load,
+    ncn_fee_group::NcnFeeGroup,
+    operator_snapshot_page::OperatorSnapshotPage,
 };
 
 // PDA'd ["epoch_reward_router", NCN, NCN_EPOCH_SLOT]
@@ -46,8 +56,14 @@ pub struct NcnRewardRouter {
     rewards_processed: PodU64,
     /// Rewards to go to the operator ( in lamports )
     operator_rewards: PodU64,
+    /// SPL token mint of the rewards routed, the default pubkey when routing lamports
+    mint: Pubkey,
+    /// Rewards carried forward into a later epoch's base reward receiver ( in lamports )
+    rewards_carried_forward: PodU64,
+    /// Rewards picked up by the last routing round ( in lamports )
+    last_rewards_routed: PodU64,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 80],
     // Routing state - so we can recover from a partial routing
     /// The last rewards to process
     last_rewards_to_process: PodU64,
@@ -61,6 +77,11 @@ impl Discriminator for NcnRewardRouter {
     const DISCRIMINATOR: u8 = Discriminators::NcnRewardRouter as u8;
 }
 
+impl VersionedAccount for NcnRewardRouter {
+    const ACCOUNT_VERSION: u8 = 1;
+    const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+}
+
 impl NcnRewardRouter {
     pub const SIZE: usize = 8 + size_of::<Self>();
 
@@ -73,6 +94,7 @@ impl NcnRewardRouter {
         operator: &Pubkey,
         operator_ncn_index: u64,
         ncn: &Pubkey,
+        mint: &Pubkey,
         epoch: u64,
         bump: u8,
         slot_created: u64,
@@ -89,7 +111,10 @@ impl NcnRewardRouter {
             reward_pool: PodU64::from(0),
             rewards_processed: PodU64::from(0),
             operator_rewards: PodU64::from(0),
-            reserved: [0; 128],
+            mint: *mint,
+            rewards_carried_forward: PodU64::from(0),
+            last_rewards_routed: PodU64::from(0),
+            reserved: [0; 80],
             last_rewards_to_process: PodU64::from(Self::NO_LAST_REWARDS_TO_PROCESS),
             last_vault_operator_delegation_index: PodU16::from(
                 Self::NO_LAST_VAULT_OPERATION_DELEGATION_INDEX,
@@ -104,17 +129,30 @@ impl NcnRewardRouter {
         ncn: &Pubkey,
         epoch: u64,
     ) -> Vec<Vec<u8>> {
-        Vec::from_iter(
-            [
-                b"ncn_reward_router".to_vec(),
-                vec![ncn_fee_group.group],
-                operator.to_bytes().to_vec(),
-                ncn.to_bytes().to_vec(),
-                epoch.to_le_bytes().to_vec(),
-            ]
-            .iter()
-            .cloned(),
-        )
+        Self::seeds_for_mint(ncn_fee_group, operator, ncn, &Pubkey::default(), epoch)
+    }
+
+    /// Token routers are seeded with their mint, the lamport router keeps the original seeds
+    pub fn seeds_for_mint(
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        mint: &Pubkey,
+        epoch: u64,
+    ) -> Vec<Vec<u8>> {
+        let mut seeds = vec![
+            b"ncn_reward_router".to_vec(),
+            vec![ncn_fee_group.group],
+            operator.to_bytes().to_vec(),
+            ncn.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ];
+
+        if mint.ne(&Pubkey::default()) {
+            seeds.push(mint.to_bytes().to_vec());
+        }
+
+        seeds
     }
 
     pub fn find_program_address(
@@ -124,7 +162,25 @@ impl NcnRewardRouter {
         ncn: &Pubkey,
         epoch: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn_fee_group, operator, ncn, epoch);
+        Self::find_program_address_for_mint(
+            program_id,
+            ncn_fee_group,
+            operator,
+            ncn,
+            &Pubkey::default(),
+            epoch,
+        )
+    }
+
+    pub fn find_program_address_for_mint(
+        program_id: &Pubkey,
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        mint: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds_for_mint(ncn_fee_group, operator, ncn, mint, epoch);
         let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
         let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
         (pda, bump, seeds)
@@ -139,15 +195,46 @@ impl NcnRewardRouter {
         epoch: u64,
         expect_writable: bool,
     ) -> Result<(), ProgramError> {
-        let expected_pda =
-            Self::find_program_address(program_id, ncn_fee_group, operator, ncn, epoch).0;
+        Self::load_for_mint(
+            program_id,
+            account,
+            ncn_fee_group,
+            operator,
+            ncn,
+            &Pubkey::default(),
+            epoch,
+            expect_writable,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn load_for_mint(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        mint: &Pubkey,
+        epoch: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address_for_mint(
+            program_id,
+            ncn_fee_group,
+            operator,
+            ncn,
+            mint,
+            epoch,
+        )
+        .0;
         check_load(
             program_id,
             account,
             &expected_pda,
             Some(Self::DISCRIMINATOR),
             expect_writable,
-        )
+        )?;
+        check_account_version::<Self>(account)
     }
 
     pub fn load_to_close(
@@ -160,13 +247,15 @@ impl NcnRewardRouter {
         let account_struct = Self::try_from_slice_unchecked(&account_data)?;
         let ncn_fee_group = account_struct.ncn_fee_group();
         let operator = *account_struct.operator();
+        let mint = *account_struct.mint();
 
-        Self::load(
+        Self::load_for_mint(
             program_id,
             account_to_close,
             ncn_fee_group,
             &operator,
             ncn,
+            &mint,
             epoch,
             true,
         )
@@ -184,6 +273,15 @@ impl NcnRewardRouter {
         &self.ncn
     }
 
+    pub const fn mint(&self) -> &Pubkey {
+        &self.mint
+    }
+
+    /// Whether the router routes SPL token rewards instead of lamports
+    pub fn is_token_router(&self) -> bool {
+        self.mint.ne(&Pubkey::default())
+    }
+
     pub fn epoch(&self) -> u64 {
         self.epoch.into()
     }
@@ -200,6 +298,49 @@ impl NcnRewardRouter {
         &self.vault_reward_routes
     }
 
+    pub fn rewards_carried_forward(&self) -> u64 {
+        self.rewards_carried_forward.into()
+    }
+
+    pub fn last_rewards_routed(&self) -> u64 {
+        self.last_rewards_routed.into()
+    }
+
+    /// Returns the part of `receiver_rewards`, the receiver's balance above rent, that was never
+    /// routed into a tally. Rewards in transit stay behind and can still be distributed.
+    pub fn carry_forward_rewards(&mut self, receiver_rewards: u64) -> Result<u64, TipRouterError> {
+        if self.still_routing() {
+            msg!("Cannot carry rewards forward while routing");
+            return Err(TipRouterError::CannotCarryForwardRewards);
+        }
+
+        let rewards_in_transit = self.total_rewards_in_transit()?;
+        if rewards_in_transit > receiver_rewards {
+            msg!("Reward receiver holds less than the rewards in transit");
+            return Err(TipRouterError::ArithmeticUnderflowError);
+        }
+
+        let rewards = receiver_rewards
+            .checked_sub(rewards_in_transit)
+            .ok_or(TipRouterError::ArithmeticUnderflowError)?;
+
+        self.increment_rewards_carried_forward(rewards)?;
+
+        Ok(rewards)
+    }
+
+    pub fn increment_rewards_carried_forward(
+        &mut self,
+        rewards: u64,
+    ) -> Result<(), TipRouterError> {
+        self.rewards_carried_forward = PodU64::from(
+            self.rewards_carried_forward()
+                .checked_add(rewards)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        Ok(())
+    }
+
     // ----------------- ROUTE STATE TRACKING --------------
     pub fn last_rewards_to_process(&self) -> u64 {
         self.last_rewards_to_process.into()
@@ -259,6 +400,7 @@ impl NcnRewardRouter {
             .ok_or(TipRouterError::ArithmeticUnderflowError)?;
 
         self.route_to_reward_pool(rewards_to_route)?;
+        self.last_rewards_routed = PodU64::from(rewards_to_route);
 
         Ok(())
     }
@@ -282,11 +424,17 @@ impl NcnRewardRouter {
         Ok(())
     }
 
+    /// Routes the reward pool to the operator's vaults, `operator_snapshot_pages` has to hold all
+    /// of the operator snapshot pages in order. Vaults are iterated header first, then page by
+    /// page.
     pub fn route_reward_pool(
         &mut self,
         operator_snapshot: &OperatorSnapshot,
+        operator_snapshot_pages: &[&OperatorSnapshotPage],
         max_iterations: u16,
     ) -> Result<(), TipRouterError> {
+        operator_snapshot.check_pages(operator_snapshot_pages)?;
+
         {
             let operator_stake_weight = operator_snapshot.stake_weights();
             let vault_ncn_fee_group = self.ncn_fee_group();
@@ -303,12 +451,17 @@ impl NcnRewardRouter {
             // Always have at least 1 iteration
             let max_iterations = max_iterations.max(1);
 
-            for vault_operator_delegation_index in starting_vault_operator_delegation_index
-                ..operator_snapshot.vault_operator_stake_weight().len()
+            for (vault_operator_delegation_index, vault_operator_delegation) in operator_snapshot
+                .vault_operator_stake_weight()
+                .iter()
+                .chain(
+                    operator_snapshot_pages
+                        .iter()
+                        .flat_map(|page| page.vault_operator_stake_weight()),
+                )
+                .enumerate()
+                .skip(starting_vault_operator_delegation_index)
             {
-                let vault_operator_delegation = operator_snapshot.vault_operator_stake_weight()
-                    [vault_operator_delegation_index];
-
                 // Update iteration state
                 {
                     iterations = iterations
@@ -741,6 +894,147 @@ impl NcnRewardReceiver {
         )?;
         Ok(())
     }
+
+    /// The receiver's ATA, which holds the rewards of a token router
+    pub fn token_account(
+        program_id: &Pubkey,
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        mint: &Pubkey,
+    ) -> Pubkey {
+        let ncn_reward_receiver =
+            Self::find_program_address(program_id, ncn_fee_group, operator, ncn, epoch).0;
+        get_associated_token_address(&ncn_reward_receiver, mint)
+    }
+
+    /// Sends any tokens left in the receiver's token account to the DAO wallet's ATA and closes
+    /// it, returning its rent to the account payer
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn close_token_account<'a, 'info>(
+        program_id: &Pubkey,
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        ncn_reward_receiver: &'a AccountInfo<'info>,
+        token_account: &'a AccountInfo<'info>,
+        dao_wallet_token_account: &'a AccountInfo<'info>,
+        account_payer: &'a AccountInfo<'info>,
+        token_program: &'a AccountInfo<'info>,
+        amount: u64,
+    ) -> ProgramResult {
+        if amount > 0 {
+            Self::transfer_tokens(
+                program_id,
+                ncn_fee_group,
+                operator,
+                ncn,
+                epoch,
+                ncn_reward_receiver,
+                token_account,
+                dao_wallet_token_account,
+                token_program,
+                amount,
+            )?;
+        }
+
+        let seeds = Self::signer_seeds(
+            program_id,
+            ncn_fee_group,
+            operator,
+            ncn,
+            epoch,
+            ncn_reward_receiver,
+        )?;
+        invoke_signed(
+            &spl_token_interface::instruction::close_account(
+                token_program.key,
+                token_account.key,
+                account_payer.key,
+                ncn_reward_receiver.key,
+                &[],
+            )?,
+            &[
+                token_account.clone(),
+                account_payer.clone(),
+                ncn_reward_receiver.clone(),
+                token_program.clone(),
+            ],
+            &[seeds
+                .iter()
+                .map(|seed| seed.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_slice()],
+        )
+    }
+
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer_tokens<'a, 'info>(
+        program_id: &Pubkey,
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        ncn_reward_receiver: &'a AccountInfo<'info>,
+        from: &'a AccountInfo<'info>,
+        to: &'a AccountInfo<'info>,
+        token_program: &'a AccountInfo<'info>,
+        amount: u64,
+    ) -> ProgramResult {
+        let seeds = Self::signer_seeds(
+            program_id,
+            ncn_fee_group,
+            operator,
+            ncn,
+            epoch,
+            ncn_reward_receiver,
+        )?;
+        invoke_signed(
+            &spl_token_interface::instruction::transfer(
+                token_program.key,
+                from.key,
+                to.key,
+                ncn_reward_receiver.key,
+                &[],
+                amount,
+            )?,
+            &[
+                from.clone(),
+                to.clone(),
+                ncn_reward_receiver.clone(),
+                token_program.clone(),
+            ],
+            &[seeds
+                .iter()
+                .map(|seed| seed.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_slice()],
+        )
+    }
+
+    fn signer_seeds(
+        program_id: &Pubkey,
+        ncn_fee_group: NcnFeeGroup,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        ncn_reward_receiver: &AccountInfo,
+    ) -> Result<Vec<Vec<u8>>, ProgramError> {
+        let (address, bump, mut seeds) =
+            Self::find_program_address(program_id, ncn_fee_group, operator, ncn, epoch);
+        seeds.push(vec![bump]);
+
+        if address.ne(ncn_reward_receiver.key) {
+            msg!("Incorrect NCN reward receiver PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(seeds)
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Copy, Zeroable, ShankType, Pod)]
@@ -809,6 +1103,9 @@ impl fmt::Display for NcnRewardRouter {
         writeln!(f, "  NCN Fee Group:                {}", self.ncn_fee_group.group)?;
         writeln!(f, "  Operator:                     {}", self.operator)?;
         writeln!(f, "  NCN:                          {}", self.ncn)?;
+        if self.is_token_router() {
+            writeln!(f, "  Mint:                         {}", self.mint)?;
+        }
         writeln!(f, "  Epoch:                        {}", self.epoch())?;
         writeln!(f, "  Bump:                         {}", self.bump)?;
         writeln!(f, "  Slot Created:                 {}", self.slot_created())?;
@@ -817,6 +1114,7 @@ impl fmt::Display for NcnRewardRouter {
         writeln!(f, "  Total Rewards:                {}", self.total_rewards())?;
         writeln!(f, "  Reward Pool:                  {}", self.reward_pool())?;
         writeln!(f, "  Rewards Processed:            {}", self.rewards_processed())?;
+        writeln!(f, "  Rewards Carried Forward:      {}", self.rewards_carried_forward())?;
         writeln!(f, "  Operator Rewards:             {}", self.operator_rewards())?;
 
         if self.still_routing() {
@@ -923,7 +1221,10 @@ mod tests {
             + size_of::<PodU64>() // reward_pool
             + size_of::<PodU64>() // rewards_processed
             + size_of::<PodU64>() // operator_rewards
-            + 128 // reserved
+            + size_of::<Pubkey>() // mint
+            + size_of::<PodU64>() // rewards_carried_forward
+            + size_of::<PodU64>() // last_rewards_routed
+            + 80 // reserved
             + size_of::<PodU64>() // last_rewards_to_process
             + size_of::<PodU16>() // last_vault_operator_delegation_index
             + size_of::<VaultRewardRoute>() * MAX_VAULTS; // vault_reward_routes
@@ -938,6 +1239,7 @@ mod tests {
             &Pubkey::new_unique(), // ncn
             0,
             &Pubkey::new_unique(), // ncn
+            &Pubkey::default(),    // mint
             TEST_EPOCH,            // epoch
             1,                     // bump
             TEST_CURRENT_SLOT,     // slot_created
@@ -985,6 +1287,7 @@ mod tests {
             &Pubkey::new_unique(), // ncn
             0,
             &Pubkey::new_unique(), // ncn
+            &Pubkey::default(),    // mint
             TEST_EPOCH,            // epoch
             1,                     // bump
             TEST_CURRENT_SLOT,     // slot_created
@@ -1025,6 +1328,66 @@ mod tests {
         assert_eq!(router.rewards_processed(), INCOMING_REWARDS / 10);
     }
 
+    #[test]
+    fn test_carry_forward_rewards() {
+        let mut router = NcnRewardRouter::new(
+            NcnFeeGroup::default(),
+            &Pubkey::new_unique(), // ncn
+            0,
+            &Pubkey::new_unique(), // ncn
+            &Pubkey::default(),    // mint
+            TEST_EPOCH,            // epoch
+            1,                     // bump
+            TEST_CURRENT_SLOT,     // slot_created
+        );
+
+        let operator_snapshot = {
+            let mut operator_snapshot = get_test_operator_snapshot(1000, 1);
+            register_test_vault_operator_delegation(&mut operator_snapshot, 1000, 1000);
+            operator_snapshot
+        };
+
+        router.route_incoming_rewards(0, 1000).unwrap();
+        router.route_operator_rewards(&operator_snapshot).unwrap();
+        router
+            .route_reward_pool(
+                &operator_snapshot,
+                &[],
+                NcnRewardRouter::MAX_ROUTE_NCN_ITERATIONS,
+            )
+            .unwrap();
+
+        // The operator was paid, the vault was not
+        router.distribute_operator_rewards().unwrap();
+        let vault_rewards = router.total_rewards_in_transit().unwrap();
+        assert!(vault_rewards > 0);
+
+        // Only lamports the receiver holds above the rewards in transit are carried forward
+        assert_eq!(
+            router.carry_forward_rewards(vault_rewards + 25).unwrap(),
+            25
+        );
+        assert_eq!(router.rewards_carried_forward(), 25);
+        assert_eq!(
+            router.carry_forward_rewards(vault_rewards - 1),
+            Err(TipRouterError::ArithmeticUnderflowError)
+        );
+
+        // The vault can still be paid
+        assert_eq!(router.total_rewards_in_transit().unwrap(), vault_rewards);
+        let vault = router
+            .vault_reward_routes()
+            .iter()
+            .find(|route| route.has_rewards())
+            .unwrap()
+            .vault();
+        assert_eq!(
+            router.distribute_vault_reward_route(&vault).unwrap(),
+            vault_rewards
+        );
+        assert_eq!(router.total_rewards_in_transit().unwrap(), 0);
+    }
+
     #[test]
     fn test_route_all_operator_rewards() {
         const INCOMING_REWARDS: u64 = 1000;
@@ -1034,6 +1397,7 @@ mod tests {
             &Pubkey::new_unique(), // ncn
             0,
             &Pubkey::new_unique(), // ncn
+            &Pubkey::default(),    // mint
             TEST_EPOCH,            // epoch
             1,                     // bump
             TEST_CURRENT_SLOT,     // slot_created
@@ -1083,6 +1447,7 @@ mod tests {
             &Pubkey::new_unique(), // ncn
             0,
             &Pubkey::new_unique(), // ncn
+            &Pubkey::default(),    // mint
             TEST_EPOCH,            // epoch
             1,                     // bump
             TEST_CURRENT_SLOT,     // slot_created
@@ -1122,11 +1487,15 @@ mod tests {
         assert_eq!(router.reward_pool(), INCOMING_REWARDS);
         assert_eq!(router.rewards_processed(), 0);
 
-        router.route_reward_pool(&operator_snapshot, 5).unwrap();
+        router
+            .route_reward_pool(&operator_snapshot, &[], 5)
+            .unwrap();
 
         assert!(router.still_routing());
 
-        router.route_reward_pool(&operator_snapshot, 1000).unwrap();
+        router
+            .route_reward_pool(&operator_snapshot, &[], 1000)
+            .unwrap();
 
         assert!(!router.still_routing());
 
@@ -1143,6 +1512,79 @@ mod tests {
         assert_eq!(router.rewards_processed(), INCOMING_REWARDS);
     }
 
+    #[test]
+    fn test_route_reward_pool_with_pages() {
+        const INCOMING_REWARDS: u64 = 1000;
+
+        let mut router = NcnRewardRouter::new(
+            NcnFeeGroup::default(),
+            &Pubkey::new_unique(), // ncn
+            0,
+            &Pubkey::new_unique(), // ncn
+            &Pubkey::default(),    // mint
+            TEST_EPOCH,            // epoch
+            1,                     // bump
+            TEST_CURRENT_SLOT,     // slot_created
+        );
+        router.route_incoming_rewards(0, INCOMING_REWARDS).unwrap();
+
+        // One vault in the header and one past MAX_VAULTS
+        let mut operator_snapshot = get_test_operator_snapshot(0, 2);
+        register_test_vault_operator_delegation(&mut operator_snapshot, 1000, 1000);
+
+        let paged_vault = Pubkey::new_unique();
+        let stake_weights = StakeWeights::snapshot(NcnFeeGroup::default(), 1000, 1000).unwrap();
+        operator_snapshot.add_page(0).unwrap();
+        let mut page = OperatorSnapshotPage::new(
+            operator_snapshot.operator(),
+            operator_snapshot.ncn(),
+            TEST_EPOCH,
+            0,
+            0,
+        );
+        page.insert_vault_operator_stake_weight(
+            MAX_VAULTS as u64,
+            &paged_vault,
+            70,
+            NcnFeeGroup::default(),
+            &stake_weights,
+        )
+        .unwrap();
+        operator_snapshot
+            .increment_paged_vault_operator_delegation_registration(
+                TEST_CURRENT_SLOT,
+                &stake_weights,
+            )
+            .unwrap();
+
+        router.route_operator_rewards(&operator_snapshot).unwrap();
+
+        // The pages have to be passed
+        assert_eq!(
+            router.route_reward_pool(&operator_snapshot, &[], 1000),
+            Err(TipRouterError::InvalidVaultPage)
+        );
+
+        // Stops in the header, then resumes through the page
+        router
+            .route_reward_pool(&operator_snapshot, &[&page], 5)
+            .unwrap();
+        assert!(router.still_routing());
+        router
+            .route_reward_pool(&operator_snapshot, &[&page], 1000)
+            .unwrap();
+        assert!(!router.still_routing());
+
+        let paged_route = router
+            .vault_reward_routes()
+            .iter()
+            .find(|route| route.vault().eq(&paged_vault))
+            .unwrap();
+        assert_eq!(paged_route.rewards(), 500);
+        assert_eq!(router.reward_pool(), 0);
+        assert_eq!(router.rewards_processed(), INCOMING_REWARDS);
+    }
+
     #[test]
     fn test_reward_multiplier() {
         const INCOMING_REWARDS: u64 = 1000;
@@ -1152,6 +1594,7 @@ mod tests {
             &Pubkey::new_unique(), // ncn
             0,
             &Pubkey::new_unique(), // ncn
+            &Pubkey::default(),    // mint
             TEST_EPOCH,            // epoch
             1,                     // bump
             TEST_CURRENT_SLOT,     // slot_created
@@ -1195,7 +1638,9 @@ mod tests {
         assert_eq!(router.reward_pool(), INCOMING_REWARDS);
         assert_eq!(router.rewards_processed(), 0);
 
-        router.route_reward_pool(&operator_snapshot, 1000).unwrap();
+        router
+            .route_reward_pool(&operator_snapshot, &[], 1000)
+            .unwrap();
         for (index, route) in router
             .vault_reward_routes()
             .iter()
@@ -1223,6 +1668,7 @@ mod tests {
             &Pubkey::new_unique(), // ncn
             0,
             &Pubkey::new_unique(), // ncn
+            &Pubkey::default(),    // mint
             TEST_EPOCH,            // epoch
             1,                     // bump
             TEST_CURRENT_SLOT,     // slot_created
@@ -1262,7 +1708,9 @@ mod tests {
         assert_eq!(router.reward_pool(), INCOMING_REWARDS);
         assert_eq!(router.rewards_processed(), 0);
 
-        router.route_reward_pool(&operator_snapshot, 1000).unwrap();
+        router
+            .route_reward_pool(&operator_snapshot, &[], 1000)
+            .unwrap();
         for route in router
             .vault_reward_routes()
             .iter()
@@ -1289,6 +1737,7 @@ mod tests {
             &Pubkey::new_unique(), // ncn
             0,
             &Pubkey::new_unique(), // ncn
+            &Pubkey::default(),    // mint
             TEST_EPOCH,            // epoch
             1,                     // bump
             TEST_CURRENT_SLOT,     // slot_created
@@ -1328,7 +1777,9 @@ mod tests {
         assert_eq!(router.reward_pool(), expected_all_vault_rewards);
         assert_eq!(router.rewards_processed(), expected_operator_rewards);
 
-        router.route_reward_pool(&operator_snapshot, 1000).unwrap();
+        router
+            .route_reward_pool(&operator_snapshot, &[], 1000)
+            .unwrap();
         for route in router
             .vault_reward_routes()
             .iter()
@@ -1355,6 +1806,7 @@ mod tests {
             &Pubkey::new_unique(), // ncn
             0,
             &Pubkey::new_unique(), // ncn
+            &Pubkey::default(),    // mint
             TEST_EPOCH,            // epoch
             1,                     // bump
             TEST_CURRENT_SLOT,     // slot_created
@@ -1394,11 +1846,15 @@ mod tests {
         assert_eq!(router.reward_pool(), expected_all_vault_rewards);
         assert_eq!(router.rewards_processed(), expected_operator_rewards);
 
-        router.route_reward_pool(&operator_snapshot, 0).unwrap();
+        router
+            .route_reward_pool(&operator_snapshot, &[], 0)
+            .unwrap();
         assert!(router.still_routing());
 
         for _ in 0..MAX_VAULTS * 2 {
-            router.route_reward_pool(&operator_snapshot, 0).unwrap();
+            router
+                .route_reward_pool(&operator_snapshot, &[], 0)
+                .unwrap();
         }
         assert!(!router.still_routing());
 
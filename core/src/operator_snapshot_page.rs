@@ -0,0 +1,338 @@
+use core::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::VAULTS_PER_PAGE, discriminators::Discriminators,
+    epoch_snapshot::VaultOperatorStakeWeight, error::TipRouterError, loaders::check_load,
+    ncn_fee_group::NcnFeeGroup, stake_weight::StakeWeights, vault_registry_page::VaultRegistryPage,
+};
+
+/// Extension of an operator snapshot holding the stake weights of the vaults whose NCN vault
+/// index is past `MAX_VAULTS`. Vaults are kept at the same position as in the vault registry
+/// pages.
+// PDA'd ["operator_snapshot_page", OPERATOR, NCN, NCN_EPOCH_SLOT, PAGE]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct OperatorSnapshotPage {
+    /// The operator the page is associated with
+    operator: Pubkey,
+    /// The NCN the page is associated with
+    ncn: Pubkey,
+    /// The epoch the page is associated with
+    epoch: PodU64,
+    /// Index of the page within the operator snapshot
+    page: PodU64,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Reserved space
+    reserved: [u8; 127],
+    /// Vault operator stake weights
+    vault_operator_stake_weight: [VaultOperatorStakeWeight; 32],
+}
+
+impl Discriminator for OperatorSnapshotPage {
+    const DISCRIMINATOR: u8 = Discriminators::OperatorSnapshotPage as u8;
+}
+
+impl OperatorSnapshotPage {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(operator: &Pubkey, ncn: &Pubkey, epoch: u64, page: u64, bump: u8) -> Self {
+        Self {
+            operator: *operator,
+            ncn: *ncn,
+            epoch: PodU64::from(epoch),
+            page: PodU64::from(page),
+            bump,
+            reserved: [0; 127],
+            vault_operator_stake_weight: [VaultOperatorStakeWeight::default(); VAULTS_PER_PAGE],
+        }
+    }
+
+    pub fn initialize(&mut self, operator: &Pubkey, ncn: &Pubkey, epoch: u64, page: u64, bump: u8) {
+        // Initializes field by field to avoid overflowing stack
+        self.operator = *operator;
+        self.ncn = *ncn;
+        self.epoch = PodU64::from(epoch);
+        self.page = PodU64::from(page);
+        self.bump = bump;
+        self.reserved = [0; 127];
+        self.vault_operator_stake_weight = [VaultOperatorStakeWeight::default(); VAULTS_PER_PAGE];
+    }
+
+    pub fn seeds(operator: &Pubkey, ncn: &Pubkey, epoch: u64, page: u64) -> Vec<Vec<u8>> {
+        vec![
+            b"operator_snapshot_page".to_vec(),
+            operator.to_bytes().to_vec(),
+            ncn.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+            page.to_le_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        page: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(operator, ncn, epoch, page);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        page: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, operator, ncn, epoch, page).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads every page of the operator snapshot, `accounts` has to hold the `page_count` pages
+    /// in order
+    pub fn load_all_pages(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        page_count: u64,
+    ) -> Result<(), ProgramError> {
+        if accounts.len() as u64 != page_count {
+            msg!(
+                "Expected {} operator snapshot pages, got {}",
+                page_count,
+                accounts.len()
+            );
+            return Err(TipRouterError::InvalidVaultPage.into());
+        }
+
+        for (page, account) in accounts.iter().enumerate() {
+            Self::load(
+                program_id,
+                account,
+                operator,
+                ncn,
+                epoch,
+                page as u64,
+                false,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    pub fn load_to_close(
+        program_id: &Pubkey,
+        account_to_close: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+    ) -> Result<(), ProgramError> {
+        let (operator, page) = {
+            let account_data = account_to_close.try_borrow_data()?;
+            let account_struct = Self::try_from_slice_unchecked(&account_data)?;
+            (*account_struct.operator(), account_struct.page())
+        };
+
+        Self::load(
+            program_id,
+            account_to_close,
+            &operator,
+            ncn,
+            epoch,
+            page,
+            true,
+        )
+    }
+
+    /// Loads the page holding the stake weight of the vault at `ncn_vault_index`. The page is
+    /// required for vaults past `MAX_VAULTS` and rejected for the others.
+    pub fn load_for_vault<'a, 'info>(
+        program_id: &Pubkey,
+        account: Option<&'a AccountInfo<'info>>,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        ncn_vault_index: u64,
+    ) -> Result<Option<&'a AccountInfo<'info>>, ProgramError> {
+        match (VaultRegistryPage::page_for_vault(ncn_vault_index), account) {
+            (None, None) => Ok(None),
+            (Some(page), Some(account)) => {
+                Self::load(program_id, account, operator, ncn, epoch, page, true)?;
+                Ok(Some(account))
+            }
+            (page, _) => {
+                msg!(
+                    "Vault {} is kept in operator snapshot page {:?}",
+                    ncn_vault_index,
+                    page
+                );
+                Err(TipRouterError::InvalidVaultPage.into())
+            }
+        }
+    }
+
+    pub const fn operator(&self) -> &Pubkey {
+        &self.operator
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn page(&self) -> u64 {
+        self.page.into()
+    }
+
+    pub const fn vault_operator_stake_weight(&self) -> &[VaultOperatorStakeWeight] {
+        &self.vault_operator_stake_weight
+    }
+
+    pub fn contains_vault(&self, vault: &Pubkey) -> bool {
+        self.vault_operator_stake_weight
+            .iter()
+            .any(|v| v.vault().eq(vault))
+    }
+
+    /// Inserts the stake weight at the position of the vault's NCN vault index
+    pub fn insert_vault_operator_stake_weight(
+        &mut self,
+        ncn_vault_index: u64,
+        vault: &Pubkey,
+        vault_index: u64,
+        ncn_fee_group: NcnFeeGroup,
+        stake_weights: &StakeWeights,
+    ) -> Result<(), TipRouterError> {
+        if VaultRegistryPage::page_for_vault(ncn_vault_index) != Some(self.page()) {
+            return Err(TipRouterError::InvalidVaultPage);
+        }
+
+        let position = VaultRegistryPage::position_for_vault(ncn_vault_index)
+            .ok_or(TipRouterError::InvalidVaultPage)?;
+        let vault_operator_stake_weight = &mut self.vault_operator_stake_weight[position];
+
+        if !vault_operator_stake_weight.is_empty() {
+            return Err(TipRouterError::DuplicateVaultOperatorDelegation);
+        }
+
+        *vault_operator_stake_weight =
+            VaultOperatorStakeWeight::new(vault, vault_index, ncn_fee_group, stake_weights);
+
+        Ok(())
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for OperatorSnapshotPage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Operator Snapshot Page -------------")?;
+        writeln!(f, "  Operator:                     {}", self.operator)?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Epoch:                        {}", self.epoch())?;
+        writeln!(f, "  Page:                         {}", self.page())?;
+
+        writeln!(f, "\nVault Operator Stake Weights:")?;
+        for (i, entry) in self.vault_operator_stake_weight.iter().enumerate() {
+            if !entry.is_empty() {
+                writeln!(f, "  Entry {}:", i)?;
+                writeln!(f, "    Vault:                      {}", entry.vault())?;
+                writeln!(f, "    Vault Index:                {}", entry.vault_index())?;
+                writeln!(f, "    NCN Fee Group:              {}", entry.ncn_fee_group().group)?;
+                writeln!(f, "    Stake Weight:               {}", entry.stake_weights().stake_weight())?;
+            }
+        }
+
+        writeln!(f, "\n")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{MAX_REALLOC_BYTES, MAX_VAULTS};
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // operator
+            + size_of::<Pubkey>() // ncn
+            + size_of::<PodU64>() // epoch
+            + size_of::<PodU64>() // page
+            + 1 // bump
+            + 127 // reserved
+            + size_of::<VaultOperatorStakeWeight>() * VAULTS_PER_PAGE; // vault_operator_stake_weight
+
+        assert_eq!(size_of::<OperatorSnapshotPage>(), expected_total);
+
+        // Pages are created in a single instruction
+        assert!(OperatorSnapshotPage::SIZE <= MAX_REALLOC_BYTES as usize);
+    }
+
+    #[test]
+    fn test_insert_vault_operator_stake_weight() {
+        let vault = Pubkey::new_unique();
+        let ncn_vault_index = MAX_VAULTS as u64 + VAULTS_PER_PAGE as u64 + 2;
+        let stake_weights = StakeWeights::snapshot(NcnFeeGroup::default(), 1_000, 10_000).unwrap();
+
+        // The vault belongs in page 1
+        let mut page =
+            OperatorSnapshotPage::new(&Pubkey::new_unique(), &Pubkey::new_unique(), 0, 0, 0);
+        assert_eq!(
+            page.insert_vault_operator_stake_weight(
+                ncn_vault_index,
+                &vault,
+                7,
+                NcnFeeGroup::default(),
+                &stake_weights,
+            ),
+            Err(TipRouterError::InvalidVaultPage)
+        );
+
+        let mut page =
+            OperatorSnapshotPage::new(&Pubkey::new_unique(), &Pubkey::new_unique(), 0, 1, 0);
+        page.insert_vault_operator_stake_weight(
+            ncn_vault_index,
+            &vault,
+            7,
+            NcnFeeGroup::default(),
+            &stake_weights,
+        )
+        .unwrap();
+        assert!(page.contains_vault(&vault));
+        assert_eq!(page.vault_operator_stake_weight()[2].vault_index(), 7);
+
+        assert_eq!(
+            page.insert_vault_operator_stake_weight(
+                ncn_vault_index,
+                &vault,
+                7,
+                NcnFeeGroup::default(),
+                &stake_weights,
+            ),
+            Err(TipRouterError::DuplicateVaultOperatorDelegation)
+        );
+    }
+}
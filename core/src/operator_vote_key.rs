@@ -0,0 +1,188 @@
+use core::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{discriminators::Discriminators, error::TipRouterError, loaders::check_load};
+
+/// A key registered by the operator admin that can sign votes for the operator,
+/// alongside the restaking `Operator`'s voter
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct OperatorVoteKey {
+    /// The operator
+    operator: Pubkey,
+    /// The NCN
+    ncn: Pubkey,
+    /// The key allowed to vote, the default pubkey when none is set
+    vote_key: Pubkey,
+    /// Slot the vote key was last set
+    slot_set: PodU64,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Reserved space
+    reserved: [u8; 127],
+}
+
+impl Discriminator for OperatorVoteKey {
+    const DISCRIMINATOR: u8 = Discriminators::OperatorVoteKey as u8;
+}
+
+impl OperatorVoteKey {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(operator: &Pubkey, ncn: &Pubkey, bump: u8) -> Self {
+        Self {
+            operator: *operator,
+            ncn: *ncn,
+            vote_key: Pubkey::default(),
+            slot_set: PodU64::from(0),
+            bump,
+            reserved: [0; 127],
+        }
+    }
+
+    pub fn seeds(operator: &Pubkey, ncn: &Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            b"operator_vote_key".to_vec(),
+            operator.to_bytes().to_vec(),
+            ncn.to_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(operator, ncn);
+        let (address, bump) = Pubkey::find_program_address(
+            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
+            program_id,
+        );
+        (address, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, operator, ncn).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    pub const fn operator(&self) -> &Pubkey {
+        &self.operator
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub const fn vote_key(&self) -> &Pubkey {
+        &self.vote_key
+    }
+
+    pub fn slot_set(&self) -> u64 {
+        self.slot_set.into()
+    }
+
+    pub fn has_vote_key(&self) -> bool {
+        self.vote_key.ne(&Pubkey::default())
+    }
+
+    /// Replaces the vote key, the default pubkey removes it
+    pub fn set_vote_key(&mut self, vote_key: &Pubkey, slot: u64) {
+        self.vote_key = *vote_key;
+        self.slot_set = PodU64::from(slot);
+    }
+
+    /// Checks that `voter` can vote for `operator`, either as the restaking `Operator`'s
+    /// voter or as the vote key registered in the optional `operator_vote_key` account
+    pub fn check_voter(
+        program_id: &Pubkey,
+        operator_vote_key: Option<&AccountInfo>,
+        operator: &Pubkey,
+        ncn: &Pubkey,
+        operator_voter: &Pubkey,
+        voter: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        if voter.eq(operator_voter) {
+            return Ok(());
+        }
+
+        if let Some(operator_vote_key) = operator_vote_key {
+            Self::load(program_id, operator_vote_key, operator, ncn, false)?;
+
+            let operator_vote_key_data = operator_vote_key.data.borrow();
+            let operator_vote_key = Self::try_from_slice_unchecked(&operator_vote_key_data)?;
+
+            if operator_vote_key.has_vote_key() && operator_vote_key.vote_key().eq(voter) {
+                return Ok(());
+            }
+        }
+
+        msg!("{} is not a voter for operator {}", voter, operator);
+        Err(TipRouterError::InvalidOperatorVoter.into())
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for OperatorVoteKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Operator Vote Key -------------")?;
+        writeln!(f, "  Operator:                     {}", self.operator)?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Vote Key:                     {}", self.vote_key)?;
+        writeln!(f, "  Slot Set:                     {}", self.slot_set())?;
+
+        writeln!(f, "\n")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // operator
+            + size_of::<Pubkey>() // ncn
+            + size_of::<Pubkey>() // vote_key
+            + size_of::<PodU64>() // slot_set
+            + 1 // bump
+            + 127; // reserved
+
+        assert_eq!(size_of::<OperatorVoteKey>(), expected_total);
+        assert_eq!(size_of::<OperatorVoteKey>() + 8, OperatorVoteKey::SIZE);
+    }
+
+    #[test]
+    fn test_set_vote_key() {
+        let mut operator_vote_key =
+            OperatorVoteKey::new(&Pubkey::new_unique(), &Pubkey::new_unique(), 255);
+        assert!(!operator_vote_key.has_vote_key());
+
+        let vote_key = Pubkey::new_unique();
+        operator_vote_key.set_vote_key(&vote_key, 100);
+        assert!(operator_vote_key.has_vote_key());
+        assert_eq!(*operator_vote_key.vote_key(), vote_key);
+        assert_eq!(operator_vote_key.slot_set(), 100);
+
+        operator_vote_key.set_vote_key(&Pubkey::default(), 200);
+        assert!(!operator_vote_key.has_vote_key());
+    }
+}
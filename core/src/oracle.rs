@@ -0,0 +1,248 @@
+//! Oracle types supported for setting weights
+//!
+//! Switchboard pull feeds are parsed with the switchboard-on-demand crate in the program, and SPL
+//! stake pools in `spl_stake_pool`. For Pyth, this module contains only the minimal code needed to
+//! read a price update account of the Pyth receiver program. This avoids depending on the anchor
+//! based pyth-solana-receiver-sdk crate.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{pubkey, pubkey::Pubkey};
+
+use crate::{constants::WEIGHT_PRECISION, error::TipRouterError};
+
+/// Which oracle publishes the price feed of a supported token ( ST ) mint
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum OracleType {
+    /// Switchboard on-demand pull feed
+    #[default]
+    Switchboard = 0x0,
+    /// Pyth receiver price update account
+    Pyth = 0x1,
+    /// SPL stake pool of an LST, weighted by its lamports per pool token
+    SplStakePool = 0x2,
+}
+
+impl TryFrom<u8> for OracleType {
+    type Error = TipRouterError;
+
+    fn try_from(oracle_type: u8) -> Result<Self, Self::Error> {
+        match oracle_type {
+            0x0 => Ok(Self::Switchboard),
+            0x1 => Ok(Self::Pyth),
+            0x2 => Ok(Self::SplStakePool),
+            _ => Err(TipRouterError::InvalidOracleType),
+        }
+    }
+}
+
+/// The Pyth receiver program, owner of all price update accounts
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Anchor discriminator of `PriceUpdateV2`, `sha256("account:PriceUpdateV2")[..8]`
+pub const PYTH_PRICE_UPDATE_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// How many Wormhole guardian signatures were checked when the price update was posted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum PythVerificationLevel {
+    Partial { num_signatures: u8 },
+    Full,
+}
+
+/// Mirror of the Pyth receiver's `PriceFeedMessage`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PythPriceFeedMessage {
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+    pub prev_publish_time: i64,
+    pub ema_price: i64,
+    pub ema_conf: u64,
+}
+
+/// Mirror of the Pyth receiver's `PriceUpdateV2` account, without the discriminator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PythPriceUpdate {
+    pub write_authority: Pubkey,
+    pub verification_level: PythVerificationLevel,
+    pub price_message: PythPriceFeedMessage,
+    pub posted_slot: u64,
+}
+
+impl PythPriceUpdate {
+    /// Parses the data of a price update account
+    pub fn parse(data: &[u8]) -> Result<Self, TipRouterError> {
+        let (discriminator, mut data) = data
+            .split_at_checked(8)
+            .ok_or(TipRouterError::BadPythPriceUpdate)?;
+
+        if discriminator.ne(PYTH_PRICE_UPDATE_DISCRIMINATOR.as_slice()) {
+            return Err(TipRouterError::BadPythPriceUpdate);
+        }
+
+        // Price update accounts can be larger than the struct, so trailing bytes are ignored
+        Self::deserialize(&mut data).map_err(|_| TipRouterError::BadPythPriceUpdate)
+    }
+
+    /// Serializes the account data, including the discriminator
+    pub fn to_account_data(&self) -> Vec<u8> {
+        let mut data = PYTH_PRICE_UPDATE_DISCRIMINATOR.to_vec();
+        // Serializing into a Vec can only fail on io errors
+        data.extend(borsh::to_vec(self).unwrap_or_default());
+        data
+    }
+
+    /// Checks the price update is fully verified, fresh and within the confidence limit,
+    /// then returns the price scaled by `WEIGHT_PRECISION`
+    pub fn weight(
+        &self,
+        unix_timestamp: i64,
+        max_stale_seconds: u64,
+        max_confidence_bps: u64,
+    ) -> Result<u128, TipRouterError> {
+        if self.verification_level.ne(&PythVerificationLevel::Full) {
+            return Err(TipRouterError::BadPythPriceUpdate);
+        }
+
+        let message = &self.price_message;
+
+        let age = unix_timestamp.saturating_sub(message.publish_time);
+        if age > max_stale_seconds.try_into().unwrap_or(i64::MAX) {
+            return Err(TipRouterError::StalePythPrice);
+        }
+
+        let price: u64 = message
+            .price
+            .try_into()
+            .map_err(|_| TipRouterError::BadPythPrice)?;
+        if price == 0 {
+            return Err(TipRouterError::BadPythPrice);
+        }
+
+        let confidence_bps = (message.conf as u128)
+            .checked_mul(10_000)
+            .and_then(|conf| conf.checked_div(price as u128))
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+        if confidence_bps > max_confidence_bps as u128 {
+            return Err(TipRouterError::PythConfidenceTooWide);
+        }
+
+        let scale = 10u128
+            .checked_pow(message.exponent.unsigned_abs())
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+        let precise_price = (price as u128)
+            .checked_mul(WEIGHT_PRECISION)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        let weight = if message.exponent < 0 {
+            precise_price.checked_div(scale)
+        } else {
+            precise_price.checked_mul(scale)
+        }
+        .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        if weight == 0 {
+            return Err(TipRouterError::BadPythPrice);
+        }
+
+        Ok(weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price_update(price: i64, conf: u64, exponent: i32, publish_time: i64) -> PythPriceUpdate {
+        PythPriceUpdate {
+            write_authority: Pubkey::new_unique(),
+            verification_level: PythVerificationLevel::Full,
+            price_message: PythPriceFeedMessage {
+                feed_id: [1; 32],
+                price,
+                conf,
+                exponent,
+                publish_time,
+                prev_publish_time: publish_time - 1,
+                ema_price: price,
+                ema_conf: conf,
+            },
+            posted_slot: 100,
+        }
+    }
+
+    #[test]
+    fn test_oracle_type_try_from() {
+        assert_eq!(OracleType::try_from(0).unwrap(), OracleType::Switchboard);
+        assert_eq!(OracleType::try_from(1).unwrap(), OracleType::Pyth);
+        assert_eq!(OracleType::try_from(2).unwrap(), OracleType::SplStakePool);
+        assert_eq!(
+            OracleType::try_from(3).unwrap_err(),
+            TipRouterError::InvalidOracleType
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let update = price_update(1_234_500_000, 1_000, -8, 1_000);
+
+        let mut data = update.to_account_data();
+        data.extend([0; 32]);
+        assert_eq!(PythPriceUpdate::parse(&data).unwrap(), update);
+
+        data[0] = 0;
+        assert_eq!(
+            PythPriceUpdate::parse(&data).unwrap_err(),
+            TipRouterError::BadPythPriceUpdate
+        );
+        assert_eq!(
+            PythPriceUpdate::parse(&[0; 4]).unwrap_err(),
+            TipRouterError::BadPythPriceUpdate
+        );
+    }
+
+    #[test]
+    fn test_weight() {
+        // 12.345 with 8 decimals
+        let update = price_update(1_234_500_000, 1_000, -8, 1_000);
+        assert_eq!(update.weight(1_010, 60, 100).unwrap(), 12_345_000_000);
+
+        // Positive exponent
+        let update = price_update(12, 0, 2, 1_000);
+        assert_eq!(
+            update.weight(1_000, 60, 100).unwrap(),
+            1_200 * WEIGHT_PRECISION
+        );
+
+        // Stale
+        let update = price_update(1_234_500_000, 1_000, -8, 1_000);
+        assert_eq!(
+            update.weight(1_061, 60, 100).unwrap_err(),
+            TipRouterError::StalePythPrice
+        );
+
+        // 2% confidence interval with a 1% limit
+        let update = price_update(100_000_000, 2_000_000, -8, 1_000);
+        assert_eq!(
+            update.weight(1_000, 60, 100).unwrap_err(),
+            TipRouterError::PythConfidenceTooWide
+        );
+
+        // Negative price
+        let update = price_update(-1, 0, -8, 1_000);
+        assert_eq!(
+            update.weight(1_000, 60, 100).unwrap_err(),
+            TipRouterError::BadPythPrice
+        );
+
+        // Partially verified
+        let mut update = price_update(1_234_500_000, 1_000, -8, 1_000);
+        update.verification_level = PythVerificationLevel::Partial { num_signatures: 5 };
+        assert_eq!(
+            update.weight(1_000, 60, 100).unwrap_err(),
+            TipRouterError::BadPythPriceUpdate
+        );
+    }
+}
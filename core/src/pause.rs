@@ -0,0 +1,72 @@
+use core::fmt;
+
+/// A subsystem the NCN admin or pause admin can halt in an emergency, each is a bit of
+/// `Config::paused_subsystems`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PausableSubsystem {
+    /// `CastVote`, `CommitVote`, `RevealVote`, `WithdrawVote` and `ResolveStalledVote`
+    Voting = 1 << 0,
+    /// `SetMerkleRoot`
+    MerkleRootUpload = 1 << 1,
+    /// `RouteBaseRewards`, `RouteNcnRewards` and `CarryForwardRewards`
+    Routing = 1 << 2,
+    /// The `Distribute*` instructions
+    Distribution = 1 << 3,
+    /// `ClaimWithPayer`
+    Claiming = 1 << 4,
+}
+
+impl PausableSubsystem {
+    /// Every subsystem flag
+    pub const ALL: u8 = 0b1_1111;
+
+    pub const fn all() -> [Self; 5] {
+        [
+            Self::Voting,
+            Self::MerkleRootUpload,
+            Self::Routing,
+            Self::Distribution,
+            Self::Claiming,
+        ]
+    }
+
+    pub const fn flag(self) -> u8 {
+        self as u8
+    }
+
+    pub const fn is_paused(self, paused_subsystems: u8) -> bool {
+        paused_subsystems & self.flag() != 0
+    }
+}
+
+impl fmt::Display for PausableSubsystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Voting => write!(f, "Voting"),
+            Self::MerkleRootUpload => write!(f, "Merkle Root Upload"),
+            Self::Routing => write!(f, "Routing"),
+            Self::Distribution => write!(f, "Distribution"),
+            Self::Claiming => write!(f, "Claiming"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pausable_subsystem_flags() {
+        let all = PausableSubsystem::all()
+            .iter()
+            .fold(0, |flags, subsystem| flags | subsystem.flag());
+        assert_eq!(all, PausableSubsystem::ALL);
+
+        let paused = PausableSubsystem::Routing.flag() | PausableSubsystem::Claiming.flag();
+        assert!(PausableSubsystem::Routing.is_paused(paused));
+        assert!(PausableSubsystem::Claiming.is_paused(paused));
+        assert!(!PausableSubsystem::Voting.is_paused(paused));
+        assert!(!PausableSubsystem::Distribution.is_paused(0));
+    }
+}
@@ -0,0 +1,262 @@
+use core::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{discriminators::Discriminators, error::TipRouterError, loaders::check_load};
+
+/// The asset NCN rewards are paid out in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PayoutAsset {
+    /// Deposited into the JitoSOL stake pool, paid to the recipient's JitoSOL ATA
+    #[default]
+    JitoSol = 0x0,
+    /// Native SOL, paid to the SOL destination of the payout preference. Only operators can be
+    /// paid in SOL, vaults are always paid to their own token accounts
+    Sol = 0x1,
+    /// Wrapped SOL, paid to the recipient's wSOL ATA
+    WrappedSol = 0x2,
+}
+
+impl TryFrom<u8> for PayoutAsset {
+    type Error = TipRouterError;
+
+    fn try_from(payout_asset: u8) -> Result<Self, Self::Error> {
+        match payout_asset {
+            0x0 => Ok(Self::JitoSol),
+            0x1 => Ok(Self::Sol),
+            0x2 => Ok(Self::WrappedSol),
+            _ => Err(TipRouterError::InvalidPayoutAsset),
+        }
+    }
+}
+
+impl fmt::Display for PayoutAsset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::JitoSol => write!(f, "JitoSOL"),
+            Self::Sol => write!(f, "SOL"),
+            Self::WrappedSol => write!(f, "wSOL"),
+        }
+    }
+}
+
+/// The asset an operator or vault wants its NCN rewards paid out in, set by its admin.
+/// Recipients without a payout preference are paid in JitoSOL.
+// PDA'd ["payout_preference", RECIPIENT, NCN]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct PayoutPreference {
+    /// The operator or vault receiving the rewards
+    recipient: Pubkey,
+    /// The NCN
+    ncn: Pubkey,
+    /// The `PayoutAsset` rewards are paid out in
+    payout_asset: u8,
+    /// Where native SOL rewards are sent, only used when paying out in SOL
+    sol_destination: Pubkey,
+    /// Slot the payout preference was last set
+    slot_set: PodU64,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Reserved space
+    reserved: [u8; 127],
+}
+
+impl Discriminator for PayoutPreference {
+    const DISCRIMINATOR: u8 = Discriminators::PayoutPreference as u8;
+}
+
+impl PayoutPreference {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(recipient: &Pubkey, ncn: &Pubkey, bump: u8) -> Self {
+        Self {
+            recipient: *recipient,
+            ncn: *ncn,
+            payout_asset: PayoutAsset::default() as u8,
+            sol_destination: Pubkey::default(),
+            slot_set: PodU64::from(0),
+            bump,
+            reserved: [0; 127],
+        }
+    }
+
+    pub fn seeds(recipient: &Pubkey, ncn: &Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            b"payout_preference".to_vec(),
+            recipient.to_bytes().to_vec(),
+            ncn.to_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        recipient: &Pubkey,
+        ncn: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(recipient, ncn);
+        let (address, bump) = Pubkey::find_program_address(
+            &seeds.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
+            program_id,
+        );
+        (address, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        recipient: &Pubkey,
+        ncn: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, recipient, ncn).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Reads the payout preference of a recipient. The PDA always has to be passed so a
+    /// preference can't be skipped, when it was never created the default preference is used.
+    pub fn load_or_default(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        recipient: &Pubkey,
+        ncn: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        if account.data_is_empty() {
+            let (expected_pda, bump, _) = Self::find_program_address(program_id, recipient, ncn);
+            if expected_pda.ne(account.key) {
+                msg!("Incorrect payout preference PDA");
+                return Err(ProgramError::InvalidSeeds);
+            }
+
+            return Ok(Self::new(recipient, ncn, bump));
+        }
+
+        Self::load(program_id, account, recipient, ncn, false)?;
+        let account_data = account.try_borrow_data()?;
+        Ok(*Self::try_from_slice_unchecked(&account_data)?)
+    }
+
+    pub const fn recipient(&self) -> &Pubkey {
+        &self.recipient
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn payout_asset(&self) -> Result<PayoutAsset, TipRouterError> {
+        PayoutAsset::try_from(self.payout_asset)
+    }
+
+    pub const fn sol_destination(&self) -> &Pubkey {
+        &self.sol_destination
+    }
+
+    pub fn slot_set(&self) -> u64 {
+        self.slot_set.into()
+    }
+
+    /// Sets the payout asset, paying out in SOL requires a SOL destination
+    pub fn set_payout_preference(
+        &mut self,
+        payout_asset: PayoutAsset,
+        sol_destination: &Pubkey,
+        slot: u64,
+    ) -> Result<(), TipRouterError> {
+        if payout_asset == PayoutAsset::Sol && sol_destination.eq(&Pubkey::default()) {
+            msg!("Paying out in SOL requires a SOL destination");
+            return Err(TipRouterError::InvalidPayoutDestination);
+        }
+
+        self.payout_asset = payout_asset as u8;
+        self.sol_destination = *sol_destination;
+        self.slot_set = PodU64::from(slot);
+
+        Ok(())
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for PayoutPreference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Payout Preference -------------")?;
+        writeln!(f, "  Recipient:                    {}", self.recipient)?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        match self.payout_asset() {
+            Ok(payout_asset) => writeln!(f, "  Payout Asset:                 {}", payout_asset)?,
+            Err(_) => writeln!(f, "  Payout Asset:                 Unknown ({})", self.payout_asset)?,
+        }
+        if self.sol_destination.ne(&Pubkey::default()) {
+            writeln!(f, "  SOL Destination:              {}", self.sol_destination)?;
+        }
+        writeln!(f, "  Slot Set:                     {}", self.slot_set())?;
+
+        writeln!(f, "\n")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // recipient
+            + size_of::<Pubkey>() // ncn
+            + 1 // payout_asset
+            + size_of::<Pubkey>() // sol_destination
+            + size_of::<PodU64>() // slot_set
+            + 1 // bump
+            + 127; // reserved
+
+        assert_eq!(size_of::<PayoutPreference>(), expected_total);
+    }
+
+    #[test]
+    fn test_set_payout_preference() {
+        let mut payout_preference =
+            PayoutPreference::new(&Pubkey::new_unique(), &Pubkey::new_unique(), 255);
+        assert_eq!(
+            payout_preference.payout_asset().unwrap(),
+            PayoutAsset::JitoSol
+        );
+
+        assert_eq!(
+            payout_preference.set_payout_preference(PayoutAsset::Sol, &Pubkey::default(), 1),
+            Err(TipRouterError::InvalidPayoutDestination)
+        );
+
+        let sol_destination = Pubkey::new_unique();
+        payout_preference
+            .set_payout_preference(PayoutAsset::Sol, &sol_destination, 2)
+            .unwrap();
+        assert_eq!(payout_preference.payout_asset().unwrap(), PayoutAsset::Sol);
+        assert_eq!(payout_preference.sol_destination(), &sol_destination);
+        assert_eq!(payout_preference.slot_set(), 2);
+
+        payout_preference
+            .set_payout_preference(PayoutAsset::WrappedSol, &Pubkey::default(), 3)
+            .unwrap();
+        assert_eq!(
+            payout_preference.payout_asset().unwrap(),
+            PayoutAsset::WrappedSol
+        );
+
+        assert_eq!(
+            PayoutAsset::try_from(3),
+            Err(TipRouterError::InvalidPayoutAsset)
+        );
+    }
+}
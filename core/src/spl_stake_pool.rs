@@ -1,11 +1,14 @@
 //! Minimal SPL Stake Pool utilities
 //!
 //! This module contains only the minimal code needed to interact with the SPL Stake Pool program,
-//! specifically for depositing SOL. This avoids depending on the full spl-stake-pool crate.
+//! specifically for depositing SOL and reading a pool's exchange rate. This avoids depending on
+//! the full spl-stake-pool crate.
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+use crate::{constants::WEIGHT_PRECISION, error::TipRouterError};
+
 /// Seed for withdraw authority seed
 const AUTHORITY_WITHDRAW: &[u8] = b"withdraw";
 
@@ -39,3 +42,146 @@ pub enum StakePoolInstruction {
         minimum_pool_tokens_out: u64,
     } = 25,
 }
+
+/// `AccountType::StakePool` of the SPL Stake Pool program
+const STAKE_POOL_ACCOUNT_TYPE: u8 = 1;
+
+/// Leading fields of an SPL Stake Pool account, up to the exchange rate.
+///
+/// The field order matches the real `StakePool` struct, the rest of the account is not read.
+/// See: <https://github.com/solana-program/stake-pool/blob/main/program/src/state.rs>
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct StakePoolHeader {
+    pub account_type: u8,
+    pub manager: Pubkey,
+    pub staker: Pubkey,
+    pub stake_deposit_authority: Pubkey,
+    pub stake_withdraw_bump_seed: u8,
+    pub validator_list: Pubkey,
+    pub reserve_stake: Pubkey,
+    pub pool_mint: Pubkey,
+    pub manager_fee_account: Pubkey,
+    pub token_program_id: Pubkey,
+    /// Total stake under management, updated once per epoch by the pool's update crank
+    pub total_lamports: u64,
+    /// Total supply of pool tokens
+    pub pool_token_supply: u64,
+    /// Last epoch `total_lamports` was updated
+    pub last_update_epoch: u64,
+}
+
+impl StakePoolHeader {
+    /// Parses the data of a stake pool account
+    pub fn parse(mut data: &[u8]) -> Result<Self, TipRouterError> {
+        let header = Self::deserialize(&mut data).map_err(|_| TipRouterError::BadStakePool)?;
+
+        if header.account_type != STAKE_POOL_ACCOUNT_TYPE {
+            return Err(TipRouterError::BadStakePool);
+        }
+
+        Ok(header)
+    }
+
+    /// Checks the pool issues `st_mint` and was updated at most `max_stale_epochs` ago,
+    /// then returns the lamports per pool token scaled by `WEIGHT_PRECISION`
+    pub fn weight(
+        &self,
+        st_mint: &Pubkey,
+        current_epoch: u64,
+        max_stale_epochs: u64,
+    ) -> Result<u128, TipRouterError> {
+        if self.pool_mint.ne(st_mint) {
+            return Err(TipRouterError::StakePoolMintMismatch);
+        }
+
+        let stale_epoch = self
+            .last_update_epoch
+            .checked_add(max_stale_epochs)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+        if current_epoch > stale_epoch {
+            return Err(TipRouterError::StaleStakePool);
+        }
+
+        if self.pool_token_supply == 0 || self.total_lamports == 0 {
+            return Err(TipRouterError::BadStakePool);
+        }
+
+        (self.total_lamports as u128)
+            .checked_mul(WEIGHT_PRECISION)
+            .and_then(|lamports| lamports.checked_div(self.pool_token_supply as u128))
+            .ok_or(TipRouterError::ArithmeticOverflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stake_pool(
+        pool_mint: &Pubkey,
+        total_lamports: u64,
+        pool_token_supply: u64,
+    ) -> StakePoolHeader {
+        StakePoolHeader {
+            account_type: STAKE_POOL_ACCOUNT_TYPE,
+            manager: Pubkey::new_unique(),
+            staker: Pubkey::new_unique(),
+            stake_deposit_authority: Pubkey::new_unique(),
+            stake_withdraw_bump_seed: 255,
+            validator_list: Pubkey::new_unique(),
+            reserve_stake: Pubkey::new_unique(),
+            pool_mint: *pool_mint,
+            manager_fee_account: Pubkey::new_unique(),
+            token_program_id: Pubkey::new_unique(),
+            total_lamports,
+            pool_token_supply,
+            last_update_epoch: 10,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let header = stake_pool(&Pubkey::new_unique(), 1_100, 1_000);
+
+        let mut data = borsh::to_vec(&header).unwrap();
+        data.extend([0; 64]);
+        assert_eq!(StakePoolHeader::parse(&data).unwrap(), header);
+
+        // Total lamports sits right after the five pubkeys following the bump seed
+        assert_eq!(data[258..266], 1_100u64.to_le_bytes());
+
+        data[0] = 2;
+        assert_eq!(
+            StakePoolHeader::parse(&data).unwrap_err(),
+            TipRouterError::BadStakePool
+        );
+        assert_eq!(
+            StakePoolHeader::parse(&[1; 8]).unwrap_err(),
+            TipRouterError::BadStakePool
+        );
+    }
+
+    #[test]
+    fn test_weight() {
+        let pool_mint = Pubkey::new_unique();
+        let header = stake_pool(&pool_mint, 1_180_000_000, 1_000_000_000);
+
+        assert_eq!(header.weight(&pool_mint, 10, 0).unwrap(), 1_180_000_000);
+        assert_eq!(header.weight(&pool_mint, 11, 1).unwrap(), 1_180_000_000);
+
+        assert_eq!(
+            header.weight(&pool_mint, 11, 0).unwrap_err(),
+            TipRouterError::StaleStakePool
+        );
+        assert_eq!(
+            header.weight(&Pubkey::new_unique(), 10, 0).unwrap_err(),
+            TipRouterError::StakePoolMintMismatch
+        );
+
+        let empty_pool = stake_pool(&pool_mint, 0, 0);
+        assert_eq!(
+            empty_pool.weight(&pool_mint, 10, 0).unwrap_err(),
+            TipRouterError::BadStakePool
+        );
+    }
+}
@@ -53,6 +53,14 @@ impl StakeWeights {
         self.stake_weight.into()
     }
 
+    /// Copy with a different voting stake weight, the NCN fee group reward weights are kept
+    pub fn with_stake_weight(&self, stake_weight: u128) -> Self {
+        Self {
+            stake_weight: PodU128::from(stake_weight),
+            ncn_fee_group_stake_weights: self.ncn_fee_group_stake_weights,
+        }
+    }
+
     pub fn ncn_fee_group_stake_weight(
         &self,
         ncn_fee_group: NcnFeeGroup,
@@ -1,20 +1,22 @@
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
-    types::{PodU128, PodU64},
+    types::{PodU128, PodU16, PodU64},
     AccountDeserialize, Discriminator,
 };
 use shank::{ShankAccount, ShankType};
 use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
-    constants::{MAX_ST_MINTS, MAX_VAULTS},
+    account_version::{check_account_version, VersionedAccount},
+    constants::{DEACTIVATED_ENTRY_RETENTION_EPOCHS, MAX_FEE_BPS, MAX_ST_MINTS, MAX_VAULTS},
     discriminators::Discriminators,
     error::TipRouterError,
     loaders::check_load,
     ncn_fee_group::NcnFeeGroup,
+    oracle::OracleType,
 };
 
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
@@ -27,12 +29,26 @@ pub struct StMintEntry {
     /// The reward multiplier in basis points
     reward_multiplier_bps: PodU64,
     // Either a switchboard feed or a no feed weight must be set
-    /// The switchboard feed for the mint
+    /// The price feed for the mint, read by the oracle of `oracle_type`
     switchboard_feed: Pubkey,
     /// The weight when no feed is available
     no_feed_weight: PodU128,
+    /// The `OracleType` of the price feed, defaults to Switchboard
+    oracle_type: u8,
+    /// Max age of the price feed, in slots for Switchboard, seconds for Pyth and epochs for SPL stake pools, 0 uses the default
+    max_staleness: PodU64,
+    /// Max standard deviation ( Switchboard ) or confidence ( Pyth ) relative to the price, 0 uses the default
+    max_confidence_bps: PodU16,
+    /// Max change of the oracle weight relative to the last epoch's weight, 0 disables the check
+    max_weight_change_bps: PodU16,
+    /// The last oracle weight set for the mint
+    last_weight: PodU128,
+    /// The epoch `last_weight` was set
+    last_weight_epoch: PodU64,
+    /// First epoch the mint is left out of weight tables, 0 while active
+    deactivation_epoch: PodU64,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 83],
 }
 
 impl StMintEntry {
@@ -49,7 +65,14 @@ impl StMintEntry {
             reward_multiplier_bps: PodU64::from(reward_multiplier_bps),
             switchboard_feed: *switchboard_feed,
             no_feed_weight: PodU128::from(no_feed_weight),
-            reserved: [0; 128],
+            oracle_type: OracleType::Switchboard as u8,
+            max_staleness: PodU64::from(0),
+            max_confidence_bps: PodU16::from(0),
+            max_weight_change_bps: PodU16::from(0),
+            last_weight: PodU128::from(0),
+            last_weight_epoch: PodU64::from(0),
+            deactivation_epoch: PodU64::from(0),
+            reserved: [0; 83],
         }
     }
 
@@ -73,6 +96,82 @@ impl StMintEntry {
         &self.switchboard_feed
     }
 
+    pub fn oracle_type(&self) -> Result<OracleType, TipRouterError> {
+        OracleType::try_from(self.oracle_type)
+    }
+
+    pub fn max_staleness(&self) -> u64 {
+        self.max_staleness.into()
+    }
+
+    pub fn max_confidence_bps(&self) -> u16 {
+        self.max_confidence_bps.into()
+    }
+
+    pub fn max_weight_change_bps(&self) -> u16 {
+        self.max_weight_change_bps.into()
+    }
+
+    pub fn last_weight(&self) -> u128 {
+        self.last_weight.into()
+    }
+
+    pub fn last_weight_epoch(&self) -> u64 {
+        self.last_weight_epoch.into()
+    }
+
+    pub fn deactivation_epoch(&self) -> u64 {
+        self.deactivation_epoch.into()
+    }
+
+    pub fn is_deactivated(&self) -> bool {
+        self.deactivation_epoch() != 0
+    }
+
+    /// Whether the mint is part of the weight table of `epoch`
+    pub fn is_active(&self, epoch: u64) -> bool {
+        !self.is_empty() && (!self.is_deactivated() || epoch < self.deactivation_epoch())
+    }
+
+    /// Max age of the price feed, or `default` when the mint doesn't set one
+    pub fn max_staleness_or(&self, default: u64) -> u64 {
+        match self.max_staleness() {
+            0 => default,
+            max_staleness => max_staleness,
+        }
+    }
+
+    /// Max standard deviation or confidence in bps, or `default` when the mint doesn't set one
+    pub fn max_confidence_bps_or(&self, default: u64) -> u64 {
+        match self.max_confidence_bps() {
+            0 => default,
+            max_confidence_bps => max_confidence_bps as u64,
+        }
+    }
+
+    /// Checks an oracle weight for `epoch` against the weight of an earlier epoch, so a bad
+    /// oracle print can't swing the stake weights. The admin can still override with `AdminSetWeight`.
+    pub fn check_weight_change(&self, weight: u128, epoch: u64) -> Result<(), TipRouterError> {
+        let max_weight_change_bps = self.max_weight_change_bps() as u128;
+        let last_weight = self.last_weight();
+
+        if max_weight_change_bps == 0 || last_weight == 0 || self.last_weight_epoch() >= epoch {
+            return Ok(());
+        }
+
+        let weight_change_bps = weight
+            .abs_diff(last_weight)
+            .checked_mul(MAX_FEE_BPS as u128)
+            .and_then(|change| change.checked_div(last_weight))
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+        if weight_change_bps > max_weight_change_bps {
+            return Err(TipRouterError::OracleWeightChangeTooLarge);
+        }
+
+        Ok(())
+    }
+
     pub fn is_empty(&self) -> bool {
         self.st_mint().eq(&Pubkey::default())
     }
@@ -101,8 +200,10 @@ pub struct VaultEntry {
     vault_index: PodU64,
     /// The slot the vault was registered
     slot_registered: PodU64,
+    /// First epoch the vault is left out of weight tables, 0 while active
+    deactivation_epoch: PodU64,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 120],
 }
 
 impl VaultEntry {
@@ -115,7 +216,8 @@ impl VaultEntry {
             st_mint: *st_mint,
             vault_index: PodU64::from(vault_index),
             slot_registered: PodU64::from(slot_registered),
-            reserved: [0; 128],
+            deactivation_epoch: PodU64::from(0),
+            reserved: [0; 120],
         }
     }
 
@@ -135,6 +237,23 @@ impl VaultEntry {
         self.slot_registered.into()
     }
 
+    pub fn deactivation_epoch(&self) -> u64 {
+        self.deactivation_epoch.into()
+    }
+
+    pub fn is_deactivated(&self) -> bool {
+        self.deactivation_epoch() != 0
+    }
+
+    pub(crate) fn set_deactivation_epoch(&mut self, epoch: u64) {
+        self.deactivation_epoch = PodU64::from(epoch);
+    }
+
+    /// Whether the vault is part of the weight table of `epoch`
+    pub fn is_active(&self, epoch: u64) -> bool {
+        !self.is_empty() && (!self.is_deactivated() || epoch < self.deactivation_epoch())
+    }
+
     pub fn is_empty(&self) -> bool {
         self.slot_registered() == u64::MAX
     }
@@ -158,8 +277,14 @@ pub struct VaultRegistry {
     pub ncn: Pubkey,
     /// The bump seed for the PDA
     pub bump: u8,
+    /// Number of deactivated vaults removed from `vault_list`
+    pub removed_vault_count: PodU64,
+    /// Number of vault registry pages created for vaults past `MAX_VAULTS`
+    pub page_count: PodU64,
+    /// Number of vaults registered in the vault registry pages
+    pub paged_vault_count: PodU64,
     /// Reserved space
-    pub reserved: [u8; 127],
+    pub reserved: [u8; 103],
     /// The list of supported token ( ST ) mints
     pub st_mint_list: [StMintEntry; 64],
     /// The list of vaults
@@ -170,6 +295,11 @@ impl Discriminator for VaultRegistry {
     const DISCRIMINATOR: u8 = Discriminators::VaultRegistry as u8;
 }
 
+impl VersionedAccount for VaultRegistry {
+    const ACCOUNT_VERSION: u8 = 1;
+    const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+}
+
 impl VaultRegistry {
     pub const SIZE: usize = 8 + size_of::<Self>();
 
@@ -177,7 +307,10 @@ impl VaultRegistry {
         Self {
             ncn: *ncn,
             bump,
-            reserved: [0; 127],
+            removed_vault_count: PodU64::from(0),
+            page_count: PodU64::from(0),
+            paged_vault_count: PodU64::from(0),
+            reserved: [0; 103],
             st_mint_list: [StMintEntry::default(); MAX_ST_MINTS],
             vault_list: [VaultEntry::default(); MAX_VAULTS],
         }
@@ -187,7 +320,10 @@ impl VaultRegistry {
         // Initializes field by field to avoid overflowing stack
         self.ncn = *ncn;
         self.bump = bump;
-        self.reserved = [0; 127];
+        self.removed_vault_count = PodU64::from(0);
+        self.page_count = PodU64::from(0);
+        self.paged_vault_count = PodU64::from(0);
+        self.reserved = [0; 103];
         self.st_mint_list = [StMintEntry::default(); MAX_ST_MINTS];
         self.vault_list = [VaultEntry::default(); MAX_VAULTS];
     }
@@ -220,13 +356,18 @@ impl VaultRegistry {
             &expected_pda,
             Some(Self::DISCRIMINATOR),
             expect_writable,
-        )
+        )?;
+        check_account_version::<Self>(account)
     }
 
     pub fn has_st_mint(&self, mint: &Pubkey) -> bool {
         self.st_mint_list.iter().any(|m| m.st_mint.eq(mint))
     }
 
+    pub fn has_vault(&self, vault: &Pubkey) -> bool {
+        self.vault_list.iter().any(|m| m.vault.eq(vault))
+    }
+
     pub fn check_st_mint_entry(entry: &StMintEntry) -> Result<(), ProgramError> {
         if entry.no_feed_weight() == 0 && entry.switchboard_feed().eq(&Pubkey::default()) {
             return Err(TipRouterError::NoFeedWeightOrSwitchboardFeed.into());
@@ -309,6 +450,79 @@ impl VaultRegistry {
         Ok(())
     }
 
+    pub fn set_st_mint_oracle_type(
+        &mut self,
+        st_mint: &Pubkey,
+        oracle_type: u8,
+    ) -> Result<(), ProgramError> {
+        let mint_entry = self
+            .st_mint_list
+            .iter_mut()
+            .find(|m| m.st_mint.eq(st_mint))
+            .ok_or(TipRouterError::MintEntryNotFound)?;
+
+        mint_entry.oracle_type = OracleType::try_from(oracle_type)? as u8;
+
+        Ok(())
+    }
+
+    pub fn set_st_mint_oracle_guards(
+        &mut self,
+        st_mint: &Pubkey,
+        max_staleness: Option<u64>,
+        max_confidence_bps: Option<u16>,
+        max_weight_change_bps: Option<u16>,
+    ) -> Result<(), ProgramError> {
+        let mint_entry = self
+            .st_mint_list
+            .iter_mut()
+            .find(|m| m.st_mint.eq(st_mint))
+            .ok_or(TipRouterError::MintEntryNotFound)?;
+
+        if let Some(max_staleness) = max_staleness {
+            mint_entry.max_staleness = PodU64::from(max_staleness);
+        }
+
+        if let Some(max_confidence_bps) = max_confidence_bps {
+            if max_confidence_bps as u64 > MAX_FEE_BPS {
+                return Err(TipRouterError::InvalidOracleGuard.into());
+            }
+            mint_entry.max_confidence_bps = PodU16::from(max_confidence_bps);
+        }
+
+        if let Some(max_weight_change_bps) = max_weight_change_bps {
+            if max_weight_change_bps as u64 > MAX_FEE_BPS {
+                return Err(TipRouterError::InvalidOracleGuard.into());
+            }
+            mint_entry.max_weight_change_bps = PodU16::from(max_weight_change_bps);
+        }
+
+        Ok(())
+    }
+
+    /// Records the oracle weight of an epoch, checked against by the next epochs' weight tables
+    pub fn set_st_mint_last_weight(
+        &mut self,
+        st_mint: &Pubkey,
+        weight: u128,
+        epoch: u64,
+    ) -> Result<(), ProgramError> {
+        let mint_entry = self
+            .st_mint_list
+            .iter_mut()
+            .find(|m| m.st_mint.eq(st_mint))
+            .ok_or(TipRouterError::MintEntryNotFound)?;
+
+        if epoch < mint_entry.last_weight_epoch() {
+            return Ok(());
+        }
+
+        mint_entry.last_weight = PodU128::from(weight);
+        mint_entry.last_weight_epoch = PodU64::from(epoch);
+
+        Ok(())
+    }
+
     pub fn register_vault(
         &mut self,
         vault: &Pubkey,
@@ -332,6 +546,104 @@ impl VaultRegistry {
         Ok(())
     }
 
+    /// Leaves the vault out of the weight tables of `epoch` onward. The epoch can't be in the
+    /// past, and can only be changed while the deactivation hasn't taken effect.
+    pub fn deactivate_vault(
+        &mut self,
+        vault: &Pubkey,
+        epoch: u64,
+        current_epoch: u64,
+    ) -> Result<(), ProgramError> {
+        let vault_entry = self
+            .vault_list
+            .iter_mut()
+            .find(|m| m.vault.eq(vault))
+            .ok_or(TipRouterError::VaultNotInRegistry)?;
+
+        Self::check_deactivation_epoch(vault_entry.deactivation_epoch(), epoch, current_epoch)?;
+
+        vault_entry.deactivation_epoch = PodU64::from(epoch);
+
+        Ok(())
+    }
+
+    /// Leaves the mint out of the weight tables of `epoch` onward. Every vault of the mint has to
+    /// be deactivated by then, otherwise their delegations could not be snapshotted.
+    pub fn deactivate_st_mint(
+        &mut self,
+        st_mint: &Pubkey,
+        epoch: u64,
+        current_epoch: u64,
+    ) -> Result<(), ProgramError> {
+        if self
+            .vault_list
+            .iter()
+            .any(|v| v.st_mint().eq(st_mint) && v.is_active(epoch))
+        {
+            return Err(TipRouterError::StMintHasActiveVaults.into());
+        }
+
+        let mint_entry = self
+            .st_mint_list
+            .iter_mut()
+            .find(|m| m.st_mint.eq(st_mint))
+            .ok_or(TipRouterError::MintEntryNotFound)?;
+
+        Self::check_deactivation_epoch(mint_entry.deactivation_epoch(), epoch, current_epoch)?;
+
+        mint_entry.deactivation_epoch = PodU64::from(epoch);
+
+        Ok(())
+    }
+
+    pub(crate) fn check_deactivation_epoch(
+        deactivation_epoch: u64,
+        epoch: u64,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
+        // Weight tables already created for earlier epochs keep their copy of the entry
+        if epoch < current_epoch || epoch == 0 {
+            return Err(TipRouterError::InvalidDeactivationEpoch);
+        }
+
+        if deactivation_epoch != 0 && deactivation_epoch <= current_epoch {
+            return Err(TipRouterError::InvalidDeactivationEpoch);
+        }
+
+        Ok(())
+    }
+
+    /// Frees the slots of entries deactivated more than `DEACTIVATED_ENTRY_RETENTION_EPOCHS`
+    /// ago, so they can be reused by new registrations. Until then, weight tables of epochs
+    /// before the deactivation can still be created from the registry. Vault registry pages are
+    /// left as is, their vaults are kept at fixed positions.
+    pub fn compact(&mut self, current_epoch: u64) -> Result<(), TipRouterError> {
+        let is_removable = |deactivation_epoch: u64| {
+            deactivation_epoch != 0
+                && deactivation_epoch.saturating_add(DEACTIVATED_ENTRY_RETENTION_EPOCHS)
+                    <= current_epoch
+        };
+
+        let mut removed_vault_count = self.removed_vault_count();
+        for vault_entry in self.vault_list.iter_mut() {
+            if !vault_entry.is_empty() && is_removable(vault_entry.deactivation_epoch()) {
+                *vault_entry = VaultEntry::default();
+                removed_vault_count = removed_vault_count
+                    .checked_add(1)
+                    .ok_or(TipRouterError::ArithmeticOverflow)?;
+            }
+        }
+        self.removed_vault_count = PodU64::from(removed_vault_count);
+
+        for mint_entry in self.st_mint_list.iter_mut() {
+            if !mint_entry.is_empty() && is_removable(mint_entry.deactivation_epoch()) {
+                *mint_entry = StMintEntry::default();
+            }
+        }
+
+        Ok(())
+    }
+
     pub const fn get_vault_entries(&self) -> &[VaultEntry; MAX_VAULTS] {
         &self.vault_list
     }
@@ -340,18 +652,80 @@ impl VaultRegistry {
         self.vault_list.iter().filter(|m| !m.is_empty()).count() as u64
     }
 
-    pub fn get_valid_vault_entries(&self) -> Vec<VaultEntry> {
+    pub fn removed_vault_count(&self) -> u64 {
+        self.removed_vault_count.into()
+    }
+
+    pub fn page_count(&self) -> u64 {
+        self.page_count.into()
+    }
+
+    pub fn paged_vault_count(&self) -> u64 {
+        self.paged_vault_count.into()
+    }
+
+    /// Records a new page, pages are created in order and are never closed
+    pub fn add_page(&mut self, page: u64) -> Result<(), TipRouterError> {
+        if page != self.page_count() {
+            return Err(TipRouterError::InvalidVaultPage);
+        }
+
+        self.page_count = PodU64::from(
+            page.checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Records a vault registered in one of the pages
+    pub fn increment_paged_vault_count(&mut self) -> Result<(), TipRouterError> {
+        self.paged_vault_count = PodU64::from(
+            self.paged_vault_count()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Vaults in the registry and its pages plus the ones removed, to compare against the NCN's
+    /// vault count
+    pub fn registered_vault_count(&self) -> Result<u64, TipRouterError> {
+        self.vault_count()
+            .checked_add(self.removed_vault_count())
+            .and_then(|count| count.checked_add(self.paged_vault_count()))
+            .ok_or(TipRouterError::ArithmeticOverflow)
+    }
+
+    /// Vaults in the weight table of `epoch`
+    pub fn active_vault_count(&self, epoch: u64) -> u64 {
+        self.vault_list
+            .iter()
+            .filter(|m| m.is_active(epoch))
+            .count() as u64
+    }
+
+    /// ST mints in the weight table of `epoch`
+    pub fn active_st_mint_count(&self, epoch: u64) -> usize {
+        self.st_mint_list
+            .iter()
+            .filter(|m| m.is_active(epoch))
+            .count()
+    }
+
+    pub fn get_valid_vault_entries(&self, epoch: u64) -> Vec<VaultEntry> {
         self.vault_list
             .iter()
-            .filter(|m| !m.is_empty())
+            .filter(|m| m.is_active(epoch))
             .copied()
             .collect()
     }
 
-    pub fn get_valid_mint_entries(&self) -> Vec<StMintEntry> {
+    pub fn get_valid_mint_entries(&self, epoch: u64) -> Vec<StMintEntry> {
         self.st_mint_list
             .iter()
-            .filter(|m| !m.is_empty())
+            .filter(|m| m.is_active(epoch))
             .copied()
             .collect()
     }
@@ -380,20 +754,30 @@ impl fmt::Display for VaultRegistry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "\n\n----------- Vault Registry -------------")?;
         writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Removed Vaults:               {}", self.removed_vault_count())?;
+        writeln!(f, "  Page Count:                   {}", self.page_count())?;
+        writeln!(f, "  Paged Vaults:                 {}", self.paged_vault_count())?;
         writeln!(f, "  ST Mints:                     ")?;
-        for mint in self.get_valid_mint_entries() {
+        for mint in self.st_mint_list.iter().filter(|m| !m.is_empty()) {
             writeln!(f, "    Mint:                       {}", mint.st_mint())?;
             writeln!(f, "      Fee Group:                {:?}", mint.ncn_fee_group())?;
             writeln!(f, "      Reward Multiplier:        {}", mint.reward_multiplier_bps())?;
+            writeln!(f, "      Oracle Type:              {:?}", mint.oracle_type())?;
             writeln!(f, "      Switchboard Feed:         {}", mint.switchboard_feed())?;
+            writeln!(f, "      Max Staleness:            {}", mint.max_staleness())?;
+            writeln!(f, "      Max Confidence BPS:       {}", mint.max_confidence_bps())?;
+            writeln!(f, "      Max Weight Change BPS:    {}", mint.max_weight_change_bps())?;
+            writeln!(f, "      Last Weight:              {} ( epoch {} )", mint.last_weight(), mint.last_weight_epoch())?;
+            writeln!(f, "      Deactivation Epoch:       {}", mint.deactivation_epoch())?;
             writeln!(f, "      No Feed Weight:           {}\n", mint.no_feed_weight())?;
         }
         writeln!(f, "  Vaults:                     ")?;
-        for vault in self.get_valid_vault_entries() {
+        for vault in self.vault_list.iter().filter(|m| !m.is_empty()) {
             writeln!(f, "    Vault:                      {}", vault.vault())?;
             writeln!(f, "      Mint:                     {}", vault.st_mint())?;
             writeln!(f, "      Index:                    {}", vault.vault_index())?;
-            writeln!(f, "      Slot Registered:          {}\n", vault.slot_registered())?;
+            writeln!(f, "      Slot Registered:          {}", vault.slot_registered())?;
+            writeln!(f, "      Deactivation Epoch:       {}\n", vault.deactivation_epoch())?;
         }
 
 
@@ -411,7 +795,10 @@ mod tests {
 
         let expected_total = size_of::<Pubkey>() // ncn
             + 1 // bump
-            + 127 // reserved
+            + size_of::<PodU64>() // removed_vault_count
+            + size_of::<PodU64>() // page_count
+            + size_of::<PodU64>() // paged_vault_count
+            + 103 // reserved
             + size_of::<StMintEntry>() * MAX_ST_MINTS // st_mint_list
             + size_of::<VaultEntry>() * MAX_VAULTS; // vault_list
 
@@ -428,24 +815,24 @@ mod tests {
         let switchboard_feed = Pubkey::new_unique();
 
         // Test 1: Initial registration should succeed
-        assert_eq!(vault_registry.get_valid_mint_entries().len(), 0);
+        assert_eq!(vault_registry.get_valid_mint_entries(0).len(), 0);
         vault_registry
             .register_st_mint(&mint, NcnFeeGroup::jto(), 1000, &switchboard_feed, 0)
             .unwrap();
-        assert_eq!(vault_registry.get_valid_mint_entries().len(), 1);
+        assert_eq!(vault_registry.get_valid_mint_entries(0).len(), 1);
 
         // Test 2: Trying to add the same mint should fail
         let result =
             vault_registry.register_st_mint(&mint, NcnFeeGroup::jto(), 1000, &switchboard_feed, 0);
         assert!(result.is_err());
-        assert_eq!(vault_registry.get_valid_mint_entries().len(), 1);
+        assert_eq!(vault_registry.get_valid_mint_entries(0).len(), 1);
 
         // Test 3: Adding a different mint should succeed
         let mint2 = Pubkey::new_unique();
         vault_registry
             .register_st_mint(&mint2, NcnFeeGroup::jto(), 1000, &switchboard_feed, 0)
             .unwrap();
-        assert_eq!(vault_registry.get_valid_mint_entries().len(), 2);
+        assert_eq!(vault_registry.get_valid_mint_entries(0).len(), 2);
 
         // Test 4: Verify mint entry data is stored correctly
         let entry = vault_registry.get_mint_entry(&mint).unwrap();
@@ -465,7 +852,7 @@ mod tests {
             0,
         );
         assert!(result.is_err());
-        assert_eq!(vault_registry.get_valid_mint_entries().len(), 2);
+        assert_eq!(vault_registry.get_valid_mint_entries(0).len(), 2);
 
         // Test 6: Fill up the mint list
         for _ in 2..MAX_ST_MINTS {
@@ -485,7 +872,7 @@ mod tests {
             0,
         );
         assert!(result.is_err());
-        assert_eq!(vault_registry.get_valid_mint_entries().len(), MAX_ST_MINTS);
+        assert_eq!(vault_registry.get_valid_mint_entries(0).len(), MAX_ST_MINTS);
 
         // Test 8: has_st_mint should work correctly
         assert!(vault_registry.has_st_mint(&mint));
@@ -623,6 +1010,100 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_set_st_mint_oracle_type() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let mint = Pubkey::new_unique();
+
+        vault_registry
+            .register_st_mint(&mint, NcnFeeGroup::lst(), 1000, &Pubkey::new_unique(), 0)
+            .unwrap();
+
+        // Registered entries default to switchboard
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.oracle_type().unwrap(), OracleType::Switchboard);
+
+        vault_registry
+            .set_st_mint_oracle_type(&mint, OracleType::Pyth as u8)
+            .unwrap();
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.oracle_type().unwrap(), OracleType::Pyth);
+
+        let result = vault_registry.set_st_mint_oracle_type(&mint, 3);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::from(TipRouterError::InvalidOracleType)
+        );
+
+        let result = vault_registry.set_st_mint_oracle_type(&Pubkey::new_unique(), 0);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::from(TipRouterError::MintEntryNotFound)
+        );
+    }
+
+    #[test]
+    fn test_oracle_guards() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+        let mint = Pubkey::new_unique();
+
+        vault_registry
+            .register_st_mint(&mint, NcnFeeGroup::lst(), 1000, &Pubkey::new_unique(), 0)
+            .unwrap();
+
+        // Unset guards fall back to the defaults and don't limit the weight change
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.max_staleness_or(100), 100);
+        assert_eq!(entry.max_confidence_bps_or(50), 50);
+        assert!(entry.check_weight_change(u128::MAX, 1).is_ok());
+
+        vault_registry
+            .set_st_mint_oracle_guards(&mint, Some(25), Some(200), Some(1_000))
+            .unwrap();
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.max_staleness_or(100), 25);
+        assert_eq!(entry.max_confidence_bps_or(50), 200);
+        assert_eq!(entry.max_weight_change_bps(), 1_000);
+
+        // No last weight yet
+        assert!(entry.check_weight_change(u128::MAX, 1).is_ok());
+
+        vault_registry
+            .set_st_mint_last_weight(&mint, 1_000, 1)
+            .unwrap();
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.last_weight(), 1_000);
+        assert_eq!(entry.last_weight_epoch(), 1);
+
+        // 10% change either way is allowed, more is not
+        assert!(entry.check_weight_change(1_100, 2).is_ok());
+        assert!(entry.check_weight_change(900, 2).is_ok());
+        assert_eq!(
+            entry.check_weight_change(1_101, 2).unwrap_err(),
+            TipRouterError::OracleWeightChangeTooLarge
+        );
+        assert_eq!(
+            entry.check_weight_change(899, 2).unwrap_err(),
+            TipRouterError::OracleWeightChangeTooLarge
+        );
+
+        // Weights of the same epoch are not checked against themselves
+        assert!(entry.check_weight_change(2_000, 1).is_ok());
+
+        // Older epochs don't overwrite the last weight
+        vault_registry
+            .set_st_mint_last_weight(&mint, 5_000, 0)
+            .unwrap();
+        let entry = vault_registry.get_mint_entry(&mint).unwrap();
+        assert_eq!(entry.last_weight(), 1_000);
+
+        let result = vault_registry.set_st_mint_oracle_guards(&mint, None, Some(10_001), None);
+        assert_eq!(
+            result.unwrap_err(),
+            ProgramError::from(TipRouterError::InvalidOracleGuard)
+        );
+    }
+
     #[test]
     fn test_mint_count() {
         let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
@@ -659,4 +1140,91 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_deactivate() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+
+        let mint = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        vault_registry
+            .register_st_mint(&mint, NcnFeeGroup::jto(), 0, &Pubkey::new_unique(), 0)
+            .unwrap();
+        vault_registry.register_vault(&vault, &mint, 0, 0).unwrap();
+
+        // The mint's vault is still active
+        assert_eq!(
+            vault_registry
+                .deactivate_st_mint(&mint, 12, 10)
+                .unwrap_err(),
+            ProgramError::from(TipRouterError::StMintHasActiveVaults)
+        );
+
+        // Can't deactivate in the past
+        assert_eq!(
+            vault_registry.deactivate_vault(&vault, 9, 10).unwrap_err(),
+            ProgramError::from(TipRouterError::InvalidDeactivationEpoch)
+        );
+
+        vault_registry.deactivate_vault(&vault, 12, 10).unwrap();
+        vault_registry.deactivate_st_mint(&mint, 12, 10).unwrap();
+
+        assert_eq!(vault_registry.get_valid_vault_entries(11).len(), 1);
+        assert_eq!(vault_registry.get_valid_mint_entries(11).len(), 1);
+        assert_eq!(vault_registry.active_vault_count(12), 0);
+        assert_eq!(vault_registry.active_st_mint_count(12), 0);
+        assert_eq!(vault_registry.get_valid_vault_entries(12).len(), 0);
+        assert_eq!(vault_registry.get_valid_mint_entries(12).len(), 0);
+
+        // Can be moved while not in effect
+        vault_registry.deactivate_vault(&vault, 11, 11).unwrap();
+        assert_eq!(vault_registry.active_vault_count(11), 0);
+
+        assert_eq!(
+            vault_registry.deactivate_vault(&vault, 20, 11).unwrap_err(),
+            ProgramError::from(TipRouterError::InvalidDeactivationEpoch)
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::default(), 0);
+
+        let mint = Pubkey::new_unique();
+        vault_registry
+            .register_st_mint(&mint, NcnFeeGroup::jto(), 0, &Pubkey::new_unique(), 0)
+            .unwrap();
+
+        let vaults: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        for (index, vault) in vaults.iter().enumerate() {
+            vault_registry
+                .register_vault(vault, &mint, index as u64, 0)
+                .unwrap();
+        }
+
+        vault_registry.deactivate_vault(&vaults[1], 10, 10).unwrap();
+
+        // Kept while weight tables of earlier epochs may still be created
+        vault_registry
+            .compact(10 + DEACTIVATED_ENTRY_RETENTION_EPOCHS - 1)
+            .unwrap();
+        assert_eq!(vault_registry.vault_count(), 3);
+        assert_eq!(vault_registry.removed_vault_count(), 0);
+
+        vault_registry
+            .compact(10 + DEACTIVATED_ENTRY_RETENTION_EPOCHS)
+            .unwrap();
+        assert_eq!(vault_registry.vault_count(), 2);
+        assert_eq!(vault_registry.removed_vault_count(), 1);
+        assert_eq!(vault_registry.registered_vault_count().unwrap(), 3);
+        assert!(!vault_registry.has_vault(&vaults[1]));
+
+        // The freed slot is reused
+        let new_vault = Pubkey::new_unique();
+        vault_registry
+            .register_vault(&new_vault, &mint, 3, 0)
+            .unwrap();
+        assert_eq!(vault_registry.get_vault_entries()[1].vault(), &new_vault);
+        assert_eq!(vault_registry.registered_vault_count().unwrap(), 4);
+    }
 }
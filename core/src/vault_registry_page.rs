@@ -0,0 +1,379 @@
+use core::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::{MAX_VAULTS, VAULTS_PER_PAGE},
+    discriminators::Discriminators,
+    error::TipRouterError,
+    loaders::check_load,
+    vault_registry::{VaultEntry, VaultRegistry},
+};
+
+/// Extension of the vault registry holding the vaults whose NCN vault index is past
+/// `MAX_VAULTS`. Page `n` holds the vaults from `MAX_VAULTS + n * VAULTS_PER_PAGE` onward, each
+/// at the position of its NCN vault index, so a vault is always in exactly one place.
+// PDA'd ["vault_registry_page", NCN, PAGE]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct VaultRegistryPage {
+    /// The NCN the page is associated with
+    ncn: Pubkey,
+    /// Index of the page within the vault registry
+    page: PodU64,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Reserved space
+    reserved: [u8; 127],
+    /// The list of vaults
+    vault_list: [VaultEntry; 32],
+}
+
+impl Discriminator for VaultRegistryPage {
+    const DISCRIMINATOR: u8 = Discriminators::VaultRegistryPage as u8;
+}
+
+impl VaultRegistryPage {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(ncn: &Pubkey, page: u64, bump: u8) -> Self {
+        Self {
+            ncn: *ncn,
+            page: PodU64::from(page),
+            bump,
+            reserved: [0; 127],
+            vault_list: [VaultEntry::default(); VAULTS_PER_PAGE],
+        }
+    }
+
+    pub fn initialize(&mut self, ncn: &Pubkey, page: u64, bump: u8) {
+        // Initializes field by field to avoid overflowing stack
+        self.ncn = *ncn;
+        self.page = PodU64::from(page);
+        self.bump = bump;
+        self.reserved = [0; 127];
+        self.vault_list = [VaultEntry::default(); VAULTS_PER_PAGE];
+    }
+
+    pub fn seeds(ncn: &Pubkey, page: u64) -> Vec<Vec<u8>> {
+        vec![
+            b"vault_registry_page".to_vec(),
+            ncn.to_bytes().to_vec(),
+            page.to_le_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        page: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, page);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        page: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ncn, page).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads any page of the vault registry, the page index is read from the account itself
+    pub fn load_any_page(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let page = {
+            let account_data = account.try_borrow_data()?;
+            Self::try_from_slice_unchecked(&account_data)?.page()
+        };
+
+        Self::load(program_id, account, ncn, page, expect_writable)
+    }
+
+    /// Loads every page of the vault registry, `accounts` has to hold the `page_count` pages in
+    /// order
+    pub fn load_all_pages(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        ncn: &Pubkey,
+        page_count: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if accounts.len() as u64 != page_count {
+            msg!(
+                "Expected {} vault registry pages, got {}",
+                page_count,
+                accounts.len()
+            );
+            return Err(TipRouterError::InvalidVaultPage.into());
+        }
+
+        for (page, account) in accounts.iter().enumerate() {
+            Self::load(program_id, account, ncn, page as u64, expect_writable)?;
+        }
+
+        Ok(())
+    }
+
+    /// Loads the page holding the vault at `ncn_vault_index`. The page is required for vaults
+    /// past `MAX_VAULTS` and rejected for the others.
+    pub fn load_for_vault<'a, 'info>(
+        program_id: &Pubkey,
+        account: Option<&'a AccountInfo<'info>>,
+        ncn: &Pubkey,
+        ncn_vault_index: u64,
+        expect_writable: bool,
+    ) -> Result<Option<&'a AccountInfo<'info>>, ProgramError> {
+        match (Self::page_for_vault(ncn_vault_index), account) {
+            (None, None) => Ok(None),
+            (Some(page), Some(account)) => {
+                Self::load(program_id, account, ncn, page, expect_writable)?;
+                Ok(Some(account))
+            }
+            (page, _) => {
+                msg!("Vault {} is kept in vault page {:?}", ncn_vault_index, page);
+                Err(TipRouterError::InvalidVaultPage.into())
+            }
+        }
+    }
+
+    /// The page holding a vault, `None` when it is kept in the header account
+    pub fn page_for_vault(ncn_vault_index: u64) -> Option<u64> {
+        ncn_vault_index
+            .checked_sub(MAX_VAULTS as u64)
+            .map(|index| index / VAULTS_PER_PAGE as u64)
+    }
+
+    /// Position of a vault within its page
+    pub fn position_for_vault(ncn_vault_index: u64) -> Option<usize> {
+        ncn_vault_index
+            .checked_sub(MAX_VAULTS as u64)
+            .map(|index| (index % VAULTS_PER_PAGE as u64) as usize)
+    }
+
+    /// Number of pages needed to hold `vault_count` vaults
+    pub fn pages_needed(vault_count: u64) -> u64 {
+        vault_count
+            .saturating_sub(MAX_VAULTS as u64)
+            .div_ceil(VAULTS_PER_PAGE as u64)
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn page(&self) -> u64 {
+        self.page.into()
+    }
+
+    pub const fn get_vault_entries(&self) -> &[VaultEntry; VAULTS_PER_PAGE] {
+        &self.vault_list
+    }
+
+    pub fn has_vault(&self, vault: &Pubkey) -> bool {
+        self.vault_list.iter().any(|v| v.vault().eq(vault))
+    }
+
+    pub fn vault_count(&self) -> u64 {
+        self.vault_list.iter().filter(|v| !v.is_empty()).count() as u64
+    }
+
+    /// Vaults in the weight table pages of `epoch`
+    pub fn active_vault_count(&self, epoch: u64) -> u64 {
+        self.vault_list
+            .iter()
+            .filter(|v| v.is_active(epoch))
+            .count() as u64
+    }
+
+    pub fn has_active_vaults(&self, st_mint: &Pubkey, epoch: u64) -> bool {
+        self.vault_list
+            .iter()
+            .any(|v| v.st_mint().eq(st_mint) && v.is_active(epoch))
+    }
+
+    /// Registers the vault at the position of its NCN vault index. Returns whether it was newly
+    /// registered, entries are never removed so a vault can't be registered twice.
+    pub fn register_vault(
+        &mut self,
+        ncn_vault_index: u64,
+        vault: &Pubkey,
+        st_mint: &Pubkey,
+        vault_index: u64,
+        current_slot: u64,
+    ) -> Result<bool, TipRouterError> {
+        if Self::page_for_vault(ncn_vault_index) != Some(self.page()) {
+            return Err(TipRouterError::InvalidVaultPage);
+        }
+
+        let position =
+            Self::position_for_vault(ncn_vault_index).ok_or(TipRouterError::InvalidVaultPage)?;
+        let vault_entry = &mut self.vault_list[position];
+
+        if !vault_entry.is_empty() {
+            if vault_entry.vault().ne(vault) {
+                return Err(TipRouterError::InvalidVaultPage);
+            }
+            return Ok(false);
+        }
+
+        *vault_entry = VaultEntry::new(vault, st_mint, vault_index, current_slot);
+        Ok(true)
+    }
+
+    pub fn deactivate_vault(
+        &mut self,
+        vault: &Pubkey,
+        epoch: u64,
+        current_epoch: u64,
+    ) -> Result<(), TipRouterError> {
+        let vault_entry = self
+            .vault_list
+            .iter_mut()
+            .find(|v| v.vault().eq(vault))
+            .ok_or(TipRouterError::VaultNotInRegistry)?;
+
+        VaultRegistry::check_deactivation_epoch(
+            vault_entry.deactivation_epoch(),
+            epoch,
+            current_epoch,
+        )?;
+
+        vault_entry.set_deactivation_epoch(epoch);
+
+        Ok(())
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for VaultRegistryPage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Vault Registry Page -------------")?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Page:                         {}", self.page())?;
+        writeln!(f, "  Vaults:                     ")?;
+        for vault in self.vault_list.iter().filter(|v| !v.is_empty()) {
+            writeln!(f, "    Vault:                      {}", vault.vault())?;
+            writeln!(f, "      Mint:                     {}", vault.st_mint())?;
+            writeln!(f, "      Index:                    {}", vault.vault_index())?;
+            writeln!(f, "      Slot Registered:          {}", vault.slot_registered())?;
+            writeln!(f, "      Deactivation Epoch:       {}\n", vault.deactivation_epoch())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MAX_REALLOC_BYTES;
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // ncn
+            + size_of::<PodU64>() // page
+            + 1 // bump
+            + 127 // reserved
+            + size_of::<VaultEntry>() * VAULTS_PER_PAGE; // vault_list
+
+        assert_eq!(size_of::<VaultRegistryPage>(), expected_total);
+
+        // Pages are created in a single instruction
+        assert!(VaultRegistryPage::SIZE <= MAX_REALLOC_BYTES as usize);
+    }
+
+    #[test]
+    fn test_page_for_vault() {
+        let per_page = VAULTS_PER_PAGE as u64;
+        let max_vaults = MAX_VAULTS as u64;
+
+        assert_eq!(VaultRegistryPage::page_for_vault(0), None);
+        assert_eq!(VaultRegistryPage::page_for_vault(max_vaults - 1), None);
+        assert_eq!(VaultRegistryPage::page_for_vault(max_vaults), Some(0));
+        assert_eq!(
+            VaultRegistryPage::page_for_vault(max_vaults + per_page - 1),
+            Some(0)
+        );
+        assert_eq!(
+            VaultRegistryPage::page_for_vault(max_vaults + per_page),
+            Some(1)
+        );
+
+        assert_eq!(VaultRegistryPage::position_for_vault(max_vaults - 1), None);
+        assert_eq!(VaultRegistryPage::position_for_vault(max_vaults), Some(0));
+        assert_eq!(
+            VaultRegistryPage::position_for_vault(max_vaults + per_page + 3),
+            Some(3)
+        );
+
+        assert_eq!(VaultRegistryPage::pages_needed(0), 0);
+        assert_eq!(VaultRegistryPage::pages_needed(max_vaults), 0);
+        assert_eq!(VaultRegistryPage::pages_needed(max_vaults + 1), 1);
+        assert_eq!(VaultRegistryPage::pages_needed(max_vaults + per_page), 1);
+        assert_eq!(
+            VaultRegistryPage::pages_needed(max_vaults + per_page + 1),
+            2
+        );
+    }
+
+    #[test]
+    fn test_register_vault() {
+        let ncn = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let st_mint = Pubkey::new_unique();
+        let ncn_vault_index = MAX_VAULTS as u64 + VAULTS_PER_PAGE as u64 + 5;
+
+        // The vault belongs in page 1
+        let mut page = VaultRegistryPage::new(&ncn, 0, 0);
+        assert_eq!(
+            page.register_vault(ncn_vault_index, &vault, &st_mint, 7, 100),
+            Err(TipRouterError::InvalidVaultPage)
+        );
+
+        let mut page = VaultRegistryPage::new(&ncn, 1, 0);
+        assert!(page
+            .register_vault(ncn_vault_index, &vault, &st_mint, 7, 100)
+            .unwrap());
+        assert_eq!(page.get_vault_entries()[5].vault(), &vault);
+        assert_eq!(page.vault_count(), 1);
+
+        // Registering again is a no-op
+        assert!(!page
+            .register_vault(ncn_vault_index, &vault, &st_mint, 7, 200)
+            .unwrap());
+        assert_eq!(page.get_vault_entries()[5].slot_registered(), 100);
+
+        // Another vault can't take its position
+        assert_eq!(
+            page.register_vault(ncn_vault_index, &Pubkey::new_unique(), &st_mint, 8, 200),
+            Err(TipRouterError::InvalidVaultPage)
+        );
+
+        assert!(page.has_active_vaults(&st_mint, 10));
+        page.deactivate_vault(&vault, 10, 5).unwrap();
+        assert!(!page.has_active_vaults(&st_mint, 10));
+        assert_eq!(page.active_vault_count(10), 0);
+        assert_eq!(page.active_vault_count(9), 1);
+    }
+}
@@ -4,7 +4,9 @@ use shank::ShankType;
 use solana_program::pubkey::Pubkey;
 use spl_math::precise_number::PreciseNumber;
 
-use crate::{error::TipRouterError, vault_registry::StMintEntry};
+use crate::{
+    constants::MAX_SLOTS_PER_WEIGHT_SAMPLE, error::TipRouterError, vault_registry::StMintEntry,
+};
 
 #[derive(Debug, Clone, Copy, Zeroable, ShankType, Pod)]
 #[repr(C)]
@@ -17,8 +19,20 @@ pub struct WeightEntry {
     slot_set: PodU64,
     /// The slot the weight was last updated
     slot_updated: PodU64,
+    /// Number of prices sampled during the weight sampling window
+    sample_count: PodU64,
+    /// Slot of the first sample
+    first_sample_slot: PodU64,
+    /// Slot of the latest sample
+    last_sample_slot: PodU64,
+    /// Weight of the latest sample
+    last_sample_weight: PodU128,
+    /// Sum of each sample's weight times the slots it counts for
+    weight_slot_sum: PodU128,
+    /// Sum of the slots each sample counts for
+    sampled_slots: PodU64,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 64],
 }
 
 impl Default for WeightEntry {
@@ -28,7 +42,13 @@ impl Default for WeightEntry {
             weight: PodU128::default(),
             slot_set: PodU64::default(),
             slot_updated: PodU64::default(),
-            reserved: [0; 128],
+            sample_count: PodU64::default(),
+            first_sample_slot: PodU64::default(),
+            last_sample_slot: PodU64::default(),
+            last_sample_weight: PodU128::default(),
+            weight_slot_sum: PodU128::default(),
+            sampled_slots: PodU64::default(),
+            reserved: [0; 64],
         }
     }
 }
@@ -40,7 +60,13 @@ impl WeightEntry {
             weight: PodU128::from(0),
             slot_set: PodU64::from(0),
             slot_updated: PodU64::from(0),
-            reserved: [0; 128],
+            sample_count: PodU64::from(0),
+            first_sample_slot: PodU64::from(0),
+            last_sample_slot: PodU64::from(0),
+            last_sample_weight: PodU128::from(0),
+            weight_slot_sum: PodU128::from(0),
+            sampled_slots: PodU64::from(0),
+            reserved: [0; 64],
         }
     }
 
@@ -87,6 +113,86 @@ impl WeightEntry {
 
         self.slot_updated = PodU64::from(current_slot);
     }
+
+    pub fn sample_count(&self) -> u64 {
+        self.sample_count.into()
+    }
+
+    pub fn first_sample_slot(&self) -> u64 {
+        self.first_sample_slot.into()
+    }
+
+    pub fn last_sample_slot(&self) -> u64 {
+        self.last_sample_slot.into()
+    }
+
+    pub fn last_sample_weight(&self) -> u128 {
+        self.last_sample_weight.into()
+    }
+
+    pub fn sampled_slots(&self) -> u64 {
+        self.sampled_slots.into()
+    }
+
+    /// Adds a price sample, each sample counts for the slots until the next one, capped at
+    /// `MAX_SLOTS_PER_WEIGHT_SAMPLE`
+    pub fn add_sample(&mut self, weight: u128, current_slot: u64) -> Result<(), TipRouterError> {
+        if self.sample_count() == 0 {
+            self.first_sample_slot = PodU64::from(current_slot);
+        } else {
+            let weight_slot_sum = self.weight_slot_sum_until(current_slot)?;
+            let sampled_slots = self.sampled_slots_until(current_slot)?;
+            self.weight_slot_sum = PodU128::from(weight_slot_sum);
+            self.sampled_slots = PodU64::from(sampled_slots);
+        }
+
+        self.sample_count = PodU64::from(
+            self.sample_count()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        self.last_sample_slot = PodU64::from(current_slot);
+        self.last_sample_weight = PodU128::from(weight);
+
+        Ok(())
+    }
+
+    /// Time weighted average of the samples from the first sample until `end_slot`
+    pub fn twap(&self, end_slot: u64) -> Result<u128, TipRouterError> {
+        if self.sample_count() == 0 {
+            return Err(TipRouterError::NoWeightSamples);
+        }
+
+        let slots = self.sampled_slots_until(end_slot)?;
+        if slots == 0 {
+            return Ok(self.last_sample_weight());
+        }
+
+        self.weight_slot_sum_until(end_slot)?
+            .checked_div(slots as u128)
+            .ok_or(TipRouterError::DenominatorIsZero)
+    }
+
+    /// Slots the latest sample counts for until `slot`
+    fn last_sample_slots_until(&self, slot: u64) -> u64 {
+        slot.saturating_sub(self.last_sample_slot())
+            .min(MAX_SLOTS_PER_WEIGHT_SAMPLE)
+    }
+
+    fn sampled_slots_until(&self, slot: u64) -> Result<u64, TipRouterError> {
+        self.sampled_slots()
+            .checked_add(self.last_sample_slots_until(slot))
+            .ok_or(TipRouterError::ArithmeticOverflow)
+    }
+
+    fn weight_slot_sum_until(&self, slot: u64) -> Result<u128, TipRouterError> {
+        let slots = self.last_sample_slots_until(slot);
+
+        self.last_sample_weight()
+            .checked_mul(slots as u128)
+            .and_then(|weight_slots| u128::from(self.weight_slot_sum).checked_add(weight_slots))
+            .ok_or(TipRouterError::ArithmeticOverflow)
+    }
 }
 
 #[cfg(test)]
@@ -130,4 +236,65 @@ mod tests {
         let result = weight_entry.precise_weight().unwrap();
         assert_eq!(result.to_imprecise().unwrap(), u128::MAX);
     }
+
+    #[test]
+    fn test_twap() {
+        let mint = Pubkey::new_unique();
+        let mint_entry =
+            StMintEntry::new(&mint, NcnFeeGroup::default(), 0, &Pubkey::new_unique(), 0);
+        let mut weight_entry = WeightEntry::new(&mint_entry);
+
+        assert_eq!(
+            weight_entry.twap(100).unwrap_err(),
+            TipRouterError::NoWeightSamples
+        );
+
+        // A single sample is its own average
+        weight_entry.add_sample(1_000, 100).unwrap();
+        assert_eq!(weight_entry.twap(100).unwrap(), 1_000);
+        assert_eq!(weight_entry.twap(200).unwrap(), 1_000);
+
+        // 1_000 for 5 slots, then a spike of 10_000 for 5 slots, then 1_100 for 10 slots
+        weight_entry.add_sample(10_000, 105).unwrap();
+        weight_entry.add_sample(1_100, 110).unwrap();
+        assert_eq!(weight_entry.sample_count(), 3);
+        assert_eq!(weight_entry.first_sample_slot(), 100);
+        assert_eq!(weight_entry.last_sample_slot(), 110);
+        assert_eq!(weight_entry.sampled_slots(), 10);
+        assert_eq!(weight_entry.twap(120).unwrap(), 3_300);
+
+        // The latest sample counts for at most `MAX_SLOTS_PER_WEIGHT_SAMPLE` slots
+        assert_eq!(weight_entry.twap(200).unwrap(), 3_300);
+
+        // Sampling does not set the weight
+        assert!(!weight_entry.is_set());
+        assert_eq!(weight_entry.weight(), 0);
+    }
+
+    #[test]
+    fn test_twap_outlier_first_sample() {
+        let mint = Pubkey::new_unique();
+        let mint_entry =
+            StMintEntry::new(&mint, NcnFeeGroup::default(), 0, &Pubkey::new_unique(), 0);
+        let mut weight_entry = WeightEntry::new(&mint_entry);
+
+        // An outlier is sampled first and nothing else is sampled for 300 slots
+        weight_entry.add_sample(1_000_000, 100).unwrap();
+        assert_eq!(weight_entry.sampled_slots(), 0);
+
+        weight_entry.add_sample(1_000, 400).unwrap();
+        assert_eq!(weight_entry.sampled_slots(), MAX_SLOTS_PER_WEIGHT_SAMPLE);
+
+        // Only `MAX_SLOTS_PER_WEIGHT_SAMPLE` slots of the outlier count
+        assert_eq!(weight_entry.twap(410).unwrap(), 500_500);
+
+        // Regular samples after it drown it out
+        for slot in (410..600).step_by(10) {
+            weight_entry.add_sample(1_000, slot).unwrap();
+        }
+        assert_eq!(weight_entry.twap(600).unwrap(), 48_571);
+
+        let uncapped_twap = (1_000_000 * 300 + 1_000 * 200) / 500;
+        assert!(weight_entry.twap(600).unwrap() < uncapped_twap / 10);
+    }
 }
@@ -1,5 +1,5 @@
 use core::fmt;
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
@@ -8,6 +8,7 @@ use solana_program::{account_info::AccountInfo, program_error::ProgramError, pub
 use spl_math::precise_number::PreciseNumber;
 
 use crate::{
+    account_version::{check_account_version, VersionedAccount},
     constants::{MAX_ST_MINTS, MAX_VAULTS},
     discriminators::Discriminators,
     error::TipRouterError,
@@ -25,12 +26,21 @@ pub struct WeightTable {
     epoch: PodU64,
     /// Slot weight table was created
     slot_created: PodU64,
-    /// Number of vaults in tracked mints at the time of creation
+    /// Number of vaults in tracked mints at the time of creation, paged vaults are counted
+    /// separately
     vault_count: PodU64,
     /// Bump seed for the PDA
     bump: u8,
+    /// Slot the weight sampling window closes, 0 when weights are set from a single price
+    sampling_end_slot: PodU64,
+    /// Number of vault registry pages at the time of creation
+    page_count: PodU64,
+    /// Number of weight table pages initialized
+    pages_initialized: PodU64,
+    /// Number of vaults in the weight table pages
+    paged_vault_count: PodU64,
     /// Reserved space
-    reserved: [u8; 128],
+    reserved: [u8; 96],
     /// A snapshot of the Vault Registry
     vault_registry: [VaultEntry; 64],
     /// The weight table
@@ -41,6 +51,11 @@ impl Discriminator for WeightTable {
     const DISCRIMINATOR: u8 = Discriminators::WeightTable as u8;
 }
 
+impl VersionedAccount for WeightTable {
+    const ACCOUNT_VERSION: u8 = 1;
+    const NCN_OFFSET: usize = 8 + offset_of!(Self, ncn);
+}
+
 impl WeightTable {
     pub const SIZE: usize = 8 + size_of::<Self>();
 
@@ -51,7 +66,11 @@ impl WeightTable {
             slot_created: PodU64::from(slot_created),
             vault_count: PodU64::from(vault_count),
             bump,
-            reserved: [0; 128],
+            sampling_end_slot: PodU64::from(0),
+            page_count: PodU64::from(0),
+            pages_initialized: PodU64::from(0),
+            paged_vault_count: PodU64::from(0),
+            reserved: [0; 96],
             vault_registry: [VaultEntry::default(); MAX_VAULTS],
             table: [WeightEntry::default(); MAX_ST_MINTS],
         }
@@ -87,6 +106,7 @@ impl WeightTable {
         ncn_epoch: u64,
         slot_created: u64,
         vault_count: u64,
+        page_count: u64,
         bump: u8,
         vault_entries: &[VaultEntry; MAX_VAULTS],
         mint_entries: &[StMintEntry; MAX_ST_MINTS],
@@ -97,7 +117,11 @@ impl WeightTable {
         self.slot_created = PodU64::from(slot_created);
         self.vault_count = PodU64::from(vault_count);
         self.bump = bump;
-        self.reserved = [0; 128];
+        self.sampling_end_slot = PodU64::from(0);
+        self.page_count = PodU64::from(page_count);
+        self.pages_initialized = PodU64::from(0);
+        self.paged_vault_count = PodU64::from(0);
+        self.reserved = [0; 96];
         self.vault_registry = [VaultEntry::default(); MAX_VAULTS];
         self.table = [WeightEntry::default(); MAX_ST_MINTS];
         self.set_vault_entries(vault_entries)?;
@@ -109,16 +133,53 @@ impl WeightTable {
         &mut self,
         vault_entries: &[VaultEntry; MAX_VAULTS],
     ) -> Result<(), TipRouterError> {
-        if self.vault_registry_initialized() {
+        if self.header_registry_initialized() {
             return Err(TipRouterError::WeightTableAlreadyInitialized);
         }
 
-        // Copy the entire slice into vault_registry
+        // Copy the vaults active this epoch, deactivated ones are left out
+        let epoch = self.epoch();
         for (i, entry) in vault_entries.iter().enumerate() {
-            self.vault_registry[i] = *entry;
+            if entry.is_active(epoch) {
+                self.vault_registry[i] = *entry;
+            }
+        }
+
+        if !self.header_registry_initialized() {
+            return Err(TipRouterError::RegistryNotInitialized);
+        }
+
+        Ok(())
+    }
+
+    /// Records the next weight table page, pages are initialized in order
+    pub fn add_page(&mut self, page: u64, vault_count: u64) -> Result<(), TipRouterError> {
+        if page != self.pages_initialized() || page >= self.page_count() {
+            return Err(TipRouterError::InvalidVaultPage);
+        }
+
+        self.pages_initialized = PodU64::from(
+            self.pages_initialized()
+                .checked_add(1)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+        self.paged_vault_count = PodU64::from(
+            self.paged_vault_count()
+                .checked_add(vault_count)
+                .ok_or(TipRouterError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Records a closed page, pages are closed from the last one and the weight table can only
+    /// be closed once all of them are
+    pub fn remove_page(&mut self, page: u64) -> Result<(), TipRouterError> {
+        if page.checked_add(1) != Some(self.pages_initialized()) {
+            return Err(TipRouterError::InvalidVaultPage);
         }
 
-        self.check_registry_initialized()?;
+        self.pages_initialized = PodU64::from(page);
 
         Ok(())
     }
@@ -131,9 +192,12 @@ impl WeightTable {
             return Err(TipRouterError::WeightTableAlreadyInitialized);
         }
 
-        // Set table using iterator
+        // Set table using iterator, deactivated mints are left out
+        let epoch = self.epoch();
         for (i, entry) in mint_entries.iter().enumerate() {
-            self.table[i] = WeightEntry::new(entry)
+            if entry.is_active(epoch) {
+                self.table[i] = WeightEntry::new(entry)
+            }
         }
 
         self.check_table_initialized()?;
@@ -141,6 +205,64 @@ impl WeightTable {
         Ok(())
     }
 
+    /// Opens the weight sampling window at the slot the weight table was created.
+    /// A `window_slots` of 0 sets weights from a single price.
+    pub fn set_sampling_window(&mut self, window_slots: u64) -> Result<(), TipRouterError> {
+        if window_slots == 0 {
+            return Ok(());
+        }
+
+        let sampling_end_slot = self
+            .slot_created()
+            .checked_add(window_slots)
+            .ok_or(TipRouterError::ArithmeticOverflow)?;
+        self.sampling_end_slot = PodU64::from(sampling_end_slot);
+
+        Ok(())
+    }
+
+    pub fn sampling_end_slot(&self) -> u64 {
+        self.sampling_end_slot.into()
+    }
+
+    pub fn is_sampling_enabled(&self) -> bool {
+        self.sampling_end_slot() > 0
+    }
+
+    /// Sets the weight from an oracle price. While the sampling window is open the price is only
+    /// sampled, the first price after the window sets the weight to the average of the samples.
+    pub fn record_oracle_weight(
+        &mut self,
+        mint: &Pubkey,
+        weight: u128,
+        current_slot: u64,
+    ) -> Result<(), TipRouterError> {
+        let sampling_end_slot = self.sampling_end_slot();
+        if !self.is_sampling_enabled() {
+            return self.set_weight(mint, weight, current_slot);
+        }
+
+        let entry = self
+            .table
+            .iter_mut()
+            .find(|entry| entry.st_mint().eq(mint))
+            .ok_or(TipRouterError::InvalidMintForWeightTable)?;
+
+        if current_slot < sampling_end_slot {
+            return entry.add_sample(weight, current_slot);
+        }
+
+        // Nothing was sampled during the window, fall back to the current price
+        let weight = if entry.sample_count() == 0 {
+            weight
+        } else {
+            entry.twap(sampling_end_slot)?
+        };
+        entry.set_weight(weight, current_slot);
+
+        Ok(())
+    }
+
     pub fn set_weight(
         &mut self,
         mint: &Pubkey,
@@ -217,8 +339,21 @@ impl WeightTable {
         self.slot_created.into()
     }
 
+    /// Number of vaults in the weight table and its pages
     pub fn vault_count(&self) -> u64 {
-        self.vault_count.into()
+        u64::from(self.vault_count).saturating_add(self.paged_vault_count())
+    }
+
+    pub fn page_count(&self) -> u64 {
+        self.page_count.into()
+    }
+
+    pub fn pages_initialized(&self) -> u64 {
+        self.pages_initialized.into()
+    }
+
+    pub fn paged_vault_count(&self) -> u64 {
+        self.paged_vault_count.into()
     }
 
     pub fn vault_entry_count(&self) -> usize {
@@ -228,8 +363,12 @@ impl WeightTable {
             .count()
     }
 
+    fn header_registry_initialized(&self) -> bool {
+        u64::from(self.vault_count) == self.vault_entry_count() as u64
+    }
+
     pub fn vault_registry_initialized(&self) -> bool {
-        self.vault_count() == self.vault_entry_count() as u64
+        self.header_registry_initialized() && self.pages_initialized() == self.page_count()
     }
 
     pub fn table_initialized(&self) -> bool {
@@ -285,7 +424,8 @@ impl WeightTable {
             &expected_pda,
             Some(Self::DISCRIMINATOR),
             expect_writable,
-        )
+        )?;
+        check_account_version::<Self>(account)
     }
 
     pub fn load_to_close(
@@ -307,6 +447,9 @@ impl fmt::Display for WeightTable {
        writeln!(f, "  Bump:                         {}", self.bump)?;
        writeln!(f, "  Slot Created:                 {}", self.slot_created())?;
        writeln!(f, "  Vault Count:                  {}", self.vault_count())?;
+       writeln!(f, "  Sampling End Slot:            {}", self.sampling_end_slot())?;
+       writeln!(f, "  Page Count:                   {}", self.page_count())?;
+       writeln!(f, "  Pages Initialized:            {}", self.pages_initialized())?;
        writeln!(f, "  Registry Initialized:         {}", self.vault_registry_initialized())?;
        writeln!(f, "  Table Initialized:            {}", self.table_initialized())?;
        writeln!(f, "  Finalized:                    {}", self.finalized())?;
@@ -330,6 +473,8 @@ impl fmt::Display for WeightTable {
                writeln!(f, "    Weight:                     {}", entry.weight())?;
                writeln!(f, "    Slot Set:                   {}", entry.slot_set())?;
                writeln!(f, "    Slot Updated:               {}", entry.slot_updated())?;
+               writeln!(f, "    Sample Count:               {}", entry.sample_count())?;
+               writeln!(f, "    Sampled Slots:              {}", entry.sampled_slots())?;
            }
        }
 
@@ -345,7 +490,7 @@ mod tests {
     use solana_program::pubkey::Pubkey;
 
     use super::*;
-    use crate::ncn_fee_group::NcnFeeGroup;
+    use crate::{ncn_fee_group::NcnFeeGroup, vault_registry::VaultRegistry};
 
     fn get_test_mint_entries(count: usize) -> [StMintEntry; 64] {
         let mut mints = [StMintEntry::default(); MAX_ST_MINTS];
@@ -370,7 +515,11 @@ mod tests {
             + size_of::<PodU64>() // slot_created
             + size_of::<PodU64>() // vault_count
             + 1 // bump
-            + 128 // reserved
+            + size_of::<PodU64>() // sampling_end_slot
+            + size_of::<PodU64>() // page_count
+            + size_of::<PodU64>() // pages_initialized
+            + size_of::<PodU64>() // paged_vault_count
+            + 96 // reserved
             + size_of::<[VaultEntry; MAX_VAULTS]>() // vault registry
             + size_of::<[WeightEntry; MAX_ST_MINTS]>(); // weight table
 
@@ -434,6 +583,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_initialize_skips_deactivated_entries() {
+        let mut vault_registry = VaultRegistry::new(&Pubkey::new_unique(), 0);
+
+        let mints = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let vaults = [Pubkey::new_unique(), Pubkey::new_unique()];
+        for (index, (mint, vault)) in mints.iter().zip(vaults.iter()).enumerate() {
+            vault_registry
+                .register_st_mint(mint, NcnFeeGroup::default(), 0, &Pubkey::new_unique(), 0)
+                .unwrap();
+            vault_registry
+                .register_vault(vault, mint, index as u64, 0)
+                .unwrap();
+        }
+
+        vault_registry.deactivate_vault(&vaults[1], 5, 5).unwrap();
+        vault_registry.deactivate_st_mint(&mints[1], 5, 5).unwrap();
+
+        // Earlier epochs still include the deactivated entries
+        let mut table = WeightTable::new(&Pubkey::new_unique(), 4, 0, 0, 0);
+        table
+            .initialize(
+                &Pubkey::new_unique(),
+                4,
+                0,
+                vault_registry.active_vault_count(4),
+                0,
+                0,
+                vault_registry.get_vault_entries(),
+                vault_registry.get_mint_entries(),
+            )
+            .unwrap();
+        assert_eq!(table.vault_count(), 2);
+        assert_eq!(table.mint_count(), 2);
+
+        let mut table = WeightTable::new(&Pubkey::new_unique(), 5, 0, 0, 0);
+        table
+            .initialize(
+                &Pubkey::new_unique(),
+                5,
+                0,
+                vault_registry.active_vault_count(5),
+                0,
+                0,
+                vault_registry.get_vault_entries(),
+                vault_registry.get_mint_entries(),
+            )
+            .unwrap();
+        assert_eq!(table.vault_count(), 1);
+        assert_eq!(table.vault_entry_count(), 1);
+        assert_eq!(table.get_mints(), vec![mints[0]]);
+        assert_eq!(
+            table.check_registry_for_vault(1),
+            Err(TipRouterError::VaultNotInRegistry)
+        );
+    }
+
+    #[test]
+    fn test_add_page() {
+        let ncn = Pubkey::new_unique();
+        let mut table = WeightTable::new(&ncn, 0, 0, 0, 0);
+        table
+            .initialize(
+                &ncn,
+                0,
+                0,
+                0,
+                2,
+                0,
+                &[VaultEntry::default(); MAX_VAULTS],
+                &get_test_mint_entries(1),
+            )
+            .unwrap();
+
+        // The registry isn't initialized until every page is
+        assert!(!table.vault_registry_initialized());
+
+        assert_eq!(table.add_page(1, 3), Err(TipRouterError::InvalidVaultPage));
+        table.add_page(0, 3).unwrap();
+        assert!(!table.vault_registry_initialized());
+        assert_eq!(table.add_page(0, 3), Err(TipRouterError::InvalidVaultPage));
+
+        table.add_page(1, 2).unwrap();
+        assert!(table.vault_registry_initialized());
+        assert_eq!(table.vault_count(), 5);
+        assert_eq!(table.add_page(2, 1), Err(TipRouterError::InvalidVaultPage));
+
+        // Pages are closed from the last one
+        assert_eq!(table.remove_page(0), Err(TipRouterError::InvalidVaultPage));
+        table.remove_page(1).unwrap();
+        table.remove_page(0).unwrap();
+        assert_eq!(table.pages_initialized(), 0);
+    }
+
     #[test]
     fn test_initialize_table_success() {
         let ncn = Pubkey::new_unique();
@@ -545,4 +788,78 @@ mod tests {
         table.set_weight(mint.st_mint(), 200, 5).unwrap();
         assert_eq!(table.get_weight(mint.st_mint()).unwrap(), 200);
     }
+
+    #[test]
+    fn test_record_oracle_weight_without_sampling() {
+        let ncn = Pubkey::new_unique();
+        let mut table = WeightTable::new(&ncn, 0, 100, 0, 0);
+        let mints = get_test_mint_entries(1);
+        let mint = mints[0];
+
+        table.set_mint_entries(&mints).unwrap();
+        table.set_sampling_window(0).unwrap();
+        assert!(!table.is_sampling_enabled());
+
+        table
+            .record_oracle_weight(mint.st_mint(), 100, 101)
+            .unwrap();
+        assert_eq!(table.get_weight(mint.st_mint()).unwrap(), 100);
+        assert!(table.finalized());
+    }
+
+    #[test]
+    fn test_record_oracle_weight_with_sampling() {
+        let ncn = Pubkey::new_unique();
+        let mut table = WeightTable::new(&ncn, 0, 100, 0, 0);
+        let mints = get_test_mint_entries(2);
+        let (mint1, mint2) = (mints[0], mints[1]);
+
+        table.set_mint_entries(&mints).unwrap();
+        table.set_sampling_window(100).unwrap();
+        assert_eq!(table.sampling_end_slot(), 200);
+
+        // Samples during the window don't set the weight
+        table
+            .record_oracle_weight(mint1.st_mint(), 1_000, 100)
+            .unwrap();
+        table
+            .record_oracle_weight(mint1.st_mint(), 2_000, 150)
+            .unwrap();
+        table
+            .record_oracle_weight(mint2.st_mint(), 500, 120)
+            .unwrap();
+        assert_eq!(table.weight_count(), 0);
+        assert!(!table.finalized());
+
+        // The first price after the window sets the average of the window
+        table
+            .record_oracle_weight(mint1.st_mint(), 9_000, 250)
+            .unwrap();
+        let entry = table.get_weight_entry(mint1.st_mint()).unwrap();
+        assert_eq!(entry.weight(), 1_500);
+        assert_eq!(entry.slot_set(), 250);
+        assert!(!table.finalized());
+
+        table
+            .record_oracle_weight(mint2.st_mint(), 700, 200)
+            .unwrap();
+        assert_eq!(table.get_weight(mint2.st_mint()).unwrap(), 500);
+        assert!(table.finalized());
+    }
+
+    #[test]
+    fn test_record_oracle_weight_without_samples() {
+        let ncn = Pubkey::new_unique();
+        let mut table = WeightTable::new(&ncn, 0, 100, 0, 0);
+        let mints = get_test_mint_entries(1);
+        let mint = mints[0];
+
+        table.set_mint_entries(&mints).unwrap();
+        table.set_sampling_window(100).unwrap();
+
+        table
+            .record_oracle_weight(mint.st_mint(), 700, 300)
+            .unwrap();
+        assert_eq!(table.get_weight(mint.st_mint()).unwrap(), 700);
+    }
 }
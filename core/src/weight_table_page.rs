@@ -0,0 +1,293 @@
+use core::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::VAULTS_PER_PAGE, discriminators::Discriminators, error::TipRouterError,
+    loaders::check_load, vault_registry::VaultEntry, vault_registry_page::VaultRegistryPage,
+};
+
+/// Extension of a weight table holding a snapshot of a vault registry page. Vaults are kept at
+/// the same position as in the vault registry page.
+// PDA'd ["weight_table_page", NCN, NCN_EPOCH_SLOT, PAGE]
+#[derive(Debug, Clone, Copy, Zeroable, Pod, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct WeightTablePage {
+    /// The NCN the page is associated with
+    ncn: Pubkey,
+    /// The epoch the page is associated with
+    epoch: PodU64,
+    /// Index of the page within the weight table
+    page: PodU64,
+    /// Number of vaults in the page
+    vault_count: PodU64,
+    /// Bump seed for the PDA
+    bump: u8,
+    /// Reserved space
+    reserved: [u8; 127],
+    /// A snapshot of the vault registry page
+    vault_registry: [VaultEntry; 32],
+}
+
+impl Discriminator for WeightTablePage {
+    const DISCRIMINATOR: u8 = Discriminators::WeightTablePage as u8;
+}
+
+impl WeightTablePage {
+    pub const SIZE: usize = 8 + size_of::<Self>();
+
+    pub fn new(ncn: &Pubkey, epoch: u64, page: u64, bump: u8) -> Self {
+        Self {
+            ncn: *ncn,
+            epoch: PodU64::from(epoch),
+            page: PodU64::from(page),
+            vault_count: PodU64::from(0),
+            bump,
+            reserved: [0; 127],
+            vault_registry: [VaultEntry::default(); VAULTS_PER_PAGE],
+        }
+    }
+
+    /// Copies the vaults of the vault registry page active this epoch, deactivated ones are left
+    /// out
+    pub fn initialize(
+        &mut self,
+        ncn: &Pubkey,
+        epoch: u64,
+        page: u64,
+        bump: u8,
+        vault_registry_page: &VaultRegistryPage,
+    ) {
+        // Initializes field by field to avoid overflowing stack
+        self.ncn = *ncn;
+        self.epoch = PodU64::from(epoch);
+        self.page = PodU64::from(page);
+        self.bump = bump;
+        self.reserved = [0; 127];
+        self.vault_registry = [VaultEntry::default(); VAULTS_PER_PAGE];
+
+        for (i, entry) in vault_registry_page.get_vault_entries().iter().enumerate() {
+            if entry.is_active(epoch) {
+                self.vault_registry[i] = *entry;
+            }
+        }
+
+        self.vault_count = PodU64::from(vault_registry_page.active_vault_count(epoch));
+    }
+
+    pub fn seeds(ncn: &Pubkey, epoch: u64, page: u64) -> Vec<Vec<u8>> {
+        vec![
+            b"weight_table_page".to_vec(),
+            ncn.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+            page.to_le_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+        page: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, epoch, page);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+        page: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let expected_pda = Self::find_program_address(program_id, ncn, epoch, page).0;
+        check_load(
+            program_id,
+            account,
+            &expected_pda,
+            Some(Self::DISCRIMINATOR),
+            expect_writable,
+        )
+    }
+
+    /// Loads any page of the weight table, the page index is read from the account itself
+    pub fn load_any_page(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        let page = {
+            let account_data = account.try_borrow_data()?;
+            Self::try_from_slice_unchecked(&account_data)?.page()
+        };
+
+        Self::load(program_id, account, ncn, epoch, page, expect_writable)
+    }
+
+    pub fn load_to_close(
+        program_id: &Pubkey,
+        account_to_close: &AccountInfo,
+        ncn: &Pubkey,
+        epoch: u64,
+    ) -> Result<(), ProgramError> {
+        Self::load_any_page(program_id, account_to_close, ncn, epoch, true)
+    }
+
+    /// Loads the page holding the vault at `ncn_vault_index`. The page is required for vaults
+    /// past `MAX_VAULTS` and rejected for the others.
+    pub fn load_for_vault<'a, 'info>(
+        program_id: &Pubkey,
+        account: Option<&'a AccountInfo<'info>>,
+        ncn: &Pubkey,
+        epoch: u64,
+        ncn_vault_index: u64,
+    ) -> Result<Option<&'a AccountInfo<'info>>, ProgramError> {
+        match (VaultRegistryPage::page_for_vault(ncn_vault_index), account) {
+            (None, None) => Ok(None),
+            (Some(page), Some(account)) => {
+                Self::load(program_id, account, ncn, epoch, page, false)?;
+                Ok(Some(account))
+            }
+            (page, _) => {
+                msg!(
+                    "Vault {} is kept in weight table page {:?}",
+                    ncn_vault_index,
+                    page
+                );
+                Err(TipRouterError::InvalidVaultPage.into())
+            }
+        }
+    }
+
+    pub const fn ncn(&self) -> &Pubkey {
+        &self.ncn
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn page(&self) -> u64 {
+        self.page.into()
+    }
+
+    pub fn vault_count(&self) -> u64 {
+        self.vault_count.into()
+    }
+
+    pub const fn get_vault_entries(&self) -> &[VaultEntry; VAULTS_PER_PAGE] {
+        &self.vault_registry
+    }
+
+    pub fn check_registry_for_vault(&self, vault_index: u64) -> Result<(), TipRouterError> {
+        if vault_index == VaultEntry::EMPTY_VAULT_INDEX {
+            return Err(TipRouterError::VaultNotInRegistry);
+        }
+
+        if !self
+            .vault_registry
+            .iter()
+            .any(|entry| entry.vault_index().eq(&vault_index))
+        {
+            return Err(TipRouterError::VaultNotInRegistry);
+        }
+        Ok(())
+    }
+}
+
+#[rustfmt::skip]
+impl fmt::Display for WeightTablePage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\n\n----------- Weight Table Page -------------")?;
+        writeln!(f, "  NCN:                          {}", self.ncn)?;
+        writeln!(f, "  Epoch:                        {}", self.epoch())?;
+        writeln!(f, "  Page:                         {}", self.page())?;
+        writeln!(f, "  Vault Count:                  {}", self.vault_count())?;
+
+        writeln!(f, "\nVault Registry Entries:")?;
+        for (i, entry) in self.vault_registry.iter().enumerate() {
+            if !entry.is_empty() {
+                writeln!(f, "  Entry {}:", i)?;
+                writeln!(f, "    Vault:                      {}", entry.vault())?;
+                writeln!(f, "    St Mint:                    {}", entry.st_mint())?;
+                writeln!(f, "    Vault Index:                {}", entry.vault_index())?;
+                writeln!(f, "    Slot Registered:            {}", entry.slot_registered())?;
+            }
+        }
+
+        writeln!(f, "\n")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{MAX_REALLOC_BYTES, MAX_VAULTS};
+
+    #[test]
+    fn test_len() {
+        let expected_total = size_of::<Pubkey>() // ncn
+            + size_of::<PodU64>() // epoch
+            + size_of::<PodU64>() // page
+            + size_of::<PodU64>() // vault_count
+            + 1 // bump
+            + 127 // reserved
+            + size_of::<VaultEntry>() * VAULTS_PER_PAGE; // vault_registry
+
+        assert_eq!(size_of::<WeightTablePage>(), expected_total);
+
+        // Pages are created in a single instruction
+        assert!(WeightTablePage::SIZE <= MAX_REALLOC_BYTES as usize);
+    }
+
+    #[test]
+    fn test_initialize_skips_deactivated_entries() {
+        let ncn = Pubkey::new_unique();
+        let st_mint = Pubkey::new_unique();
+        let vaults = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let mut vault_registry_page = VaultRegistryPage::new(&ncn, 0, 0);
+        for (i, vault) in vaults.iter().enumerate() {
+            vault_registry_page
+                .register_vault(
+                    MAX_VAULTS as u64 + i as u64,
+                    vault,
+                    &st_mint,
+                    70 + i as u64,
+                    0,
+                )
+                .unwrap();
+        }
+        vault_registry_page
+            .deactivate_vault(&vaults[1], 5, 5)
+            .unwrap();
+
+        let mut page = WeightTablePage::new(&ncn, 4, 0, 0);
+        page.initialize(&ncn, 4, 0, 0, &vault_registry_page);
+        assert_eq!(page.vault_count(), 2);
+
+        let mut page = WeightTablePage::new(&ncn, 5, 0, 0);
+        page.initialize(&ncn, 5, 0, 0, &vault_registry_page);
+        assert_eq!(page.vault_count(), 1);
+        assert!(page.check_registry_for_vault(70).is_ok());
+        assert_eq!(
+            page.check_registry_for_vault(71),
+            Err(TipRouterError::VaultNotInRegistry)
+        );
+        assert_eq!(
+            page.check_registry_for_vault(VaultEntry::EMPTY_VAULT_INDEX),
+            Err(TipRouterError::VaultNotInRegistry)
+        );
+    }
+}
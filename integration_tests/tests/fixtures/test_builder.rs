@@ -7,19 +7,24 @@ use jito_restaking_core::{config::Config, ncn_vault_ticket::NcnVaultTicket};
 use jito_tip_router_core::{
     account_payer::AccountPayer,
     ballot_box::BallotBox,
+    ballot_box_page::BallotBoxPage,
     base_fee_group::BaseFeeGroup,
     base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    base_reward_router_page::BaseRewardRouterPage,
     constants::{JITOSOL_MINT, JTO_SOL_FEED},
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    epoch_state_page::EpochStatePage,
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
     spl_stake_pool::find_withdraw_authority_program_address,
+    vault_registry_page::VaultRegistryPage,
     weight_table::WeightTable,
+    weight_table_page::WeightTablePage,
 };
 use solana_commitment_config::CommitmentLevel;
 use solana_program::{
-    clock::Clock, native_token::sol_str_to_lamports, program_pack::Pack, pubkey::Pubkey,
+    clock::Clock, native_token::sol_str_to_lamports, program_pack::Pack, pubkey::Pubkey, rent::Rent,
 };
 use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
@@ -236,6 +241,10 @@ impl TestBuilder {
         self.context.banks_client.get_sysvar().await.unwrap()
     }
 
+    pub async fn rent(&mut self) -> Rent {
+        self.context.banks_client.get_sysvar().await.unwrap()
+    }
+
     pub async fn epoch_schedule(&mut self) -> EpochSchedule {
         self.context.banks_client.get_sysvar().await.unwrap()
     }
@@ -597,6 +606,14 @@ impl TestBuilder {
 
         self.warp_slot_incremental(epoch_length * 2).await.unwrap();
 
+        // Vaults past MAX_VAULTS are registered to vault registry pages
+        let vault_registry_pages = VaultRegistryPage::pages_needed(test_ncn.vaults.len() as u64);
+        for page in 0..vault_registry_pages {
+            tip_router_client
+                .do_initialize_vault_registry_page(test_ncn.ncn_root.ncn_pubkey, page)
+                .await?;
+        }
+
         for vault in test_ncn.vaults.iter() {
             let ncn = test_ncn.ncn_root.ncn_pubkey;
             let vault = vault.vault_pubkey;
@@ -844,6 +861,35 @@ impl TestBuilder {
         Ok(())
     }
 
+    // 9b. Apply the stake weight caps left once the epoch snapshot is finalized
+    pub async fn add_stake_weight_caps_to_test_ncn(
+        &mut self,
+        test_ncn: &TestNcn,
+    ) -> TestResult<()> {
+        let mut tip_router_client = self.tip_router_client();
+
+        let epoch = self.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        for operator_root in test_ncn.operators.iter() {
+            let operator = operator_root.operator_pubkey;
+
+            let operator_snapshot = tip_router_client
+                .get_operator_snapshot(operator, ncn, epoch)
+                .await?;
+
+            if operator_snapshot.stake_weight_caps_applied() {
+                continue;
+            }
+
+            tip_router_client
+                .do_apply_stake_weight_caps(operator, ncn, epoch)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     // Intermission 2 - all snapshots are taken
     pub async fn snapshot_test_ncn(&mut self, test_ncn: &TestNcn) -> TestResult<()> {
         self.add_epoch_state_for_test_ncn(test_ncn).await?;
@@ -852,6 +898,7 @@ impl TestBuilder {
         self.add_operator_snapshots_to_test_ncn(test_ncn).await?;
         self.add_vault_operator_delegation_snapshots_to_test_ncn(test_ncn)
             .await?;
+        self.add_stake_weight_caps_to_test_ncn(test_ncn).await?;
 
         Ok(())
     }
@@ -987,9 +1034,11 @@ impl TestBuilder {
         for operator_root in test_ncn.operators.iter() {
             let operator = operator_root.operator_pubkey;
 
-            let operator_route = base_reward_router.ncn_fee_group_reward_route(&operator);
+            let operator_route = tip_router_client
+                .get_base_reward_route(ncn, operator, epoch)
+                .await?;
 
-            if let Ok(operator_route) = operator_route {
+            if let Some(operator_route) = operator_route {
                 for group in NcnFeeGroup::all_groups().iter() {
                     let rewards = operator_route.rewards(*group).unwrap();
 
@@ -1136,6 +1185,9 @@ impl TestBuilder {
         // NCN Reward Routers
         for operator_root in test_ncn.operators.iter() {
             let operator = operator_root.operator_pubkey;
+            let epoch_state_page = tip_router_client
+                .get_epoch_state_page_for_operator(ncn, operator, epoch_to_close)
+                .await?;
             for group in NcnFeeGroup::all_groups().iter() {
                 let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
                     &jito_tip_router_program::id(),
@@ -1168,11 +1220,12 @@ impl TestBuilder {
                 };
 
                 tip_router_client
-                    .do_close_epoch_account(
+                    .do_close_epoch_account_with_remaining_accounts(
                         ncn,
                         epoch_to_close,
                         ncn_reward_router,
                         Some(ncn_reward_receiver),
+                        epoch_state_page.as_slice(),
                     )
                     .await?;
 
@@ -1223,6 +1276,31 @@ impl TestBuilder {
                 epoch_to_close,
             );
 
+            // Pages are closed from the last one, before the base reward router
+            let page_count = tip_router_client
+                .get_base_reward_router(ncn, epoch_to_close)
+                .await?
+                .page_count();
+            for page in (0..page_count).rev() {
+                let (base_reward_router_page, _, _) =
+                    BaseRewardRouterPage::find_program_address_for_mint(
+                        &jito_tip_router_program::id(),
+                        &ncn,
+                        &Pubkey::default(),
+                        epoch_to_close,
+                        page,
+                    );
+
+                tip_router_client
+                    .do_close_epoch_account(
+                        ncn,
+                        epoch_to_close,
+                        base_reward_router_page,
+                        Some(base_reward_router),
+                    )
+                    .await?;
+            }
+
             tip_router_client
                 .airdrop(&base_reward_receiver, EXTRA_SOL_TO_AIRDROP)
                 .await?;
@@ -1286,6 +1364,24 @@ impl TestBuilder {
                 epoch_to_close,
             );
 
+            // Pages are closed from the last one, before the ballot box
+            let page_count = tip_router_client
+                .get_ballot_box(ncn, epoch_to_close)
+                .await?
+                .page_count();
+            for page in (0..page_count).rev() {
+                let (ballot_box_page, _, _) = BallotBoxPage::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch_to_close,
+                    page,
+                );
+
+                tip_router_client
+                    .do_close_epoch_account(ncn, epoch_to_close, ballot_box_page, Some(ballot_box))
+                    .await?;
+            }
+
             tip_router_client
                 .do_close_epoch_account(ncn, epoch_to_close, ballot_box, None)
                 .await?;
@@ -1305,8 +1401,27 @@ impl TestBuilder {
                 epoch_to_close,
             );
 
+            // Pages are closed from the last one, before the operator snapshot
+            let operator_snapshot_pages = tip_router_client
+                .get_operator_snapshot_pages(operator, ncn, epoch_to_close)
+                .await?;
+            for operator_snapshot_page in operator_snapshot_pages.into_iter().rev() {
+                tip_router_client
+                    .do_close_epoch_account(
+                        ncn,
+                        epoch_to_close,
+                        operator_snapshot_page,
+                        Some(operator_snapshot),
+                    )
+                    .await?;
+            }
+
+            // Operators past MAX_OPERATORS take their epoch state page as the receiver
+            let epoch_state_page = tip_router_client
+                .get_epoch_state_page_for_operator(ncn, operator, epoch_to_close)
+                .await?;
             tip_router_client
-                .do_close_epoch_account(ncn, epoch_to_close, operator_snapshot, None)
+                .do_close_epoch_account(ncn, epoch_to_close, operator_snapshot, epoch_state_page)
                 .await?;
 
             let result = self.get_account(&operator_snapshot).await?;
@@ -1337,6 +1452,29 @@ impl TestBuilder {
                 epoch_to_close,
             );
 
+            // Pages are closed from the last one, before the weight table
+            let pages_initialized = tip_router_client
+                .get_weight_table(ncn, epoch_to_close)
+                .await?
+                .pages_initialized();
+            for page in (0..pages_initialized).rev() {
+                let (weight_table_page, _, _) = WeightTablePage::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch_to_close,
+                    page,
+                );
+
+                tip_router_client
+                    .do_close_epoch_account(
+                        ncn,
+                        epoch_to_close,
+                        weight_table_page,
+                        Some(weight_table),
+                    )
+                    .await?;
+            }
+
             tip_router_client
                 .do_close_epoch_account(ncn, epoch_to_close, weight_table, None)
                 .await?;
@@ -1353,6 +1491,27 @@ impl TestBuilder {
                 epoch_to_close,
             );
 
+            // Pages are closed from the last one, before the epoch state
+            let page_count = tip_router_client
+                .get_epoch_state(ncn, epoch_to_close)
+                .await?
+                .page_count();
+            for page in (0..page_count).rev() {
+                let (epoch_state_page, _, _) = EpochStatePage::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch_to_close,
+                    page,
+                );
+
+                tip_router_client
+                    .do_close_epoch_account(ncn, epoch_to_close, epoch_state_page, None)
+                    .await?;
+
+                let result = self.get_account(&epoch_state_page).await?;
+                assert!(result.is_none());
+            }
+
             tip_router_client
                 .do_close_epoch_account(ncn, epoch_to_close, epoch_state, None)
                 .await?;
@@ -6,38 +6,61 @@ use jito_restaking_core::{
 use jito_tip_distribution_sdk::derive_tip_distribution_account_address;
 use jito_tip_router_client::{
     instructions::{
-        AdminRegisterStMintBuilder, AdminSetConfigFeesBuilder, AdminSetNewAdminBuilder,
-        AdminSetParametersBuilder, AdminSetStMintBuilder, AdminSetTieBreakerBuilder,
-        AdminSetWeightBuilder, CastVoteBuilder, ClaimWithPayerBuilder, CloseEpochAccountBuilder,
-        DistributeBaseNcnRewardRouteBuilder, DistributeBaseRewardsBuilder,
-        DistributeNcnOperatorRewardsBuilder, DistributeNcnVaultRewardsBuilder,
-        InitializeBallotBoxBuilder, InitializeBaseRewardRouterBuilder, InitializeConfigBuilder,
+        AdminAcceptAdminBuilder, AdminCancelAdminProposalBuilder, AdminCancelScheduledFeesBuilder,
+        AdminDeactivateStMintBuilder, AdminDeactivateVaultBuilder, AdminProposeNewAdminBuilder,
+        AdminRegisterStMintBuilder, AdminSetAdminMultisigBuilder, AdminSetBaseFeeGroupBuilder,
+        AdminSetConfigFeesBuilder, AdminSetNewAdminBuilder, AdminSetParametersBuilder,
+        AdminSetPausedSubsystemsBuilder, AdminSetRewardMintBuilder, AdminSetStMintBuilder,
+        AdminSetTieBreakerBuilder, AdminSetWeightBuilder, ApplyStakeWeightCapsBuilder,
+        ApproveAdminActionBuilder, CarryForwardRewardsBuilder, CastVoteBuilder,
+        ClaimWithPayerBuilder, CloseAdminProposalBuilder, CloseEpochAccountBuilder,
+        CloseOperatorVoteKeyBuilder, CommitVoteBuilder, DistributeBaseNcnRewardRouteBuilder,
+        DistributeBaseRewardsBuilder, DistributeNcnOperatorRewardsBuilder,
+        DistributeNcnVaultRewardsBuilder, ExecuteAdminActionBuilder, InitializeBallotBoxBuilder,
+        InitializeBallotBoxPageBuilder, InitializeBaseRewardRouterBuilder,
+        InitializeBaseRewardRouterPageBuilder, InitializeConfigBuilder,
         InitializeEpochSnapshotBuilder, InitializeEpochStateBuilder,
-        InitializeNcnRewardRouterBuilder, InitializeOperatorSnapshotBuilder,
-        InitializeVaultRegistryBuilder, InitializeWeightTableBuilder, ReallocBallotBoxBuilder,
+        InitializeEpochStatePageBuilder, InitializeNcnRewardRouterBuilder,
+        InitializeOperatorSnapshotBuilder, InitializeOperatorSnapshotPageBuilder,
+        InitializeVaultRegistryBuilder, InitializeVaultRegistryPageBuilder,
+        InitializeWeightTableBuilder, InitializeWeightTablePageBuilder, MigrateAccountBuilder,
+        OracleSetWeightBuilder, ProposeAdminActionBuilder, ReallocBallotBoxBuilder,
         ReallocBaseRewardRouterBuilder, ReallocEpochStateBuilder, ReallocOperatorSnapshotBuilder,
         ReallocVaultRegistryBuilder, ReallocWeightTableBuilder, RegisterVaultBuilder,
-        RouteBaseRewardsBuilder, RouteNcnRewardsBuilder, SetMerkleRootBuilder,
-        SnapshotVaultOperatorDelegationBuilder, SwitchboardSetWeightBuilder,
+        ResolveStalledVoteBuilder, RevealVoteBuilder, RouteBaseRewardsBuilder,
+        RouteNcnRewardsBuilder, SetMerkleRootBuilder, SetOperatorVoteKeyBuilder,
+        SetPayoutPreferenceBuilder, SnapshotVaultOperatorDelegationBuilder,
+        SwitchboardSetWeightBuilder, WithdrawVoteBuilder,
     },
-    types::ConfigAdminRole,
+    types::{AdminAction, ConfigAdminRole},
 };
 use jito_tip_router_core::{
     account_payer::AccountPayer,
+    admin_multisig::{AdminMultisig, AdminProposal},
     ballot_box::BallotBox,
-    base_fee_group::BaseFeeGroup,
-    base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
-    config::Config as NcnConfig,
+    ballot_box_page::BallotBoxPage,
+    base_fee_group::{BaseFeeGroup, BaseFeeGroupSettings},
+    base_reward_router::{BaseRewardReceiver, BaseRewardRouter, NcnRewardRoute},
+    base_reward_router_page::BaseRewardRouterPage,
+    config::{Config as NcnConfig, ConfigAdminRole as CoreConfigAdminRole},
     constants::{JITOSOL_MINT, MAX_REALLOC_BYTES},
     epoch_marker::EpochMarker,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    epoch_state_page::EpochStatePage,
     error::TipRouterError,
+    events::TipRouterEvent,
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    operator_snapshot_page::OperatorSnapshotPage,
+    operator_vote_key::OperatorVoteKey,
+    oracle::OracleType,
+    payout_preference::{PayoutAsset, PayoutPreference},
     spl_stake_pool::find_withdraw_authority_program_address,
     vault_registry::VaultRegistry,
+    vault_registry_page::VaultRegistryPage,
     weight_table::WeightTable,
+    weight_table_page::WeightTablePage,
 };
 use jito_vault_core::{
     vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
@@ -45,9 +68,13 @@ use jito_vault_core::{
 use solana_commitment_config::CommitmentLevel;
 use solana_compute_budget_interface::ComputeBudgetInstruction;
 use solana_program::{
-    hash::Hash, instruction::InstructionError, native_token::sol_str_to_lamports, pubkey::Pubkey,
+    clock::Clock,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    native_token::sol_str_to_lamports,
+    pubkey::Pubkey,
 };
-use solana_program_test::{BanksClient, ProgramTestBanksClientExt};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTestBanksClientExt};
 #[allow(deprecated)]
 use solana_sdk::{
     signature::{Keypair, Signer},
@@ -85,6 +112,27 @@ impl TipRouterClient {
         Ok(())
     }
 
+    /// Processes the transaction and returns the tip router events it logged
+    pub async fn process_transaction_with_events(
+        &mut self,
+        tx: &Transaction,
+    ) -> TestResult<Vec<TipRouterEvent>> {
+        let result = self
+            .banks_client
+            .process_transaction_with_metadata(tx.clone())
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        let logs = result
+            .metadata
+            .map(|metadata| metadata.log_messages)
+            .unwrap_or_default();
+        Ok(TipRouterEvent::from_logs(
+            &jito_tip_router_program::id(),
+            &logs,
+        ))
+    }
+
     pub async fn get_best_latest_blockhash(&mut self) -> TestResult<Hash> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         let new_blockhash = self
@@ -174,6 +222,175 @@ impl TipRouterClient {
         Ok(*VaultRegistry::try_from_slice_unchecked(vault_registry.data.as_slice()).unwrap())
     }
 
+    pub async fn get_vault_registry_page(
+        &mut self,
+        ncn_pubkey: Pubkey,
+        page: u64,
+    ) -> TestResult<VaultRegistryPage> {
+        let vault_registry_page = VaultRegistryPage::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn_pubkey,
+            page,
+        )
+        .0;
+        let vault_registry_page = self
+            .banks_client
+            .get_account(vault_registry_page)
+            .await?
+            .unwrap();
+        Ok(
+            *VaultRegistryPage::try_from_slice_unchecked(vault_registry_page.data.as_slice())
+                .unwrap(),
+        )
+    }
+
+    pub async fn get_ncn_vault_index(&mut self, ncn_vault_ticket: Pubkey) -> TestResult<u64> {
+        let account = self
+            .banks_client
+            .get_account(ncn_vault_ticket)
+            .await?
+            .unwrap();
+        Ok(
+            NcnVaultTicket::try_from_slice_unchecked(account.data.as_slice())
+                .unwrap()
+                .index(),
+        )
+    }
+
+    pub async fn get_ncn_operator_index(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+    ) -> TestResult<u64> {
+        let ncn_operator_state =
+            NcnOperatorState::find_program_address(&jito_restaking_program::id(), &ncn, &operator)
+                .0;
+        let account = self
+            .banks_client
+            .get_account(ncn_operator_state)
+            .await?
+            .unwrap();
+        Ok(
+            NcnOperatorState::try_from_slice_unchecked(account.data.as_slice())
+                .unwrap()
+                .index(),
+        )
+    }
+
+    /// The epoch state page tracking the operator, if it is past `MAX_OPERATORS`
+    pub async fn get_epoch_state_page_for_operator(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+    ) -> TestResult<Option<Pubkey>> {
+        let ncn_operator_index = self.get_ncn_operator_index(ncn, operator).await?;
+        Ok(
+            EpochStatePage::page_for_operator(ncn_operator_index).map(|page| {
+                EpochStatePage::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                    page,
+                )
+                .0
+            }),
+        )
+    }
+
+    /// The ballot box page holding the operator's vote, if it is past `MAX_OPERATORS`
+    pub async fn get_ballot_box_page_for_operator(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+    ) -> TestResult<Option<Pubkey>> {
+        let ncn_operator_index = self.get_ncn_operator_index(ncn, operator).await?;
+        Ok(
+            BallotBoxPage::page_for_operator(ncn_operator_index).map(|page| {
+                BallotBoxPage::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                    page,
+                )
+                .0
+            }),
+        )
+    }
+
+    /// The base reward router page holding the operator's reward route, if it is past
+    /// `MAX_OPERATORS`
+    pub async fn get_base_reward_router_page_for_operator(
+        &mut self,
+        ncn: Pubkey,
+        mint: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+    ) -> TestResult<Option<Pubkey>> {
+        let ncn_operator_index = self.get_ncn_operator_index(ncn, operator).await?;
+        Ok(
+            BaseRewardRouterPage::page_for_operator(ncn_operator_index).map(|page| {
+                BaseRewardRouterPage::find_program_address_for_mint(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    &mint,
+                    epoch,
+                    page,
+                )
+                .0
+            }),
+        )
+    }
+
+    /// The ballot box pages and then the base reward router pages of the epoch, in order, as
+    /// routing expects them
+    pub async fn get_routing_page_accounts(
+        &mut self,
+        ncn: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+    ) -> TestResult<Vec<AccountMeta>> {
+        let page_count = self.get_ballot_box(ncn, epoch).await?.page_count();
+        let ballot_box_pages = (0..page_count).map(|page| {
+            AccountMeta::new_readonly(
+                BallotBoxPage::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                    page,
+                )
+                .0,
+                false,
+            )
+        });
+        let base_reward_router_pages = (0..page_count).map(|page| {
+            AccountMeta::new(
+                BaseRewardRouterPage::find_program_address_for_mint(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    &mint,
+                    epoch,
+                    page,
+                )
+                .0,
+                false,
+            )
+        });
+        Ok(ballot_box_pages.chain(base_reward_router_pages).collect())
+    }
+
+    /// The vault registry pages of the NCN, in order
+    pub async fn get_vault_registry_pages(&mut self, ncn: Pubkey) -> TestResult<Vec<Pubkey>> {
+        let page_count = self.get_vault_registry(ncn).await?.page_count();
+        Ok((0..page_count)
+            .map(|page| {
+                VaultRegistryPage::find_program_address(&jito_tip_router_program::id(), &ncn, page)
+                    .0
+            })
+            .collect())
+    }
+
     pub async fn get_epoch_state(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<EpochState> {
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
@@ -235,6 +452,85 @@ impl TipRouterClient {
         Ok(*account)
     }
 
+    #[allow(dead_code)]
+    pub async fn get_operator_snapshot_page(
+        &mut self,
+        operator: Pubkey,
+        ncn: Pubkey,
+        ncn_epoch: u64,
+        page: u64,
+    ) -> TestResult<OperatorSnapshotPage> {
+        let address = OperatorSnapshotPage::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            ncn_epoch,
+            page,
+        )
+        .0;
+
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+
+        let account =
+            OperatorSnapshotPage::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap();
+
+        Ok(*account)
+    }
+
+    /// The operator snapshot pages of the operator, in order
+    pub async fn get_operator_snapshot_pages(
+        &mut self,
+        operator: Pubkey,
+        ncn: Pubkey,
+        ncn_epoch: u64,
+    ) -> TestResult<Vec<Pubkey>> {
+        let page_count = self
+            .get_operator_snapshot(operator, ncn, ncn_epoch)
+            .await?
+            .page_count();
+        Ok((0..page_count)
+            .map(|page| {
+                OperatorSnapshotPage::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &operator,
+                    &ncn,
+                    ncn_epoch,
+                    page,
+                )
+                .0
+            })
+            .collect())
+    }
+
+    pub async fn get_operator_vote_key(
+        &mut self,
+        operator: Pubkey,
+        ncn: Pubkey,
+    ) -> TestResult<OperatorVoteKey> {
+        let address =
+            OperatorVoteKey::find_program_address(&jito_tip_router_program::id(), &operator, &ncn)
+                .0;
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+        Ok(*OperatorVoteKey::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap())
+    }
+
+    pub async fn get_payout_preference(
+        &mut self,
+        recipient: Pubkey,
+        ncn: Pubkey,
+    ) -> TestResult<Option<PayoutPreference>> {
+        let address = PayoutPreference::find_program_address(
+            &jito_tip_router_program::id(),
+            &recipient,
+            &ncn,
+        )
+        .0;
+        let raw_account = self.banks_client.get_account(address).await?;
+        Ok(raw_account.map(|raw_account| {
+            *PayoutPreference::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap()
+        }))
+    }
+
     pub async fn get_ballot_box(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<BallotBox> {
         let address =
             BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
@@ -242,6 +538,36 @@ impl TipRouterClient {
         Ok(*BallotBox::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap())
     }
 
+    /// The operator's reward route, read from the base reward router page for operators past
+    /// `MAX_OPERATORS`
+    pub async fn get_base_reward_route(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+    ) -> TestResult<Option<NcnRewardRoute>> {
+        let route = match self
+            .get_base_reward_router_page_for_operator(ncn, Pubkey::default(), operator, epoch)
+            .await?
+        {
+            Some(address) => {
+                let raw_account = self.banks_client.get_account(address).await?.unwrap();
+                BaseRewardRouterPage::try_from_slice_unchecked(raw_account.data.as_slice())
+                    .unwrap()
+                    .ncn_fee_group_reward_route(&operator)
+                    .ok()
+                    .copied()
+            }
+            None => self
+                .get_base_reward_router(ncn, epoch)
+                .await?
+                .ncn_fee_group_reward_route(&operator)
+                .ok()
+                .copied(),
+        };
+        Ok(route)
+    }
+
     pub async fn get_base_reward_router(
         &mut self,
         ncn: Pubkey,
@@ -378,6 +704,32 @@ impl TipRouterClient {
             new_ncn_fee_bps,
             ncn_root,
             new_priority_fee_distribution_fee_bps,
+            None,
+        )
+        .await
+    }
+
+    pub async fn do_schedule_config_fees(
+        &mut self,
+        base_fee_group: BaseFeeGroup,
+        new_base_fee_bps: u16,
+        activation_epoch: u64,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+        self.airdrop(&ncn_root.ncn_admin.pubkey(), 1.0).await?;
+        self.set_config_fees(
+            config_pda,
+            None,
+            Some(base_fee_group),
+            None,
+            Some(new_base_fee_bps),
+            None,
+            None,
+            ncn_root,
+            None,
+            Some(activation_epoch),
         )
         .await
     }
@@ -393,6 +745,7 @@ impl TipRouterClient {
         new_ncn_fee_bps: Option<u16>,
         ncn_root: &NcnRoot,
         new_priority_fee_distribution_fee_bps: Option<u16>,
+        activation_epoch: Option<u64>,
     ) -> TestResult<()> {
         let ix = {
             let mut builder = AdminSetConfigFeesBuilder::new();
@@ -429,6 +782,10 @@ impl TipRouterClient {
                 builder.new_priority_fee_distribution_fee_bps(fee_bps);
             }
 
+            if let Some(activation_epoch) = activation_epoch {
+                builder.activation_epoch(activation_epoch);
+            }
+
             builder.instruction()
         };
 
@@ -442,6 +799,71 @@ impl TipRouterClient {
         .await
     }
 
+    pub async fn do_set_base_fee_group(
+        &mut self,
+        base_fee_group: BaseFeeGroup,
+        name: Option<&str>,
+        enabled: Option<bool>,
+        payout_asset: Option<PayoutAsset>,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+
+        let mut builder = AdminSetBaseFeeGroupBuilder::new();
+        builder
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .fee_admin(ncn_root.ncn_admin.pubkey())
+            .base_fee_group(base_fee_group.group);
+
+        if let Some(name) = name {
+            builder.name(BaseFeeGroupSettings::name_bytes(name));
+        }
+
+        if let Some(enabled) = enabled {
+            builder.enabled(enabled);
+        }
+
+        if let Some(payout_asset) = payout_asset {
+            builder.payout_asset(payout_asset as u8);
+        }
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[builder.instruction()],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_cancel_scheduled_fees(
+        &mut self,
+        activation_epoch: u64,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+
+        let ix = AdminCancelScheduledFeesBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .fee_admin(ncn_root.ncn_admin.pubkey())
+            .activation_epoch(activation_epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_set_new_admin(
         &mut self,
         role: ConfigAdminRole,
@@ -480,135 +902,221 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_full_initialize_epoch_state(
+    pub async fn do_propose_new_admin(
         &mut self,
-        ncn: Pubkey,
-        epoch: u64,
+        role: ConfigAdminRole,
+        new_admin: Pubkey,
+        ncn_root: &NcnRoot,
     ) -> TestResult<()> {
-        self.do_intialize_epoch_state(ncn, epoch).await?;
-        let num_reallocs = (EpochState::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
-        self.do_realloc_epoch_state(ncn, epoch, num_reallocs)
-            .await?;
-        Ok(())
+        let ix = Self::propose_new_admin_ix(role, new_admin, ncn_root);
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn do_intialize_epoch_state(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
-        self.initialize_epoch_state(ncn, epoch).await
+    pub async fn do_propose_new_admin_with_events(
+        &mut self,
+        role: ConfigAdminRole,
+        new_admin: Pubkey,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<Vec<TipRouterEvent>> {
+        let ix = Self::propose_new_admin_ix(role, new_admin, ncn_root);
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction_with_events(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn initialize_epoch_state(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
-        let (epoch_marker, _, _) =
-            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+    fn propose_new_admin_ix(
+        role: ConfigAdminRole,
+        new_admin: Pubkey,
+        ncn_root: &NcnRoot,
+    ) -> Instruction {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
 
-        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        AdminProposeNewAdminBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .new_admin(new_admin)
+            .role(role)
+            .instruction()
+    }
 
-        let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+    pub async fn do_accept_admin(
+        &mut self,
+        ncn: Pubkey,
+        role: ConfigAdminRole,
+        new_admin: &Keypair,
+    ) -> TestResult<()> {
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let ix = InitializeEpochStateBuilder::new()
-            .epoch_marker(epoch_marker)
-            .epoch_state(epoch_state)
-            .config(config)
+        let ix = AdminAcceptAdminBuilder::new()
+            .config(config_pda)
             .ncn(ncn)
-            .account_payer(account_payer)
-            .system_program(system_program::id())
-            .epoch(epoch)
+            .new_admin(new_admin.pubkey())
+            .role(role)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix],
             Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &[&self.payer, new_admin],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_realloc_epoch_state(
+    pub async fn do_cancel_admin_proposal(
         &mut self,
         ncn: Pubkey,
-        epoch: u64,
-        num_reallocations: u64,
+        role: ConfigAdminRole,
+        signer: &Keypair,
     ) -> TestResult<()> {
-        self.realloc_epoch_state(ncn, epoch, num_reallocations)
-            .await
+        let ix = Self::cancel_admin_proposal_ix(ncn, role, signer);
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, signer],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn realloc_epoch_state(
+    pub async fn do_cancel_admin_proposal_with_events(
         &mut self,
         ncn: Pubkey,
-        epoch: u64,
-        num_reallocations: u64,
-    ) -> TestResult<()> {
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        role: ConfigAdminRole,
+        signer: &Keypair,
+    ) -> TestResult<Vec<TipRouterEvent>> {
+        let ix = Self::cancel_admin_proposal_ix(ncn, role, signer);
 
-        let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction_with_events(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, signer],
+            blockhash,
+        ))
+        .await
+    }
 
-        let ix = ReallocEpochStateBuilder::new()
-            .epoch_state(epoch_state)
-            .config(config)
+    fn cancel_admin_proposal_ix(
+        ncn: Pubkey,
+        role: ConfigAdminRole,
+        signer: &Keypair,
+    ) -> Instruction {
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        AdminCancelAdminProposalBuilder::new()
+            .config(config_pda)
             .ncn(ncn)
-            .account_payer(account_payer)
-            .system_program(system_program::id())
-            .epoch(epoch)
+            .signer(signer.pubkey())
+            .role(role)
+            .instruction()
+    }
+
+    pub async fn do_set_admin_handover_delay(
+        &mut self,
+        admin_handover_delay_epochs: u16,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+
+        let ix = AdminSetParametersBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .admin_handover_delay_epochs(admin_handover_delay_epochs)
             .instruction();
 
-        let ixs = vec![ix; num_reallocations as usize];
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_paused_subsystems(
+        &mut self,
+        ncn: Pubkey,
+        paused_subsystems: u8,
+        admin: &Keypair,
+    ) -> TestResult<()> {
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let ix = AdminSetPausedSubsystemsBuilder::new()
+            .config(config_pda)
+            .ncn(ncn)
+            .admin(admin.pubkey())
+            .paused_subsystems(paused_subsystems)
+            .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &ixs,
+            &[ix],
             Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &[&self.payer, admin],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_full_initialize_weight_table(
+    pub async fn do_set_reward_mint(
         &mut self,
         ncn: Pubkey,
-        epoch: u64,
+        mint: Pubkey,
+        allowed: bool,
+        ncn_admin: &Keypair,
     ) -> TestResult<()> {
-        self.do_initialize_weight_table(ncn, epoch).await?;
-        let num_reallocs = (WeightTable::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
-        self.do_realloc_weight_table(ncn, epoch, num_reallocs)
-            .await?;
-        Ok(())
-    }
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-    pub async fn do_initialize_weight_table(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
-        self.initialize_weight_table(ncn, epoch).await
-    }
+        let ix = AdminSetRewardMintBuilder::new()
+            .config(config_pda)
+            .ncn(ncn)
+            .mint(mint)
+            .ncn_admin(ncn_admin.pubkey())
+            .allowed(allowed)
+            .instruction();
 
-    pub async fn initialize_weight_table(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
-        let (epoch_marker, _, _) =
-            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-        let vault_registry =
-            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let weight_table =
-            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
 
+    pub async fn do_migrate_account(&mut self, ncn: Pubkey, account: Pubkey) -> TestResult<()> {
         let (account_payer, _, _) =
             AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-        let ix = InitializeWeightTableBuilder::new()
-            .epoch_marker(epoch_marker)
-            .epoch_state(epoch_state)
-            .vault_registry(vault_registry)
+        let ix = MigrateAccountBuilder::new()
             .ncn(ncn)
-            .weight_table(weight_table)
+            .account_to_migrate(account)
             .account_payer(account_payer)
             .system_program(system_program::id())
-            .epoch(epoch)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -621,82 +1129,109 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_admin_set_weight(
+    pub async fn do_set_weight_sampling_window(
         &mut self,
-        ncn: Pubkey,
-        epoch: u64,
-        st_mint: Pubkey,
-        weight: u128,
+        weight_sampling_window_slots: u64,
+        ncn_root: &NcnRoot,
     ) -> TestResult<()> {
-        self.admin_set_weight(ncn, epoch, st_mint, weight).await
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+
+        let ix = AdminSetParametersBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .weight_sampling_window_slots(weight_sampling_window_slots)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn admin_set_weight(
+    pub async fn do_set_stake_weight_caps(
         &mut self,
-        ncn: Pubkey,
-        epoch: u64,
-        st_mint: Pubkey,
-        weight: u128,
+        max_operator_stake_weight_bps: u16,
+        max_vault_stake_weight_bps: u16,
+        ncn_root: &NcnRoot,
     ) -> TestResult<()> {
-        let weight_table =
-            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
 
-        let ix = AdminSetWeightBuilder::new()
-            .epoch_state(epoch_state)
-            .ncn(ncn)
-            .weight_table(weight_table)
-            .weight_table_admin(self.payer.pubkey())
-            .st_mint(st_mint)
-            .weight(weight)
-            .epoch(epoch)
+        let ix = AdminSetParametersBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .max_operator_stake_weight_bps(max_operator_stake_weight_bps)
+            .max_vault_stake_weight_bps(max_vault_stake_weight_bps)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix],
-            Some(&self.payer.pubkey()),
-            &[&self.payer],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_switchboard_set_weight(
+    pub async fn do_full_initialize_epoch_state(
         &mut self,
         ncn: Pubkey,
         epoch: u64,
-        st_mint: Pubkey,
     ) -> TestResult<()> {
-        let vault_registry = self.get_vault_registry(ncn).await?;
-
-        let mint_entry = vault_registry.get_mint_entry(&st_mint)?;
-        let switchboard_feed = mint_entry.switchboard_feed();
+        self.do_intialize_epoch_state(ncn, epoch).await?;
+        let num_reallocs = (EpochState::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+        self.do_realloc_epoch_state(ncn, epoch, num_reallocs)
+            .await?;
 
-        self.switchboard_set_weight(ncn, epoch, st_mint, *switchboard_feed)
-            .await
+        let operator_count = {
+            let account = self.banks_client.get_account(ncn).await?.unwrap();
+            jito_restaking_core::ncn::Ncn::try_from_slice_unchecked(account.data.as_slice())
+                .unwrap()
+                .operator_count()
+        };
+        for page in 0..EpochStatePage::pages_needed(operator_count) {
+            self.do_initialize_epoch_state_page(ncn, epoch, page)
+                .await?;
+        }
+        Ok(())
     }
 
-    pub async fn switchboard_set_weight(
+    pub async fn do_initialize_epoch_state_page(
         &mut self,
         ncn: Pubkey,
         epoch: u64,
-        st_mint: Pubkey,
-        switchboard_feed: Pubkey,
+        page: u64,
     ) -> TestResult<()> {
-        let weight_table =
-            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let epoch_state_page =
+            EpochStatePage::find_program_address(&jito_tip_router_program::id(), &ncn, epoch, page)
+                .0;
 
-        let ix = SwitchboardSetWeightBuilder::new()
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = InitializeEpochStatePageBuilder::new()
+            .epoch_marker(epoch_marker)
             .epoch_state(epoch_state)
+            .config(config)
+            .epoch_state_page(epoch_state_page)
             .ncn(ncn)
-            .weight_table(weight_table)
-            .st_mint(st_mint)
-            .switchboard_feed(switchboard_feed)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
             .epoch(epoch)
+            .page(page)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -709,37 +1244,29 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_full_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
-        self.do_initialize_vault_registry(ncn).await?;
-        let num_reallocs = (WeightTable::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
-        self.do_realloc_vault_registry(ncn, num_reallocs).await?;
-        Ok(())
+    pub async fn do_intialize_epoch_state(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        self.initialize_epoch_state(ncn, epoch).await
     }
 
-    pub async fn do_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
-        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let vault_registry =
-            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+    pub async fn initialize_epoch_state(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
-        self.initialize_vault_registry(&ncn_config, &vault_registry, &ncn)
-            .await
-    }
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-    pub async fn initialize_vault_registry(
-        &mut self,
-        ncn_config: &Pubkey,
-        vault_registry: &Pubkey,
-        ncn: &Pubkey,
-    ) -> TestResult<()> {
         let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), ncn);
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-        let ix = InitializeVaultRegistryBuilder::new()
-            .config(*ncn_config)
-            .vault_registry(*vault_registry)
-            .ncn(*ncn)
+        let ix = InitializeEpochStateBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
             .account_payer(account_payer)
             .system_program(system_program::id())
+            .epoch(epoch)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -752,34 +1279,36 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_realloc_vault_registry(
+    pub async fn do_realloc_epoch_state(
         &mut self,
         ncn: Pubkey,
+        epoch: u64,
         num_reallocations: u64,
     ) -> TestResult<()> {
-        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let vault_registry =
-            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        self.realloc_vault_registry(&ncn, &ncn_config, &vault_registry, num_reallocations)
+        self.realloc_epoch_state(ncn, epoch, num_reallocations)
             .await
     }
 
-    pub async fn realloc_vault_registry(
+    pub async fn realloc_epoch_state(
         &mut self,
-        ncn: &Pubkey,
-        config: &Pubkey,
-        vault_registry: &Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
         num_reallocations: u64,
     ) -> TestResult<()> {
-        let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), ncn);
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let ix = ReallocVaultRegistryBuilder::new()
-            .ncn(*ncn)
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = ReallocEpochStateBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
             .account_payer(account_payer)
-            .config(*config)
-            .vault_registry(*vault_registry)
             .system_program(system_program::id())
+            .epoch(epoch)
             .instruction();
 
         let ixs = vec![ix; num_reallocations as usize];
@@ -794,35 +1323,61 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_register_vault(
+    pub async fn do_full_initialize_weight_table(
         &mut self,
         ncn: Pubkey,
-        vault: Pubkey,
-        ncn_vault_ticket: Pubkey,
+        epoch: u64,
     ) -> TestResult<()> {
-        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-
-        let vault_registry =
-            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        self.do_initialize_weight_table(ncn, epoch).await?;
+        let num_reallocs = (WeightTable::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+        self.do_realloc_weight_table(ncn, epoch, num_reallocs)
+            .await?;
 
-        self.register_vault(ncn_config, vault_registry, ncn, vault, ncn_vault_ticket)
-            .await
+        // Vaults past MAX_VAULTS are snapshotted into weight table pages
+        let page_count = self.get_weight_table(ncn, epoch).await?.page_count();
+        for page in 0..page_count {
+            self.do_initialize_weight_table_page(ncn, epoch, page)
+                .await?;
+        }
+        Ok(())
     }
 
-    pub async fn register_vault(
+    pub async fn do_initialize_weight_table_page(
         &mut self,
-        config: Pubkey,
-        vault_registry: Pubkey,
         ncn: Pubkey,
-        vault: Pubkey,
-        ncn_vault_ticket: Pubkey,
+        epoch: u64,
+        page: u64,
     ) -> TestResult<()> {
-        let ix = RegisterVaultBuilder::new()
-            .config(config)
-            .vault_registry(vault_registry)
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let vault_registry_page =
+            VaultRegistryPage::find_program_address(&jito_tip_router_program::id(), &ncn, page).0;
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let weight_table_page = WeightTablePage::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            epoch,
+            page,
+        )
+        .0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = InitializeWeightTablePageBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .vault_registry_page(vault_registry_page)
             .ncn(ncn)
-            .vault(vault)
-            .ncn_vault_ticket(ncn_vault_ticket)
+            .weight_table(weight_table)
+            .weight_table_page(weight_table_page)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .page(page)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -835,70 +1390,33 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_admin_register_st_mint(
-        &mut self,
-        ncn: Pubkey,
-        st_mint: Pubkey,
-        ncn_fee_group: NcnFeeGroup,
-        reward_multiplier_bps: u64,
-        switchboard_feed: Option<Pubkey>,
-        no_feed_weight: Option<u128>,
-    ) -> TestResult<()> {
-        let vault_registry =
-            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-
-        let (ncn_config, _, _) =
-            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
-
-        let admin = self.payer.pubkey();
-
-        self.admin_register_st_mint(
-            ncn,
-            ncn_config,
-            vault_registry,
-            admin,
-            st_mint,
-            ncn_fee_group,
-            reward_multiplier_bps,
-            switchboard_feed,
-            no_feed_weight,
-        )
-        .await
+    pub async fn do_initialize_weight_table(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        self.initialize_weight_table(ncn, epoch).await
     }
 
-    pub async fn admin_register_st_mint(
-        &mut self,
-        ncn: Pubkey,
-        ncn_config: Pubkey,
-        vault_registry: Pubkey,
-        admin: Pubkey,
-        st_mint: Pubkey,
-        ncn_fee_group: NcnFeeGroup,
-        reward_multiplier_bps: u64,
-        switchboard_feed: Option<Pubkey>,
-        no_feed_weight: Option<u128>,
-    ) -> TestResult<()> {
-        let ix = {
-            let mut builder = AdminRegisterStMintBuilder::new();
-            builder
-                .config(ncn_config)
-                .ncn(ncn)
-                .vault_registry(vault_registry)
-                .admin(admin)
-                .st_mint(st_mint)
-                .ncn_fee_group(ncn_fee_group.group)
-                .reward_multiplier_bps(reward_multiplier_bps);
-
-            if let Some(switchboard_feed) = switchboard_feed {
-                builder.switchboard_feed(switchboard_feed);
-            }
+    pub async fn initialize_weight_table(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
-            if let Some(no_feed_weight) = no_feed_weight {
-                builder.no_feed_weight(no_feed_weight);
-            }
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-            builder.instruction()
-        };
+        let ix = InitializeWeightTableBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .vault_registry(vault_registry)
+            .ncn(ncn)
+            .weight_table(weight_table)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
@@ -910,76 +1428,24 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_admin_set_st_mint(
+    pub async fn do_admin_set_weight(
         &mut self,
         ncn: Pubkey,
+        epoch: u64,
         st_mint: Pubkey,
-        ncn_fee_group: Option<NcnFeeGroup>,
-        reward_multiplier_bps: Option<u64>,
-        switchboard_feed: Option<Pubkey>,
-        no_feed_weight: Option<u128>,
+        weight: u128,
     ) -> TestResult<()> {
-        let vault_registry =
-            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-
-        let (ncn_config, _, _) =
-            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
-
-        let admin = self.payer.pubkey();
-
-        self.admin_set_st_mint(
-            ncn,
-            ncn_config,
-            vault_registry,
-            admin,
-            st_mint,
-            ncn_fee_group,
-            reward_multiplier_bps,
-            switchboard_feed,
-            no_feed_weight,
-        )
-        .await
+        self.admin_set_weight(ncn, epoch, st_mint, weight).await
     }
 
-    pub async fn admin_set_st_mint(
+    pub async fn admin_set_weight(
         &mut self,
         ncn: Pubkey,
-        ncn_config: Pubkey,
-        vault_registry: Pubkey,
-        admin: Pubkey,
+        epoch: u64,
         st_mint: Pubkey,
-        ncn_fee_group: Option<NcnFeeGroup>,
-        reward_multiplier_bps: Option<u64>,
-        switchboard_feed: Option<Pubkey>,
-        no_feed_weight: Option<u128>,
+        weight: u128,
     ) -> TestResult<()> {
-        let ix = {
-            let mut builder = AdminSetStMintBuilder::new();
-            builder
-                .config(ncn_config)
-                .ncn(ncn)
-                .vault_registry(vault_registry)
-                .admin(admin)
-                .st_mint(st_mint);
-
-            if let Some(ncn_fee_group) = ncn_fee_group {
-                builder.ncn_fee_group(ncn_fee_group.group);
-            }
-
-            if let Some(reward_multiplier_bps) = reward_multiplier_bps {
-                builder.reward_multiplier_bps(reward_multiplier_bps);
-            }
-
-            if let Some(switchboard_feed) = switchboard_feed {
-                builder.switchboard_feed(switchboard_feed);
-            }
-
-            if let Some(no_feed_weight) = no_feed_weight {
-                builder.no_feed_weight(no_feed_weight);
-            }
-
-            builder.instruction()
-        };
+        let ix = self.admin_set_weight_ix(ncn, epoch, st_mint, weight);
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
@@ -991,42 +1457,17 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_initialize_epoch_snapshot(
+    pub async fn do_admin_set_weight_with_events(
         &mut self,
         ncn: Pubkey,
         epoch: u64,
-    ) -> TestResult<()> {
-        self.initialize_epoch_snapshot(ncn, epoch).await
-    }
-
-    pub async fn initialize_epoch_snapshot(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
-        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let (epoch_marker, _, _) =
-            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-        let weight_table =
-            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-        let epoch_snapshot =
-            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-
-        let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
-
-        let ix = InitializeEpochSnapshotBuilder::new()
-            .epoch_marker(epoch_marker)
-            .epoch_state(epoch_state)
-            .config(config_pda)
-            .ncn(ncn)
-            .weight_table(weight_table)
-            .epoch_snapshot(epoch_snapshot)
-            .account_payer(account_payer)
-            .system_program(system_program::id())
-            .epoch(epoch)
-            .instruction();
+        st_mint: Pubkey,
+        weight: u128,
+    ) -> TestResult<Vec<TipRouterEvent>> {
+        let ix = self.admin_set_weight_ix(ncn, epoch, st_mint, weight);
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
-        self.process_transaction(&Transaction::new_signed_with_payer(
+        self.process_transaction_with_events(&Transaction::new_signed_with_payer(
             &[ix],
             Some(&self.payer.pubkey()),
             &[&self.payer],
@@ -1035,69 +1476,62 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_full_initialize_operator_snapshot(
-        &mut self,
-        operator: Pubkey,
+    fn admin_set_weight_ix(
+        &self,
         ncn: Pubkey,
         epoch: u64,
-    ) -> TestResult<()> {
-        self.do_initialize_operator_snapshot(operator, ncn, epoch)
-            .await?;
-        let num_reallocs =
-            (OperatorSnapshot::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
-        self.do_realloc_operator_snapshot(operator, ncn, epoch, num_reallocs)
-            .await?;
-        Ok(())
+        st_mint: Pubkey,
+        weight: u128,
+    ) -> Instruction {
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        AdminSetWeightBuilder::new()
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .weight_table(weight_table)
+            .weight_table_admin(self.payer.pubkey())
+            .st_mint(st_mint)
+            .weight(weight)
+            .epoch(epoch)
+            .instruction()
     }
 
-    pub async fn do_initialize_operator_snapshot(
+    pub async fn do_switchboard_set_weight(
         &mut self,
-        operator: Pubkey,
         ncn: Pubkey,
         epoch: u64,
+        st_mint: Pubkey,
     ) -> TestResult<()> {
-        self.initialize_operator_snapshot(operator, ncn, epoch)
+        let vault_registry = self.get_vault_registry(ncn).await?;
+
+        let mint_entry = vault_registry.get_mint_entry(&st_mint)?;
+        let switchboard_feed = mint_entry.switchboard_feed();
+
+        self.switchboard_set_weight(ncn, epoch, st_mint, *switchboard_feed)
             .await
     }
 
-    pub async fn initialize_operator_snapshot(
+    pub async fn switchboard_set_weight(
         &mut self,
-        operator: Pubkey,
         ncn: Pubkey,
         epoch: u64,
+        st_mint: Pubkey,
+        switchboard_feed: Pubkey,
     ) -> TestResult<()> {
-        let (epoch_marker, _, _) =
-            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let ncn_operator_state =
-            NcnOperatorState::find_program_address(&jito_restaking_program::id(), &ncn, &operator)
-                .0;
-        let epoch_snapshot =
-            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-        let operator_snapshot = OperatorSnapshot::find_program_address(
-            &jito_tip_router_program::id(),
-            &operator,
-            &ncn,
-            epoch,
-        )
-        .0;
-
-        let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-        let ix = InitializeOperatorSnapshotBuilder::new()
-            .epoch_marker(epoch_marker)
+        let ix = SwitchboardSetWeightBuilder::new()
             .epoch_state(epoch_state)
-            .config(config_pda)
             .ncn(ncn)
-            .operator(operator)
-            .ncn_operator_state(ncn_operator_state)
-            .epoch_snapshot(epoch_snapshot)
-            .operator_snapshot(operator_snapshot)
-            .account_payer(account_payer)
-            .system_program(system_program::id())
+            .weight_table(weight_table)
+            .st_mint(st_mint)
+            .switchboard_feed(switchboard_feed)
             .epoch(epoch)
             .instruction();
 
@@ -1111,92 +1545,44 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_snapshot_vault_operator_delegation(
+    pub async fn do_oracle_set_weight(
         &mut self,
-        vault: Pubkey,
-        operator: Pubkey,
         ncn: Pubkey,
         epoch: u64,
+        st_mint: Pubkey,
     ) -> TestResult<()> {
-        self.snapshot_vault_operator_delegation(vault, operator, ncn, epoch)
-            .await
-    }
+        let vault_registry = self.get_vault_registry(ncn).await?;
 
-    pub async fn snapshot_vault_operator_delegation(
-        &mut self,
-        vault: Pubkey,
-        operator: Pubkey,
-        ncn: Pubkey,
-        epoch: u64,
-    ) -> TestResult<()> {
-        self.snapshot_vault_operator_delegation_with_override(
-            vault, operator, ncn, epoch, None, None,
-        )
-        .await
+        let mint_entry = vault_registry.get_mint_entry(&st_mint)?;
+        let oracle_feed = mint_entry.switchboard_feed();
+
+        let vault_registry_pda =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        self.oracle_set_weight(ncn, epoch, st_mint, *oracle_feed, Some(vault_registry_pda))
+            .await
     }
 
-    pub async fn snapshot_vault_operator_delegation_with_override(
+    pub async fn oracle_set_weight(
         &mut self,
-        vault: Pubkey,
-        operator: Pubkey,
         ncn: Pubkey,
         epoch: u64,
-        vault_operator_delegation_opt: Option<Pubkey>,
-        vault_ncn_ticket_opt: Option<Pubkey>,
+        st_mint: Pubkey,
+        oracle_feed: Pubkey,
+        vault_registry: Option<Pubkey>,
     ) -> TestResult<()> {
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
-
-        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-
-        let epoch_snapshot =
-            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-        let operator_snapshot = OperatorSnapshot::find_program_address(
-            &jito_tip_router_program::id(),
-            &operator,
-            &ncn,
-            epoch,
-        )
-        .0;
-
-        let vault_ncn_ticket = if let Some(vault_ncn_ticket) = vault_ncn_ticket_opt {
-            vault_ncn_ticket
-        } else {
-            VaultNcnTicket::find_program_address(&jito_vault_program::id(), &vault, &ncn).0
-        };
-
-        let vault_operator_delegation =
-            if let Some(vault_operator_delegation) = vault_operator_delegation_opt {
-                vault_operator_delegation
-            } else {
-                VaultOperatorDelegation::find_program_address(
-                    &jito_vault_program::id(),
-                    &vault,
-                    &operator,
-                )
-                .0
-            };
-
-        let ncn_vault_ticket =
-            NcnVaultTicket::find_program_address(&jito_restaking_program::id(), &ncn, &vault).0;
-
         let weight_table =
             WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
-        let ix = SnapshotVaultOperatorDelegationBuilder::new()
+        let ix = OracleSetWeightBuilder::new()
             .epoch_state(epoch_state)
-            .config(config_pda)
-            .restaking_config(restaking_config)
             .ncn(ncn)
-            .operator(operator)
-            .vault(vault)
-            .vault_ncn_ticket(vault_ncn_ticket)
-            .ncn_vault_ticket(ncn_vault_ticket)
-            .vault_operator_delegation(vault_operator_delegation)
             .weight_table(weight_table)
-            .epoch_snapshot(epoch_snapshot)
-            .operator_snapshot(operator_snapshot)
+            .st_mint(st_mint)
+            .oracle_feed(oracle_feed)
+            .vault_registry(vault_registry)
             .epoch(epoch)
             .instruction();
 
@@ -1210,58 +1596,37 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_full_initialize_ballot_box(
-        &mut self,
-        ncn: Pubkey,
-        epoch: u64,
-    ) -> TestResult<()> {
-        self.do_initialize_ballot_box(ncn, epoch).await?;
-        let num_reallocs = (BallotBox::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
-        self.do_realloc_ballot_box(ncn, epoch, num_reallocs).await?;
+    pub async fn do_full_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
+        self.do_initialize_vault_registry(ncn).await?;
+        let num_reallocs = (WeightTable::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+        self.do_realloc_vault_registry(ncn, num_reallocs).await?;
         Ok(())
     }
 
-    pub async fn do_initialize_ballot_box(
-        &mut self,
-        ncn: Pubkey,
-        epoch: u64,
-    ) -> Result<(), TestError> {
+    pub async fn do_initialize_vault_registry(&mut self, ncn: Pubkey) -> TestResult<()> {
         let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let ballot_box = jito_tip_router_core::ballot_box::BallotBox::find_program_address(
-            &jito_tip_router_program::id(),
-            &ncn,
-            epoch,
-        )
-        .0;
-
-        self.initialize_ballot_box(ncn_config, ballot_box, ncn, epoch)
+        self.initialize_vault_registry(&ncn_config, &vault_registry, &ncn)
             .await
     }
 
-    pub async fn initialize_ballot_box(
+    pub async fn initialize_vault_registry(
         &mut self,
-        config: Pubkey,
-        ballot_box: Pubkey,
-        ncn: Pubkey,
-        epoch: u64,
-    ) -> Result<(), TestError> {
-        let (epoch_marker, _, _) =
-            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-
+        ncn_config: &Pubkey,
+        vault_registry: &Pubkey,
+        ncn: &Pubkey,
+    ) -> TestResult<()> {
         let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), ncn);
 
-        let ix = InitializeBallotBoxBuilder::new()
-            .epoch_marker(epoch_marker)
-            .epoch_state(epoch_state)
-            .config(config)
-            .ballot_box(ballot_box)
-            .ncn(ncn)
-            .epoch(epoch)
+        let ix = InitializeVaultRegistryBuilder::new()
+            .config(*ncn_config)
+            .vault_registry(*vault_registry)
+            .ncn(*ncn)
             .account_payer(account_payer)
+            .system_program(system_program::id())
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -1274,46 +1639,34 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_realloc_ballot_box(
+    pub async fn do_realloc_vault_registry(
         &mut self,
         ncn: Pubkey,
-        epoch: u64,
         num_reallocations: u64,
-    ) -> Result<(), TestError> {
+    ) -> TestResult<()> {
         let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-
-        let ballot_box = jito_tip_router_core::ballot_box::BallotBox::find_program_address(
-            &jito_tip_router_program::id(),
-            &ncn,
-            epoch,
-        )
-        .0;
-
-        self.realloc_ballot_box(ncn_config, ballot_box, ncn, epoch, num_reallocations)
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        self.realloc_vault_registry(&ncn, &ncn_config, &vault_registry, num_reallocations)
             .await
     }
 
-    pub async fn realloc_ballot_box(
+    pub async fn realloc_vault_registry(
         &mut self,
-        config: Pubkey,
-        ballot_box: Pubkey,
-        ncn: Pubkey,
-        epoch: u64,
+        ncn: &Pubkey,
+        config: &Pubkey,
+        vault_registry: &Pubkey,
         num_reallocations: u64,
-    ) -> Result<(), TestError> {
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-
+    ) -> TestResult<()> {
         let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), ncn);
 
-        let ix = ReallocBallotBoxBuilder::new()
-            .epoch_state(epoch_state)
-            .config(config)
-            .ballot_box(ballot_box)
-            .ncn(ncn)
-            .epoch(epoch)
+        let ix = ReallocVaultRegistryBuilder::new()
+            .ncn(*ncn)
             .account_payer(account_payer)
+            .config(*config)
+            .vault_registry(*vault_registry)
+            .system_program(system_program::id())
             .instruction();
 
         let ixs = vec![ix; num_reallocations as usize];
@@ -1328,185 +1681,126 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_cast_vote(
+    pub async fn do_register_vault(
         &mut self,
         ncn: Pubkey,
-        operator: Pubkey,
-        operator_admin: &Keypair,
-        meta_merkle_root: [u8; 32],
-        epoch: u64,
-    ) -> Result<(), TestError> {
+        vault: Pubkey,
+        ncn_vault_ticket: Pubkey,
+    ) -> TestResult<()> {
         let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let ballot_box = jito_tip_router_core::ballot_box::BallotBox::find_program_address(
-            &jito_tip_router_program::id(),
-            &ncn,
-            epoch,
-        )
-        .0;
-
-        let epoch_snapshot =
-            jito_tip_router_core::epoch_snapshot::EpochSnapshot::find_program_address(
-                &jito_tip_router_program::id(),
-                &ncn,
-                epoch,
-            )
-            .0;
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let operator_snapshot =
-            jito_tip_router_core::epoch_snapshot::OperatorSnapshot::find_program_address(
-                &jito_tip_router_program::id(),
-                &operator,
-                &ncn,
-                epoch,
-            )
-            .0;
+        // Vaults past MAX_VAULTS are registered to their page
+        let ncn_vault_index = self.get_ncn_vault_index(ncn_vault_ticket).await?;
+        let vault_registry_page = VaultRegistryPage::page_for_vault(ncn_vault_index).map(|page| {
+            VaultRegistryPage::find_program_address(&jito_tip_router_program::id(), &ncn, page).0
+        });
 
-        self.cast_vote(
+        self.register_vault(
             ncn_config,
-            ballot_box,
+            vault_registry,
             ncn,
-            epoch_snapshot,
-            operator_snapshot,
-            operator,
-            operator_admin,
-            meta_merkle_root,
-            epoch,
+            vault,
+            ncn_vault_ticket,
+            vault_registry_page,
         )
         .await
     }
 
-    pub async fn cast_vote(
+    pub async fn register_vault(
         &mut self,
-        ncn_config: Pubkey,
-        ballot_box: Pubkey,
+        config: Pubkey,
+        vault_registry: Pubkey,
         ncn: Pubkey,
-        epoch_snapshot: Pubkey,
-        operator_snapshot: Pubkey,
-        operator: Pubkey,
-        operator_voter: &Keypair,
-        meta_merkle_root: [u8; 32],
-        epoch: u64,
-    ) -> Result<(), TestError> {
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-
-        let ix = CastVoteBuilder::new()
-            .epoch_state(epoch_state)
-            .config(ncn_config)
-            .ballot_box(ballot_box)
+        vault: Pubkey,
+        ncn_vault_ticket: Pubkey,
+        vault_registry_page: Option<Pubkey>,
+    ) -> TestResult<()> {
+        let ix = RegisterVaultBuilder::new()
+            .config(config)
+            .vault_registry(vault_registry)
             .ncn(ncn)
-            .epoch_snapshot(epoch_snapshot)
-            .operator_snapshot(operator_snapshot)
-            .operator(operator)
-            .operator_voter(operator_voter.pubkey())
-            .meta_merkle_root(meta_merkle_root)
-            .epoch(epoch)
+            .vault(vault)
+            .ncn_vault_ticket(ncn_vault_ticket)
+            .vault_registry_page(vault_registry_page)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix],
             Some(&self.payer.pubkey()),
-            &[&self.payer, operator_voter],
+            &[&self.payer],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_set_merkle_root(
+    pub async fn do_admin_register_st_mint(
         &mut self,
         ncn: Pubkey,
-        vote_account: Pubkey,
-        distribution_program: Pubkey,
-        proof: Vec<[u8; 32]>,
-        merkle_root: [u8; 32],
-        max_total_claim: u64,
-        max_num_nodes: u64,
-        epoch: u64,
-    ) -> Result<(), TestError> {
-        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let ballot_box =
-            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-
-        let (distribution_account, distribution_config) = if distribution_program
-            .eq(&jito_tip_distribution_sdk::id())
-        {
-            let tip_distribution_account = derive_tip_distribution_account_address(
-                &distribution_program,
-                &vote_account,
-                epoch - 1,
-            )
-            .0;
+        st_mint: Pubkey,
+        ncn_fee_group: NcnFeeGroup,
+        reward_multiplier_bps: u64,
+        switchboard_feed: Option<Pubkey>,
+        no_feed_weight: Option<u128>,
+    ) -> TestResult<()> {
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-            let tip_distribution_config =
-                jito_tip_distribution_sdk::derive_config_account_address(&distribution_program).0;
-            (tip_distribution_account, tip_distribution_config)
-        } else {
-            let distribution_account = derive_priority_fee_distribution_account_address(
-                &distribution_program,
-                &vote_account,
-                epoch - 1,
-            )
-            .0;
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-            let config = jito_priority_fee_distribution_sdk::derive_config_account_address(
-                &distribution_program,
-            )
-            .0;
-            (distribution_account, config)
-        };
+        let admin = self.payer.pubkey();
 
-        self.set_merkle_root(
-            config,
+        self.admin_register_st_mint(
             ncn,
-            ballot_box,
-            vote_account,
-            distribution_account,
-            distribution_config,
-            distribution_program,
-            proof,
-            merkle_root,
-            max_total_claim,
-            max_num_nodes,
-            epoch,
+            ncn_config,
+            vault_registry,
+            admin,
+            st_mint,
+            ncn_fee_group,
+            reward_multiplier_bps,
+            switchboard_feed,
+            no_feed_weight,
         )
         .await
     }
 
-    pub async fn set_merkle_root(
+    pub async fn admin_register_st_mint(
         &mut self,
-        config: Pubkey,
         ncn: Pubkey,
-        ballot_box: Pubkey,
-        vote_account: Pubkey,
-        distribution_account: Pubkey,
-        distribution_config: Pubkey,
-        distribution_program: Pubkey,
-        proof: Vec<[u8; 32]>,
-        merkle_root: [u8; 32],
-        max_total_claim: u64,
-        max_num_nodes: u64,
-        epoch: u64,
-    ) -> Result<(), TestError> {
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        ncn_config: Pubkey,
+        vault_registry: Pubkey,
+        admin: Pubkey,
+        st_mint: Pubkey,
+        ncn_fee_group: NcnFeeGroup,
+        reward_multiplier_bps: u64,
+        switchboard_feed: Option<Pubkey>,
+        no_feed_weight: Option<u128>,
+    ) -> TestResult<()> {
+        let ix = {
+            let mut builder = AdminRegisterStMintBuilder::new();
+            builder
+                .config(ncn_config)
+                .ncn(ncn)
+                .vault_registry(vault_registry)
+                .admin(admin)
+                .st_mint(st_mint)
+                .ncn_fee_group(ncn_fee_group.group)
+                .reward_multiplier_bps(reward_multiplier_bps);
 
-        let ix = SetMerkleRootBuilder::new()
-            .epoch_state(epoch_state)
-            .config(config)
-            .ncn(ncn)
-            .ballot_box(ballot_box)
-            .vote_account(vote_account)
-            .tip_distribution_account(distribution_account)
-            .tip_distribution_config(distribution_config)
-            .tip_distribution_program(distribution_program)
-            .proof(proof)
-            .merkle_root(merkle_root)
-            .max_total_claim(max_total_claim)
-            .max_num_nodes(max_num_nodes)
-            .epoch(epoch)
-            .instruction();
+            if let Some(switchboard_feed) = switchboard_feed {
+                builder.switchboard_feed(switchboard_feed);
+            }
+
+            if let Some(no_feed_weight) = no_feed_weight {
+                builder.no_feed_weight(no_feed_weight);
+            }
+
+            builder.instruction()
+        };
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
@@ -1518,49 +1812,58 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_admin_set_tie_breaker(
+    pub async fn do_admin_set_st_mint(
         &mut self,
         ncn: Pubkey,
-        meta_merkle_root: [u8; 32],
-        epoch: u64,
-    ) -> Result<(), TestError> {
-        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let ballot_box =
-            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        st_mint: Pubkey,
+        ncn_fee_group: Option<NcnFeeGroup>,
+        reward_multiplier_bps: Option<u64>,
+        switchboard_feed: Option<Pubkey>,
+        no_feed_weight: Option<u128>,
+    ) -> TestResult<()> {
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let tie_breaker_admin = self.payer.pubkey();
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-        self.admin_set_tie_breaker(
-            ncn_config,
-            ballot_box,
+        let admin = self.payer.pubkey();
+
+        self.admin_set_st_mint(
             ncn,
-            tie_breaker_admin,
-            meta_merkle_root,
-            epoch,
+            ncn_config,
+            vault_registry,
+            admin,
+            st_mint,
+            ncn_fee_group,
+            reward_multiplier_bps,
+            switchboard_feed,
+            no_feed_weight,
         )
         .await
     }
 
-    pub async fn admin_set_tie_breaker(
+    pub async fn do_admin_set_st_mint_oracle(
         &mut self,
-        ncn_config: Pubkey,
-        ballot_box: Pubkey,
         ncn: Pubkey,
-        tie_breaker_admin: Pubkey,
-        meta_merkle_root: [u8; 32],
-        epoch: u64,
-    ) -> Result<(), TestError> {
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        st_mint: Pubkey,
+        oracle_type: OracleType,
+        oracle_feed: Pubkey,
+    ) -> TestResult<()> {
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let ix = AdminSetTieBreakerBuilder::new()
-            .epoch_state(epoch_state)
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = AdminSetStMintBuilder::new()
             .config(ncn_config)
-            .ballot_box(ballot_box)
             .ncn(ncn)
-            .tie_breaker_admin(tie_breaker_admin)
-            .meta_merkle_root(meta_merkle_root)
-            .epoch(epoch)
+            .vault_registry(vault_registry)
+            .admin(self.payer.pubkey())
+            .st_mint(st_mint)
+            .switchboard_feed(oracle_feed)
+            .oracle_type(oracle_type as u8)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -1573,55 +1876,218 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_full_initialize_base_reward_router(
+    pub async fn do_admin_set_st_mint_oracle_guards(
+        &mut self,
+        ncn: Pubkey,
+        st_mint: Pubkey,
+        max_staleness: Option<u64>,
+        max_confidence_bps: Option<u16>,
+        max_weight_change_bps: Option<u16>,
+    ) -> TestResult<()> {
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = {
+            let mut builder = AdminSetStMintBuilder::new();
+            builder
+                .config(ncn_config)
+                .ncn(ncn)
+                .vault_registry(vault_registry)
+                .admin(self.payer.pubkey())
+                .st_mint(st_mint);
+
+            if let Some(max_staleness) = max_staleness {
+                builder.max_staleness(max_staleness);
+            }
+
+            if let Some(max_confidence_bps) = max_confidence_bps {
+                builder.max_confidence_bps(max_confidence_bps);
+            }
+
+            if let Some(max_weight_change_bps) = max_weight_change_bps {
+                builder.max_weight_change_bps(max_weight_change_bps);
+            }
+
+            builder.instruction()
+        };
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_admin_deactivate_vault(
         &mut self,
         ncn: Pubkey,
+        vault: Pubkey,
         epoch: u64,
     ) -> TestResult<()> {
-        self.do_initialize_base_reward_router(ncn, epoch).await?;
-        let num_reallocs =
-            (BaseRewardRouter::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
-        self.do_realloc_base_reward_router(ncn, epoch, num_reallocs)
-            .await?;
-        Ok(())
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        // Vaults past MAX_VAULTS are deactivated in their page
+        let mut vault_registry_page = None;
+        if !self.get_vault_registry(ncn).await?.has_vault(&vault) {
+            for page in self.get_vault_registry_pages(ncn).await? {
+                let page_account = self.banks_client.get_account(page).await?.unwrap();
+                if VaultRegistryPage::try_from_slice_unchecked(page_account.data.as_slice())
+                    .unwrap()
+                    .has_vault(&vault)
+                {
+                    vault_registry_page = Some(page);
+                }
+            }
+        }
+
+        let ix = AdminDeactivateVaultBuilder::new()
+            .config(ncn_config)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .admin(self.payer.pubkey())
+            .vault(vault)
+            .epoch(epoch)
+            .vault_registry_page(vault_registry_page)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn do_initialize_base_reward_router(
+    pub async fn do_admin_deactivate_st_mint(
         &mut self,
         ncn: Pubkey,
+        st_mint: Pubkey,
         epoch: u64,
     ) -> TestResult<()> {
-        let (base_reward_router, _, _) =
-            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let (base_reward_receiver, _, _) =
-            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-        self.initialize_base_reward_router(ncn, base_reward_router, base_reward_receiver, epoch)
-            .await
+        let vault_registry_pages = self
+            .get_vault_registry_pages(ncn)
+            .await?
+            .into_iter()
+            .map(|page| AccountMeta::new_readonly(page, false))
+            .collect::<Vec<_>>();
+
+        let ix = AdminDeactivateStMintBuilder::new()
+            .config(ncn_config)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .admin(self.payer.pubkey())
+            .st_mint(st_mint)
+            .epoch(epoch)
+            .add_remaining_accounts(&vault_registry_pages)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn initialize_base_reward_router(
+    pub async fn admin_set_st_mint(
+        &mut self,
+        ncn: Pubkey,
+        ncn_config: Pubkey,
+        vault_registry: Pubkey,
+        admin: Pubkey,
+        st_mint: Pubkey,
+        ncn_fee_group: Option<NcnFeeGroup>,
+        reward_multiplier_bps: Option<u64>,
+        switchboard_feed: Option<Pubkey>,
+        no_feed_weight: Option<u128>,
+    ) -> TestResult<()> {
+        let ix = {
+            let mut builder = AdminSetStMintBuilder::new();
+            builder
+                .config(ncn_config)
+                .ncn(ncn)
+                .vault_registry(vault_registry)
+                .admin(admin)
+                .st_mint(st_mint);
+
+            if let Some(ncn_fee_group) = ncn_fee_group {
+                builder.ncn_fee_group(ncn_fee_group.group);
+            }
+
+            if let Some(reward_multiplier_bps) = reward_multiplier_bps {
+                builder.reward_multiplier_bps(reward_multiplier_bps);
+            }
+
+            if let Some(switchboard_feed) = switchboard_feed {
+                builder.switchboard_feed(switchboard_feed);
+            }
+
+            if let Some(no_feed_weight) = no_feed_weight {
+                builder.no_feed_weight(no_feed_weight);
+            }
+
+            builder.instruction()
+        };
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_initialize_epoch_snapshot(
         &mut self,
         ncn: Pubkey,
-        base_reward_router: Pubkey,
-        base_reward_receiver: Pubkey,
         epoch: u64,
     ) -> TestResult<()> {
+        self.initialize_epoch_snapshot(ncn, epoch).await
+    }
+
+    pub async fn initialize_epoch_snapshot(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
         let (epoch_marker, _, _) =
             EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let epoch_snapshot =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
         let (account_payer, _, _) =
             AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-        let ix = InitializeBaseRewardRouterBuilder::new()
+        let ix = InitializeEpochSnapshotBuilder::new()
             .epoch_marker(epoch_marker)
             .epoch_state(epoch_state)
+            .config(config_pda)
             .ncn(ncn)
-            .base_reward_router(base_reward_router)
-            .base_reward_receiver(base_reward_receiver)
+            .weight_table(weight_table)
+            .epoch_snapshot(epoch_snapshot)
             .account_payer(account_payer)
             .system_program(system_program::id())
             .epoch(epoch)
@@ -1637,79 +2103,76 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_initialize_ncn_reward_router(
+    pub async fn do_full_initialize_operator_snapshot(
         &mut self,
-        ncn_fee_group: NcnFeeGroup,
-        ncn: Pubkey,
         operator: Pubkey,
+        ncn: Pubkey,
         epoch: u64,
     ) -> TestResult<()> {
-        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
-            &jito_tip_router_program::id(),
-            ncn_fee_group,
-            &operator,
-            &ncn,
-            epoch,
-        );
-
-        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
-            &jito_tip_router_program::id(),
-            ncn_fee_group,
-            &operator,
-            &ncn,
-            epoch,
-        );
-
-        let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
-            &jito_tip_router_program::id(),
-            &operator,
-            &ncn,
-            epoch,
-        );
+        self.do_initialize_operator_snapshot(operator, ncn, epoch)
+            .await?;
+        let num_reallocs =
+            (OperatorSnapshot::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+        self.do_realloc_operator_snapshot(operator, ncn, epoch, num_reallocs)
+            .await?;
 
-        self.initialize_ncn_reward_router(
-            ncn_fee_group,
-            ncn,
-            operator,
-            operator_snapshot,
-            ncn_reward_router,
-            ncn_reward_receiver,
-            epoch,
-        )
-        .await
+        // Delegations from vaults past MAX_VAULTS are snapshotted into operator snapshot pages,
+        // inactive operators are finalized without any
+        if !self
+            .get_operator_snapshot(operator, ncn, epoch)
+            .await?
+            .finalized()
+        {
+            let page_count = self.get_vault_registry(ncn).await?.page_count();
+            for page in 0..page_count {
+                self.do_initialize_operator_snapshot_page(operator, ncn, epoch, page)
+                    .await?;
+            }
+        }
+        Ok(())
     }
 
-    pub async fn initialize_ncn_reward_router(
+    pub async fn do_initialize_operator_snapshot_page(
         &mut self,
-        ncn_fee_group: NcnFeeGroup,
-        ncn: Pubkey,
         operator: Pubkey,
-        operator_snapshot: Pubkey,
-        ncn_reward_router: Pubkey,
-        ncn_reward_receiver: Pubkey,
+        ncn: Pubkey,
         epoch: u64,
+        page: u64,
     ) -> TestResult<()> {
         let (epoch_marker, _, _) =
             EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
-
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let operator_snapshot = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        )
+        .0;
+        let operator_snapshot_page = OperatorSnapshotPage::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+            page,
+        )
+        .0;
 
         let (account_payer, _, _) =
             AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-        let ix = InitializeNcnRewardRouterBuilder::new()
+        let ix = InitializeOperatorSnapshotPageBuilder::new()
             .epoch_marker(epoch_marker)
             .epoch_state(epoch_state)
             .ncn(ncn)
             .operator(operator)
             .operator_snapshot(operator_snapshot)
-            .ncn_reward_router(ncn_reward_router)
-            .ncn_reward_receiver(ncn_reward_receiver)
+            .operator_snapshot_page(operator_snapshot_page)
             .account_payer(account_payer)
             .system_program(system_program::id())
-            .ncn_fee_group(ncn_fee_group.group)
             .epoch(epoch)
+            .page(page)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -1722,173 +2185,230 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_route_base_rewards(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
-        let (epoch_snapshot, _, _) =
-            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
-
-        let (ballot_box, _, _) =
-            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
-
-        let (base_reward_router, _, _) =
-            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
-
-        let (base_reward_receiver, _, _) =
-            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
-
-        //Pretty close to max
-        let max_iterations: u16 = BaseRewardRouter::MAX_ROUTE_BASE_ITERATIONS;
-
-        let mut still_routing = true;
-        while still_routing {
-            self.route_base_rewards(
-                ncn,
-                epoch_snapshot,
-                ballot_box,
-                base_reward_router,
-                base_reward_receiver,
-                max_iterations,
-                epoch,
-            )
-            .await?;
-
-            let base_reward_router_account = self.get_base_reward_router(ncn, epoch).await?;
-
-            still_routing = base_reward_router_account.still_routing();
-        }
-
-        Ok(())
+    pub async fn do_initialize_operator_snapshot(
+        &mut self,
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        self.initialize_operator_snapshot(operator, ncn, epoch)
+            .await
     }
 
-    pub async fn route_base_rewards(
+    pub async fn initialize_operator_snapshot(
         &mut self,
+        operator: Pubkey,
         ncn: Pubkey,
-        epoch_snapshot: Pubkey,
-        ballot_box: Pubkey,
-        base_reward_router: Pubkey,
-        base_reward_receiver: Pubkey,
-        max_iterations: u16,
         epoch: u64,
     ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let ncn_operator_state =
+            NcnOperatorState::find_program_address(&jito_restaking_program::id(), &ncn, &operator)
+                .0;
+        let epoch_snapshot =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let operator_snapshot = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        )
+        .0;
 
-        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-        let ix = RouteBaseRewardsBuilder::new()
+        let ix = InitializeOperatorSnapshotBuilder::new()
+            .epoch_marker(epoch_marker)
             .epoch_state(epoch_state)
-            .config(config)
+            .config(config_pda)
             .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
             .epoch_snapshot(epoch_snapshot)
-            .ballot_box(ballot_box)
-            .base_reward_router(base_reward_router)
-            .base_reward_receiver(base_reward_receiver)
-            .max_iterations(max_iterations)
+            .operator_snapshot(operator_snapshot)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
             .epoch(epoch)
             .instruction();
 
-        let blockhash = self.get_best_latest_blockhash().await?;
-        let tx = &Transaction::new_signed_with_payer(
-            &[
-                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
-                ix,
-            ],
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
             Some(&self.payer.pubkey()),
             &[&self.payer],
             blockhash,
-        );
+        ))
+        .await
+    }
 
-        self.process_transaction(tx).await
+    pub async fn do_snapshot_vault_operator_delegation(
+        &mut self,
+        vault: Pubkey,
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        self.snapshot_vault_operator_delegation(vault, operator, ncn, epoch)
+            .await
     }
 
-    pub async fn do_route_ncn_rewards(
+    pub async fn snapshot_vault_operator_delegation(
         &mut self,
-        ncn_fee_group: NcnFeeGroup,
+        vault: Pubkey,
+        operator: Pubkey,
         ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        self.snapshot_vault_operator_delegation_with_override(
+            vault, operator, ncn, epoch, None, None,
+        )
+        .await
+    }
+
+    pub async fn snapshot_vault_operator_delegation_with_override(
+        &mut self,
+        vault: Pubkey,
         operator: Pubkey,
+        ncn: Pubkey,
         epoch: u64,
+        vault_operator_delegation_opt: Option<Pubkey>,
+        vault_ncn_ticket_opt: Option<Pubkey>,
     ) -> TestResult<()> {
-        let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
-            &jito_tip_router_program::id(),
-            &operator,
-            &ncn,
-            epoch,
-        );
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
 
-        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
-            &jito_tip_router_program::id(),
-            ncn_fee_group,
-            &operator,
-            &ncn,
-            epoch,
-        );
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+        let epoch_snapshot =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let operator_snapshot = OperatorSnapshot::find_program_address(
             &jito_tip_router_program::id(),
-            ncn_fee_group,
             &operator,
             &ncn,
             epoch,
-        );
+        )
+        .0;
 
-        let max_iterations: u16 = NcnRewardRouter::MAX_ROUTE_NCN_ITERATIONS;
-        let mut still_routing = true;
+        let vault_ncn_ticket = if let Some(vault_ncn_ticket) = vault_ncn_ticket_opt {
+            vault_ncn_ticket
+        } else {
+            VaultNcnTicket::find_program_address(&jito_vault_program::id(), &vault, &ncn).0
+        };
 
-        while still_routing {
-            self.route_ncn_rewards(
-                ncn_fee_group,
-                ncn,
-                operator,
-                operator_snapshot,
-                ncn_reward_router,
-                ncn_reward_receiver,
-                max_iterations,
+        let vault_operator_delegation =
+            if let Some(vault_operator_delegation) = vault_operator_delegation_opt {
+                vault_operator_delegation
+            } else {
+                VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault,
+                    &operator,
+                )
+                .0
+            };
+
+        let ncn_vault_ticket =
+            NcnVaultTicket::find_program_address(&jito_restaking_program::id(), &ncn, &vault).0;
+
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        // Vaults past MAX_VAULTS are snapshotted from their weight table page into their
+        // operator snapshot page
+        let page =
+            VaultRegistryPage::page_for_vault(self.get_ncn_vault_index(ncn_vault_ticket).await?);
+        let weight_table_page = page.map(|page| {
+            WeightTablePage::find_program_address(&jito_tip_router_program::id(), &ncn, epoch, page)
+                .0
+        });
+        let operator_snapshot_page = page.map(|page| {
+            OperatorSnapshotPage::find_program_address(
+                &jito_tip_router_program::id(),
+                &operator,
+                &ncn,
                 epoch,
+                page,
             )
-            .await?;
+            .0
+        });
 
-            let ncn_reward_router_account = self
-                .get_ncn_reward_router(ncn_fee_group, operator, ncn, epoch)
-                .await?;
+        let epoch_state_page = self
+            .get_epoch_state_page_for_operator(ncn, operator, epoch)
+            .await?;
 
-            still_routing = ncn_reward_router_account.still_routing();
-        }
+        let ix = SnapshotVaultOperatorDelegationBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config_pda)
+            .restaking_config(restaking_config)
+            .ncn(ncn)
+            .operator(operator)
+            .vault(vault)
+            .vault_ncn_ticket(vault_ncn_ticket)
+            .ncn_vault_ticket(ncn_vault_ticket)
+            .vault_operator_delegation(vault_operator_delegation)
+            .weight_table(weight_table)
+            .epoch_snapshot(epoch_snapshot)
+            .operator_snapshot(operator_snapshot)
+            .weight_table_page(weight_table_page)
+            .operator_snapshot_page(operator_snapshot_page)
+            .epoch_state_page(epoch_state_page)
+            .epoch(epoch)
+            .instruction();
 
-        Ok(())
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn route_ncn_rewards(
+    pub async fn do_apply_stake_weight_caps(
         &mut self,
-        ncn_fee_group: NcnFeeGroup,
-        ncn: Pubkey,
         operator: Pubkey,
-        operator_snapshot: Pubkey,
-        ncn_reward_router: Pubkey,
-        ncn_reward_receiver: Pubkey,
-        max_iterations: u16,
+        ncn: Pubkey,
         epoch: u64,
     ) -> TestResult<()> {
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let epoch_snapshot =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let operator_snapshot = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        )
+        .0;
 
-        let ix = RouteNcnRewardsBuilder::new()
+        let operator_snapshot_pages = self
+            .get_operator_snapshot_pages(operator, ncn, epoch)
+            .await?
+            .into_iter()
+            .map(|page| AccountMeta::new_readonly(page, false))
+            .collect::<Vec<_>>();
+
+        let ix = ApplyStakeWeightCapsBuilder::new()
             .epoch_state(epoch_state)
             .ncn(ncn)
+            .epoch_snapshot(epoch_snapshot)
             .operator(operator)
             .operator_snapshot(operator_snapshot)
-            .ncn_reward_router(ncn_reward_router)
-            .ncn_reward_receiver(ncn_reward_receiver)
-            .ncn_fee_group(ncn_fee_group.group)
-            .max_iterations(max_iterations)
             .epoch(epoch)
+            .add_remaining_accounts(&operator_snapshot_pages)
             .instruction();
 
-        let blockhash = self.get_best_latest_blockhash().await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[
-                // TODO: should make this instruction much more efficient
-                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
-                ix,
-            ],
+            &[ix],
             Some(&self.payer.pubkey()),
             &[&self.payer],
             blockhash,
@@ -1896,151 +2416,144 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_distribute_base_rewards(
+    pub async fn do_full_initialize_ballot_box(
         &mut self,
-        base_fee_group: BaseFeeGroup,
         ncn: Pubkey,
         epoch: u64,
-        pool_root: &PoolRoot,
     ) -> TestResult<()> {
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        self.do_initialize_ballot_box(ncn, epoch).await?;
+        let num_reallocs = (BallotBox::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+        self.do_realloc_ballot_box(ncn, epoch, num_reallocs).await?;
 
-        let (ncn_config, _, _) =
-            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+        // Votes of operators past MAX_OPERATORS are kept in pages
+        let operator_count = {
+            let raw_account = self.banks_client.get_account(ncn).await?.unwrap();
+            jito_restaking_core::ncn::Ncn::try_from_slice_unchecked(raw_account.data.as_slice())
+                .unwrap()
+                .operator_count()
+        };
+        for page in 0..BallotBoxPage::pages_needed(operator_count) {
+            self.do_initialize_ballot_box_page(ncn, epoch, page).await?;
+        }
+        Ok(())
+    }
 
-        let (base_reward_router, _, _) =
-            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+    pub async fn do_initialize_ballot_box(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let ncn_config_account = self.get_ncn_config(ncn).await?;
-        let base_fee_wallet = ncn_config_account
-            .fee_config
-            .base_fee_wallet(base_fee_group)
-            .unwrap();
-        let base_fee_wallet_ata = get_associated_token_address(base_fee_wallet, &JITOSOL_MINT);
-        let create_base_fee_wallet_ata_ix = create_associated_token_account_idempotent(
-            &self.payer.pubkey(),
-            base_fee_wallet,
-            &JITOSOL_MINT,
-            &spl_token_interface::id(),
-        );
-        let (base_reward_receiver, _, _) =
-            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let ballot_box = jito_tip_router_core::ballot_box::BallotBox::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            epoch,
+        )
+        .0;
 
-        // stake pool accounts
-        let stake_pool = pool_root.pool_address;
-        let (stake_pool_withdraw_authority, _) = find_withdraw_authority_program_address(
-            &jito_tip_router_program::spl_stake_pool_id(),
-            &stake_pool,
-        );
-        let reserve_stake = pool_root.reserve_stake;
-        let manager_fee_account = pool_root.manager_fee_account;
-        let referrer_pool_tokens_account = pool_root.referrer_pool_tokens_account;
+        self.initialize_ballot_box(ncn_config, ballot_box, ncn, epoch)
+            .await
+    }
 
-        let ix = DistributeBaseRewardsBuilder::new()
+    pub async fn initialize_ballot_box(
+        &mut self,
+        config: Pubkey,
+        ballot_box: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = InitializeBallotBoxBuilder::new()
+            .epoch_marker(epoch_marker)
             .epoch_state(epoch_state)
-            .config(ncn_config)
+            .config(config)
+            .ballot_box(ballot_box)
             .ncn(ncn)
-            .base_reward_router(base_reward_router)
-            .base_reward_receiver(base_reward_receiver)
-            .base_fee_wallet(*base_fee_wallet)
-            .base_fee_wallet_ata(base_fee_wallet_ata)
-            .stake_pool_program(jito_tip_router_program::spl_stake_pool_id())
-            .stake_pool(stake_pool)
-            .stake_pool_withdraw_authority(stake_pool_withdraw_authority)
-            .reserve_stake(reserve_stake)
-            .manager_fee_account(manager_fee_account)
-            .referrer_pool_tokens_account(referrer_pool_tokens_account)
-            .pool_mint(JITOSOL_MINT)
-            .token_program(spl_token_interface::id())
-            .system_program(system_program::id())
-            .base_fee_group(base_fee_group.group)
             .epoch(epoch)
+            .account_payer(account_payer)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
-
-        let transaction = Transaction::new_signed_with_payer(
-            &[create_base_fee_wallet_ata_ix, ix],
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
             Some(&self.payer.pubkey()),
             &[&self.payer],
             blockhash,
-        );
-
-        self.process_transaction(&transaction).await
+        ))
+        .await
     }
 
-    pub async fn do_distribute_base_ncn_reward_route(
+    pub async fn do_initialize_vault_registry_page(
         &mut self,
-        ncn_fee_group: NcnFeeGroup,
-        operator: Pubkey,
         ncn: Pubkey,
-        epoch: u64,
-    ) -> TestResult<()> {
-        let (ncn_config, _, _) =
-            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+        page: u64,
+    ) -> Result<(), TestError> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let vault_registry_page =
+            VaultRegistryPage::find_program_address(&jito_tip_router_program::id(), &ncn, page).0;
 
-        let (base_reward_router, _, _) =
-            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
-        let (base_reward_receiver, _, _) =
-            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
 
-        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
-            &jito_tip_router_program::id(),
-            ncn_fee_group,
-            &operator,
-            &ncn,
-            epoch,
-        );
-        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
-            &jito_tip_router_program::id(),
-            ncn_fee_group,
-            &operator,
-            &ncn,
-            epoch,
-        );
+        let ix = InitializeVaultRegistryPageBuilder::new()
+            .config(ncn_config)
+            .vault_registry(vault_registry)
+            .vault_registry_page(vault_registry_page)
+            .ncn(ncn)
+            .account_payer(account_payer)
+            .page(page)
+            .instruction();
 
-        self.distribute_base_ncn_reward_route(
-            ncn_fee_group,
-            operator,
-            ncn,
-            ncn_config,
-            base_reward_router,
-            base_reward_receiver,
-            ncn_reward_router,
-            ncn_reward_receiver,
-            epoch,
-        )
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
         .await
     }
 
-    pub async fn distribute_base_ncn_reward_route(
+    pub async fn do_initialize_ballot_box_page(
         &mut self,
-        ncn_fee_group: NcnFeeGroup,
-        operator: Pubkey,
         ncn: Pubkey,
-        ncn_config: Pubkey,
-        base_reward_router: Pubkey,
-        base_reward_receiver: Pubkey,
-        ncn_reward_router: Pubkey,
-        ncn_reward_receiver: Pubkey,
         epoch: u64,
-    ) -> TestResult<()> {
+        page: u64,
+    ) -> Result<(), TestError> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let ballot_box_page =
+            BallotBoxPage::find_program_address(&jito_tip_router_program::id(), &ncn, epoch, page)
+                .0;
 
-        let ix = DistributeBaseNcnRewardRouteBuilder::new()
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = InitializeBallotBoxPageBuilder::new()
+            .epoch_marker(epoch_marker)
             .epoch_state(epoch_state)
             .config(ncn_config)
+            .ballot_box(ballot_box)
+            .ballot_box_page(ballot_box_page)
             .ncn(ncn)
-            .operator(operator)
-            .base_reward_router(base_reward_router)
-            .base_reward_receiver(base_reward_receiver)
-            .ncn_reward_router(ncn_reward_router)
-            .ncn_reward_receiver(ncn_reward_receiver)
-            .system_program(system_program::id())
-            .ncn_fee_group(ncn_fee_group.group)
             .epoch(epoch)
+            .page(page)
+            .account_payer(account_payer)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -2053,86 +2566,53 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_distribute_ncn_operator_rewards(
+    pub async fn do_realloc_ballot_box(
         &mut self,
-        ncn_fee_group: NcnFeeGroup,
-        operator: Pubkey,
         ncn: Pubkey,
         epoch: u64,
-        pool_root: &PoolRoot,
-    ) -> TestResult<()> {
+        num_reallocations: u64,
+    ) -> Result<(), TestError> {
         let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
-            &jito_tip_router_program::id(),
-            ncn_fee_group,
-            &operator,
-            &ncn,
-            epoch,
-        );
-
-        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
-            &jito_tip_router_program::id(),
-            ncn_fee_group,
-            &operator,
-            &ncn,
-            epoch,
-        );
-
-        // Add stake pool accounts
-        let stake_pool = pool_root.pool_address;
-        let (stake_pool_withdraw_authority, _) = find_withdraw_authority_program_address(
-            &jito_tip_router_program::spl_stake_pool_id(),
-            &stake_pool,
-        );
-        let reserve_stake = pool_root.reserve_stake;
-        let manager_fee_account = pool_root.manager_fee_account;
-        let referrer_pool_tokens_account = pool_root.referrer_pool_tokens_account;
-
-        let operator_ata = get_associated_token_address(&operator, &JITOSOL_MINT);
-        let operator_ata_ix = create_associated_token_account_idempotent(
-            &self.payer.pubkey(),
-            &operator,
-            &JITOSOL_MINT,
-            &spl_token_interface::id(),
-        );
-
-        let operator_snapshot = OperatorSnapshot::find_program_address(
+        let ballot_box = jito_tip_router_core::ballot_box::BallotBox::find_program_address(
             &jito_tip_router_program::id(),
-            &operator,
             &ncn,
             epoch,
         )
         .0;
 
+        self.realloc_ballot_box(ncn_config, ballot_box, ncn, epoch, num_reallocations)
+            .await
+    }
+
+    pub async fn realloc_ballot_box(
+        &mut self,
+        config: Pubkey,
+        ballot_box: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+        num_reallocations: u64,
+    ) -> Result<(), TestError> {
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
-        let ix = DistributeNcnOperatorRewardsBuilder::new()
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = ReallocBallotBoxBuilder::new()
             .epoch_state(epoch_state)
-            .config(ncn_config)
+            .config(config)
+            .ballot_box(ballot_box)
             .ncn(ncn)
-            .operator(operator)
-            .operator_ata(operator_ata)
-            .operator_snapshot(operator_snapshot)
-            .ncn_reward_router(ncn_reward_router)
-            .ncn_reward_receiver(ncn_reward_receiver)
-            .stake_pool_program(jito_tip_router_program::spl_stake_pool_id())
-            .stake_pool(stake_pool)
-            .stake_pool_withdraw_authority(stake_pool_withdraw_authority)
-            .reserve_stake(reserve_stake)
-            .manager_fee_account(manager_fee_account)
-            .referrer_pool_tokens_account(referrer_pool_tokens_account)
-            .pool_mint(JITOSOL_MINT)
-            .token_program(spl_token_interface::id())
-            .system_program(system_program::id())
-            .ncn_fee_group(ncn_fee_group.group)
             .epoch(epoch)
+            .account_payer(account_payer)
             .instruction();
 
+        let ixs = vec![ix; num_reallocations as usize];
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[operator_ata_ix, ix],
+            &ixs,
             Some(&self.payer.pubkey()),
             &[&self.payer],
             blockhash,
@@ -2140,495 +2620,3072 @@ impl TipRouterClient {
         .await
     }
 
-    pub async fn do_distribute_ncn_vault_rewards(
+    pub async fn do_cast_vote(
         &mut self,
-        ncn_fee_group: NcnFeeGroup,
-        vault: Pubkey,
-        operator: Pubkey,
         ncn: Pubkey,
+        operator: Pubkey,
+        operator_admin: &Keypair,
+        meta_merkle_root: [u8; 32],
         epoch: u64,
-        pool_root: &PoolRoot,
-    ) -> TestResult<()> {
+    ) -> Result<(), TestError> {
         let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
-            &jito_tip_router_program::id(),
-            ncn_fee_group,
-            &operator,
-            &ncn,
-            epoch,
-        );
-        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
-            &jito_tip_router_program::id(),
-            ncn_fee_group,
-            &operator,
-            &ncn,
-            epoch,
-        );
-
-        let operator_snapshot = OperatorSnapshot::find_program_address(
+        let ballot_box = jito_tip_router_core::ballot_box::BallotBox::find_program_address(
             &jito_tip_router_program::id(),
-            &operator,
             &ncn,
             epoch,
         )
         .0;
 
-        // Add stake pool accounts
-        let stake_pool = pool_root.pool_address;
-        let (stake_pool_withdraw_authority, _) = find_withdraw_authority_program_address(
-            &jito_tip_router_program::spl_stake_pool_id(),
-            &stake_pool,
-        );
-        let reserve_stake = pool_root.reserve_stake;
-        let manager_fee_account = pool_root.manager_fee_account;
-        let referrer_pool_tokens_account = pool_root.referrer_pool_tokens_account;
+        let epoch_snapshot =
+            jito_tip_router_core::epoch_snapshot::EpochSnapshot::find_program_address(
+                &jito_tip_router_program::id(),
+                &ncn,
+                epoch,
+            )
+            .0;
 
-        let vault_ata = get_associated_token_address(&vault, &JITOSOL_MINT);
+        let operator_snapshot =
+            jito_tip_router_core::epoch_snapshot::OperatorSnapshot::find_program_address(
+                &jito_tip_router_program::id(),
+                &operator,
+                &ncn,
+                epoch,
+            )
+            .0;
 
-        let vault_ata_ix = create_associated_token_account_idempotent(
-            &self.payer.pubkey(),
-            &vault,
-            &JITOSOL_MINT,
-            &spl_token_interface::id(),
-        );
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        self.cast_vote(
+            ncn_config,
+            ballot_box,
+            ncn,
+            epoch_snapshot,
+            operator_snapshot,
+            operator,
+            operator_admin,
+            meta_merkle_root,
+            epoch,
+        )
+        .await
+    }
 
-        let ix = DistributeNcnVaultRewardsBuilder::new()
-            .epoch_state(epoch_state)
-            .config(ncn_config)
-            .ncn(ncn)
-            .operator(operator)
-            .vault(vault)
-            .vault_ata(vault_ata)
-            .operator_snapshot(operator_snapshot)
-            .ncn_reward_router(ncn_reward_router)
-            .ncn_reward_receiver(ncn_reward_receiver)
-            .stake_pool_program(jito_tip_router_program::spl_stake_pool_id())
-            .stake_pool(stake_pool)
-            .stake_pool_withdraw_authority(stake_pool_withdraw_authority)
-            .reserve_stake(reserve_stake)
-            .manager_fee_account(manager_fee_account)
-            .referrer_pool_tokens_account(referrer_pool_tokens_account)
-            .pool_mint(JITOSOL_MINT)
-            .token_program(spl_token_interface::id())
-            .system_program(system_program::id())
-            .ncn_fee_group(ncn_fee_group.group)
-            .epoch(epoch)
-            .instruction();
+    pub async fn do_cast_vote_with_events(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_voter: &Keypair,
+        meta_merkle_root: [u8; 32],
+        epoch: u64,
+    ) -> TestResult<Vec<TipRouterEvent>> {
+        let ix = Self::cast_vote_ix(ncn, operator, operator_voter, meta_merkle_root, epoch);
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
-        self.process_transaction(&Transaction::new_signed_with_payer(
-            &[vault_ata_ix, ix],
+        self.process_transaction_with_events(&Transaction::new_signed_with_payer(
+            &[ix],
             Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &[&self.payer, operator_voter],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_realloc_operator_snapshot(
-        &mut self,
-        operator: Pubkey,
+    fn cast_vote_ix(
         ncn: Pubkey,
+        operator: Pubkey,
+        operator_voter: &Keypair,
+        meta_merkle_root: [u8; 32],
         epoch: u64,
-        num_reallocations: u64,
-    ) -> Result<(), TestError> {
-        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
-        let ncn_operator_state =
-            NcnOperatorState::find_program_address(&jito_restaking_program::id(), &ncn, &operator)
-                .0;
-        let epoch_snapshot =
-            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-        let operator_snapshot = OperatorSnapshot::find_program_address(
-            &jito_tip_router_program::id(),
-            &operator,
-            &ncn,
-            epoch,
-        )
-        .0;
-
-        self.realloc_operator_snapshot(
-            config,
-            restaking_config,
-            ncn,
-            operator,
-            ncn_operator_state,
-            epoch_snapshot,
-            operator_snapshot,
-            epoch,
-            num_reallocations,
-        )
-        .await
+    ) -> Instruction {
+        CastVoteBuilder::new()
+            .epoch_state(
+                EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .ballot_box(
+                BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .ncn(ncn)
+            .epoch_snapshot(
+                EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .operator_snapshot(
+                OperatorSnapshot::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &operator,
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .operator(operator)
+            .operator_voter(operator_voter.pubkey())
+            .operator_vote_key(Some(
+                OperatorVoteKey::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &operator,
+                    &ncn,
+                )
+                .0,
+            ))
+            .meta_merkle_root(meta_merkle_root)
+            .epoch(epoch)
+            .instruction()
     }
 
-    pub async fn realloc_operator_snapshot(
+    pub async fn cast_vote(
         &mut self,
-        config: Pubkey,
-        restaking_config: Pubkey,
+        ncn_config: Pubkey,
+        ballot_box: Pubkey,
         ncn: Pubkey,
-        operator: Pubkey,
-        ncn_operator_state: Pubkey,
         epoch_snapshot: Pubkey,
         operator_snapshot: Pubkey,
+        operator: Pubkey,
+        operator_voter: &Keypair,
+        meta_merkle_root: [u8; 32],
         epoch: u64,
-        num_reallocations: u64,
     ) -> Result<(), TestError> {
         let epoch_state =
             EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
 
-        let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        let ballot_box_page = self
+            .get_ballot_box_page_for_operator(ncn, operator, epoch)
+            .await?;
 
-        let ix = ReallocOperatorSnapshotBuilder::new()
+        let ix = CastVoteBuilder::new()
             .epoch_state(epoch_state)
-            .config(config)
-            .restaking_config(restaking_config)
+            .config(ncn_config)
+            .ballot_box(ballot_box)
+            .ballot_box_page(ballot_box_page)
             .ncn(ncn)
-            .operator(operator)
-            .ncn_operator_state(ncn_operator_state)
             .epoch_snapshot(epoch_snapshot)
             .operator_snapshot(operator_snapshot)
-            .account_payer(account_payer)
-            .system_program(system_program::id())
+            .operator(operator)
+            .operator_voter(operator_voter.pubkey())
+            .operator_vote_key(Some(
+                OperatorVoteKey::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &operator,
+                    &ncn,
+                )
+                .0,
+            ))
+            .meta_merkle_root(meta_merkle_root)
             .epoch(epoch)
             .instruction();
 
-        let ixs = vec![ix; num_reallocations as usize];
-
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &ixs,
+            &[ix],
             Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &[&self.payer, operator_voter],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_realloc_base_reward_router(
+    pub async fn do_withdraw_vote(
         &mut self,
         ncn: Pubkey,
+        operator: Pubkey,
+        operator_voter: &Keypair,
         epoch: u64,
-        num_reallocations: u64,
-    ) -> Result<(), TestError> {
-        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let base_reward_router =
-            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+    ) -> TestResult<()> {
+        let ix = WithdrawVoteBuilder::new()
+            .epoch_state(
+                EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .ballot_box(
+                BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .ncn(ncn)
+            .operator(operator)
+            .operator_voter(operator_voter.pubkey())
+            .operator_vote_key(Some(
+                OperatorVoteKey::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &operator,
+                    &ncn,
+                )
+                .0,
+            ))
+            .epoch(epoch)
+            .instruction();
 
-        self.realloc_base_reward_router(
-            ncn_config,
-            base_reward_router,
-            ncn,
-            epoch,
-            num_reallocations,
-        )
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, operator_voter],
+            blockhash,
+        ))
         .await
     }
 
-    pub async fn realloc_base_reward_router(
+    pub async fn do_set_operator_vote_key(
         &mut self,
-        ncn_config: Pubkey,
-        base_reward_router: Pubkey,
         ncn: Pubkey,
-        epoch: u64,
-        num_reallocations: u64,
-    ) -> Result<(), TestError> {
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-
-        let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        operator: Pubkey,
+        operator_admin: &Keypair,
+        vote_key: Pubkey,
+    ) -> TestResult<()> {
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let operator_vote_key =
+            OperatorVoteKey::find_program_address(&jito_tip_router_program::id(), &operator, &ncn)
+                .0;
+        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
+        let ncn_operator_state =
+            NcnOperatorState::find_program_address(&jito_restaking_program::id(), &ncn, &operator)
+                .0;
 
-        let ix = ReallocBaseRewardRouterBuilder::new()
-            .epoch_state(epoch_state)
-            .config(ncn_config)
-            .base_reward_router(base_reward_router)
+        let ix = SetOperatorVoteKeyBuilder::new()
+            .config(config)
+            .operator_vote_key(operator_vote_key)
             .ncn(ncn)
-            .epoch(epoch)
-            .account_payer(account_payer)
+            .operator(operator)
+            .restaking_config(restaking_config)
+            .ncn_operator_state(ncn_operator_state)
+            .operator_admin(operator_admin.pubkey())
             .system_program(system_program::id())
+            .vote_key(vote_key)
             .instruction();
 
-        let ixs = vec![ix; num_reallocations as usize];
-
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &ixs,
+            &[ix],
             Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &[&self.payer, operator_admin],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_realloc_weight_table(
+    pub async fn do_close_operator_vote_key(
         &mut self,
         ncn: Pubkey,
-        epoch: u64,
-        num_reallocations: u64,
-    ) -> Result<(), TestError> {
-        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
-        let weight_table =
-            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-        let vault_registry =
-            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        operator: Pubkey,
+        operator_admin: &Keypair,
+    ) -> TestResult<()> {
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let operator_vote_key =
+            OperatorVoteKey::find_program_address(&jito_tip_router_program::id(), &operator, &ncn)
+                .0;
 
-        self.realloc_weight_table(
+        let ix = CloseOperatorVoteKeyBuilder::new()
+            .config(config)
+            .operator_vote_key(operator_vote_key)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_admin(operator_admin.pubkey())
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, operator_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_payout_preference(
+        &mut self,
+        ncn: Pubkey,
+        recipient: Pubkey,
+        admin: &Keypair,
+        payout_asset: PayoutAsset,
+        sol_destination: Pubkey,
+    ) -> TestResult<()> {
+        let ix =
+            Self::set_payout_preference_ix(ncn, recipient, admin, payout_asset, sol_destination);
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_payout_preference_with_events(
+        &mut self,
+        ncn: Pubkey,
+        recipient: Pubkey,
+        admin: &Keypair,
+        payout_asset: PayoutAsset,
+        sol_destination: Pubkey,
+    ) -> TestResult<Vec<TipRouterEvent>> {
+        let ix =
+            Self::set_payout_preference_ix(ncn, recipient, admin, payout_asset, sol_destination);
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction_with_events(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    fn set_payout_preference_ix(
+        ncn: Pubkey,
+        recipient: Pubkey,
+        admin: &Keypair,
+        payout_asset: PayoutAsset,
+        sol_destination: Pubkey,
+    ) -> Instruction {
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let payout_preference = PayoutPreference::find_program_address(
+            &jito_tip_router_program::id(),
+            &recipient,
+            &ncn,
+        )
+        .0;
+
+        SetPayoutPreferenceBuilder::new()
+            .config(config)
+            .payout_preference(payout_preference)
+            .ncn(ncn)
+            .recipient(recipient)
+            .admin(admin.pubkey())
+            .system_program(system_program::id())
+            .payout_asset(payout_asset as u8)
+            .sol_destination(sol_destination)
+            .instruction()
+    }
+
+    /// The account the NCN rewards of an operator or vault are paid to under its payout
+    /// preference, with the instruction creating it when it is an ATA
+    async fn payout_destination(
+        &mut self,
+        recipient: Pubkey,
+        ncn: Pubkey,
+    ) -> TestResult<(Pubkey, Vec<Instruction>)> {
+        let payout_preference = self.get_payout_preference(recipient, ncn).await?;
+        let payout_asset = payout_preference
+            .map(|payout_preference| payout_preference.payout_asset().unwrap())
+            .unwrap_or_default();
+
+        let mint = match (payout_asset, payout_preference) {
+            (PayoutAsset::Sol, Some(payout_preference)) => {
+                return Ok((*payout_preference.sol_destination(), vec![]));
+            }
+            (PayoutAsset::WrappedSol, _) => spl_token_interface::native_mint::id(),
+            _ => JITOSOL_MINT,
+        };
+
+        let ata = get_associated_token_address(&recipient, &mint);
+        let ata_ix = create_associated_token_account_idempotent(
+            &self.payer.pubkey(),
+            &recipient,
+            &mint,
+            &spl_token_interface::id(),
+        );
+
+        Ok((ata, vec![ata_ix]))
+    }
+
+    pub async fn do_commit_vote(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_voter: &Keypair,
+        vote_commitment: [u8; 32],
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let ix = CommitVoteBuilder::new()
+            .epoch_state(
+                EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .ballot_box(
+                BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .ncn(ncn)
+            .epoch_snapshot(
+                EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .operator_snapshot(
+                OperatorSnapshot::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &operator,
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .operator(operator)
+            .operator_voter(operator_voter.pubkey())
+            .operator_vote_key(Some(
+                OperatorVoteKey::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &operator,
+                    &ncn,
+                )
+                .0,
+            ))
+            .vote_commitment(vote_commitment)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, operator_voter],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_reveal_vote(
+        &mut self,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_voter: &Keypair,
+        meta_merkle_root: [u8; 32],
+        salt: [u8; 32],
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let ix = RevealVoteBuilder::new()
+            .epoch_state(
+                EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+            .ballot_box(
+                BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .ncn(ncn)
+            .epoch_snapshot(
+                EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+            )
+            .operator_snapshot(
+                OperatorSnapshot::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &operator,
+                    &ncn,
+                    epoch,
+                )
+                .0,
+            )
+            .operator(operator)
+            .operator_voter(operator_voter.pubkey())
+            .operator_vote_key(Some(
+                OperatorVoteKey::find_program_address(
+                    &jito_tip_router_program::id(),
+                    &operator,
+                    &ncn,
+                )
+                .0,
+            ))
+            .meta_merkle_root(meta_merkle_root)
+            .salt(salt)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, operator_voter],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_merkle_root(
+        &mut self,
+        ncn: Pubkey,
+        vote_account: Pubkey,
+        distribution_program: Pubkey,
+        proof: Vec<[u8; 32]>,
+        merkle_root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (distribution_account, distribution_config) = if distribution_program
+            .eq(&jito_tip_distribution_sdk::id())
+        {
+            let tip_distribution_account = derive_tip_distribution_account_address(
+                &distribution_program,
+                &vote_account,
+                epoch - 1,
+            )
+            .0;
+
+            let tip_distribution_config =
+                jito_tip_distribution_sdk::derive_config_account_address(&distribution_program).0;
+            (tip_distribution_account, tip_distribution_config)
+        } else {
+            let distribution_account = derive_priority_fee_distribution_account_address(
+                &distribution_program,
+                &vote_account,
+                epoch - 1,
+            )
+            .0;
+
+            let config = jito_priority_fee_distribution_sdk::derive_config_account_address(
+                &distribution_program,
+            )
+            .0;
+            (distribution_account, config)
+        };
+
+        self.set_merkle_root(
+            config,
+            ncn,
+            ballot_box,
+            vote_account,
+            distribution_account,
+            distribution_config,
+            distribution_program,
+            proof,
+            merkle_root,
+            max_total_claim,
+            max_num_nodes,
+            epoch,
+        )
+        .await
+    }
+
+    pub async fn set_merkle_root(
+        &mut self,
+        config: Pubkey,
+        ncn: Pubkey,
+        ballot_box: Pubkey,
+        vote_account: Pubkey,
+        distribution_account: Pubkey,
+        distribution_config: Pubkey,
+        distribution_program: Pubkey,
+        proof: Vec<[u8; 32]>,
+        merkle_root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let ix = SetMerkleRootBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .ballot_box(ballot_box)
+            .vote_account(vote_account)
+            .tip_distribution_account(distribution_account)
+            .tip_distribution_config(distribution_config)
+            .tip_distribution_program(distribution_program)
+            .proof(proof)
+            .merkle_root(merkle_root)
+            .max_total_claim(max_total_claim)
+            .max_num_nodes(max_num_nodes)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_admin_set_tie_breaker(
+        &mut self,
+        ncn: Pubkey,
+        meta_merkle_root: [u8; 32],
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let tie_breaker_admin = self.payer.pubkey();
+
+        self.admin_set_tie_breaker(
             ncn_config,
-            weight_table,
+            ballot_box,
             ncn,
-            vault_registry,
+            tie_breaker_admin,
+            meta_merkle_root,
             epoch,
-            num_reallocations,
         )
-        .await
+        .await
+    }
+
+    pub async fn admin_set_tie_breaker(
+        &mut self,
+        ncn_config: Pubkey,
+        ballot_box: Pubkey,
+        ncn: Pubkey,
+        tie_breaker_admin: Pubkey,
+        meta_merkle_root: [u8; 32],
+        epoch: u64,
+    ) -> Result<(), TestError> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let ix = AdminSetTieBreakerBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ballot_box(ballot_box)
+            .ncn(ncn)
+            .tie_breaker_admin(tie_breaker_admin)
+            .meta_merkle_root(meta_merkle_root)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_resolve_stalled_vote(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let epoch_snapshot =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let ix = ResolveStalledVoteBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ballot_box(ballot_box)
+            .ncn(ncn)
+            .epoch_snapshot(epoch_snapshot)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_full_initialize_base_reward_router(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        self.do_initialize_base_reward_router(ncn, epoch).await?;
+        let num_reallocs =
+            (BaseRewardRouter::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+        self.do_realloc_base_reward_router(ncn, epoch, num_reallocs)
+            .await?;
+
+        // One router page for every ballot box page
+        let page_count = self.get_ballot_box(ncn, epoch).await?.page_count();
+        for page in 0..page_count {
+            self.do_initialize_base_reward_router_page(ncn, Pubkey::default(), epoch, page)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn do_initialize_base_reward_router_page(
+        &mut self,
+        ncn: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+        page: u64,
+    ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let ballot_box =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let base_reward_router = BaseRewardRouter::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            &ncn,
+            &mint,
+            epoch,
+        )
+        .0;
+        let base_reward_router_page = BaseRewardRouterPage::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            &ncn,
+            &mint,
+            epoch,
+            page,
+        )
+        .0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = InitializeBaseRewardRouterPageBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .ballot_box(ballot_box)
+            .base_reward_router(base_reward_router)
+            .base_reward_router_page(base_reward_router_page)
+            .account_payer(account_payer)
+            .epoch(epoch)
+            .page(page)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_initialize_base_reward_router(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (base_reward_router, _, _) =
+            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        self.initialize_base_reward_router(ncn, base_reward_router, base_reward_receiver, epoch)
+            .await
+    }
+
+    pub async fn initialize_base_reward_router(
+        &mut self,
+        ncn: Pubkey,
+        base_reward_router: Pubkey,
+        base_reward_receiver: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = InitializeBaseRewardRouterBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .base_reward_router(base_reward_router)
+            .base_reward_receiver(base_reward_receiver)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_initialize_ncn_reward_router(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        ncn: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        self.initialize_ncn_reward_router(
+            ncn_fee_group,
+            ncn,
+            operator,
+            operator_snapshot,
+            ncn_reward_router,
+            ncn_reward_receiver,
+            epoch,
+        )
+        .await
+    }
+
+    pub async fn initialize_ncn_reward_router(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_snapshot: Pubkey,
+        ncn_reward_router: Pubkey,
+        ncn_reward_receiver: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let epoch_state_page = self
+            .get_epoch_state_page_for_operator(ncn, operator, epoch)
+            .await?;
+
+        let ix = InitializeNcnRewardRouterBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_snapshot(operator_snapshot)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .ncn_fee_group(ncn_fee_group.group)
+            .epoch_state_page(epoch_state_page)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_route_base_rewards(&mut self, ncn: Pubkey, epoch: u64) -> TestResult<()> {
+        let (epoch_snapshot, _, _) =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let (ballot_box, _, _) =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let (base_reward_router, _, _) =
+            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        //Pretty close to max
+        let max_iterations: u16 = BaseRewardRouter::MAX_ROUTE_BASE_ITERATIONS;
+
+        let mut still_routing = true;
+        while still_routing {
+            self.route_base_rewards(
+                ncn,
+                epoch_snapshot,
+                ballot_box,
+                base_reward_router,
+                base_reward_receiver,
+                max_iterations,
+                epoch,
+            )
+            .await?;
+
+            let base_reward_router_account = self.get_base_reward_router(ncn, epoch).await?;
+
+            still_routing = base_reward_router_account.still_routing();
+        }
+
+        Ok(())
+    }
+
+    pub async fn route_base_rewards(
+        &mut self,
+        ncn: Pubkey,
+        epoch_snapshot: Pubkey,
+        ballot_box: Pubkey,
+        base_reward_router: Pubkey,
+        base_reward_receiver: Pubkey,
+        max_iterations: u16,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let account_payer =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let routing_pages = self
+            .get_routing_page_accounts(ncn, Pubkey::default(), epoch)
+            .await?;
+
+        // The payer cranks, it collects the crank bounty when one is set
+        let ix = RouteBaseRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .epoch_snapshot(epoch_snapshot)
+            .ballot_box(ballot_box)
+            .base_reward_router(base_reward_router)
+            .base_reward_receiver(base_reward_receiver)
+            .account_payer(Some(account_payer))
+            .cranker(Some(self.payer.pubkey()))
+            .system_program(Some(system_program::id()))
+            .max_iterations(max_iterations)
+            .epoch(epoch)
+            .add_remaining_accounts(&routing_pages)
+            .instruction();
+
+        let blockhash = self.get_best_latest_blockhash().await?;
+        let tx = &Transaction::new_signed_with_payer(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                ix,
+            ],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        self.process_transaction(tx).await
+    }
+
+    pub async fn do_route_ncn_rewards(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        ncn: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        let max_iterations: u16 = NcnRewardRouter::MAX_ROUTE_NCN_ITERATIONS;
+        let mut still_routing = true;
+
+        while still_routing {
+            self.route_ncn_rewards(
+                ncn_fee_group,
+                ncn,
+                operator,
+                operator_snapshot,
+                ncn_reward_router,
+                ncn_reward_receiver,
+                max_iterations,
+                epoch,
+            )
+            .await?;
+
+            let ncn_reward_router_account = self
+                .get_ncn_reward_router(ncn_fee_group, operator, ncn, epoch)
+                .await?;
+
+            still_routing = ncn_reward_router_account.still_routing();
+        }
+
+        Ok(())
+    }
+
+    pub async fn route_ncn_rewards(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        ncn: Pubkey,
+        operator: Pubkey,
+        operator_snapshot: Pubkey,
+        ncn_reward_router: Pubkey,
+        ncn_reward_receiver: Pubkey,
+        max_iterations: u16,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let operator_snapshot_pages = self
+            .get_operator_snapshot_pages(operator, ncn, epoch)
+            .await?
+            .into_iter()
+            .map(|page| AccountMeta::new_readonly(page, false))
+            .collect::<Vec<_>>();
+
+        let epoch_state_page = self
+            .get_epoch_state_page_for_operator(ncn, operator, epoch)
+            .await?;
+
+        let ix = RouteNcnRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_snapshot(operator_snapshot)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .ncn_fee_group(ncn_fee_group.group)
+            .max_iterations(max_iterations)
+            .epoch_state_page(epoch_state_page)
+            .epoch(epoch)
+            .add_remaining_accounts(&operator_snapshot_pages)
+            .instruction();
+
+        let blockhash = self.get_best_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                // TODO: should make this instruction much more efficient
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                ix,
+            ],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_distribute_base_rewards(
+        &mut self,
+        base_fee_group: BaseFeeGroup,
+        ncn: Pubkey,
+        epoch: u64,
+        pool_root: &PoolRoot,
+    ) -> TestResult<()> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let (base_reward_router, _, _) =
+            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let ncn_config_account = self.get_ncn_config(ncn).await?;
+        let base_fee_wallet = ncn_config_account
+            .fee_config
+            .base_fee_wallet(base_fee_group)
+            .unwrap();
+        let payout_asset = ncn_config_account
+            .fee_config
+            .base_fee_group_settings(base_fee_group)
+            .unwrap()
+            .payout_asset()
+            .unwrap();
+
+        // SOL is sent to the base fee wallet itself, JitoSOL and wSOL to its ATA
+        let (base_fee_wallet_ata, create_base_fee_wallet_ata_ixs) = match payout_asset {
+            PayoutAsset::Sol => (*base_fee_wallet, vec![]),
+            PayoutAsset::JitoSol | PayoutAsset::WrappedSol => {
+                let mint = if payout_asset == PayoutAsset::WrappedSol {
+                    spl_token_interface::native_mint::id()
+                } else {
+                    JITOSOL_MINT
+                };
+
+                (
+                    get_associated_token_address(base_fee_wallet, &mint),
+                    vec![create_associated_token_account_idempotent(
+                        &self.payer.pubkey(),
+                        base_fee_wallet,
+                        &mint,
+                        &spl_token_interface::id(),
+                    )],
+                )
+            }
+        };
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        // stake pool accounts
+        let stake_pool = pool_root.pool_address;
+        let (stake_pool_withdraw_authority, _) = find_withdraw_authority_program_address(
+            &jito_tip_router_program::spl_stake_pool_id(),
+            &stake_pool,
+        );
+        let reserve_stake = pool_root.reserve_stake;
+        let manager_fee_account = pool_root.manager_fee_account;
+        let referrer_pool_tokens_account = pool_root.referrer_pool_tokens_account;
+
+        let ix = DistributeBaseRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ncn(ncn)
+            .base_reward_router(base_reward_router)
+            .base_reward_receiver(base_reward_receiver)
+            .base_fee_wallet(*base_fee_wallet)
+            .base_fee_wallet_ata(base_fee_wallet_ata)
+            .stake_pool_program(jito_tip_router_program::spl_stake_pool_id())
+            .stake_pool(stake_pool)
+            .stake_pool_withdraw_authority(stake_pool_withdraw_authority)
+            .reserve_stake(reserve_stake)
+            .manager_fee_account(manager_fee_account)
+            .referrer_pool_tokens_account(referrer_pool_tokens_account)
+            .pool_mint(JITOSOL_MINT)
+            .token_program(spl_token_interface::id())
+            .system_program(system_program::id())
+            .base_fee_group(base_fee_group.group)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[create_base_fee_wallet_ata_ixs, vec![ix]].concat(),
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        self.process_transaction(&transaction).await
+    }
+
+    pub async fn do_distribute_base_ncn_reward_route(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (ncn_config, _, _) =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let (base_reward_router, _, _) =
+            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        self.distribute_base_ncn_reward_route(
+            ncn_fee_group,
+            operator,
+            ncn,
+            ncn_config,
+            base_reward_router,
+            base_reward_receiver,
+            ncn_reward_router,
+            ncn_reward_receiver,
+            epoch,
+        )
+        .await
+    }
+
+    pub async fn distribute_base_ncn_reward_route(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        operator: Pubkey,
+        ncn: Pubkey,
+        ncn_config: Pubkey,
+        base_reward_router: Pubkey,
+        base_reward_receiver: Pubkey,
+        ncn_reward_router: Pubkey,
+        ncn_reward_receiver: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let base_reward_router_page = self
+            .get_base_reward_router_page_for_operator(ncn, Pubkey::default(), operator, epoch)
+            .await?;
+
+        let ix = DistributeBaseNcnRewardRouteBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ncn(ncn)
+            .operator(operator)
+            .base_reward_router(base_reward_router)
+            .base_reward_receiver(base_reward_receiver)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .system_program(system_program::id())
+            .ncn_fee_group(ncn_fee_group.group)
+            .base_reward_router_page(base_reward_router_page)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_distribute_ncn_operator_rewards(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+        pool_root: &PoolRoot,
+    ) -> TestResult<()> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        // Add stake pool accounts
+        let stake_pool = pool_root.pool_address;
+        let (stake_pool_withdraw_authority, _) = find_withdraw_authority_program_address(
+            &jito_tip_router_program::spl_stake_pool_id(),
+            &stake_pool,
+        );
+        let reserve_stake = pool_root.reserve_stake;
+        let manager_fee_account = pool_root.manager_fee_account;
+        let referrer_pool_tokens_account = pool_root.referrer_pool_tokens_account;
+
+        let (operator_ata, operator_ata_ixs) = self.payout_destination(operator, ncn).await?;
+        let payout_preference =
+            PayoutPreference::find_program_address(&jito_tip_router_program::id(), &operator, &ncn)
+                .0;
+
+        let operator_snapshot = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        )
+        .0;
+
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let epoch_state_page = self
+            .get_epoch_state_page_for_operator(ncn, operator, epoch)
+            .await?;
+
+        let ix = DistributeNcnOperatorRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_ata(operator_ata)
+            .operator_snapshot(operator_snapshot)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .stake_pool_program(jito_tip_router_program::spl_stake_pool_id())
+            .stake_pool(stake_pool)
+            .stake_pool_withdraw_authority(stake_pool_withdraw_authority)
+            .reserve_stake(reserve_stake)
+            .manager_fee_account(manager_fee_account)
+            .referrer_pool_tokens_account(referrer_pool_tokens_account)
+            .pool_mint(JITOSOL_MINT)
+            .token_program(spl_token_interface::id())
+            .system_program(system_program::id())
+            .payout_preference(payout_preference)
+            .ncn_fee_group(ncn_fee_group.group)
+            .epoch_state_page(epoch_state_page)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_ata_ixs, vec![ix]].concat(),
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_distribute_ncn_vault_rewards(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        vault: Pubkey,
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+        pool_root: &PoolRoot,
+    ) -> TestResult<()> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        let operator_snapshot = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        )
+        .0;
+
+        // Add stake pool accounts
+        let stake_pool = pool_root.pool_address;
+        let (stake_pool_withdraw_authority, _) = find_withdraw_authority_program_address(
+            &jito_tip_router_program::spl_stake_pool_id(),
+            &stake_pool,
+        );
+        let reserve_stake = pool_root.reserve_stake;
+        let manager_fee_account = pool_root.manager_fee_account;
+        let referrer_pool_tokens_account = pool_root.referrer_pool_tokens_account;
+
+        let (vault_ata, vault_ata_ixs) = self.payout_destination(vault, ncn).await?;
+        let payout_preference =
+            PayoutPreference::find_program_address(&jito_tip_router_program::id(), &vault, &ncn).0;
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let epoch_state_page = self
+            .get_epoch_state_page_for_operator(ncn, operator, epoch)
+            .await?;
+
+        let ix = DistributeNcnVaultRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ncn(ncn)
+            .operator(operator)
+            .vault(vault)
+            .vault_ata(vault_ata)
+            .operator_snapshot(operator_snapshot)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .stake_pool_program(jito_tip_router_program::spl_stake_pool_id())
+            .stake_pool(stake_pool)
+            .stake_pool_withdraw_authority(stake_pool_withdraw_authority)
+            .reserve_stake(reserve_stake)
+            .manager_fee_account(manager_fee_account)
+            .referrer_pool_tokens_account(referrer_pool_tokens_account)
+            .pool_mint(JITOSOL_MINT)
+            .token_program(spl_token_interface::id())
+            .system_program(system_program::id())
+            .payout_preference(payout_preference)
+            .ncn_fee_group(ncn_fee_group.group)
+            .epoch_state_page(epoch_state_page)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[vault_ata_ixs, vec![ix]].concat(),
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_realloc_operator_snapshot(
+        &mut self,
+        operator: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+        num_reallocations: u64,
+    ) -> Result<(), TestError> {
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let restaking_config = Config::find_program_address(&jito_restaking_program::id()).0;
+        let ncn_operator_state =
+            NcnOperatorState::find_program_address(&jito_restaking_program::id(), &ncn, &operator)
+                .0;
+        let epoch_snapshot =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let operator_snapshot = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        )
+        .0;
+
+        self.realloc_operator_snapshot(
+            config,
+            restaking_config,
+            ncn,
+            operator,
+            ncn_operator_state,
+            epoch_snapshot,
+            operator_snapshot,
+            epoch,
+            num_reallocations,
+        )
+        .await
+    }
+
+    pub async fn realloc_operator_snapshot(
+        &mut self,
+        config: Pubkey,
+        restaking_config: Pubkey,
+        ncn: Pubkey,
+        operator: Pubkey,
+        ncn_operator_state: Pubkey,
+        epoch_snapshot: Pubkey,
+        operator_snapshot: Pubkey,
+        epoch: u64,
+        num_reallocations: u64,
+    ) -> Result<(), TestError> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let epoch_state_page = self
+            .get_epoch_state_page_for_operator(ncn, operator, epoch)
+            .await?;
+
+        let ix = ReallocOperatorSnapshotBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .restaking_config(restaking_config)
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .epoch_snapshot(epoch_snapshot)
+            .operator_snapshot(operator_snapshot)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .epoch_state_page(epoch_state_page)
+            .epoch(epoch)
+            .instruction();
+
+        let ixs = vec![ix; num_reallocations as usize];
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_realloc_base_reward_router(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        num_reallocations: u64,
+    ) -> Result<(), TestError> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let base_reward_router =
+            BaseRewardRouter::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        self.realloc_base_reward_router(
+            ncn_config,
+            base_reward_router,
+            ncn,
+            epoch,
+            num_reallocations,
+        )
+        .await
+    }
+
+    pub async fn realloc_base_reward_router(
+        &mut self,
+        ncn_config: Pubkey,
+        base_reward_router: Pubkey,
+        ncn: Pubkey,
+        epoch: u64,
+        num_reallocations: u64,
+    ) -> Result<(), TestError> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = ReallocBaseRewardRouterBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .base_reward_router(base_reward_router)
+            .ncn(ncn)
+            .epoch(epoch)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .instruction();
+
+        let ixs = vec![ix; num_reallocations as usize];
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_realloc_weight_table(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        num_reallocations: u64,
+    ) -> Result<(), TestError> {
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let weight_table =
+            WeightTable::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        self.realloc_weight_table(
+            ncn_config,
+            weight_table,
+            ncn,
+            vault_registry,
+            epoch,
+            num_reallocations,
+        )
+        .await
+    }
+
+    pub async fn realloc_weight_table(
+        &mut self,
+        ncn_config: Pubkey,
+        weight_table: Pubkey,
+        ncn: Pubkey,
+        vault_registry: Pubkey,
+        epoch: u64,
+        num_reallocations: u64,
+    ) -> Result<(), TestError> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let ix = ReallocWeightTableBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .weight_table(weight_table)
+            .ncn(ncn)
+            .vault_registry(vault_registry)
+            .epoch(epoch)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .instruction();
+
+        let ixs = vec![ix; num_reallocations as usize];
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_claim_with_payer(
+        &mut self,
+        ncn: Pubkey,
+        claimant: Pubkey,
+        distribution_account: Pubkey,
+        distribution_program: Pubkey,
+        proof: Vec<[u8; 32]>,
+        amount: u64,
+    ) -> TestResult<()> {
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let (config, _, _) = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        // NOTE: Config and ClaimStatus seeds are the same between Tip Distribution and Priority
+        //  Fee Distribution programs, so it's ok to use the same SDK.
+        let distribution_config =
+            jito_tip_distribution_sdk::derive_config_account_address(&distribution_program).0;
+
+        let (claim_status, claim_status_bump) =
+            jito_tip_distribution_sdk::derive_claim_status_account_address(
+                &distribution_program,
+                &claimant,
+                &distribution_account,
+            );
+
+        self.claim_with_payer(
+            ncn,
+            config,
+            account_payer,
+            distribution_config,
+            distribution_account,
+            distribution_program,
+            claim_status,
+            claimant,
+            proof,
+            amount,
+            claim_status_bump,
+        )
+        .await
+    }
+
+    pub async fn claim_with_payer(
+        &mut self,
+        ncn: Pubkey,
+        config: Pubkey,
+        account_payer: Pubkey,
+        distribution_config: Pubkey,
+        distribution_account: Pubkey,
+        distribution_program: Pubkey,
+        claim_status: Pubkey,
+        claimant: Pubkey,
+        proof: Vec<[u8; 32]>,
+        amount: u64,
+        bump: u8,
+    ) -> TestResult<()> {
+        let ix = ClaimWithPayerBuilder::new()
+            .account_payer(account_payer)
+            .ncn(ncn)
+            .config(config)
+            .tip_distribution_config(distribution_config)
+            .tip_distribution_account(distribution_account)
+            .tip_distribution_program(distribution_program)
+            .claim_status(claim_status)
+            .claimant(claimant)
+            .system_program(system_program::id())
+            .proof(proof)
+            .amount(amount)
+            .bump(bump)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        self.process_transaction(&tx).await
+    }
+
+    pub async fn do_close_epoch_account(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        account_to_close: Pubkey,
+        receiver_to_close: Option<Pubkey>,
+    ) -> TestResult<()> {
+        self.do_close_epoch_account_with_remaining_accounts(
+            ncn,
+            epoch,
+            account_to_close,
+            receiver_to_close,
+            &[],
+        )
+        .await
+    }
+
+    /// Closes an epoch account that takes extra accounts after `receiver_to_close`, such as the
+    /// epoch state page of an NCN reward router past `MAX_OPERATORS`
+    pub async fn do_close_epoch_account_with_remaining_accounts(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        account_to_close: Pubkey,
+        receiver_to_close: Option<Pubkey>,
+        remaining_accounts: &[Pubkey],
+    ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let (config, _, _) = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let config_account = self.get_ncn_config(ncn).await?;
+        let dao_wallet = *config_account
+            .fee_config
+            .base_fee_wallet(BaseFeeGroup::dao())
+            .expect("No DAO wallet ( do_close_epoch_account )");
+
+        self.close_epoch_account(
+            epoch_marker,
+            epoch_state,
+            ncn,
+            config,
+            account_to_close,
+            receiver_to_close,
+            account_payer,
+            dao_wallet,
+            epoch,
+            remaining_accounts,
+        )
+        .await
+    }
+
+    pub async fn close_epoch_account(
+        &mut self,
+        epoch_marker: Pubkey,
+        epoch_state: Pubkey,
+        ncn: Pubkey,
+        config: Pubkey,
+        account_to_close: Pubkey,
+        receiver_to_close: Option<Pubkey>,
+        account_payer: Pubkey,
+        dao_wallet: Pubkey,
+        epoch: u64,
+        remaining_accounts: &[Pubkey],
+    ) -> TestResult<()> {
+        let mut ix = CloseEpochAccountBuilder::new();
+
+        ix.account_payer(account_payer)
+            .epoch_marker(epoch_marker)
+            .config(config)
+            .account_to_close(account_to_close)
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .dao_wallet(dao_wallet)
+            .system_program(system_program::id())
+            .epoch(epoch);
+
+        if let Some(receiver_to_close) = receiver_to_close {
+            ix.receiver_to_close(Some(receiver_to_close));
+        }
+
+        let remaining_accounts = remaining_accounts
+            .iter()
+            .map(|account| AccountMeta::new(*account, false))
+            .collect::<Vec<_>>();
+        let ix = ix.add_remaining_accounts(&remaining_accounts).instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        self.process_transaction(&tx).await
+    }
+
+    pub async fn do_carry_forward_rewards(
+        &mut self,
+        ncn: Pubkey,
+        epoch: u64,
+        reward_router: Pubkey,
+        reward_receiver: Pubkey,
+    ) -> TestResult<()> {
+        let current_epoch = self.banks_client.get_sysvar::<Clock>().await?.epoch;
+
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+
+        let (config, _, _) = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let current_epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, current_epoch).0;
+
+        let (current_base_reward_receiver, _, _) = BaseRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            current_epoch,
+        );
+
+        self.carry_forward_rewards(
+            epoch_state,
+            config,
+            ncn,
+            reward_router,
+            reward_receiver,
+            current_epoch_state,
+            current_base_reward_receiver,
+            epoch,
+        )
+        .await
+    }
+
+    pub async fn carry_forward_rewards(
+        &mut self,
+        epoch_state: Pubkey,
+        config: Pubkey,
+        ncn: Pubkey,
+        reward_router: Pubkey,
+        reward_receiver: Pubkey,
+        current_epoch_state: Pubkey,
+        current_base_reward_receiver: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let ix = CarryForwardRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(config)
+            .ncn(ncn)
+            .reward_router(reward_router)
+            .reward_receiver(reward_receiver)
+            .current_epoch_state(current_epoch_state)
+            .current_base_reward_receiver(current_base_reward_receiver)
+            .system_program(system_program::id())
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        self.process_transaction(&tx).await
+    }
+
+    pub async fn get_token_base_reward_router(
+        &mut self,
+        ncn: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+    ) -> TestResult<BaseRewardRouter> {
+        let address = BaseRewardRouter::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            &ncn,
+            &mint,
+            epoch,
+        )
+        .0;
+
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+
+        let account =
+            BaseRewardRouter::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap();
+        Ok(*account)
+    }
+
+    pub async fn get_token_ncn_reward_router(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        operator: Pubkey,
+        ncn: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+    ) -> TestResult<NcnRewardRouter> {
+        let address = NcnRewardRouter::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            &mint,
+            epoch,
+        )
+        .0;
+
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+
+        let account =
+            NcnRewardRouter::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap();
+        Ok(*account)
+    }
+
+    /// Initializes and fully reallocates the base reward router for token rewards of `mint`
+    pub async fn do_full_initialize_token_base_reward_router(
+        &mut self,
+        ncn: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let (base_reward_router, _, _) = BaseRewardRouter::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            &ncn,
+            &mint,
+            epoch,
+        );
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let initialize_ix = InitializeBaseRewardRouterBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .base_reward_router(base_reward_router)
+            .base_reward_receiver(base_reward_receiver)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .mint(Some(mint))
+            .config(Some(ncn_config))
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                create_associated_token_account_idempotent(
+                    &self.payer.pubkey(),
+                    &base_reward_receiver,
+                    &mint,
+                    &spl_token_interface::id(),
+                ),
+                initialize_ix,
+            ],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await?;
+
+        let num_reallocs =
+            (BaseRewardRouter::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+        let realloc_ix = ReallocBaseRewardRouterBuilder::new()
+            .config(ncn_config)
+            .epoch_state(epoch_state)
+            .base_reward_router(base_reward_router)
+            .ncn(ncn)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .mint(Some(mint))
+            .epoch(epoch)
+            .instruction();
+
+        let mut ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(1_400_000)];
+        ixs.extend(vec![realloc_ix; num_reallocs as usize]);
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await?;
+
+        // One router page for every ballot box page
+        let page_count = self.get_ballot_box(ncn, epoch).await?.page_count();
+        for page in 0..page_count {
+            self.do_initialize_base_reward_router_page(ncn, mint, epoch, page)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn do_initialize_token_ncn_reward_router(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        ncn: Pubkey,
+        operator: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        );
+        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            &mint,
+            epoch,
+        );
+        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        let ix = InitializeNcnRewardRouterBuilder::new()
+            .epoch_marker(epoch_marker)
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_snapshot(operator_snapshot)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .account_payer(account_payer)
+            .system_program(system_program::id())
+            .mint(Some(mint))
+            .config(Some(ncn_config))
+            .ncn_fee_group(ncn_fee_group.group)
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                create_associated_token_account_idempotent(
+                    &self.payer.pubkey(),
+                    &ncn_reward_receiver,
+                    &mint,
+                    &spl_token_interface::id(),
+                ),
+                ix,
+            ],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_route_token_base_rewards(
+        &mut self,
+        ncn: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let (epoch_snapshot, _, _) =
+            EpochSnapshot::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let (ballot_box, _, _) =
+            BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let (base_reward_router, _, _) = BaseRewardRouter::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            &ncn,
+            &mint,
+            epoch,
+        );
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let account_payer =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let routing_pages = self.get_routing_page_accounts(ncn, mint, epoch).await?;
+
+        let mut still_routing = true;
+        while still_routing {
+            let ix = RouteBaseRewardsBuilder::new()
+                .epoch_state(epoch_state)
+                .config(config)
+                .ncn(ncn)
+                .epoch_snapshot(epoch_snapshot)
+                .ballot_box(ballot_box)
+                .base_reward_router(base_reward_router)
+                .base_reward_receiver(base_reward_receiver)
+                .mint(Some(mint))
+                .base_reward_receiver_token_account(Some(BaseRewardReceiver::token_account(
+                    &jito_tip_router_program::id(),
+                    &ncn,
+                    epoch,
+                    &mint,
+                )))
+                .max_iterations(BaseRewardRouter::MAX_ROUTE_BASE_ITERATIONS)
+                .account_payer(Some(account_payer))
+                .cranker(Some(self.payer.pubkey()))
+                .system_program(Some(system_program::id()))
+                .epoch(epoch)
+                .add_remaining_accounts(&routing_pages)
+                .instruction();
+
+            let blockhash = self.get_best_latest_blockhash().await?;
+            self.process_transaction(&Transaction::new_signed_with_payer(
+                &[
+                    ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                    ix,
+                ],
+                Some(&self.payer.pubkey()),
+                &[&self.payer],
+                blockhash,
+            ))
+            .await?;
+
+            still_routing = self
+                .get_token_base_reward_router(ncn, mint, epoch)
+                .await?
+                .still_routing();
+        }
+
+        Ok(())
+    }
+
+    pub async fn do_route_token_ncn_rewards(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        ncn: Pubkey,
+        operator: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let (operator_snapshot, _, _) = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        );
+        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            &mint,
+            epoch,
+        );
+        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+
+        let operator_snapshot_pages = self
+            .get_operator_snapshot_pages(operator, ncn, epoch)
+            .await?
+            .into_iter()
+            .map(|page| AccountMeta::new_readonly(page, false))
+            .collect::<Vec<_>>();
+
+        let mut still_routing = true;
+        while still_routing {
+            let ix = RouteNcnRewardsBuilder::new()
+                .epoch_state(epoch_state)
+                .config(NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0)
+                .ncn(ncn)
+                .operator(operator)
+                .operator_snapshot(operator_snapshot)
+                .ncn_reward_router(ncn_reward_router)
+                .ncn_reward_receiver(ncn_reward_receiver)
+                .mint(Some(mint))
+                .ncn_reward_receiver_token_account(Some(NcnRewardReceiver::token_account(
+                    &jito_tip_router_program::id(),
+                    ncn_fee_group,
+                    &operator,
+                    &ncn,
+                    epoch,
+                    &mint,
+                )))
+                .ncn_fee_group(ncn_fee_group.group)
+                .max_iterations(NcnRewardRouter::MAX_ROUTE_NCN_ITERATIONS)
+                .epoch(epoch)
+                .add_remaining_accounts(&operator_snapshot_pages)
+                .instruction();
+
+            let blockhash = self.get_best_latest_blockhash().await?;
+            self.process_transaction(&Transaction::new_signed_with_payer(
+                &[
+                    ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                    ix,
+                ],
+                Some(&self.payer.pubkey()),
+                &[&self.payer],
+                blockhash,
+            ))
+            .await?;
+
+            still_routing = self
+                .get_token_ncn_reward_router(ncn_fee_group, operator, ncn, mint, epoch)
+                .await?
+                .still_routing();
+        }
+
+        Ok(())
+    }
+
+    pub async fn do_distribute_token_base_rewards(
+        &mut self,
+        base_fee_group: BaseFeeGroup,
+        ncn: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+        pool_root: &PoolRoot,
+    ) -> TestResult<()> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (base_reward_router, _, _) = BaseRewardRouter::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            &ncn,
+            &mint,
+            epoch,
+        );
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let ncn_config_account = self.get_ncn_config(ncn).await?;
+        let base_fee_wallet = *ncn_config_account
+            .fee_config
+            .base_fee_wallet(base_fee_group)
+            .unwrap();
+
+        // The stake pool accounts are unused for token rewards
+        let stake_pool = pool_root.pool_address;
+        let (stake_pool_withdraw_authority, _) = find_withdraw_authority_program_address(
+            &jito_tip_router_program::spl_stake_pool_id(),
+            &stake_pool,
+        );
+
+        let account_payer =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let ix = DistributeBaseRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ncn(ncn)
+            .base_reward_router(base_reward_router)
+            .base_reward_receiver(base_reward_receiver)
+            .base_fee_wallet(base_fee_wallet)
+            .base_fee_wallet_ata(get_associated_token_address(&base_fee_wallet, &mint))
+            .stake_pool_program(jito_tip_router_program::spl_stake_pool_id())
+            .stake_pool(stake_pool)
+            .stake_pool_withdraw_authority(stake_pool_withdraw_authority)
+            .reserve_stake(pool_root.reserve_stake)
+            .manager_fee_account(pool_root.manager_fee_account)
+            .referrer_pool_tokens_account(pool_root.referrer_pool_tokens_account)
+            .pool_mint(JITOSOL_MINT)
+            .token_program(spl_token_interface::id())
+            .system_program(system_program::id())
+            .mint(Some(mint))
+            .base_reward_receiver_token_account(Some(BaseRewardReceiver::token_account(
+                &jito_tip_router_program::id(),
+                &ncn,
+                epoch,
+                &mint,
+            )))
+            .base_fee_group(base_fee_group.group)
+            .account_payer(Some(account_payer))
+            .cranker(Some(self.payer.pubkey()))
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                create_associated_token_account_idempotent(
+                    &self.payer.pubkey(),
+                    &base_fee_wallet,
+                    &mint,
+                    &spl_token_interface::id(),
+                ),
+                ix,
+            ],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_distribute_token_base_ncn_reward_route(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        operator: Pubkey,
+        ncn: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (base_reward_router, _, _) = BaseRewardRouter::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            &ncn,
+            &mint,
+            epoch,
+        );
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            &mint,
+            epoch,
+        );
+        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+        let base_reward_router_page = self
+            .get_base_reward_router_page_for_operator(ncn, mint, operator, epoch)
+            .await?;
+
+        let account_payer =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let ix = DistributeBaseNcnRewardRouteBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ncn(ncn)
+            .operator(operator)
+            .base_reward_router(base_reward_router)
+            .base_reward_receiver(base_reward_receiver)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .system_program(system_program::id())
+            .mint(Some(mint))
+            .base_reward_receiver_token_account(Some(BaseRewardReceiver::token_account(
+                &jito_tip_router_program::id(),
+                &ncn,
+                epoch,
+                &mint,
+            )))
+            .ncn_reward_receiver_token_account(Some(NcnRewardReceiver::token_account(
+                &jito_tip_router_program::id(),
+                ncn_fee_group,
+                &operator,
+                &ncn,
+                epoch,
+                &mint,
+            )))
+            .token_program(Some(spl_token_interface::id()))
+            .ncn_fee_group(ncn_fee_group.group)
+            .base_reward_router_page(base_reward_router_page)
+            .account_payer(Some(account_payer))
+            .cranker(Some(self.payer.pubkey()))
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_distribute_token_ncn_operator_rewards(
+        &mut self,
+        ncn_fee_group: NcnFeeGroup,
+        operator: Pubkey,
+        ncn: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+        pool_root: &PoolRoot,
+    ) -> TestResult<()> {
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let ncn_config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let (ncn_reward_router, _, _) = NcnRewardRouter::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            &mint,
+            epoch,
+        );
+        let (ncn_reward_receiver, _, _) = NcnRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            ncn_fee_group,
+            &operator,
+            &ncn,
+            epoch,
+        );
+        let operator_snapshot = OperatorSnapshot::find_program_address(
+            &jito_tip_router_program::id(),
+            &operator,
+            &ncn,
+            epoch,
+        )
+        .0;
+        let payout_preference =
+            PayoutPreference::find_program_address(&jito_tip_router_program::id(), &operator, &ncn)
+                .0;
+
+        // The stake pool accounts are unused for token rewards
+        let stake_pool = pool_root.pool_address;
+        let (stake_pool_withdraw_authority, _) = find_withdraw_authority_program_address(
+            &jito_tip_router_program::spl_stake_pool_id(),
+            &stake_pool,
+        );
+
+        let account_payer =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+
+        let ix = DistributeNcnOperatorRewardsBuilder::new()
+            .epoch_state(epoch_state)
+            .config(ncn_config)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_ata(get_associated_token_address(&operator, &mint))
+            .operator_snapshot(operator_snapshot)
+            .ncn_reward_router(ncn_reward_router)
+            .ncn_reward_receiver(ncn_reward_receiver)
+            .stake_pool_program(jito_tip_router_program::spl_stake_pool_id())
+            .stake_pool(stake_pool)
+            .stake_pool_withdraw_authority(stake_pool_withdraw_authority)
+            .reserve_stake(pool_root.reserve_stake)
+            .manager_fee_account(pool_root.manager_fee_account)
+            .referrer_pool_tokens_account(pool_root.referrer_pool_tokens_account)
+            .pool_mint(JITOSOL_MINT)
+            .token_program(spl_token_interface::id())
+            .system_program(system_program::id())
+            .payout_preference(payout_preference)
+            .mint(Some(mint))
+            .ncn_reward_receiver_token_account(Some(NcnRewardReceiver::token_account(
+                &jito_tip_router_program::id(),
+                ncn_fee_group,
+                &operator,
+                &ncn,
+                epoch,
+                &mint,
+            )))
+            .ncn_fee_group(ncn_fee_group.group)
+            .account_payer(Some(account_payer))
+            .cranker(Some(self.payer.pubkey()))
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                create_associated_token_account_idempotent(
+                    &self.payer.pubkey(),
+                    &operator,
+                    &mint,
+                    &spl_token_interface::id(),
+                ),
+                ix,
+            ],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Closes a token reward router, the receiver's leftover tokens go to the DAO wallet's ATA
+    pub async fn do_close_token_reward_router(
+        &mut self,
+        ncn: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+        reward_router: Pubkey,
+        reward_receiver: Pubkey,
+    ) -> TestResult<()> {
+        let (epoch_marker, _, _) =
+            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        let (config, _, _) = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+
+        let config_account = self.get_ncn_config(ncn).await?;
+        let dao_wallet = *config_account
+            .fee_config
+            .base_fee_wallet(BaseFeeGroup::dao())
+            .expect("No DAO wallet ( do_close_token_reward_router )");
+
+        let ix = CloseEpochAccountBuilder::new()
+            .account_payer(account_payer)
+            .epoch_marker(epoch_marker)
+            .config(config)
+            .account_to_close(reward_router)
+            .epoch_state(epoch_state)
+            .ncn(ncn)
+            .dao_wallet(dao_wallet)
+            .system_program(system_program::id())
+            .receiver_to_close(Some(reward_receiver))
+            .add_remaining_accounts(&[
+                AccountMeta::new(get_associated_token_address(&reward_receiver, &mint), false),
+                AccountMeta::new(get_associated_token_address(&dao_wallet, &mint), false),
+                AccountMeta::new_readonly(spl_token_interface::id(), false),
+            ])
+            .epoch(epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                create_associated_token_account_idempotent(
+                    &self.payer.pubkey(),
+                    &dao_wallet,
+                    &mint,
+                    &spl_token_interface::id(),
+                ),
+                ix,
+            ],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_parameters(
+        &mut self,
+        starting_valid_epoch: Option<u64>,
+        epochs_before_stall: Option<u64>,
+        epochs_after_consensus_before_close: Option<u64>,
+        valid_slots_after_consensus: Option<u64>,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+
+        let mut ix = AdminSetParametersBuilder::new();
+        ix.config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey());
+
+        if let Some(epoch) = starting_valid_epoch {
+            ix.starting_valid_epoch(epoch);
+        }
+
+        if let Some(epochs) = epochs_before_stall {
+            ix.epochs_before_stall(epochs);
+        }
+
+        if let Some(epochs) = epochs_after_consensus_before_close {
+            ix.epochs_after_consensus_before_close(epochs);
+        }
+
+        if let Some(slots) = valid_slots_after_consensus {
+            ix.valid_slots_after_consensus(slots);
+        }
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix.instruction()],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_commit_reveal_windows(
+        &mut self,
+        commit_window_slots: u64,
+        reveal_window_slots: u64,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+
+        let ix = AdminSetParametersBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .commit_window_slots(commit_window_slots)
+            .reveal_window_slots(reveal_window_slots)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_consensus_threshold(
+        &mut self,
+        consensus_threshold_bps: u16,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+
+        let ix = AdminSetParametersBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .consensus_threshold_bps(consensus_threshold_bps)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_quorum(
+        &mut self,
+        quorum_operator_count: u16,
+        quorum_operator_bps: u16,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+
+        let ix = AdminSetParametersBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .quorum_operator_count(quorum_operator_count)
+            .quorum_operator_bps(quorum_operator_bps)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_crank_bounty(
+        &mut self,
+        crank_bounty_lamports: u64,
+        max_crank_bounty_lamports_per_epoch: u64,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+
+        let ix = AdminSetParametersBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .crank_bounty_lamports(crank_bounty_lamports)
+            .max_crank_bounty_lamports_per_epoch(max_crank_bounty_lamports_per_epoch)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_fee_increase_notice_epochs(
+        &mut self,
+        fee_increase_notice_epochs: u64,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+
+        let ix = AdminSetParametersBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .fee_increase_notice_epochs(fee_increase_notice_epochs)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_stall_fallback_threshold(
+        &mut self,
+        stall_fallback_threshold_bps: u16,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let config_pda =
+            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
+
+        let ix = AdminSetParametersBuilder::new()
+            .config(config_pda)
+            .ncn(ncn_root.ncn_pubkey)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
+            .stall_fallback_threshold_bps(stall_fallback_threshold_bps)
+            .instruction();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn get_admin_multisig(
+        &mut self,
+        ncn: Pubkey,
+        role: ConfigAdminRole,
+    ) -> TestResult<AdminMultisig> {
+        let address = AdminMultisig::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            core_admin_role(role),
+        )
+        .0;
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+        Ok(*AdminMultisig::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap())
+    }
+
+    pub async fn get_admin_proposal(
+        &mut self,
+        ncn: Pubkey,
+        role: ConfigAdminRole,
+        nonce: u64,
+    ) -> TestResult<AdminProposal> {
+        let address = AdminProposal::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            core_admin_role(role),
+            nonce,
+        )
+        .0;
+        let raw_account = self.banks_client.get_account(address).await?.unwrap();
+        Ok(*AdminProposal::try_from_slice_unchecked(raw_account.data.as_slice()).unwrap())
     }
 
-    pub async fn realloc_weight_table(
+    pub async fn do_set_admin_multisig(
         &mut self,
-        ncn_config: Pubkey,
-        weight_table: Pubkey,
-        ncn: Pubkey,
-        vault_registry: Pubkey,
-        epoch: u64,
-        num_reallocations: u64,
-    ) -> Result<(), TestError> {
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-
-        let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        role: ConfigAdminRole,
+        threshold: u8,
+        signers: Vec<Pubkey>,
+        ncn_root: &NcnRoot,
+    ) -> TestResult<()> {
+        let ncn = ncn_root.ncn_pubkey;
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let admin_multisig = AdminMultisig::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            core_admin_role(role),
+        )
+        .0;
+        let account_payer =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn).0;
 
-        let ix = ReallocWeightTableBuilder::new()
-            .epoch_state(epoch_state)
-            .config(ncn_config)
-            .weight_table(weight_table)
+        let ix = AdminSetAdminMultisigBuilder::new()
+            .config(config)
+            .admin_multisig(admin_multisig)
             .ncn(ncn)
-            .vault_registry(vault_registry)
-            .epoch(epoch)
+            .ncn_admin(ncn_root.ncn_admin.pubkey())
             .account_payer(account_payer)
             .system_program(system_program::id())
+            .role(role)
+            .threshold(threshold)
+            .signers(signers)
             .instruction();
 
-        let ixs = vec![ix; num_reallocations as usize];
-
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &ixs,
+            &[ix],
             Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &[&self.payer, &ncn_root.ncn_admin],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_claim_with_payer(
+    /// Proposes an admin action, returning the proposal nonce
+    pub async fn do_propose_admin_action(
         &mut self,
         ncn: Pubkey,
-        claimant: Pubkey,
-        distribution_account: Pubkey,
-        distribution_program: Pubkey,
-        proof: Vec<[u8; 32]>,
-        amount: u64,
-    ) -> TestResult<()> {
-        let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
-
-        let (config, _, _) = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
-
-        // NOTE: Config and ClaimStatus seeds are the same between Tip Distribution and Priority
-        //  Fee Distribution programs, so it's ok to use the same SDK.
-        let distribution_config =
-            jito_tip_distribution_sdk::derive_config_account_address(&distribution_program).0;
+        role: ConfigAdminRole,
+        action: AdminAction,
+        signer: &Keypair,
+    ) -> TestResult<u64> {
+        self.airdrop(&signer.pubkey(), 1.0).await?;
 
-        let (claim_status, claim_status_bump) =
-            jito_tip_distribution_sdk::derive_claim_status_account_address(
-                &distribution_program,
-                &claimant,
-                &distribution_account,
-            );
+        let nonce = self.get_admin_multisig(ncn, role).await?.proposal_count();
 
-        self.claim_with_payer(
-            ncn,
-            config,
-            account_payer,
-            distribution_config,
-            distribution_account,
-            distribution_program,
-            claim_status,
-            claimant,
-            proof,
-            amount,
-            claim_status_bump,
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let admin_multisig = AdminMultisig::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            core_admin_role(role),
         )
-        .await
-    }
+        .0;
+        let admin_proposal = AdminProposal::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            core_admin_role(role),
+            nonce,
+        )
+        .0;
 
-    pub async fn claim_with_payer(
-        &mut self,
-        ncn: Pubkey,
-        config: Pubkey,
-        account_payer: Pubkey,
-        distribution_config: Pubkey,
-        distribution_account: Pubkey,
-        distribution_program: Pubkey,
-        claim_status: Pubkey,
-        claimant: Pubkey,
-        proof: Vec<[u8; 32]>,
-        amount: u64,
-        bump: u8,
-    ) -> TestResult<()> {
-        let ix = ClaimWithPayerBuilder::new()
-            .account_payer(account_payer)
-            .ncn(ncn)
+        let ix = ProposeAdminActionBuilder::new()
             .config(config)
-            .tip_distribution_config(distribution_config)
-            .tip_distribution_account(distribution_account)
-            .tip_distribution_program(distribution_program)
-            .claim_status(claim_status)
-            .claimant(claimant)
+            .admin_multisig(admin_multisig)
+            .admin_proposal(admin_proposal)
+            .ncn(ncn)
+            .signer(signer.pubkey())
             .system_program(system_program::id())
-            .proof(proof)
-            .amount(amount)
-            .bump(bump)
+            .role(role)
+            .action(action)
             .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix],
             Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &[&self.payer, signer],
             blockhash,
-        );
+        ))
+        .await?;
 
-        self.process_transaction(&tx).await
+        Ok(nonce)
     }
 
-    pub async fn do_close_epoch_account(
+    pub async fn do_approve_admin_action(
         &mut self,
         ncn: Pubkey,
-        epoch: u64,
-        account_to_close: Pubkey,
-        receiver_to_close: Option<Pubkey>,
+        role: ConfigAdminRole,
+        nonce: u64,
+        signer: &Keypair,
     ) -> TestResult<()> {
-        let (epoch_marker, _, _) =
-            EpochMarker::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
-
-        let epoch_state =
-            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
-
-        let (account_payer, _, _) =
-            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
-
-        let (config, _, _) = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn);
+        let admin_multisig = AdminMultisig::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            core_admin_role(role),
+        )
+        .0;
+        let admin_proposal = AdminProposal::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            core_admin_role(role),
+            nonce,
+        )
+        .0;
 
-        let config_account = self.get_ncn_config(ncn).await?;
-        let dao_wallet = *config_account
-            .fee_config
-            .base_fee_wallet(BaseFeeGroup::dao())
-            .expect("No DAO wallet ( do_close_epoch_account )");
+        let ix = ApproveAdminActionBuilder::new()
+            .admin_multisig(admin_multisig)
+            .admin_proposal(admin_proposal)
+            .ncn(ncn)
+            .signer(signer.pubkey())
+            .role(role)
+            .nonce(nonce)
+            .instruction();
 
-        self.close_epoch_account(
-            epoch_marker,
-            epoch_state,
-            ncn,
-            config,
-            account_to_close,
-            receiver_to_close,
-            account_payer,
-            dao_wallet,
-            epoch,
-        )
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, signer],
+            blockhash,
+        ))
         .await
     }
 
-    pub async fn close_epoch_account(
+    /// Executes an admin action, `epoch` is only needed to set the tie breaker
+    pub async fn do_execute_admin_action(
         &mut self,
-        epoch_marker: Pubkey,
-        epoch_state: Pubkey,
         ncn: Pubkey,
-        config: Pubkey,
-        account_to_close: Pubkey,
-        receiver_to_close: Option<Pubkey>,
-        account_payer: Pubkey,
-        dao_wallet: Pubkey,
-        epoch: u64,
+        role: ConfigAdminRole,
+        nonce: u64,
+        epoch: Option<u64>,
     ) -> TestResult<()> {
-        let mut ix = CloseEpochAccountBuilder::new();
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let admin_multisig = AdminMultisig::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            core_admin_role(role),
+        )
+        .0;
+        let admin_proposal = AdminProposal::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            core_admin_role(role),
+            nonce,
+        )
+        .0;
 
-        ix.account_payer(account_payer)
-            .epoch_marker(epoch_marker)
+        let mut builder = ExecuteAdminActionBuilder::new();
+        builder
             .config(config)
-            .account_to_close(account_to_close)
-            .epoch_state(epoch_state)
+            .admin_multisig(admin_multisig)
+            .admin_proposal(admin_proposal)
             .ncn(ncn)
-            .dao_wallet(dao_wallet)
-            .system_program(system_program::id())
-            .epoch(epoch);
+            .role(role)
+            .nonce(nonce);
 
-        if let Some(receiver_to_close) = receiver_to_close {
-            ix.receiver_to_close(Some(receiver_to_close));
+        if let Some(epoch) = epoch {
+            builder.add_remaining_accounts(&[
+                AccountMeta::new(
+                    EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+                    false,
+                ),
+                AccountMeta::new(
+                    BallotBox::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0,
+                    false,
+                ),
+            ]);
         }
 
-        let ix = ix.instruction();
+        let ix = builder.instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
+        self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix],
             Some(&self.payer.pubkey()),
             &[&self.payer],
             blockhash,
-        );
-
-        self.process_transaction(&tx).await
+        ))
+        .await
     }
 
-    pub async fn do_set_parameters(
+    pub async fn do_close_admin_proposal(
         &mut self,
-        starting_valid_epoch: Option<u64>,
-        epochs_before_stall: Option<u64>,
-        epochs_after_consensus_before_close: Option<u64>,
-        valid_slots_after_consensus: Option<u64>,
-        ncn_root: &NcnRoot,
+        ncn: Pubkey,
+        role: ConfigAdminRole,
+        nonce: u64,
+        signer: &Keypair,
     ) -> TestResult<()> {
-        let config_pda =
-            NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn_root.ncn_pubkey).0;
-
-        let mut ix = AdminSetParametersBuilder::new();
-        ix.config(config_pda)
-            .ncn(ncn_root.ncn_pubkey)
-            .ncn_admin(ncn_root.ncn_admin.pubkey());
-
-        if let Some(epoch) = starting_valid_epoch {
-            ix.starting_valid_epoch(epoch);
-        }
-
-        if let Some(epochs) = epochs_before_stall {
-            ix.epochs_before_stall(epochs);
-        }
-
-        if let Some(epochs) = epochs_after_consensus_before_close {
-            ix.epochs_after_consensus_before_close(epochs);
-        }
+        let config = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let admin_multisig = AdminMultisig::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            core_admin_role(role),
+        )
+        .0;
+        let admin_proposal = AdminProposal::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            core_admin_role(role),
+            nonce,
+        )
+        .0;
+        let proposer = *self.get_admin_proposal(ncn, role, nonce).await?.proposer();
 
-        if let Some(slots) = valid_slots_after_consensus {
-            ix.valid_slots_after_consensus(slots);
-        }
+        let ix = CloseAdminProposalBuilder::new()
+            .config(config)
+            .admin_multisig(admin_multisig)
+            .admin_proposal(admin_proposal)
+            .ncn(ncn)
+            .proposer(proposer)
+            .signer(signer.pubkey())
+            .role(role)
+            .nonce(nonce)
+            .instruction();
 
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[ix.instruction()],
-            Some(&ncn_root.ncn_admin.pubkey()),
-            &[&ncn_root.ncn_admin],
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, signer],
             blockhash,
         ))
         .await
     }
 }
 
+const fn core_admin_role(role: ConfigAdminRole) -> CoreConfigAdminRole {
+    match role {
+        ConfigAdminRole::FeeAdmin => CoreConfigAdminRole::FeeAdmin,
+        ConfigAdminRole::TieBreakerAdmin => CoreConfigAdminRole::TieBreakerAdmin,
+        ConfigAdminRole::PauseAdmin => CoreConfigAdminRole::PauseAdmin,
+    }
+}
+
 #[inline(always)]
 #[track_caller]
 pub fn assert_tip_router_error<T>(
@@ -1,5 +1,7 @@
 use jito_bytemuck::Discriminator;
-use jito_tip_router_core::{ballot_box::BallotBox, epoch_state::EpochState};
+use jito_tip_router_core::{
+    account_version::VersionedAccount, ballot_box::BallotBox, epoch_state::EpochState,
+};
 use solana_sdk::{account::Account, native_token::LAMPORTS_PER_SOL};
 
 pub fn serialized_epoch_state_account(epoch_state: &EpochState) -> Account {
@@ -19,8 +21,8 @@ pub fn serialized_epoch_state_account(epoch_state: &EpochState) -> Account {
 
 pub fn serialized_ballot_box_account(ballot_box: &BallotBox) -> Account {
     // TODO add AccountSerialize to jito_restaking::bytemuck?
-    let mut data = vec![BallotBox::DISCRIMINATOR; 1];
-    data.extend_from_slice(&[0; 7]);
+    let mut data = vec![BallotBox::DISCRIMINATOR, BallotBox::ACCOUNT_VERSION];
+    data.extend_from_slice(&[0; 6]);
     data.extend_from_slice(bytemuck::bytes_of(ballot_box));
 
     Account {
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::error::TipRouterError;
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_admin_deactivate_vault_and_st_mint() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut vault_client = fixture.vault_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 2, None).await?;
+        fixture.warp_epoch_incremental(1).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+        let vault = test_ncn.vaults[1].vault_pubkey;
+        let st_mint = vault_client.get_vault(&vault).await?.supported_mint;
+
+        // The mint's vault has to be deactivated first
+        let result = tip_router_client
+            .do_admin_deactivate_st_mint(ncn, st_mint, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::StMintHasActiveVaults);
+
+        // Earlier epochs can't change
+        let result = tip_router_client
+            .do_admin_deactivate_vault(ncn, vault, epoch - 1)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidDeactivationEpoch);
+
+        tip_router_client
+            .do_admin_deactivate_vault(ncn, vault, epoch)
+            .await?;
+        tip_router_client
+            .do_admin_deactivate_st_mint(ncn, st_mint, epoch)
+            .await?;
+
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        assert_eq!(vault_registry.vault_count(), 2);
+        assert_eq!(vault_registry.get_valid_vault_entries(epoch).len(), 1);
+        assert_eq!(vault_registry.get_valid_mint_entries(epoch).len(), 1);
+        assert_eq!(vault_registry.get_valid_vault_entries(epoch - 1).len(), 2);
+
+        // The weight table of the epoch leaves the vault and its mint out
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+        tip_router_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        assert_eq!(weight_table.vault_count(), 1);
+        assert_eq!(weight_table.mint_count(), 1);
+        assert!(!weight_table.get_mints().contains(&st_mint));
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.vault_count(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,629 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_client::types::{AdminAction, ConfigAdminRole};
+    use jito_tip_router_core::{
+        admin_multisig::{AdminMultisig, AdminProposal},
+        ballot_box::Ballot,
+        config::ConfigAdminRole as CoreConfigAdminRole,
+        constants::DEFAULT_CONSENSUS_REACHED_SLOT,
+        error::TipRouterError,
+        pause::PausableSubsystem,
+    };
+    use solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+    };
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    fn set_block_engine_fee(new_block_engine_fee_bps: u16) -> AdminAction {
+        AdminAction::SetConfigFees {
+            new_block_engine_fee_bps: Some(new_block_engine_fee_bps),
+            base_fee_group: None,
+            new_base_fee_wallet: None,
+            new_base_fee_bps: None,
+            ncn_fee_group: None,
+            new_ncn_fee_bps: None,
+            new_priority_fee_distribution_fee_bps: None,
+            activation_epoch: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_multisig_set_config_fees() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        let signers: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+        tip_router_client
+            .do_set_admin_multisig(
+                ConfigAdminRole::FeeAdmin,
+                2,
+                signers.iter().map(|s| s.pubkey()).collect(),
+                &ncn_root,
+            )
+            .await?;
+
+        let admin_multisig = AdminMultisig::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            CoreConfigAdminRole::FeeAdmin,
+        )
+        .0;
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.fee_admin, admin_multisig);
+
+        // The previous fee admin can no longer update fees directly
+        let result = tip_router_client
+            .do_set_config_fees(Some(500), None, None, None, None, None, &ncn_root, None)
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectFeeAdmin);
+
+        let nonce = tip_router_client
+            .do_propose_admin_action(
+                ncn,
+                ConfigAdminRole::FeeAdmin,
+                set_block_engine_fee(500),
+                &signers[0],
+            )
+            .await?;
+        assert_eq!(nonce, 0);
+
+        let result = tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::FeeAdmin, nonce, None)
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminProposalThresholdNotMet);
+
+        let result = tip_router_client
+            .do_approve_admin_action(ncn, ConfigAdminRole::FeeAdmin, nonce, &Keypair::new())
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminMultisigSignerNotFound);
+
+        let result = tip_router_client
+            .do_approve_admin_action(ncn, ConfigAdminRole::FeeAdmin, nonce, &signers[0])
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminProposalAlreadyApproved);
+
+        tip_router_client
+            .do_approve_admin_action(ncn, ConfigAdminRole::FeeAdmin, nonce, &signers[2])
+            .await?;
+
+        tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::FeeAdmin, nonce, None)
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.fee_config.block_engine_fee_bps(), 500);
+
+        let proposal = tip_router_client
+            .get_admin_proposal(ncn, ConfigAdminRole::FeeAdmin, nonce)
+            .await?;
+        assert!(proposal.executed());
+        assert_eq!(proposal.approval_count(), 2);
+
+        fixture.warp_slot_incremental(1).await?;
+
+        let result = tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::FeeAdmin, nonce, None)
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminProposalAlreadyExecuted);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_admin_multisig_set_tie_breaker() -> TestResult<()> {
+        const STACK_SIZE: usize = 32 * 1024 * 1024;
+        let handle = std::thread::Builder::new()
+            .name("test_admin_multisig_set_tie_breaker".to_string())
+            .stack_size(STACK_SIZE)
+            .spawn(|| {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .expect("failed to build tokio runtime");
+                runtime.block_on(async {
+                    let mut fixture = TestBuilder::new().await;
+                    let mut tip_router_client = fixture.tip_router_client();
+
+                    // Each operator gets 50% voting share
+                    let test_ncn = fixture.create_initial_test_ncn(2, 1, None).await?;
+                    fixture.snapshot_test_ncn(&test_ncn).await?;
+
+                    let epoch = fixture.clock().await.epoch;
+                    let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+                    tip_router_client
+                        .do_full_initialize_ballot_box(ncn, epoch)
+                        .await?;
+
+                    let meta_merkle_root = [1; 32];
+                    tip_router_client
+                        .do_cast_vote(
+                            ncn,
+                            test_ncn.operators[0].operator_pubkey,
+                            &test_ncn.operators[0].operator_admin,
+                            meta_merkle_root,
+                            epoch,
+                        )
+                        .await?;
+
+                    fixture.warp_epoch_incremental(10).await?;
+
+                    let signers: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+                    tip_router_client
+                        .do_set_admin_multisig(
+                            ConfigAdminRole::TieBreakerAdmin,
+                            1,
+                            signers.iter().map(|s| s.pubkey()).collect(),
+                            &test_ncn.ncn_root,
+                        )
+                        .await?;
+
+                    // The previous tie breaker admin can no longer set the tie breaker directly
+                    let result = tip_router_client
+                        .do_admin_set_tie_breaker(ncn, meta_merkle_root, epoch)
+                        .await;
+                    assert_tip_router_error(result, TipRouterError::TieBreakerAdminInvalid);
+
+                    let nonce = tip_router_client
+                        .do_propose_admin_action(
+                            ncn,
+                            ConfigAdminRole::TieBreakerAdmin,
+                            AdminAction::SetTieBreaker {
+                                meta_merkle_root,
+                                epoch,
+                            },
+                            &signers[1],
+                        )
+                        .await?;
+
+                    tip_router_client
+                        .do_execute_admin_action(
+                            ncn,
+                            ConfigAdminRole::TieBreakerAdmin,
+                            nonce,
+                            Some(epoch),
+                        )
+                        .await?;
+
+                    let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+                    assert_eq!(
+                        *ballot_box.get_winning_ballot_tally().unwrap().ballot(),
+                        Ballot::new(&meta_merkle_root)
+                    );
+                    assert_eq!(
+                        ballot_box.slot_consensus_reached(),
+                        DEFAULT_CONSENSUS_REACHED_SLOT
+                    );
+                    assert!(ballot_box.is_consensus_reached());
+
+                    Ok(())
+                })
+            })
+            .expect("failed to spawn admin multisig test thread");
+
+        match handle.join() {
+            Ok(result) => result,
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_multisig_stale_proposal_and_new_admin() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        let result = tip_router_client
+            .do_set_admin_multisig(
+                ConfigAdminRole::FeeAdmin,
+                3,
+                vec![Pubkey::new_unique()],
+                &ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidAdminMultisig);
+
+        let signers: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+        let signer_pubkeys: Vec<Pubkey> = signers.iter().map(|s| s.pubkey()).collect();
+        tip_router_client
+            .do_set_admin_multisig(
+                ConfigAdminRole::FeeAdmin,
+                1,
+                signer_pubkeys.clone(),
+                &ncn_root,
+            )
+            .await?;
+
+        let new_fee_admin = Pubkey::new_unique();
+        let action = AdminAction::SetNewAdmin {
+            role: ConfigAdminRole::FeeAdmin,
+            new_admin: new_fee_admin,
+        };
+
+        // Setting the tie breaker is not a fee admin action
+        let result = tip_router_client
+            .do_propose_admin_action(
+                ncn,
+                ConfigAdminRole::FeeAdmin,
+                AdminAction::SetTieBreaker {
+                    meta_merkle_root: [1; 32],
+                    epoch: 0,
+                },
+                &signers[0],
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminActionRoleMismatch);
+
+        let stale_nonce = tip_router_client
+            .do_propose_admin_action(ncn, ConfigAdminRole::FeeAdmin, action.clone(), &signers[0])
+            .await?;
+
+        // Changing the signers invalidates open proposals
+        let signer_nonce = tip_router_client
+            .do_propose_admin_action(
+                ncn,
+                ConfigAdminRole::FeeAdmin,
+                AdminAction::SetMultisigSigner {
+                    role: ConfigAdminRole::FeeAdmin,
+                    signer: signer_pubkeys[1],
+                    is_signer: true,
+                    threshold: 2,
+                },
+                &signers[0],
+            )
+            .await?;
+        tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::FeeAdmin, signer_nonce, None)
+            .await?;
+
+        let admin_multisig = tip_router_client
+            .get_admin_multisig(ncn, ConfigAdminRole::FeeAdmin)
+            .await?;
+        assert_eq!(admin_multisig.signers(), signer_pubkeys.as_slice());
+        assert_eq!(admin_multisig.threshold(), 2);
+
+        let result = tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::FeeAdmin, stale_nonce, None)
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminProposalStale);
+
+        let nonce = tip_router_client
+            .do_propose_admin_action(ncn, ConfigAdminRole::FeeAdmin, action, &signers[0])
+            .await?;
+        assert_eq!(nonce, signer_nonce + 1);
+
+        tip_router_client
+            .do_approve_admin_action(ncn, ConfigAdminRole::FeeAdmin, nonce, &signers[1])
+            .await?;
+        tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::FeeAdmin, nonce, None)
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.fee_admin, new_fee_admin);
+
+        // The multisig handed over the role and can no longer propose
+        let result = tip_router_client
+            .do_propose_admin_action(
+                ncn,
+                ConfigAdminRole::FeeAdmin,
+                set_block_engine_fee(500),
+                &signers[0],
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminMultisigNotActive);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ncn_admin_cannot_rewrite_active_multisig() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        let signers: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+        let signer_pubkeys: Vec<Pubkey> = signers.iter().map(|s| s.pubkey()).collect();
+        tip_router_client
+            .do_set_admin_multisig(
+                ConfigAdminRole::FeeAdmin,
+                2,
+                signer_pubkeys.clone(),
+                &ncn_root,
+            )
+            .await?;
+
+        fixture.warp_slot_incremental(1).await?;
+
+        // The NCN admin can't make the active multisig a 1 of 1 of their own key
+        let result = tip_router_client
+            .do_set_admin_multisig(
+                ConfigAdminRole::FeeAdmin,
+                1,
+                vec![ncn_root.ncn_admin.pubkey()],
+                &ncn_root,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminMultisigActive);
+
+        let admin_multisig = tip_router_client
+            .get_admin_multisig(ncn, ConfigAdminRole::FeeAdmin)
+            .await?;
+        assert_eq!(admin_multisig.signers(), signer_pubkeys.as_slice());
+        assert_eq!(admin_multisig.threshold(), 2);
+
+        // Removing a signer needs the approval of the multisig itself
+        let nonce = tip_router_client
+            .do_propose_admin_action(
+                ncn,
+                ConfigAdminRole::FeeAdmin,
+                AdminAction::SetMultisigSigner {
+                    role: ConfigAdminRole::FeeAdmin,
+                    signer: signer_pubkeys[2],
+                    is_signer: false,
+                    threshold: 1,
+                },
+                &signers[0],
+            )
+            .await?;
+
+        let result = tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::FeeAdmin, nonce, None)
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminProposalThresholdNotMet);
+
+        tip_router_client
+            .do_approve_admin_action(ncn, ConfigAdminRole::FeeAdmin, nonce, &signers[1])
+            .await?;
+        tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::FeeAdmin, nonce, None)
+            .await?;
+
+        let admin_multisig = tip_router_client
+            .get_admin_multisig(ncn, ConfigAdminRole::FeeAdmin)
+            .await?;
+        assert_eq!(admin_multisig.signers(), &signer_pubkeys[..2]);
+        assert_eq!(admin_multisig.threshold(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_multisig_set_paused_subsystems() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        let signers: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+        tip_router_client
+            .do_set_admin_multisig(
+                ConfigAdminRole::PauseAdmin,
+                1,
+                signers.iter().map(|s| s.pubkey()).collect(),
+                &ncn_root,
+            )
+            .await?;
+
+        let nonce = tip_router_client
+            .do_propose_admin_action(
+                ncn,
+                ConfigAdminRole::PauseAdmin,
+                AdminAction::SetPausedSubsystems {
+                    paused_subsystems: PausableSubsystem::Routing.flag(),
+                },
+                &signers[0],
+            )
+            .await?;
+        tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::PauseAdmin, nonce, None)
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert!(config.is_paused(PausableSubsystem::Routing));
+        assert!(!config.is_paused(PausableSubsystem::Voting));
+
+        // The multisig holds the pause admin role, so it can't unpause
+        let nonce = tip_router_client
+            .do_propose_admin_action(
+                ncn,
+                ConfigAdminRole::PauseAdmin,
+                AdminAction::SetPausedSubsystems {
+                    paused_subsystems: 0,
+                },
+                &signers[1],
+            )
+            .await?;
+        let result = tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::PauseAdmin, nonce, None)
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectNcnAdmin);
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert!(config.is_paused(PausableSubsystem::Routing));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_close_admin_proposal() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        let signers: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+        let signer_pubkeys: Vec<Pubkey> = signers.iter().map(|s| s.pubkey()).collect();
+        tip_router_client
+            .do_set_admin_multisig(ConfigAdminRole::FeeAdmin, 2, signer_pubkeys, &ncn_root)
+            .await?;
+
+        let proposal_address = |nonce| {
+            AdminProposal::find_program_address(
+                &jito_tip_router_program::id(),
+                &ncn,
+                CoreConfigAdminRole::FeeAdmin,
+                nonce,
+            )
+            .0
+        };
+
+        // Only the proposer can cancel an open proposal
+        let cancelled_nonce = tip_router_client
+            .do_propose_admin_action(
+                ncn,
+                ConfigAdminRole::FeeAdmin,
+                set_block_engine_fee(500),
+                &signers[0],
+            )
+            .await?;
+
+        let result = tip_router_client
+            .do_close_admin_proposal(ncn, ConfigAdminRole::FeeAdmin, cancelled_nonce, &signers[1])
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminProposalStillOpen);
+
+        let proposal = fixture
+            .get_account(&proposal_address(cancelled_nonce))
+            .await?
+            .unwrap();
+        let proposer_lamports = fixture
+            .get_account(&signers[0].pubkey())
+            .await?
+            .unwrap()
+            .lamports;
+
+        tip_router_client
+            .do_close_admin_proposal(ncn, ConfigAdminRole::FeeAdmin, cancelled_nonce, &signers[0])
+            .await?;
+
+        assert!(fixture
+            .get_account(&proposal_address(cancelled_nonce))
+            .await?
+            .is_none());
+        assert_eq!(
+            fixture
+                .get_account(&signers[0].pubkey())
+                .await?
+                .unwrap()
+                .lamports,
+            proposer_lamports + proposal.lamports
+        );
+
+        // Anyone can close executed proposals
+        let executed_nonce = tip_router_client
+            .do_propose_admin_action(
+                ncn,
+                ConfigAdminRole::FeeAdmin,
+                set_block_engine_fee(600),
+                &signers[0],
+            )
+            .await?;
+        tip_router_client
+            .do_approve_admin_action(ncn, ConfigAdminRole::FeeAdmin, executed_nonce, &signers[1])
+            .await?;
+        tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::FeeAdmin, executed_nonce, None)
+            .await?;
+
+        let proposal = fixture
+            .get_account(&proposal_address(executed_nonce))
+            .await?
+            .unwrap();
+        let proposer_lamports = fixture
+            .get_account(&signers[0].pubkey())
+            .await?
+            .unwrap()
+            .lamports;
+
+        tip_router_client
+            .do_close_admin_proposal(
+                ncn,
+                ConfigAdminRole::FeeAdmin,
+                executed_nonce,
+                &Keypair::new(),
+            )
+            .await?;
+        assert!(fixture
+            .get_account(&proposal_address(executed_nonce))
+            .await?
+            .is_none());
+
+        // The rent still goes back to the proposer
+        assert_eq!(
+            fixture
+                .get_account(&signers[0].pubkey())
+                .await?
+                .unwrap()
+                .lamports,
+            proposer_lamports + proposal.lamports
+        );
+
+        // And stale ones
+        let stale_nonce = tip_router_client
+            .do_propose_admin_action(
+                ncn,
+                ConfigAdminRole::FeeAdmin,
+                set_block_engine_fee(700),
+                &signers[0],
+            )
+            .await?;
+        let signer_nonce = tip_router_client
+            .do_propose_admin_action(
+                ncn,
+                ConfigAdminRole::FeeAdmin,
+                AdminAction::SetMultisigSigner {
+                    role: ConfigAdminRole::FeeAdmin,
+                    signer: signers[1].pubkey(),
+                    is_signer: false,
+                    threshold: 1,
+                },
+                &signers[0],
+            )
+            .await?;
+        tip_router_client
+            .do_approve_admin_action(ncn, ConfigAdminRole::FeeAdmin, signer_nonce, &signers[1])
+            .await?;
+        tip_router_client
+            .do_execute_admin_action(ncn, ConfigAdminRole::FeeAdmin, signer_nonce, None)
+            .await?;
+
+        tip_router_client
+            .do_close_admin_proposal(ncn, ConfigAdminRole::FeeAdmin, stale_nonce, &signers[1])
+            .await?;
+        assert!(fixture
+            .get_account(&proposal_address(stale_nonce))
+            .await?
+            .is_none());
+
+        Ok(())
+    }
+}
@@ -60,4 +60,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_admin_set_consensus_threshold() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn_root = &test_ncn.ncn_root;
+        let ncn = ncn_root.ncn_pubkey;
+
+        // Test invalid thresholds
+        let result = tip_router_client
+            .do_set_consensus_threshold(5_000, ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidConsensusThreshold);
+
+        let result = tip_router_client
+            .do_set_consensus_threshold(10_001, ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidConsensusThreshold);
+
+        tip_router_client
+            .do_set_consensus_threshold(7_500, ncn_root)
+            .await?;
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.consensus_threshold_bps(), 7_500);
+
+        // The threshold is fixed once the epoch state exists
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+        tip_router_client
+            .do_set_consensus_threshold(6_000, ncn_root)
+            .await?;
+        fixture.add_ballot_box_to_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.consensus_threshold_bps(), 7_500);
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert_eq!(ballot_box.consensus_threshold_bps(), 7_500);
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.consensus_threshold_bps(), 6_000);
+
+        Ok(())
+    }
 }
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
 
+    use jito_tip_router_core::events::TipRouterEvent;
+
     use crate::fixtures::{test_builder::TestBuilder, TestResult};
 
     #[tokio::test]
@@ -30,10 +32,20 @@ mod tests {
         let mint = vault.supported_mint;
         let weight = 100;
 
-        tip_router_client
-            .do_admin_set_weight(test_ncn.ncn_root.ncn_pubkey, epoch, mint, weight)
+        let events = tip_router_client
+            .do_admin_set_weight_with_events(test_ncn.ncn_root.ncn_pubkey, epoch, mint, weight)
             .await?;
 
+        assert_eq!(
+            events,
+            vec![TipRouterEvent::AdminWeightSet {
+                ncn: test_ncn.ncn_root.ncn_pubkey,
+                epoch,
+                st_mint: mint,
+                weight,
+            }]
+        );
+
         Ok(())
     }
 }
@@ -2,8 +2,12 @@
 mod tests {
     use jito_tip_router_core::{
         ballot_box::Ballot, constants::MAX_OPERATORS, error::TipRouterError,
+        events::TipRouterEvent, operator_vote_key::OperatorVoteKey,
+    };
+    use solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
     };
-    use solana_sdk::pubkey::Pubkey;
 
     use crate::fixtures::{
         test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
@@ -49,6 +53,66 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_cast_vote_events() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let clock = fixture.clock().await;
+        let slot = clock.slot;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let epoch = clock.epoch;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let meta_merkle_root = [1u8; 32];
+
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+
+        let events = tip_router_client
+            .do_cast_vote_with_events(ncn, operator, operator_admin, meta_merkle_root, epoch)
+            .await?;
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events[0],
+            TipRouterEvent::VoteCast {
+                ncn: event_ncn,
+                epoch: event_epoch,
+                operator: event_operator,
+                meta_merkle_root: event_root,
+                slot: event_slot,
+                ..
+            } if event_ncn == ncn
+                && event_epoch == epoch
+                && event_operator == operator
+                && event_root == meta_merkle_root
+                && event_slot == slot
+        ));
+        assert_eq!(
+            events[1],
+            TipRouterEvent::ConsensusReached {
+                ncn,
+                epoch,
+                meta_merkle_root,
+                slot,
+            }
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_change_vote() -> TestResult<()> {
         let mut fixture = TestBuilder::new().await;
@@ -152,6 +216,246 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_cast_vote_operator_quorum() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(3, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        // Require every operator to vote, even though two thirds of the stake is enough
+        tip_router_client
+            .do_set_quorum(3, 0, &test_ncn.ncn_root)
+            .await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let epoch = fixture.clock().await.epoch;
+
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, epoch).await?;
+        assert_eq!(epoch_snapshot.active_operator_count(), 3);
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let meta_merkle_root = [1u8; 32];
+
+        for operator in test_ncn.operators.iter().take(2) {
+            tip_router_client
+                .do_cast_vote(
+                    ncn,
+                    operator.operator_pubkey,
+                    &operator.operator_admin,
+                    meta_merkle_root,
+                    epoch,
+                )
+                .await?;
+        }
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert_eq!(ballot_box.quorum_operator_count(), 3);
+        assert_eq!(ballot_box.operators_voted(), 2);
+        assert!(!ballot_box.is_consensus_reached());
+
+        let operator = &test_ncn.operators[2];
+        tip_router_client
+            .do_cast_vote(
+                ncn,
+                operator.operator_pubkey,
+                &operator.operator_admin,
+                meta_merkle_root,
+                epoch,
+            )
+            .await?;
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(
+            *ballot_box.get_winning_ballot_tally().unwrap().ballot(),
+            Ballot::new(&meta_merkle_root)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_invalid_quorum() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        // The quorum can't ask for more operators than the NCN has
+        let result = tip_router_client
+            .do_set_quorum(2, 0, &test_ncn.ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidQuorum);
+
+        let result = tip_router_client
+            .do_set_quorum(MAX_OPERATORS as u16 + 1, 0, &test_ncn.ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidQuorum);
+
+        tip_router_client
+            .do_set_quorum(1, 0, &test_ncn.ncn_root)
+            .await?;
+
+        let result = tip_router_client
+            .do_set_quorum(0, 10_001, &test_ncn.ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidQuorum);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cast_vote_stake_weight_caps() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(3, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        // Each operator holds a third of the stake but votes with at most 20% of it
+        tip_router_client
+            .do_set_stake_weight_caps(2_000, 0, &test_ncn.ncn_root)
+            .await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+        fixture.add_admin_weights_for_test_ncn(&test_ncn).await?;
+        fixture.add_epoch_snapshot_to_test_ncn(&test_ncn).await?;
+        fixture
+            .add_operator_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+        fixture
+            .add_vault_operator_delegation_snapshots_to_test_ncn(&test_ncn)
+            .await?;
+        //////
+
+        let epoch = fixture.clock().await.epoch;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let meta_merkle_root = [1u8; 32];
+
+        // The caps depend on the finalized total, votes wait until they are applied
+        let operator = &test_ncn.operators[0];
+        let result = tip_router_client
+            .do_cast_vote(
+                ncn,
+                operator.operator_pubkey,
+                &operator.operator_admin,
+                meta_merkle_root,
+                epoch,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::StakeWeightCapsNotApplied);
+
+        fixture.add_stake_weight_caps_to_test_ncn(&test_ncn).await?;
+
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, epoch).await?;
+        let total_stake_weight = epoch_snapshot.stake_weights().stake_weight();
+        let max_operator_stake_weight = total_stake_weight * 2_000 / 10_000;
+        assert!(epoch_snapshot.stake_weight_caps_applied());
+        assert_eq!(
+            epoch_snapshot.voting_stake_weight().unwrap(),
+            3 * max_operator_stake_weight
+        );
+
+        for operator in test_ncn.operators.iter() {
+            let operator_snapshot = tip_router_client
+                .get_operator_snapshot(operator.operator_pubkey, ncn, epoch)
+                .await?;
+            assert_eq!(operator_snapshot.max_operator_stake_weight_bps(), 2_000);
+            assert_eq!(
+                operator_snapshot.capped_stake_weight(),
+                operator_snapshot.stake_weights().stake_weight() - max_operator_stake_weight
+            );
+        }
+
+        // Caps are only applied once
+        let result = tip_router_client
+            .do_apply_stake_weight_caps(operator.operator_pubkey, ncn, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::StakeWeightCapsAlreadyApplied);
+
+        tip_router_client
+            .do_cast_vote(
+                ncn,
+                operator.operator_pubkey,
+                &operator.operator_admin,
+                meta_merkle_root,
+                epoch,
+            )
+            .await?;
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(!ballot_box.is_consensus_reached());
+        assert_eq!(
+            ballot_box.operator_votes()[0]
+                .stake_weights()
+                .stake_weight(),
+            max_operator_stake_weight
+        );
+
+        // Voting doesn't write to the operator snapshot
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator.operator_pubkey, ncn, epoch)
+            .await?;
+        assert_eq!(
+            operator_snapshot.capped_stake_weight(),
+            operator_snapshot.stake_weights().stake_weight() - max_operator_stake_weight
+        );
+
+        for operator in test_ncn.operators.iter().skip(1) {
+            tip_router_client
+                .do_cast_vote(
+                    ncn,
+                    operator.operator_pubkey,
+                    &operator.operator_admin,
+                    meta_merkle_root,
+                    epoch,
+                )
+                .await?;
+        }
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(ballot_box.is_consensus_reached());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_invalid_stake_weight_caps() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let result = tip_router_client
+            .do_set_stake_weight_caps(99, 0, &test_ncn.ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidStakeWeightCap);
+
+        let result = tip_router_client
+            .do_set_stake_weight_caps(0, 10_001, &test_ncn.ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidStakeWeightCap);
+
+        Ok(())
+    }
+
     #[ignore = "long test"]
     #[tokio::test]
     async fn test_cast_vote_max_cu() -> TestResult<()> {
@@ -200,4 +504,234 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_cast_vote_with_operator_vote_key() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let meta_merkle_root = [1u8; 32];
+        let vote_key = Keypair::new();
+
+        // No vote key registered yet
+        let result = tip_router_client
+            .do_cast_vote(ncn, operator, &vote_key, meta_merkle_root, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidOperatorVoter);
+
+        // Only the operator admin can register a vote key
+        let result = tip_router_client
+            .do_set_operator_vote_key(ncn, operator, &vote_key, vote_key.pubkey())
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectOperatorAdmin);
+
+        tip_router_client
+            .do_set_operator_vote_key(ncn, operator, operator_admin, vote_key.pubkey())
+            .await?;
+
+        let operator_vote_key = tip_router_client
+            .get_operator_vote_key(operator, ncn)
+            .await?;
+        assert_eq!(*operator_vote_key.vote_key(), vote_key.pubkey());
+
+        // Rotating the key revokes the previous one
+        let new_vote_key = Keypair::new();
+        tip_router_client
+            .do_set_operator_vote_key(ncn, operator, operator_admin, new_vote_key.pubkey())
+            .await?;
+
+        let result = tip_router_client
+            .do_cast_vote(ncn, operator, &vote_key, meta_merkle_root, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidOperatorVoter);
+
+        tip_router_client
+            .do_cast_vote(ncn, operator, &new_vote_key, meta_merkle_root, epoch)
+            .await?;
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(ballot_box.has_ballot(&Ballot::new(&meta_merkle_root)));
+        assert!(ballot_box.is_consensus_reached());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_operator_vote_key_rent_is_paid_by_operator_admin() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.warp_slot_incremental(1000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+        let operator_vote_key =
+            OperatorVoteKey::find_program_address(&jito_tip_router_program::id(), &operator, &ncn)
+                .0;
+
+        let operator_admin_lamports = fixture
+            .get_account(&operator_admin.pubkey())
+            .await?
+            .unwrap()
+            .lamports;
+
+        tip_router_client
+            .do_set_operator_vote_key(ncn, operator, operator_admin, Pubkey::new_unique())
+            .await?;
+
+        let operator_vote_key_lamports = fixture
+            .get_account(&operator_vote_key)
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(
+            fixture
+                .get_account(&operator_admin.pubkey())
+                .await?
+                .unwrap()
+                .lamports,
+            operator_admin_lamports - operator_vote_key_lamports
+        );
+
+        // Only the operator admin can close it
+        let wrong_admin = Keypair::new();
+        let result = tip_router_client
+            .do_close_operator_vote_key(ncn, operator, &wrong_admin)
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectOperatorAdmin);
+
+        tip_router_client
+            .do_close_operator_vote_key(ncn, operator, operator_admin)
+            .await?;
+
+        assert!(fixture.get_account(&operator_vote_key).await?.is_none());
+        assert_eq!(
+            fixture
+                .get_account(&operator_admin.pubkey())
+                .await?
+                .unwrap()
+                .lamports,
+            operator_admin_lamports
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_operator_vote_key_inactive_operator() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut restaking_client = fixture.restaking_program_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.warp_slot_incremental(1000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+
+        // The NCN removes the operator
+        restaking_client
+            .do_ncn_cooldown_operator(&test_ncn.ncn_root, &operator)
+            .await?;
+
+        let result = tip_router_client
+            .do_set_operator_vote_key(ncn, operator, operator_admin, Pubkey::new_unique())
+            .await;
+        assert_tip_router_error(result, TipRouterError::OperatorNotActiveInNcn);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_vote() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(2, 1, None).await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+
+        let result = tip_router_client
+            .do_withdraw_vote(ncn, operator, operator_admin, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::OperatorVoteNotFound);
+
+        let meta_merkle_root = [1u8; 32];
+        tip_router_client
+            .do_cast_vote(ncn, operator, operator_admin, meta_merkle_root, epoch)
+            .await?;
+
+        fixture.warp_slot_incremental(1).await?;
+
+        tip_router_client
+            .do_withdraw_vote(ncn, operator, operator_admin, epoch)
+            .await?;
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(!ballot_box.has_ballot(&Ballot::new(&meta_merkle_root)));
+        assert_eq!(ballot_box.operators_voted(), 0);
+        assert_eq!(ballot_box.unique_ballots(), 0);
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.voting_progress().tally(), 0);
+
+        fixture.warp_slot_incremental(1).await?;
+
+        // Both operators vote for the same root to reach consensus
+        for operator_root in test_ncn.operators.iter() {
+            tip_router_client
+                .do_cast_vote(
+                    ncn,
+                    operator_root.operator_pubkey,
+                    &operator_root.operator_admin,
+                    meta_merkle_root,
+                    epoch,
+                )
+                .await?;
+        }
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(ballot_box.is_consensus_reached());
+
+        let result = tip_router_client
+            .do_withdraw_vote(ncn, operator, operator_admin, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::ConsensusAlreadyReached);
+
+        Ok(())
+    }
 }
@@ -2,7 +2,10 @@
 mod tests {
 
     use jito_tip_router_core::ballot_box::BallotBox;
+    use jito_tip_router_core::ballot_box_page::BallotBoxPage;
     use jito_tip_router_core::base_reward_router::{BaseRewardReceiver, BaseRewardRouter};
+    use jito_tip_router_core::base_reward_router_page::BaseRewardRouterPage;
+    use jito_tip_router_core::constants::{MAX_OPERATORS, MAX_VAULTS};
     use jito_tip_router_core::epoch_snapshot::{EpochSnapshot, OperatorSnapshot};
     use jito_tip_router_core::ncn_fee_group::NcnFeeGroup;
     use jito_tip_router_core::ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter};
@@ -30,8 +33,425 @@ mod tests {
         fixture
             .reward_test_ncn(&test_ncn, 10_000, &pool_root)
             .await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch = fixture.clock().await.epoch;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        let epoch_snapshot = tip_router_client.get_epoch_snapshot(ncn, epoch).await?;
+        let base_reward_router = tip_router_client.get_base_reward_router(ncn, epoch).await?;
+
         fixture.close_epoch_accounts_for_test_ncn(&test_ncn).await?;
 
+        // The marker keeps the epoch's consensus and reward totals
+        let epoch_marker = tip_router_client.get_epoch_marker(ncn, epoch).await?;
+        assert_eq!(
+            *epoch_marker.meta_merkle_root(),
+            ballot_box.get_winning_ballot().unwrap().root()
+        );
+        assert_eq!(
+            epoch_marker.slot_consensus_reached(),
+            ballot_box.slot_consensus_reached()
+        );
+        assert!(!epoch_marker.was_tie_breaker_set());
+        assert_eq!(
+            epoch_marker.total_stake_weight(),
+            epoch_snapshot.stake_weights().stake_weight()
+        );
+        assert_eq!(epoch_marker.operators_voted(), OPERATOR_COUNT as u64);
+        assert_eq!(
+            epoch_marker.total_rewards(),
+            base_reward_router.total_rewards()
+        );
+        assert!(epoch_marker.total_rewards() > 0);
+        assert_eq!(
+            epoch_marker.base_rewards() + epoch_marker.ncn_rewards(),
+            epoch_marker.total_rewards()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn close_all_epoch_accounts_past_max_vaults_ok() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut stake_pool_client = fixture.stake_pool_client();
+        let pool_root = stake_pool_client.do_initialize_stake_pool().await?;
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(1, MAX_VAULTS + 1, None)
+            .await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture
+            .reward_test_ncn(&test_ncn, 10_000, &pool_root)
+            .await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let epoch = fixture.clock().await.epoch;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let operator_snapshot_pages = tip_router_client
+            .get_operator_snapshot_pages(operator, ncn, epoch)
+            .await?;
+        assert_eq!(operator_snapshot_pages.len(), 1);
+
+        // Every page is closed before the operator snapshot
+        fixture.close_epoch_accounts_for_test_ncn(&test_ncn).await?;
+
+        let result = fixture.get_account(&operator_snapshot_pages[0]).await?;
+        assert!(result.is_none());
+
+        Ok(())
+    }
+
+    #[ignore = "long test"]
+    #[tokio::test]
+    async fn close_all_epoch_accounts_past_max_operators_ok() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut stake_pool_client = fixture.stake_pool_client();
+        let pool_root = stake_pool_client.do_initialize_stake_pool().await?;
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(MAX_OPERATORS + 1, 1, None)
+            .await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[MAX_OPERATORS].operator_pubkey;
+        let mut tip_router_client = fixture.tip_router_client();
+        assert_eq!(
+            tip_router_client
+                .get_ncn_operator_index(ncn, operator)
+                .await?,
+            MAX_OPERATORS as u64
+        );
+
+        // The operator is snapshotted, votes and is rewarded through the pages
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture
+            .reward_test_ncn(&test_ncn, 10_000_000_000, &pool_root)
+            .await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(ballot_box.is_consensus_reached());
+        assert_eq!(ballot_box.operators_voted(), MAX_OPERATORS as u64 + 1);
+        assert_eq!(ballot_box.page_count(), 1);
+
+        let base_reward_router = tip_router_client.get_base_reward_router(ncn, epoch).await?;
+        assert_eq!(base_reward_router.page_count(), 1);
+        assert!(!base_reward_router.still_routing());
+
+        let mut operator_rewards = 0;
+        for group in NcnFeeGroup::all_groups().iter() {
+            let ncn_reward_router = tip_router_client
+                .get_ncn_reward_router(*group, operator, ncn, epoch)
+                .await?;
+            operator_rewards += ncn_reward_router.total_rewards();
+        }
+        assert!(operator_rewards > 0);
+
+        let ballot_box_page =
+            BallotBoxPage::find_program_address(&jito_tip_router_program::id(), &ncn, epoch, 0).0;
+        let base_reward_router_page = BaseRewardRouterPage::find_program_address_for_mint(
+            &jito_tip_router_program::id(),
+            &ncn,
+            &Pubkey::default(),
+            epoch,
+            0,
+        )
+        .0;
+
+        fixture.close_epoch_accounts_for_test_ncn(&test_ncn).await?;
+
+        let epoch_marker = tip_router_client.get_epoch_marker(ncn, epoch).await?;
+        assert_eq!(epoch_marker.operators_voted(), MAX_OPERATORS as u64 + 1);
+
+        let result = fixture.get_account(&ballot_box_page).await?;
+        assert!(result.is_none());
+        let result = fixture.get_account(&base_reward_router_page).await?;
+        assert!(result.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn carry_forward_rewards_ok() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut stake_pool_client = fixture.stake_pool_client();
+        let pool_root = stake_pool_client.do_initialize_stake_pool().await?;
+
+        const OPERATOR_COUNT: usize = 1;
+        const VAULT_COUNT: usize = 1;
+        const UNROUTED_REWARDS: u64 = 1_000_000;
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(OPERATOR_COUNT, VAULT_COUNT, None)
+            .await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture
+            .reward_test_ncn(&test_ncn, 10_000, &pool_root)
+            .await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let epoch_to_carry = fixture.clock().await.epoch;
+
+        let (base_reward_router, _, _) = BaseRewardRouter::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            epoch_to_carry,
+        );
+        let (base_reward_receiver, _, _) = BaseRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            epoch_to_carry,
+        );
+
+        // Tips that arrive after the last route are never distributed
+        tip_router_client
+            .airdrop_lamports(&base_reward_receiver, UNROUTED_REWARDS)
+            .await?;
+
+        // The epoch is not finished yet
+        {
+            let result = tip_router_client
+                .do_carry_forward_rewards(
+                    ncn,
+                    epoch_to_carry,
+                    base_reward_router,
+                    base_reward_receiver,
+                )
+                .await;
+
+            assert_tip_router_error(result, TipRouterError::CannotCarryForwardRewards);
+        }
+
+        {
+            let config = tip_router_client.get_ncn_config(ncn).await?;
+            fixture
+                .warp_epoch_incremental(config.epochs_after_consensus_before_close() + 1)
+                .await?;
+        }
+
+        let current_epoch = fixture.clock().await.epoch;
+        tip_router_client
+            .do_full_initialize_epoch_state(ncn, current_epoch)
+            .await?;
+
+        let (current_base_reward_receiver, _, _) = BaseRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            current_epoch,
+        );
+
+        let receiver_rent = fixture.rent().await.minimum_balance(0);
+        let rewards_to_carry = fixture
+            .get_account(&base_reward_receiver)
+            .await?
+            .unwrap()
+            .lamports
+            - receiver_rent;
+        assert!(rewards_to_carry >= UNROUTED_REWARDS);
+
+        tip_router_client
+            .do_carry_forward_rewards(
+                ncn,
+                epoch_to_carry,
+                base_reward_router,
+                base_reward_receiver,
+            )
+            .await?;
+
+        let base_reward_receiver_balance = fixture
+            .get_account(&base_reward_receiver)
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(base_reward_receiver_balance, receiver_rent);
+
+        let current_base_reward_receiver_balance = fixture
+            .get_account(&current_base_reward_receiver)
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(current_base_reward_receiver_balance, rewards_to_carry);
+
+        let base_reward_router_account = tip_router_client
+            .get_base_reward_router(ncn, epoch_to_carry)
+            .await?;
+        assert_eq!(
+            base_reward_router_account.rewards_carried_forward(),
+            rewards_to_carry
+        );
+        assert_eq!(base_reward_router_account.total_rewards_in_transit()?, 0);
+
+        let epoch_state = tip_router_client
+            .get_epoch_state(ncn, epoch_to_carry)
+            .await?;
+        assert_eq!(epoch_state.rewards_carried_forward(), rewards_to_carry);
+
+        let current_epoch_state = tip_router_client
+            .get_epoch_state(ncn, current_epoch)
+            .await?;
+        assert_eq!(current_epoch_state.rewards_carried_in(), rewards_to_carry);
+
+        // The router can be closed with only rent left in its receiver
+        tip_router_client
+            .do_close_epoch_account(
+                ncn,
+                epoch_to_carry,
+                base_reward_router,
+                Some(base_reward_receiver),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn carry_forward_rewards_keeps_assigned_rewards() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        const OPERATOR_COUNT: usize = 1;
+        const VAULT_COUNT: usize = 1;
+        const ROUTED_REWARDS: u64 = 10_000_000;
+        const UNROUTED_REWARDS: u64 = 1_000_000;
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(OPERATOR_COUNT, VAULT_COUNT, None)
+            .await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let epoch_to_carry = fixture.clock().await.epoch;
+
+        let (base_reward_router, _, _) = BaseRewardRouter::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            epoch_to_carry,
+        );
+        let (base_reward_receiver, _, _) = BaseRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            epoch_to_carry,
+        );
+
+        {
+            let config = tip_router_client.get_ncn_config(ncn).await?;
+            fixture
+                .warp_slot_incremental(config.valid_slots_after_consensus() + 1)
+                .await?;
+        }
+
+        // Routed into the fee group tallies, but never distributed
+        tip_router_client
+            .airdrop_lamports(&base_reward_receiver, ROUTED_REWARDS)
+            .await?;
+        tip_router_client
+            .do_route_base_rewards(ncn, epoch_to_carry)
+            .await?;
+
+        tip_router_client
+            .airdrop_lamports(&base_reward_receiver, UNROUTED_REWARDS)
+            .await?;
+
+        let rewards_in_transit = tip_router_client
+            .get_base_reward_router(ncn, epoch_to_carry)
+            .await?
+            .total_rewards_in_transit()?;
+        assert_eq!(rewards_in_transit, ROUTED_REWARDS);
+
+        {
+            let config = tip_router_client.get_ncn_config(ncn).await?;
+            fixture
+                .warp_epoch_incremental(config.epochs_after_consensus_before_close() + 1)
+                .await?;
+        }
+
+        let current_epoch = fixture.clock().await.epoch;
+        tip_router_client
+            .do_full_initialize_epoch_state(ncn, current_epoch)
+            .await?;
+
+        let (current_base_reward_receiver, _, _) = BaseRewardReceiver::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            current_epoch,
+        );
+
+        tip_router_client
+            .do_carry_forward_rewards(
+                ncn,
+                epoch_to_carry,
+                base_reward_router,
+                base_reward_receiver,
+            )
+            .await?;
+
+        // Only the unrouted lamports moved
+        let receiver_rent = fixture.rent().await.minimum_balance(0);
+        let base_reward_receiver_balance = fixture
+            .get_account(&base_reward_receiver)
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(
+            base_reward_receiver_balance,
+            receiver_rent + rewards_in_transit
+        );
+
+        let current_base_reward_receiver_balance = fixture
+            .get_account(&current_base_reward_receiver)
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(current_base_reward_receiver_balance, UNROUTED_REWARDS);
+
+        let base_reward_router_account = tip_router_client
+            .get_base_reward_router(ncn, epoch_to_carry)
+            .await?;
+        assert_eq!(
+            base_reward_router_account.rewards_carried_forward(),
+            UNROUTED_REWARDS
+        );
+        assert_eq!(
+            base_reward_router_account.total_rewards_in_transit()?,
+            rewards_in_transit
+        );
+
+        // The assigned NCN fee group rewards can still be distributed
+        let operator_route = base_reward_router_account.ncn_fee_group_reward_route(&operator)?;
+        let mut ncn_rewards = 0;
+        for group in NcnFeeGroup::all_groups().iter() {
+            let rewards = operator_route.rewards(*group)?;
+            if rewards == 0 {
+                continue;
+            }
+
+            tip_router_client
+                .do_distribute_base_ncn_reward_route(*group, operator, ncn, epoch_to_carry)
+                .await?;
+            ncn_rewards += rewards;
+        }
+        assert!(ncn_rewards > 0);
+
+        let base_reward_router_account = tip_router_client
+            .get_base_reward_router(ncn, epoch_to_carry)
+            .await?;
+        assert_eq!(
+            base_reward_router_account.total_rewards_in_transit()?,
+            rewards_in_transit - ncn_rewards
+        );
+
         Ok(())
     }
 
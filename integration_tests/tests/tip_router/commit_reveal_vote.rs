@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::{
+        ballot_box::{Ballot, OperatorVote},
+        error::TipRouterError,
+    };
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_commit_reveal_vote() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        let commit_window_slots = 1000;
+        let reveal_window_slots = 1000;
+        tip_router_client
+            .do_set_commit_reveal_windows(
+                commit_window_slots,
+                reveal_window_slots,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let clock = fixture.clock().await;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+        let epoch = clock.epoch;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let meta_merkle_root = [1u8; 32];
+        let salt = [2u8; 32];
+        let vote_commitment = OperatorVote::commitment_hash(&meta_merkle_root, &salt, &operator);
+
+        // Direct votes are not accepted while commit-reveal is enabled
+        let result = tip_router_client
+            .do_cast_vote(ncn, operator, operator_admin, meta_merkle_root, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::CommitRevealRequired);
+
+        tip_router_client
+            .do_commit_vote(ncn, operator, operator_admin, vote_commitment, epoch)
+            .await?;
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert!(ballot_box.is_commit_reveal());
+        assert_eq!(ballot_box.operators_committed(), 1);
+        assert_eq!(ballot_box.operators_voted(), 0);
+        assert!(!ballot_box.has_ballot(&Ballot::new(&meta_merkle_root)));
+
+        // Reveals only open once the commit window has passed
+        let result = tip_router_client
+            .do_reveal_vote(ncn, operator, operator_admin, meta_merkle_root, salt, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::RevealPhaseNotStarted);
+
+        fixture.warp_slot_incremental(commit_window_slots).await?;
+
+        // The revealed root has to match the commitment
+        let result = tip_router_client
+            .do_reveal_vote(ncn, operator, operator_admin, [3u8; 32], salt, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::VoteCommitmentMismatch);
+
+        tip_router_client
+            .do_reveal_vote(ncn, operator, operator_admin, meta_merkle_root, salt, epoch)
+            .await?;
+
+        let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+        assert_eq!(ballot_box.operators_voted(), 1);
+        assert!(ballot_box.has_ballot(&Ballot::new(&meta_merkle_root)));
+        assert!(ballot_box.is_consensus_reached());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_commit_vote_without_commit_reveal() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        //////
+
+        let clock = fixture.clock().await;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+        let operator_admin = &test_ncn.operators[0].operator_admin;
+        let epoch = clock.epoch;
+
+        tip_router_client
+            .do_full_initialize_ballot_box(ncn, epoch)
+            .await?;
+
+        let vote_commitment = OperatorVote::commitment_hash(&[1u8; 32], &[2u8; 32], &operator);
+
+        let result = tip_router_client
+            .do_commit_vote(ncn, operator, operator_admin, vote_commitment, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::CommitRevealNotEnabled);
+
+        Ok(())
+    }
+}
@@ -2,14 +2,23 @@
 mod tests {
 
     use jito_tip_router_core::{
+        account_payer::AccountPayer,
         base_fee_group::BaseFeeGroup,
         base_reward_router::BaseRewardReceiver,
-        constants::{JITOSOL_MINT, MAX_OPERATORS, MAX_VAULTS},
+        constants::{JITOSOL_MINT, MAX_OPERATORS, MAX_VAULTS, MIN_CRANK_BOUNTY_REWARDS_MULTIPLE},
+        error::TipRouterError,
+        events::TipRouterEvent,
         ncn_fee_group::{NcnFeeGroup, NcnFeeGroupType},
+        payout_preference::PayoutAsset,
+    };
+    use solana_sdk::{
+        clock::DEFAULT_SLOTS_PER_EPOCH, instruction::InstructionError, pubkey::Pubkey,
+        signature::Keypair, signer::Signer, transaction::TransactionError,
     };
-    use solana_sdk::{clock::DEFAULT_SLOTS_PER_EPOCH, signature::Keypair, signer::Signer};
 
-    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
 
     #[tokio::test]
     async fn test_remainder_rewards() -> TestResult<()> {
@@ -78,6 +87,293 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_crank_bounty() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        const CRANK_BOUNTY_LAMPORTS: u64 = 1_000;
+        const MAX_CRANK_BOUNTY_LAMPORTS_PER_EPOCH: u64 = 1_500;
+
+        let test_ncn = fixture.create_initial_test_ncn(2, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        tip_router_client
+            .do_set_crank_bounty(
+                CRANK_BOUNTY_LAMPORTS,
+                MAX_CRANK_BOUNTY_LAMPORTS_PER_EPOCH,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        tip_router_client.airdrop(&account_payer, 1.0).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        let valid_slots_after_consensus = {
+            let config = tip_router_client.get_ncn_config(ncn).await?;
+            config.valid_slots_after_consensus()
+        };
+
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        let account_payer_initial_balance =
+            fixture.get_account(&account_payer).await?.unwrap().lamports;
+
+        // Each routing of new rewards earns a bounty until the epoch's cap is reached
+        for _ in 0..3 {
+            tip_router_client
+                .airdrop_lamports(&base_reward_receiver, 1_000_000)
+                .await?;
+            tip_router_client.do_route_base_rewards(ncn, epoch).await?;
+        }
+
+        // Routing without new rewards does no work and earns nothing
+        tip_router_client.do_route_base_rewards(ncn, epoch).await?;
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(
+            epoch_state.crank_bounties_paid(),
+            MAX_CRANK_BOUNTY_LAMPORTS_PER_EPOCH
+        );
+
+        let account_payer_final_balance =
+            fixture.get_account(&account_payer).await?.unwrap().lamports;
+        assert_eq!(
+            account_payer_initial_balance - account_payer_final_balance,
+            MAX_CRANK_BOUNTY_LAMPORTS_PER_EPOCH
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_crank_bounty_minimum_rewards() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        const CRANK_BOUNTY_LAMPORTS: u64 = 1_000;
+        const MAX_CRANK_BOUNTY_LAMPORTS_PER_EPOCH: u64 = 10_000;
+
+        let test_ncn = fixture.create_initial_test_ncn(2, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        tip_router_client
+            .do_set_crank_bounty(
+                CRANK_BOUNTY_LAMPORTS,
+                MAX_CRANK_BOUNTY_LAMPORTS_PER_EPOCH,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        tip_router_client.airdrop(&account_payer, 1.0).await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        let valid_slots_after_consensus = {
+            let config = tip_router_client.get_ncn_config(ncn).await?;
+            config.valid_slots_after_consensus()
+        };
+
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+
+        // Dust below the minimum is routed without a bounty
+        for _ in 0..3 {
+            tip_router_client
+                .airdrop_lamports(
+                    &base_reward_receiver,
+                    CRANK_BOUNTY_LAMPORTS * MIN_CRANK_BOUNTY_REWARDS_MULTIPLE - 1,
+                )
+                .await?;
+            tip_router_client.do_route_base_rewards(ncn, epoch).await?;
+        }
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.crank_bounties_paid(), 0);
+
+        tip_router_client
+            .airdrop_lamports(
+                &base_reward_receiver,
+                CRANK_BOUNTY_LAMPORTS * MIN_CRANK_BOUNTY_REWARDS_MULTIPLE,
+            )
+            .await?;
+        tip_router_client.do_route_base_rewards(ncn, epoch).await?;
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.crank_bounties_paid(), CRANK_BOUNTY_LAMPORTS);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_crank_bounty_token_rewards() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut vault_client = fixture.vault_client();
+        let mut stake_pool_client = fixture.stake_pool_client();
+        let pool_root = stake_pool_client.do_initialize_stake_pool().await?;
+
+        const CRANK_BOUNTY_LAMPORTS: u64 = 1_000;
+        const MAX_CRANK_BOUNTY_LAMPORTS_PER_EPOCH: u64 = 10_000;
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, Some(1000)).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let dao_wallet = Keypair::new();
+        tip_router_client.airdrop(&dao_wallet.pubkey(), 1.0).await?;
+        tip_router_client
+            .do_set_config_fees(
+                None,
+                None,
+                Some(dao_wallet.pubkey()),
+                Some(270),
+                None,
+                Some(15),
+                &test_ncn.ncn_root,
+                None,
+            )
+            .await?;
+
+        tip_router_client
+            .do_set_crank_bounty(
+                CRANK_BOUNTY_LAMPORTS,
+                MAX_CRANK_BOUNTY_LAMPORTS_PER_EPOCH,
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        tip_router_client.airdrop(&account_payer, 1.0).await?;
+
+        fixture
+            .warp_slot_incremental(DEFAULT_SLOTS_PER_EPOCH * 2)
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        // A mint worth nothing, whose base units would still clear the bounty minimum
+        // many times over if they were taken for lamports
+        let mint = Keypair::new();
+        vault_client
+            .create_token_mint(&mint, &spl_token_interface::id())
+            .await?;
+        let mint = mint.pubkey();
+
+        tip_router_client
+            .do_set_reward_mint(ncn, mint, true, &test_ncn.ncn_root.ncn_admin)
+            .await?;
+        tip_router_client
+            .do_full_initialize_token_base_reward_router(ncn, mint, epoch)
+            .await?;
+        for group in NcnFeeGroup::all_groups().iter() {
+            tip_router_client
+                .do_initialize_token_ncn_reward_router(*group, ncn, operator, mint, epoch)
+                .await?;
+        }
+
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        vault_client
+            .mint_spl_to(
+                &mint,
+                &base_reward_receiver,
+                CRANK_BOUNTY_LAMPORTS * MIN_CRANK_BOUNTY_REWARDS_MULTIPLE * 1_000,
+            )
+            .await?;
+
+        let valid_slots_after_consensus = {
+            let config = tip_router_client.get_ncn_config(ncn).await?;
+            config.valid_slots_after_consensus()
+        };
+
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        let account_payer_initial_balance =
+            fixture.get_account(&account_payer).await?.unwrap().lamports;
+
+        // Every token step is cranked with the bounty accounts
+        tip_router_client
+            .do_route_token_base_rewards(ncn, mint, epoch)
+            .await?;
+        tip_router_client
+            .do_distribute_token_base_rewards(BaseFeeGroup::dao(), ncn, mint, epoch, &pool_root)
+            .await?;
+
+        let mut operator_rewards = 0;
+        for group in NcnFeeGroup::all_groups().iter() {
+            tip_router_client
+                .do_distribute_token_base_ncn_reward_route(*group, operator, ncn, mint, epoch)
+                .await?;
+
+            tip_router_client
+                .do_route_token_ncn_rewards(*group, ncn, operator, mint, epoch)
+                .await?;
+
+            let ncn_reward_router = tip_router_client
+                .get_token_ncn_reward_router(*group, operator, ncn, mint, epoch)
+                .await?;
+            if ncn_reward_router.operator_rewards() == 0 {
+                continue;
+            }
+            operator_rewards += ncn_reward_router.operator_rewards();
+
+            tip_router_client
+                .do_distribute_token_ncn_operator_rewards(
+                    *group, operator, ncn, mint, epoch, &pool_root,
+                )
+                .await?;
+        }
+        assert!(operator_rewards > 0);
+
+        // Token amounts are not lamports, moving them earns no bounty
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.crank_bounties_paid(), 0);
+
+        let account_payer_final_balance =
+            fixture.get_account(&account_payer).await?.unwrap().lamports;
+        assert_eq!(account_payer_final_balance, account_payer_initial_balance);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_route_and_distribute_base_rewards() -> TestResult<()> {
         let mut fixture = TestBuilder::new().await;
@@ -377,6 +673,526 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_distribute_base_rewards_to_base_fee_groups() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut stake_pool_client = fixture.stake_pool_client();
+        let pool_root = stake_pool_client.do_initialize_stake_pool().await?;
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let dao_wallet = Pubkey::new_unique();
+        let grants_wallet = Pubkey::new_unique();
+        tip_router_client.airdrop(&dao_wallet, 1.0).await?;
+        tip_router_client.airdrop(&grants_wallet, 1.0).await?;
+
+        let grants_group = BaseFeeGroup::try_from(1).unwrap();
+
+        // Split the DAO fee between the DAO and a grants wallet paid in SOL
+        tip_router_client
+            .do_set_config_fees(
+                Some(300),
+                None,
+                Some(dao_wallet),
+                Some(180),
+                None,
+                Some(15),
+                &test_ncn.ncn_root,
+                None,
+            )
+            .await?;
+
+        let result = tip_router_client
+            .do_set_config_fees(
+                None,
+                Some(grants_group),
+                Some(grants_wallet),
+                Some(90),
+                None,
+                None,
+                &test_ncn.ncn_root,
+                None,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::BaseFeeGroupDisabled);
+
+        tip_router_client
+            .do_set_base_fee_group(
+                grants_group,
+                Some("Grants"),
+                Some(true),
+                Some(PayoutAsset::Sol),
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        tip_router_client
+            .do_set_config_fees(
+                None,
+                Some(grants_group),
+                Some(grants_wallet),
+                Some(90),
+                None,
+                None,
+                &test_ncn.ncn_root,
+                None,
+            )
+            .await?;
+
+        fixture
+            .warp_slot_incremental(DEFAULT_SLOTS_PER_EPOCH * 2)
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        tip_router_client
+            .airdrop_lamports(&base_reward_receiver, 10_000)
+            .await?;
+
+        let valid_slots_after_consensus = tip_router_client
+            .get_ncn_config(ncn)
+            .await?
+            .valid_slots_after_consensus();
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        tip_router_client.do_route_base_rewards(ncn, epoch).await?;
+
+        let base_reward_router = tip_router_client.get_base_reward_router(ncn, epoch).await?;
+        let dao_rewards = base_reward_router
+            .base_fee_group_reward(BaseFeeGroup::dao())
+            .unwrap();
+        let grants_rewards = base_reward_router
+            .base_fee_group_reward(grants_group)
+            .unwrap();
+        assert!(dao_rewards > 0);
+        assert!(grants_rewards > 0);
+
+        stake_pool_client
+            .update_stake_pool_balance(&pool_root)
+            .await?;
+
+        let grants_initial_balance = fixture.get_account(&grants_wallet).await?.unwrap().lamports;
+
+        for group in [BaseFeeGroup::dao(), grants_group] {
+            tip_router_client
+                .do_distribute_base_rewards(group, ncn, epoch, &pool_root)
+                .await?;
+        }
+
+        // The DAO is paid in JitoSOL, the grants wallet in SOL
+        let dao_lst_balance = fixture
+            .get_associated_token_account(&dao_wallet, &JITOSOL_MINT)
+            .await?
+            .map_or(0, |account| account.amount);
+        assert!(dao_lst_balance > 0);
+
+        let grants_final_balance = fixture.get_account(&grants_wallet).await?.unwrap().lamports;
+        assert_eq!(
+            grants_final_balance - grants_initial_balance,
+            grants_rewards
+        );
+
+        let base_reward_router = tip_router_client.get_base_reward_router(ncn, epoch).await?;
+        assert_eq!(
+            base_reward_router
+                .base_fee_group_reward(grants_group)
+                .unwrap(),
+            0
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_distribute_ncn_rewards_in_payout_asset() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut stake_pool_client = fixture.stake_pool_client();
+        let pool_root = stake_pool_client.do_initialize_stake_pool().await?;
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, Some(1000)).await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator_root = &test_ncn.operators[0];
+        let operator = operator_root.operator_pubkey;
+        let vault_root = &test_ncn.vaults[0];
+        let vault = vault_root.vault_pubkey;
+
+        let dao_wallet = Keypair::new();
+        let dao_wallet_address = dao_wallet.pubkey();
+        tip_router_client.airdrop(&dao_wallet_address, 1.0).await?;
+
+        tip_router_client
+            .do_set_config_fees(
+                Some(300), // block engine fee = 3%
+                None,
+                Some(dao_wallet_address), // DAO wallet
+                Some(270),                // DAO fee = 2.7%
+                None,
+                Some(15), // NCN fee = .15%
+                &test_ncn.ncn_root,
+                None,
+            )
+            .await?;
+
+        // The operator is paid in SOL, the vault keeps the JitoSOL default
+        let sol_destination = Keypair::new().pubkey();
+        tip_router_client.airdrop(&sol_destination, 1.0).await?;
+
+        // Only the operator admin can set the payout preference
+        let result = tip_router_client
+            .do_set_payout_preference(
+                ncn,
+                operator,
+                &vault_root.vault_admin,
+                PayoutAsset::Sol,
+                sol_destination,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectOperatorAdmin);
+
+        // Paying out in SOL needs a destination
+        let result = tip_router_client
+            .do_set_payout_preference(
+                ncn,
+                operator,
+                &operator_root.operator_admin,
+                PayoutAsset::Sol,
+                Pubkey::default(),
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidPayoutDestination);
+
+        // Vaults can't send their rewards to a SOL destination
+        let result = tip_router_client
+            .do_set_payout_preference(
+                ncn,
+                vault,
+                &vault_root.vault_admin,
+                PayoutAsset::Sol,
+                sol_destination,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::VaultSolPayoutNotAllowed);
+
+        // The operator admin pays for the payout preference, not the account payer
+        let (account_payer, _, _) =
+            AccountPayer::find_program_address(&jito_tip_router_program::id(), &ncn);
+        let account_payer_balance = fixture.get_account(&account_payer).await?.unwrap().lamports;
+
+        let events = tip_router_client
+            .do_set_payout_preference_with_events(
+                ncn,
+                operator,
+                &operator_root.operator_admin,
+                PayoutAsset::Sol,
+                sol_destination,
+            )
+            .await?;
+        assert_eq!(
+            events,
+            vec![TipRouterEvent::PayoutPreferenceSet {
+                ncn,
+                recipient: operator,
+                payout_asset: PayoutAsset::Sol as u8,
+                sol_destination,
+            }]
+        );
+
+        let payout_preference = tip_router_client
+            .get_payout_preference(operator, ncn)
+            .await?
+            .unwrap();
+        assert_eq!(payout_preference.payout_asset().unwrap(), PayoutAsset::Sol);
+        assert_eq!(*payout_preference.sol_destination(), sol_destination);
+        assert_eq!(
+            fixture.get_account(&account_payer).await?.unwrap().lamports,
+            account_payer_balance
+        );
+        assert!(tip_router_client
+            .get_payout_preference(vault, ncn)
+            .await?
+            .is_none());
+
+        fixture
+            .warp_slot_incremental(DEFAULT_SLOTS_PER_EPOCH * 2)
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        tip_router_client
+            .airdrop_lamports(&base_reward_receiver, 1_000_000)
+            .await?;
+
+        let valid_slots_after_consensus = {
+            let config = tip_router_client.get_ncn_config(ncn).await?;
+            config.valid_slots_after_consensus()
+        };
+
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        tip_router_client.do_route_base_rewards(ncn, epoch).await?;
+
+        stake_pool_client
+            .update_stake_pool_balance(&pool_root)
+            .await?;
+
+        for group in NcnFeeGroup::all_groups().iter() {
+            tip_router_client
+                .do_distribute_base_ncn_reward_route(*group, operator, ncn, epoch)
+                .await?;
+        }
+
+        let sol_destination_initial_balance = fixture
+            .get_account(&sol_destination)
+            .await?
+            .unwrap()
+            .lamports;
+
+        let mut operator_rewards = 0;
+        for group in NcnFeeGroup::all_groups().iter() {
+            tip_router_client
+                .do_route_ncn_rewards(*group, ncn, operator, epoch)
+                .await?;
+
+            let ncn_reward_router = tip_router_client
+                .get_ncn_reward_router(*group, operator, ncn, epoch)
+                .await?;
+            operator_rewards += ncn_reward_router.operator_rewards();
+
+            tip_router_client
+                .do_distribute_ncn_operator_rewards(*group, operator, ncn, epoch, &pool_root)
+                .await?;
+
+            if ncn_reward_router.vault_reward_route(&vault).is_ok() {
+                tip_router_client
+                    .do_distribute_ncn_vault_rewards(
+                        *group, vault, operator, ncn, epoch, &pool_root,
+                    )
+                    .await?;
+            }
+        }
+        assert!(operator_rewards > 0);
+
+        // Operator rewards are paid in SOL, without going through the stake pool
+        let sol_destination_final_balance = fixture
+            .get_account(&sol_destination)
+            .await?
+            .unwrap()
+            .lamports;
+        assert_eq!(
+            sol_destination_final_balance - sol_destination_initial_balance,
+            operator_rewards
+        );
+
+        // Vault rewards are still paid in JitoSOL
+        let vault_balance = fixture
+            .get_associated_token_account(&vault, &JITOSOL_MINT)
+            .await?
+            .map_or(0, |account| account.amount);
+        assert!(vault_balance > 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_route_and_distribute_token_rewards() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let mut vault_client = fixture.vault_client();
+        let mut stake_pool_client = fixture.stake_pool_client();
+        let pool_root = stake_pool_client.do_initialize_stake_pool().await?;
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, Some(1000)).await?;
+
+        ///// TipRouter Setup /////
+        fixture.warp_slot_incremental(1000).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+
+        let dao_wallet = Keypair::new();
+        let dao_wallet_address = dao_wallet.pubkey();
+        tip_router_client.airdrop(&dao_wallet_address, 1.0).await?;
+
+        tip_router_client
+            .do_set_config_fees(
+                Some(300), // block engine fee = 3%
+                None,
+                Some(dao_wallet_address), // DAO wallet
+                Some(270),                // DAO fee = 2.7%
+                None,
+                Some(15), // NCN fee = .15%
+                &test_ncn.ncn_root,
+                None,
+            )
+            .await?;
+
+        fixture
+            .warp_slot_incremental(DEFAULT_SLOTS_PER_EPOCH * 2)
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        // Reward token
+        let mint = Keypair::new();
+        vault_client
+            .create_token_mint(&mint, &spl_token_interface::id())
+            .await?;
+        let mint = mint.pubkey();
+
+        // Token routers can only be created for mints the NCN admin allowed
+        let result = tip_router_client
+            .do_initialize_token_ncn_reward_router(
+                NcnFeeGroup::default(),
+                ncn,
+                operator,
+                mint,
+                epoch,
+            )
+            .await;
+        assert_eq!(
+            result.err().unwrap().to_transaction_error().unwrap(),
+            TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(TipRouterError::RewardMintNotAllowed as u32)
+            )
+        );
+
+        let result = tip_router_client
+            .do_set_reward_mint(ncn, mint, true, &Keypair::new())
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectNcnAdmin);
+
+        tip_router_client
+            .do_set_reward_mint(ncn, mint, true, &test_ncn.ncn_root.ncn_admin)
+            .await?;
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert!(config.is_reward_mint(&mint));
+
+        tip_router_client
+            .do_full_initialize_token_base_reward_router(ncn, mint, epoch)
+            .await?;
+        for group in NcnFeeGroup::all_groups().iter() {
+            tip_router_client
+                .do_initialize_token_ncn_reward_router(*group, ncn, operator, mint, epoch)
+                .await?;
+        }
+
+        // Token routers keep the epoch state open until they are closed
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(
+            epoch_state.open_token_reward_routers() as usize,
+            1 + NcnFeeGroup::all_groups().len()
+        );
+
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        vault_client
+            .mint_spl_to(&mint, &base_reward_receiver, 1_000_000_000)
+            .await?;
+
+        let valid_slots_after_consensus = {
+            let config = tip_router_client.get_ncn_config(ncn).await?;
+            config.valid_slots_after_consensus()
+        };
+
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        tip_router_client
+            .do_route_token_base_rewards(ncn, mint, epoch)
+            .await?;
+
+        // SOL routers are left untouched
+        let base_reward_router = tip_router_client.get_base_reward_router(ncn, epoch).await?;
+        assert_eq!(base_reward_router.total_rewards(), 0);
+
+        let token_base_reward_router = tip_router_client
+            .get_token_base_reward_router(ncn, mint, epoch)
+            .await?;
+        assert_eq!(token_base_reward_router.mint(), &mint);
+        assert_eq!(token_base_reward_router.total_rewards(), 1_000_000_000);
+
+        let dao_rewards = token_base_reward_router
+            .base_fee_group_reward(BaseFeeGroup::dao())
+            .unwrap();
+        assert!(dao_rewards > 0);
+
+        tip_router_client
+            .do_distribute_token_base_rewards(BaseFeeGroup::dao(), ncn, mint, epoch, &pool_root)
+            .await?;
+
+        // DAO rewards are paid in the token, without going through the stake pool
+        let dao_balance = fixture
+            .get_associated_token_account(&dao_wallet_address, &mint)
+            .await?
+            .map_or(0, |account| account.amount);
+        assert_eq!(dao_balance, dao_rewards);
+
+        let mut operator_rewards = 0;
+        for group in NcnFeeGroup::all_groups().iter() {
+            tip_router_client
+                .do_distribute_token_base_ncn_reward_route(*group, operator, ncn, mint, epoch)
+                .await?;
+
+            tip_router_client
+                .do_route_token_ncn_rewards(*group, ncn, operator, mint, epoch)
+                .await?;
+
+            let ncn_reward_router = tip_router_client
+                .get_token_ncn_reward_router(*group, operator, ncn, mint, epoch)
+                .await?;
+            if ncn_reward_router.operator_rewards() == 0 {
+                continue;
+            }
+            operator_rewards += ncn_reward_router.operator_rewards();
+
+            tip_router_client
+                .do_distribute_token_ncn_operator_rewards(
+                    *group, operator, ncn, mint, epoch, &pool_root,
+                )
+                .await?;
+        }
+        assert!(operator_rewards > 0);
+
+        let operator_balance = fixture
+            .get_associated_token_account(&operator, &mint)
+            .await?
+            .map_or(0, |account| account.amount);
+        assert_eq!(operator_balance, operator_rewards);
+
+        Ok(())
+    }
+
     #[ignore = "20-30 minute test"]
     #[tokio::test]
     async fn test_route_rewards_to_max_accounts() -> TestResult<()> {
@@ -421,6 +1237,10 @@ mod tests {
 
         // Set all Base fee groups to something
         for group in BaseFeeGroup::all_groups().iter() {
+            tip_router_client
+                .do_set_base_fee_group(*group, None, Some(true), None, &test_ncn.ncn_root)
+                .await?;
+
             tip_router_client
                 .do_set_config_fees(
                     None,
@@ -452,8 +1272,13 @@ mod tests {
         }
 
         // Set all vaults to a different type of reward
+        let epoch = fixture.clock().await.epoch;
         let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
-        for (index, mint_entry) in vault_registry.get_valid_mint_entries().iter().enumerate() {
+        for (index, mint_entry) in vault_registry
+            .get_valid_mint_entries(epoch)
+            .iter()
+            .enumerate()
+        {
             let group_index = index % NcnFeeGroup::all_groups().len();
 
             tip_router_client
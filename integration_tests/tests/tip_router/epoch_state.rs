@@ -1,8 +1,11 @@
 #[cfg(test)]
 mod tests {
-    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
     use jito_tip_router_core::{
-        constants::MAX_OPERATORS, epoch_state::AccountStatus, ncn_fee_group::NcnFeeGroup,
+        constants::MAX_OPERATORS, epoch_state::AccountStatus, error::TipRouterError,
+        ncn_fee_group::NcnFeeGroup,
     };
 
     #[tokio::test]
@@ -138,9 +141,18 @@ mod tests {
             let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
 
             for i in 0..OPERATOR_COUNT {
-                assert_eq!(epoch_state.operator_snapshot_progress(i).tally(), 0);
                 assert_eq!(
-                    epoch_state.operator_snapshot_progress(i).total(),
+                    epoch_state
+                        .operator_snapshot_progress(i, None)
+                        .unwrap()
+                        .tally(),
+                    0
+                );
+                assert_eq!(
+                    epoch_state
+                        .operator_snapshot_progress(i, None)
+                        .unwrap()
+                        .total(),
                     VAULT_COUNT as u64
                 );
             }
@@ -188,14 +200,23 @@ mod tests {
 
             for i in 0..OPERATOR_COUNT {
                 assert_eq!(
-                    epoch_state.operator_snapshot_progress(i).tally(),
+                    epoch_state
+                        .operator_snapshot_progress(i, None)
+                        .unwrap()
+                        .tally(),
                     VAULT_COUNT as u64
                 );
                 assert_eq!(
-                    epoch_state.operator_snapshot_progress(i).total(),
+                    epoch_state
+                        .operator_snapshot_progress(i, None)
+                        .unwrap()
+                        .total(),
                     VAULT_COUNT as u64
                 );
-                assert!(epoch_state.operator_snapshot_progress(i).is_complete());
+                assert!(epoch_state
+                    .operator_snapshot_progress(i, None)
+                    .unwrap()
+                    .is_complete());
             }
         }
 
@@ -313,20 +334,20 @@ mod tests {
                 if i < OPERATOR_COUNT && group == NcnFeeGroup::default() {
                     assert_eq!(
                         epoch_state
-                            .ncn_distribution_progress(i, group)
+                            .ncn_distribution_progress(i, group, None)
                             .unwrap()
                             .total(),
                         expected_operator_router_rewards
                     );
                     assert_eq!(
                         epoch_state
-                            .ncn_distribution_progress(i, group)
+                            .ncn_distribution_progress(i, group, None)
                             .unwrap()
                             .tally(),
                         expected_operator_router_rewards
                     );
                     assert!(epoch_state
-                        .ncn_distribution_progress(i, group)
+                        .ncn_distribution_progress(i, group, None)
                         .unwrap()
                         .is_complete());
                 } else if i >= OPERATOR_COUNT {
@@ -343,4 +364,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_initialize_epoch_state_page_not_needed() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        // All operators of a single operator NCN fit in the epoch state
+        let result = tip_router_client
+            .do_initialize_epoch_state_page(ncn, epoch, 0)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidEpochStatePage);
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.page_count(), 0);
+
+        Ok(())
+    }
 }
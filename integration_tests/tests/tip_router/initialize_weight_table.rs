@@ -95,4 +95,61 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_initialize_paged_weight_table_ok() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(1, MAX_VAULTS + 1, None)
+            .await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_weight_table(ncn, epoch)
+            .await?;
+        let num_reallocs = (WeightTable::SIZE as f64 / MAX_REALLOC_BYTES as f64).ceil() as u64 - 1;
+        tip_router_client
+            .do_realloc_weight_table(ncn, epoch, num_reallocs)
+            .await?;
+
+        // The last vault is only in the weight table once its page is initialized
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        assert_eq!(weight_table.page_count(), 1);
+        assert_eq!(weight_table.vault_count(), MAX_VAULTS as u64);
+        assert!(!weight_table.vault_registry_initialized());
+
+        // Pages are initialized in order
+        let result = tip_router_client
+            .do_initialize_weight_table_page(ncn, epoch, 1)
+            .await;
+        assert!(result.is_err());
+
+        tip_router_client
+            .do_initialize_weight_table_page(ncn, epoch, 0)
+            .await?;
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        assert_eq!(weight_table.pages_initialized(), 1);
+        assert_eq!(weight_table.vault_count(), MAX_VAULTS as u64 + 1);
+        assert!(weight_table.vault_registry_initialized());
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.vault_count(), MAX_VAULTS as u64 + 1);
+
+        // Every page of the weight table is initialized
+        fixture.warp_slot_incremental(1).await?;
+        let result = tip_router_client
+            .do_initialize_weight_table_page(ncn, epoch, 0)
+            .await;
+        assert!(result.is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use jito_bytemuck::types::PodU16;
+    use jito_tip_router_core::{
+        account_version::{VersionedAccount, ACCOUNT_VERSION_INDEX},
+        base_fee_group::BaseFeeGroup,
+        config::{Config as NcnConfig, ConfigV0},
+        epoch_state::EpochState,
+        error::TipRouterError,
+        fees::{FeeConfigV0, Fees},
+        vault_registry::VaultRegistry,
+    };
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_migrate_vault_registry() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        // Roll the vault registry back to the layout version before versions were tracked
+        let vault_registry =
+            VaultRegistry::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let mut raw_account = fixture.get_account(&vault_registry).await?.unwrap();
+        raw_account.data[ACCOUNT_VERSION_INDEX] = 0;
+        fixture.set_account(vault_registry, raw_account).await;
+
+        let result = tip_router_client
+            .do_initialize_weight_table(ncn, epoch)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidAccountVersion);
+
+        tip_router_client
+            .do_migrate_account(ncn, vault_registry)
+            .await?;
+
+        let raw_account = fixture.get_account(&vault_registry).await?.unwrap();
+        assert_eq!(
+            raw_account.data[ACCOUNT_VERSION_INDEX],
+            VaultRegistry::ACCOUNT_VERSION
+        );
+        assert_eq!(raw_account.data.len(), VaultRegistry::SIZE);
+
+        fixture.warp_slot_incremental(1).await?;
+        tip_router_client
+            .do_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        // Already on the current version
+        fixture.warp_slot_incremental(1).await?;
+        let result = tip_router_client
+            .do_migrate_account(ncn, vault_registry)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidAccountVersion);
+
+        // Epoch states have no versioned layout
+        let epoch_state =
+            EpochState::find_program_address(&jito_tip_router_program::id(), &ncn, epoch).0;
+        let result = tip_router_client.do_migrate_account(ncn, epoch_state).await;
+        assert_tip_router_error(result, TipRouterError::AccountNotVersioned);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_migrate_config_from_v0() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        let epoch = fixture.clock().await.epoch;
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        let dao_wallet = *config
+            .fee_config
+            .base_fee_wallet(BaseFeeGroup::dao())
+            .unwrap();
+
+        // Write the config in the layout and size it had before versions were tracked
+        let legacy = ConfigV0 {
+            ncn,
+            tie_breaker_admin: config.tie_breaker_admin,
+            fee_admin: config.fee_admin,
+            valid_slots_after_consensus: config.valid_slots_after_consensus,
+            epochs_before_stall: config.epochs_before_stall,
+            fee_config: FeeConfigV0 {
+                block_engine_fee_bps: PodU16::from(300),
+                base_fee_wallets: [dao_wallet; BaseFeeGroup::FEE_GROUP_COUNT],
+                reserved: [0; 128],
+                fee_1: Fees::new(270, 15, epoch).unwrap(),
+                fee_2: Fees::new(270, 15, epoch).unwrap(),
+            },
+            bump: config.bump,
+            epochs_after_consensus_before_close: config.epochs_after_consensus_before_close,
+            starting_valid_epoch: config.starting_valid_epoch,
+            reserved: [0; 111],
+        };
+
+        let config_pda = NcnConfig::find_program_address(&jito_tip_router_program::id(), &ncn).0;
+        let mut raw_account = fixture.get_account(&config_pda).await?.unwrap();
+        let legacy_size = NcnConfig::version_size(0).unwrap();
+        raw_account.data.truncate(legacy_size);
+        raw_account.data[ACCOUNT_VERSION_INDEX] = 0;
+        raw_account.data[8..].copy_from_slice(bytemuck::bytes_of(&legacy));
+        raw_account.lamports = fixture.rent().await.minimum_balance(legacy_size);
+        fixture.set_account(config_pda, raw_account).await;
+
+        tip_router_client
+            .do_migrate_account(ncn, config_pda)
+            .await?;
+
+        let raw_account = fixture.get_account(&config_pda).await?.unwrap();
+        assert_eq!(raw_account.data.len(), NcnConfig::SIZE);
+        assert_eq!(
+            raw_account.data[ACCOUNT_VERSION_INDEX],
+            NcnConfig::ACCOUNT_VERSION
+        );
+        assert!(fixture
+            .rent()
+            .await
+            .is_exempt(raw_account.lamports, NcnConfig::SIZE));
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.ncn, ncn);
+        assert_eq!(config.tie_breaker_admin, legacy.tie_breaker_admin);
+        assert_eq!(config.fee_config.block_engine_fee_bps(), 300);
+        assert_eq!(
+            config
+                .fee_config
+                .current_fees(epoch)
+                .base_fee_bps(BaseFeeGroup::dao()),
+            Ok(270)
+        );
+        assert_eq!(config.pause_admin, Pubkey::default());
+
+        // The migrated config works with instructions that load it
+        tip_router_client
+            .do_set_paused_subsystems(ncn, 0, &test_ncn.ncn_root.ncn_admin)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
+mod admin_deactivate;
+mod admin_multisig;
 mod admin_set_parameters;
 mod admin_set_st_mint;
 mod admin_update_weight_table;
 mod bpf;
 mod cast_vote;
 mod close_epoch_accounts;
+mod commit_reveal_vote;
 mod distribute_rewards;
 mod epoch_state;
 mod initialize_ballot_box;
@@ -15,6 +18,9 @@ mod initialize_operator_snapshot;
 mod initialize_vault_registry;
 mod initialize_weight_table;
 mod meta_tests;
+mod migrate_account;
+mod oracle_set_weight;
+mod pause;
 mod register_vault;
 mod restaking_variations;
 mod set_config_fees;
@@ -0,0 +1,463 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_core::{
+        constants::{MAX_SLOTS_PER_WEIGHT_SAMPLE, PYTH_MAX_STALE_SECONDS},
+        error::TipRouterError,
+        oracle::{
+            OracleType, PythPriceFeedMessage, PythPriceUpdate, PythVerificationLevel,
+            PYTH_RECEIVER_PROGRAM_ID,
+        },
+        spl_stake_pool::StakePoolHeader,
+    };
+    use solana_sdk::{account::Account, pubkey::Pubkey};
+
+    use crate::fixtures::{
+        test_builder::TestBuilder,
+        tip_router_client::{assert_tip_router_error, TipRouterClient},
+        TestResult,
+    };
+
+    fn pyth_price_update_account(price: i64, publish_time: i64, owner: &Pubkey) -> Account {
+        let price_update = PythPriceUpdate {
+            write_authority: Pubkey::new_unique(),
+            verification_level: PythVerificationLevel::Full,
+            price_message: PythPriceFeedMessage {
+                feed_id: [1; 32],
+                price,
+                conf: price as u64 / 1_000,
+                exponent: -8,
+                publish_time,
+                prev_publish_time: publish_time,
+                ema_price: price,
+                ema_conf: price as u64 / 1_000,
+            },
+            posted_slot: 0,
+        };
+
+        Account {
+            lamports: 1_000_000_000,
+            data: price_update.to_account_data(),
+            owner: *owner,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    fn stake_pool_account(pool_mint: &Pubkey, last_update_epoch: u64) -> Account {
+        let header = StakePoolHeader {
+            account_type: 1,
+            manager: Pubkey::new_unique(),
+            staker: Pubkey::new_unique(),
+            stake_deposit_authority: Pubkey::new_unique(),
+            stake_withdraw_bump_seed: 255,
+            validator_list: Pubkey::new_unique(),
+            reserve_stake: Pubkey::new_unique(),
+            pool_mint: *pool_mint,
+            manager_fee_account: Pubkey::new_unique(),
+            token_program_id: Pubkey::new_unique(),
+            total_lamports: 1_180_000_000_000,
+            pool_token_supply: 1_000_000_000_000,
+            last_update_epoch,
+        };
+
+        let mut data = borsh::to_vec(&header).unwrap();
+        data.resize(611, 0);
+
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: jito_tip_router_program::spl_stake_pool_id(),
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    /// Points the first ST mint of the NCN at a Pyth price feed, then initializes the weight table
+    async fn setup_pyth_mint(
+        fixture: &mut TestBuilder,
+        tip_router_client: &mut TipRouterClient,
+        ncn: Pubkey,
+        pyth_feed: Pubkey,
+    ) -> TestResult<(Pubkey, u64)> {
+        setup_oracle_mint(fixture, tip_router_client, ncn, OracleType::Pyth, pyth_feed).await
+    }
+
+    async fn setup_oracle_mint(
+        fixture: &mut TestBuilder,
+        tip_router_client: &mut TipRouterClient,
+        ncn: Pubkey,
+        oracle_type: OracleType,
+        oracle_feed: Pubkey,
+    ) -> TestResult<(Pubkey, u64)> {
+        let epoch = fixture.clock().await.epoch;
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        let st_mint = *vault_registry.get_valid_mint_entries(epoch)[0].st_mint();
+
+        tip_router_client
+            .do_admin_set_st_mint_oracle(ncn, st_mint, oracle_type, oracle_feed)
+            .await?;
+
+        tip_router_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        Ok((st_mint, epoch))
+    }
+
+    #[tokio::test]
+    async fn test_pyth_set_weight_ok() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let pyth_feed = Pubkey::new_unique();
+        let (st_mint, epoch) =
+            setup_pyth_mint(&mut fixture, &mut tip_router_client, ncn, pyth_feed).await?;
+
+        let clock = fixture.clock().await;
+        fixture
+            .set_account(
+                pyth_feed,
+                pyth_price_update_account(
+                    1_234_500_000,
+                    clock.unix_timestamp,
+                    &PYTH_RECEIVER_PROGRAM_ID,
+                ),
+            )
+            .await;
+
+        tip_router_client
+            .do_oracle_set_weight(ncn, epoch, st_mint)
+            .await?;
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        let weight_entry = weight_table.get_weight_entry(&st_mint).unwrap();
+        assert_eq!(weight_entry.weight(), 12_345_000_000);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_pyth_set_weight_sampling_window() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        tip_router_client
+            .do_set_weight_sampling_window(100, &test_ncn.ncn_root)
+            .await?;
+
+        let pyth_feed = Pubkey::new_unique();
+        let (st_mint, epoch) =
+            setup_pyth_mint(&mut fixture, &mut tip_router_client, ncn, pyth_feed).await?;
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        let sampling_end_slot = weight_table.sampling_end_slot();
+        assert_eq!(sampling_end_slot, weight_table.slot_created() + 100);
+
+        // Two samples during the window, neither sets the weight
+        for (price, slots) in [(1_000_000_000, 1), (1_100_000_000, 50)] {
+            fixture.warp_slot_incremental(slots).await?;
+            let clock = fixture.clock().await;
+            fixture
+                .set_account(
+                    pyth_feed,
+                    pyth_price_update_account(
+                        price,
+                        clock.unix_timestamp,
+                        &PYTH_RECEIVER_PROGRAM_ID,
+                    ),
+                )
+                .await;
+
+            tip_router_client
+                .do_oracle_set_weight(ncn, epoch, st_mint)
+                .await?;
+        }
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        let weight_entry = weight_table.get_weight_entry(&st_mint).unwrap();
+        assert_eq!(weight_entry.sample_count(), 2);
+        assert!(!weight_entry.is_set());
+        assert!(!weight_table.finalized());
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.set_weight_progress().tally(), 0);
+
+        let first_sample_slot = weight_entry.first_sample_slot();
+        let last_sample_slot = weight_entry.last_sample_slot();
+
+        // The price after the window is ignored, the weight is the average of the window
+        fixture.warp_slot_incremental(100).await?;
+        let clock = fixture.clock().await;
+        fixture
+            .set_account(
+                pyth_feed,
+                pyth_price_update_account(
+                    5_000_000_000,
+                    clock.unix_timestamp,
+                    &PYTH_RECEIVER_PROGRAM_ID,
+                ),
+            )
+            .await;
+
+        tip_router_client
+            .do_oracle_set_weight(ncn, epoch, st_mint)
+            .await?;
+
+        // Each sample counts for at most `MAX_SLOTS_PER_WEIGHT_SAMPLE` slots
+        let first_sample_slots =
+            (last_sample_slot - first_sample_slot).min(MAX_SLOTS_PER_WEIGHT_SAMPLE);
+        let last_sample_slots =
+            (sampling_end_slot - last_sample_slot).min(MAX_SLOTS_PER_WEIGHT_SAMPLE);
+        let expected_weight = (10_000_000_000u128 * first_sample_slots as u128
+            + 11_000_000_000u128 * last_sample_slots as u128)
+            / (first_sample_slots + last_sample_slots) as u128;
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        let weight_entry = weight_table.get_weight_entry(&st_mint).unwrap();
+        assert!(weight_entry.is_set());
+        assert_eq!(weight_entry.weight(), expected_weight);
+        assert!(weight_table.finalized());
+
+        let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+        assert_eq!(epoch_state.set_weight_progress().tally(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stake_pool_set_weight_ok() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        // So a pool can be updated in a previous epoch
+        fixture.warp_epoch_incremental(1).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let stake_pool = Pubkey::new_unique();
+        let (st_mint, epoch) = setup_oracle_mint(
+            &mut fixture,
+            &mut tip_router_client,
+            ncn,
+            OracleType::SplStakePool,
+            stake_pool,
+        )
+        .await?;
+
+        // Not updated this epoch
+        fixture
+            .set_account(stake_pool, stake_pool_account(&st_mint, epoch - 1))
+            .await;
+
+        let result = tip_router_client
+            .do_oracle_set_weight(ncn, epoch, st_mint)
+            .await;
+        assert_tip_router_error(result, TipRouterError::StaleStakePool);
+
+        // Pool of another mint
+        fixture.warp_slot_incremental(1).await?;
+        fixture
+            .set_account(stake_pool, stake_pool_account(&Pubkey::new_unique(), epoch))
+            .await;
+
+        let result = tip_router_client
+            .do_oracle_set_weight(ncn, epoch, st_mint)
+            .await;
+        assert_tip_router_error(result, TipRouterError::StakePoolMintMismatch);
+
+        fixture.warp_slot_incremental(1).await?;
+        fixture
+            .set_account(stake_pool, stake_pool_account(&st_mint, epoch))
+            .await;
+
+        tip_router_client
+            .do_oracle_set_weight(ncn, epoch, st_mint)
+            .await?;
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        let weight_entry = weight_table.get_weight_entry(&st_mint).unwrap();
+        assert_eq!(weight_entry.weight(), 1_180_000_000);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_pyth_set_weight_change_guard() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let pyth_feed = Pubkey::new_unique();
+
+        let epoch = fixture.clock().await.epoch;
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        let st_mint = *vault_registry.get_valid_mint_entries(epoch)[0].st_mint();
+
+        // At most 10% change from the last epoch
+        tip_router_client
+            .do_admin_set_st_mint_oracle_guards(ncn, st_mint, None, None, Some(1_000))
+            .await?;
+
+        let (_, epoch) =
+            setup_pyth_mint(&mut fixture, &mut tip_router_client, ncn, pyth_feed).await?;
+
+        let clock = fixture.clock().await;
+        fixture
+            .set_account(
+                pyth_feed,
+                pyth_price_update_account(
+                    1_234_500_000,
+                    clock.unix_timestamp,
+                    &PYTH_RECEIVER_PROGRAM_ID,
+                ),
+            )
+            .await;
+
+        tip_router_client
+            .do_oracle_set_weight(ncn, epoch, st_mint)
+            .await?;
+
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        let mint_entry = vault_registry.get_mint_entry(&st_mint)?;
+        assert_eq!(mint_entry.last_weight(), 12_345_000_000);
+        assert_eq!(mint_entry.last_weight_epoch(), epoch);
+
+        // Next epoch
+        fixture.warp_epoch_incremental(1).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        tip_router_client
+            .do_full_initialize_weight_table(ncn, epoch)
+            .await?;
+
+        // A 21% jump is rejected
+        let clock = fixture.clock().await;
+        fixture
+            .set_account(
+                pyth_feed,
+                pyth_price_update_account(
+                    1_500_000_000,
+                    clock.unix_timestamp,
+                    &PYTH_RECEIVER_PROGRAM_ID,
+                ),
+            )
+            .await;
+
+        let result = tip_router_client
+            .do_oracle_set_weight(ncn, epoch, st_mint)
+            .await;
+        assert_tip_router_error(result, TipRouterError::OracleWeightChangeTooLarge);
+
+        // A 5% move is accepted
+        fixture.warp_slot_incremental(1).await?;
+        let clock = fixture.clock().await;
+        fixture
+            .set_account(
+                pyth_feed,
+                pyth_price_update_account(
+                    1_300_000_000,
+                    clock.unix_timestamp,
+                    &PYTH_RECEIVER_PROGRAM_ID,
+                ),
+            )
+            .await;
+
+        // The weight has to be recorded for the guard of the next epoch
+        let result = tip_router_client
+            .oracle_set_weight(ncn, epoch, st_mint, pyth_feed, None)
+            .await;
+        assert_tip_router_error(
+            result,
+            TipRouterError::VaultRegistryRequiredForWeightChangeGuard,
+        );
+
+        tip_router_client
+            .do_oracle_set_weight(ncn, epoch, st_mint)
+            .await?;
+
+        let weight_table = tip_router_client.get_weight_table(ncn, epoch).await?;
+        let weight_entry = weight_table.get_weight_entry(&st_mint).unwrap();
+        assert_eq!(weight_entry.weight(), 13_000_000_000);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_pyth_set_weight_stale_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let pyth_feed = Pubkey::new_unique();
+        let (st_mint, epoch) =
+            setup_pyth_mint(&mut fixture, &mut tip_router_client, ncn, pyth_feed).await?;
+
+        let clock = fixture.clock().await;
+        fixture
+            .set_account(
+                pyth_feed,
+                pyth_price_update_account(
+                    1_234_500_000,
+                    clock.unix_timestamp - PYTH_MAX_STALE_SECONDS as i64 - 1,
+                    &PYTH_RECEIVER_PROGRAM_ID,
+                ),
+            )
+            .await;
+
+        let result = tip_router_client
+            .do_oracle_set_weight(ncn, epoch, st_mint)
+            .await;
+        assert_tip_router_error(result, TipRouterError::StalePythPrice);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_pyth_set_weight_wrong_owner_fails() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        fixture.add_epoch_state_for_test_ncn(&test_ncn).await?;
+
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let pyth_feed = Pubkey::new_unique();
+        let (st_mint, epoch) =
+            setup_pyth_mint(&mut fixture, &mut tip_router_client, ncn, pyth_feed).await?;
+
+        let clock = fixture.clock().await;
+        fixture
+            .set_account(
+                pyth_feed,
+                pyth_price_update_account(
+                    1_234_500_000,
+                    clock.unix_timestamp,
+                    &Pubkey::new_unique(),
+                ),
+            )
+            .await;
+
+        let result = tip_router_client
+            .do_oracle_set_weight(ncn, epoch, st_mint)
+            .await;
+        assert_tip_router_error(result, TipRouterError::BadPythPriceUpdate);
+
+        Ok(())
+    }
+}
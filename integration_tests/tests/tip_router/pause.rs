@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use jito_tip_router_client::types::ConfigAdminRole;
+    use jito_tip_router_core::{
+        base_reward_router::BaseRewardReceiver, error::TipRouterError, pause::PausableSubsystem,
+    };
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
+
+    #[tokio::test]
+    async fn test_pause_routing() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(2, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        fixture.warp_slot_incremental(1000).await?;
+
+        let pause_admin = Keypair::new();
+        tip_router_client
+            .do_set_new_admin(
+                ConfigAdminRole::PauseAdmin,
+                pause_admin.pubkey(),
+                &test_ncn.ncn_root,
+            )
+            .await?;
+
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+        fixture.vote_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+
+        let valid_slots_after_consensus = {
+            let config = tip_router_client.get_ncn_config(ncn).await?;
+            config.valid_slots_after_consensus()
+        };
+
+        fixture
+            .warp_slot_incremental(valid_slots_after_consensus + 1)
+            .await?;
+
+        fixture.add_routers_for_test_ncn(&test_ncn).await?;
+
+        let (base_reward_receiver, _, _) =
+            BaseRewardReceiver::find_program_address(&jito_tip_router_program::id(), &ncn, epoch);
+        tip_router_client
+            .airdrop_lamports(&base_reward_receiver, 1_000_000)
+            .await?;
+
+        // Only the NCN admin and the pause admin can pause
+        let result = tip_router_client
+            .do_set_paused_subsystems(ncn, PausableSubsystem::Routing.flag(), &Keypair::new())
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectPauseAdmin);
+
+        tip_router_client
+            .do_set_paused_subsystems(ncn, PausableSubsystem::Routing.flag(), &pause_admin)
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert!(config.is_paused(PausableSubsystem::Routing));
+        assert!(!config.is_paused(PausableSubsystem::Voting));
+
+        let result = tip_router_client.do_route_base_rewards(ncn, epoch).await;
+        assert_tip_router_error(result, TipRouterError::Paused);
+
+        // The pause admin can pause more subsystems but cannot unpause
+        tip_router_client
+            .do_set_paused_subsystems(
+                ncn,
+                PausableSubsystem::Routing.flag() | PausableSubsystem::Claiming.flag(),
+                &pause_admin,
+            )
+            .await?;
+
+        let result = tip_router_client
+            .do_set_paused_subsystems(ncn, 0, &pause_admin)
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectNcnAdmin);
+
+        let result = tip_router_client
+            .do_set_paused_subsystems(
+                ncn,
+                PausableSubsystem::ALL + 1,
+                &test_ncn.ncn_root.ncn_admin,
+            )
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidPausedSubsystems);
+
+        tip_router_client
+            .do_set_paused_subsystems(ncn, 0, &test_ncn.ncn_root.ncn_admin)
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.paused_subsystems(), 0);
+
+        tip_router_client.do_route_base_rewards(ncn, epoch).await?;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,14 @@
 #[cfg(test)]
 mod tests {
     use jito_restaking_core::{config::Config, ncn_vault_ticket::NcnVaultTicket};
-    use jito_tip_router_core::{constants::JTO_SOL_FEED, ncn_fee_group::NcnFeeGroup};
+    use jito_tip_router_core::{
+        constants::JTO_SOL_FEED, error::TipRouterError, ncn_fee_group::NcnFeeGroup,
+    };
     use solana_sdk::{signature::Keypair, signer::Signer};
 
-    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
 
     #[tokio::test]
     async fn test_register_vault_success() -> TestResult<()> {
@@ -184,6 +188,27 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_initialize_vault_registry_page_not_needed() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture.create_initial_test_ncn(1, 1, None).await?;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+        // A single vault NCN fits in the vault registry
+        let result = tip_router_client
+            .do_initialize_vault_registry_page(ncn, 0)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidVaultPage);
+
+        let vault_registry = tip_router_client.get_vault_registry(ncn).await?;
+        assert_eq!(vault_registry.page_count(), 0);
+        assert_eq!(vault_registry.vault_count(), 1);
+
+        Ok(())
+    }
+
     //TODO Note an error now
     // #[tokio::test]
     // async fn test_register_vault_fails_with_weight_table() -> TestResult<()> {
@@ -2,10 +2,14 @@
 mod tests {
     use std::u64;
 
-    use jito_tip_router_core::{base_fee_group::BaseFeeGroup, ncn_fee_group::NcnFeeGroup};
+    use jito_tip_router_core::{
+        base_fee_group::BaseFeeGroup, error::TipRouterError, ncn_fee_group::NcnFeeGroup,
+    };
     use solana_sdk::pubkey::Pubkey;
 
-    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
 
     #[tokio::test]
     async fn test_set_config_fees_ok() -> TestResult<()> {
@@ -107,6 +111,10 @@ mod tests {
             .await?;
 
         for group in BaseFeeGroup::all_groups().iter() {
+            tip_router_client
+                .do_set_base_fee_group(*group, None, Some(true), None, &ncn_root)
+                .await?;
+
             // Change fees and fee wallet
             tip_router_client
                 .do_set_config_fees(
@@ -169,4 +177,120 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_schedule_and_cancel_config_fees_ok() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        tip_router_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+
+        let base_fee_group = BaseFeeGroup::default();
+        let epoch = fixture.clock().await.epoch;
+
+        tip_router_client
+            .do_schedule_config_fees(base_fee_group, 200, epoch + 2, &ncn_root)
+            .await?;
+        tip_router_client
+            .do_schedule_config_fees(base_fee_group, 300, epoch + 4, &ncn_root)
+            .await?;
+
+        let ncn_config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        let scheduled_fees = ncn_config.fee_config.scheduled_fees(epoch);
+        assert_eq!(scheduled_fees.len(), 2);
+        assert_eq!(scheduled_fees[0].activation_epoch(), epoch + 2);
+        assert_eq!(scheduled_fees[1].activation_epoch(), epoch + 4);
+
+        // Fees can't be scheduled for an epoch that already started
+        let result = tip_router_client
+            .do_schedule_config_fees(base_fee_group, 400, epoch, &ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidFeeActivationEpoch);
+
+        tip_router_client
+            .do_cancel_scheduled_fees(epoch + 2, &ncn_root)
+            .await?;
+
+        let result = tip_router_client
+            .do_cancel_scheduled_fees(epoch + 2, &ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::FeesNotScheduled);
+
+        let ncn_config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        let fee_config = ncn_config.fee_config;
+        let initial_base_fee_bps = fee_config
+            .current_fees(epoch)
+            .base_fee_bps(base_fee_group)
+            .unwrap();
+
+        let scheduled_fees = fee_config.scheduled_fees(epoch);
+        assert_eq!(scheduled_fees.len(), 1);
+        assert_eq!(scheduled_fees[0].activation_epoch(), epoch + 4);
+
+        assert_eq!(
+            fee_config
+                .current_fees(epoch + 2)
+                .base_fee_bps(base_fee_group)
+                .unwrap(),
+            initial_base_fee_bps
+        );
+        assert_eq!(
+            fee_config
+                .current_fees(epoch + 4)
+                .base_fee_bps(base_fee_group)
+                .unwrap(),
+            300
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fee_increase_notice() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+
+        tip_router_client
+            .do_initialize_config(ncn_root.ncn_pubkey, &ncn_root.ncn_admin)
+            .await?;
+        tip_router_client
+            .do_set_fee_increase_notice_epochs(5, &ncn_root)
+            .await?;
+
+        let base_fee_group = BaseFeeGroup::default();
+        let epoch = fixture.clock().await.epoch;
+
+        let ncn_config = tip_router_client
+            .get_ncn_config(ncn_root.ncn_pubkey)
+            .await?;
+        let base_fee_bps = ncn_config
+            .fee_config
+            .current_fees(epoch)
+            .base_fee_bps(base_fee_group)
+            .unwrap();
+
+        // Decreases can activate right away
+        tip_router_client
+            .do_schedule_config_fees(base_fee_group, base_fee_bps - 1, epoch + 1, &ncn_root)
+            .await?;
+
+        let result = tip_router_client
+            .do_schedule_config_fees(base_fee_group, base_fee_bps + 1, epoch + 4, &ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::FeeIncreaseNoticeTooShort);
+
+        tip_router_client
+            .do_schedule_config_fees(base_fee_group, base_fee_bps + 1, epoch + 5, &ncn_root)
+            .await?;
+
+        Ok(())
+    }
 }
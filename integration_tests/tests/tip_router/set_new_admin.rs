@@ -1,8 +1,16 @@
 mod tests {
     use jito_tip_router_client::types::ConfigAdminRole;
-    use jito_tip_router_core::{config::Config as NcnConfig, error::TipRouterError};
+    use jito_tip_router_core::{
+        admin_multisig::AdminMultisig,
+        config::{Config as NcnConfig, ConfigAdminRole as CoreConfigAdminRole},
+        error::TipRouterError,
+        events::TipRouterEvent,
+    };
     use solana_program::pubkey::Pubkey;
-    use solana_sdk::{instruction::InstructionError, signature::Keypair};
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::{Keypair, Signer},
+    };
 
     use crate::fixtures::{
         assert_ix_error, restaking_client::NcnRoot, test_builder::TestBuilder,
@@ -88,4 +96,258 @@ mod tests {
         assert_tip_router_error(result, TipRouterError::IncorrectNcnAdmin);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_admin_handover() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+        tip_router_client
+            .do_set_admin_handover_delay(2, &ncn_root)
+            .await?;
+
+        let result = tip_router_client
+            .do_set_new_admin(ConfigAdminRole::FeeAdmin, Pubkey::new_unique(), &ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminHandoverRequired);
+
+        let new_fee_admin = Keypair::new();
+        tip_router_client
+            .do_propose_new_admin(ConfigAdminRole::FeeAdmin, new_fee_admin.pubkey(), &ncn_root)
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.fee_admin, ncn_root.ncn_admin.pubkey());
+        assert_eq!(
+            config.pending_admin(CoreConfigAdminRole::FeeAdmin),
+            Some(&new_fee_admin.pubkey())
+        );
+
+        // A pending fee admin handover doesn't block the other roles
+        let new_tie_breaker_admin = Keypair::new();
+        tip_router_client
+            .do_propose_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                new_tie_breaker_admin.pubkey(),
+                &ncn_root,
+            )
+            .await?;
+
+        let result = tip_router_client
+            .do_accept_admin(ncn, ConfigAdminRole::FeeAdmin, &new_fee_admin)
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminHandoverDelayNotElapsed);
+
+        fixture.warp_epoch_incremental(2).await?;
+
+        let result = tip_router_client
+            .do_accept_admin(ncn, ConfigAdminRole::FeeAdmin, &Keypair::new())
+            .await;
+        assert_tip_router_error(result, TipRouterError::InvalidPendingAdmin);
+
+        tip_router_client
+            .do_accept_admin(ncn, ConfigAdminRole::FeeAdmin, &new_fee_admin)
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.fee_admin, new_fee_admin.pubkey());
+        assert_eq!(
+            config.pending_admin(CoreConfigAdminRole::TieBreakerAdmin),
+            Some(&new_tie_breaker_admin.pubkey())
+        );
+
+        tip_router_client
+            .do_accept_admin(
+                ncn,
+                ConfigAdminRole::TieBreakerAdmin,
+                &new_tie_breaker_admin,
+            )
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.tie_breaker_admin, new_tie_breaker_admin.pubkey());
+        assert!(!config.has_pending_admin());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_handover_delay_cannot_be_skipped() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+        tip_router_client
+            .do_set_admin_handover_delay(2, &ncn_root)
+            .await?;
+
+        // Lowering the delay waits out the current one
+        fixture.warp_slot_incremental(1).await?;
+        tip_router_client
+            .do_set_admin_handover_delay(0, &ncn_root)
+            .await?;
+
+        let result = tip_router_client
+            .do_set_new_admin(ConfigAdminRole::FeeAdmin, Pubkey::new_unique(), &ncn_root)
+            .await;
+        assert_tip_router_error(result, TipRouterError::AdminHandoverRequired);
+
+        // Handing a role to a multisig goes through the same delay
+        let signers = vec![Pubkey::new_unique()];
+        tip_router_client
+            .do_set_admin_multisig(ConfigAdminRole::FeeAdmin, 1, signers.clone(), &ncn_root)
+            .await?;
+
+        let admin_multisig = AdminMultisig::find_program_address(
+            &jito_tip_router_program::id(),
+            &ncn,
+            CoreConfigAdminRole::FeeAdmin,
+        )
+        .0;
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.fee_admin, ncn_root.ncn_admin.pubkey());
+        assert_eq!(
+            config.pending_admin(CoreConfigAdminRole::FeeAdmin),
+            Some(&admin_multisig)
+        );
+
+        fixture.warp_epoch_incremental(2).await?;
+
+        tip_router_client
+            .do_set_admin_multisig(ConfigAdminRole::FeeAdmin, 1, signers, &ncn_root)
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(config.fee_admin, admin_multisig);
+        assert!(!config.has_pending_admin());
+
+        // The lowered delay is in effect now
+        tip_router_client
+            .do_set_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                Pubkey::new_unique(),
+                &ncn_root,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_admin_proposal() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        let new_tie_breaker = Keypair::new();
+        tip_router_client
+            .do_propose_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                new_tie_breaker.pubkey(),
+                &ncn_root,
+            )
+            .await?;
+
+        let result = tip_router_client
+            .do_cancel_admin_proposal(ncn, ConfigAdminRole::TieBreakerAdmin, &Keypair::new())
+            .await;
+        assert_tip_router_error(result, TipRouterError::IncorrectNcnAdmin);
+
+        // The pending admin can decline the handover
+        tip_router_client
+            .do_cancel_admin_proposal(ncn, ConfigAdminRole::TieBreakerAdmin, &new_tie_breaker)
+            .await?;
+
+        let result = tip_router_client
+            .do_accept_admin(ncn, ConfigAdminRole::TieBreakerAdmin, &new_tie_breaker)
+            .await;
+        assert_tip_router_error(result, TipRouterError::NoPendingAdminHandover);
+
+        fixture.warp_slot_incremental(1).await?;
+
+        tip_router_client
+            .do_propose_new_admin(
+                ConfigAdminRole::TieBreakerAdmin,
+                new_tie_breaker.pubkey(),
+                &ncn_root,
+            )
+            .await?;
+
+        // So can the NCN admin
+        tip_router_client
+            .do_cancel_admin_proposal(ncn, ConfigAdminRole::TieBreakerAdmin, &ncn_root.ncn_admin)
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert!(!config.has_pending_admin());
+        assert_eq!(config.tie_breaker_admin, ncn_root.ncn_admin.pubkey());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_handover_events() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+        let ncn_root = fixture.setup_ncn().await?;
+        let ncn = ncn_root.ncn_pubkey;
+
+        tip_router_client
+            .do_initialize_config(ncn, &ncn_root.ncn_admin)
+            .await?;
+
+        let new_tie_breaker = Keypair::new();
+        let events = tip_router_client
+            .do_propose_new_admin_with_events(
+                ConfigAdminRole::TieBreakerAdmin,
+                new_tie_breaker.pubkey(),
+                &ncn_root,
+            )
+            .await?;
+
+        let config = tip_router_client.get_ncn_config(ncn).await?;
+        assert_eq!(
+            events,
+            vec![TipRouterEvent::AdminHandoverProposed {
+                ncn,
+                role: CoreConfigAdminRole::TieBreakerAdmin,
+                new_admin: new_tie_breaker.pubkey(),
+                accept_epoch: config
+                    .pending_admin_accept_epoch(CoreConfigAdminRole::TieBreakerAdmin),
+            }]
+        );
+
+        let events = tip_router_client
+            .do_cancel_admin_proposal_with_events(
+                ncn,
+                ConfigAdminRole::TieBreakerAdmin,
+                &ncn_root.ncn_admin,
+            )
+            .await?;
+
+        assert_eq!(
+            events,
+            vec![TipRouterEvent::AdminHandoverCancelled {
+                ncn,
+                role: CoreConfigAdminRole::TieBreakerAdmin,
+                pending_admin: new_tie_breaker.pubkey(),
+            }]
+        );
+
+        Ok(())
+    }
 }
@@ -1,9 +1,13 @@
 #[cfg(test)]
 mod tests {
 
-    use jito_tip_router_core::{ballot_box::Ballot, constants::DEFAULT_CONSENSUS_REACHED_SLOT};
+    use jito_tip_router_core::{
+        ballot_box::Ballot, constants::DEFAULT_CONSENSUS_REACHED_SLOT, error::TipRouterError,
+    };
 
-    use crate::fixtures::{test_builder::TestBuilder, TestResult};
+    use crate::fixtures::{
+        test_builder::TestBuilder, tip_router_client::assert_tip_router_error, TestResult,
+    };
 
     #[test]
     fn test_set_tie_breaker() -> TestResult<()> {
@@ -85,4 +89,138 @@ mod tests {
             Err(payload) => std::panic::resume_unwind(payload),
         }
     }
+
+    #[test]
+    fn test_resolve_stalled_vote() -> TestResult<()> {
+        const STACK_SIZE: usize = 32 * 1024 * 1024;
+        let handle = std::thread::Builder::new()
+            .name("test_resolve_stalled_vote".to_string())
+            .stack_size(STACK_SIZE)
+            .spawn(|| {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .expect("failed to build tokio runtime");
+                runtime.block_on(async {
+                    let mut fixture = TestBuilder::new().await;
+                    let mut tip_router_client = fixture.tip_router_client();
+
+                    // Each operator gets 20% voting share
+                    let test_ncn = fixture.create_initial_test_ncn(5, 1, None).await?;
+
+                    ///// TipRouter Setup /////
+                    fixture.snapshot_test_ncn(&test_ncn).await?;
+
+                    let clock = fixture.clock().await;
+                    let epoch = clock.epoch;
+                    let ncn = test_ncn.ncn_root.ncn_pubkey;
+
+                    // The fallback has to stay below the default 2/3 consensus threshold
+                    let result = tip_router_client
+                        .do_set_stall_fallback_threshold(7_000, &test_ncn.ncn_root)
+                        .await;
+                    assert_tip_router_error(result, TipRouterError::InvalidStallFallbackThreshold);
+
+                    tip_router_client
+                        .do_set_stall_fallback_threshold(5_500, &test_ncn.ncn_root)
+                        .await?;
+
+                    tip_router_client
+                        .do_full_initialize_ballot_box(ncn, epoch)
+                        .await?;
+
+                    let meta_merkle_root = [1; 32];
+                    let tie_breaker_merkle_root = [2; 32];
+
+                    // 60% of the stake votes for the same ballot, short of consensus
+                    for operator_root in test_ncn.operators.iter().take(3) {
+                        tip_router_client
+                            .do_cast_vote(
+                                ncn,
+                                operator_root.operator_pubkey,
+                                &operator_root.operator_admin,
+                                meta_merkle_root,
+                                epoch,
+                            )
+                            .await?;
+                    }
+
+                    let operator_root = &test_ncn.operators[3];
+                    tip_router_client
+                        .do_cast_vote(
+                            ncn,
+                            operator_root.operator_pubkey,
+                            &operator_root.operator_admin,
+                            tie_breaker_merkle_root,
+                            epoch,
+                        )
+                        .await?;
+
+                    let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+                    assert!(!ballot_box.is_consensus_reached());
+                    assert_eq!(ballot_box.stall_fallback_threshold_bps(), 5_500);
+
+                    // Voting has not stalled yet
+                    let result = tip_router_client.do_resolve_stalled_vote(ncn, epoch).await;
+                    assert_tip_router_error(result, TipRouterError::VotingNotFinalized);
+
+                    // Turning the fallback off does not affect ballot boxes that already exist
+                    tip_router_client
+                        .do_set_stall_fallback_threshold(0, &test_ncn.ncn_root)
+                        .await?;
+
+                    fixture.warp_epoch_incremental(10).await?;
+
+                    tip_router_client
+                        .do_resolve_stalled_vote(ncn, epoch)
+                        .await?;
+
+                    let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+                    let ballot = Ballot::new(&meta_merkle_root);
+                    assert_eq!(
+                        *ballot_box.get_winning_ballot_tally().unwrap().ballot(),
+                        ballot
+                    );
+                    assert_eq!(
+                        ballot_box.slot_consensus_reached(),
+                        DEFAULT_CONSENSUS_REACHED_SLOT
+                    );
+                    assert!(ballot_box.is_consensus_reached());
+                    assert!(ballot_box.stall_fallback_used());
+
+                    let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+                    assert!(epoch_state.was_stall_fallback_used());
+
+                    // The admin tie breaker can replace the fallback result
+                    tip_router_client
+                        .do_admin_set_tie_breaker(ncn, tie_breaker_merkle_root, epoch)
+                        .await?;
+
+                    let ballot_box = tip_router_client.get_ballot_box(ncn, epoch).await?;
+                    assert_eq!(
+                        *ballot_box.get_winning_ballot_tally().unwrap().ballot(),
+                        Ballot::new(&tie_breaker_merkle_root)
+                    );
+                    assert!(ballot_box.tie_breaker_set());
+                    assert!(!ballot_box.stall_fallback_used());
+
+                    let epoch_state = tip_router_client.get_epoch_state(ncn, epoch).await?;
+                    assert!(!epoch_state.was_stall_fallback_used());
+
+                    // But cannot override its own ballot
+                    let result = tip_router_client
+                        .do_admin_set_tie_breaker(ncn, meta_merkle_root, epoch)
+                        .await;
+                    assert_tip_router_error(result, TipRouterError::ConsensusAlreadyReached);
+
+                    Ok(())
+                })
+            })
+            .expect("failed to spawn stall resolution test thread");
+
+        match handle.join() {
+            Ok(result) => result,
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    }
 }
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use jito_tip_router_core::constants::MAX_VAULTS;
     use jito_vault_core::{
         vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
     };
@@ -302,4 +303,49 @@ mod tests {
         assert_eq!(snapshot_0.stake_weights().stake_weight(), 0);
         assert_eq!(snapshot_0.valid_operator_vault_delegations(), 0);
     }
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegation_past_max_vaults() -> TestResult<()> {
+        let mut fixture = TestBuilder::new().await;
+        let mut tip_router_client = fixture.tip_router_client();
+
+        let test_ncn = fixture
+            .create_initial_test_ncn(1, MAX_VAULTS + 1, None)
+            .await?;
+
+        fixture.warp_slot_incremental(1000).await?;
+        fixture.snapshot_test_ncn(&test_ncn).await?;
+
+        let epoch = fixture.clock().await.epoch;
+        let ncn = test_ncn.ncn_root.ncn_pubkey;
+        let operator = test_ncn.operators[0].operator_pubkey;
+
+        // The last vault is kept in the operator's first snapshot page
+        let operator_snapshot = tip_router_client
+            .get_operator_snapshot(operator, ncn, epoch)
+            .await?;
+        assert!(operator_snapshot.finalized());
+        assert!(operator_snapshot.stake_weight_caps_applied());
+        assert_eq!(operator_snapshot.page_count(), 1);
+        assert_eq!(
+            operator_snapshot.vault_operator_delegations_registered(),
+            MAX_VAULTS as u64 + 1
+        );
+
+        let last_vault = test_ncn.vaults[MAX_VAULTS].vault_pubkey;
+        assert!(!operator_snapshot.contains_vault(&last_vault));
+
+        let operator_snapshot_page = tip_router_client
+            .get_operator_snapshot_page(operator, ncn, epoch, 0)
+            .await?;
+        assert!(operator_snapshot_page.contains_vault(&last_vault));
+        assert!(
+            operator_snapshot_page.vault_operator_stake_weight()[0]
+                .stake_weights()
+                .stake_weight()
+                > 0
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,62 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    config::{Config as NcnConfig, ConfigAdminRole},
+    error::TipRouterError,
+    events::TipRouterEvent,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Cancels a pending admin handover, either the NCN admin or the pending admin can cancel
+pub fn process_admin_cancel_admin_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: ConfigAdminRole,
+) -> ProgramResult {
+    let [config, ncn_account, signer] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer, false)?;
+
+    NcnConfig::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = NcnConfig::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    if config.ncn != *ncn_account.key {
+        return Err(TipRouterError::IncorrectNcn.into());
+    }
+
+    let pending_admin = *config
+        .pending_admin(role)
+        .ok_or(TipRouterError::NoPendingAdminHandover)?;
+
+    let ncn_admin = {
+        let ncn_data = ncn_account.data.borrow();
+        Ncn::try_from_slice_unchecked(&ncn_data)?.admin
+    };
+
+    if signer.key.ne(&ncn_admin) && signer.key.ne(&pending_admin) {
+        msg!("Signer is neither the NCN admin nor the pending admin");
+        return Err(TipRouterError::IncorrectNcnAdmin.into());
+    }
+
+    config.cancel_admin_handover(role)?;
+
+    msg!("{:?} handover to {:?} cancelled", role, pending_admin);
+
+    TipRouterEvent::AdminHandoverCancelled {
+        ncn: *ncn_account.key,
+        role,
+        pending_admin,
+    }
+    .emit();
+
+    Ok(())
+}
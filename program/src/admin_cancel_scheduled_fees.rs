@@ -0,0 +1,52 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{config::Config, error::TipRouterError, events::TipRouterEvent};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Cancels fees scheduled to activate in a future epoch, only the fee admin can sign
+pub fn process_admin_cancel_scheduled_fees(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    activation_epoch: u64,
+) -> ProgramResult {
+    let [config, ncn_account, fee_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(fee_admin, false)?;
+
+    Config::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    let epoch = Clock::get()?.epoch;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    if config.ncn != *ncn_account.key {
+        return Err(TipRouterError::IncorrectNcn.into());
+    }
+
+    if config.fee_admin != *fee_admin.key {
+        return Err(TipRouterError::IncorrectFeeAdmin.into());
+    }
+
+    config
+        .fee_config
+        .cancel_scheduled_fees(activation_epoch, epoch)?;
+
+    msg!("Cancelled fees scheduled for epoch {}", activation_epoch);
+
+    TipRouterEvent::ScheduledFeesCancelled {
+        ncn: *ncn_account.key,
+        epoch,
+        activation_epoch,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    config::Config, error::TipRouterError, events::TipRouterEvent, vault_registry::VaultRegistry,
+    vault_registry_page::VaultRegistryPage,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Leaves the ST mint out of the weight tables of `epoch` onward. Every vault registry page has
+/// to be passed in order, the mint's vaults in them have to be deactivated as well
+pub fn process_admin_deactivate_st_mint(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    st_mint: &Pubkey,
+    epoch: u64,
+) -> ProgramResult {
+    let [config, ncn, vault_registry, admin, vault_registry_pages @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, ncn.key, false)?;
+    VaultRegistry::load(program_id, vault_registry, ncn.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+
+    load_signer(admin, false)?;
+
+    {
+        let ncn_data = ncn.data.borrow();
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+
+        if ncn_account.ncn_program_admin.ne(admin.key) {
+            msg!("Admin is not the NCN program admin");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    let current_epoch = Clock::get()?.epoch;
+
+    let mut vault_registry_data = vault_registry.data.borrow_mut();
+    let vault_registry_account =
+        VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
+
+    VaultRegistryPage::load_all_pages(
+        program_id,
+        vault_registry_pages,
+        ncn.key,
+        vault_registry_account.page_count(),
+        false,
+    )?;
+
+    for vault_registry_page in vault_registry_pages.iter() {
+        let vault_registry_page_data = vault_registry_page.data.borrow();
+        let vault_registry_page_account =
+            VaultRegistryPage::try_from_slice_unchecked(&vault_registry_page_data)?;
+
+        if vault_registry_page_account.has_active_vaults(st_mint, epoch) {
+            return Err(TipRouterError::StMintHasActiveVaults.into());
+        }
+    }
+
+    vault_registry_account.deactivate_st_mint(st_mint, epoch, current_epoch)?;
+
+    msg!("Deactivated ST mint {} from epoch {}", st_mint, epoch);
+
+    TipRouterEvent::StMintDeactivated {
+        ncn: *ncn.key,
+        st_mint: *st_mint,
+        epoch,
+    }
+    .emit();
+
+    Ok(())
+}
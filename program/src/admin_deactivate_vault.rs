@@ -0,0 +1,72 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    config::Config, events::TipRouterEvent, vault_registry::VaultRegistry,
+    vault_registry_page::VaultRegistryPage,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Leaves the vault out of the weight tables of `epoch` onward. Vaults past `MAX_VAULTS` are
+/// deactivated in the vault registry page passed as the optional account
+pub fn process_admin_deactivate_vault(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    vault: &Pubkey,
+    epoch: u64,
+) -> ProgramResult {
+    let [config, ncn, vault_registry, admin, optional_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, ncn.key, false)?;
+    VaultRegistry::load(program_id, vault_registry, ncn.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+
+    load_signer(admin, false)?;
+
+    {
+        let ncn_data = ncn.data.borrow();
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+
+        if ncn_account.ncn_program_admin.ne(admin.key) {
+            msg!("Admin is not the NCN program admin");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    let current_epoch = Clock::get()?.epoch;
+
+    if let Some(vault_registry_page) = optional_accounts
+        .first()
+        .filter(|account| account.key.ne(program_id))
+    {
+        VaultRegistryPage::load_any_page(program_id, vault_registry_page, ncn.key, true)?;
+
+        let mut vault_registry_page_data = vault_registry_page.data.borrow_mut();
+        let vault_registry_page_account =
+            VaultRegistryPage::try_from_slice_unchecked_mut(&mut vault_registry_page_data)?;
+
+        vault_registry_page_account.deactivate_vault(vault, epoch, current_epoch)?;
+    } else {
+        let mut vault_registry_data = vault_registry.data.borrow_mut();
+        let vault_registry_account =
+            VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
+
+        vault_registry_account.deactivate_vault(vault, epoch, current_epoch)?;
+    }
+
+    msg!("Deactivated vault {} from epoch {}", vault, epoch);
+
+    TipRouterEvent::VaultDeactivated {
+        ncn: *ncn.key,
+        vault: *vault,
+        epoch,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use jito_jsm_core::loader::{load_signer, load_system_account, load_system_progra
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
     account_payer::AccountPayer,
+    account_version::{VersionedAccount, ACCOUNT_VERSION_INDEX},
     config::Config,
     constants::{
         MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE, MAX_EPOCHS_BEFORE_STALL, MAX_FEE_BPS,
@@ -96,6 +97,7 @@ pub fn process_admin_initialize_config(
 
     let mut config_data = config.try_borrow_mut_data()?;
     config_data[0] = Config::DISCRIMINATOR;
+    config_data[ACCOUNT_VERSION_INDEX] = Config::ACCOUNT_VERSION;
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
 
     let fee_config = FeeConfig::new(
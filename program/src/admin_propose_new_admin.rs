@@ -0,0 +1,62 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    config::{Config as NcnConfig, ConfigAdminRole},
+    error::TipRouterError,
+    events::TipRouterEvent,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+pub fn process_admin_propose_new_admin(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: ConfigAdminRole,
+) -> ProgramResult {
+    let [config, ncn_account, ncn_admin, new_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(ncn_admin, true)?;
+
+    NcnConfig::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = NcnConfig::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    // Verify NCN and Admin
+    if config.ncn != *ncn_account.key {
+        return Err(TipRouterError::IncorrectNcn.into());
+    }
+
+    let ncn_data = ncn_account.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+
+    if ncn.admin != *ncn_admin.key {
+        return Err(TipRouterError::IncorrectNcnAdmin.into());
+    }
+
+    let current_epoch = Clock::get()?.epoch;
+    config.propose_admin_handover(role, new_admin.key, current_epoch)?;
+
+    msg!(
+        "{:?} handover to {:?} proposed, can be accepted from epoch {}",
+        role,
+        new_admin.key,
+        config.pending_admin_accept_epoch(role)
+    );
+
+    TipRouterEvent::AdminHandoverProposed {
+        ncn: *ncn_account.key,
+        role,
+        new_admin: *new_admin.key,
+        accept_epoch: config.pending_admin_accept_epoch(role),
+    }
+    .emit();
+
+    Ok(())
+}
@@ -2,11 +2,12 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::{load_signer, load_token_mint};
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    config::Config, ncn_fee_group::NcnFeeGroup, vault_registry::VaultRegistry,
+    config::Config, events::TipRouterEvent, ncn_fee_group::NcnFeeGroup,
+    vault_registry::VaultRegistry,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
 pub fn process_admin_register_st_mint(
@@ -16,6 +17,10 @@ pub fn process_admin_register_st_mint(
     reward_multiplier_bps: u64,
     switchboard_feed: Option<Pubkey>,
     no_feed_weight: Option<u128>,
+    oracle_type: Option<u8>,
+    max_staleness: Option<u64>,
+    max_confidence_bps: Option<u16>,
+    max_weight_change_bps: Option<u16>,
 ) -> ProgramResult {
     let [config, ncn, st_mint, vault_registry, admin] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -53,6 +58,8 @@ pub fn process_admin_register_st_mint(
         return Err(ProgramError::InvalidArgument);
     }
 
+    vault_registry_account.compact(Clock::get()?.epoch)?;
+
     vault_registry_account.register_st_mint(
         st_mint.key,
         ncn_fee_group,
@@ -61,5 +68,24 @@ pub fn process_admin_register_st_mint(
         no_feed_weight,
     )?;
 
+    if let Some(oracle_type) = oracle_type {
+        vault_registry_account.set_st_mint_oracle_type(st_mint.key, oracle_type)?;
+    }
+
+    vault_registry_account.set_st_mint_oracle_guards(
+        st_mint.key,
+        max_staleness,
+        max_confidence_bps,
+        max_weight_change_bps,
+    )?;
+
+    TipRouterEvent::StMintRegistered {
+        ncn: *ncn.key,
+        st_mint: *st_mint.key,
+        ncn_fee_group: ncn_fee_group.group,
+        reward_multiplier_bps,
+    }
+    .emit();
+
     Ok(())
 }
@@ -0,0 +1,138 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_signer, load_system_account, load_system_program};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer,
+    admin_multisig::AdminMultisig,
+    config::{Config as NcnConfig, ConfigAdminRole},
+    error::TipRouterError,
+    events::TipRouterEvent,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Sets the signers of a role's multisig and hands the role over to the multisig. With a handover
+/// delay the multisig becomes the admin once the delay has passed and this is called again.
+/// Once the multisig is the admin its signers only change through `SetMultisigSigner` actions.
+pub fn process_admin_set_admin_multisig(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: ConfigAdminRole,
+    threshold: u8,
+    signers: &[Pubkey],
+) -> ProgramResult {
+    let [config, admin_multisig, ncn_account, ncn_admin, account_payer, system_program] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(ncn_admin, false)?;
+    load_system_program(system_program)?;
+
+    NcnConfig::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+    AccountPayer::load(program_id, account_payer, ncn_account.key, true)?;
+
+    {
+        let ncn_data = ncn_account.data.borrow();
+        let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+        if ncn.admin != *ncn_admin.key {
+            return Err(TipRouterError::IncorrectNcnAdmin.into());
+        }
+    }
+
+    {
+        let config_data = config.data.borrow();
+        let config = NcnConfig::try_from_slice_unchecked(&config_data)?;
+        if config.admin(role).eq(admin_multisig.key) {
+            msg!(
+                "Admin multisig is already the {:?}, propose a SetMultisigSigner action instead",
+                role
+            );
+            return Err(TipRouterError::AdminMultisigActive.into());
+        }
+    }
+
+    if admin_multisig.data_is_empty() {
+        load_system_account(admin_multisig, true)?;
+
+        let (admin_multisig_pda, admin_multisig_bump, mut admin_multisig_seeds) =
+            AdminMultisig::find_program_address(program_id, ncn_account.key, role);
+        admin_multisig_seeds.push(vec![admin_multisig_bump]);
+
+        if admin_multisig_pda != *admin_multisig.key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        AccountPayer::pay_and_create_account(
+            program_id,
+            ncn_account.key,
+            account_payer,
+            admin_multisig,
+            system_program,
+            program_id,
+            AdminMultisig::SIZE,
+            &admin_multisig_seeds,
+        )?;
+
+        let mut admin_multisig_data = admin_multisig.try_borrow_mut_data()?;
+        admin_multisig_data[0] = AdminMultisig::DISCRIMINATOR;
+        let admin_multisig_account =
+            AdminMultisig::try_from_slice_unchecked_mut(&mut admin_multisig_data)?;
+        *admin_multisig_account = AdminMultisig::new(ncn_account.key, role, admin_multisig_bump);
+    } else {
+        AdminMultisig::load(program_id, admin_multisig, ncn_account.key, role, true)?;
+    }
+
+    let signers_changed = {
+        let mut admin_multisig_data = admin_multisig.try_borrow_mut_data()?;
+        let admin_multisig_account =
+            AdminMultisig::try_from_slice_unchecked_mut(&mut admin_multisig_data)?;
+        let signers_changed = !admin_multisig_account.has_signers(signers, threshold);
+        if signers_changed {
+            admin_multisig_account.set_signers(signers, threshold)?;
+        }
+        signers_changed
+    };
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = NcnConfig::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    let current_epoch = Clock::get()?.epoch;
+
+    // New signers restart the delay of a pending handover to the multisig
+    if signers_changed && config.pending_admin(role).eq(&Some(admin_multisig.key)) {
+        config.propose_admin_handover(role, admin_multisig.key, current_epoch)?;
+    }
+
+    if !config.hand_over_admin(role, admin_multisig.key, current_epoch)? {
+        msg!(
+            "{:?} handover to a {} of {} multisig {:?} proposed, can be completed from epoch {}",
+            role,
+            threshold,
+            signers.len(),
+            admin_multisig.key,
+            config.pending_admin_accept_epoch(role)
+        );
+        return Ok(());
+    }
+
+    msg!(
+        "{:?} set to a {} of {} multisig {:?}",
+        role,
+        threshold,
+        signers.len(),
+        admin_multisig.key
+    );
+
+    TipRouterEvent::AdminUpdated {
+        ncn: *ncn_account.key,
+        role,
+        new_admin: *admin_multisig.key,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    base_fee_group::BaseFeeGroup, config::Config, error::TipRouterError, events::TipRouterEvent,
+    payout_preference::PayoutAsset,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Names, enables or disables a base fee group and sets the asset its rewards are paid out in,
+/// only the fee admin can sign
+pub fn process_admin_set_base_fee_group(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    base_fee_group: u8,
+    name: Option<[u8; 32]>,
+    enabled: Option<bool>,
+    payout_asset: Option<u8>,
+) -> ProgramResult {
+    let [config, ncn_account, fee_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(fee_admin, false)?;
+
+    Config::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    let epoch = Clock::get()?.epoch;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    if config.ncn != *ncn_account.key {
+        return Err(TipRouterError::IncorrectNcn.into());
+    }
+
+    if config.fee_admin != *fee_admin.key {
+        return Err(TipRouterError::IncorrectFeeAdmin.into());
+    }
+
+    update_base_fee_group(config, epoch, base_fee_group, name, enabled, payout_asset)
+}
+
+/// Updates a base fee group once the fee admin has been verified, shared with the admin multisig
+pub fn update_base_fee_group(
+    config: &mut Config,
+    epoch: u64,
+    base_fee_group: u8,
+    name: Option<[u8; 32]>,
+    enabled: Option<bool>,
+    payout_asset: Option<u8>,
+) -> ProgramResult {
+    let event = TipRouterEvent::BaseFeeGroupUpdated {
+        ncn: config.ncn,
+        epoch,
+        base_fee_group,
+        name,
+        enabled,
+        payout_asset,
+    };
+
+    let group = BaseFeeGroup::try_from(base_fee_group)?;
+    let payout_asset = payout_asset.map(PayoutAsset::try_from).transpose()?;
+
+    config
+        .fee_config
+        .update_base_fee_group(group, name, enabled, payout_asset, epoch)?;
+
+    event.emit();
+
+    Ok(())
+}
@@ -2,7 +2,8 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    base_fee_group::BaseFeeGroup, config::Config, error::TipRouterError, ncn_fee_group::NcnFeeGroup,
+    base_fee_group::BaseFeeGroup, config::Config, error::TipRouterError, events::TipRouterEvent,
+    ncn_fee_group::NcnFeeGroup,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
@@ -20,6 +21,7 @@ pub fn process_admin_set_config_fees(
     ncn_fee_group: Option<u8>,
     new_ncn_fee_bps: Option<u16>,
     new_priority_fee_distribution_fee_bps: Option<u16>,
+    activation_epoch: Option<u64>,
 ) -> ProgramResult {
     let [config, ncn_account, fee_admin] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -44,6 +46,47 @@ pub fn process_admin_set_config_fees(
         return Err(TipRouterError::IncorrectFeeAdmin.into());
     }
 
+    update_config_fees(
+        config,
+        epoch,
+        new_block_engine_fee_bps,
+        base_fee_group,
+        new_base_fee_wallet,
+        new_base_fee_bps,
+        ncn_fee_group,
+        new_ncn_fee_bps,
+        new_priority_fee_distribution_fee_bps,
+        activation_epoch,
+    )
+}
+
+/// Updates the fee config once the fee admin has been verified, shared with the admin multisig
+#[allow(clippy::too_many_arguments)]
+pub fn update_config_fees(
+    config: &mut Config,
+    epoch: u64,
+    new_block_engine_fee_bps: Option<u16>,
+    base_fee_group: Option<u8>,
+    new_base_fee_wallet: Option<Pubkey>,
+    new_base_fee_bps: Option<u16>,
+    ncn_fee_group: Option<u8>,
+    new_ncn_fee_bps: Option<u16>,
+    new_priority_fee_distribution_fee_bps: Option<u16>,
+    activation_epoch: Option<u64>,
+) -> ProgramResult {
+    let event = TipRouterEvent::ConfigFeesUpdated {
+        ncn: config.ncn,
+        epoch,
+        new_block_engine_fee_bps,
+        base_fee_group,
+        new_base_fee_wallet,
+        new_base_fee_bps,
+        ncn_fee_group,
+        new_ncn_fee_bps,
+        new_priority_fee_distribution_fee_bps,
+        activation_epoch,
+    };
+
     let base_fee_group = base_fee_group.map(BaseFeeGroup::try_from).transpose()?;
     let ncn_fee_group = ncn_fee_group.map(NcnFeeGroup::try_from).transpose()?;
 
@@ -56,7 +99,10 @@ pub fn process_admin_set_config_fees(
         new_ncn_fee_bps,
         epoch,
         new_priority_fee_distribution_fee_bps,
+        activation_epoch,
     )?;
 
+    event.emit();
+
     Ok(())
 }
@@ -4,10 +4,11 @@ use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
     config::{Config as NcnConfig, ConfigAdminRole},
     error::TipRouterError,
+    events::TipRouterEvent,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
 pub fn process_admin_set_new_admin(
@@ -39,16 +40,21 @@ pub fn process_admin_set_new_admin(
         return Err(TipRouterError::IncorrectNcnAdmin.into());
     }
 
-    match role {
-        ConfigAdminRole::FeeAdmin => {
-            config.fee_admin = *new_admin.key;
-            msg!("Fee admin set to {:?}", new_admin.key);
-        }
-        ConfigAdminRole::TieBreakerAdmin => {
-            config.tie_breaker_admin = *new_admin.key;
-            msg!("Tie breaker admin set to {:?}", new_admin.key);
-        }
+    // Once a handover delay is set, new admins have to go through propose and accept
+    let current_epoch = Clock::get()?.epoch;
+    if config.admin_handover_delay_epochs(current_epoch) > 0 {
+        return Err(TipRouterError::AdminHandoverRequired.into());
     }
 
+    config.set_admin(role, new_admin.key);
+    msg!("{:?} set to {:?}", role, new_admin.key);
+
+    TipRouterEvent::AdminUpdated {
+        ncn: *ncn_account.key,
+        role,
+        new_admin: *new_admin.key,
+    }
+    .emit();
+
     Ok(())
 }
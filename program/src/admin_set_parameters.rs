@@ -1,20 +1,31 @@
-use jito_bytemuck::{types::PodU64, AccountDeserialize};
+use jito_bytemuck::{
+    types::{PodU16, PodU64},
+    AccountDeserialize,
+};
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
     config::Config,
     constants::{
-        MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE, MAX_EPOCHS_BEFORE_STALL,
-        MAX_VALID_SLOTS_AFTER_CONSENSUS, MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE,
-        MIN_EPOCHS_BEFORE_STALL, MIN_VALID_SLOTS_AFTER_CONSENSUS,
+        precise_consensus, MAX_ADMIN_HANDOVER_DELAY_EPOCHS, MAX_COMMIT_WINDOW_SLOTS,
+        MAX_CONSENSUS_THRESHOLD_BPS, MAX_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE,
+        MAX_EPOCHS_BEFORE_STALL, MAX_QUORUM_OPERATOR_BPS, MAX_REVEAL_WINDOW_SLOTS,
+        MAX_STAKE_WEIGHT_CAP_BPS, MAX_STALL_FALLBACK_THRESHOLD_BPS,
+        MAX_VALID_SLOTS_AFTER_CONSENSUS, MAX_WEIGHT_SAMPLING_WINDOW_SLOTS, MIN_COMMIT_WINDOW_SLOTS,
+        MIN_CONSENSUS_THRESHOLD_BPS, MIN_EPOCHS_AFTER_CONSENSUS_BEFORE_CLOSE,
+        MIN_EPOCHS_BEFORE_STALL, MIN_REVEAL_WINDOW_SLOTS, MIN_STAKE_WEIGHT_CAP_BPS,
+        MIN_STALL_FALLBACK_THRESHOLD_BPS, MIN_VALID_SLOTS_AFTER_CONSENSUS,
+        MIN_WEIGHT_SAMPLING_WINDOW_SLOTS,
     },
     error::TipRouterError,
+    events::TipRouterEvent,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn process_admin_set_parameters(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -22,6 +33,19 @@ pub fn process_admin_set_parameters(
     epochs_before_stall: Option<u64>,
     epochs_after_consensus_before_close: Option<u64>,
     valid_slots_after_consensus: Option<u64>,
+    commit_window_slots: Option<u64>,
+    reveal_window_slots: Option<u64>,
+    consensus_threshold_bps: Option<u16>,
+    quorum_operator_count: Option<u16>,
+    quorum_operator_bps: Option<u16>,
+    stall_fallback_threshold_bps: Option<u16>,
+    admin_handover_delay_epochs: Option<u16>,
+    weight_sampling_window_slots: Option<u64>,
+    max_operator_stake_weight_bps: Option<u16>,
+    max_vault_stake_weight_bps: Option<u16>,
+    fee_increase_notice_epochs: Option<u64>,
+    crank_bounty_lamports: Option<u64>,
+    max_crank_bounty_lamports_per_epoch: Option<u64>,
 ) -> ProgramResult {
     let [config, ncn_account, ncn_admin] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -33,13 +57,14 @@ pub fn process_admin_set_parameters(
     Config::load(program_id, config, ncn_account.key, true)?;
     Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
 
-    {
+    let ncn_operator_count = {
         let ncn_data = ncn_account.data.borrow();
         let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
         if ncn.admin != *ncn_admin.key {
             return Err(TipRouterError::IncorrectNcnAdmin.into());
         }
-    }
+        ncn.operator_count()
+    };
 
     let mut config_data = config.try_borrow_mut_data()?;
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
@@ -48,6 +73,8 @@ pub fn process_admin_set_parameters(
         return Err(TipRouterError::IncorrectNcn.into());
     }
 
+    let current_epoch = Clock::get()?.epoch;
+
     if let Some(epoch) = starting_valid_epoch {
         msg!("Updated valid_starting_epoch to {}", epoch);
         config.starting_valid_epoch = PodU64::from(epoch);
@@ -79,5 +106,148 @@ pub fn process_admin_set_parameters(
         config.valid_slots_after_consensus = PodU64::from(slots);
     }
 
+    // A commit window of 0 turns commit-reveal voting off for ballot boxes created afterwards
+    if let Some(slots) = commit_window_slots {
+        if slots != 0 && !(MIN_COMMIT_WINDOW_SLOTS..=MAX_COMMIT_WINDOW_SLOTS).contains(&slots) {
+            return Err(TipRouterError::InvalidCommitWindow.into());
+        }
+        msg!("Updated commit_window_slots to {}", slots);
+        config.commit_window_slots = PodU64::from(slots);
+    }
+
+    if let Some(slots) = reveal_window_slots {
+        if !(MIN_REVEAL_WINDOW_SLOTS..=MAX_REVEAL_WINDOW_SLOTS).contains(&slots) {
+            return Err(TipRouterError::InvalidRevealWindow.into());
+        }
+        msg!("Updated reveal_window_slots to {}", slots);
+        config.reveal_window_slots = PodU64::from(slots);
+    }
+
+    if config.is_commit_reveal_enabled() && config.reveal_window_slots() == 0 {
+        return Err(TipRouterError::InvalidRevealWindow.into());
+    }
+
+    // Takes effect for epoch states created afterwards
+    if let Some(bps) = consensus_threshold_bps {
+        if !(MIN_CONSENSUS_THRESHOLD_BPS..=MAX_CONSENSUS_THRESHOLD_BPS).contains(&bps) {
+            return Err(TipRouterError::InvalidConsensusThreshold.into());
+        }
+        msg!("Updated consensus_threshold_bps to {}", bps);
+        config.consensus_threshold_bps = PodU16::from(bps);
+    }
+
+    // Quorum settings take effect for ballot boxes created afterwards. Operators past
+    // `MAX_OPERATORS` vote through ballot box pages, so the count is only bounded by the NCN
+    if let Some(count) = quorum_operator_count {
+        if count as u64 > ncn_operator_count {
+            msg!(
+                "Quorum of {} operators is more than the NCN's {} operators",
+                count,
+                ncn_operator_count
+            );
+            return Err(TipRouterError::InvalidQuorum.into());
+        }
+        msg!("Updated quorum_operator_count to {}", count);
+        config.quorum_operator_count = PodU16::from(count);
+    }
+
+    if let Some(bps) = quorum_operator_bps {
+        if bps > MAX_QUORUM_OPERATOR_BPS {
+            return Err(TipRouterError::InvalidQuorum.into());
+        }
+        msg!("Updated quorum_operator_bps to {}", bps);
+        config.quorum_operator_bps = PodU16::from(bps);
+    }
+
+    // A stall fallback threshold of 0 leaves stalled votes to the tie breaker admin
+    if let Some(bps) = stall_fallback_threshold_bps {
+        if bps != 0
+            && !(MIN_STALL_FALLBACK_THRESHOLD_BPS..=MAX_STALL_FALLBACK_THRESHOLD_BPS).contains(&bps)
+        {
+            return Err(TipRouterError::InvalidStallFallbackThreshold.into());
+        }
+        msg!("Updated stall_fallback_threshold_bps to {}", bps);
+        config.stall_fallback_threshold_bps = PodU16::from(bps);
+    }
+
+    // The fallback has to stay below the consensus threshold, or it would never be reached first
+    if config.is_stall_fallback_enabled()
+        && !precise_consensus(config.stall_fallback_threshold_bps())?
+            .less_than(&precise_consensus(config.consensus_threshold_bps())?)
+    {
+        msg!(
+            "Stall fallback threshold {} bps is not below the consensus threshold",
+            config.stall_fallback_threshold_bps()
+        );
+        return Err(TipRouterError::InvalidStallFallbackThreshold.into());
+    }
+
+    // Applies to handovers proposed afterwards, a lower delay only once the current one has passed
+    if let Some(epochs) = admin_handover_delay_epochs {
+        if epochs > MAX_ADMIN_HANDOVER_DELAY_EPOCHS {
+            return Err(TipRouterError::InvalidAdminHandoverDelay.into());
+        }
+        let effective_epoch = config.set_admin_handover_delay_epochs(epochs, current_epoch)?;
+        msg!(
+            "Updated admin_handover_delay_epochs to {} from epoch {}",
+            epochs,
+            effective_epoch
+        );
+    }
+
+    // A sampling window of 0 sets weights from a single price for weight tables created afterwards
+    if let Some(slots) = weight_sampling_window_slots {
+        if slots != 0
+            && !(MIN_WEIGHT_SAMPLING_WINDOW_SLOTS..=MAX_WEIGHT_SAMPLING_WINDOW_SLOTS)
+                .contains(&slots)
+        {
+            return Err(TipRouterError::InvalidWeightSamplingWindow.into());
+        }
+        msg!("Updated weight_sampling_window_slots to {}", slots);
+        config.weight_sampling_window_slots = PodU64::from(slots);
+    }
+
+    // A cap of 0 disables it, caps apply to operator snapshots created afterwards
+    if let Some(bps) = max_operator_stake_weight_bps {
+        if bps != 0 && !(MIN_STAKE_WEIGHT_CAP_BPS..=MAX_STAKE_WEIGHT_CAP_BPS).contains(&bps) {
+            return Err(TipRouterError::InvalidStakeWeightCap.into());
+        }
+        msg!("Updated max_operator_stake_weight_bps to {}", bps);
+        config.max_operator_stake_weight_bps = PodU16::from(bps);
+    }
+
+    if let Some(bps) = max_vault_stake_weight_bps {
+        if bps != 0 && !(MIN_STAKE_WEIGHT_CAP_BPS..=MAX_STAKE_WEIGHT_CAP_BPS).contains(&bps) {
+            return Err(TipRouterError::InvalidStakeWeightCap.into());
+        }
+        msg!("Updated max_vault_stake_weight_bps to {}", bps);
+        config.max_vault_stake_weight_bps = PodU16::from(bps);
+    }
+
+    // Only applies to fee increases scheduled afterwards
+    if let Some(epochs) = fee_increase_notice_epochs {
+        config.fee_config.set_fee_increase_notice_epochs(epochs)?;
+        msg!("Updated fee_increase_notice_epochs to {}", epochs);
+    }
+
+    // A bounty of 0 stops paying crankers, bounties are paid by the account payer
+    if let Some(lamports) = crank_bounty_lamports {
+        msg!("Updated crank_bounty_lamports to {}", lamports);
+        config.crank_bounty_lamports = PodU64::from(lamports);
+    }
+
+    if let Some(lamports) = max_crank_bounty_lamports_per_epoch {
+        msg!(
+            "Updated max_crank_bounty_lamports_per_epoch to {}",
+            lamports
+        );
+        config.max_crank_bounty_lamports_per_epoch = PodU64::from(lamports);
+    }
+
+    TipRouterEvent::ConfigParametersUpdated {
+        ncn: *ncn_account.key,
+    }
+    .emit();
+
     Ok(())
 }
@@ -0,0 +1,79 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{config::Config, error::TipRouterError, events::TipRouterEvent};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Halts or resumes subsystems in an emergency. The NCN admin can set any bitmask, the pause
+/// admin can only add subsystems to it.
+pub fn process_admin_set_paused_subsystems(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused_subsystems: u8,
+) -> ProgramResult {
+    let [config, ncn_account, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(admin, false)?;
+
+    Config::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    let is_ncn_admin = {
+        let ncn_data = ncn_account.data.borrow();
+        let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+        ncn.admin.eq(admin.key)
+    };
+
+    let epoch = Clock::get()?.epoch;
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    if config.ncn != *ncn_account.key {
+        return Err(TipRouterError::IncorrectNcn.into());
+    }
+
+    if !is_ncn_admin
+        && (config.pause_admin.eq(&Pubkey::default()) || config.pause_admin.ne(admin.key))
+    {
+        return Err(TipRouterError::IncorrectPauseAdmin.into());
+    }
+
+    update_paused_subsystems(config, epoch, admin.key, is_ncn_admin, paused_subsystems)
+}
+
+/// Updates the paused subsystems once `admin` has been verified as the NCN admin or the pause
+/// admin, shared with the admin multisig. The pause admin can only add subsystems.
+pub fn update_paused_subsystems(
+    config: &mut Config,
+    epoch: u64,
+    admin: &Pubkey,
+    is_ncn_admin: bool,
+    paused_subsystems: u8,
+) -> ProgramResult {
+    if !is_ncn_admin {
+        let current_paused_subsystems = config.paused_subsystems();
+        if paused_subsystems & current_paused_subsystems != current_paused_subsystems {
+            msg!("Only the NCN admin can unpause subsystems");
+            return Err(TipRouterError::IncorrectNcnAdmin.into());
+        }
+    }
+
+    config.set_paused_subsystems(paused_subsystems)?;
+    msg!("Updated paused_subsystems to {:#07b}", paused_subsystems);
+
+    TipRouterEvent::PausedSubsystemsUpdated {
+        ncn: config.ncn,
+        epoch,
+        admin: *admin,
+        paused_subsystems,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_signer, load_token_mint};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{config::Config, error::TipRouterError};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Allows or disallows an SPL token mint for reward routers, only the NCN admin can sign
+pub fn process_admin_set_reward_mint(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    allowed: bool,
+) -> ProgramResult {
+    let [config, ncn_account, mint, ncn_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(ncn_admin, false)?;
+
+    Config::load(program_id, config, ncn_account.key, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn_account, false)?;
+
+    // Mints can be disallowed even if they are no longer valid
+    if allowed {
+        load_token_mint(mint)?;
+    }
+
+    {
+        let ncn_data = ncn_account.data.borrow();
+        let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+        if ncn.admin != *ncn_admin.key {
+            return Err(TipRouterError::IncorrectNcnAdmin.into());
+        }
+    }
+
+    let mut config_data = config.try_borrow_mut_data()?;
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    if config.ncn != *ncn_account.key {
+        return Err(TipRouterError::IncorrectNcn.into());
+    }
+
+    config.set_reward_mint(mint.key, allowed)?;
+
+    if allowed {
+        msg!("Allowed reward mint {}", mint.key);
+    } else {
+        msg!("Disallowed reward mint {}", mint.key);
+    }
+
+    Ok(())
+}
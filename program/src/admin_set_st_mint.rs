@@ -1,7 +1,7 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::ncn::Ncn;
-use jito_tip_router_core::{config::Config, vault_registry::VaultRegistry};
+use jito_tip_router_core::{config::Config, events::TipRouterEvent, vault_registry::VaultRegistry};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
@@ -15,6 +15,10 @@ pub fn process_admin_set_st_mint(
     reward_multiplier_bps: Option<u64>,
     switchboard_feed: Option<Pubkey>,
     no_feed_weight: Option<u128>,
+    oracle_type: Option<u8>,
+    max_staleness: Option<u64>,
+    max_confidence_bps: Option<u16>,
+    max_weight_change_bps: Option<u16>,
 ) -> ProgramResult {
     let [config, ncn, vault_registry, admin] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -48,5 +52,22 @@ pub fn process_admin_set_st_mint(
         no_feed_weight,
     )?;
 
+    if let Some(oracle_type) = oracle_type {
+        vault_registry_account.set_st_mint_oracle_type(st_mint, oracle_type)?;
+    }
+
+    vault_registry_account.set_st_mint_oracle_guards(
+        st_mint,
+        max_staleness,
+        max_confidence_bps,
+        max_weight_change_bps,
+    )?;
+
+    TipRouterEvent::StMintUpdated {
+        ncn: *ncn.key,
+        st_mint: *st_mint,
+    }
+    .emit();
+
     Ok(())
 }
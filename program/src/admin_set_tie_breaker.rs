@@ -3,7 +3,7 @@ use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
     ballot_box::BallotBox, config::Config as NcnConfig, epoch_state::EpochState,
-    error::TipRouterError,
+    error::TipRouterError, events::TipRouterEvent,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -20,20 +20,48 @@ pub fn process_admin_set_tie_breaker(
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
     NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
-    BallotBox::load(program_id, ballot_box, ncn.key, epoch, true)?;
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
     load_signer(tie_breaker_admin, false)?;
 
-    let ncn_config_data = ncn_config.data.borrow();
-    let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+    {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
 
-    if ncn_config.tie_breaker_admin.ne(tie_breaker_admin.key) {
-        msg!("Tie breaker admin invalid");
-        return Err(TipRouterError::TieBreakerAdminInvalid.into());
+        if ncn_config.tie_breaker_admin.ne(tie_breaker_admin.key) {
+            msg!("Tie breaker admin invalid");
+            return Err(TipRouterError::TieBreakerAdminInvalid.into());
+        }
     }
 
+    set_tie_breaker(
+        program_id,
+        epoch_state,
+        ncn_config,
+        ballot_box,
+        ncn,
+        meta_merkle_root,
+        epoch,
+    )
+}
+
+/// Sets the tie breaker ballot once the tie breaker admin has been verified,
+/// shared with the admin multisig
+pub fn set_tie_breaker(
+    program_id: &Pubkey,
+    epoch_state: &AccountInfo,
+    ncn_config: &AccountInfo,
+    ballot_box: &AccountInfo,
+    ncn: &AccountInfo,
+    meta_merkle_root: &[u8; 32],
+    epoch: u64,
+) -> ProgramResult {
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, true)?;
+
+    let ncn_config_data = ncn_config.data.borrow();
+    let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+
     let mut ballot_box_data = ballot_box.data.borrow_mut();
     let ballot_box_account = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
 
@@ -51,9 +79,28 @@ pub fn process_admin_set_tie_breaker(
         let slot = clock.slot;
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
-        epoch_state_account
-            .update_set_tie_breaker(ballot_box_account.is_consensus_reached(), slot)?;
+        epoch_state_account.update_set_tie_breaker(
+            ballot_box_account.is_consensus_reached(),
+            false,
+            slot,
+        )?;
+    }
+
+    TipRouterEvent::TieBreakerSet {
+        ncn: *ncn.key,
+        epoch,
+        meta_merkle_root: *meta_merkle_root,
+        is_stall_fallback: false,
+    }
+    .emit();
+
+    TipRouterEvent::ConsensusReached {
+        ncn: *ncn.key,
+        epoch,
+        meta_merkle_root: *meta_merkle_root,
+        slot: clock.slot,
     }
+    .emit();
 
     Ok(())
 }
@@ -2,7 +2,8 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    epoch_state::EpochState, error::TipRouterError, weight_table::WeightTable,
+    epoch_state::EpochState, error::TipRouterError, events::TipRouterEvent,
+    weight_table::WeightTable,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -58,5 +59,13 @@ pub fn process_admin_set_weight(
         );
     }
 
+    TipRouterEvent::AdminWeightSet {
+        ncn: *ncn.key,
+        epoch,
+        st_mint: *st_mint,
+        weight,
+    }
+    .emit();
+
     Ok(())
 }
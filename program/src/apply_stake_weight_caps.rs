@@ -0,0 +1,85 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_tip_router_core::{
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_state::EpochState,
+    error::TipRouterError,
+    operator_snapshot_page::OperatorSnapshotPage,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Caps the operator's voting stake weight once the total stake weight of the epoch is known, so
+/// votes can be weighed without writing to the operator snapshot. Operators with vaults past
+/// `MAX_VAULTS` take their operator snapshot pages as remaining accounts.
+pub fn process_apply_stake_weight_caps(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn, epoch_snapshot, operator, operator_snapshot, operator_snapshot_pages @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, false)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    Operator::load(&jito_restaking_program::id(), operator, false)?;
+    EpochSnapshot::load(program_id, epoch_snapshot, ncn.key, epoch, true)?;
+    OperatorSnapshot::load(
+        program_id,
+        operator_snapshot,
+        operator.key,
+        ncn.key,
+        epoch,
+        true,
+    )?;
+
+    let mut epoch_snapshot_data = epoch_snapshot.try_borrow_mut_data()?;
+    let epoch_snapshot_account =
+        EpochSnapshot::try_from_slice_unchecked_mut(&mut epoch_snapshot_data)?;
+
+    if !epoch_snapshot_account.finalized() {
+        msg!("Epoch snapshot is not finalized");
+        return Err(TipRouterError::EpochSnapshotNotFinalized.into());
+    }
+
+    let mut operator_snapshot_data = operator_snapshot.try_borrow_mut_data()?;
+    let operator_snapshot_account =
+        OperatorSnapshot::try_from_slice_unchecked_mut(&mut operator_snapshot_data)?;
+
+    OperatorSnapshotPage::load_all_pages(
+        program_id,
+        operator_snapshot_pages,
+        operator.key,
+        ncn.key,
+        epoch,
+        operator_snapshot_account.page_count(),
+    )?;
+    let operator_snapshot_pages_data = operator_snapshot_pages
+        .iter()
+        .map(|page| page.try_borrow_data())
+        .collect::<Result<Vec<_>, _>>()?;
+    let operator_snapshot_pages_accounts = operator_snapshot_pages_data
+        .iter()
+        .map(|data| OperatorSnapshotPage::try_from_slice_unchecked(data))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let capped_stake_weight = operator_snapshot_account.apply_stake_weight_caps(
+        epoch_snapshot_account.stake_weights().stake_weight(),
+        &operator_snapshot_pages_accounts,
+    )?;
+
+    epoch_snapshot_account.increment_operators_capped(capped_stake_weight)?;
+
+    msg!(
+        "Capped {} stake weight of operator {}",
+        capped_stake_weight,
+        operator.key
+    );
+
+    Ok(())
+}
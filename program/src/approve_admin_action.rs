@@ -0,0 +1,47 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    admin_multisig::{AdminMultisig, AdminProposal},
+    config::ConfigAdminRole,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Approves an admin action as one of the signers of the role's multisig
+pub fn process_approve_admin_action(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: ConfigAdminRole,
+    nonce: u64,
+) -> ProgramResult {
+    let [admin_multisig, admin_proposal, ncn, signer] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer, false)?;
+
+    AdminMultisig::load(program_id, admin_multisig, ncn.key, role, false)?;
+    AdminProposal::load(program_id, admin_proposal, ncn.key, role, nonce, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+
+    let admin_multisig_data = admin_multisig.data.borrow();
+    let admin_multisig_account = AdminMultisig::try_from_slice_unchecked(&admin_multisig_data)?;
+
+    let mut admin_proposal_data = admin_proposal.try_borrow_mut_data()?;
+    let admin_proposal_account =
+        AdminProposal::try_from_slice_unchecked_mut(&mut admin_proposal_data)?;
+
+    admin_proposal_account.approve(admin_multisig_account, signer.key)?;
+
+    msg!(
+        "Proposal {} has {} of {} approvals",
+        nonce,
+        admin_proposal_account.approval_count(),
+        admin_multisig_account.threshold()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,206 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::load_system_program;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    config::Config as NcnConfig,
+    epoch_state::EpochState,
+    error::TipRouterError,
+    events::TipRouterEvent,
+    ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    pause::PausableSubsystem,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    epoch_schedule::EpochSchedule, msg, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// Permissionless, moves what is left in a finished epoch's base or NCN reward router and its
+/// receiver into the current epoch's base reward receiver, to be routed again. Rewards already
+/// assigned to a group, operator or vault stay behind. Only lamport routers can be carried forward.
+pub fn process_carry_forward_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, config, ncn, reward_router, reward_receiver, current_epoch_state, current_base_reward_receiver, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_program(system_program)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    NcnConfig::load_unpaused(
+        program_id,
+        config,
+        ncn.key,
+        PausableSubsystem::Routing,
+        false,
+    )?;
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+
+    let clock = Clock::get()?;
+    let current_epoch = clock.epoch;
+    if epoch >= current_epoch {
+        msg!("Rewards can only be carried forward into a later epoch");
+        return Err(TipRouterError::CannotCarryForwardRewards.into());
+    }
+
+    EpochState::load(
+        program_id,
+        current_epoch_state,
+        ncn.key,
+        current_epoch,
+        true,
+    )?;
+    BaseRewardReceiver::load(
+        program_id,
+        current_base_reward_receiver,
+        ncn.key,
+        current_epoch,
+        true,
+    )?;
+
+    // The epoch is finished once its accounts can be closed
+    {
+        let config_data = config.try_borrow_data()?;
+        let config_account = NcnConfig::try_from_slice_unchecked(&config_data)?;
+
+        let epoch_state_data = epoch_state.try_borrow_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked(&epoch_state_data)?;
+
+        let can_close_epoch_accounts = epoch_state_account.can_close_epoch_accounts(
+            &EpochSchedule::get()?,
+            config_account.epochs_after_consensus_before_close(),
+            clock.slot,
+        )?;
+
+        if !can_close_epoch_accounts {
+            msg!("Epoch is not finished");
+            return Err(TipRouterError::CannotCarryForwardRewards.into());
+        }
+    }
+
+    let discriminator = {
+        let reward_router_data = reward_router.try_borrow_data()?;
+        *reward_router_data
+            .first()
+            .ok_or(ProgramError::InvalidAccountData)?
+    };
+
+    // Only lamports above rent that were never assigned are moved, the rewards in transit stay
+    // with the receiver so they can still be distributed
+    let receiver_rewards = reward_receiver
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+
+    let rewards = match discriminator {
+        BaseRewardRouter::DISCRIMINATOR => {
+            BaseRewardRouter::load_to_close(program_id, reward_router, ncn.key, epoch)?;
+            BaseRewardReceiver::load(program_id, reward_receiver, ncn.key, epoch, true)?;
+
+            let mut reward_router_data = reward_router.try_borrow_mut_data()?;
+            let base_reward_router =
+                BaseRewardRouter::try_from_slice_unchecked_mut(&mut reward_router_data)?;
+
+            if base_reward_router.is_token_router() {
+                msg!("Token rewards cannot be carried forward");
+                return Err(TipRouterError::CannotCarryForwardRewards.into());
+            }
+
+            let rewards = base_reward_router.carry_forward_rewards(receiver_rewards)?;
+
+            if rewards > 0 {
+                BaseRewardReceiver::transfer(
+                    program_id,
+                    ncn.key,
+                    epoch,
+                    reward_receiver,
+                    current_base_reward_receiver,
+                    rewards,
+                )?;
+            }
+
+            rewards
+        }
+        NcnRewardRouter::DISCRIMINATOR => {
+            NcnRewardRouter::load_to_close(program_id, reward_router, ncn.key, epoch)?;
+
+            let mut reward_router_data = reward_router.try_borrow_mut_data()?;
+            let ncn_reward_router =
+                NcnRewardRouter::try_from_slice_unchecked_mut(&mut reward_router_data)?;
+
+            if ncn_reward_router.is_token_router() {
+                msg!("Token rewards cannot be carried forward");
+                return Err(TipRouterError::CannotCarryForwardRewards.into());
+            }
+
+            let ncn_fee_group = ncn_reward_router.ncn_fee_group();
+            let operator = *ncn_reward_router.operator();
+
+            NcnRewardReceiver::load(
+                program_id,
+                reward_receiver,
+                ncn_fee_group,
+                &operator,
+                ncn.key,
+                epoch,
+                true,
+            )?;
+
+            let rewards = ncn_reward_router.carry_forward_rewards(receiver_rewards)?;
+
+            if rewards > 0 {
+                NcnRewardReceiver::transfer(
+                    program_id,
+                    ncn_fee_group,
+                    &operator,
+                    ncn.key,
+                    epoch,
+                    reward_receiver,
+                    current_base_reward_receiver,
+                    rewards,
+                )?;
+            }
+
+            rewards
+        }
+        _ => {
+            msg!("Account is not a reward router");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    };
+
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_carry_forward_rewards(rewards)?;
+    }
+
+    {
+        let mut current_epoch_state_data = current_epoch_state.try_borrow_mut_data()?;
+        let current_epoch_state_account =
+            EpochState::try_from_slice_unchecked_mut(&mut current_epoch_state_data)?;
+        current_epoch_state_account.update_carry_in_rewards(rewards)?;
+    }
+
+    msg!(
+        "Carried {} lamports forward from epoch {} to epoch {}",
+        rewards,
+        epoch,
+        current_epoch
+    );
+
+    TipRouterEvent::RewardsCarriedForward {
+        ncn: *ncn.key,
+        epoch,
+        to_epoch: current_epoch,
+        reward_router: *reward_router.key,
+        rewards,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -3,7 +3,9 @@ use jito_priority_fee_distribution_sdk::instruction::claim_ix as priority_fee_di
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_distribution_sdk as jito_tip_distribution;
 use jito_tip_distribution_sdk::instruction::claim_ix;
-use jito_tip_router_core::{account_payer::AccountPayer, config::Config};
+use jito_tip_router_core::{
+    account_payer::AccountPayer, config::Config, events::TipRouterEvent, pause::PausableSubsystem,
+};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
     program_error::ProgramError, pubkey::Pubkey,
@@ -24,7 +26,13 @@ pub fn process_claim_with_payer(
 
     // Verify claim status address
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
-    Config::load(program_id, config, ncn.key, false)?;
+    Config::load_unpaused(
+        program_id,
+        config,
+        ncn.key,
+        PausableSubsystem::Claiming,
+        false,
+    )?;
     AccountPayer::load(program_id, account_payer, ncn.key, true)?;
 
     let distibution_program_id = distribution_program.key;
@@ -100,5 +108,14 @@ pub fn process_claim_with_payer(
         ],
     )?;
 
+    TipRouterEvent::RewardsClaimed {
+        ncn: *ncn.key,
+        distribution_program: *distibution_program_id,
+        distribution_account: *distribution_account.key,
+        claimant: *claimant.key,
+        amount,
+    }
+    .emit();
+
     Ok(())
 }
@@ -0,0 +1,69 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer,
+    admin_multisig::{AdminMultisig, AdminProposal},
+    config::{Config as NcnConfig, ConfigAdminRole},
+    error::TipRouterError,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Closes an admin proposal and returns its rent to the proposer. Executed or stale
+/// proposals, and proposals of a multisig that no longer holds the role, can be closed by anyone;
+/// open proposals only by their proposer
+pub fn process_close_admin_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: ConfigAdminRole,
+    nonce: u64,
+) -> ProgramResult {
+    let [config, admin_multisig, admin_proposal, ncn, proposer, signer] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer, false)?;
+
+    NcnConfig::load(program_id, config, ncn.key, false)?;
+    AdminMultisig::load(program_id, admin_multisig, ncn.key, role, false)?;
+    AdminProposal::load(program_id, admin_proposal, ncn.key, role, nonce, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+
+    {
+        let config_data = config.data.borrow();
+        let config_account = NcnConfig::try_from_slice_unchecked(&config_data)?;
+
+        let admin_multisig_data = admin_multisig.data.borrow();
+        let admin_multisig_account = AdminMultisig::try_from_slice_unchecked(&admin_multisig_data)?;
+
+        let admin_proposal_data = admin_proposal.data.borrow();
+        let admin_proposal_account = AdminProposal::try_from_slice_unchecked(&admin_proposal_data)?;
+
+        if admin_proposal_account.proposer().ne(proposer.key) {
+            msg!(
+                "Proposal {} was proposed by {}",
+                nonce,
+                admin_proposal_account.proposer()
+            );
+            return Err(TipRouterError::IncorrectAdminProposer.into());
+        }
+
+        let multisig_active = config_account.admin(role).eq(admin_multisig.key);
+        if multisig_active
+            && !admin_proposal_account.is_closed(admin_multisig_account)
+            && proposer.key.ne(signer.key)
+        {
+            msg!("Proposal {} is still open", nonce);
+            return Err(TipRouterError::AdminProposalStillOpen.into());
+        }
+    }
+
+    AccountPayer::close_account(program_id, proposer, admin_proposal)?;
+
+    msg!("Proposal {} closed", nonce);
+
+    Ok(())
+}
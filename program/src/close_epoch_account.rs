@@ -1,18 +1,24 @@
 use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::loader::load_system_program;
+use jito_jsm_core::loader::{load_associated_token_account, load_system_program};
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
     account_payer::AccountPayer,
     ballot_box::BallotBox,
+    ballot_box_page::BallotBoxPage,
     base_fee_group::BaseFeeGroup,
     base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    base_reward_router_page::BaseRewardRouterPage,
     config::Config as NcnConfig,
     epoch_marker::EpochMarker,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    epoch_state_page::EpochStatePage,
     error::TipRouterError,
+    events::TipRouterEvent,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    operator_snapshot_page::OperatorSnapshotPage,
     weight_table::WeightTable,
+    weight_table_page::WeightTablePage,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
@@ -20,7 +26,12 @@ use solana_program::{
     sysvar::Sysvar,
 };
 
-/// Crank Closes all accounts associated with an epoch
+use crate::token_rewards::load_receiver_token_account;
+
+/// Crank Closes all accounts associated with an epoch. Operator snapshots and NCN reward routers
+/// of operators past `MAX_OPERATORS` also take their epoch state page, after the receiver for
+/// routers. Epoch state pages are closed once all of their accounts are. Base reward router
+/// pages take their base reward router.
 #[allow(clippy::cognitive_complexity)]
 pub fn process_close_epoch_account(
     program_id: &Pubkey,
@@ -108,10 +119,40 @@ pub fn process_close_epoch_account(
                 }
                 WeightTable::DISCRIMINATOR => {
                     WeightTable::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
+                    {
+                        let account_to_close_data = account_to_close.try_borrow_data()?;
+                        let weight_table =
+                            WeightTable::try_from_slice_unchecked(&account_to_close_data)?;
+                        if weight_table.pages_initialized() > 0 {
+                            msg!("Weight table pages have to be closed first");
+                            return Err(TipRouterError::CannotCloseAccount.into());
+                        }
+                    }
                     epoch_state_account.close_weight_table();
                 }
+                WeightTablePage::DISCRIMINATOR => {
+                    WeightTablePage::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
+                    let page = {
+                        let account_to_close_data = account_to_close.try_borrow_data()?;
+                        WeightTablePage::try_from_slice_unchecked(&account_to_close_data)?.page()
+                    };
+                    let [weight_table] = optional_accounts else {
+                        msg!("Weight table account is missing");
+                        return Err(TipRouterError::CannotCloseAccount.into());
+                    };
+                    WeightTable::load(program_id, weight_table, ncn.key, epoch, true)?;
+                    let mut weight_table_data = weight_table.try_borrow_mut_data()?;
+                    let weight_table =
+                        WeightTable::try_from_slice_unchecked_mut(&mut weight_table_data)?;
+                    weight_table.remove_page(page)?;
+                }
                 EpochSnapshot::DISCRIMINATOR => {
                     EpochSnapshot::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
+                    let account_to_close_data = account_to_close.try_borrow_data()?;
+                    let epoch_snapshot =
+                        EpochSnapshot::try_from_slice_unchecked(&account_to_close_data)?;
+                    epoch_state_account
+                        .archive_epoch_snapshot(epoch_snapshot.stake_weights().stake_weight());
                     epoch_state_account.close_epoch_snapshot();
                 }
                 OperatorSnapshot::DISCRIMINATOR => {
@@ -119,36 +160,219 @@ pub fn process_close_epoch_account(
                     let account_to_close_data = account_to_close.try_borrow_data()?;
                     let account_to_close_struct =
                         OperatorSnapshot::try_from_slice_unchecked(&account_to_close_data)?;
-                    let ncn_operator_index = account_to_close_struct.ncn_operator_index() as usize;
-                    epoch_state_account.close_operator_snapshot(ncn_operator_index);
+                    if account_to_close_struct.page_count() > 0 {
+                        msg!("Operator snapshot pages have to be closed first");
+                        return Err(TipRouterError::CannotCloseAccount.into());
+                    }
+                    let ncn_operator_index = account_to_close_struct.ncn_operator_index();
+                    let epoch_state_page = EpochStatePage::load_for_operator(
+                        program_id,
+                        optional_accounts
+                            .first()
+                            .filter(|account| account.key.ne(program_id)),
+                        ncn.key,
+                        epoch,
+                        ncn_operator_index,
+                        true,
+                    )?;
+                    let mut epoch_state_page_data = epoch_state_page
+                        .map(|account| account.try_borrow_mut_data())
+                        .transpose()?;
+                    let epoch_state_page = epoch_state_page_data
+                        .as_deref_mut()
+                        .map(|data| EpochStatePage::try_from_slice_unchecked_mut(data))
+                        .transpose()?;
+                    epoch_state_account
+                        .close_operator_snapshot(ncn_operator_index as usize, epoch_state_page)?;
+                }
+                EpochStatePage::DISCRIMINATOR => {
+                    EpochStatePage::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
+                    let page = {
+                        let account_to_close_data = account_to_close.try_borrow_data()?;
+                        EpochStatePage::try_from_slice_unchecked(&account_to_close_data)?.page()
+                    };
+                    epoch_state_account.remove_page(page)?;
+                }
+                OperatorSnapshotPage::DISCRIMINATOR => {
+                    OperatorSnapshotPage::load_to_close(
+                        program_id,
+                        account_to_close,
+                        ncn.key,
+                        epoch,
+                    )?;
+                    let (operator, page) = {
+                        let account_to_close_data = account_to_close.try_borrow_data()?;
+                        let operator_snapshot_page =
+                            OperatorSnapshotPage::try_from_slice_unchecked(&account_to_close_data)?;
+                        (
+                            *operator_snapshot_page.operator(),
+                            operator_snapshot_page.page(),
+                        )
+                    };
+                    let [operator_snapshot] = optional_accounts else {
+                        msg!("Operator snapshot account is missing");
+                        return Err(TipRouterError::CannotCloseAccount.into());
+                    };
+                    OperatorSnapshot::load(
+                        program_id,
+                        operator_snapshot,
+                        &operator,
+                        ncn.key,
+                        epoch,
+                        true,
+                    )?;
+                    let mut operator_snapshot_data = operator_snapshot.try_borrow_mut_data()?;
+                    let operator_snapshot = OperatorSnapshot::try_from_slice_unchecked_mut(
+                        &mut operator_snapshot_data,
+                    )?;
+                    operator_snapshot.remove_page(page)?;
                 }
                 BallotBox::DISCRIMINATOR => {
                     BallotBox::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
+                    let account_to_close_data = account_to_close.try_borrow_data()?;
+                    let ballot_box = BallotBox::try_from_slice_unchecked(&account_to_close_data)?;
+                    if ballot_box.page_count() > 0 {
+                        msg!("Ballot box pages have to be closed first");
+                        return Err(TipRouterError::CannotCloseAccount.into());
+                    }
+                    if let Ok(winning_ballot) = ballot_box.get_winning_ballot() {
+                        epoch_state_account.archive_ballot_box(&winning_ballot.root());
+                    }
                     epoch_state_account.close_ballot_box();
                 }
+                BallotBoxPage::DISCRIMINATOR => {
+                    BallotBoxPage::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
+                    let page = {
+                        let account_to_close_data = account_to_close.try_borrow_data()?;
+                        BallotBoxPage::try_from_slice_unchecked(&account_to_close_data)?.page()
+                    };
+                    let [ballot_box] = optional_accounts else {
+                        msg!("Ballot box account is missing");
+                        return Err(TipRouterError::CannotCloseAccount.into());
+                    };
+                    BallotBox::load(program_id, ballot_box, ncn.key, epoch, true)?;
+                    let mut ballot_box_data = ballot_box.try_borrow_mut_data()?;
+                    let ballot_box = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
+                    ballot_box.remove_page(page)?;
+                }
                 BaseRewardRouter::DISCRIMINATOR => {
                     BaseRewardRouter::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
-                    let [base_reward_receiver] = optional_accounts else {
-                        msg!("Base reward receiver account is missing");
-                        return Err(TipRouterError::CannotCloseAccountNoReceiverProvided.into());
+                    let (total_rewards, mint) = {
+                        let account_to_close_data = account_to_close.try_borrow_data()?;
+                        let base_reward_router =
+                            BaseRewardRouter::try_from_slice_unchecked(&account_to_close_data)?;
+                        if base_reward_router.page_count() > 0 {
+                            msg!("Base reward router pages have to be closed first");
+                            return Err(TipRouterError::CannotCloseAccount.into());
+                        }
+                        (
+                            base_reward_router.total_rewards(),
+                            *base_reward_router.mint(),
+                        )
                     };
-                    BaseRewardReceiver::load(
+
+                    // Token routers send what is left to the DAO wallet's ATA
+                    if mint.ne(&Pubkey::default()) {
+                        let [base_reward_receiver, base_reward_receiver_token_account, dao_wallet_token_account, token_program] =
+                            optional_accounts
+                        else {
+                            msg!("Base reward receiver token accounts are missing");
+                            return Err(TipRouterError::CannotCloseAccountNoReceiverProvided.into());
+                        };
+                        BaseRewardReceiver::load(
+                            program_id,
+                            base_reward_receiver,
+                            ncn.key,
+                            epoch,
+                            false,
+                        )?;
+                        if token_program.key.ne(&spl_token_interface::id()) {
+                            msg!("Incorrect token program for token rewards");
+                            return Err(ProgramError::IncorrectProgramId);
+                        }
+                        let amount = load_receiver_token_account(
+                            base_reward_receiver_token_account,
+                            base_reward_receiver.key,
+                            &mint,
+                        )?;
+                        load_associated_token_account(
+                            dao_wallet_token_account,
+                            dao_wallet.key,
+                            &mint,
+                        )?;
+
+                        BaseRewardReceiver::close_token_account(
+                            program_id,
+                            ncn.key,
+                            epoch,
+                            base_reward_receiver,
+                            base_reward_receiver_token_account,
+                            dao_wallet_token_account,
+                            account_payer,
+                            token_program,
+                            amount,
+                        )?;
+
+                        epoch_state_account.close_token_reward_router()?;
+                    } else {
+                        epoch_state_account.archive_base_reward_router(total_rewards);
+
+                        let [base_reward_receiver] = optional_accounts else {
+                            msg!("Base reward receiver account is missing");
+                            return Err(TipRouterError::CannotCloseAccountNoReceiverProvided.into());
+                        };
+                        BaseRewardReceiver::load(
+                            program_id,
+                            base_reward_receiver,
+                            ncn.key,
+                            epoch,
+                            true,
+                        )?;
+                        BaseRewardReceiver::close(
+                            program_id,
+                            ncn.key,
+                            epoch,
+                            base_reward_receiver,
+                            dao_wallet,
+                            account_payer,
+                        )?;
+
+                        epoch_state_account.close_base_reward_router();
+                    }
+                }
+                BaseRewardRouterPage::DISCRIMINATOR => {
+                    BaseRewardRouterPage::load_to_close(
                         program_id,
-                        base_reward_receiver,
+                        account_to_close,
                         ncn.key,
                         epoch,
-                        true,
                     )?;
-                    BaseRewardReceiver::close(
+                    let (mint, page) = {
+                        let account_to_close_data = account_to_close.try_borrow_data()?;
+                        let base_reward_router_page =
+                            BaseRewardRouterPage::try_from_slice_unchecked(&account_to_close_data)?;
+                        (
+                            *base_reward_router_page.mint(),
+                            base_reward_router_page.page(),
+                        )
+                    };
+                    let [base_reward_router] = optional_accounts else {
+                        msg!("Base reward router account is missing");
+                        return Err(TipRouterError::CannotCloseAccount.into());
+                    };
+                    BaseRewardRouter::load_for_mint(
                         program_id,
+                        base_reward_router,
                         ncn.key,
+                        &mint,
                         epoch,
-                        base_reward_receiver,
-                        dao_wallet,
-                        account_payer,
+                        true,
                     )?;
-
-                    epoch_state_account.close_base_reward_router();
+                    let mut base_reward_router_data = base_reward_router.try_borrow_mut_data()?;
+                    let base_reward_router = BaseRewardRouter::try_from_slice_unchecked_mut(
+                        &mut base_reward_router_data,
+                    )?;
+                    base_reward_router.remove_page(page)?;
                 }
                 NcnRewardRouter::DISCRIMINATOR => {
                     NcnRewardRouter::load_to_close(program_id, account_to_close, ncn.key, epoch)?;
@@ -156,38 +380,106 @@ pub fn process_close_epoch_account(
                     let ncn_reward_router =
                         NcnRewardRouter::try_from_slice_unchecked(&account_to_close_data)?;
 
-                    let ncn_operator_index = ncn_reward_router.ncn_operator_index() as usize;
+                    let ncn_operator_index = ncn_reward_router.ncn_operator_index();
                     let operator = ncn_reward_router.operator();
                     let ncn_fee_group = ncn_reward_router.ncn_fee_group();
 
-                    let [ncn_reward_receiver] = optional_accounts else {
-                        msg!("NCN reward receiver account is missing");
-                        return Err(TipRouterError::CannotCloseAccountNoReceiverProvided.into());
-                    };
+                    // Token routers send what is left to the DAO wallet's ATA
+                    if ncn_reward_router.is_token_router() {
+                        let mint = ncn_reward_router.mint();
+                        let [ncn_reward_receiver, ncn_reward_receiver_token_account, dao_wallet_token_account, token_program] =
+                            optional_accounts
+                        else {
+                            msg!("NCN reward receiver token accounts are missing");
+                            return Err(TipRouterError::CannotCloseAccountNoReceiverProvided.into());
+                        };
+                        NcnRewardReceiver::load(
+                            program_id,
+                            ncn_reward_receiver,
+                            ncn_fee_group,
+                            operator,
+                            ncn.key,
+                            epoch,
+                            false,
+                        )?;
+                        if token_program.key.ne(&spl_token_interface::id()) {
+                            msg!("Incorrect token program for token rewards");
+                            return Err(ProgramError::IncorrectProgramId);
+                        }
+                        let amount = load_receiver_token_account(
+                            ncn_reward_receiver_token_account,
+                            ncn_reward_receiver.key,
+                            mint,
+                        )?;
+                        load_associated_token_account(
+                            dao_wallet_token_account,
+                            dao_wallet.key,
+                            mint,
+                        )?;
 
-                    NcnRewardReceiver::load(
-                        program_id,
-                        ncn_reward_receiver,
-                        ncn_fee_group,
-                        operator,
-                        ncn.key,
-                        epoch,
-                        true,
-                    )?;
+                        NcnRewardReceiver::close_token_account(
+                            program_id,
+                            ncn_fee_group,
+                            operator,
+                            ncn.key,
+                            epoch,
+                            ncn_reward_receiver,
+                            ncn_reward_receiver_token_account,
+                            dao_wallet_token_account,
+                            account_payer,
+                            token_program,
+                            amount,
+                        )?;
 
-                    NcnRewardReceiver::close(
-                        program_id,
-                        ncn_fee_group,
-                        operator,
-                        ncn.key,
-                        epoch,
-                        ncn_reward_receiver,
-                        dao_wallet,
-                        account_payer,
-                    )?;
+                        epoch_state_account.close_token_reward_router()?;
+                    } else {
+                        let [ncn_reward_receiver, epoch_state_page @ ..] = optional_accounts else {
+                            msg!("NCN reward receiver account is missing");
+                            return Err(TipRouterError::CannotCloseAccountNoReceiverProvided.into());
+                        };
 
-                    epoch_state_account
-                        .close_ncn_reward_router(ncn_operator_index, ncn_fee_group)?;
+                        NcnRewardReceiver::load(
+                            program_id,
+                            ncn_reward_receiver,
+                            ncn_fee_group,
+                            operator,
+                            ncn.key,
+                            epoch,
+                            true,
+                        )?;
+
+                        NcnRewardReceiver::close(
+                            program_id,
+                            ncn_fee_group,
+                            operator,
+                            ncn.key,
+                            epoch,
+                            ncn_reward_receiver,
+                            dao_wallet,
+                            account_payer,
+                        )?;
+
+                        let epoch_state_page = EpochStatePage::load_for_operator(
+                            program_id,
+                            epoch_state_page.first(),
+                            ncn.key,
+                            epoch,
+                            ncn_operator_index,
+                            true,
+                        )?;
+                        let mut epoch_state_page_data = epoch_state_page
+                            .map(|account| account.try_borrow_mut_data())
+                            .transpose()?;
+                        let epoch_state_page = epoch_state_page_data
+                            .as_deref_mut()
+                            .map(|data| EpochStatePage::try_from_slice_unchecked_mut(data))
+                            .transpose()?;
+                        epoch_state_account.close_ncn_reward_router(
+                            ncn_operator_index as usize,
+                            ncn_fee_group,
+                            epoch_state_page,
+                        )?;
+                    }
                 }
                 _ => {
                     return Err(TipRouterError::InvalidAccountToCloseDiscriminator.into());
@@ -222,7 +514,25 @@ pub fn process_close_epoch_account(
 
         let slot_closed = Clock::get()?.slot;
         *epoch_marker = EpochMarker::new(ncn.key, epoch, slot_closed);
+
+        let epoch_state_data = epoch_state.try_borrow_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked(&epoch_state_data)?;
+        epoch_marker.archive(epoch_state_account);
+    }
+
+    let discriminator = if closing_epoch_state {
+        EpochState::DISCRIMINATOR
+    } else {
+        account_to_close.try_borrow_data()?[0]
+    };
+
+    TipRouterEvent::AccountClosed {
+        ncn: *ncn.key,
+        epoch,
+        account: *account_to_close.key,
+        discriminator,
     }
+    .emit();
 
     AccountPayer::close_account(program_id, account_payer, account_to_close)
 }
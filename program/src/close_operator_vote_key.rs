@@ -0,0 +1,43 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_tip_router_core::{
+    account_payer::AccountPayer, config::Config as NcnConfig, error::TipRouterError,
+    operator_vote_key::OperatorVoteKey,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Closes the operator vote key account, only the operator admin can sign and receives the rent.
+/// Operators that left the NCN can still close it.
+pub fn process_close_operator_vote_key(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, operator_vote_key, ncn, operator, operator_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(operator_admin, true)?;
+
+    NcnConfig::load(program_id, config, ncn.key, false)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    Operator::load(&jito_restaking_program::id(), operator, false)?;
+    OperatorVoteKey::load(program_id, operator_vote_key, operator.key, ncn.key, true)?;
+
+    {
+        let operator_data = operator.data.borrow();
+        let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+        if operator_account.admin.ne(operator_admin.key) {
+            return Err(TipRouterError::IncorrectOperatorAdmin.into());
+        }
+    }
+
+    AccountPayer::close_account(program_id, operator_admin, operator_vote_key)?;
+
+    msg!("Operator {} vote key closed", operator.key);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use jito_tip_router_core::events::TipRouterEvent;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::vote::load_vote_accounts;
+
+/// Records an operator's commitment to a vote, the merkle root stays hidden until it is revealed
+pub fn process_commit_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    vote_commitment: &[u8; 32],
+    epoch: u64,
+) -> ProgramResult {
+    let vote_accounts = load_vote_accounts(program_id, accounts, epoch, false)?;
+    let ncn = vote_accounts.ncn.key;
+    let operator = vote_accounts.operator.key;
+
+    let slot = Clock::get()?.slot;
+
+    vote_accounts.with_ballot_box(|ballot_box, ballot_box_page| {
+        ballot_box.commit_vote_with_page(ballot_box_page, operator, vote_commitment, slot)?;
+
+        msg!(
+            "Operator {} committed vote for epoch {} ({} operators committed)",
+            operator,
+            epoch,
+            ballot_box.operators_committed()
+        );
+
+        Ok(())
+    })?;
+
+    TipRouterEvent::VoteCommitted {
+        ncn: *ncn,
+        epoch,
+        operator: *operator,
+        slot,
+    }
+    .emit();
+
+    Ok(())
+}
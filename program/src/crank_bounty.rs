@@ -0,0 +1,82 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_tip_router_core::{
+    account_payer::AccountPayer, config::Config as NcnConfig, epoch_state::EpochState,
+    events::TipRouterEvent,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey, rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// Pays the signer of a completed crank step the config's crank bounty from the account payer.
+/// `rewards` is the lamports the step moved, token steps pay no bounty. `bounty_accounts` are
+/// the optional `account_payer` and `cranker` accounts of the instruction, no bounty is paid when
+/// either is left out, bounties are off, the step moved too few rewards, the epoch's bounty
+/// budget is spent or the account payer cannot cover the bounty and stay rent exempt.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_crank_bounty<'a, 'info>(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+    config: &'a AccountInfo<'info>,
+    epoch_state: &'a AccountInfo<'info>,
+    rewards: u64,
+    bounty_accounts: &'a [AccountInfo<'info>],
+) -> ProgramResult {
+    let mut bounty_accounts = bounty_accounts
+        .iter()
+        .take(2)
+        .filter(|account| account.key.ne(program_id));
+    let (Some(account_payer), Some(cranker)) = (bounty_accounts.next(), bounty_accounts.next())
+    else {
+        return Ok(());
+    };
+
+    AccountPayer::load(program_id, account_payer, ncn, true)?;
+    load_signer(cranker, true)?;
+
+    let bounty = {
+        let config_data = config.try_borrow_data()?;
+        let config_account = NcnConfig::try_from_slice_unchecked(&config_data)?;
+
+        let epoch_state_data = epoch_state.try_borrow_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked(&epoch_state_data)?;
+
+        epoch_state_account.crank_bounty(
+            config_account.crank_bounty_lamports(),
+            config_account.max_crank_bounty_lamports_per_epoch(),
+            rewards,
+        )
+    };
+
+    if bounty == 0 {
+        return Ok(());
+    }
+
+    let available_lamports = account_payer
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    if available_lamports < bounty {
+        msg!("Account payer cannot cover the crank bounty");
+        return Ok(());
+    }
+
+    AccountPayer::transfer(program_id, ncn, account_payer, cranker, bounty)?;
+
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_crank_bounty_paid(bounty)?;
+    }
+
+    TipRouterEvent::CrankBountyPaid {
+        ncn: *ncn,
+        epoch,
+        cranker: *cranker.key,
+        lamports: bounty,
+    }
+    .emit();
+
+    Ok(())
+}
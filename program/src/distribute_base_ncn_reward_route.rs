@@ -1,13 +1,16 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_system_program;
+use jito_jsm_core::loader::{load_associated_token_account, load_system_program};
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_tip_router_core::{
     base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    base_reward_router_page::BaseRewardRouterPage,
     config::Config as NcnConfig,
     epoch_state::EpochState,
     error::TipRouterError,
+    events::TipRouterEvent,
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    pause::PausableSubsystem,
 };
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
@@ -15,14 +18,19 @@ use solana_program::{
 };
 use solana_system_interface::instruction as system_instruction;
 
-/// Can be backfilled for previous epochs
+use crate::{crank_bounty::pay_crank_bounty, token_rewards::load_receiver_token_account};
+
+/// Can be backfilled for previous epochs. Token routers transfer their `mint` between the
+/// receivers' ATAs instead of lamports. Moving lamports earns the optional `cranker` a crank
+/// bounty, moving tokens earns none. Operators past `MAX_OPERATORS` are routed in the optional
+/// base reward router page.
 pub fn process_distribute_base_ncn_reward_route(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     ncn_fee_group: u8,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, ncn, operator, base_reward_router, base_reward_receiver, ncn_reward_router, ncn_reward_receiver, system_program] =
+    let [epoch_state, ncn_config, ncn, operator, base_reward_router, base_reward_receiver, ncn_reward_router, ncn_reward_receiver, system_program, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -34,14 +42,33 @@ pub fn process_distribute_base_ncn_reward_route(
 
     let ncn_fee_group = NcnFeeGroup::try_from(ncn_fee_group)?;
 
-    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
-    BaseRewardRouter::load(program_id, base_reward_router, ncn.key, epoch, true)?;
-    NcnRewardRouter::load(
+    let mint = optional_accounts
+        .first()
+        .filter(|account| account.key.ne(program_id));
+    let mint_key = mint.map_or(Pubkey::default(), |mint| *mint.key);
+
+    NcnConfig::load_unpaused(
+        program_id,
+        ncn_config,
+        ncn.key,
+        PausableSubsystem::Distribution,
+        false,
+    )?;
+    BaseRewardRouter::load_for_mint(
+        program_id,
+        base_reward_router,
+        ncn.key,
+        &mint_key,
+        epoch,
+        true,
+    )?;
+    NcnRewardRouter::load_for_mint(
         program_id,
         ncn_reward_router,
         ncn_fee_group,
         operator.key,
         ncn.key,
+        &mint_key,
         epoch,
         false,
     )?;
@@ -58,6 +85,23 @@ pub fn process_distribute_base_ncn_reward_route(
 
     load_system_program(system_program)?;
 
+    let ncn_operator_index = {
+        let ncn_reward_router_data = ncn_reward_router.try_borrow_data()?;
+        let ncn_reward_router_account =
+            NcnRewardRouter::try_from_slice_unchecked(&ncn_reward_router_data)?;
+        ncn_reward_router_account.ncn_operator_index()
+    };
+    let base_reward_router_page = BaseRewardRouterPage::load_for_operator(
+        program_id,
+        optional_accounts
+            .get(6)
+            .filter(|account| account.key.ne(program_id)),
+        ncn.key,
+        &mint_key,
+        epoch,
+        ncn_operator_index,
+    )?;
+
     // Get rewards and update state
     let rewards = {
         let mut epoch_reward_router_data = base_reward_router.try_borrow_mut_data()?;
@@ -69,10 +113,64 @@ pub fn process_distribute_base_ncn_reward_route(
             return Err(TipRouterError::RouterStillRouting.into());
         }
 
-        base_reward_router_account
-            .distribute_ncn_fee_group_reward_route(ncn_fee_group, operator.key)?
+        match base_reward_router_page {
+            Some(base_reward_router_page) => {
+                let mut base_reward_router_page_data =
+                    base_reward_router_page.try_borrow_mut_data()?;
+                let base_reward_router_page_account =
+                    BaseRewardRouterPage::try_from_slice_unchecked_mut(
+                        &mut base_reward_router_page_data,
+                    )?;
+                base_reward_router_account.distribute_ncn_fee_group_reward_route_with_page(
+                    Some(base_reward_router_page_account),
+                    ncn_fee_group,
+                    operator.key,
+                )?
+            }
+            None => base_reward_router_account
+                .distribute_ncn_fee_group_reward_route(ncn_fee_group, operator.key)?,
+        }
     };
 
+    if mint.is_some() {
+        let [_, base_reward_receiver_token_account, ncn_reward_receiver_token_account, token_program, ..] =
+            optional_accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if token_program.key.ne(&spl_token_interface::id()) {
+            msg!("Incorrect token program for token rewards");
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        load_receiver_token_account(
+            base_reward_receiver_token_account,
+            base_reward_receiver.key,
+            &mint_key,
+        )?;
+        load_associated_token_account(
+            ncn_reward_receiver_token_account,
+            ncn_reward_receiver.key,
+            &mint_key,
+        )?;
+
+        if rewards > 0 {
+            BaseRewardReceiver::transfer_tokens(
+                program_id,
+                ncn.key,
+                epoch,
+                base_reward_receiver,
+                base_reward_receiver_token_account,
+                ncn_reward_receiver_token_account,
+                token_program,
+                rewards,
+            )?;
+        }
+
+        // Epoch state progress, events and crank bounties are tracked in lamports
+        return Ok(());
+    }
+
     // Send rewards
     if rewards > 0 {
         let (_, base_reward_receiver_bump, mut base_reward_receiver_seeds) =
@@ -100,5 +198,26 @@ pub fn process_distribute_base_ncn_reward_route(
         epoch_state_account.update_distribute_base_ncn_rewards(rewards)?;
     }
 
+    TipRouterEvent::BaseNcnRewardsDistributed {
+        ncn: *ncn.key,
+        epoch,
+        operator: *operator.key,
+        ncn_fee_group: ncn_fee_group.group,
+        rewards,
+    }
+    .emit();
+
+    if rewards > 0 {
+        pay_crank_bounty(
+            program_id,
+            ncn.key,
+            epoch,
+            ncn_config,
+            epoch_state,
+            rewards,
+            optional_accounts.get(4..).unwrap_or_default(),
+        )?;
+    }
+
     Ok(())
 }
@@ -8,19 +8,29 @@ use jito_tip_router_core::{
     constants::JITOSOL_MINT,
     epoch_state::EpochState,
     error::TipRouterError,
+    events::TipRouterEvent,
+    pause::PausableSubsystem,
+    payout_preference::PayoutAsset,
 };
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
     program_error::ProgramError, pubkey::Pubkey,
 };
 
+use crate::{crank_bounty::pay_crank_bounty, token_rewards::load_receiver_token_account};
+
+/// Pays the rewards of a base fee group out to its fee wallet in the group's `PayoutAsset`: deposited
+/// into the JitoSOL ATA of the fee wallet, sent to its wSOL ATA, or sent to the fee wallet itself
+/// as SOL, passed as `base_fee_wallet_ata`. Token routers transfer their `mint` to the fee
+/// wallet's ATA for that mint instead. Paying out lamports earns the optional `cranker` a crank
+/// bounty, token payouts earn none.
 pub fn process_distribute_base_rewards(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     base_fee_group: u8,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, ncn, base_reward_router, base_reward_receiver, base_fee_wallet, base_fee_wallet_ata, stake_pool_program, stake_pool, stake_pool_withdraw_authority, reserve_stake, manager_fee_account, referrer_pool_tokens_account, pool_mint, token_program, system_program] =
+    let [epoch_state, ncn_config, ncn, base_reward_router, base_reward_receiver, base_fee_wallet, base_fee_wallet_ata, stake_pool_program, stake_pool, stake_pool_withdraw_authority, reserve_stake, manager_fee_account, referrer_pool_tokens_account, pool_mint, token_program, system_program, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -28,10 +38,28 @@ pub fn process_distribute_base_rewards(
 
     EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
-    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
-    BaseRewardRouter::load(program_id, base_reward_router, ncn.key, epoch, true)?;
+    NcnConfig::load_unpaused(
+        program_id,
+        ncn_config,
+        ncn.key,
+        PausableSubsystem::Distribution,
+        false,
+    )?;
     BaseRewardReceiver::load(program_id, base_reward_receiver, ncn.key, epoch, true)?;
-    load_associated_token_account(base_fee_wallet_ata, base_fee_wallet.key, &JITOSOL_MINT)?;
+
+    let mint = optional_accounts
+        .first()
+        .filter(|account| account.key.ne(program_id));
+    let mint_key = mint.map_or(Pubkey::default(), |mint| *mint.key);
+
+    BaseRewardRouter::load_for_mint(
+        program_id,
+        base_reward_router,
+        ncn.key,
+        &mint_key,
+        epoch,
+        true,
+    )?;
 
     let spl_stake_pool_id = crate::spl_stake_pool_id();
 
@@ -42,7 +70,7 @@ pub fn process_distribute_base_rewards(
 
     let group = BaseFeeGroup::try_from(base_fee_group)?;
 
-    {
+    let payout_asset = {
         let ncn_config_data = ncn_config.try_borrow_data()?;
         let ncn_config_account = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
         let fee_wallet = ncn_config_account.fee_config.base_fee_wallet(group)?;
@@ -51,6 +79,46 @@ pub fn process_distribute_base_rewards(
             msg!("Incorrect base fee wallet");
             return Err(ProgramError::InvalidAccountData);
         }
+
+        ncn_config_account
+            .fee_config
+            .base_fee_group_settings(group)?
+            .payout_asset()?
+    };
+
+    if mint.is_some() {
+        if token_program.key.ne(&spl_token_interface::id()) {
+            msg!("Incorrect token program for token rewards");
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        load_associated_token_account(base_fee_wallet_ata, base_fee_wallet.key, &mint_key)?;
+    } else {
+        match payout_asset {
+            PayoutAsset::JitoSol => {
+                load_associated_token_account(
+                    base_fee_wallet_ata,
+                    base_fee_wallet.key,
+                    &JITOSOL_MINT,
+                )?;
+            }
+            PayoutAsset::WrappedSol => {
+                if token_program.key.ne(&spl_token_interface::id()) {
+                    msg!("Incorrect token program for wSOL");
+                    return Err(ProgramError::IncorrectProgramId);
+                }
+                load_associated_token_account(
+                    base_fee_wallet_ata,
+                    base_fee_wallet.key,
+                    &spl_token_interface::native_mint::id(),
+                )?;
+            }
+            PayoutAsset::Sol => {
+                if base_fee_wallet_ata.key.ne(base_fee_wallet.key) {
+                    msg!("SOL rewards are sent to the base fee wallet");
+                    return Err(TipRouterError::InvalidPayoutDestination.into());
+                }
+            }
+        }
     }
 
     // Get rewards and update state
@@ -67,46 +135,91 @@ pub fn process_distribute_base_rewards(
         base_reward_router_account.distribute_base_fee_group_rewards(group)?
     };
 
-    if rewards > 0 {
-        let (_, base_reward_receiver_bump, mut base_reward_receiver_seeds) =
-            BaseRewardReceiver::find_program_address(program_id, ncn.key, epoch);
-        base_reward_receiver_seeds.push(vec![base_reward_receiver_bump]);
-
-        let deposit_ix = crate::deposit_sol(
-            stake_pool_program.key,
-            stake_pool.key,
-            stake_pool_withdraw_authority.key,
-            reserve_stake.key,
+    if mint.is_some() {
+        let base_reward_receiver_token_account = optional_accounts
+            .get(1)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        load_receiver_token_account(
+            base_reward_receiver_token_account,
             base_reward_receiver.key,
-            base_fee_wallet_ata.key,
-            manager_fee_account.key,
-            referrer_pool_tokens_account.key,
-            pool_mint.key,
-            token_program.key,
-            rewards,
-        );
-
-        // Invoke the deposit instruction with base_reward_router as signer
-        invoke_signed(
-            &deposit_ix,
-            &[
-                stake_pool.clone(),
-                stake_pool_withdraw_authority.clone(),
-                reserve_stake.clone(),
-                base_reward_receiver.clone(),
-                base_fee_wallet_ata.clone(),
-                manager_fee_account.clone(),
-                referrer_pool_tokens_account.clone(),
-                pool_mint.clone(),
-                system_program.clone(),
-                token_program.clone(),
-            ],
-            &[base_reward_receiver_seeds
-                .iter()
-                .map(|s| s.as_slice())
-                .collect::<Vec<&[u8]>>()
-                .as_slice()],
+            &mint_key,
         )?;
+
+        if rewards > 0 {
+            BaseRewardReceiver::transfer_tokens(
+                program_id,
+                ncn.key,
+                epoch,
+                base_reward_receiver,
+                base_reward_receiver_token_account,
+                base_fee_wallet_ata,
+                token_program,
+                rewards,
+            )?;
+        }
+
+        // Epoch state progress, events and crank bounties are tracked in lamports
+        return Ok(());
+    }
+
+    if rewards > 0 {
+        match payout_asset {
+            PayoutAsset::JitoSol => {
+                let (_, base_reward_receiver_bump, mut base_reward_receiver_seeds) =
+                    BaseRewardReceiver::find_program_address(program_id, ncn.key, epoch);
+                base_reward_receiver_seeds.push(vec![base_reward_receiver_bump]);
+
+                let deposit_ix = crate::deposit_sol(
+                    stake_pool_program.key,
+                    stake_pool.key,
+                    stake_pool_withdraw_authority.key,
+                    reserve_stake.key,
+                    base_reward_receiver.key,
+                    base_fee_wallet_ata.key,
+                    manager_fee_account.key,
+                    referrer_pool_tokens_account.key,
+                    pool_mint.key,
+                    token_program.key,
+                    rewards,
+                );
+
+                // Invoke the deposit instruction with base_reward_router as signer
+                invoke_signed(
+                    &deposit_ix,
+                    &[
+                        stake_pool.clone(),
+                        stake_pool_withdraw_authority.clone(),
+                        reserve_stake.clone(),
+                        base_reward_receiver.clone(),
+                        base_fee_wallet_ata.clone(),
+                        manager_fee_account.clone(),
+                        referrer_pool_tokens_account.clone(),
+                        pool_mint.clone(),
+                        system_program.clone(),
+                        token_program.clone(),
+                    ],
+                    &[base_reward_receiver_seeds
+                        .iter()
+                        .map(|s| s.as_slice())
+                        .collect::<Vec<&[u8]>>()
+                        .as_slice()],
+                )?;
+            }
+            PayoutAsset::Sol | PayoutAsset::WrappedSol => {
+                BaseRewardReceiver::transfer(
+                    program_id,
+                    ncn.key,
+                    epoch,
+                    base_reward_receiver,
+                    base_fee_wallet_ata,
+                    rewards,
+                )?;
+
+                if payout_asset == PayoutAsset::WrappedSol {
+                    crate::payout::sync_wrapped_sol(base_fee_wallet_ata, token_program)?;
+                }
+            }
+        }
     }
 
     {
@@ -115,5 +228,26 @@ pub fn process_distribute_base_rewards(
         epoch_state_account.update_distribute_base_rewards(rewards)?;
     }
 
+    TipRouterEvent::BaseRewardsDistributed {
+        ncn: *ncn.key,
+        epoch,
+        base_fee_group,
+        recipient: *base_fee_wallet.key,
+        rewards,
+    }
+    .emit();
+
+    if rewards > 0 {
+        pay_crank_bounty(
+            program_id,
+            ncn.key,
+            epoch,
+            ncn_config,
+            epoch_state,
+            rewards,
+            optional_accounts.get(2..).unwrap_or_default(),
+        )?;
+    }
+
     Ok(())
 }
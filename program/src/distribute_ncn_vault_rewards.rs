@@ -3,12 +3,15 @@ use jito_jsm_core::loader::load_associated_token_account;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_tip_router_core::{
     config::Config as NcnConfig,
-    constants::JITOSOL_MINT,
     epoch_snapshot::OperatorSnapshot,
     epoch_state::EpochState,
+    epoch_state_page::EpochStatePage,
     error::TipRouterError,
+    events::TipRouterEvent,
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    pause::PausableSubsystem,
+    payout_preference::{PayoutAsset, PayoutPreference},
 };
 use jito_vault_core::vault::Vault;
 use solana_program::{
@@ -16,14 +19,18 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey,
 };
 
-/// Can be backfilled for previous epochs
+use crate::{crank_bounty::pay_crank_bounty, token_rewards::load_receiver_token_account};
+
+/// Can be backfilled for previous epochs. Token routers pay out their `mint` to the vault's ATA for
+/// that mint, regardless of its payout preference. Paying out lamports earns the optional
+/// `cranker` a crank bounty, token payouts earn none. Operators past `MAX_OPERATORS` take their `epoch_state_page`.
 pub fn process_distribute_ncn_vault_rewards(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     ncn_fee_group: u8,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, ncn, operator, vault, vault_ata, operator_snapshot, ncn_reward_router, ncn_reward_receiver, stake_pool_program, stake_pool, stake_pool_withdraw_authority, reserve_stake, manager_fee_account, referrer_pool_tokens_account, pool_mint, token_program, system_program] =
+    let [epoch_state, ncn_config, ncn, operator, vault, vault_ata, operator_snapshot, ncn_reward_router, ncn_reward_receiver, stake_pool_program, stake_pool, stake_pool_withdraw_authority, reserve_stake, manager_fee_account, referrer_pool_tokens_account, pool_mint, token_program, system_program, payout_preference, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -44,13 +51,25 @@ pub fn process_distribute_ncn_vault_rewards(
 
     let ncn_fee_group = NcnFeeGroup::try_from(ncn_fee_group)?;
 
-    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
-    NcnRewardRouter::load(
+    NcnConfig::load_unpaused(
+        program_id,
+        ncn_config,
+        ncn.key,
+        PausableSubsystem::Distribution,
+        false,
+    )?;
+    let mint = optional_accounts
+        .first()
+        .filter(|account| account.key.ne(program_id));
+    let mint_key = mint.map_or(Pubkey::default(), |mint| *mint.key);
+
+    NcnRewardRouter::load_for_mint(
         program_id,
         ncn_reward_router,
         ncn_fee_group,
         operator.key,
         ncn.key,
+        &mint_key,
         epoch,
         true,
     )?;
@@ -63,7 +82,24 @@ pub fn process_distribute_ncn_vault_rewards(
         epoch,
         true,
     )?;
-    load_associated_token_account(vault_ata, vault.key, &JITOSOL_MINT)?;
+    let payout_preference =
+        PayoutPreference::load_or_default(program_id, payout_preference, vault.key, ncn.key)?;
+
+    // Token rewards are always paid to the ATA for their mint
+    let payout_asset = if mint.is_some() {
+        if token_program.key.ne(&spl_token_interface::id()) {
+            msg!("Incorrect token program for token rewards");
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        load_associated_token_account(vault_ata, vault.key, &mint_key)?;
+        None
+    } else {
+        Some(crate::payout::load_payout_destination(
+            &payout_preference,
+            vault_ata,
+            token_program,
+        )?)
+    };
 
     if stake_pool_program.key.ne(&crate::spl_stake_pool_id()) {
         msg!("Incorrect stake pool program ID");
@@ -84,54 +120,101 @@ pub fn process_distribute_ncn_vault_rewards(
         ncn_reward_router_account.distribute_vault_reward_route(vault.key)?
     };
 
-    // Send rewards
-    if rewards > 0 {
-        let (_, ncn_reward_receiver_bump, mut ncn_reward_receiver_seeds) =
-            NcnRewardReceiver::find_program_address(
+    let Some(payout_asset) = payout_asset else {
+        let ncn_reward_receiver_token_account = optional_accounts
+            .get(1)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        load_receiver_token_account(
+            ncn_reward_receiver_token_account,
+            ncn_reward_receiver.key,
+            &mint_key,
+        )?;
+
+        if rewards > 0 {
+            NcnRewardReceiver::transfer_tokens(
                 program_id,
                 ncn_fee_group,
                 operator.key,
                 ncn.key,
                 epoch,
-            );
-        ncn_reward_receiver_seeds.push(vec![ncn_reward_receiver_bump]);
-
-        let deposit_ix = crate::deposit_sol(
-            stake_pool_program.key,
-            stake_pool.key,
-            stake_pool_withdraw_authority.key,
-            reserve_stake.key,
-            ncn_reward_receiver.key,
-            vault_ata.key,
-            manager_fee_account.key,
-            referrer_pool_tokens_account.key,
-            pool_mint.key,
-            token_program.key,
-            rewards,
-        );
-
-        // Invoke the deposit instruction with ncn_reward_receiver as signer
-        invoke_signed(
-            &deposit_ix,
-            &[
-                stake_pool.clone(),
-                stake_pool_withdraw_authority.clone(),
-                reserve_stake.clone(),
-                ncn_reward_receiver.clone(),
-                vault_ata.clone(),
-                manager_fee_account.clone(),
-                referrer_pool_tokens_account.clone(),
-                pool_mint.clone(),
-                system_program.clone(),
-                token_program.clone(),
-                stake_pool_program.clone(),
-            ],
-            &[ncn_reward_receiver_seeds
-                .iter()
-                .map(|s| s.as_slice())
-                .collect::<Vec<&[u8]>>()
-                .as_slice()],
-        )?;
+                ncn_reward_receiver,
+                ncn_reward_receiver_token_account,
+                vault_ata,
+                token_program,
+                rewards,
+            )?;
+        }
+
+        // Epoch state progress, events and crank bounties are tracked in lamports
+        return Ok(());
+    };
+
+    // Send rewards
+    if rewards > 0 {
+        match payout_asset {
+            PayoutAsset::JitoSol => {
+                let (_, ncn_reward_receiver_bump, mut ncn_reward_receiver_seeds) =
+                    NcnRewardReceiver::find_program_address(
+                        program_id,
+                        ncn_fee_group,
+                        operator.key,
+                        ncn.key,
+                        epoch,
+                    );
+                ncn_reward_receiver_seeds.push(vec![ncn_reward_receiver_bump]);
+
+                let deposit_ix = crate::deposit_sol(
+                    stake_pool_program.key,
+                    stake_pool.key,
+                    stake_pool_withdraw_authority.key,
+                    reserve_stake.key,
+                    ncn_reward_receiver.key,
+                    vault_ata.key,
+                    manager_fee_account.key,
+                    referrer_pool_tokens_account.key,
+                    pool_mint.key,
+                    token_program.key,
+                    rewards,
+                );
+
+                // Invoke the deposit instruction with ncn_reward_receiver as signer
+                invoke_signed(
+                    &deposit_ix,
+                    &[
+                        stake_pool.clone(),
+                        stake_pool_withdraw_authority.clone(),
+                        reserve_stake.clone(),
+                        ncn_reward_receiver.clone(),
+                        vault_ata.clone(),
+                        manager_fee_account.clone(),
+                        referrer_pool_tokens_account.clone(),
+                        pool_mint.clone(),
+                        system_program.clone(),
+                        token_program.clone(),
+                        stake_pool_program.clone(),
+                    ],
+                    &[ncn_reward_receiver_seeds
+                        .iter()
+                        .map(|s| s.as_slice())
+                        .collect::<Vec<&[u8]>>()
+                        .as_slice()],
+                )?;
+            }
+            PayoutAsset::Sol | PayoutAsset::WrappedSol => {
+                crate::payout::pay_out_sol(
+                    program_id,
+                    payout_asset,
+                    ncn_fee_group,
+                    operator.key,
+                    ncn.key,
+                    epoch,
+                    ncn_reward_receiver,
+                    vault_ata,
+                    token_program,
+                    rewards,
+                )?;
+            }
+        }
     }
 
     {
@@ -139,12 +222,54 @@ pub fn process_distribute_ncn_vault_rewards(
         let operator_snapshot_account =
             OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_data)?;
 
+        let ncn_operator_index = operator_snapshot_account.ncn_operator_index();
+        let epoch_state_page = EpochStatePage::load_for_operator(
+            program_id,
+            optional_accounts
+                .get(4)
+                .filter(|account| account.key.ne(program_id)),
+            ncn.key,
+            epoch,
+            ncn_operator_index,
+            true,
+        )?;
+        let mut epoch_state_page_data = epoch_state_page
+            .map(|account| account.try_borrow_mut_data())
+            .transpose()?;
+        let epoch_state_page = epoch_state_page_data
+            .as_deref_mut()
+            .map(|data| EpochStatePage::try_from_slice_unchecked_mut(data))
+            .transpose()?;
+
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
         epoch_state_account.update_distribute_ncn_rewards(
-            operator_snapshot_account.ncn_operator_index() as usize,
+            ncn_operator_index as usize,
             ncn_fee_group,
             rewards,
+            epoch_state_page,
+        )?;
+    }
+
+    TipRouterEvent::NcnRewardsDistributed {
+        ncn: *ncn.key,
+        epoch,
+        operator: *operator.key,
+        ncn_fee_group: ncn_fee_group.group,
+        recipient: *vault.key,
+        rewards,
+    }
+    .emit();
+
+    if rewards > 0 {
+        pay_crank_bounty(
+            program_id,
+            ncn.key,
+            epoch,
+            ncn_config,
+            epoch_state,
+            rewards,
+            optional_accounts.get(2..).unwrap_or_default(),
         )?;
     }
 
@@ -0,0 +1,182 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    admin_multisig::{AdminAction, AdminMultisig, AdminProposal},
+    config::{Config as NcnConfig, ConfigAdminRole},
+    error::TipRouterError,
+    events::TipRouterEvent,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    admin_set_base_fee_group::update_base_fee_group, admin_set_config_fees::update_config_fees,
+    admin_set_paused_subsystems::update_paused_subsystems, admin_set_tie_breaker::set_tie_breaker,
+};
+
+/// Permissionlessly executes an admin action once the role's multisig threshold is met. Setting
+/// the tie breaker takes the epoch state and ballot box of its epoch as remaining accounts.
+pub fn process_execute_admin_action(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: ConfigAdminRole,
+    nonce: u64,
+) -> ProgramResult {
+    let [config, admin_multisig, admin_proposal, ncn, remaining_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    NcnConfig::load(program_id, config, ncn.key, true)?;
+    AdminMultisig::load(program_id, admin_multisig, ncn.key, role, true)?;
+    AdminProposal::load(program_id, admin_proposal, ncn.key, role, nonce, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+
+    {
+        let config_data = config.data.borrow();
+        let config = NcnConfig::try_from_slice_unchecked(&config_data)?;
+        if config.admin(role).ne(admin_multisig.key) {
+            msg!("Admin multisig is not the {:?}", role);
+            return Err(TipRouterError::AdminMultisigNotActive.into());
+        }
+    }
+
+    let clock = Clock::get()?;
+
+    let action = {
+        let admin_multisig_data = admin_multisig.data.borrow();
+        let admin_multisig_account = AdminMultisig::try_from_slice_unchecked(&admin_multisig_data)?;
+
+        let mut admin_proposal_data = admin_proposal.try_borrow_mut_data()?;
+        let admin_proposal_account =
+            AdminProposal::try_from_slice_unchecked_mut(&mut admin_proposal_data)?;
+
+        admin_proposal_account.execute(admin_multisig_account, clock.slot)?
+    };
+
+    msg!("Executing proposal {}: {:?}", nonce, action);
+
+    match action {
+        AdminAction::SetTieBreaker {
+            meta_merkle_root,
+            epoch,
+        } => {
+            let [epoch_state, ballot_box, ..] = remaining_accounts else {
+                msg!("Setting the tie breaker needs the epoch state and ballot box");
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            set_tie_breaker(
+                program_id,
+                epoch_state,
+                config,
+                ballot_box,
+                ncn,
+                &meta_merkle_root,
+                epoch,
+            )
+        }
+        AdminAction::SetConfigFees {
+            new_block_engine_fee_bps,
+            base_fee_group,
+            new_base_fee_wallet,
+            new_base_fee_bps,
+            ncn_fee_group,
+            new_ncn_fee_bps,
+            new_priority_fee_distribution_fee_bps,
+            activation_epoch,
+        } => {
+            let mut config_data = config.try_borrow_mut_data()?;
+            let config = NcnConfig::try_from_slice_unchecked_mut(&mut config_data)?;
+            update_config_fees(
+                config,
+                clock.epoch,
+                new_block_engine_fee_bps,
+                base_fee_group,
+                new_base_fee_wallet,
+                new_base_fee_bps,
+                ncn_fee_group,
+                new_ncn_fee_bps,
+                new_priority_fee_distribution_fee_bps,
+                activation_epoch,
+            )
+        }
+        AdminAction::SetNewAdmin { role, new_admin } => {
+            let mut config_data = config.try_borrow_mut_data()?;
+            let config = NcnConfig::try_from_slice_unchecked_mut(&mut config_data)?;
+
+            // Goes through the handover timelock, the new admin accepts once the delay has passed
+            if !config.hand_over_admin(role, &new_admin, clock.epoch)? {
+                msg!(
+                    "{:?} handover to {:?} proposed, can be accepted from epoch {}",
+                    role,
+                    new_admin,
+                    config.pending_admin_accept_epoch(role)
+                );
+                return Ok(());
+            }
+            msg!("{:?} set to {:?}", role, new_admin);
+
+            TipRouterEvent::AdminUpdated {
+                ncn: *ncn.key,
+                role,
+                new_admin,
+            }
+            .emit();
+
+            Ok(())
+        }
+        AdminAction::SetBaseFeeGroup {
+            base_fee_group,
+            name,
+            enabled,
+            payout_asset,
+        } => {
+            let mut config_data = config.try_borrow_mut_data()?;
+            let config = NcnConfig::try_from_slice_unchecked_mut(&mut config_data)?;
+            update_base_fee_group(
+                config,
+                clock.epoch,
+                base_fee_group,
+                name,
+                enabled,
+                payout_asset,
+            )
+        }
+        AdminAction::SetMultisigSigner {
+            role,
+            signer,
+            is_signer,
+            threshold,
+        } => {
+            // The action's role was checked against the multisig when it was proposed
+            let mut admin_multisig_data = admin_multisig.try_borrow_mut_data()?;
+            let admin_multisig_account =
+                AdminMultisig::try_from_slice_unchecked_mut(&mut admin_multisig_data)?;
+            admin_multisig_account.update_signer(&signer, is_signer, threshold)?;
+
+            msg!(
+                "{:?} multisig signer {:?} {}, threshold set to {}",
+                role,
+                signer,
+                if is_signer { "added" } else { "removed" },
+                threshold
+            );
+
+            Ok(())
+        }
+        AdminAction::SetPausedSubsystems { paused_subsystems } => {
+            // The multisig holds the pause admin role, so it can only pause more subsystems
+            let mut config_data = config.try_borrow_mut_data()?;
+            let config = NcnConfig::try_from_slice_unchecked_mut(&mut config_data)?;
+            update_paused_subsystems(
+                config,
+                clock.epoch,
+                admin_multisig.key,
+                false,
+                paused_subsystems,
+            )
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_system_account, load_system_program};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer, ballot_box::BallotBox, ballot_box_page::BallotBoxPage,
+    config::Config as NcnConfig, epoch_marker::EpochMarker, epoch_state::EpochState,
+    error::TipRouterError,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Initializes the next ballot box page, for the votes of operators past `MAX_OPERATORS`
+pub fn process_initialize_ballot_box_page(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+    page: u64,
+) -> ProgramResult {
+    let [epoch_marker, epoch_state, ncn_config, ballot_box, ballot_box_page, ncn, account_payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Verify accounts
+    load_system_account(ballot_box_page, true)?;
+    load_system_program(system_program)?;
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochState::load_and_check_is_closing(program_id, epoch_state, ncn.key, epoch, false)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, true)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+    EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
+
+    // Pages are only paid for when the NCN has operators past the ballot box
+    let pages_needed = {
+        let ncn_data = ncn.data.borrow();
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+        BallotBoxPage::pages_needed(ncn_account.operator_count())
+    };
+
+    if page >= pages_needed {
+        msg!(
+            "Ballot box page {} is not needed, the NCN needs {} pages",
+            page,
+            pages_needed
+        );
+        return Err(TipRouterError::InvalidBallotBoxPage.into());
+    }
+
+    let (ballot_box_page_pda, ballot_box_page_bump, mut ballot_box_page_seeds) =
+        BallotBoxPage::find_program_address(program_id, ncn.key, epoch, page);
+    ballot_box_page_seeds.push(vec![ballot_box_page_bump]);
+
+    if ballot_box_page_pda != *ballot_box_page.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    {
+        let mut ballot_box_data = ballot_box.try_borrow_mut_data()?;
+        let ballot_box_account = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
+        ballot_box_account.add_page(page)?;
+    }
+
+    AccountPayer::pay_and_create_account(
+        program_id,
+        ncn.key,
+        account_payer,
+        ballot_box_page,
+        system_program,
+        program_id,
+        BallotBoxPage::SIZE,
+        &ballot_box_page_seeds,
+    )?;
+
+    let mut ballot_box_page_data = ballot_box_page.try_borrow_mut_data()?;
+    ballot_box_page_data[0] = BallotBoxPage::DISCRIMINATOR;
+    let ballot_box_page_account =
+        BallotBoxPage::try_from_slice_unchecked_mut(&mut ballot_box_page_data)?;
+    *ballot_box_page_account = BallotBoxPage::new(ncn.key, epoch, page, ballot_box_page_bump);
+
+    Ok(())
+}
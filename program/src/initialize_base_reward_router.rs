@@ -12,13 +12,16 @@ use solana_program::{
     pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
-/// Can be backfilled for previous epochs
+use crate::token_rewards::load_reward_mint;
+
+/// Can be backfilled for previous epochs. Passing a `mint` initializes a router for SPL token
+/// rewards of that mint instead of lamports, the mint has to be allowed in the `config`.
 pub fn process_initialize_base_reward_router(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_marker, epoch_state, ncn, base_reward_router, base_reward_receiver, account_payer, system_program] =
+    let [epoch_marker, epoch_state, ncn, base_reward_router, base_reward_receiver, account_payer, system_program, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -33,8 +36,16 @@ pub fn process_initialize_base_reward_router(
     load_system_account(base_reward_router, true)?;
     load_system_program(system_program)?;
 
+    let mint = optional_accounts
+        .first()
+        .filter(|account| account.key.ne(program_id));
+    if let Some(mint) = mint {
+        load_reward_mint(program_id, ncn.key, mint, optional_accounts.get(1))?;
+    }
+    let mint_key = mint.map_or(Pubkey::default(), |mint| *mint.key);
+
     let (base_reward_router_pubkey, base_reward_router_bump, mut base_reward_router_seeds) =
-        BaseRewardRouter::find_program_address(program_id, ncn.key, epoch);
+        BaseRewardRouter::find_program_address_for_mint(program_id, ncn.key, &mint_key, epoch);
     base_reward_router_seeds.push(vec![base_reward_router_bump]);
 
     if base_reward_router_pubkey.ne(base_reward_router.key) {
@@ -59,6 +70,11 @@ pub fn process_initialize_base_reward_router(
         &base_reward_router_seeds,
     )?;
 
+    // Token rewards are held by the receiver's ATA, the receiver itself only needs rent once
+    if mint.is_some() {
+        return Ok(());
+    }
+
     let min_rent = Rent::get()?.minimum_balance(0);
     msg!(
         "Transferring rent of {} lamports to base reward receiver {}",
@@ -0,0 +1,102 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_system_account, load_system_program};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer, ballot_box::BallotBox, base_reward_router::BaseRewardRouter,
+    base_reward_router_page::BaseRewardRouterPage, epoch_marker::EpochMarker,
+    epoch_state::EpochState, error::TipRouterError,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Initializes the next base reward router page, routing the rewards of the operators voting in
+/// the ballot box page of the same index. Works for lamport and token routers, the mint is read
+/// from the router.
+pub fn process_initialize_base_reward_router_page(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+    page: u64,
+) -> ProgramResult {
+    let [epoch_marker, epoch_state, ncn, ballot_box, base_reward_router, base_reward_router_page, account_payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Verify accounts
+    load_system_account(base_reward_router_page, true)?;
+    load_system_program(system_program)?;
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochState::load_and_check_is_closing(program_id, epoch_state, ncn.key, epoch, false)?;
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, false)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+    EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
+
+    let mint = {
+        let base_reward_router_data = base_reward_router.try_borrow_data()?;
+        let base_reward_router_account =
+            BaseRewardRouter::try_from_slice_unchecked(&base_reward_router_data)?;
+        *base_reward_router_account.mint()
+    };
+    BaseRewardRouter::load_for_mint(program_id, base_reward_router, ncn.key, &mint, epoch, true)?;
+
+    // There is one router page for every ballot box page
+    let ballot_box_page_count = {
+        let ballot_box_data = ballot_box.try_borrow_data()?;
+        let ballot_box_account = BallotBox::try_from_slice_unchecked(&ballot_box_data)?;
+        ballot_box_account.page_count()
+    };
+
+    if page >= ballot_box_page_count {
+        msg!(
+            "Base reward router page {} is not needed, the ballot box has {} pages",
+            page,
+            ballot_box_page_count
+        );
+        return Err(TipRouterError::InvalidBaseRewardRouterPage.into());
+    }
+
+    let (
+        base_reward_router_page_pda,
+        base_reward_router_page_bump,
+        mut base_reward_router_page_seeds,
+    ) = BaseRewardRouterPage::find_program_address_for_mint(
+        program_id, ncn.key, &mint, epoch, page,
+    );
+    base_reward_router_page_seeds.push(vec![base_reward_router_page_bump]);
+
+    if base_reward_router_page_pda != *base_reward_router_page.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    {
+        let mut base_reward_router_data = base_reward_router.try_borrow_mut_data()?;
+        let base_reward_router_account =
+            BaseRewardRouter::try_from_slice_unchecked_mut(&mut base_reward_router_data)?;
+        base_reward_router_account.add_page(page)?;
+    }
+
+    AccountPayer::pay_and_create_account(
+        program_id,
+        ncn.key,
+        account_payer,
+        base_reward_router_page,
+        system_program,
+        program_id,
+        BaseRewardRouterPage::SIZE,
+        &base_reward_router_page_seeds,
+    )?;
+
+    let mut base_reward_router_page_data = base_reward_router_page.try_borrow_mut_data()?;
+    base_reward_router_page_data[0] = BaseRewardRouterPage::DISCRIMINATOR;
+    let base_reward_router_page_account =
+        BaseRewardRouterPage::try_from_slice_unchecked_mut(&mut base_reward_router_page_data)?;
+    *base_reward_router_page_account =
+        BaseRewardRouterPage::new(ncn.key, &mint, epoch, page, base_reward_router_page_bump);
+
+    Ok(())
+}
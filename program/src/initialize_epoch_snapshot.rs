@@ -2,8 +2,14 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::{load_system_account, load_system_program};
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    account_payer::AccountPayer, config::Config, epoch_marker::EpochMarker,
-    epoch_snapshot::EpochSnapshot, epoch_state::EpochState, error::TipRouterError, fees,
+    account_payer::AccountPayer,
+    account_version::{VersionedAccount, ACCOUNT_VERSION_INDEX},
+    config::Config,
+    epoch_marker::EpochMarker,
+    epoch_snapshot::EpochSnapshot,
+    epoch_state::EpochState,
+    error::TipRouterError,
+    fees,
     weight_table::WeightTable,
 };
 use solana_program::{
@@ -96,6 +102,7 @@ pub fn process_initialize_epoch_snapshot(
     let mut epoch_snapshot_data: std::cell::RefMut<'_, &mut [u8]> =
         epoch_snapshot.try_borrow_mut_data()?;
     epoch_snapshot_data[0] = EpochSnapshot::DISCRIMINATOR;
+    epoch_snapshot_data[ACCOUNT_VERSION_INDEX] = EpochSnapshot::ACCOUNT_VERSION;
     let epoch_snapshot_account =
         EpochSnapshot::try_from_slice_unchecked_mut(&mut epoch_snapshot_data)?;
 
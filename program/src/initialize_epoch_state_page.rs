@@ -0,0 +1,84 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_system_account, load_system_program};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer, config::Config as NcnConfig, epoch_marker::EpochMarker,
+    epoch_state::EpochState, epoch_state_page::EpochStatePage, error::TipRouterError,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Initializes the next epoch state page, for the epoch accounts of operators past `MAX_OPERATORS`
+pub fn process_initialize_epoch_state_page(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+    page: u64,
+) -> ProgramResult {
+    let [epoch_marker, epoch_state, ncn_config, epoch_state_page, ncn, account_payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Verify accounts
+    load_system_account(epoch_state_page, true)?;
+    load_system_program(system_program)?;
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochState::load_and_check_is_closing(program_id, epoch_state, ncn.key, epoch, true)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+    EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
+
+    // Pages are only paid for when the NCN has operators past the epoch state
+    let pages_needed = {
+        let ncn_data = ncn.data.borrow();
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+        EpochStatePage::pages_needed(ncn_account.operator_count())
+    };
+
+    if page >= pages_needed {
+        msg!(
+            "Epoch state page {} is not needed, the NCN needs {} pages",
+            page,
+            pages_needed
+        );
+        return Err(TipRouterError::InvalidEpochStatePage.into());
+    }
+
+    let (epoch_state_page_pda, epoch_state_page_bump, mut epoch_state_page_seeds) =
+        EpochStatePage::find_program_address(program_id, ncn.key, epoch, page);
+    epoch_state_page_seeds.push(vec![epoch_state_page_bump]);
+
+    if epoch_state_page_pda != *epoch_state_page.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.add_page(page)?;
+    }
+
+    AccountPayer::pay_and_create_account(
+        program_id,
+        ncn.key,
+        account_payer,
+        epoch_state_page,
+        system_program,
+        program_id,
+        EpochStatePage::SIZE,
+        &epoch_state_page_seeds,
+    )?;
+
+    let mut epoch_state_page_data = epoch_state_page.try_borrow_mut_data()?;
+    epoch_state_page_data[0] = EpochStatePage::DISCRIMINATOR;
+    let epoch_state_page_account =
+        EpochStatePage::try_from_slice_unchecked_mut(&mut epoch_state_page_data)?;
+    *epoch_state_page_account = EpochStatePage::new(ncn.key, epoch, page, epoch_state_page_bump);
+
+    Ok(())
+}
@@ -3,9 +3,11 @@ use jito_jsm_core::loader::{load_system_account, load_system_program};
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_tip_router_core::{
     account_payer::AccountPayer,
+    account_version::{VersionedAccount, ACCOUNT_VERSION_INDEX},
     epoch_marker::EpochMarker,
     epoch_snapshot::OperatorSnapshot,
     epoch_state::EpochState,
+    epoch_state_page::EpochStatePage,
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
 };
@@ -14,14 +16,17 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
-/// Can be backfilled for previous epochs
+use crate::token_rewards::load_reward_mint;
+
+/// Can be backfilled for previous epochs. Passing a `mint` initializes a router for SPL token
+/// rewards of that mint instead of lamports, the mint has to be allowed in the `config`.
 pub fn process_initialize_ncn_reward_router(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     ncn_fee_group: u8,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_marker, epoch_state, ncn, operator, operator_snapshot, ncn_reward_router, ncn_reward_receiver, account_payer, system_program] =
+    let [epoch_marker, epoch_state, ncn, operator, operator_snapshot, ncn_reward_router, ncn_reward_receiver, account_payer, system_program, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -53,6 +58,14 @@ pub fn process_initialize_ncn_reward_router(
     AccountPayer::load(program_id, account_payer, ncn.key, true)?;
     EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
 
+    let mint = optional_accounts
+        .first()
+        .filter(|account| account.key.ne(program_id));
+    if let Some(mint) = mint {
+        load_reward_mint(program_id, ncn.key, mint, optional_accounts.get(1))?;
+    }
+    let mint_key = mint.map_or(Pubkey::default(), |mint| *mint.key);
+
     let operator_ncn_index = {
         let operator_snapshot_data = operator_snapshot.try_borrow_data()?;
         let operator_snapshot_account =
@@ -65,11 +78,12 @@ pub fn process_initialize_ncn_reward_router(
     let current_slot = Clock::get()?.slot;
 
     let (ncn_reward_router_pubkey, ncn_reward_router_bump, mut ncn_reward_router_seeds) =
-        NcnRewardRouter::find_program_address(
+        NcnRewardRouter::find_program_address_for_mint(
             program_id,
             ncn_fee_group,
             operator.key,
             ncn.key,
+            &mint_key,
             epoch,
         );
     ncn_reward_router_seeds.push(vec![ncn_reward_router_bump]);
@@ -98,6 +112,7 @@ pub fn process_initialize_ncn_reward_router(
 
     let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
     ncn_reward_router_data[0] = NcnRewardRouter::DISCRIMINATOR;
+    ncn_reward_router_data[ACCOUNT_VERSION_INDEX] = NcnRewardRouter::ACCOUNT_VERSION;
     let ncn_reward_router_account =
         NcnRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
 
@@ -106,11 +121,21 @@ pub fn process_initialize_ncn_reward_router(
         operator.key,
         operator_ncn_index,
         ncn.key,
+        &mint_key,
         epoch,
         ncn_reward_router_bump,
         current_slot,
     );
 
+    // Token rewards are held by the receiver's ATA, the receiver itself only needs rent once
+    if mint.is_some() {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_initialize_token_reward_router()?;
+
+        return Ok(());
+    }
+
     let min_rent = Rent::get()?.minimum_balance(0);
     msg!(
         "Transferring rent of {} lamports to ncn reward receiver {}",
@@ -125,11 +150,33 @@ pub fn process_initialize_ncn_reward_router(
         min_rent,
     )?;
 
+    // Operators past the epoch state are tracked in an epoch state page
     {
+        let epoch_state_page = EpochStatePage::load_for_operator(
+            program_id,
+            optional_accounts
+                .get(2)
+                .filter(|account| account.key.ne(program_id)),
+            ncn.key,
+            epoch,
+            operator_ncn_index,
+            true,
+        )?;
+        let mut epoch_state_page_data = epoch_state_page
+            .map(|account| account.try_borrow_mut_data())
+            .transpose()?;
+        let epoch_state_page = epoch_state_page_data
+            .as_deref_mut()
+            .map(|data| EpochStatePage::try_from_slice_unchecked_mut(data))
+            .transpose()?;
+
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
-        epoch_state_account
-            .update_realloc_ncn_reward_router(operator_ncn_index as usize, ncn_fee_group)?;
+        epoch_state_account.update_realloc_ncn_reward_router(
+            operator_ncn_index as usize,
+            ncn_fee_group,
+            epoch_state_page,
+        )?;
     }
 
     Ok(())
@@ -0,0 +1,108 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_system_account, load_system_program};
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_tip_router_core::{
+    account_payer::AccountPayer, epoch_marker::EpochMarker, epoch_snapshot::OperatorSnapshot,
+    epoch_state::EpochState, error::TipRouterError, operator_snapshot_page::OperatorSnapshotPage,
+    vault_registry_page::VaultRegistryPage,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Initializes the next operator snapshot page, for the operator's delegations from vaults past
+/// `MAX_VAULTS`. Pages have to be initialized before those delegations are snapshotted.
+pub fn process_initialize_operator_snapshot_page(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+    page: u64,
+) -> ProgramResult {
+    let [epoch_marker, epoch_state, ncn, operator, operator_snapshot, operator_snapshot_page, account_payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Verify accounts
+    load_system_account(operator_snapshot_page, true)?;
+    load_system_program(system_program)?;
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    Operator::load(&jito_restaking_program::id(), operator, false)?;
+    EpochState::load_and_check_is_closing(program_id, epoch_state, ncn.key, epoch, false)?;
+    OperatorSnapshot::load(
+        program_id,
+        operator_snapshot,
+        operator.key,
+        ncn.key,
+        epoch,
+        true,
+    )?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+    EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
+
+    // Pages are only paid for when the NCN has vaults past `MAX_VAULTS`
+    let pages_needed = {
+        let ncn_data = ncn.data.borrow();
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+        VaultRegistryPage::pages_needed(ncn_account.vault_count())
+    };
+
+    if page >= pages_needed {
+        msg!(
+            "Operator snapshot page {} is not needed, the NCN needs {} pages",
+            page,
+            pages_needed
+        );
+        return Err(TipRouterError::InvalidVaultPage.into());
+    }
+
+    let (operator_snapshot_page_pda, operator_snapshot_page_bump, mut operator_snapshot_page_seeds) =
+        OperatorSnapshotPage::find_program_address(program_id, operator.key, ncn.key, epoch, page);
+    operator_snapshot_page_seeds.push(vec![operator_snapshot_page_bump]);
+
+    if operator_snapshot_page_pda != *operator_snapshot_page.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Pages are initialized in order, while the operator snapshot still takes delegations
+    {
+        let mut operator_snapshot_data = operator_snapshot.try_borrow_mut_data()?;
+        let operator_snapshot_account =
+            OperatorSnapshot::try_from_slice_unchecked_mut(&mut operator_snapshot_data)?;
+
+        if operator_snapshot_account.finalized() {
+            msg!("Operator snapshot is already finalized");
+            return Err(TipRouterError::VaultOperatorDelegationFinalized.into());
+        }
+
+        operator_snapshot_account.add_page(page)?;
+    }
+
+    AccountPayer::pay_and_create_account(
+        program_id,
+        ncn.key,
+        account_payer,
+        operator_snapshot_page,
+        system_program,
+        program_id,
+        OperatorSnapshotPage::SIZE,
+        &operator_snapshot_page_seeds,
+    )?;
+
+    let mut operator_snapshot_page_data = operator_snapshot_page.try_borrow_mut_data()?;
+    operator_snapshot_page_data[0] = OperatorSnapshotPage::DISCRIMINATOR;
+    let operator_snapshot_page_account =
+        OperatorSnapshotPage::try_from_slice_unchecked_mut(&mut operator_snapshot_page_data)?;
+    operator_snapshot_page_account.initialize(
+        operator.key,
+        ncn.key,
+        epoch,
+        page,
+        operator_snapshot_page_bump,
+    );
+
+    Ok(())
+}
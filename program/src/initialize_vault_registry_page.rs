@@ -0,0 +1,83 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_system_account, load_system_program};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer, config::Config as NcnConfig, error::TipRouterError,
+    vault_registry::VaultRegistry, vault_registry_page::VaultRegistryPage,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Initializes the next vault registry page, for the vaults past `MAX_VAULTS`
+pub fn process_initialize_vault_registry_page(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    page: u64,
+) -> ProgramResult {
+    let [ncn_config, vault_registry, vault_registry_page, ncn, account_payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Verify accounts
+    load_system_account(vault_registry_page, true)?;
+    load_system_program(system_program)?;
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
+    VaultRegistry::load(program_id, vault_registry, ncn.key, true)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+
+    // Pages are only paid for when the NCN has vaults past the vault registry
+    let pages_needed = {
+        let ncn_data = ncn.data.borrow();
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+        VaultRegistryPage::pages_needed(ncn_account.vault_count())
+    };
+
+    if page >= pages_needed {
+        msg!(
+            "Vault registry page {} is not needed, the NCN needs {} pages",
+            page,
+            pages_needed
+        );
+        return Err(TipRouterError::InvalidVaultPage.into());
+    }
+
+    let (vault_registry_page_pda, vault_registry_page_bump, mut vault_registry_page_seeds) =
+        VaultRegistryPage::find_program_address(program_id, ncn.key, page);
+    vault_registry_page_seeds.push(vec![vault_registry_page_bump]);
+
+    if vault_registry_page_pda != *vault_registry_page.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    {
+        let mut vault_registry_data = vault_registry.try_borrow_mut_data()?;
+        let vault_registry_account =
+            VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
+        vault_registry_account.add_page(page)?;
+    }
+
+    AccountPayer::pay_and_create_account(
+        program_id,
+        ncn.key,
+        account_payer,
+        vault_registry_page,
+        system_program,
+        program_id,
+        VaultRegistryPage::SIZE,
+        &vault_registry_page_seeds,
+    )?;
+
+    let mut vault_registry_page_data = vault_registry_page.try_borrow_mut_data()?;
+    vault_registry_page_data[0] = VaultRegistryPage::DISCRIMINATOR;
+    let vault_registry_page_account =
+        VaultRegistryPage::try_from_slice_unchecked_mut(&mut vault_registry_page_data)?;
+    vault_registry_page_account.initialize(ncn.key, page, vault_registry_page_bump);
+
+    Ok(())
+}
@@ -41,7 +41,7 @@ pub fn process_initialize_weight_table(
     let vault_registry_count = {
         let vault_registry_data = vault_registry.data.borrow();
         let vault_registry = VaultRegistry::try_from_slice_unchecked(&vault_registry_data)?;
-        vault_registry.vault_count()
+        vault_registry.registered_vault_count()?
     };
 
     if vault_count != vault_registry_count {
@@ -0,0 +1,95 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::loader::{load_system_account, load_system_program};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer, epoch_marker::EpochMarker, epoch_state::EpochState,
+    vault_registry_page::VaultRegistryPage, weight_table::WeightTable,
+    weight_table_page::WeightTablePage,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Initializes the next weight table page, a snapshot of the vault registry page of the same
+/// index. The weight table isn't finalized until all of its pages are initialized.
+pub fn process_initialize_weight_table_page(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+    page: u64,
+) -> ProgramResult {
+    let [epoch_marker, epoch_state, vault_registry_page, ncn, weight_table, weight_table_page, account_payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Verify accounts
+    load_system_account(weight_table_page, true)?;
+    load_system_program(system_program)?;
+
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochState::load_and_check_is_closing(program_id, epoch_state, ncn.key, epoch, true)?;
+    VaultRegistryPage::load(program_id, vault_registry_page, ncn.key, page, false)?;
+    WeightTable::load(program_id, weight_table, ncn.key, epoch, true)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+    EpochMarker::check_dne(program_id, epoch_marker, ncn.key, epoch)?;
+
+    let (weight_table_page_pda, weight_table_page_bump, mut weight_table_page_seeds) =
+        WeightTablePage::find_program_address(program_id, ncn.key, epoch, page);
+    weight_table_page_seeds.push(vec![weight_table_page_bump]);
+
+    if weight_table_page_pda != *weight_table_page.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    AccountPayer::pay_and_create_account(
+        program_id,
+        ncn.key,
+        account_payer,
+        weight_table_page,
+        system_program,
+        program_id,
+        WeightTablePage::SIZE,
+        &weight_table_page_seeds,
+    )?;
+
+    let vault_count = {
+        let vault_registry_page_data = vault_registry_page.data.borrow();
+        let vault_registry_page_account =
+            VaultRegistryPage::try_from_slice_unchecked(&vault_registry_page_data)?;
+
+        let mut weight_table_page_data = weight_table_page.try_borrow_mut_data()?;
+        weight_table_page_data[0] = WeightTablePage::DISCRIMINATOR;
+        let weight_table_page_account =
+            WeightTablePage::try_from_slice_unchecked_mut(&mut weight_table_page_data)?;
+        weight_table_page_account.initialize(
+            ncn.key,
+            epoch,
+            page,
+            weight_table_page_bump,
+            vault_registry_page_account,
+        );
+
+        weight_table_page_account.vault_count()
+    };
+
+    // Pages are initialized in order, up to the vault registry pages the weight table was
+    // created with
+    {
+        let mut weight_table_data = weight_table.try_borrow_mut_data()?;
+        let weight_table_account =
+            WeightTable::try_from_slice_unchecked_mut(&mut weight_table_data)?;
+        weight_table_account.add_page(page, vault_count)?;
+    }
+
+    // Update Epoch State
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_initialize_weight_table_page(vault_count)?;
+    }
+
+    Ok(())
+}
@@ -1,28 +1,57 @@
 #![allow(deprecated)]
 
+mod admin_accept_admin;
+mod admin_cancel_admin_proposal;
+mod admin_cancel_scheduled_fees;
+mod admin_deactivate_st_mint;
+mod admin_deactivate_vault;
 mod admin_initialize_config;
+mod admin_propose_new_admin;
 mod admin_register_st_mint;
+mod admin_set_admin_multisig;
+mod admin_set_base_fee_group;
 mod admin_set_config_fees;
 mod admin_set_new_admin;
 mod admin_set_parameters;
+mod admin_set_paused_subsystems;
+mod admin_set_reward_mint;
 mod admin_set_st_mint;
 mod admin_set_tie_breaker;
 mod admin_set_weight;
+mod apply_stake_weight_caps;
+mod approve_admin_action;
+mod carry_forward_rewards;
 mod cast_vote;
 mod claim_with_payer;
+mod close_admin_proposal;
 mod close_epoch_account;
+mod close_operator_vote_key;
+mod commit_vote;
+mod crank_bounty;
 mod distribute_base_ncn_reward_route;
 mod distribute_base_rewards;
 mod distribute_ncn_operator_rewards;
 mod distribute_ncn_vault_rewards;
+mod execute_admin_action;
 mod initialize_ballot_box;
+mod initialize_ballot_box_page;
 mod initialize_base_reward_router;
+mod initialize_base_reward_router_page;
 mod initialize_epoch_snapshot;
 mod initialize_epoch_state;
+mod initialize_epoch_state_page;
 mod initialize_ncn_reward_router;
 mod initialize_operator_snapshot;
+mod initialize_operator_snapshot_page;
 mod initialize_vault_registry;
+mod initialize_vault_registry_page;
 mod initialize_weight_table;
+mod initialize_weight_table_page;
+mod migrate_account;
+mod oracle;
+mod oracle_set_weight;
+mod payout;
+mod propose_admin_action;
 mod realloc_ballot_box;
 mod realloc_base_reward_router;
 mod realloc_epoch_state;
@@ -30,11 +59,17 @@ mod realloc_operator_snapshot;
 mod realloc_vault_registry;
 mod realloc_weight_table;
 mod register_vault;
+mod resolve_stalled_vote;
+mod reveal_vote;
 mod route_base_rewards;
 mod route_ncn_rewards;
 mod set_merkle_root;
+mod set_operator_vote_key;
+mod set_payout_preference;
 mod snapshot_vault_operator_delegation;
-mod switchboard_set_weight;
+mod token_rewards;
+mod vote;
+mod withdraw_vote;
 
 use admin_set_new_admin::process_admin_set_new_admin;
 use borsh::BorshDeserialize;
@@ -57,34 +92,60 @@ use solana_security_txt::security_txt;
 use solana_system_interface::program as system_program;
 
 use crate::{
+    admin_accept_admin::process_admin_accept_admin,
+    admin_cancel_admin_proposal::process_admin_cancel_admin_proposal,
+    admin_cancel_scheduled_fees::process_admin_cancel_scheduled_fees,
+    admin_deactivate_st_mint::process_admin_deactivate_st_mint,
+    admin_deactivate_vault::process_admin_deactivate_vault,
     admin_initialize_config::process_admin_initialize_config,
+    admin_propose_new_admin::process_admin_propose_new_admin,
     admin_register_st_mint::process_admin_register_st_mint,
+    admin_set_admin_multisig::process_admin_set_admin_multisig,
+    admin_set_base_fee_group::process_admin_set_base_fee_group,
     admin_set_config_fees::process_admin_set_config_fees,
     admin_set_parameters::process_admin_set_parameters,
+    admin_set_paused_subsystems::process_admin_set_paused_subsystems,
+    admin_set_reward_mint::process_admin_set_reward_mint,
     admin_set_st_mint::process_admin_set_st_mint,
     admin_set_tie_breaker::process_admin_set_tie_breaker,
-    admin_set_weight::process_admin_set_weight, cast_vote::process_cast_vote,
-    claim_with_payer::process_claim_with_payer, close_epoch_account::process_close_epoch_account,
+    admin_set_weight::process_admin_set_weight,
+    apply_stake_weight_caps::process_apply_stake_weight_caps,
+    approve_admin_action::process_approve_admin_action,
+    carry_forward_rewards::process_carry_forward_rewards, cast_vote::process_cast_vote,
+    claim_with_payer::process_claim_with_payer, close_admin_proposal::process_close_admin_proposal,
+    close_epoch_account::process_close_epoch_account,
+    close_operator_vote_key::process_close_operator_vote_key, commit_vote::process_commit_vote,
     distribute_base_ncn_reward_route::process_distribute_base_ncn_reward_route,
     distribute_base_rewards::process_distribute_base_rewards,
     distribute_ncn_operator_rewards::process_distribute_ncn_operator_rewards,
     distribute_ncn_vault_rewards::process_distribute_ncn_vault_rewards,
+    execute_admin_action::process_execute_admin_action,
     initialize_ballot_box::process_initialize_ballot_box,
+    initialize_ballot_box_page::process_initialize_ballot_box_page,
     initialize_base_reward_router::process_initialize_base_reward_router,
+    initialize_base_reward_router_page::process_initialize_base_reward_router_page,
     initialize_epoch_snapshot::process_initialize_epoch_snapshot,
+    initialize_epoch_state_page::process_initialize_epoch_state_page,
     initialize_ncn_reward_router::process_initialize_ncn_reward_router,
     initialize_operator_snapshot::process_initialize_operator_snapshot,
+    initialize_operator_snapshot_page::process_initialize_operator_snapshot_page,
     initialize_vault_registry::process_initialize_vault_registry,
+    initialize_vault_registry_page::process_initialize_vault_registry_page,
     initialize_weight_table::process_initialize_weight_table,
+    initialize_weight_table_page::process_initialize_weight_table_page,
+    migrate_account::process_migrate_account, oracle_set_weight::process_oracle_set_weight,
+    propose_admin_action::process_propose_admin_action,
     realloc_ballot_box::process_realloc_ballot_box,
     realloc_base_reward_router::process_realloc_base_reward_router,
     realloc_operator_snapshot::process_realloc_operator_snapshot,
     realloc_vault_registry::process_realloc_vault_registry,
     realloc_weight_table::process_realloc_weight_table, register_vault::process_register_vault,
+    resolve_stalled_vote::process_resolve_stalled_vote, reveal_vote::process_reveal_vote,
     route_base_rewards::process_route_base_rewards, route_ncn_rewards::process_route_ncn_rewards,
-    set_merkle_root::process_set_merkle_root,
+    set_merkle_root::process_set_merkle_root, set_operator_vote_key::process_set_operator_vote_key,
+    set_payout_preference::process_set_payout_preference,
     snapshot_vault_operator_delegation::process_snapshot_vault_operator_delegation,
-    switchboard_set_weight::process_switchboard_set_weight,
+    withdraw_vote::process_withdraw_vote,
 };
 
 declare_id!(env!("TIP_ROUTER_PROGRAM_ID"));
@@ -151,6 +212,10 @@ pub fn process_instruction(
             msg!("Instruction: RegisterVault");
             process_register_vault(program_id, accounts)
         }
+        TipRouterInstruction::InitializeVaultRegistryPage { page } => {
+            msg!("Instruction: InitializeVaultRegistryPage");
+            process_initialize_vault_registry_page(program_id, accounts, page)
+        }
 
         // ---------------------------------------------------- //
         //                       SNAPSHOT                       //
@@ -171,9 +236,17 @@ pub fn process_instruction(
             msg!("Instruction: ReallocWeightTable");
             process_realloc_weight_table(program_id, accounts, epoch)
         }
+        TipRouterInstruction::InitializeWeightTablePage { epoch, page } => {
+            msg!("Instruction: InitializeWeightTablePage");
+            process_initialize_weight_table_page(program_id, accounts, epoch, page)
+        }
         TipRouterInstruction::SwitchboardSetWeight { epoch, st_mint } => {
             msg!("Instruction: SwitchboardSetWeight");
-            process_switchboard_set_weight(program_id, accounts, &st_mint, epoch)
+            process_oracle_set_weight(program_id, accounts, &st_mint, epoch)
+        }
+        TipRouterInstruction::OracleSetWeight { epoch, st_mint } => {
+            msg!("Instruction: OracleSetWeight");
+            process_oracle_set_weight(program_id, accounts, &st_mint, epoch)
         }
         TipRouterInstruction::InitializeEpochSnapshot { epoch } => {
             msg!("Instruction: InitializeEpochSnapshot");
@@ -187,10 +260,18 @@ pub fn process_instruction(
             msg!("Instruction: ReallocOperatorSnapshot");
             process_realloc_operator_snapshot(program_id, accounts, epoch)
         }
+        TipRouterInstruction::InitializeOperatorSnapshotPage { epoch, page } => {
+            msg!("Instruction: InitializeOperatorSnapshotPage");
+            process_initialize_operator_snapshot_page(program_id, accounts, epoch, page)
+        }
         TipRouterInstruction::SnapshotVaultOperatorDelegation { epoch } => {
             msg!("Instruction: SnapshotVaultOperatorDelegation");
             process_snapshot_vault_operator_delegation(program_id, accounts, epoch)
         }
+        TipRouterInstruction::ApplyStakeWeightCaps { epoch } => {
+            msg!("Instruction: ApplyStakeWeightCaps");
+            process_apply_stake_weight_caps(program_id, accounts, epoch)
+        }
 
         // ---------------------------------------------------- //
         //                         VOTE                         //
@@ -210,6 +291,29 @@ pub fn process_instruction(
             msg!("Instruction: CastVote");
             process_cast_vote(program_id, accounts, &meta_merkle_root, epoch)
         }
+        TipRouterInstruction::CommitVote {
+            vote_commitment,
+            epoch,
+        } => {
+            msg!("Instruction: CommitVote");
+            process_commit_vote(program_id, accounts, &vote_commitment, epoch)
+        }
+        TipRouterInstruction::RevealVote {
+            meta_merkle_root,
+            salt,
+            epoch,
+        } => {
+            msg!("Instruction: RevealVote");
+            process_reveal_vote(program_id, accounts, &meta_merkle_root, &salt, epoch)
+        }
+        TipRouterInstruction::WithdrawVote { epoch } => {
+            msg!("Instruction: WithdrawVote");
+            process_withdraw_vote(program_id, accounts, epoch)
+        }
+        TipRouterInstruction::ResolveStalledVote { epoch } => {
+            msg!("Instruction: ResolveStalledVote");
+            process_resolve_stalled_vote(program_id, accounts, epoch)
+        }
         TipRouterInstruction::SetMerkleRoot {
             proof,
             merkle_root,
@@ -311,6 +415,19 @@ pub fn process_instruction(
             epochs_before_stall,
             epochs_after_consensus_before_close,
             valid_slots_after_consensus,
+            commit_window_slots,
+            reveal_window_slots,
+            consensus_threshold_bps,
+            quorum_operator_count,
+            quorum_operator_bps,
+            stall_fallback_threshold_bps,
+            admin_handover_delay_epochs,
+            weight_sampling_window_slots,
+            max_operator_stake_weight_bps,
+            max_vault_stake_weight_bps,
+            fee_increase_notice_epochs,
+            crank_bounty_lamports,
+            max_crank_bounty_lamports_per_epoch,
         } => {
             msg!("Instruction: AdminSetParameters");
             process_admin_set_parameters(
@@ -320,6 +437,19 @@ pub fn process_instruction(
                 epochs_before_stall,
                 epochs_after_consensus_before_close,
                 valid_slots_after_consensus,
+                commit_window_slots,
+                reveal_window_slots,
+                consensus_threshold_bps,
+                quorum_operator_count,
+                quorum_operator_bps,
+                stall_fallback_threshold_bps,
+                admin_handover_delay_epochs,
+                weight_sampling_window_slots,
+                max_operator_stake_weight_bps,
+                max_vault_stake_weight_bps,
+                fee_increase_notice_epochs,
+                crank_bounty_lamports,
+                max_crank_bounty_lamports_per_epoch,
             )
         }
         TipRouterInstruction::AdminSetConfigFees {
@@ -330,6 +460,7 @@ pub fn process_instruction(
             ncn_fee_group,
             new_ncn_fee_bps,
             new_priority_fee_distribution_fee_bps,
+            activation_epoch,
         } => {
             msg!("Instruction: AdminSetConfigFees");
             process_admin_set_config_fees(
@@ -342,6 +473,7 @@ pub fn process_instruction(
                 ncn_fee_group,
                 new_ncn_fee_bps,
                 new_priority_fee_distribution_fee_bps,
+                activation_epoch,
             )
         }
         TipRouterInstruction::AdminSetNewAdmin { role } => {
@@ -355,6 +487,54 @@ pub fn process_instruction(
             msg!("Instruction: AdminSetTieBreaker");
             process_admin_set_tie_breaker(program_id, accounts, &meta_merkle_root, epoch)
         }
+        TipRouterInstruction::AdminSetAdminMultisig {
+            role,
+            threshold,
+            signers,
+        } => {
+            msg!("Instruction: AdminSetAdminMultisig");
+            process_admin_set_admin_multisig(program_id, accounts, role, threshold, &signers)
+        }
+        TipRouterInstruction::ProposeAdminAction { role, action } => {
+            msg!("Instruction: ProposeAdminAction");
+            process_propose_admin_action(program_id, accounts, role, &action)
+        }
+        TipRouterInstruction::ApproveAdminAction { role, nonce } => {
+            msg!("Instruction: ApproveAdminAction");
+            process_approve_admin_action(program_id, accounts, role, nonce)
+        }
+        TipRouterInstruction::ExecuteAdminAction { role, nonce } => {
+            msg!("Instruction: ExecuteAdminAction");
+            process_execute_admin_action(program_id, accounts, role, nonce)
+        }
+        TipRouterInstruction::AdminProposeNewAdmin { role } => {
+            msg!("Instruction: AdminProposeNewAdmin");
+            process_admin_propose_new_admin(program_id, accounts, role)
+        }
+        TipRouterInstruction::AdminAcceptAdmin { role } => {
+            msg!("Instruction: AdminAcceptAdmin");
+            process_admin_accept_admin(program_id, accounts, role)
+        }
+        TipRouterInstruction::AdminCancelAdminProposal { role } => {
+            msg!("Instruction: AdminCancelAdminProposal");
+            process_admin_cancel_admin_proposal(program_id, accounts, role)
+        }
+        TipRouterInstruction::SetOperatorVoteKey { vote_key } => {
+            msg!("Instruction: SetOperatorVoteKey");
+            process_set_operator_vote_key(program_id, accounts, &vote_key)
+        }
+        TipRouterInstruction::CloseOperatorVoteKey => {
+            msg!("Instruction: CloseOperatorVoteKey");
+            process_close_operator_vote_key(program_id, accounts)
+        }
+        TipRouterInstruction::InitializeEpochStatePage { epoch, page } => {
+            msg!("Instruction: InitializeEpochStatePage");
+            process_initialize_epoch_state_page(program_id, accounts, epoch, page)
+        }
+        TipRouterInstruction::InitializeBaseRewardRouterPage { epoch, page } => {
+            msg!("Instruction: InitializeBaseRewardRouterPage");
+            process_initialize_base_reward_router_page(program_id, accounts, epoch, page)
+        }
         TipRouterInstruction::AdminSetWeight {
             st_mint,
             weight,
@@ -368,6 +548,10 @@ pub fn process_instruction(
             reward_multiplier_bps,
             switchboard_feed,
             no_feed_weight,
+            oracle_type,
+            max_staleness,
+            max_confidence_bps,
+            max_weight_change_bps,
         } => {
             msg!("Instruction: AdminRegisterStMint");
             process_admin_register_st_mint(
@@ -377,6 +561,10 @@ pub fn process_instruction(
                 reward_multiplier_bps,
                 switchboard_feed,
                 no_feed_weight,
+                oracle_type,
+                max_staleness,
+                max_confidence_bps,
+                max_weight_change_bps,
             )
         }
         TipRouterInstruction::AdminSetStMint {
@@ -385,6 +573,10 @@ pub fn process_instruction(
             reward_multiplier_bps,
             switchboard_feed,
             no_feed_weight,
+            oracle_type,
+            max_staleness,
+            max_confidence_bps,
+            max_weight_change_bps,
         } => {
             msg!("Instruction: AdminSetStMint");
             process_admin_set_st_mint(
@@ -395,8 +587,71 @@ pub fn process_instruction(
                 reward_multiplier_bps,
                 switchboard_feed,
                 no_feed_weight,
+                oracle_type,
+                max_staleness,
+                max_confidence_bps,
+                max_weight_change_bps,
+            )
+        }
+        TipRouterInstruction::AdminDeactivateVault { vault, epoch } => {
+            msg!("Instruction: AdminDeactivateVault");
+            process_admin_deactivate_vault(program_id, accounts, &vault, epoch)
+        }
+        TipRouterInstruction::AdminDeactivateStMint { st_mint, epoch } => {
+            msg!("Instruction: AdminDeactivateStMint");
+            process_admin_deactivate_st_mint(program_id, accounts, &st_mint, epoch)
+        }
+        TipRouterInstruction::InitializeBallotBoxPage { epoch, page } => {
+            msg!("Instruction: InitializeBallotBoxPage");
+            process_initialize_ballot_box_page(program_id, accounts, epoch, page)
+        }
+        TipRouterInstruction::SetPayoutPreference {
+            payout_asset,
+            sol_destination,
+        } => {
+            msg!("Instruction: SetPayoutPreference");
+            process_set_payout_preference(program_id, accounts, payout_asset, &sol_destination)
+        }
+        TipRouterInstruction::CarryForwardRewards { epoch } => {
+            msg!("Instruction: CarryForwardRewards");
+            process_carry_forward_rewards(program_id, accounts, epoch)
+        }
+        TipRouterInstruction::AdminCancelScheduledFees { activation_epoch } => {
+            msg!("Instruction: AdminCancelScheduledFees");
+            process_admin_cancel_scheduled_fees(program_id, accounts, activation_epoch)
+        }
+        TipRouterInstruction::AdminSetBaseFeeGroup {
+            base_fee_group,
+            name,
+            enabled,
+            payout_asset,
+        } => {
+            msg!("Instruction: AdminSetBaseFeeGroup");
+            process_admin_set_base_fee_group(
+                program_id,
+                accounts,
+                base_fee_group,
+                name,
+                enabled,
+                payout_asset,
             )
         }
+        TipRouterInstruction::AdminSetPausedSubsystems { paused_subsystems } => {
+            msg!("Instruction: AdminSetPausedSubsystems");
+            process_admin_set_paused_subsystems(program_id, accounts, paused_subsystems)
+        }
+        TipRouterInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
+        TipRouterInstruction::CloseAdminProposal { role, nonce } => {
+            msg!("Instruction: CloseAdminProposal");
+            process_close_admin_proposal(program_id, accounts, role, nonce)
+        }
+        TipRouterInstruction::AdminSetRewardMint { allowed } => {
+            msg!("Instruction: AdminSetRewardMint");
+            process_admin_set_reward_mint(program_id, accounts, allowed)
+        }
     }
 }
 
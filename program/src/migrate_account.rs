@@ -0,0 +1,141 @@
+use jito_bytemuck::Discriminator;
+use jito_jsm_core::loader::load_system_program;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    account_payer::AccountPayer,
+    account_version::{account_version, VersionedAccount, ACCOUNT_VERSION_INDEX},
+    ballot_box::BallotBox,
+    base_reward_router::BaseRewardRouter,
+    config::Config as NcnConfig,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    error::TipRouterError,
+    events::TipRouterEvent,
+    ncn_reward_router::NcnRewardRouter,
+    vault_registry::VaultRegistry,
+    weight_table::WeightTable,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Permissionless - upgrades a versioned account to its next layout version
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [ncn, account_to_migrate, account_payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_system_program(system_program)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    AccountPayer::load(program_id, account_payer, ncn.key, true)?;
+
+    if account_to_migrate.owner.ne(program_id) {
+        msg!("Account has an invalid owner");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    if !account_to_migrate.is_writable {
+        msg!("Account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let discriminator = account_to_migrate
+        .try_borrow_data()?
+        .first()
+        .copied()
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    match discriminator {
+        NcnConfig::DISCRIMINATOR => {
+            migrate_account::<NcnConfig>(program_id, ncn, account_to_migrate, account_payer)
+        }
+        VaultRegistry::DISCRIMINATOR => {
+            migrate_account::<VaultRegistry>(program_id, ncn, account_to_migrate, account_payer)
+        }
+        WeightTable::DISCRIMINATOR => {
+            migrate_account::<WeightTable>(program_id, ncn, account_to_migrate, account_payer)
+        }
+        EpochSnapshot::DISCRIMINATOR => {
+            migrate_account::<EpochSnapshot>(program_id, ncn, account_to_migrate, account_payer)
+        }
+        OperatorSnapshot::DISCRIMINATOR => {
+            migrate_account::<OperatorSnapshot>(program_id, ncn, account_to_migrate, account_payer)
+        }
+        BallotBox::DISCRIMINATOR => {
+            migrate_account::<BallotBox>(program_id, ncn, account_to_migrate, account_payer)
+        }
+        BaseRewardRouter::DISCRIMINATOR => {
+            migrate_account::<BaseRewardRouter>(program_id, ncn, account_to_migrate, account_payer)
+        }
+        NcnRewardRouter::DISCRIMINATOR => {
+            migrate_account::<NcnRewardRouter>(program_id, ncn, account_to_migrate, account_payer)
+        }
+        _ => {
+            msg!("Account has no versioned layout");
+            Err(TipRouterError::AccountNotVersioned.into())
+        }
+    }
+}
+
+fn migrate_account<'a, 'info, T: VersionedAccount>(
+    program_id: &Pubkey,
+    ncn: &'a AccountInfo<'info>,
+    account: &'a AccountInfo<'info>,
+    account_payer: &'a AccountInfo<'info>,
+) -> ProgramResult {
+    let (version, account_ncn) = {
+        let account_data = account.try_borrow_data()?;
+        (
+            account_version(&account_data),
+            T::ncn_from_data(&account_data)?,
+        )
+    };
+
+    if version >= T::ACCOUNT_VERSION {
+        msg!("Account is already on layout version {}", version);
+        return Err(TipRouterError::InvalidAccountVersion.into());
+    }
+
+    if account_ncn.ne(ncn.key) {
+        msg!("Account belongs to another NCN");
+        return Err(TipRouterError::IncorrectNcn.into());
+    }
+
+    if account.data_len() < T::version_size(version)? {
+        msg!("Account is smaller than its layout version {}", version);
+        return Err(TipRouterError::InvalidAccountVersion.into());
+    }
+
+    let new_version = version + 1;
+    let new_size = T::version_size(new_version)?;
+    if account.data_len() < new_size {
+        msg!(
+            "Reallocating account from {} bytes to {} bytes",
+            account.data_len(),
+            new_size
+        );
+        AccountPayer::pay_and_realloc(program_id, ncn.key, account_payer, account, new_size)?;
+    }
+
+    {
+        let mut account_data = account.try_borrow_mut_data()?;
+        T::migrate_from(version, &mut account_data)?;
+        account_data[ACCOUNT_VERSION_INDEX] = new_version;
+    }
+
+    msg!(
+        "Migrated account from layout version {} to {}",
+        version,
+        new_version
+    );
+
+    TipRouterEvent::AccountMigrated {
+        ncn: *ncn.key,
+        account: *account.key,
+        discriminator: T::DISCRIMINATOR,
+        account_version: new_version,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use jito_tip_router_core::{
+    constants::{
+        MAX_FEE_BPS, PYTH_MAX_CONFIDENCE_BPS, PYTH_MAX_STALE_SECONDS,
+        SPL_STAKE_POOL_MAX_STALE_EPOCHS, SWITCHBOARD_MAX_STALE_SLOTS, SWITCHBOARD_MAX_STD_DEV_BPS,
+        WEIGHT_PRECISION,
+    },
+    error::TipRouterError,
+    oracle::{OracleType, PythPriceUpdate, PYTH_RECEIVER_PROGRAM_ID},
+    spl_stake_pool::StakePoolHeader,
+    vault_registry::StMintEntry,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, msg, program_error::ProgramError, pubkey::Pubkey,
+};
+use switchboard_on_demand::{
+    prelude::rust_decimal::{prelude::ToPrimitive, Decimal},
+    PullFeedAccountData,
+};
+
+/// Reads the weight of an ST mint from its registered price feed, or falls back to the
+/// no feed weight when no feed is registered. Oracle weights must pass the mint's guards.
+pub fn get_oracle_weight(
+    st_mint_entry: &StMintEntry,
+    oracle_feed: &AccountInfo,
+    clock: &Clock,
+    epoch: u64,
+) -> Result<u128, ProgramError> {
+    let registered_feed = st_mint_entry.switchboard_feed();
+
+    if registered_feed.eq(&Pubkey::default()) {
+        let no_feed_weight = st_mint_entry.no_feed_weight();
+        if no_feed_weight == 0 {
+            msg!("No feed weight is not set");
+            return Err(TipRouterError::NoFeedWeightNotSet.into());
+        }
+
+        msg!("No Feed Weight: {}", no_feed_weight);
+        return Ok(no_feed_weight);
+    }
+
+    if registered_feed.ne(oracle_feed.key) {
+        msg!("Oracle feed is not registered");
+        return Err(TipRouterError::SwitchboardNotRegistered.into());
+    }
+
+    let weight = match st_mint_entry.oracle_type()? {
+        OracleType::Switchboard => get_switchboard_weight(
+            oracle_feed,
+            clock,
+            st_mint_entry.max_staleness_or(SWITCHBOARD_MAX_STALE_SLOTS),
+            st_mint_entry.max_confidence_bps_or(SWITCHBOARD_MAX_STD_DEV_BPS),
+        )?,
+        OracleType::Pyth => get_pyth_weight(
+            oracle_feed,
+            clock,
+            st_mint_entry.max_staleness_or(PYTH_MAX_STALE_SECONDS),
+            st_mint_entry.max_confidence_bps_or(PYTH_MAX_CONFIDENCE_BPS),
+        )?,
+        OracleType::SplStakePool => get_stake_pool_weight(
+            oracle_feed,
+            st_mint_entry.st_mint(),
+            clock,
+            st_mint_entry.max_staleness_or(SPL_STAKE_POOL_MAX_STALE_EPOCHS),
+        )?,
+    };
+
+    msg!("Oracle Weight: {}", weight);
+
+    if let Err(e) = st_mint_entry.check_weight_change(weight, epoch) {
+        msg!(
+            "Weight changed more than {} bps from {} in epoch {}",
+            st_mint_entry.max_weight_change_bps(),
+            st_mint_entry.last_weight(),
+            st_mint_entry.last_weight_epoch()
+        );
+        return Err(e.into());
+    }
+
+    Ok(weight)
+}
+
+/// Switchboard on-demand pull feed, checked for staleness in slots and for its standard deviation
+fn get_switchboard_weight(
+    switchboard_feed: &AccountInfo,
+    clock: &Clock,
+    max_stale_slots: u64,
+    max_std_dev_bps: u64,
+) -> Result<u128, ProgramError> {
+    let feed = PullFeedAccountData::parse(switchboard_feed.data.borrow())
+        .map_err(|_| TipRouterError::BadSwitchboardFeed)?;
+
+    let current_slot = clock.slot;
+    let price: Decimal = feed
+        .value(current_slot)
+        .map_err(|_| TipRouterError::BadSwitchboardValue)?;
+
+    let stale_slot = {
+        feed.result
+            .slot
+            .checked_add(max_stale_slots)
+            .ok_or(TipRouterError::ArithmeticOverflow)?
+    };
+
+    if current_slot > stale_slot {
+        msg!("Stale feed");
+        return Err(TipRouterError::StaleSwitchboardFeed.into());
+    }
+
+    let std_dev = feed.std_dev().ok_or(TipRouterError::BadSwitchboardValue)?;
+    let max_std_dev = price
+        .abs()
+        .checked_mul(max_std_dev_bps.into())
+        .and_then(|value| value.checked_div(MAX_FEE_BPS.into()))
+        .ok_or(TipRouterError::ArithmeticOverflow)?;
+
+    if std_dev > max_std_dev {
+        msg!("Std dev {} is too wide for price {}", std_dev, price);
+        return Err(TipRouterError::SwitchboardConfidenceTooWide.into());
+    }
+
+    msg!("Oracle Price: {}", price);
+    let weight = price
+        .checked_mul(WEIGHT_PRECISION.into())
+        .ok_or(TipRouterError::ArithmeticOverflow)?
+        .round();
+
+    Ok(weight.to_u128().ok_or(TipRouterError::CastToU128Error)?)
+}
+
+/// Pyth receiver price update, checked for staleness in seconds and for its confidence interval
+fn get_pyth_weight(
+    pyth_price_update: &AccountInfo,
+    clock: &Clock,
+    max_stale_seconds: u64,
+    max_confidence_bps: u64,
+) -> Result<u128, ProgramError> {
+    if pyth_price_update.owner.ne(&PYTH_RECEIVER_PROGRAM_ID) {
+        msg!("Pyth price update is not owned by the pyth receiver");
+        return Err(TipRouterError::BadPythPriceUpdate.into());
+    }
+
+    let price_update = PythPriceUpdate::parse(&pyth_price_update.data.borrow())?;
+
+    msg!(
+        "Oracle Price: {} x 10^{}",
+        price_update.price_message.price,
+        price_update.price_message.exponent
+    );
+
+    Ok(price_update.weight(clock.unix_timestamp, max_stale_seconds, max_confidence_bps)?)
+}
+
+/// SPL stake pool of the ST mint, weighted by its lamports per pool token. The pool must have
+/// been updated within the last `max_stale_epochs` epochs.
+fn get_stake_pool_weight(
+    stake_pool: &AccountInfo,
+    st_mint: &Pubkey,
+    clock: &Clock,
+    max_stale_epochs: u64,
+) -> Result<u128, ProgramError> {
+    if stake_pool.owner.ne(&crate::spl_stake_pool_id()) {
+        msg!("Stake pool is not owned by the spl stake pool program");
+        return Err(TipRouterError::BadStakePool.into());
+    }
+
+    let header = StakePoolHeader::parse(&stake_pool.data.borrow())?;
+
+    msg!(
+        "Stake Pool Rate: {} / {} ( updated epoch {} )",
+        header.total_lamports,
+        header.pool_token_supply,
+        header.last_update_epoch
+    );
+
+    Ok(header.weight(st_mint, clock.epoch, max_stale_epochs)?)
+}
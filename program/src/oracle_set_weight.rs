@@ -0,0 +1,97 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    epoch_state::EpochState, error::TipRouterError, vault_registry::VaultRegistry,
+    weight_table::WeightTable,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::oracle::get_oracle_weight;
+
+/// Updates weight table from the price feed of the ST mint. During the weight sampling window the
+/// price is only sampled, the first call after the window sets the time weighted average. The set
+/// weight is recorded in the vault registry for the weight change guard of the next epochs, so the
+/// registry is required for ST mints with a guard.
+pub fn process_oracle_set_weight(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    st_mint: &Pubkey,
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn, weight_table, oracle_feed, rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    WeightTable::load(program_id, weight_table, ncn.key, epoch, true)?;
+
+    let vault_registry = rest.first().filter(|a| a.key.ne(program_id));
+    if let Some(vault_registry) = vault_registry {
+        VaultRegistry::load(program_id, vault_registry, ncn.key, true)?;
+    }
+
+    let clock = Clock::get()?;
+
+    let st_mint_entry = {
+        let weight_table_data = weight_table.data.borrow();
+        let weight_table_account = WeightTable::try_from_slice_unchecked(&weight_table_data)?;
+
+        *weight_table_account
+            .get_weight_entry(st_mint)?
+            .st_mint_entry()
+    };
+
+    if st_mint_entry.max_weight_change_bps() > 0 && vault_registry.is_none() {
+        msg!("ST mint {} has a weight change guard", st_mint);
+        return Err(TipRouterError::VaultRegistryRequiredForWeightChangeGuard.into());
+    }
+
+    let weight = get_oracle_weight(&st_mint_entry, oracle_feed, &clock, epoch)?;
+
+    let mut weight_table_data = weight_table.try_borrow_mut_data()?;
+    let weight_table_account = WeightTable::try_from_slice_unchecked_mut(&mut weight_table_data)?;
+
+    weight_table_account.check_table_initialized()?;
+
+    if weight_table_account.finalized() {
+        msg!("Weight table is finalized");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    weight_table_account.record_oracle_weight(st_mint, weight, clock.slot)?;
+
+    let weight_entry = weight_table_account.get_weight_entry(st_mint)?;
+    if !weight_entry.is_set() {
+        msg!(
+            "Weight sample {} of {}, sampling window ends at slot {}",
+            weight_entry.sample_count(),
+            st_mint,
+            weight_table_account.sampling_end_slot()
+        );
+        return Ok(());
+    }
+    let weight = weight_entry.weight();
+
+    // Update Epoch State
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_set_weight(
+            weight_table_account.weight_count() as u64,
+            weight_table_account.st_mint_count() as u64,
+        );
+    }
+
+    if let Some(vault_registry) = vault_registry {
+        let mut vault_registry_data = vault_registry.try_borrow_mut_data()?;
+        let vault_registry_account =
+            VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
+        vault_registry_account.set_st_mint_last_weight(st_mint, weight, epoch)?;
+    }
+
+    Ok(())
+}
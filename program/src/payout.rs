@@ -0,0 +1,99 @@
+use jito_jsm_core::loader::load_associated_token_account;
+use jito_tip_router_core::{
+    constants::JITOSOL_MINT,
+    error::TipRouterError,
+    ncn_fee_group::NcnFeeGroup,
+    ncn_reward_router::NcnRewardReceiver,
+    payout_preference::{PayoutAsset, PayoutPreference},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+
+/// Checks the account rewards are sent to against the recipient's payout preference:
+/// its JitoSOL or wSOL ATA, or the SOL destination
+pub fn load_payout_destination(
+    payout_preference: &PayoutPreference,
+    destination: &AccountInfo,
+    token_program: &AccountInfo,
+) -> Result<PayoutAsset, ProgramError> {
+    let payout_asset = payout_preference.payout_asset()?;
+
+    match payout_asset {
+        PayoutAsset::JitoSol => {
+            load_associated_token_account(
+                destination,
+                payout_preference.recipient(),
+                &JITOSOL_MINT,
+            )?;
+        }
+        PayoutAsset::WrappedSol => {
+            if token_program.key.ne(&spl_token_interface::id()) {
+                msg!("Incorrect token program for wSOL");
+                return Err(ProgramError::IncorrectProgramId);
+            }
+
+            load_associated_token_account(
+                destination,
+                payout_preference.recipient(),
+                &spl_token_interface::native_mint::id(),
+            )?;
+        }
+        PayoutAsset::Sol => {
+            if destination.key.ne(payout_preference.sol_destination()) {
+                msg!(
+                    "Incorrect SOL destination, expected {}",
+                    payout_preference.sol_destination()
+                );
+                return Err(TipRouterError::InvalidPayoutDestination.into());
+            }
+        }
+    }
+
+    Ok(payout_asset)
+}
+
+/// Pays out rewards held by an NCN reward receiver in native SOL or wSOL. wSOL is paid by
+/// sending the lamports to the recipient's wSOL ATA and syncing its balance.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_out_sol<'a, 'info>(
+    program_id: &Pubkey,
+    payout_asset: PayoutAsset,
+    ncn_fee_group: NcnFeeGroup,
+    operator: &Pubkey,
+    ncn: &Pubkey,
+    epoch: u64,
+    ncn_reward_receiver: &'a AccountInfo<'info>,
+    destination: &'a AccountInfo<'info>,
+    token_program: &'a AccountInfo<'info>,
+    rewards: u64,
+) -> ProgramResult {
+    NcnRewardReceiver::transfer(
+        program_id,
+        ncn_fee_group,
+        operator,
+        ncn,
+        epoch,
+        ncn_reward_receiver,
+        destination,
+        rewards,
+    )?;
+
+    if payout_asset == PayoutAsset::WrappedSol {
+        sync_wrapped_sol(destination, token_program)?;
+    }
+
+    Ok(())
+}
+
+/// Syncs the balance of a wSOL token account after lamports were sent to it
+pub fn sync_wrapped_sol<'a, 'info>(
+    destination: &'a AccountInfo<'info>,
+    token_program: &'a AccountInfo<'info>,
+) -> ProgramResult {
+    invoke(
+        &spl_token_interface::instruction::sync_native(token_program.key, destination.key)?,
+        &[destination.clone(), token_program.clone()],
+    )
+}
@@ -0,0 +1,96 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    admin_multisig::{AdminAction, AdminMultisig, AdminProposal},
+    config::{Config as NcnConfig, ConfigAdminRole},
+    error::TipRouterError,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Creates a proposal for an admin action, counting as the proposer's approval. The proposer pays
+/// for the proposal account.
+pub fn process_propose_admin_action(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: ConfigAdminRole,
+    action: &AdminAction,
+) -> ProgramResult {
+    let [config, admin_multisig, admin_proposal, ncn, signer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(signer, true)?;
+    load_system_account(admin_proposal, true)?;
+    load_system_program(system_program)?;
+
+    NcnConfig::load(program_id, config, ncn.key, false)?;
+    AdminMultisig::load(program_id, admin_multisig, ncn.key, role, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+
+    if action.role() != role {
+        msg!("{:?} cannot propose {:?}", role, action);
+        return Err(TipRouterError::AdminActionRoleMismatch.into());
+    }
+
+    {
+        let config_data = config.data.borrow();
+        let config = NcnConfig::try_from_slice_unchecked(&config_data)?;
+        if config.admin(role).ne(admin_multisig.key) {
+            msg!("Admin multisig is not the {:?}", role);
+            return Err(TipRouterError::AdminMultisigNotActive.into());
+        }
+    }
+
+    let mut admin_multisig_data = admin_multisig.try_borrow_mut_data()?;
+    let admin_multisig_account =
+        AdminMultisig::try_from_slice_unchecked_mut(&mut admin_multisig_data)?;
+
+    admin_multisig_account.signer_index(signer.key)?;
+    let nonce = admin_multisig_account.next_proposal_nonce()?;
+
+    let (admin_proposal_pda, admin_proposal_bump, mut admin_proposal_seeds) =
+        AdminProposal::find_program_address(program_id, ncn.key, role, nonce);
+    admin_proposal_seeds.push(vec![admin_proposal_bump]);
+
+    if admin_proposal_pda != *admin_proposal.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    create_account(
+        signer,
+        admin_proposal,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        AdminProposal::SIZE as u64,
+        &admin_proposal_seeds,
+    )?;
+
+    let mut admin_proposal_data = admin_proposal.try_borrow_mut_data()?;
+    admin_proposal_data[0] = AdminProposal::DISCRIMINATOR;
+    let admin_proposal_account =
+        AdminProposal::try_from_slice_unchecked_mut(&mut admin_proposal_data)?;
+
+    *admin_proposal_account = AdminProposal::new(
+        ncn.key,
+        role,
+        nonce,
+        admin_multisig_account.version(),
+        signer.key,
+        action,
+        Clock::get()?.slot,
+        admin_proposal_bump,
+    )?;
+    admin_proposal_account.approve(admin_multisig_account, signer.key)?;
+
+    msg!("Proposal {} created for {:?}", nonce, action);
+
+    Ok(())
+}
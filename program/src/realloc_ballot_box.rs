@@ -2,8 +2,12 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    account_payer::AccountPayer, ballot_box::BallotBox, config::Config as NcnConfig,
-    epoch_state::EpochState, utils::get_new_size,
+    account_payer::AccountPayer,
+    account_version::{VersionedAccount, ACCOUNT_VERSION_INDEX},
+    ballot_box::BallotBox,
+    config::Config as NcnConfig,
+    epoch_state::EpochState,
+    utils::get_new_size,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -52,14 +56,34 @@ pub fn process_realloc_ballot_box(
     if should_initialize {
         let mut ballot_box_data = ballot_box.try_borrow_mut_data()?;
         ballot_box_data[0] = BallotBox::DISCRIMINATOR;
+        ballot_box_data[ACCOUNT_VERSION_INDEX] = BallotBox::ACCOUNT_VERSION;
         let ballot_box_account = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
         ballot_box_account.initialize(ncn.key, epoch, ballot_box_bump, Clock::get()?.slot);
 
+        // Commit-reveal windows, quorum and the stall fallback threshold are fixed for the epoch
+        // once the ballot box exists
+        {
+            let ncn_config_data = ncn_config.try_borrow_data()?;
+            let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+            ballot_box_account.set_commit_reveal_windows(
+                ncn_config.commit_window_slots(),
+                ncn_config.reveal_window_slots(),
+            )?;
+            ballot_box_account.set_quorum(
+                ncn_config.quorum_operator_count(),
+                ncn_config.quorum_operator_bps(),
+            );
+            ballot_box_account
+                .set_stall_fallback_threshold_bps(ncn_config.stall_fallback_threshold_bps());
+        }
+
         // Update Epoch State
         {
             let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
             let epoch_state_account =
                 EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+            ballot_box_account
+                .set_consensus_threshold_bps(epoch_state_account.consensus_threshold_bps());
             epoch_state_account.update_realloc_ballot_box();
         }
     }
@@ -2,8 +2,12 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    account_payer::AccountPayer, base_reward_router::BaseRewardRouter, config::Config as NcnConfig,
-    epoch_state::EpochState, utils::get_new_size,
+    account_payer::AccountPayer,
+    account_version::{VersionedAccount, ACCOUNT_VERSION_INDEX},
+    base_reward_router::BaseRewardRouter,
+    config::Config as NcnConfig,
+    epoch_state::EpochState,
+    utils::get_new_size,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -15,7 +19,7 @@ pub fn process_realloc_base_reward_router(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, base_reward_router, ncn, account_payer, system_program] =
+    let [epoch_state, ncn_config, base_reward_router, ncn, account_payer, system_program, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -27,8 +31,13 @@ pub fn process_realloc_base_reward_router(
     NcnConfig::load(program_id, ncn_config, ncn.key, false)?;
     AccountPayer::load(program_id, account_payer, ncn.key, true)?;
 
+    let mint = optional_accounts
+        .first()
+        .filter(|account| account.key.ne(program_id))
+        .map_or(Pubkey::default(), |mint| *mint.key);
+
     let (base_reward_router_pda, base_reward_router_bump, _) =
-        BaseRewardRouter::find_program_address(program_id, ncn.key, epoch);
+        BaseRewardRouter::find_program_address_for_mint(program_id, ncn.key, &mint, epoch);
 
     if base_reward_router_pda != *base_reward_router.key {
         msg!("Base reward router account is not at the correct PDA");
@@ -57,11 +66,13 @@ pub fn process_realloc_base_reward_router(
     if should_initialize {
         let mut base_reward_router_data = base_reward_router.try_borrow_mut_data()?;
         base_reward_router_data[0] = BaseRewardRouter::DISCRIMINATOR;
+        base_reward_router_data[ACCOUNT_VERSION_INDEX] = BaseRewardRouter::ACCOUNT_VERSION;
         let base_reward_router_account =
             BaseRewardRouter::try_from_slice_unchecked_mut(&mut base_reward_router_data)?;
 
         base_reward_router_account.initialize(
             ncn.key,
+            &mint,
             epoch,
             base_reward_router_bump,
             Clock::get()?.slot,
@@ -72,7 +83,11 @@ pub fn process_realloc_base_reward_router(
             let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
             let epoch_state_account =
                 EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
-            epoch_state_account.update_realloc_base_reward_router();
+            if mint.eq(&Pubkey::default()) {
+                epoch_state_account.update_realloc_base_reward_router();
+            } else {
+                epoch_state_account.update_initialize_token_reward_router()?;
+            }
         }
     }
 
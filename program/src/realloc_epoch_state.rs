@@ -47,12 +47,18 @@ pub fn process_realloc_epoch_state(
         && epoch_state.try_borrow_data()?[0] != EpochState::DISCRIMINATOR;
 
     if should_initialize {
+        let consensus_threshold_bps = {
+            let config_data = config.try_borrow_data()?;
+            let config = Config::try_from_slice_unchecked(&config_data)?;
+            config.consensus_threshold_bps()
+        };
+
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         epoch_state_data[0] = EpochState::DISCRIMINATOR;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
         epoch_state_account.initialize(ncn.key, epoch, epoch_state_bump, Clock::get()?.slot);
 
-        epoch_state_account.update_realloc_epoch_state();
+        epoch_state_account.update_realloc_epoch_state(consensus_threshold_bps);
     }
 
     Ok(())
@@ -5,9 +5,11 @@ use jito_restaking_core::{
 };
 use jito_tip_router_core::{
     account_payer::AccountPayer,
+    account_version::{VersionedAccount, ACCOUNT_VERSION_INDEX},
     config::Config as NcnConfig,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    epoch_state_page::EpochStatePage,
     loaders::load_ncn_epoch,
     stake_weight::StakeWeights,
     utils::get_new_size,
@@ -22,7 +24,7 @@ pub fn process_realloc_operator_snapshot(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, restaking_config, ncn, operator, ncn_operator_state, epoch_snapshot, operator_snapshot, account_payer, system_program] =
+    let [epoch_state, ncn_config, restaking_config, ncn, operator, ncn_operator_state, epoch_snapshot, operator_snapshot, account_payer, system_program, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -113,8 +115,18 @@ pub fn process_realloc_operator_snapshot(
             )
         };
 
+        let (max_operator_stake_weight_bps, max_vault_stake_weight_bps) = {
+            let ncn_config_data = ncn_config.data.borrow();
+            let ncn_config_account = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+            (
+                ncn_config_account.max_operator_stake_weight_bps(),
+                ncn_config_account.max_vault_stake_weight_bps(),
+            )
+        };
+
         let mut operator_snapshot_data = operator_snapshot.try_borrow_mut_data()?;
         operator_snapshot_data[0] = OperatorSnapshot::DISCRIMINATOR;
+        operator_snapshot_data[ACCOUNT_VERSION_INDEX] = OperatorSnapshot::ACCOUNT_VERSION;
         let operator_snapshot_account =
             OperatorSnapshot::try_from_slice_unchecked_mut(&mut operator_snapshot_data)?;
 
@@ -131,6 +143,10 @@ pub fn process_realloc_operator_snapshot(
             vault_count,
         )?;
 
+        // The caps are fixed for the epoch, later config changes only apply to new snapshots
+        operator_snapshot_account
+            .set_stake_weight_caps(max_operator_stake_weight_bps, max_vault_stake_weight_bps);
+
         // Increment operator registration for an inactive operator
         if !is_active {
             let mut epoch_snapshot_data = epoch_snapshot.try_borrow_mut_data()?;
@@ -139,18 +155,44 @@ pub fn process_realloc_operator_snapshot(
 
             epoch_snapshot_account.increment_operator_registration(
                 current_slot,
+                false,
                 0,
                 &StakeWeights::default(),
             )?;
+
+            // Without stake weight there is nothing to cap
+            let capped_stake_weight = operator_snapshot_account.apply_stake_weight_caps(0, &[])?;
+            epoch_snapshot_account.increment_operators_capped(capped_stake_weight)?;
         }
 
-        // Update Epoch State
+        // Update Epoch State, operators past the epoch state are tracked in an epoch state page
         {
+            let epoch_state_page = EpochStatePage::load_for_operator(
+                program_id,
+                optional_accounts
+                    .first()
+                    .filter(|account| account.key.ne(program_id)),
+                ncn.key,
+                epoch,
+                ncn_operator_index,
+                true,
+            )?;
+            let mut epoch_state_page_data = epoch_state_page
+                .map(|account| account.try_borrow_mut_data())
+                .transpose()?;
+            let epoch_state_page = epoch_state_page_data
+                .as_deref_mut()
+                .map(|data| EpochStatePage::try_from_slice_unchecked_mut(data))
+                .transpose()?;
+
             let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
             let epoch_state_account =
                 EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
-            epoch_state_account
-                .update_realloc_operator_snapshot(ncn_operator_index as usize, is_active)?;
+            epoch_state_account.update_realloc_operator_snapshot(
+                ncn_operator_index as usize,
+                is_active,
+                epoch_state_page,
+            )?;
         }
     }
 
@@ -2,7 +2,10 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    account_payer::AccountPayer, config::Config as NcnConfig, utils::get_new_size,
+    account_payer::AccountPayer,
+    account_version::{VersionedAccount, ACCOUNT_VERSION_INDEX},
+    config::Config as NcnConfig,
+    utils::get_new_size,
     vault_registry::VaultRegistry,
 };
 use solana_program::{
@@ -55,6 +58,7 @@ pub fn process_realloc_vault_registry(
     if should_initialize {
         let mut vault_registry_data = vault_registry.try_borrow_mut_data()?;
         vault_registry_data[0] = VaultRegistry::DISCRIMINATOR;
+        vault_registry_data[ACCOUNT_VERSION_INDEX] = VaultRegistry::ACCOUNT_VERSION;
         let vault_registry_account =
             VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
         vault_registry_account.initialize(ncn.key, vault_registry_bump);
@@ -2,8 +2,13 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::loader::load_system_program;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
-    account_payer::AccountPayer, config::Config as NcnConfig, epoch_state::EpochState,
-    utils::get_new_size, vault_registry::VaultRegistry, weight_table::WeightTable,
+    account_payer::AccountPayer,
+    account_version::{VersionedAccount, ACCOUNT_VERSION_INDEX},
+    config::Config as NcnConfig,
+    epoch_state::EpochState,
+    utils::get_new_size,
+    vault_registry::VaultRegistry,
+    weight_table::WeightTable,
 };
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
@@ -53,13 +58,16 @@ pub fn process_realloc_weight_table(
         let vault_registry_data = vault_registry.data.borrow();
         let vault_registry = VaultRegistry::try_from_slice_unchecked(&vault_registry_data)?;
 
-        let vault_count = vault_registry.vault_count();
-        let st_mint_count = vault_registry.st_mint_count();
+        // Deactivated vaults and mints are left out of the weight table
+        let vault_count = vault_registry.active_vault_count(epoch);
+        let page_count = vault_registry.page_count();
+        let st_mint_count = vault_registry.active_st_mint_count(epoch);
         let vault_entries = vault_registry.get_vault_entries();
         let mint_entries = vault_registry.get_mint_entries();
 
         let mut weight_table_data = weight_table.try_borrow_mut_data()?;
         weight_table_data[0] = WeightTable::DISCRIMINATOR;
+        weight_table_data[ACCOUNT_VERSION_INDEX] = WeightTable::ACCOUNT_VERSION;
         let weight_table_account =
             WeightTable::try_from_slice_unchecked_mut(&mut weight_table_data)?;
 
@@ -68,11 +76,19 @@ pub fn process_realloc_weight_table(
             epoch,
             Clock::get()?.slot,
             vault_count,
+            page_count,
             weight_table_bump,
             vault_entries,
             mint_entries,
         )?;
 
+        // The sampling window is fixed for the epoch once the weight table exists
+        {
+            let ncn_config_data = ncn_config.try_borrow_data()?;
+            let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+            weight_table_account.set_sampling_window(ncn_config.weight_sampling_window_slots())?;
+        }
+
         // Update Epoch State
         {
             let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
@@ -1,6 +1,9 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::{ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
-use jito_tip_router_core::{config::Config, vault_registry::VaultRegistry};
+use jito_tip_router_core::{
+    config::Config, error::TipRouterError, vault_registry::VaultRegistry,
+    vault_registry_page::VaultRegistryPage,
+};
 use jito_vault_core::vault::Vault;
 use solana_program::{
     account_info::AccountInfo,
@@ -12,7 +15,8 @@ use solana_program::{
 };
 
 pub fn process_register_vault(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config, vault_registry, ncn, vault, ncn_vault_ticket] = accounts else {
+    let [config, vault_registry, ncn, vault, ncn_vault_ticket, optional_accounts @ ..] = accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -28,9 +32,30 @@ pub fn process_register_vault(program_id: &Pubkey, accounts: &[AccountInfo]) ->
         false,
     )?;
 
+    // Vaults past `MAX_VAULTS` are kept at the position of their NCN vault index in a page
+    let ncn_vault_index = {
+        let ncn_vault_ticket_data = ncn_vault_ticket.data.borrow();
+        NcnVaultTicket::try_from_slice_unchecked(&ncn_vault_ticket_data)?.index()
+    };
+    let vault_registry_page = VaultRegistryPage::load_for_vault(
+        program_id,
+        optional_accounts
+            .first()
+            .filter(|account| account.key.ne(program_id)),
+        ncn.key,
+        ncn_vault_index,
+        true,
+    )?;
+
     let clock = Clock::get()?;
     let slot = clock.slot;
 
+    let ncn_vault_count = {
+        let ncn_data = ncn.data.borrow();
+        let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+        ncn_account.vault_count()
+    };
+
     let mut vault_registry_data = vault_registry.try_borrow_mut_data()?;
     let vault_registry = VaultRegistry::try_from_slice_unchecked_mut(&mut vault_registry_data)?;
 
@@ -42,6 +67,41 @@ pub fn process_register_vault(program_id: &Pubkey, accounts: &[AccountInfo]) ->
         return Err(ProgramError::InvalidAccountData);
     }
 
+    if vault_registry
+        .get_mint_entry(&vault_account.supported_mint)?
+        .is_deactivated()
+    {
+        return Err(TipRouterError::StMintDeactivated.into());
+    }
+
+    if let Some(vault_registry_page) = vault_registry_page {
+        let mut vault_registry_page_data = vault_registry_page.try_borrow_mut_data()?;
+        let vault_registry_page =
+            VaultRegistryPage::try_from_slice_unchecked_mut(&mut vault_registry_page_data)?;
+
+        if vault_registry_page.register_vault(
+            ncn_vault_index,
+            vault.key,
+            &vault_account.supported_mint,
+            vault_account.vault_index(),
+            slot,
+        )? {
+            vault_registry.increment_paged_vault_count()?;
+        }
+
+        return Ok(());
+    }
+
+    vault_registry.compact(clock.epoch)?;
+
+    // A removed vault can't be registered again, it would count twice against the NCN's vaults
+    if !vault_registry.has_vault(vault.key)
+        && vault_registry.registered_vault_count()? >= ncn_vault_count
+    {
+        msg!("Vault was removed from the registry");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
     vault_registry.register_vault(
         vault.key,
         &vault_account.supported_mint,
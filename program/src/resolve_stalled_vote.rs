@@ -0,0 +1,94 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::ncn::Ncn;
+use jito_tip_router_core::{
+    ballot_box::BallotBox, config::Config as NcnConfig, epoch_snapshot::EpochSnapshot,
+    epoch_state::EpochState, events::TipRouterEvent, pause::PausableSubsystem,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Finalizes the leading ballot of a stalled vote without the tie breaker admin,
+/// as long as it meets the stall fallback threshold the ballot box was created with.
+pub fn process_resolve_stalled_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn_config, ballot_box, ncn, epoch_snapshot] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    NcnConfig::load_unpaused(
+        program_id,
+        ncn_config,
+        ncn.key,
+        PausableSubsystem::Voting,
+        false,
+    )?;
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, true)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    EpochSnapshot::load(program_id, epoch_snapshot, ncn.key, epoch, false)?;
+
+    let epochs_before_stall = {
+        let ncn_config_data = ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+        ncn_config.epochs_before_stall()
+    };
+
+    let total_stake_weight = {
+        let epoch_snapshot_data = epoch_snapshot.data.borrow();
+        let epoch_snapshot = EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?;
+        epoch_snapshot.voting_stake_weight()?
+    };
+
+    let mut ballot_box_data = ballot_box.data.borrow_mut();
+    let ballot_box_account = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
+
+    let clock = Clock::get()?;
+
+    ballot_box_account.resolve_stalled_vote(
+        total_stake_weight,
+        clock.epoch,
+        epochs_before_stall,
+    )?;
+
+    msg!(
+        "Stalled vote resolved for epoch {} with ballot {:?}",
+        epoch,
+        ballot_box_account.get_winning_ballot()?
+    );
+
+    let meta_merkle_root = ballot_box_account.get_winning_ballot()?.root();
+
+    TipRouterEvent::TieBreakerSet {
+        ncn: *ncn.key,
+        epoch,
+        meta_merkle_root,
+        is_stall_fallback: true,
+    }
+    .emit();
+
+    TipRouterEvent::ConsensusReached {
+        ncn: *ncn.key,
+        epoch,
+        meta_merkle_root,
+        slot: clock.slot,
+    }
+    .emit();
+
+    // Update Epoch State
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_set_tie_breaker(
+            ballot_box_account.is_consensus_reached(),
+            true,
+            clock.slot,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::vote::{load_vote_accounts, tally_vote};
+
+/// Reveals a committed vote, the vote is only tallied once it matches the operator's commitment
+pub fn process_reveal_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    meta_merkle_root: &[u8; 32],
+    salt: &[u8; 32],
+    epoch: u64,
+) -> ProgramResult {
+    let vote_accounts = load_vote_accounts(program_id, accounts, epoch, true)?;
+
+    tally_vote(&vote_accounts, epoch, meta_merkle_root, Some(salt))
+}
@@ -2,36 +2,65 @@ use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::ncn::Ncn;
 use jito_tip_router_core::{
     ballot_box::BallotBox,
+    ballot_box_page::BallotBoxPage,
     base_reward_router::{BaseRewardReceiver, BaseRewardRouter},
+    base_reward_router_page::BaseRewardRouterPage,
     config::Config as NcnConfig,
     epoch_snapshot::EpochSnapshot,
     epoch_state::EpochState,
     error::TipRouterError,
+    events::TipRouterEvent,
+    pause::PausableSubsystem,
 };
+
+use crate::{crank_bounty::pay_crank_bounty, token_rewards::load_receiver_token_account};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
-/// Can be backfilled for previous epochs
+/// Can be backfilled for previous epochs. Token routers route the balance of the base reward
+/// receiver's ATA for their `mint` instead of its lamports. The call that finishes routing
+/// the round's lamports pays the optional `cranker` a crank bounty, the optional system program
+/// has to be passed with it. Token amounts are not priced in lamports, so they earn no bounty.
+/// When the ballot box has pages, every ballot box page and then every base reward router page
+/// is passed in order as remaining accounts.
 pub fn process_route_base_rewards(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     max_iterations: u16,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, config, ncn, epoch_snapshot, ballot_box, base_reward_router, base_reward_receiver] =
+    let [epoch_state, config, ncn, epoch_snapshot, ballot_box, base_reward_router, base_reward_receiver, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
-    NcnConfig::load(program_id, config, ncn.key, false)?;
+    NcnConfig::load_unpaused(
+        program_id,
+        config,
+        ncn.key,
+        PausableSubsystem::Routing,
+        false,
+    )?;
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
 
+    let mint = optional_accounts
+        .first()
+        .filter(|account| account.key.ne(program_id));
+    let mint_key = mint.map_or(Pubkey::default(), |mint| *mint.key);
+
     EpochSnapshot::load(program_id, epoch_snapshot, ncn.key, epoch, false)?;
-    BaseRewardRouter::load(program_id, base_reward_router, ncn.key, epoch, true)?;
+    BaseRewardRouter::load_for_mint(
+        program_id,
+        base_reward_router,
+        ncn.key,
+        &mint_key,
+        epoch,
+        true,
+    )?;
     BallotBox::load(program_id, ballot_box, ncn.key, epoch, false)?;
     BaseRewardReceiver::load(program_id, base_reward_receiver, ncn.key, epoch, true)?;
 
@@ -54,22 +83,112 @@ pub fn process_route_base_rewards(
         return Err(TipRouterError::VotingIsNotOver.into());
     }
 
-    let base_reward_receiver_balance = **base_reward_receiver.try_borrow_lamports()?;
+    let page_count = ballot_box_account.page_count() as usize;
+    let (ballot_box_pages, base_reward_router_pages) = {
+        let page_accounts = optional_accounts.get(5..).unwrap_or_default();
+        if page_accounts.len() < page_count * 2 {
+            msg!("Expected {} ballot box and router pages", page_count);
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        page_accounts[..page_count * 2].split_at(page_count)
+    };
+
+    for (page, ballot_box_page) in ballot_box_pages.iter().enumerate() {
+        BallotBoxPage::load(
+            program_id,
+            ballot_box_page,
+            ncn.key,
+            epoch,
+            page as u64,
+            false,
+        )?;
+    }
+    for (page, base_reward_router_page) in base_reward_router_pages.iter().enumerate() {
+        BaseRewardRouterPage::load_for_mint(
+            program_id,
+            base_reward_router_page,
+            ncn.key,
+            &mint_key,
+            epoch,
+            page as u64,
+            true,
+        )?;
+    }
+
+    let (base_reward_receiver_balance, rent_cost) = if mint.is_some() {
+        let base_reward_receiver_token_account = optional_accounts
+            .get(1)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let token_balance = load_receiver_token_account(
+            base_reward_receiver_token_account,
+            base_reward_receiver.key,
+            &mint_key,
+        )?;
+        (token_balance, 0)
+    } else {
+        (
+            **base_reward_receiver.try_borrow_lamports()?,
+            Rent::get()?.minimum_balance(0),
+        )
+    };
 
     let mut base_reward_router_data = base_reward_router.try_borrow_mut_data()?;
     let base_reward_router_account =
         BaseRewardRouter::try_from_slice_unchecked_mut(&mut base_reward_router_data)?;
 
-    let rent_cost = Rent::get()?.minimum_balance(0);
+    let was_routing = base_reward_router_account.still_routing();
 
-    if !base_reward_router_account.still_routing() {
+    if !was_routing {
         base_reward_router_account
             .route_incoming_rewards(rent_cost, base_reward_receiver_balance)?;
 
         base_reward_router_account.route_reward_pool(epoch_snapshot_account.fees())?;
     }
 
-    base_reward_router_account.route_ncn_fee_group_rewards(ballot_box_account, max_iterations)?;
+    {
+        let ballot_box_page_data = ballot_box_pages
+            .iter()
+            .map(|page| page.try_borrow_data())
+            .collect::<Result<Vec<_>, _>>()?;
+        let ballot_box_page_accounts = ballot_box_page_data
+            .iter()
+            .map(|data| BallotBoxPage::try_from_slice_unchecked(data))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut base_reward_router_page_data = base_reward_router_pages
+            .iter()
+            .map(|page| page.try_borrow_mut_data())
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut base_reward_router_page_accounts = base_reward_router_page_data
+            .iter_mut()
+            .map(|data| BaseRewardRouterPage::try_from_slice_unchecked_mut(data))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        base_reward_router_account.route_ncn_fee_group_rewards_with_pages(
+            ballot_box_account,
+            &ballot_box_page_accounts,
+            &mut base_reward_router_page_accounts,
+            max_iterations,
+        )?;
+    }
+
+    // Epoch state progress, events and crank bounties are tracked in lamports
+    if mint.is_some() {
+        return Ok(());
+    }
+
+    // The bounty is paid once, by the call that finishes routing the round
+    if !base_reward_router_account.still_routing() {
+        pay_crank_bounty(
+            program_id,
+            ncn.key,
+            epoch,
+            config,
+            epoch_state,
+            base_reward_router_account.last_rewards_routed(),
+            optional_accounts.get(2..).unwrap_or_default(),
+        )?;
+    }
 
     {
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
@@ -77,5 +196,12 @@ pub fn process_route_base_rewards(
         epoch_state_account.update_route_base_rewards(base_reward_router_account.total_rewards());
     }
 
+    TipRouterEvent::BaseRewardsRouted {
+        ncn: *ncn.key,
+        epoch,
+        total_rewards: base_reward_router_account.total_rewards(),
+    }
+    .emit();
+
     Ok(())
 }
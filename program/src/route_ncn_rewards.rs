@@ -1,17 +1,29 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_tip_router_core::{
+    config::Config as NcnConfig,
     epoch_snapshot::OperatorSnapshot,
     epoch_state::EpochState,
+    epoch_state_page::EpochStatePage,
+    events::TipRouterEvent,
     ncn_fee_group::NcnFeeGroup,
     ncn_reward_router::{NcnRewardReceiver, NcnRewardRouter},
+    operator_snapshot_page::OperatorSnapshotPage,
+    pause::PausableSubsystem,
 };
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
     pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
-/// Can be backfilled for previous epochs
+use crate::{crank_bounty::pay_crank_bounty, token_rewards::load_receiver_token_account};
+
+/// Can be backfilled for previous epochs. Token routers route the balance of the NCN reward
+/// receiver's ATA for their `mint` instead of its lamports. The call that finishes routing
+/// the round's lamports pays the optional `cranker` a crank bounty, the optional system program
+/// has to be passed with it. Token amounts are not priced in lamports, so they earn no bounty.
+/// Operators past `MAX_OPERATORS` take their `epoch_state_page`. Operators with vaults past
+/// `MAX_VAULTS` take their operator snapshot pages as remaining accounts.
 pub fn process_route_ncn_rewards(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -19,13 +31,20 @@ pub fn process_route_ncn_rewards(
     max_iterations: u16,
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn, operator, operator_snapshot, ncn_reward_router, ncn_reward_receiver] =
+    let [epoch_state, config, ncn, operator, operator_snapshot, ncn_reward_router, ncn_reward_receiver, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    NcnConfig::load_unpaused(
+        program_id,
+        config,
+        ncn.key,
+        PausableSubsystem::Routing,
+        false,
+    )?;
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
     Operator::load(&jito_restaking_program::id(), operator, false)?;
     NcnRewardReceiver::load(
@@ -48,12 +67,18 @@ pub fn process_route_ncn_rewards(
         epoch,
         false,
     )?;
-    NcnRewardRouter::load(
+    let mint = optional_accounts
+        .first()
+        .filter(|account| account.key.ne(program_id));
+    let mint_key = mint.map_or(Pubkey::default(), |mint| *mint.key);
+
+    NcnRewardRouter::load_for_mint(
         program_id,
         ncn_reward_router,
         ncn_fee_group,
         operator.key,
         ncn.key,
+        &mint_key,
         epoch,
         true,
     )?;
@@ -62,30 +87,114 @@ pub fn process_route_ncn_rewards(
     let operator_snapshot_account =
         OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_data)?;
 
-    let account_balance = **ncn_reward_receiver.try_borrow_lamports()?;
+    let operator_snapshot_pages = optional_accounts.get(6..).unwrap_or_default();
+    OperatorSnapshotPage::load_all_pages(
+        program_id,
+        operator_snapshot_pages,
+        operator.key,
+        ncn.key,
+        epoch,
+        operator_snapshot_account.page_count(),
+    )?;
+    let operator_snapshot_pages_data = operator_snapshot_pages
+        .iter()
+        .map(|page| page.try_borrow_data())
+        .collect::<Result<Vec<_>, _>>()?;
+    let operator_snapshot_pages_accounts = operator_snapshot_pages_data
+        .iter()
+        .map(|data| OperatorSnapshotPage::try_from_slice_unchecked(data))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (account_balance, rent_cost) = if mint.is_some() {
+        let ncn_reward_receiver_token_account = optional_accounts
+            .get(1)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let token_balance = load_receiver_token_account(
+            ncn_reward_receiver_token_account,
+            ncn_reward_receiver.key,
+            &mint_key,
+        )?;
+        (token_balance, 0)
+    } else {
+        (
+            **ncn_reward_receiver.try_borrow_lamports()?,
+            Rent::get()?.minimum_balance(0),
+        )
+    };
 
     let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
     let ncn_reward_router_account =
         NcnRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
 
-    let rent_cost = Rent::get()?.minimum_balance(0);
+    let was_routing = ncn_reward_router_account.still_routing();
 
-    if !ncn_reward_router_account.still_routing() {
+    if !was_routing {
         ncn_reward_router_account.route_incoming_rewards(rent_cost, account_balance)?;
         ncn_reward_router_account.route_operator_rewards(operator_snapshot_account)?;
     }
 
-    ncn_reward_router_account.route_reward_pool(operator_snapshot_account, max_iterations)?;
+    ncn_reward_router_account.route_reward_pool(
+        operator_snapshot_account,
+        &operator_snapshot_pages_accounts,
+        max_iterations,
+    )?;
+
+    // Epoch state progress, events and crank bounties are tracked in lamports
+    if mint.is_some() {
+        return Ok(());
+    }
+
+    // The bounty is paid once, by the call that finishes routing the round
+    if !ncn_reward_router_account.still_routing() {
+        pay_crank_bounty(
+            program_id,
+            ncn.key,
+            epoch,
+            config,
+            epoch_state,
+            ncn_reward_router_account.last_rewards_routed(),
+            optional_accounts.get(2..).unwrap_or_default(),
+        )?;
+    }
 
     {
+        let ncn_operator_index = operator_snapshot_account.ncn_operator_index();
+        let epoch_state_page = EpochStatePage::load_for_operator(
+            program_id,
+            optional_accounts
+                .get(5)
+                .filter(|account| account.key.ne(program_id)),
+            ncn.key,
+            epoch,
+            ncn_operator_index,
+            true,
+        )?;
+        let mut epoch_state_page_data = epoch_state_page
+            .map(|account| account.try_borrow_mut_data())
+            .transpose()?;
+        let epoch_state_page = epoch_state_page_data
+            .as_deref_mut()
+            .map(|data| EpochStatePage::try_from_slice_unchecked_mut(data))
+            .transpose()?;
+
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
         epoch_state_account.update_route_ncn_rewards(
-            operator_snapshot_account.ncn_operator_index() as usize,
+            ncn_operator_index as usize,
             ncn_fee_group,
             ncn_reward_router_account.total_rewards(),
+            epoch_state_page,
         )?;
     }
 
+    TipRouterEvent::NcnRewardsRouted {
+        ncn: *ncn.key,
+        epoch,
+        operator: *operator.key,
+        ncn_fee_group: ncn_fee_group.group,
+        total_rewards: ncn_reward_router_account.total_rewards(),
+    }
+    .emit();
+
     Ok(())
 }
@@ -9,7 +9,7 @@ use jito_tip_distribution_sdk::{
 };
 use jito_tip_router_core::{
     ballot_box::BallotBox, config::Config as NcnConfig, epoch_state::EpochState,
-    error::TipRouterError,
+    error::TipRouterError, events::TipRouterEvent, pause::PausableSubsystem,
 };
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
@@ -33,7 +33,13 @@ pub fn process_set_merkle_root(
     };
 
     EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
-    NcnConfig::load(program_id, ncn_config, ncn.key, true)?;
+    NcnConfig::load_unpaused(
+        program_id,
+        ncn_config,
+        ncn.key,
+        PausableSubsystem::MerkleRootUpload,
+        true,
+    )?;
     Ncn::load(&jito_restaking_program::id(), ncn, false)?;
     BallotBox::load(program_id, ballot_box, ncn.key, epoch, false)?;
 
@@ -131,5 +137,16 @@ pub fn process_set_merkle_root(
         epoch_state_account.update_set_merkle_root()?;
     }
 
+    TipRouterEvent::MerkleRootSet {
+        ncn: *ncn.key,
+        epoch,
+        vote_account: *vote_account.key,
+        distribution_account: *distribution_account.key,
+        merkle_root,
+        max_total_claim,
+        max_num_nodes,
+    }
+    .emit();
+
     Ok(())
 }
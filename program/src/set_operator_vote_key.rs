@@ -0,0 +1,116 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
+};
+use jito_tip_router_core::{
+    config::Config as NcnConfig, error::TipRouterError, loaders::load_ncn_epoch,
+    operator_vote_key::OperatorVoteKey,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Sets the key that can vote for an operator, only the admin of an operator active in the NCN
+/// can sign. The operator admin pays for the operator vote key account.
+pub fn process_set_operator_vote_key(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    vote_key: &Pubkey,
+) -> ProgramResult {
+    let [config, operator_vote_key, ncn, operator, restaking_config, ncn_operator_state, operator_admin, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(operator_admin, true)?;
+    load_system_program(system_program)?;
+
+    NcnConfig::load(program_id, config, ncn.key, false)?;
+    Config::load(&jito_restaking_program::id(), restaking_config, false)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    Operator::load(&jito_restaking_program::id(), operator, false)?;
+    NcnOperatorState::load(
+        &jito_restaking_program::id(),
+        ncn_operator_state,
+        ncn,
+        operator,
+        false,
+    )?;
+
+    {
+        let operator_data = operator.data.borrow();
+        let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+        if operator_account.admin.ne(operator_admin.key) {
+            return Err(TipRouterError::IncorrectOperatorAdmin.into());
+        }
+    }
+
+    let slot = Clock::get()?.slot;
+
+    // Both the NCN and the operator have to have opted in
+    {
+        let (_, ncn_epoch_length) = load_ncn_epoch(restaking_config, slot, None)?;
+
+        let ncn_operator_state_data = ncn_operator_state.data.borrow();
+        let ncn_operator_state_account =
+            NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
+
+        let is_active = ncn_operator_state_account
+            .ncn_opt_in_state
+            .is_active(slot, ncn_epoch_length)?
+            && ncn_operator_state_account
+                .operator_opt_in_state
+                .is_active(slot, ncn_epoch_length)?;
+
+        if !is_active {
+            msg!("Operator {} is not active in the NCN", operator.key);
+            return Err(TipRouterError::OperatorNotActiveInNcn.into());
+        }
+    }
+
+    if operator_vote_key.data_is_empty() {
+        load_system_account(operator_vote_key, true)?;
+
+        let (operator_vote_key_pda, operator_vote_key_bump, mut operator_vote_key_seeds) =
+            OperatorVoteKey::find_program_address(program_id, operator.key, ncn.key);
+        operator_vote_key_seeds.push(vec![operator_vote_key_bump]);
+
+        if operator_vote_key_pda != *operator_vote_key.key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        create_account(
+            operator_admin,
+            operator_vote_key,
+            system_program,
+            program_id,
+            &Rent::get()?,
+            OperatorVoteKey::SIZE as u64,
+            &operator_vote_key_seeds,
+        )?;
+
+        let mut operator_vote_key_data = operator_vote_key.try_borrow_mut_data()?;
+        operator_vote_key_data[0] = OperatorVoteKey::DISCRIMINATOR;
+        let operator_vote_key_account =
+            OperatorVoteKey::try_from_slice_unchecked_mut(&mut operator_vote_key_data)?;
+        *operator_vote_key_account =
+            OperatorVoteKey::new(operator.key, ncn.key, operator_vote_key_bump);
+    } else {
+        OperatorVoteKey::load(program_id, operator_vote_key, operator.key, ncn.key, true)?;
+    }
+
+    let mut operator_vote_key_data = operator_vote_key.try_borrow_mut_data()?;
+    let operator_vote_key_account =
+        OperatorVoteKey::try_from_slice_unchecked_mut(&mut operator_vote_key_data)?;
+    operator_vote_key_account.set_vote_key(vote_key, slot);
+
+    msg!("Operator {} vote key set to {}", operator.key, vote_key);
+
+    Ok(())
+}
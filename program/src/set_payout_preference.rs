@@ -0,0 +1,113 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_tip_router_core::{
+    config::Config as NcnConfig,
+    error::TipRouterError,
+    events::TipRouterEvent,
+    payout_preference::{PayoutAsset, PayoutPreference},
+};
+use jito_vault_core::vault::Vault;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Sets the asset an operator or vault is paid its NCN rewards in, only the operator or vault
+/// admin can sign. The admin pays for the payout preference account.
+pub fn process_set_payout_preference(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    payout_asset: u8,
+    sol_destination: &Pubkey,
+) -> ProgramResult {
+    let [config, payout_preference, ncn, recipient, admin, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(admin, true)?;
+    load_system_program(system_program)?;
+
+    NcnConfig::load(program_id, config, ncn.key, false)?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+
+    let payout_asset = PayoutAsset::try_from(payout_asset)?;
+
+    // The recipient is either an operator or a vault
+    if recipient.owner.eq(&jito_restaking_program::id()) {
+        Operator::load(&jito_restaking_program::id(), recipient, false)?;
+
+        let operator_data = recipient.data.borrow();
+        let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+        if operator_account.admin.ne(admin.key) {
+            return Err(TipRouterError::IncorrectOperatorAdmin.into());
+        }
+    } else {
+        Vault::load(&jito_vault_program::id(), recipient, false)?;
+
+        let vault_data = recipient.data.borrow();
+        let vault_account = Vault::try_from_slice_unchecked(&vault_data)?;
+        if vault_account.admin.ne(admin.key) {
+            return Err(TipRouterError::IncorrectVaultAdmin.into());
+        }
+
+        // Vault rewards belong to its depositors, so they can't be sent to an arbitrary address
+        if payout_asset == PayoutAsset::Sol {
+            msg!("Vaults can only be paid in JitoSOL or wSOL");
+            return Err(TipRouterError::VaultSolPayoutNotAllowed.into());
+        }
+    }
+
+    if payout_preference.data_is_empty() {
+        load_system_account(payout_preference, true)?;
+
+        let (payout_preference_pda, payout_preference_bump, mut payout_preference_seeds) =
+            PayoutPreference::find_program_address(program_id, recipient.key, ncn.key);
+        payout_preference_seeds.push(vec![payout_preference_bump]);
+
+        if payout_preference_pda != *payout_preference.key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        create_account(
+            admin,
+            payout_preference,
+            system_program,
+            program_id,
+            &Rent::get()?,
+            PayoutPreference::SIZE as u64,
+            &payout_preference_seeds,
+        )?;
+
+        let mut payout_preference_data = payout_preference.try_borrow_mut_data()?;
+        payout_preference_data[0] = PayoutPreference::DISCRIMINATOR;
+        let payout_preference_account =
+            PayoutPreference::try_from_slice_unchecked_mut(&mut payout_preference_data)?;
+        *payout_preference_account =
+            PayoutPreference::new(recipient.key, ncn.key, payout_preference_bump);
+    } else {
+        PayoutPreference::load(program_id, payout_preference, recipient.key, ncn.key, true)?;
+    }
+
+    let slot = Clock::get()?.slot;
+
+    let mut payout_preference_data = payout_preference.try_borrow_mut_data()?;
+    let payout_preference_account =
+        PayoutPreference::try_from_slice_unchecked_mut(&mut payout_preference_data)?;
+    payout_preference_account.set_payout_preference(payout_asset, sol_destination, slot)?;
+
+    msg!("{} rewards paid out in {}", recipient.key, payout_asset);
+
+    TipRouterEvent::PayoutPreferenceSet {
+        ncn: *ncn.key,
+        recipient: *recipient.key,
+        payout_asset: payout_asset as u8,
+        sol_destination: *sol_destination,
+    }
+    .emit();
+
+    Ok(())
+}
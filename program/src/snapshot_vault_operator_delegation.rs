@@ -6,10 +6,13 @@ use jito_tip_router_core::{
     config::Config as NcnConfig,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    epoch_state_page::EpochStatePage,
     error::TipRouterError,
     loaders::load_ncn_epoch,
+    operator_snapshot_page::OperatorSnapshotPage,
     stake_weight::StakeWeights,
     weight_table::WeightTable,
+    weight_table_page::WeightTablePage,
 };
 use jito_vault_core::{
     vault::Vault, vault_ncn_ticket::VaultNcnTicket,
@@ -25,7 +28,7 @@ pub fn process_snapshot_vault_operator_delegation(
     accounts: &[AccountInfo],
     epoch: u64,
 ) -> ProgramResult {
-    let [epoch_state, ncn_config, restaking_config, ncn, operator, vault, vault_ncn_ticket, ncn_vault_ticket, vault_operator_delegation, weight_table, epoch_snapshot, operator_snapshot] =
+    let [epoch_state, ncn_config, restaking_config, ncn, operator, vault, vault_ncn_ticket, ncn_vault_ticket, vault_operator_delegation, weight_table, epoch_snapshot, operator_snapshot, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -86,6 +89,22 @@ pub fn process_snapshot_vault_operator_delegation(
     let (_, ncn_epoch_length) = load_ncn_epoch(restaking_config, current_slot, None)?;
 
     WeightTable::load(program_id, weight_table, ncn.key, epoch, false)?;
+
+    // Vaults past `MAX_VAULTS` are kept in the weight table page of their NCN vault index
+    let ncn_vault_index = {
+        let ncn_vault_ticket_data = ncn_vault_ticket.data.borrow();
+        NcnVaultTicket::try_from_slice_unchecked(&ncn_vault_ticket_data)?.index()
+    };
+    let weight_table_page = WeightTablePage::load_for_vault(
+        program_id,
+        optional_accounts
+            .first()
+            .filter(|account| account.key.ne(program_id)),
+        ncn.key,
+        epoch,
+        ncn_vault_index,
+    )?;
+
     EpochSnapshot::load(program_id, epoch_snapshot, ncn.key, epoch, true)?;
     OperatorSnapshot::load(
         program_id,
@@ -95,6 +114,16 @@ pub fn process_snapshot_vault_operator_delegation(
         epoch,
         true,
     )?;
+    let operator_snapshot_page = OperatorSnapshotPage::load_for_vault(
+        program_id,
+        optional_accounts
+            .get(1)
+            .filter(|account| account.key.ne(program_id)),
+        operator.key,
+        ncn.key,
+        epoch,
+        ncn_vault_index,
+    )?;
 
     // check vault is up to date
     let vault_needs_update = {
@@ -153,7 +182,13 @@ pub fn process_snapshot_vault_operator_delegation(
         let weight_table_account = WeightTable::try_from_slice_unchecked(&weight_table_data)?;
         let weight_entry = weight_table_account.get_weight_entry(&st_mint)?;
 
-        weight_table_account.check_registry_for_vault(vault_index)?;
+        if let Some(weight_table_page) = weight_table_page {
+            let weight_table_page_data = weight_table_page.data.borrow();
+            WeightTablePage::try_from_slice_unchecked(&weight_table_page_data)?
+                .check_registry_for_vault(vault_index)?;
+        } else {
+            weight_table_account.check_registry_for_vault(vault_index)?;
+        }
 
         let total_stake_weight: u128 = if is_active {
             let vault_operator_delegation_data = vault_operator_delegation.data.borrow();
@@ -184,13 +219,28 @@ pub fn process_snapshot_vault_operator_delegation(
     let stake_weights =
         StakeWeights::snapshot(ncn_fee_group, total_stake_weight, reward_multiplier_bps)?;
 
-    operator_snapshot_account.increment_vault_operator_delegation_registration(
-        current_slot,
-        vault.key,
-        vault_index,
-        ncn_fee_group,
-        &stake_weights,
-    )?;
+    if let Some(operator_snapshot_page) = operator_snapshot_page {
+        let mut operator_snapshot_page_data = operator_snapshot_page.try_borrow_mut_data()?;
+        OperatorSnapshotPage::try_from_slice_unchecked_mut(&mut operator_snapshot_page_data)?
+            .insert_vault_operator_stake_weight(
+                ncn_vault_index,
+                vault.key,
+                vault_index,
+                ncn_fee_group,
+                &stake_weights,
+            )?;
+
+        operator_snapshot_account
+            .increment_paged_vault_operator_delegation_registration(current_slot, &stake_weights)?;
+    } else {
+        operator_snapshot_account.increment_vault_operator_delegation_registration(
+            current_slot,
+            vault.key,
+            vault_index,
+            ncn_fee_group,
+            &stake_weights,
+        )?;
+    }
 
     // If operator is finalized, increment operator registration
     if operator_snapshot_account.finalized() {
@@ -200,18 +250,46 @@ pub fn process_snapshot_vault_operator_delegation(
 
         epoch_snapshot_account.increment_operator_registration(
             current_slot,
+            operator_snapshot_account.is_active(),
             operator_snapshot_account.valid_operator_vault_delegations(),
             operator_snapshot_account.stake_weights(),
         )?;
+
+        // Caps that don't depend on the epoch's total stake weight are applied right away, the
+        // rest once the epoch snapshot is finalized through `ApplyStakeWeightCaps`
+        if !operator_snapshot_account.has_stake_weight_caps() {
+            let capped_stake_weight = operator_snapshot_account.apply_stake_weight_caps(0, &[])?;
+            epoch_snapshot_account.increment_operators_capped(capped_stake_weight)?;
+        }
     }
 
-    // Update Epoch State
+    // Update Epoch State, operators past the epoch state are tracked in an epoch state page
     {
+        let ncn_operator_index = operator_snapshot_account.ncn_operator_index();
+        let epoch_state_page = EpochStatePage::load_for_operator(
+            program_id,
+            optional_accounts
+                .get(2)
+                .filter(|account| account.key.ne(program_id)),
+            ncn.key,
+            epoch,
+            ncn_operator_index,
+            true,
+        )?;
+        let mut epoch_state_page_data = epoch_state_page
+            .map(|account| account.try_borrow_mut_data())
+            .transpose()?;
+        let epoch_state_page = epoch_state_page_data
+            .as_deref_mut()
+            .map(|data| EpochStatePage::try_from_slice_unchecked_mut(data))
+            .transpose()?;
+
         let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
         let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
         epoch_state_account.update_snapshot_vault_operator_delegation(
-            operator_snapshot_account.ncn_operator_index() as usize,
+            ncn_operator_index as usize,
             operator_snapshot_account.finalized(),
+            epoch_state_page,
         )?;
     }
 
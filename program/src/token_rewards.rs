@@ -0,0 +1,44 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_associated_token_account, load_token_mint};
+use jito_tip_router_core::{config::Config as NcnConfig, error::TipRouterError};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    program_pack::Pack, pubkey::Pubkey,
+};
+use spl_token_interface::state::Account as TokenAccount;
+
+/// Checks the ATA holding the rewards of a token reward router and returns its token balance
+pub fn load_receiver_token_account(
+    token_account: &AccountInfo,
+    receiver: &Pubkey,
+    mint: &Pubkey,
+) -> Result<u64, ProgramError> {
+    load_associated_token_account(token_account, receiver, mint)?;
+
+    let token_account_data = token_account.try_borrow_data()?;
+    Ok(TokenAccount::unpack(&token_account_data)?.amount)
+}
+
+/// Checks a token router's mint is one of the reward mints allowed by the NCN config
+pub fn load_reward_mint(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    mint: &AccountInfo,
+    config: Option<&AccountInfo>,
+) -> ProgramResult {
+    load_token_mint(mint)?;
+
+    let config = config
+        .filter(|account| account.key.ne(program_id))
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    NcnConfig::load(program_id, config, ncn, false)?;
+
+    let config_data = config.try_borrow_data()?;
+    let config_account = NcnConfig::try_from_slice_unchecked(&config_data)?;
+    if !config_account.is_reward_mint(mint.key) {
+        msg!("{} is not an allowed reward mint", mint.key);
+        return Err(TipRouterError::RewardMintNotAllowed.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,258 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_tip_router_core::{
+    ballot_box::{Ballot, BallotBox},
+    ballot_box_page::BallotBoxPage,
+    config::Config as NcnConfig,
+    epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
+    epoch_state::EpochState,
+    error::TipRouterError,
+    events::TipRouterEvent,
+    operator_vote_key::OperatorVoteKey,
+    pause::PausableSubsystem,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Accounts shared by `CastVote`, `CommitVote` and `RevealVote`
+pub struct VoteAccounts<'a, 'info> {
+    pub epoch_state: &'a AccountInfo<'info>,
+    pub ncn_config: &'a AccountInfo<'info>,
+    pub ballot_box: &'a AccountInfo<'info>,
+    pub ncn: &'a AccountInfo<'info>,
+    pub epoch_snapshot: &'a AccountInfo<'info>,
+    pub operator_snapshot: &'a AccountInfo<'info>,
+    pub operator: &'a AccountInfo<'info>,
+    /// Only set for operators past the ballot box
+    pub ballot_box_page: Option<&'a AccountInfo<'info>>,
+}
+
+impl VoteAccounts<'_, '_> {
+    /// Runs `f` on the ballot box and the operator's ballot box page, if any
+    pub fn with_ballot_box<T>(
+        &self,
+        f: impl FnOnce(&mut BallotBox, Option<&mut BallotBoxPage>) -> Result<T, ProgramError>,
+    ) -> Result<T, ProgramError> {
+        let mut ballot_box_data = self.ballot_box.data.borrow_mut();
+        let ballot_box = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
+
+        let mut ballot_box_page_data = self
+            .ballot_box_page
+            .map(|account| account.try_borrow_mut_data())
+            .transpose()?;
+        let ballot_box_page = ballot_box_page_data
+            .as_deref_mut()
+            .map(|data| BallotBoxPage::try_from_slice_unchecked_mut(data))
+            .transpose()?;
+
+        f(ballot_box, ballot_box_page)
+    }
+}
+
+/// Loads the accounts of a vote once the epoch snapshot is finalized, the signer must be the
+/// operator's voter. The epoch state is only written by the votes that get tallied.
+pub fn load_vote_accounts<'a, 'info>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    epoch: u64,
+    epoch_state_writable: bool,
+) -> Result<VoteAccounts<'a, 'info>, ProgramError> {
+    let [epoch_state, ncn_config, ballot_box, ncn, epoch_snapshot, operator_snapshot, operator, operator_admin, optional_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Operator is voting, needs to be signer
+    load_signer(operator_admin, false)?;
+
+    EpochState::load(
+        program_id,
+        epoch_state,
+        ncn.key,
+        epoch,
+        epoch_state_writable,
+    )?;
+    NcnConfig::load_unpaused(
+        program_id,
+        ncn_config,
+        ncn.key,
+        PausableSubsystem::Voting,
+        false,
+    )?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    Operator::load(&jito_restaking_program::id(), operator, false)?;
+
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, true)?;
+    EpochSnapshot::load(program_id, epoch_snapshot, ncn.key, epoch, false)?;
+    OperatorSnapshot::load(
+        program_id,
+        operator_snapshot,
+        operator.key,
+        ncn.key,
+        epoch,
+        false,
+    )?;
+
+    {
+        let operator_data = operator.data.borrow();
+        let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+
+        // The client passes the program id in place of a missing optional account
+        let operator_vote_key = optional_accounts
+            .first()
+            .filter(|account| account.key.ne(program_id));
+
+        OperatorVoteKey::check_voter(
+            program_id,
+            operator_vote_key,
+            operator.key,
+            ncn.key,
+            &operator_account.voter,
+            operator_admin.key,
+        )?;
+    }
+
+    {
+        let epoch_snapshot_data = epoch_snapshot.data.borrow();
+        let epoch_snapshot = EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?;
+
+        if !epoch_snapshot.finalized() {
+            return Err(TipRouterError::EpochSnapshotNotFinalized.into());
+        }
+    }
+
+    let ncn_operator_index = {
+        let operator_snapshot_data = operator_snapshot.data.borrow();
+        let operator_snapshot =
+            OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_data)?;
+        operator_snapshot.ncn_operator_index()
+    };
+
+    // Votes of operators past the ballot box are kept in a ballot box page
+    let ballot_box_page = BallotBoxPage::load_for_operator(
+        program_id,
+        optional_accounts
+            .get(1)
+            .filter(|account| account.key.ne(program_id)),
+        ncn.key,
+        epoch,
+        ncn_operator_index,
+    )?;
+
+    Ok(VoteAccounts {
+        epoch_state,
+        ncn_config,
+        ballot_box,
+        ncn,
+        epoch_snapshot,
+        operator_snapshot,
+        operator,
+        ballot_box_page,
+    })
+}
+
+/// Casts the operator's vote, or reveals it against their commitment when `salt` is set, then
+/// tallies the ballot box and updates the epoch state
+pub fn tally_vote(
+    vote_accounts: &VoteAccounts,
+    epoch: u64,
+    meta_merkle_root: &[u8; 32],
+    salt: Option<&[u8; 32]>,
+) -> ProgramResult {
+    let valid_slots_after_consensus = {
+        let ncn_config_data = vote_accounts.ncn_config.data.borrow();
+        let ncn_config = NcnConfig::try_from_slice_unchecked(&ncn_config_data)?;
+        ncn_config.valid_slots_after_consensus()
+    };
+
+    // Votes are tallied against the total left once every operator's caps are applied
+    let (total_stake_weight, active_operator_count) = {
+        let epoch_snapshot_data = vote_accounts.epoch_snapshot.data.borrow();
+        let epoch_snapshot = EpochSnapshot::try_from_slice_unchecked(&epoch_snapshot_data)?;
+        (
+            epoch_snapshot.voting_stake_weight()?,
+            epoch_snapshot.active_operator_count(),
+        )
+    };
+
+    let operator_stake_weights = {
+        let operator_snapshot_data = vote_accounts.operator_snapshot.data.borrow();
+        let operator_snapshot =
+            OperatorSnapshot::try_from_slice_unchecked(&operator_snapshot_data)?;
+        operator_snapshot.voting_stake_weights()?
+    };
+
+    let ncn = vote_accounts.ncn.key;
+    let operator = vote_accounts.operator.key;
+    let slot = Clock::get()?.slot;
+    let ballot = Ballot::new(meta_merkle_root);
+
+    vote_accounts.with_ballot_box(|ballot_box, ballot_box_page| {
+        let was_consensus_reached = ballot_box.is_consensus_reached();
+
+        match salt {
+            Some(salt) => ballot_box.reveal_vote_with_page(
+                ballot_box_page,
+                operator,
+                &ballot,
+                salt,
+                &operator_stake_weights,
+                slot,
+                valid_slots_after_consensus,
+            )?,
+            None => ballot_box.cast_vote_with_page(
+                ballot_box_page,
+                operator,
+                &ballot,
+                &operator_stake_weights,
+                slot,
+                valid_slots_after_consensus,
+            )?,
+        }
+
+        ballot_box.tally_votes(total_stake_weight, active_operator_count, slot)?;
+
+        TipRouterEvent::VoteCast {
+            ncn: *ncn,
+            epoch,
+            operator: *operator,
+            meta_merkle_root: *meta_merkle_root,
+            stake_weight: operator_stake_weights.stake_weight(),
+            slot,
+        }
+        .emit();
+
+        if ballot_box.is_consensus_reached() {
+            msg!(
+                "Consensus reached for epoch {} with ballot {:?}",
+                epoch,
+                ballot_box.get_winning_ballot_tally()?
+            );
+
+            if !was_consensus_reached {
+                TipRouterEvent::ConsensusReached {
+                    ncn: *ncn,
+                    epoch,
+                    meta_merkle_root: ballot_box.get_winning_ballot()?.root(),
+                    slot: ballot_box.slot_consensus_reached(),
+                }
+                .emit();
+            }
+        }
+
+        // Update Epoch State
+        let mut epoch_state_data = vote_accounts.epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_cast_vote(
+            ballot_box.operators_voted(),
+            ballot_box.is_consensus_reached(),
+            slot,
+        )?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,100 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_tip_router_core::{
+    ballot_box::BallotBox, ballot_box_page::BallotBoxPage, config::Config as NcnConfig,
+    epoch_state::EpochState, events::TipRouterEvent, operator_vote_key::OperatorVoteKey,
+    pause::PausableSubsystem,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_withdraw_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [epoch_state, ncn_config, ballot_box, ncn, operator, operator_voter, optional_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(operator_voter, false)?;
+
+    EpochState::load(program_id, epoch_state, ncn.key, epoch, true)?;
+    NcnConfig::load_unpaused(
+        program_id,
+        ncn_config,
+        ncn.key,
+        PausableSubsystem::Voting,
+        false,
+    )?;
+    Ncn::load(&jito_restaking_program::id(), ncn, false)?;
+    Operator::load(&jito_restaking_program::id(), operator, false)?;
+    BallotBox::load(program_id, ballot_box, ncn.key, epoch, true)?;
+
+    // Votes of operators past the ballot box are withdrawn from their ballot box page
+    let ballot_box_page = optional_accounts
+        .get(1)
+        .filter(|account| account.key.ne(program_id));
+    if let Some(ballot_box_page) = ballot_box_page {
+        BallotBoxPage::load_any_page(program_id, ballot_box_page, ncn.key, epoch, true)?;
+    }
+
+    {
+        let operator_data = operator.data.borrow();
+        let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+
+        // The client passes the program id in place of a missing optional account
+        let operator_vote_key = optional_accounts
+            .first()
+            .filter(|account| account.key.ne(program_id));
+
+        OperatorVoteKey::check_voter(
+            program_id,
+            operator_vote_key,
+            operator.key,
+            ncn.key,
+            &operator_account.voter,
+            operator_voter.key,
+        )?;
+    }
+
+    let mut ballot_box_data = ballot_box.data.borrow_mut();
+    let ballot_box = BallotBox::try_from_slice_unchecked_mut(&mut ballot_box_data)?;
+
+    let mut ballot_box_page_data = ballot_box_page
+        .map(|account| account.try_borrow_mut_data())
+        .transpose()?;
+    let ballot_box_page = ballot_box_page_data
+        .as_deref_mut()
+        .map(|data| BallotBoxPage::try_from_slice_unchecked_mut(data))
+        .transpose()?;
+
+    ballot_box.withdraw_vote_with_page(ballot_box_page, operator.key)?;
+
+    msg!(
+        "Operator {} withdrew their vote for epoch {}",
+        operator.key,
+        epoch
+    );
+
+    TipRouterEvent::VoteWithdrawn {
+        ncn: *ncn.key,
+        epoch,
+        operator: *operator.key,
+    }
+    .emit();
+
+    // Update Epoch State
+    {
+        let mut epoch_state_data = epoch_state.try_borrow_mut_data()?;
+        let epoch_state_account = EpochState::try_from_slice_unchecked_mut(&mut epoch_state_data)?;
+        epoch_state_account.update_withdraw_vote(ballot_box.operators_voted());
+    }
+
+    Ok(())
+}
@@ -2,7 +2,10 @@ use std::path::PathBuf;
 
 use clap::Parser;
 use log::info;
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair},
+};
 
 use crate::OperatorState;
 
@@ -15,6 +18,11 @@ pub struct Cli {
     #[arg(short, long, env)]
     pub operator_address: String,
 
+    /// Keypair registered as the operator's tip router vote key, votes are signed
+    /// with the keypair at `keypair_path` when not set
+    #[arg(long, env)]
+    pub vote_keypair: Option<String>,
+
     #[arg(short, long, env, default_value = "http://localhost:8899")]
     pub rpc_url: String,
 
@@ -84,6 +92,11 @@ impl Cli {
         })
     }
 
+    pub fn get_vote_keypair(&self) -> Keypair {
+        let vote_keypair_path = self.vote_keypair.as_ref().unwrap_or(&self.keypair_path);
+        read_keypair_file(vote_keypair_path).expect("Failed to read vote keypair file")
+    }
+
     pub fn create_save_path(&self) {
         let save_path = self.get_save_path();
         if !save_path.exists() {
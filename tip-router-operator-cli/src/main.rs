@@ -70,6 +70,7 @@ async fn main() -> Result<()> {
     info!(
         "CLI Arguments:
         keypair_path: {}
+        vote_keypair: {:?}
         operator_address: {}
         rpc_url: {}
         ledger_path: {}
@@ -80,6 +81,7 @@ async fn main() -> Result<()> {
         vote_microlamports: {}
         claim_microlamports: {}",
         cli.keypair_path,
+        cli.vote_keypair,
         cli.operator_address,
         cli.rpc_url,
         cli.ledger_path.display(),
@@ -403,6 +405,7 @@ async fn main() -> Result<()> {
             submit_to_ncn(
                 &rpc_client,
                 &keypair.clone(),
+                &cli.get_vote_keypair(),
                 &operator_address,
                 &meta_merkle_tree_path,
                 epoch,
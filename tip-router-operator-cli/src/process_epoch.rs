@@ -360,6 +360,7 @@ pub async fn loop_stages(
                 let submit_result = submit_to_ncn(
                     &rpc_client,
                     &keypair,
+                    &cli.get_vote_keypair(),
                     &operator_address,
                     &meta_merkle_tree_path,
                     epoch_to_process,
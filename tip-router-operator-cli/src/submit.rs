@@ -19,8 +19,8 @@ use crate::tip_router::send_set_merkle_root_txs;
 use crate::{get_epoch_percentage, meta_merkle_tree_file_name, rpc_utils, Version};
 use crate::{
     tip_router::{
-        cast_vote, get_ncn_config, set_merkle_root_instructions,
-        set_priority_fee_merkle_root_instructions,
+        cast_vote, get_ballot_box_page, get_ncn_config, next_vote_action,
+        set_merkle_root_instructions, set_priority_fee_merkle_root_instructions, vote_salt,
     },
     Cli,
 };
@@ -39,6 +39,7 @@ pub async fn submit_recent_epochs_to_ncn(
 ) -> Result<(), anyhow::Error> {
     let epoch = client.get_epoch_info().await?;
     let operator_address = Pubkey::from_str(&cli_args.operator_address)?;
+    let vote_keypair = cli_args.get_vote_keypair();
 
     for i in 0..num_monitored_epochs {
         let process_epoch = epoch
@@ -56,6 +57,7 @@ pub async fn submit_recent_epochs_to_ncn(
         match submit_to_ncn(
             client,
             keypair,
+            &vote_keypair,
             &operator_address,
             &target_meta_merkle_tree_path,
             process_epoch,
@@ -82,6 +84,7 @@ pub async fn submit_recent_epochs_to_ncn(
 pub async fn submit_to_ncn(
     client: &AsyncRpcClient,
     keypair: &Keypair,
+    vote_keypair: &Keypair,
     operator_address: &Pubkey,
     meta_merkle_tree_path: &PathBuf,
     merkle_root_epoch: u64,
@@ -169,43 +172,64 @@ pub async fn submit_to_ncn(
             anyhow::anyhow!("Failed to determine if voting is valid: {:?}", e)
         })?;
 
-    // If exists, look for vote from current operator
-    let vote = ballot_box
-        .operator_votes()
-        .iter()
-        .find(|vote| vote.operator() == operator_address);
-
-    let should_cast_vote = match vote {
-        Some(vote) => {
-            // If vote exists, cast_vote if different from current meta_merkle_root
-            let tally = ballot_box
-                .ballot_tallies()
-                .get(vote.ballot_index() as usize)
-                .ok_or_else(|| anyhow::anyhow!("Ballot tally not found"))?;
+    // Operators past the ballot box vote into a ballot box page
+    let ballot_box_page = get_ballot_box_page(
+        client,
+        tip_router_program_id,
+        ncn_address,
+        operator_address,
+        tip_router_target_epoch,
+    )
+    .await
+    .map_err(|e| {
+        datapoint_error!(
+            "tip_router_cli.ballot_box_page_error",
+            ("operator_address", operator_address.to_string(), String),
+            ("epoch", tip_router_target_epoch, i64),
+            ("status", "error", String),
+            ("error", format!("{:?}", e), String),
+            "cluster" => cluster,
+        );
+        anyhow::anyhow!("Failed to get ballot box page: {:?}", e)
+    })?;
 
-            tally.ballot().root() != meta_merkle_tree.merkle_root
-        }
-        None => true,
-    };
+    // With commit-reveal voting the operator commits first and reveals once the commit window has passed
+    let salt = vote_salt(
+        vote_keypair,
+        &meta_merkle_tree.merkle_root,
+        tip_router_target_epoch,
+    );
+    let vote_action = next_vote_action(
+        &ballot_box,
+        ballot_box_page
+            .as_ref()
+            .map(|(_, ballot_box_page)| ballot_box_page),
+        operator_address,
+        &meta_merkle_tree.merkle_root,
+        &salt,
+        epoch_info.absolute_slot,
+    );
 
     info!(
         "Determining if operator needs to vote...\n\
-        should_cast_vote: {}\n\
+        vote_action: {:?}\n\
         is_voting_valid: {}
         ",
-        should_cast_vote, is_voting_valid
+        vote_action, is_voting_valid
     );
 
-    if should_cast_vote && is_voting_valid {
+    if let Some(vote_action) = vote_action.filter(|_| is_voting_valid) {
         let res = cast_vote(
             client,
             keypair,
             tip_router_program_id,
             ncn_address,
             operator_address,
-            keypair,
+            vote_keypair,
+            ballot_box_page.map(|(address, _)| address),
             meta_merkle_tree.merkle_root,
             tip_router_target_epoch,
+            vote_action,
             submit_as_memo,
             compute_unit_price,
         )
@@ -222,13 +246,14 @@ pub async fn submit_to_ncn(
                         format!("{:?}", meta_merkle_tree.merkle_root),
                         String
                     ),
+                    ("vote_action", format!("{:?}", vote_action), String),
                     ("version", Version::default().to_string(), String),
                     ("tx_sig", format!("{:?}", signature), String),
                     "cluster" => cluster,
                 );
                 info!(
-                    "Cast vote for epoch {} with signature {:?}",
-                    tip_router_target_epoch, signature
+                    "Cast vote ({:?}) for epoch {} with signature {:?}",
+                    vote_action, tip_router_target_epoch, signature
                 )
             }
             Err(e) => {
@@ -2,12 +2,16 @@ use anyhow::Result;
 use jito_bytemuck::AccountDeserialize;
 use jito_priority_fee_distribution_sdk::PriorityFeeDistributionAccount;
 use jito_tip_distribution_sdk::{derive_config_account_address, TipDistributionAccount};
-use jito_tip_router_client::instructions::{CastVoteBuilder, SetMerkleRootBuilder};
+use jito_tip_router_client::instructions::{
+    CastVoteBuilder, CommitVoteBuilder, RevealVoteBuilder, SetMerkleRootBuilder,
+};
 use jito_tip_router_core::{
-    ballot_box::BallotBox,
+    ballot_box::{BallotBox, OperatorVote},
+    ballot_box_page::BallotBoxPage,
     config::Config,
     epoch_snapshot::{EpochSnapshot, OperatorSnapshot},
     epoch_state::EpochState,
+    operator_vote_key::OperatorVoteKey,
 };
 use log::{error, info};
 use meta_merkle_tree::meta_merkle_tree::MetaMerkleTree;
@@ -15,6 +19,7 @@ use solana_client::rpc_config::RpcSendTransactionConfig;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_rpc_client_api::client_error::{ErrorKind, Result as ClientResult};
 use solana_sdk::{
+    hash::hashv,
     instruction::Instruction,
     pubkey::Pubkey,
     signature::{Keypair, Signature},
@@ -37,7 +42,115 @@ pub async fn get_ncn_config(
         .expect("tip router config account should deserialize"))
 }
 
-/// Generate and send a CastVote instruction with the merkle root.
+/// Fetch the ballot box page holding the operator's vote, `None` when the vote is kept in the
+/// ballot box itself
+pub async fn get_ballot_box_page(
+    client: &RpcClient,
+    tip_router_program_id: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    tip_router_epoch: u64,
+) -> Result<Option<(Pubkey, BallotBoxPage)>> {
+    let operator_snapshot_pda = OperatorSnapshot::find_program_address(
+        tip_router_program_id,
+        operator,
+        ncn,
+        tip_router_epoch,
+    )
+    .0;
+    let operator_snapshot = client.get_account(&operator_snapshot_pda).await?;
+    let ncn_operator_index =
+        OperatorSnapshot::try_from_slice_unchecked(operator_snapshot.data.as_slice())
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize operator snapshot: {:?}", e))?
+            .ncn_operator_index();
+
+    let Some(page) = BallotBoxPage::page_for_operator(ncn_operator_index) else {
+        return Ok(None);
+    };
+
+    let ballot_box_page_pda =
+        BallotBoxPage::find_program_address(tip_router_program_id, ncn, tip_router_epoch, page).0;
+    let ballot_box_page = client.get_account(&ballot_box_page_pda).await?;
+    let ballot_box_page = *BallotBoxPage::try_from_slice_unchecked(ballot_box_page.data.as_slice())
+        .map_err(|e| anyhow::anyhow!("Failed to deserialize ballot box page: {:?}", e))?;
+
+    Ok(Some((ballot_box_page_pda, ballot_box_page)))
+}
+
+/// How a vote is submitted to the ballot box
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteAction {
+    /// Vote for the merkle root directly
+    Cast,
+    /// Commit to the merkle root without revealing it
+    Commit,
+    /// Reveal the merkle root committed to earlier
+    Reveal,
+}
+
+/// Salt used to commit to a merkle root. Derived from the voter's signature so the same
+/// salt can be recomputed for the reveal without persisting it between runs.
+pub fn vote_salt(
+    operator_voter: &Keypair,
+    meta_merkle_root: &[u8; 32],
+    tip_router_epoch: u64,
+) -> [u8; 32] {
+    let message = [
+        b"tip_router_vote_salt".as_ref(),
+        &tip_router_epoch.to_le_bytes(),
+        meta_merkle_root,
+    ]
+    .concat();
+    let signature = operator_voter.sign_message(&message);
+    hashv(&[signature.as_ref()]).to_bytes()
+}
+
+/// Determines the next vote to submit for an operator, if any. Operators past the ballot box
+/// keep their vote in `ballot_box_page`.
+pub fn next_vote_action(
+    ballot_box: &BallotBox,
+    ballot_box_page: Option<&BallotBoxPage>,
+    operator: &Pubkey,
+    meta_merkle_root: &[u8; 32],
+    salt: &[u8; 32],
+    current_slot: u64,
+) -> Option<VoteAction> {
+    let operator_votes = match ballot_box_page {
+        Some(ballot_box_page) => ballot_box_page.operator_votes().as_slice(),
+        None => ballot_box.operator_votes().as_slice(),
+    };
+    let vote = operator_votes
+        .iter()
+        .find(|vote| vote.operator() == operator);
+
+    if !ballot_box.is_commit_reveal() {
+        return match vote {
+            // If vote exists, cast_vote if different from current meta_merkle_root
+            Some(vote) => ballot_box
+                .ballot_tallies()
+                .get(vote.ballot_index() as usize)
+                .filter(|tally| tally.ballot().root() != *meta_merkle_root)
+                .map(|_| VoteAction::Cast),
+            None => Some(VoteAction::Cast),
+        };
+    }
+
+    let commitment = OperatorVote::commitment_hash(meta_merkle_root, salt, operator);
+    let committed = vote.is_some_and(|vote| *vote.commitment() == commitment);
+    let revealed = vote.is_some_and(|vote| vote.is_revealed());
+
+    if ballot_box.is_commit_phase(current_slot) && !committed {
+        Some(VoteAction::Commit)
+    } else if ballot_box.is_reveal_phase(current_slot) && committed && !revealed {
+        Some(VoteAction::Reveal)
+    } else {
+        None
+    }
+}
+
+/// Generate and send a CastVote, CommitVote or RevealVote instruction with the merkle root.
+/// `operator_voter` is either the restaking operator's voter or its registered vote key, and
+/// `ballot_box_page` the page holding the operator's vote if it is past the ballot box.
 #[allow(clippy::too_many_arguments)]
 pub async fn cast_vote(
     client: &RpcClient,
@@ -46,8 +159,10 @@ pub async fn cast_vote(
     ncn: &Pubkey,
     operator: &Pubkey,
     operator_voter: &Keypair,
+    ballot_box_page: Option<Pubkey>,
     meta_merkle_root: [u8; 32],
     tip_router_epoch: u64,
+    vote_action: VoteAction,
     submit_as_memo: bool,
     compute_unit_price: u64,
 ) -> Result<Signature> {
@@ -70,6 +185,10 @@ pub async fn cast_vote(
     )
     .0;
 
+    // Only read when the voter is not the restaking operator's voter
+    let operator_vote_key =
+        OperatorVoteKey::find_program_address(tip_router_program_id, operator, ncn).0;
+
     let ix = if submit_as_memo {
         spl_memo_interface::instruction::build_memo(
             &spl_memo_interface::v3::id(),
@@ -77,21 +196,68 @@ pub async fn cast_vote(
             &[&operator_voter.pubkey()],
         )
     } else {
-        CastVoteBuilder::new()
-            .epoch_state(epoch_state)
-            .config(ncn_config)
-            .ballot_box(ballot_box)
-            .ncn(*ncn)
-            .epoch_snapshot(epoch_snapshot)
-            .operator_snapshot(operator_snapshot)
-            .operator(*operator)
-            .operator_voter(operator_voter.pubkey())
-            .meta_merkle_root(meta_merkle_root)
-            .epoch(tip_router_epoch)
-            .instruction()
+        match vote_action {
+            VoteAction::Cast => CastVoteBuilder::new()
+                .epoch_state(epoch_state)
+                .config(ncn_config)
+                .ballot_box(ballot_box)
+                .ncn(*ncn)
+                .epoch_snapshot(epoch_snapshot)
+                .operator_snapshot(operator_snapshot)
+                .operator(*operator)
+                .operator_voter(operator_voter.pubkey())
+                .operator_vote_key(Some(operator_vote_key))
+                .ballot_box_page(ballot_box_page)
+                .meta_merkle_root(meta_merkle_root)
+                .epoch(tip_router_epoch)
+                .instruction(),
+            VoteAction::Commit => {
+                let salt = vote_salt(operator_voter, &meta_merkle_root, tip_router_epoch);
+                CommitVoteBuilder::new()
+                    .epoch_state(epoch_state)
+                    .config(ncn_config)
+                    .ballot_box(ballot_box)
+                    .ncn(*ncn)
+                    .epoch_snapshot(epoch_snapshot)
+                    .operator_snapshot(operator_snapshot)
+                    .operator(*operator)
+                    .operator_voter(operator_voter.pubkey())
+                    .operator_vote_key(Some(operator_vote_key))
+                    .ballot_box_page(ballot_box_page)
+                    .vote_commitment(OperatorVote::commitment_hash(
+                        &meta_merkle_root,
+                        &salt,
+                        operator,
+                    ))
+                    .epoch(tip_router_epoch)
+                    .instruction()
+            }
+            VoteAction::Reveal => RevealVoteBuilder::new()
+                .epoch_state(epoch_state)
+                .config(ncn_config)
+                .ballot_box(ballot_box)
+                .ncn(*ncn)
+                .epoch_snapshot(epoch_snapshot)
+                .operator_snapshot(operator_snapshot)
+                .operator(*operator)
+                .operator_voter(operator_voter.pubkey())
+                .operator_vote_key(Some(operator_vote_key))
+                .ballot_box_page(ballot_box_page)
+                .meta_merkle_root(meta_merkle_root)
+                .salt(vote_salt(
+                    operator_voter,
+                    &meta_merkle_root,
+                    tip_router_epoch,
+                ))
+                .epoch(tip_router_epoch)
+                .instruction(),
+        }
     };
 
-    info!("Submitting meta merkle root {:?}", meta_merkle_root);
+    info!(
+        "Submitting meta merkle root {:?} ({:?})",
+        meta_merkle_root, vote_action
+    );
 
     // Configure instruction with priority fees
     let instructions = priority_fees::configure_instruction(ix, compute_unit_price, None);